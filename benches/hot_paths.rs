@@ -0,0 +1,82 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use revw::app::{App, FileMode, FormatMode};
+use revw::bench::{draw, synthetic_json, synthetic_markdown, synthetic_value};
+use revw::config::RcConfig;
+use revw::convert::{Converter, Format};
+use revw::json_ops::JsonOperations;
+use revw::markdown_ops::MarkdownOperations;
+use std::hint::black_box;
+
+/// Document sizes in entries
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+/// A View-mode app holding `entries` synthetic cards, independent of ~/.revwrc
+fn loaded_app(entries: usize) -> App {
+    let mut app = App::with_config(FormatMode::View, RcConfig::default());
+    app.file_mode = FileMode::Json;
+    app.json_input = synthetic_json(entries);
+    app.convert_json();
+    app
+}
+
+fn parse_markdown(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_markdown");
+    group.sample_size(10);
+    for entries in SIZES {
+        let markdown = synthetic_markdown(entries);
+        group.bench_with_input(BenchmarkId::from_parameter(entries), &markdown, |b, markdown| {
+            b.iter(|| MarkdownOperations::parse_to_json(black_box(markdown)))
+        });
+    }
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert");
+    group.sample_size(10);
+    for entries in SIZES {
+        let value = synthetic_value(entries);
+        for format in [Format::Md, Format::Text, Format::Table] {
+            let converter = Converter::new().to(format);
+            group.bench_with_input(BenchmarkId::new(format!("{:?}", format), entries), &value, |b, value| {
+                b.iter(|| converter.convert_value(black_box(value)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn convert_json(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert_json");
+    group.sample_size(10);
+    for entries in SIZES {
+        let mut app = loaded_app(entries);
+        group.bench_function(BenchmarkId::from_parameter(entries), |b| b.iter(|| app.convert_json()));
+    }
+    group.finish();
+}
+
+fn filter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter");
+    group.sample_size(10);
+    for entries in SIZES {
+        let value = synthetic_value(entries);
+        group.bench_with_input(BenchmarkId::from_parameter(entries), &value, |b, value| {
+            b.iter(|| JsonOperations::filter_entries(black_box(value), "resource 42"))
+        });
+    }
+    group.finish();
+}
+
+fn render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    group.sample_size(10);
+    for entries in SIZES {
+        let mut app = loaded_app(entries);
+        group.bench_function(BenchmarkId::from_parameter(entries), |b| b.iter(|| draw(&mut app)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_markdown, convert, convert_json, filter, render);
+criterion_main!(benches);
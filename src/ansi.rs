@@ -0,0 +1,144 @@
+//! ANSI colors for `--stdout` listings, taken from the active color scheme.
+//!
+//! The listing renderers call a `Painter` for each part they print (section headers,
+//! entry titles, URLs); a painter without a scheme hands text back unchanged, so the
+//! plain output stays byte-for-byte what it was.
+
+use crate::config::ColorScheme;
+use ratatui::style::Color;
+
+/// When `--color` colors the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Only when stdout is a terminal and `NO_COLOR` is not set
+    #[default]
+    Auto,
+    Always,
+    Never,
+    /// Always, with the Mono scheme: bold and underline, no colors
+    Mono,
+}
+
+impl ColorMode {
+    /// Parse a `--color` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "auto" => Some(ColorMode::Auto),
+            "always" => Some(ColorMode::Always),
+            "never" => Some(ColorMode::Never),
+            "mono" => Some(ColorMode::Mono),
+            _ => None,
+        }
+    }
+
+    /// Whether to color, given whether stdout is a terminal
+    pub fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            ColorMode::Always | ColorMode::Mono => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => is_terminal && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+        }
+    }
+}
+
+/// Decorates parts of a listing; `Painter::plain()` leaves them as they are
+#[derive(Debug, Clone, Copy)]
+pub struct Painter<'a> {
+    scheme: Option<&'a ColorScheme>,
+}
+
+impl<'a> Painter<'a> {
+    pub fn plain() -> Self {
+        Self { scheme: None }
+    }
+
+    pub fn new(scheme: &'a ColorScheme) -> Self {
+        Self { scheme: Some(scheme) }
+    }
+
+    /// `OUTSIDE`, `## INSIDE`, table headers: bold in the Markdown header color
+    pub fn section(&self, text: &str) -> String {
+        self.paint(text, |s| s.md_header, "1")
+    }
+
+    /// Entry names and dates: bold in the card title color
+    pub fn title(&self, text: &str) -> String {
+        self.paint(text, |s| s.card_title, "1")
+    }
+
+    /// URLs: underlined in the Markdown link color
+    pub fn url(&self, text: &str) -> String {
+        self.paint(text, |s| s.md_url, "4")
+    }
+
+    /// Percentages and field labels: the dimmed text color
+    pub fn dim(&self, text: &str) -> String {
+        self.paint(text, |s| s.text_dim, "")
+    }
+
+    fn paint(&self, text: &str, color: fn(&ColorScheme) -> Color, attributes: &str) -> String {
+        let Some(scheme) = self.scheme else {
+            return text.to_string();
+        };
+        if text.is_empty() {
+            return String::new();
+        }
+        let codes: Vec<String> = [attributes.to_string(), foreground(color(scheme)).unwrap_or_default()]
+            .into_iter()
+            .filter(|code| !code.is_empty())
+            .collect();
+        if codes.is_empty() {
+            return text.to_string();
+        }
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
+
+/// SGR parameters for a foreground color; None for the terminal default
+fn foreground(color: Color) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => "30",
+        Color::Red => "31",
+        Color::Green => "32",
+        Color::Yellow => "33",
+        Color::Blue => "34",
+        Color::Magenta => "35",
+        Color::Cyan => "36",
+        Color::Gray => "37",
+        Color::DarkGray => "90",
+        Color::LightRed => "91",
+        Color::LightGreen => "92",
+        Color::LightYellow => "93",
+        Color::LightBlue => "94",
+        Color::LightMagenta => "95",
+        Color::LightCyan => "96",
+        Color::White => "97",
+        Color::Indexed(index) => return Some(format!("38;5;{}", index)),
+        Color::Rgb(r, g, b) => return Some(format!("38;2;{};{};{}", r, g, b)),
+    };
+    Some(code.to_string())
+}
+
+/// Color Markdown line by line: section headers, entry headings and `**URL:**` values
+pub fn paint_markdown(markdown: &str, painter: &Painter) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if line.starts_with("## ") {
+                painter.section(line)
+            } else if line.starts_with("### ") {
+                painter.title(line)
+            } else if let Some(url) = line.strip_prefix("**URL:** ") {
+                format!("{} {}", painter.dim("**URL:**"), painter.url(url))
+            } else if line.starts_with("**") && line[2..].contains(":**") {
+                let end = line[2..].find(":**").map_or(0, |i| i + 5);
+                format!("{}{}", painter.dim(&line[..end]), &line[end..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + if markdown.ends_with('\n') { "\n" } else { "" }
+}
@@ -1,32 +1,86 @@
+mod attachments;
+mod background;
+mod bulk;
 mod clipboard;
 mod command;
 mod completion;
+mod confirm;
 mod edit;
+mod entry;
 mod explorer;
 mod explorer_ops;
+mod field_edit;
 mod file;
+mod filter;
+mod finder;
+mod glyphs;
 mod help;
 mod history;
+mod hooks;
+mod journal;
+mod keys;
+mod links;
+mod log;
 mod markdown;
+mod matches;
 mod navigation;
+mod operators;
 mod outline;
+mod palette;
+mod panels;
+mod preview;
+mod overlay_completion;
+mod recent;
+mod reorder;
+mod report;
+mod rotate;
+mod safe;
 mod search;
+mod skeleton;
+mod snapshots;
+mod sources;
+mod stats;
 mod substitute;
+mod swap;
+mod sync;
+mod text_visual;
 mod token;
 mod undo;
 
-use crate::config::{BorderStyle, ColorScheme, RcConfig};
-use crate::content_ops::ContentOperations;
+#[allow(unused_imports)] // library API; the app writes it through clipboard_set_text
+pub use clipboard::osc52_sequence;
+pub use confirm::ConfirmAction;
+pub use operators::Motion;
+pub use outline::OutlineGroup;
+
+use crate::config::{AutosavePolicy, BorderStyle, ClipboardBackend, ColorScheme, DefaultSection, ExplorerFilter, Glyphs, InsideOrder, PasteSource, RcConfig};
+use crate::content_ops::{ContentOperations, TagStats};
+use crate::due;
+use crate::file_index::FileIndex;
+use crate::hooks::Hooks;
+use crate::i18n::{self, Lang, Msg};
 use crate::json_ops::JsonOperations;
+use crate::json_path;
+use crate::keymap::{KeyContext, Keymap, PaletteItem};
+use crate::line_endings::TextFormat;
 use crate::markdown_ops::MarkdownOperations;
 use crate::navigation::Navigator;
 use crate::rendering::{RelfEntry, RelfLineStyle, RelfRenderResult, Renderer};
+use crate::snapshot::{self, DiffLine, Snapshot};
+use crate::status::EntryStatus;
+use crate::sync::SyncConfig;
 use crate::syntax_highlight::SyntaxHighlighter;
-use crate::ui::markdown_highlight::highlight_markdown_with_code_blocks;
-use ratatui::text::Span;
+use crate::task::BackgroundTask;
+use crate::url_ops::UrlRules;
+use chrono::Local;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+};
 use std::{
+    collections::{HashMap, HashSet},
     path::PathBuf,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 #[derive(Clone, PartialEq)]
@@ -35,6 +89,8 @@ pub enum InputMode {
     Insert,
     Command, // For vim-style commands like :w, :wq
     Search,  // For vim-style search like /pattern
+    Filter,  // Live filter prompt (F in View mode)
+    Field,   // One-field prompt (cn / cu / cp in View mode)
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -44,6 +100,12 @@ pub enum FormatMode {
     Help,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TextVisualMode {
+    Char, // v: character-wise selection
+    Line, // V: line-wise selection
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum FileMode {
     Json,
@@ -68,13 +130,18 @@ pub struct App {
     pub relf_entries: Vec<RelfEntry>,
     pub selected_entry_index: usize, // Currently selected entry in View mode
     pub editing_entry: bool, // Whether we're editing entry in overlay
+    pub editing_new_entry: Option<usize>, // Card selected before o / O inserted the card being edited (cancel removes it)
     pub edit_buffer: Vec<String>, // Buffer for editing entry fields
     pub edit_buffer_is_placeholder: Vec<bool>, // Track if each field is a placeholder
+    pub edit_field_keys: Vec<String>, // JSON key of each field (standard fields first, then custom ones)
     pub edit_field_index: usize, // Which field is being edited
     pub edit_field_editing_mode: bool, // Whether editing within a field (Enter pressed)
     pub edit_insert_mode: bool, // Whether in insert mode within overlay
     pub edit_skip_normal_mode: bool, // True if entered insert mode directly with 'i' (skip normal mode on Esc)
     pub edit_cursor_pos: usize, // Cursor position within current field
+    pub edit_completion_index: HashMap<String, Vec<String>>, // Existing names and tags, built when the overlay opens
+    pub edit_completions: Vec<String>, // Suggestions for the text before the cursor (Tab accepts)
+    pub edit_completion_selected: usize,
     pub edit_hscroll: u16, // Horizontal scroll offset for overlay fields
     pub edit_vscroll: u16, // Vertical scroll offset for context field
     pub overlay_context_height: u16, // Last rendered visible height for overlay context field
@@ -82,12 +149,17 @@ pub struct App {
     pub overlay_field_width: u16, // Last rendered visible width for single-line overlay fields
     pub edit_yank_buffer: String, // Yank buffer for overlay context field
     pub showing_help: bool, // Track if help is being shown
+    pub cheatsheet_open: bool, // Compact key list for the current context (g? / F1)
+    pub tag_stats: Option<Vec<TagStats>>, // Rows of the :stats tags table while it is shown
+    pub log_file: Option<PathBuf>, // Debug log being written (--log-file or set log_file=PATH)
+    pub log_lines: Option<Vec<String>>, // Tail of the debug log while :log shows it
+    pub link_hints: Vec<(char, String)>, // Labelled links of the selected card while gl waits for a letter
+    pub focus_mode: bool, // Selected card fills the content area (z in View mode); j/k scroll it
     pub scroll: u16,
     pub max_scroll: u16,
     pub status_message: String,
     pub status_time: Option<Instant>,
     pub file_path: Option<PathBuf>,
-    pub vim_buffer: String,
     pub format_mode: FormatMode,
     pub previous_format_mode: FormatMode, // Store mode before entering Help
     pub command_buffer: String,     // For vim commands like :w, :wq
@@ -98,9 +170,10 @@ pub struct App {
     pub content_cursor_line: usize, // Current line in content
     pub content_cursor_col: usize,  // Current column in content line
     pub show_cursor: bool,          // Show/hide cursor in Normal mode
-    pub dd_count: usize,            // Count consecutive 'd' presses for dd command
-    pub yy_count: usize,            // Count consecutive 'y' presses for yy command
+    pub pending_operator: String,   // Operator waiting for a motion (d, c, di, ci)
     pub line_yank_buffer: String,   // Buffer for yanked line (dd/yy commands)
+    pub card_register: Vec<(String, serde_json::Value)>, // Cards deleted with dd in View mode (section, entry), for p/P
+    pub keymap: Keymap,             // Normal-mode key sequences and counts typed so far (gg, 3dd)
     // Current renderable content width (inner area). Used for accurate wrapping.
     pub content_width: u16,
     // Horizontal scroll offset (used mainly in View mode without wrapping)
@@ -114,18 +187,53 @@ pub struct App {
     pub current_match_index: Option<usize>,
     // Filter functionality (View mode only)
     pub filter_pattern: String,
+    // Quick edit of one field of the selected card at the status bar (cn / cu / cp)
+    pub field_edit_key: Option<&'static str>,
+    pub field_edit_buffer: String,
     // Undo/Redo functionality
     pub undo_stack: Vec<UndoState>,
     pub redo_stack: Vec<UndoState>,
+    pub undo_group_active: bool, // While set, edits join the last undo state (c operator + typed text)
     // Auto-reload functionality
     pub auto_reload: bool,
+    pub safe_mode: bool, // --safe: no autosave, file watching, hooks or clipboard reads
     pub last_save_time: Option<Instant>,
     pub file_path_changed: bool, // Signal that file path changed and watcher needs update
+    pub background_task: Option<BackgroundTask>, // Long operation running off the UI thread, shown in the status bar
+    pub hook_task: Option<BackgroundTask>, // Hooks running off the UI thread; keys keep working
+    pub hook_queue: Vec<(PathBuf, &'static str)>, // Saved files whose post_save hooks wait for hook_task
+    // Save conflict detection: the file as last loaded/saved, and the pending r/o/m/c prompt
+    pub disk_state: Option<DiskState>,
+    pub merge_base: Option<serde_json::Value>, // Entries as last loaded/saved, the base of the (m)erge answer
+    pub save_conflict: bool,
+    pub autosave_held: bool, // Set when a conflicting save is cancelled, cleared by the next save or reload
+    pub disk_changed: bool, // File changed on disk while the buffer had unsaved changes; warns until saved or reloaded
+    pub convert_delete_pending: Option<PathBuf>, // File :convert was run on, awaiting y/n to delete it
+    // Crash recovery: where swap files go (None writes none), the swap file of the modified
+    // buffer and when it was last written, a leftover one's content awaiting r/d, and a
+    // swap file left alone (another session's, or recovery put off with Esc)
+    pub swap_root: Option<PathBuf>,
+    pub swap_file: Option<PathBuf>,
+    pub swap_written: Option<Instant>,
+    pub swap_recovery_pending: Option<String>,
+    pub swap_kept: Option<PathBuf>,
+    // Journal files: INSIDE entries the file was opened with (None when not a journal),
+    // and the (JSON, Markdown) content that last kept them intact
+    pub journal_history: Option<Vec<serde_json::Value>>,
+    pub journal_checked: (String, String),
+    // Files of a merged view (`revw a.json b.json`), empty when one file is open
+    pub sources: Vec<SourceFile>,
     // Scrollbar interaction state
     pub dragging_scrollbar: Option<ScrollbarType>,
     // Substitute confirmation state
     pub substitute_confirmations: Vec<SubstituteMatch>,
     pub current_substitute_index: usize,
+    // Smart paste: pasted OUTSIDE entries whose URL already exists, awaiting y/n/a/q
+    pub paste_merge_pending: Vec<serde_json::Value>,
+    // Card moving to the other section (original index), awaiting f/d/c
+    pub card_move_pending: Option<usize>,
+    // Bulk update (:rename-tag, :set-url-domain): rewritten JSON and done message, awaiting y/n
+    pub bulk_update_pending: Option<(String, String)>,
     // Double-click detection
     pub last_click_time: Option<Instant>,
     // Line number display setting
@@ -133,6 +241,13 @@ pub struct App {
     pub show_relative_line_numbers: bool,
     // Maximum visible cards in View mode (1-10, default 5)
     pub max_visible_cards: usize,
+    // Context lines shown per card before "… (+N lines)" (0 = all)
+    pub max_context_lines: usize,
+    pub expanded_cards: HashSet<String>, // Identities of cards opened with Tab, see RelfEntry::identity
+    pub card_scroll: usize, // First card shown in the card view (kept in sync while rendering)
+    pub smooth_scroll: bool, // Glide the card view to a new scroll position over a few frames
+    pub card_scroll_shown: usize, // First card drawn in the last frame, trailing card_scroll while gliding
+    pub scroll_animating: bool, // A glide is under way; the event loop redraws without waiting for keys
     // Total visual (wrapped) rows of the selected card's context - updated each render
     pub card_context_rows: usize,
     // Show file extension in explorer
@@ -151,6 +266,9 @@ pub struct App {
     pub explorer_current_dir: PathBuf,
     pub explorer_has_focus: bool, // Track which window has focus
     pub explorer_dir_changed: bool, // Signal that explorer directory changed and watcher needs update
+    pub explorer_filter: ExplorerFilter, // Ignored files, dotfiles and unsupported extensions to leave out
+    pub explorer_show_all: bool, // gh: list every file regardless of explorer_filter
+    pub explorer_area: Rect, // Where the explorer was last drawn, for mouse clicks and scrolling
     // File operation confirmation/prompt state
     pub file_op_pending: Option<FileOperation>,
     pub file_op_prompt_buffer: String, // Buffer for filename input during file operations
@@ -158,12 +276,18 @@ pub struct App {
     pub visual_mode: bool,
     pub visual_start_index: usize, // Start of visual selection
     pub visual_end_index: usize,   // End of visual selection (inclusive)
+    pub visual_inverted: bool,     // Select every card except the visual range
+    // Visual text selection (Edit mode only)
+    pub text_visual: Option<TextVisualMode>,
+    pub text_visual_anchor: (usize, usize), // (line, col) where the selection started
     // View Edit mode (Overlay mode only) - render \n as newlines
     pub view_edit_mode: bool,
     // Color scheme
     pub colorscheme: ColorScheme,
     // Border style (rounded or plain)
     pub border_style: BorderStyle,
+    pub accessible: bool, // Blank borders and ASCII instead of decorative glyphs, for screen readers
+    pub glyphs: Glyphs, // Box drawing and symbols, or ASCII for terminals without good Unicode support
     // Card outline overlay
     pub outline_open: bool,
     pub outline_selected_index: usize,
@@ -174,12 +298,105 @@ pub struct App {
     pub outline_search_query: String, // Search query for outline
     pub outline_search_matches: Vec<usize>, // Indices of matching entries
     pub outline_search_current: usize, // Current match index in search_matches
+    pub outline_group: OutlineGroup, // How the card outline groups its rows (:outline group)
+    pub outline_collapsed: Vec<String>, // Titles of folded outline groups
+    pub outline_area: Rect, // Where the outline was last drawn, for mouse clicks and scrolling
+    // Quickfix-style list of search matches (:matches)
+    pub matches_open: bool,
+    pub matches_selected_index: usize,
+    pub matches_has_focus: bool,
+    // Named snapshots (:snapshot NAME, :snapshots)
+    pub snapshot_root: Option<PathBuf>, // Where snapshot stores live (under the data dir)
+    pub snapshots_open: bool,
+    pub snapshots: Vec<Snapshot>,
+    pub snapshots_selected_index: usize,
+    pub snapshot_diff: Option<Vec<DiffLine>>, // Selected snapshot vs the buffer, while shown
+    pub snapshot_diff_scroll: usize,
+    // Recently opened files (:recent, startup screen)
+    pub recent_list_path: Option<PathBuf>, // Where opened files are recorded; None records nothing
+    pub recent_open: bool,
+    pub recent_files: Vec<PathBuf>,
+    pub recent_selected_index: usize,
+    // Fuzzy file finder (Ctrl+p, :find)
+    pub finder_open: bool,
+    pub finder_query: String,
+    pub finder_index: Option<FileIndex>, // Files under the explorer directory, filled in the background
+    pub finder_matches: Vec<(usize, Vec<usize>)>, // Index into finder_index.files and the matched char positions
+    pub finder_selected: usize,
+    // Command palette (Space, Ctrl+k)
+    pub palette_open: bool,
+    pub palette_query: String,
+    pub palette_items: Vec<PaletteItem>, // Actions of the mode the palette was opened in
+    pub palette_matches: Vec<(usize, Vec<usize>)>, // Index into palette_items and the matched char positions
+    pub palette_selected: usize,
     // File mode (JSON or Markdown)
     pub file_mode: FileMode,
     // Syntax highlighter (lazy initialized)
     pub syntax_highlighter: Option<SyntaxHighlighter>,
-    // Cache for markdown syntax highlighting (Edit mode)
-    pub markdown_highlight_cache: Vec<Vec<Span<'static>>>,
+    // Highlighted Edit mode lines on screen, keyed by a hash of their text (a whole code
+    // block for lines inside one); lines that scroll out of view are dropped
+    pub edit_highlight_cache: HashMap<u64, Vec<Vec<Span<'static>>>>,
+    // Visual rows of Edit mode lines, keyed by a hash of line text and wrap width
+    pub edit_wrap_cache: HashMap<u64, usize>,
+    // Highlighted card bodies for the cards on screen (View mode), keyed by relf_entries index
+    // with a hash of what they were built from; cards that scroll out of view are dropped
+    pub card_body_cache: HashMap<usize, (u64, Vec<Line<'static>>)>,
+    // Inline image preview (requires the `preview` feature to draw)
+    pub image_preview: bool,
+    pub preview_request: Option<(PathBuf, Rect)>, // Image + area requested by the card renderer this frame
+    pub preview_drawn: Option<(PathBuf, Rect)>,   // Image + area currently painted on the terminal
+    pub preview_remote: Option<String>,           // Remote image URL of the selected card, noted once in the status bar
+    // Local image behind each previewed URL, keyed with the open file's directory;
+    // cleared when the file is loaded or reloaded so moved images are found again
+    pub preview_paths: HashMap<(String, Option<PathBuf>), Option<PathBuf>>,
+    // Strict parsing: reject unknown structure instead of best-effort parsing
+    pub strict_parsing: bool,
+    // When mutating operations write the file (always, on-action, manual)
+    pub autosave: AutosavePolicy,
+    pub autosave_interval: Duration, // Autosave writes at most once per interval
+    pub autosave_pending: bool, // A change autosave held back until the interval has passed
+    // Write JSON in canonical form (key order, 2-space indent, trailing newline) on save
+    pub format_on_save: bool,
+    pub autoname_on_save: bool, // Name unnamed OUTSIDE entries after their context when saving
+    // Save through a temporary file renamed over the original instead of rewriting it in place
+    pub atomic_save: bool,
+    // Write files back with the CRLF line endings and BOM they were loaded with
+    pub preserve_line_endings: bool,
+    // Line endings and BOM of the open file
+    pub text_format: TextFormat,
+    // Clipboard backend for yanks (system, osc52, auto)
+    pub clipboard: ClipboardBackend,
+    // Where pastes read from (clipboard, or the primary selection on Linux)
+    pub paste_source: PasteSource,
+    // Set OUTSIDE percentages from context task checkboxes when the edit overlay saves
+    pub checkbox_percentage: bool,
+    // Show OUTSIDE entries whose status is dropped (hidden by default)
+    pub show_dropped: bool,
+    // Ask y/n before deleting cards, clearing sections and pastes that replace content
+    pub confirm_delete: bool,
+    pub confirm_clear: bool,
+    pub confirm_paste: bool,
+    pub paste_limit: usize, // Pastes bringing in more entries ask first; 0 never asks
+    pub skeleton_md: Option<PathBuf>, // What new .md files start as (set skeleton_md=PATH)
+    pub skeleton_json: Option<PathBuf>, // What new .json files start as (set skeleton_json=PATH)
+    pub confirm_pending: Option<ConfirmAction>, // Destructive action waiting for y/n
+    // Cards due within this many days are highlighted; active overdue OUTSIDE entries in the file
+    pub due_soon_days: i64,
+    pub overdue_count: usize,
+    // Normalizations applied to edited and pasted URLs
+    pub url_rules: UrlRules,
+    // Section :a TEXT adds its entry to
+    pub default_section: DefaultSection,
+    // Order INSIDE cards are shown in (display only)
+    pub inside_order: InsideOrder,
+    // Language of help, status messages and overlay titles
+    pub lang: Lang,
+    // Shell commands run before/after saving and after imports (`hook EVENT COMMAND` in ~/.revwrc)
+    pub hooks: Hooks,
+    // WebDAV remote for :sync (`sync KEY VALUE` in ~/.revwrc)
+    pub sync: SyncConfig,
+    pub sync_root: Option<PathBuf>, // Where the last-sync records live (under the data dir)
+    pub templates: Vec<(String, String)>, // :yank-template templates (`template NAME TEXT` in ~/.revwrc)
 }
 
 #[derive(Clone)]
@@ -204,6 +421,27 @@ pub enum ScrollbarType {
     Horizontal,
 }
 
+/// Modification time and content hash of the open file when it was last loaded or saved
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DiskState {
+    pub modified: Option<SystemTime>,
+    pub len: u64,
+    pub hash: u64,
+}
+
+/// One file of a merged view
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    // Shown on its cards and stored under crate::sources::KEY in each entry
+    pub label: String,
+    pub markdown: bool,
+    // The file as last loaded or saved; saving replaces its entries and keeps other top-level keys
+    pub base: serde_json::Value,
+    pub disk_state: Option<DiskState>,
+    pub text_format: TextFormat,
+}
+
 #[derive(Clone)]
 pub struct UndoState {
     pub json_input: String,
@@ -215,10 +453,12 @@ pub struct UndoState {
 
 impl App {
     pub fn new(format_mode: FormatMode) -> Self {
-        // Load RC configuration
-        let rc_config = RcConfig::load();
+        Self::with_config(format_mode, RcConfig::load())
+    }
 
-        let app = Self {
+    /// App set up from `rc_config`, such as ~/.revwrc layered with a project config
+    pub fn with_config(format_mode: FormatMode, rc_config: RcConfig) -> Self {
+        Self {
             input_mode: InputMode::Normal,
             json_input: String::new(),
             markdown_input: String::new(),
@@ -228,13 +468,18 @@ impl App {
             relf_entries: Vec::new(),
             selected_entry_index: 0,
             editing_entry: false,
+            editing_new_entry: None,
             edit_buffer: Vec::new(),
             edit_buffer_is_placeholder: Vec::new(),
+            edit_field_keys: Vec::new(),
             edit_field_index: 0,
             edit_field_editing_mode: false,
             edit_insert_mode: false,
             edit_skip_normal_mode: false,
             edit_cursor_pos: 0,
+            edit_completion_index: HashMap::new(),
+            edit_completions: Vec::new(),
+            edit_completion_selected: 0,
             edit_hscroll: 0,
             edit_vscroll: 0,
             overlay_context_height: 20,
@@ -242,12 +487,17 @@ impl App {
             overlay_field_width: 70,
             edit_yank_buffer: String::new(),
             showing_help: false,
+            cheatsheet_open: false,
+            tag_stats: None,
+            log_file: rc_config.log_file,
+            log_lines: None,
+            link_hints: Vec::new(),
+            focus_mode: false,
             scroll: 0,
             max_scroll: 0,
             status_message: "".to_string(),
             status_time: Some(Instant::now()),
             file_path: None,
-            vim_buffer: String::new(),
             format_mode,
             previous_format_mode: format_mode, // Initialize with same mode
             command_buffer: String::new(),
@@ -258,9 +508,10 @@ impl App {
             content_cursor_line: 0,
             content_cursor_col: 0,
             show_cursor: true,
-            dd_count: 0,
-            yy_count: 0,
+            pending_operator: String::new(),
             line_yank_buffer: String::new(),
+            card_register: Vec::new(),
+            keymap: Keymap::default(),
             content_width: 80,
             hscroll: 0,
             visible_height: 20,
@@ -269,19 +520,49 @@ impl App {
             search_matches: Vec::new(),
             current_match_index: None,
             filter_pattern: String::new(),
+            field_edit_key: None,
+            field_edit_buffer: String::new(),
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
+            undo_group_active: false,
             auto_reload: true,
+            safe_mode: false,
             last_save_time: None,
+            disk_state: None,
+            merge_base: None,
+            save_conflict: false,
+            disk_changed: false,
+            autosave_held: false,
+            convert_delete_pending: None,
+            swap_root: None,
+            swap_file: None,
+            swap_written: None,
+            swap_recovery_pending: None,
+            swap_kept: None,
+            journal_history: None,
+            journal_checked: (String::new(), String::new()),
+            sources: Vec::new(),
             file_path_changed: false,
+            background_task: None,
+            hook_task: None,
+            hook_queue: Vec::new(),
             dragging_scrollbar: None,
             substitute_confirmations: Vec::new(),
             current_substitute_index: 0,
+            paste_merge_pending: Vec::new(),
+            card_move_pending: None,
+            bulk_update_pending: None,
             last_click_time: None,
             show_line_numbers: rc_config.show_line_numbers,
             show_relative_line_numbers: rc_config.show_relative_line_numbers,
             show_extension: rc_config.show_extension,
             max_visible_cards: rc_config.max_visible_cards,
+            max_context_lines: rc_config.max_context_lines,
+            expanded_cards: HashSet::new(),
+            card_scroll: 0,
+            smooth_scroll: rc_config.smooth_scroll,
+            card_scroll_shown: 0,
+            scroll_animating: false,
             card_context_rows: 0,
             command_history: Vec::new(),
             search_history: Vec::new(),
@@ -295,14 +576,22 @@ impl App {
             explorer_current_dir: std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
             explorer_has_focus: true, // Explorer has focus when opened
             explorer_dir_changed: false,
+            explorer_filter: rc_config.explorer_filter,
+            explorer_show_all: false,
+            explorer_area: Rect::default(),
             file_op_pending: None,
             file_op_prompt_buffer: String::new(),
             visual_mode: false,
             visual_start_index: 0,
             visual_end_index: 0,
+            visual_inverted: false,
+            text_visual: None,
+            text_visual_anchor: (0, 0),
             view_edit_mode: false,
             colorscheme: rc_config.colorscheme,
             border_style: rc_config.border_style,
+            accessible: rc_config.accessible,
+            glyphs: rc_config.glyphs,
             outline_open: false,
             outline_selected_index: 0,
             outline_scroll: 0,
@@ -312,16 +601,78 @@ impl App {
             outline_search_query: String::new(),
             outline_search_matches: Vec::new(),
             outline_search_current: 0,
+            outline_group: OutlineGroup::default(),
+            outline_collapsed: Vec::new(),
+            outline_area: Rect::default(),
+            matches_open: false,
+            matches_selected_index: 0,
+            matches_has_focus: false,
+            snapshot_root: snapshot::default_root(),
+            snapshots_open: false,
+            snapshots: Vec::new(),
+            snapshots_selected_index: 0,
+            snapshot_diff: None,
+            snapshot_diff_scroll: 0,
+            // Set by the interactive front end, so --stdout runs and tests leave the list alone
+            recent_list_path: None,
+            recent_open: false,
+            recent_files: Vec::new(),
+            recent_selected_index: 0,
+            finder_open: false,
+            finder_query: String::new(),
+            finder_index: None,
+            finder_matches: Vec::new(),
+            finder_selected: 0,
+            palette_open: false,
+            palette_query: String::new(),
+            palette_items: Vec::new(),
+            palette_matches: Vec::new(),
+            palette_selected: 0,
             file_mode: if rc_config.default_format.as_deref() == Some("markdown") {
                 FileMode::Markdown
             } else {
                 FileMode::Json
             },
             syntax_highlighter: None,
-            markdown_highlight_cache: Vec::new(),
-        };
-
-        app
+            edit_highlight_cache: HashMap::new(),
+            edit_wrap_cache: HashMap::new(),
+            card_body_cache: HashMap::new(),
+            image_preview: rc_config.image_preview,
+            preview_request: None,
+            preview_drawn: None,
+            preview_remote: None,
+            preview_paths: HashMap::new(),
+            strict_parsing: rc_config.strict_parsing,
+            autosave: rc_config.autosave,
+            autosave_interval: rc_config.autosave_interval,
+            autosave_pending: false,
+            format_on_save: rc_config.format_on_save,
+            autoname_on_save: rc_config.autoname_on_save,
+            atomic_save: rc_config.atomic_save,
+            preserve_line_endings: rc_config.preserve_line_endings,
+            text_format: TextFormat::default(),
+            clipboard: rc_config.clipboard,
+            paste_source: rc_config.paste_source,
+            checkbox_percentage: rc_config.checkbox_percentage,
+            show_dropped: rc_config.show_dropped,
+            confirm_delete: rc_config.confirm_delete,
+            confirm_clear: rc_config.confirm_clear,
+            confirm_paste: rc_config.confirm_paste,
+            paste_limit: rc_config.paste_limit,
+            skeleton_md: rc_config.skeleton_md,
+            skeleton_json: rc_config.skeleton_json,
+            confirm_pending: None,
+            due_soon_days: rc_config.due_soon_days,
+            overdue_count: 0,
+            url_rules: rc_config.url_rules,
+            default_section: rc_config.default_section,
+            inside_order: rc_config.inside_order,
+            lang: rc_config.lang,
+            hooks: rc_config.hooks,
+            sync: rc_config.sync,
+            sync_root: crate::sync::default_root(),
+            templates: rc_config.templates,
+        }
     }
 
     // --- Edit mode wrap helpers ---
@@ -392,6 +743,10 @@ impl App {
             self.json_input.is_empty()
         };
 
+        // Counted over the whole file, whatever the filter shows
+        self.overdue_count = serde_json::from_str(&self.json_input)
+            .map_or(0, |value| due::overdue_count(&value, Local::now().date_naive()));
+
         if active_is_empty {
             self.rendered_content = vec![];
             self.relf_line_styles.clear();
@@ -408,8 +763,6 @@ impl App {
             FormatMode::Edit => {
                 // In Edit mode, always show raw content without any processing
                 self.rendered_content = if self.is_markdown_file() {
-                    // Update highlight cache for markdown
-                    self.update_markdown_highlight_cache();
                     self.render_markdown()
                 } else {
                     self.render_json()
@@ -422,7 +775,6 @@ impl App {
             FormatMode::Help => {
                 // In Help mode, don't process JSON - help content is set separately
                 // This branch should not be reached during normal operation
-                return;
             }
             FormatMode::View => {
                 // In View mode, try to parse JSON directly
@@ -443,6 +795,7 @@ impl App {
                 if !self.relf_entries.is_empty() {
                     self.set_status("");
                 } else if !self.json_input.is_empty()
+                    && !Self::has_sections(&self.json_input)
                     && (self.rendered_content.is_empty()
                         || (self.rendered_content.len() >= 2
                             && self.rendered_content[0].contains("Not valid JSON")))
@@ -450,6 +803,7 @@ impl App {
                 {
                     // Only show error if we have input content, it's not markdown, and parsing failed
                     if !self.status_message.contains("Not a JSON file") {
+                        tracing::warn!(path = ?self.file_path, "parse failed: not valid JSON");
                         self.set_status("Not a JSON file - showing as text");
                     }
                 } else {
@@ -459,8 +813,24 @@ impl App {
         }
     }
 
+    /// JSON with an `outside` or `inside` array: a note file, even with no entries yet
+    fn has_sections(json_input: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(json_input)
+            .is_ok_and(|value| ["outside", "inside"].iter().any(|key| value.get(key).is_some_and(|v| v.is_array())))
+    }
+
     fn render_relf(&self) -> RelfRenderResult {
-        Renderer::render_relf(&self.json_input, &self.filter_pattern)
+        let mut relf = Renderer::render_relf(&self.json_input, &self.filter_pattern);
+        // Dropped entries stay out of sight unless asked for
+        if !self.show_dropped && !self.filter_pattern.to_lowercase().contains("dropped") {
+            relf.entries.retain(|entry| entry.status != EntryStatus::Dropped);
+        }
+        // OUTSIDE cards always have a name, so INSIDE ones start at the first without
+        if self.inside_order == InsideOrder::NewestFirst {
+            let start = relf.entries.iter().position(|entry| entry.name.is_none()).unwrap_or(relf.entries.len());
+            relf.entries[start..].sort_by(|a, b| b.date.cmp(&a.date).then(b.original_index.cmp(&a.original_index)));
+        }
+        relf
     }
 
     fn render_json(&self) -> Vec<String> {
@@ -471,6 +841,16 @@ impl App {
         self.markdown_input.lines().map(|line| line.to_string()).collect()
     }
 
+    /// `msg` in the interface language (see `i18n`)
+    pub fn msg(&self, msg: Msg) -> &'static str {
+        i18n::text(self.lang, msg)
+    }
+
+    /// `msg` in the interface language with its `{}` placeholders filled by `args`
+    pub fn msg_with(&self, msg: Msg, args: &[&dyn std::fmt::Display]) -> String {
+        i18n::format(self.lang, msg, args)
+    }
+
     pub fn set_status(&mut self, message: &str) {
         if message.is_empty() {
             self.status_message = String::new();
@@ -483,17 +863,18 @@ impl App {
 
     /// Check if the current file is a Markdown file
     pub fn is_markdown_file(&self) -> bool {
-        // Check file extension if file path exists
+        // A .md or .json extension decides
         if let Some(is_md) = self.file_path
             .as_ref()
             .and_then(|path| path.extension())
             .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("json"))
             .map(|ext| ext.eq_ignore_ascii_case("md"))
         {
             return is_md;
         }
 
-        // If no file path, use file_mode setting
+        // Otherwise the format detected from the content when loading (file_mode)
         self.file_mode == FileMode::Markdown
     }
 
@@ -507,15 +888,26 @@ impl App {
 
     pub fn update_status(&mut self) {
         // Clear status message after 3 seconds
-        if let Some(time) = self.status_time {
-            if time.elapsed() > Duration::from_secs(3) {
-                self.status_message = String::new();
-                self.status_time = None;
-            }
+        if let Some(time) = self.status_time
+            && time.elapsed() > Duration::from_secs(3) {
+            self.status_message = String::new();
+            self.status_time = None;
+        }
+        // A skipped auto-reload stays on screen until it is dealt with
+        if self.status_message.is_empty() && self.disk_changed {
+            self.status_message = file::DISK_CHANGED.to_string();
         }
     }
 
 
+    /// JSON path under the Edit-mode cursor (`outside[3].context`); None for Markdown
+    pub fn cursor_json_path(&self) -> Option<String> {
+        if self.is_markdown_file() {
+            return None;
+        }
+        json_path::path_at(&self.json_input, self.content_cursor_line, self.content_cursor_col)
+    }
+
     pub fn get_content_lines(&self) -> Vec<String> {
         let content = if self.is_markdown_file() && !self.markdown_input.is_empty() {
             &self.markdown_input
@@ -593,8 +985,8 @@ impl App {
         let mut visual_row = 0u16;
 
         // Sum up all visual lines before the cursor line
-        for i in 0..self.content_cursor_line.min(lines.len()) {
-            visual_row += self.calculate_visual_lines(&lines[i]);
+        for line in lines.iter().take(self.content_cursor_line) {
+            visual_row += self.calculate_visual_lines(line);
         }
 
         // Now handle the cursor line itself
@@ -628,8 +1020,35 @@ impl App {
         }
     }
 
+    /// Which key bindings apply right now, for the cheat sheet
+    pub fn key_context(&self) -> KeyContext {
+        if self.editing_entry {
+            if self.edit_field_editing_mode {
+                KeyContext::OverlayField
+            } else {
+                KeyContext::Overlay
+            }
+        } else if self.format_mode == FormatMode::Edit {
+            if self.text_visual.is_some() {
+                KeyContext::Selection
+            } else {
+                KeyContext::Edit
+            }
+        } else if self.visual_mode {
+            KeyContext::Visual
+        } else if self.focus_mode {
+            KeyContext::Focus
+        } else {
+            KeyContext::View
+        }
+    }
+
+    pub fn toggle_cheatsheet(&mut self) {
+        self.cheatsheet_open = !self.cheatsheet_open;
+    }
+
     pub fn show_help(&mut self) {
-        self.rendered_content = help::get_help_content();
+        self.rendered_content = help::get_help_content(self.lang);
         self.relf_line_styles.clear();
         self.relf_visual_styles.clear();
         self.relf_entries.clear();
@@ -645,7 +1064,6 @@ impl App {
         self.content_cursor_col = 0;
         self.scroll = 0;
         self.view_edit_mode = false;
-        self.markdown_highlight_cache.clear();
         self.is_modified = true;
         self.convert_json();
         self.set_status("Content cleared");
@@ -658,11 +1076,11 @@ impl App {
         }
 
         // Re-render with filter applied
+        self.filter_pattern = pattern;
         self.convert_json();
 
         let filtered_count = self.relf_entries.len();
-        self.set_status(&format!("Filter: {} ({} entries)", pattern, filtered_count));
-        self.filter_pattern = pattern;
+        self.set_status(&self.msg_with(Msg::FilterCount, &[&self.filter_pattern, &filtered_count]));
     }
 
     pub fn clear_filter(&mut self) {
@@ -673,28 +1091,4 @@ impl App {
         }
     }
 
-    /// Update markdown highlight cache (for Edit mode)
-    pub fn update_markdown_highlight_cache(&mut self) {
-        if !self.is_markdown_file() {
-            return;
-        }
-
-        // Ensure syntax highlighter is initialized
-        if self.syntax_highlighter.is_none() {
-            self.syntax_highlighter = Some(SyntaxHighlighter::new(self.colorscheme.clone()));
-        }
-
-        let lines: Vec<String> = if self.is_markdown_file() {
-            self.markdown_input.lines().map(|s| s.to_string()).collect()
-        } else {
-            self.json_input.lines().map(|s| s.to_string()).collect()
-        };
-
-        self.markdown_highlight_cache = highlight_markdown_with_code_blocks(
-            &lines,
-            &self.colorscheme,
-            self.syntax_highlighter.as_ref(),
-        );
-    }
-
 }
@@ -0,0 +1,127 @@
+use super::{App, FormatMode};
+use crate::i18n::Msg;
+use crate::paths;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+impl App {
+    /// Resolve an attachment path: `~` and `$VAR` expand, relative paths are relative to the open file
+    pub fn resolve_attachment(&self, path: &str) -> PathBuf {
+        let expanded = paths::expand(path);
+        if expanded.is_relative()
+            && let Some(dir) = self.file_path.as_ref().and_then(|p| p.parent()) {
+            return dir.join(expanded);
+        }
+        expanded
+    }
+
+    /// Attachment paths from `attachments` whose files don't exist
+    pub fn missing_attachments(&self, attachments: &[String]) -> Vec<String> {
+        attachments
+            .iter()
+            .filter(|path| !self.resolve_attachment(path).exists())
+            .cloned()
+            .collect()
+    }
+
+    /// Add a file path to the selected card's attachments (:attach <path>)
+    pub fn attach_to_selected(&mut self, path: &str) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let path = path.trim();
+        if path.is_empty() {
+            self.set_status("Usage: :attach <path>");
+            return;
+        }
+        let Some(original_index) = self.selected_original_index() else {
+            self.set_status(self.msg(Msg::NoEntrySelected));
+            return;
+        };
+
+        let already_attached = self
+            .relf_entries
+            .get(self.selected_entry_index)
+            .is_some_and(|entry| entry.attachments.iter().any(|a| a == path));
+        if already_attached {
+            self.set_status(&format!("Already attached: {}", path));
+            return;
+        }
+
+        let result = self.modify_entry(original_index, |entry, _| {
+            let list = entry
+                .entry("attachments".to_string())
+                .or_insert_with(|| Value::Array(vec![]));
+            if let Some(arr) = list.as_array_mut() {
+                arr.push(Value::String(path.to_string()));
+            }
+        });
+
+        match result {
+            Ok(()) => {
+                self.autosave();
+                if self.resolve_attachment(path).exists() {
+                    self.set_status(&format!("Attached: {}", path));
+                } else {
+                    self.set_status(&format!("Attached (file not found): {}", path));
+                }
+            }
+            Err(e) => self.set_status(&e),
+        }
+    }
+
+    /// Open the selected card's attachments with the system opener (ga)
+    pub fn open_selected_attachments(&mut self) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let attachments = self
+            .relf_entries
+            .get(self.selected_entry_index)
+            .map(|entry| entry.attachments.clone())
+            .unwrap_or_default();
+        if attachments.is_empty() {
+            self.set_status("No attachments on selected entry");
+            return;
+        }
+
+        let missing = self.missing_attachments(&attachments);
+        let mut opened = 0;
+        for path in attachments.iter().filter(|p| !missing.contains(p)) {
+            if let Err(e) = open_with_system(&self.resolve_attachment(path)) {
+                self.set_status(&format!("Failed to open {}: {}", path, e));
+                return;
+            }
+            opened += 1;
+        }
+
+        if missing.is_empty() {
+            self.set_status(&format!("Opened {} attachment(s)", opened));
+        } else {
+            self.set_status(&format!("Opened {} attachment(s), missing: {}", opened, missing.join(", ")));
+        }
+    }
+}
+
+/// Launch the platform's default application for a file
+pub(super) fn open_with_system(path: &std::path::Path) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut c = Command::new("cmd");
+        c.args(["/C", "start", ""]);
+        c
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
@@ -0,0 +1,90 @@
+use super::App;
+use super::file::{clean_path, parse_file_content};
+use crate::task::{Apply, BackgroundTask, Progress};
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Files larger than this load on a worker thread when opened from the UI
+const BACKGROUND_LOAD_BYTES: u64 = 1 << 20;
+
+const READ_CHUNK: usize = 64 * 1024;
+
+impl App {
+    /// Open a file from the UI: large files load in the background, others right away
+    pub fn open_file(&mut self, path: PathBuf) {
+        let path = clean_path(&path);
+        match fs::metadata(&path) {
+            Ok(metadata) if metadata.len() > BACKGROUND_LOAD_BYTES => self.load_file_in_background(path),
+            _ => self.load_file(path),
+        }
+    }
+
+    /// Read and parse `path` on a worker thread, showing progress in the status bar
+    pub fn load_file_in_background(&mut self, path: PathBuf) {
+        if self.background_task.is_some() {
+            self.set_status("Another operation is still running");
+            return;
+        }
+        self.flush_autosave(true);
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        let task = BackgroundTask::spawn(&format!("Loading {}", name), move |progress| {
+            let content = read_with_progress(&path, progress)?;
+            progress.stage(&format!("Parsing {}", name));
+            let parsed = parse_file_content(&path, &content);
+            let apply: Apply = Box::new(move |app: &mut App| app.apply_loaded_file(path, content, parsed));
+            Ok(apply)
+        });
+        self.background_task = Some(task);
+    }
+
+    /// Take in the running task's progress and apply its result once it is done.
+    /// Called by the event loop before every frame.
+    pub fn poll_background_task(&mut self) {
+        let Some(task) = self.background_task.as_mut() else {
+            return;
+        };
+        let Some(result) = task.poll() else {
+            return;
+        };
+        self.background_task = None;
+        match result {
+            Ok(apply) => apply(self),
+            Err(e) => self.set_status(&e),
+        }
+    }
+
+    /// Esc while a task runs: drop it and let the worker stop
+    pub fn cancel_background_task(&mut self) {
+        if let Some(task) = self.background_task.take() {
+            task.cancel();
+            self.set_status(&format!("Cancelled: {}", task.label));
+        }
+    }
+}
+
+/// Read `path` in chunks, reporting bytes read and stopping when the task is cancelled
+fn read_with_progress(path: &Path, progress: &Progress) -> Result<String, String> {
+    let error = |e: std::io::Error| format!("Error reading {}: {}", path.display(), e);
+    let mut file = File::open(path).map_err(error)?;
+    let total = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let mut bytes = Vec::with_capacity(total as usize);
+    let mut chunk = vec![0; READ_CHUNK];
+    loop {
+        if progress.is_cancelled() {
+            return Err("Cancelled".to_string());
+        }
+        let read = file.read(&mut chunk).map_err(error)?;
+        if read == 0 {
+            break;
+        }
+        bytes.extend_from_slice(&chunk[..read]);
+        progress.update(bytes.len() as u64, total.max(bytes.len() as u64));
+    }
+    String::from_utf8(bytes).map_err(|_| format!("Error reading {}: not valid UTF-8", path.display()))
+}
@@ -0,0 +1,323 @@
+use super::{App, FormatMode};
+use crate::i18n::Msg;
+use crate::json_ops::JsonOperations;
+use crate::priority;
+use crate::status::{self, EntryStatus};
+use serde_json::{Map, Value};
+
+impl App {
+    /// Apply `f` to every card in the Visual selection (or the selected card) as one undo step.
+    /// The selection is kept so further bulk commands can follow.
+    fn modify_selected_entries<F>(&mut self, what: &str, f: F)
+    where
+        F: FnMut(&mut Map<String, Value>, &str) -> bool,
+    {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let original_indices: Vec<usize> = self
+            .visual_selected_indices()
+            .into_iter()
+            .filter_map(|idx| self.relf_entries.get(idx).map(|entry| entry.original_index))
+            .collect();
+        let selected = original_indices.len();
+
+        let (formatted, changed) = match JsonOperations::modify_entries(&self.json_input, &original_indices, f) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        if changed == 0 {
+            self.set_status(&format!("{}: nothing to change in {} card{}", what, selected, if selected == 1 { "" } else { "s" }));
+            return;
+        }
+
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        self.autosave();
+        self.set_status(&format!("{}: {} of {} card{}", what, changed, selected, if selected == 1 { "" } else { "s" }));
+    }
+
+    /// :set pct N - set the percentage of the selected OUTSIDE cards
+    pub fn set_selected_percentage(&mut self, text: &str) {
+        let Some(percentage) = text.trim().parse::<i64>().ok().filter(|n| (0..=100).contains(n)) else {
+            self.set_status("Percentage must be a number from 0 to 100");
+            return;
+        };
+        self.modify_selected_entries(&format!("Set {}%", percentage), |entry, section| {
+            if section != "outside" || entry.get("percentage") == Some(&Value::from(percentage)) {
+                return false;
+            }
+            entry.insert("percentage".to_string(), Value::from(percentage));
+            true
+        });
+    }
+
+    /// :priority LEVEL - set (or with `none`, remove) the priority of the selected OUTSIDE cards
+    pub fn set_selected_priority(&mut self, text: &str) {
+        let text = text.trim();
+        let value = if text == "none" {
+            None
+        } else {
+            match priority::value_from_text(text) {
+                Some(value) => Some(value),
+                None => {
+                    self.set_status("Priority must be high, medium, low, 1-5 or none");
+                    return;
+                }
+            }
+        };
+        let what = match &value {
+            Some(_) => format!("Priority {}", text.to_ascii_lowercase()),
+            None => "Priority cleared".to_string(),
+        };
+        self.modify_selected_entries(&what, |entry, section| {
+            if section != "outside" || entry.get("priority") == value.as_ref() {
+                return false;
+            }
+            match &value {
+                Some(value) => entry.insert("priority".to_string(), value.clone()),
+                None => entry.remove("priority"),
+            };
+            true
+        });
+    }
+
+    /// :status active|done|dropped - set the status of the selected OUTSIDE cards
+    pub fn set_selected_status(&mut self, text: &str) {
+        let Some(status) = EntryStatus::by_name(text) else {
+            self.set_status("Status must be active, done or dropped");
+            return;
+        };
+        self.apply_selected_status(status);
+    }
+
+    /// :done - mark the selected OUTSIDE cards done, or active again when all of them are done
+    pub fn toggle_selected_done(&mut self) {
+        let statuses: Vec<EntryStatus> = self
+            .visual_selected_indices()
+            .into_iter()
+            .filter_map(|idx| self.relf_entries.get(idx))
+            .filter(|entry| entry.name.is_some())
+            .map(|entry| entry.status)
+            .collect();
+        let all_done = !statuses.is_empty() && statuses.iter().all(|&status| status == EntryStatus::Done);
+        self.apply_selected_status(if all_done { EntryStatus::Active } else { EntryStatus::Done });
+    }
+
+    /// Active is the default, so it removes the `status` key instead of storing it
+    fn apply_selected_status(&mut self, status: EntryStatus) {
+        let value = (status != EntryStatus::Active).then(|| Value::String(status.name().to_string()));
+        let what = format!("Status {}", status.name());
+        self.modify_selected_entries(&what, |entry, section| {
+            if section != "outside" || entry.get(status::KEY) == value.as_ref() {
+                return false;
+            }
+            match &value {
+                Some(value) => entry.insert(status::KEY.to_string(), value.clone()),
+                None => entry.remove(status::KEY),
+            };
+            true
+        });
+    }
+
+    /// :tag add NAME / :tag remove NAME - edit the `tags` list of the selected cards
+    pub fn tag_selected(&mut self, args: &str) {
+        let (add, tag) = match args.trim().split_once(char::is_whitespace) {
+            Some(("add", tag)) => (true, tag.trim()),
+            Some(("remove" | "rm", tag)) => (false, tag.trim()),
+            _ => {
+                self.set_status("Usage: :tag add NAME or :tag remove NAME");
+                return;
+            }
+        };
+        let what = format!("Tag '{}' {}", tag, if add { "added" } else { "removed" });
+        let tag = Value::String(tag.to_string());
+        self.modify_selected_entries(&what, |entry, _| {
+            let mut tags: Vec<Value> = entry
+                .get("tags")
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default();
+            let present = tags.contains(&tag);
+            if add == present {
+                return false;
+            }
+            if add {
+                tags.push(tag.clone());
+            } else {
+                tags.retain(|t| *t != tag);
+            }
+            if tags.is_empty() {
+                entry.remove("tags");
+            } else {
+                entry.insert("tags".to_string(), Value::Array(tags));
+            }
+            true
+        });
+    }
+
+    /// Rewrite `field` across all entries with `f`, then ask before applying the result.
+    /// `what` describes the change in the prompt and the status afterwards.
+    fn prompt_bulk_update<F>(&mut self, what: &str, field: &str, f: F)
+    where
+        F: FnMut(&Value) -> Option<Value>,
+    {
+        let (formatted, changed) = match JsonOperations::transform_field(&self.json_input, field, f) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        if changed == 0 {
+            self.set_status(&format!("{}: no entries would change", what));
+            return;
+        }
+        let entries = format!("{} entr{}", changed, if changed == 1 { "y" } else { "ies" });
+        self.set_status(&format!("{}: {} will change. Apply? (y/n)", what, entries));
+        self.bulk_update_pending = Some((formatted, format!("{}: {} changed", what, entries)));
+    }
+
+    /// Answer the bulk update prompt: y applies the change as one undo step, n drops it
+    pub fn handle_bulk_update_confirmation(&mut self, answer: char) {
+        let Some((formatted, done)) = self.bulk_update_pending.take() else {
+            return;
+        };
+        if answer != 'y' {
+            self.set_status("Bulk update cancelled");
+            return;
+        }
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        self.autosave();
+        self.set_status(&done);
+    }
+
+    /// :autoname - name every unnamed OUTSIDE entry after the first line of its context,
+    /// asking before applying with a summary of the names
+    pub fn autoname_entries(&mut self) {
+        let (formatted, names) = match JsonOperations::autoname(&self.json_input) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        if names.is_empty() {
+            self.set_status("Autoname: no unnamed entries with a context");
+            return;
+        }
+        let entries = format!("{} entr{}", names.len(), if names.len() == 1 { "y" } else { "ies" });
+        self.set_status(&format!("Autoname: {} ({}). Apply? (y/n)", entries, name_summary(&names)));
+        self.bulk_update_pending = Some((formatted, format!("Autoname: named {}", entries)));
+    }
+
+    /// Give unnamed entries a name before a save (`autoname_on_save`), as one undo step
+    pub(super) fn autoname_before_save(&mut self) {
+        let Ok((formatted, names)) = JsonOperations::autoname(&self.json_input) else {
+            return;
+        };
+        if names.is_empty() {
+            return;
+        }
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.sync_markdown_from_json();
+        self.convert_json();
+    }
+
+    /// :rename-tag OLD NEW - rename a tag in every entry's `tags` list
+    pub fn rename_tag(&mut self, args: &str) {
+        let Some((old, new)) = two_args(args) else {
+            self.set_status("Usage: :rename-tag OLD NEW");
+            return;
+        };
+        let what = format!("Rename tag '{}' to '{}'", old, new);
+        let (old, new) = (Value::String(old.to_string()), Value::String(new.to_string()));
+        self.prompt_bulk_update(&what, "tags", |tags| {
+            let tags = tags.as_array()?;
+            if !tags.contains(&old) {
+                return None;
+            }
+            // An entry that already has the new tag keeps only one copy
+            let mut renamed: Vec<Value> = Vec::with_capacity(tags.len());
+            for tag in tags {
+                let tag = if *tag == old { new.clone() } else { tag.clone() };
+                if !renamed.contains(&tag) {
+                    renamed.push(tag);
+                }
+            }
+            Some(Value::Array(renamed))
+        });
+    }
+
+    /// :set-url-domain OLD NEW - move every URL on host OLD (or a subdomain of it) to NEW
+    pub fn set_url_domain(&mut self, args: &str) {
+        let Some((old, new)) = two_args(args) else {
+            self.set_status("Usage: :set-url-domain OLD.COM NEW.COM");
+            return;
+        };
+        let what = format!("Change URL domain {} to {}", old, new);
+        self.prompt_bulk_update(&what, "url", |url| {
+            replace_url_domain(url.as_str()?, old, new).map(Value::String)
+        });
+    }
+}
+
+/// "'A', 'B', 'C' and 2 more"
+fn name_summary(names: &[String]) -> String {
+    const SHOWN: usize = 3;
+    let quoted: Vec<String> = names.iter().take(SHOWN).map(|name| format!("'{}'", name)).collect();
+    match names.len().checked_sub(SHOWN) {
+        Some(more) if more > 0 => format!("{} and {} more", quoted.join(", "), more),
+        _ => quoted.join(", "),
+    }
+}
+
+fn two_args(args: &str) -> Option<(&str, &str)> {
+    let mut parts = args.split_whitespace();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(first), Some(second), None) => Some((first, second)),
+        _ => None,
+    }
+}
+
+/// `url` with its host `old` (or `sub.old`) swapped for `new` (or `sub.new`); `None` when it is on another host
+fn replace_url_domain(url: &str, old: &str, new: &str) -> Option<String> {
+    let authority_start = url.find("://").map_or(0, |i| i + 3);
+    let authority_end = url[authority_start..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| authority_start + i);
+    // The host follows any `user:pw@` and ends at the port
+    let host_start = url[authority_start..authority_end]
+        .rfind('@')
+        .map_or(authority_start, |i| authority_start + i + 1);
+    let host_end = url[host_start..authority_end]
+        .find(':')
+        .map_or(authority_end, |i| host_start + i);
+    let host = &url[host_start..host_end];
+    let subdomain_len = if host.eq_ignore_ascii_case(old) {
+        0
+    } else {
+        let sub = host
+            .len()
+            .checked_sub(old.len() + 1)
+            .filter(|&sub| host.is_char_boundary(sub))?;
+        let suffix = &host[sub..];
+        if !suffix.starts_with('.') || !suffix[1..].eq_ignore_ascii_case(old) {
+            return None;
+        }
+        sub + 1
+    };
+    Some(format!("{}{}{}", &url[..host_start + subdomain_len], new, &url[host_end..]))
+}
@@ -0,0 +1,88 @@
+use super::super::App;
+use crate::base64;
+use crate::config::{ClipboardBackend, PasteSource};
+use arboard::Clipboard;
+use std::io::Write;
+
+impl App {
+    /// Put text on the clipboard with the configured backend
+    pub(crate) fn clipboard_set_text(&self, text: String) -> Result<(), String> {
+        match self.clipboard {
+            ClipboardBackend::System => system_set_text(text),
+            ClipboardBackend::Osc52 => osc52_set_text(&text),
+            ClipboardBackend::Auto => {
+                if is_remote_session() {
+                    osc52_set_text(&text)
+                } else {
+                    // No usable system clipboard (e.g. headless): let the terminal take it
+                    system_set_text(text.clone()).or_else(|_| osc52_set_text(&text))
+                }
+            }
+        }
+    }
+
+    /// Read text from the clipboard, or from the primary selection when `paste_source` says
+    /// so. OSC 52 cannot be read back reliably, so this always asks the system; over SSH use
+    /// the terminal's own paste instead.
+    pub(crate) fn clipboard_get_text(&self) -> Result<String, String> {
+        if self.safe_mode {
+            return Err("Safe mode: clipboard reads are off (paste with the terminal)".to_string());
+        }
+        if self.paste_source == PasteSource::Primary {
+            return primary_get_text();
+        }
+        Clipboard::new()
+            .and_then(|mut clipboard| clipboard.get_text())
+            .map_err(|e| match self.clipboard {
+                ClipboardBackend::System => e.to_string(),
+                _ => format!("{} (OSC 52 is write-only, paste with the terminal)", e),
+            })
+    }
+}
+
+fn system_set_text(text: String) -> Result<(), String> {
+    Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Text of the primary selection (X11, or Wayland through XWayland)
+#[cfg(target_os = "linux")]
+fn primary_get_text() -> Result<String, String> {
+    use arboard::{GetExtLinux, LinuxClipboardKind};
+    Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get().clipboard(LinuxClipboardKind::Primary).text())
+        .map_err(|e| format!("Primary selection: {}", e))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn primary_get_text() -> Result<String, String> {
+    Err("The primary selection is only available on Linux".to_string())
+}
+
+fn osc52_set_text(text: &str) -> Result<(), String> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(osc52_sequence(text, in_tmux).as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|e| e.to_string())
+}
+
+/// SSH session with no display to reach a system clipboard through
+fn is_remote_session() -> bool {
+    let ssh = std::env::var_os("SSH_CONNECTION").is_some() || std::env::var_os("SSH_TTY").is_some();
+    let display = std::env::var_os("DISPLAY").is_some() || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    ssh && !display
+}
+
+/// OSC 52 "set clipboard" escape sequence for `text`; inside tmux it is wrapped in a
+/// DCS passthrough so tmux forwards it to the outer terminal
+pub fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", base64::encode(text.as_bytes()));
+    if in_tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
@@ -1,90 +1,82 @@
 use super::super::super::{App, FormatMode};
-use arboard::Clipboard;
 use serde_json::Value;
+use crate::i18n::Msg;
 
 impl App {
     /// Copy all content to clipboard (both OUTSIDE and INSIDE sections)
     pub fn copy_to_clipboard(&mut self) {
         // In View mode with cards, copy all entries with OUTSIDE/INSIDE sections
-        if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
-            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    let outside_count = obj
-                        .get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-
-                    let mut all_content = Vec::new();
-
-                    // Add OUTSIDE section
-                    if outside_count > 0 {
-                        all_content.push("OUTSIDE".to_string());
-                        all_content.push(String::new());
-
-                        for (i, entry) in self.relf_entries.iter().enumerate() {
-                            if i < outside_count {
-                                if i > 0 {
-                                    all_content.push(String::new());
-                                }
-                                for line in &entry.lines {
-                                    all_content.push(line.clone());
-                                }
-                            }
+        if self.format_mode == FormatMode::View && !self.relf_entries.is_empty()
+            && let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object() {
+            let outside_count = obj
+                .get("outside")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.len())
+                .unwrap_or(0);
+
+            let mut all_content = Vec::new();
+
+            // Add OUTSIDE section
+            if outside_count > 0 {
+                all_content.push("OUTSIDE".to_string());
+                all_content.push(String::new());
+
+                for (i, entry) in self.relf_entries.iter().enumerate() {
+                    if i < outside_count {
+                        if i > 0 {
+                            all_content.push(String::new());
                         }
-
-                        all_content.push(String::new());
-                    }
-
-                    // Add INSIDE section
-                    let inside_count = self.relf_entries.len() - outside_count;
-                    if inside_count > 0 {
-                        all_content.push("INSIDE".to_string());
-                        all_content.push(String::new());
-
-                        for (i, entry) in self.relf_entries.iter().enumerate() {
-                            if i >= outside_count {
-                                if i > outside_count {
-                                    all_content.push(String::new());
-                                }
-                                for line in &entry.lines {
-                                    all_content.push(line.clone());
-                                }
-                            }
+                        for line in &entry.lines {
+                            all_content.push(line.clone());
                         }
                     }
+                }
 
-                    if all_content.is_empty() {
-                        self.set_status("Nothing to copy");
-                        return;
-                    }
+                all_content.push(String::new());
+            }
+
+            // Add INSIDE section
+            let inside_count = self.relf_entries.len() - outside_count;
+            if inside_count > 0 {
+                all_content.push("INSIDE".to_string());
+                all_content.push(String::new());
 
-                    let content = all_content.join("\n");
-                    match Clipboard::new() {
-                        Ok(mut clipboard) => match clipboard.set_text(content) {
-                            Ok(()) => self.set_status("Copied to clipboard"),
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                        },
-                        Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+                for (i, entry) in self.relf_entries.iter().enumerate() {
+                    if i >= outside_count {
+                        if i > outside_count {
+                            all_content.push(String::new());
+                        }
+                        for line in &entry.lines {
+                            all_content.push(line.clone());
+                        }
                     }
-                    return;
                 }
             }
+
+            if all_content.is_empty() {
+                self.set_status(self.msg(Msg::NothingToCopy));
+                return;
+            }
+
+            let content = all_content.join("\n");
+            match self.clipboard_set_text(content) {
+                Ok(()) => self.set_status(self.msg(Msg::Copied)),
+                Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+            }
+            return;
         }
 
         // Fallback to rendered_content
         if self.rendered_content.is_empty() {
-            self.set_status("Nothing to copy");
+            self.set_status(self.msg(Msg::NothingToCopy));
             return;
         }
 
         let content = self.rendered_content.join("\n");
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.set_text(content) {
-                Ok(()) => self.set_status("Copied to clipboard"),
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+        match self.clipboard_set_text(content) {
+            Ok(()) => self.set_status(self.msg(Msg::Copied)),
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
         }
     }
 
@@ -92,46 +84,42 @@ impl App {
     pub fn copy_inside_data(&mut self) {
         // In view mode, copy all INSIDE entries from relf_entries
         if self.format_mode == FormatMode::View {
-            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    let outside_count = obj
-                        .get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-
-                    // Collect INSIDE entries (indices >= outside_count)
-                    let mut inside_content = Vec::new();
-                    inside_content.push("INSIDE".to_string());
-                    inside_content.push(String::new());
-
-                    for (i, entry) in self.relf_entries.iter().enumerate() {
-                        if i >= outside_count {
-                            // Add blank line between entries (but not before first entry)
-                            if i > outside_count {
-                                inside_content.push(String::new());
-                            }
-                            for line in &entry.lines {
-                                inside_content.push(line.clone());
-                            }
+            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object() {
+                let outside_count = obj
+                    .get("outside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
+
+                // Collect INSIDE entries (indices >= outside_count)
+                let mut inside_content = Vec::new();
+                inside_content.push("INSIDE".to_string());
+                inside_content.push(String::new());
+
+                for (i, entry) in self.relf_entries.iter().enumerate() {
+                    if i >= outside_count {
+                        // Add blank line between entries (but not before first entry)
+                        if i > outside_count {
+                            inside_content.push(String::new());
+                        }
+                        for line in &entry.lines {
+                            inside_content.push(line.clone());
                         }
                     }
+                }
 
-                    if inside_content.is_empty() {
-                        self.set_status("No INSIDE entries found");
-                        return;
-                    }
-
-                    let content = inside_content.join("\n");
-                    match Clipboard::new() {
-                        Ok(mut clipboard) => match clipboard.set_text(content) {
-                            Ok(()) => self.set_status("Copied INSIDE section to clipboard"),
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                        },
-                        Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                    }
+                if inside_content.is_empty() {
+                    self.set_status("No INSIDE entries found");
                     return;
                 }
+
+                let content = inside_content.join("\n");
+                match self.clipboard_set_text(content) {
+                    Ok(()) => self.set_status("Copied INSIDE section to clipboard"),
+                    Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+                }
+                return;
             }
             self.set_status("Failed to parse JSON");
             return;
@@ -148,12 +136,9 @@ impl App {
                         let wrapper_value = Value::Object(wrapper);
 
                         match serde_json::to_string_pretty(&wrapper_value) {
-                            Ok(formatted) => match Clipboard::new() {
-                                Ok(mut clipboard) => match clipboard.set_text(formatted) {
-                                    Ok(()) => self.set_status("Copied inside data to clipboard"),
-                                    Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                                },
-                                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+                            Ok(formatted) => match self.clipboard_set_text(formatted) {
+                                Ok(()) => self.set_status("Copied inside data to clipboard"),
+                                Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                             },
                             Err(e) => {
                                 self.set_status(&format!("Error formatting inside data: {}", e))
@@ -166,7 +151,7 @@ impl App {
                     self.set_status("JSON is not an object");
                 }
             }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::InvalidJson, &[&e])),
         }
     }
 
@@ -174,46 +159,42 @@ impl App {
     pub fn copy_outside_data(&mut self) {
         // In view mode, copy all OUTSIDE entries from relf_entries
         if self.format_mode == FormatMode::View {
-            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    let outside_count = obj
-                        .get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-
-                    // Collect OUTSIDE entries (indices < outside_count)
-                    let mut outside_content = Vec::new();
-                    outside_content.push("OUTSIDE".to_string());
-                    outside_content.push(String::new());
-
-                    for (i, entry) in self.relf_entries.iter().enumerate() {
-                        if i < outside_count {
-                            // Add blank line between entries (but not before first entry)
-                            if i > 0 {
-                                outside_content.push(String::new());
-                            }
-                            for line in &entry.lines {
-                                outside_content.push(line.clone());
-                            }
+            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object() {
+                let outside_count = obj
+                    .get("outside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
+
+                // Collect OUTSIDE entries (indices < outside_count)
+                let mut outside_content = Vec::new();
+                outside_content.push("OUTSIDE".to_string());
+                outside_content.push(String::new());
+
+                for (i, entry) in self.relf_entries.iter().enumerate() {
+                    if i < outside_count {
+                        // Add blank line between entries (but not before first entry)
+                        if i > 0 {
+                            outside_content.push(String::new());
+                        }
+                        for line in &entry.lines {
+                            outside_content.push(line.clone());
                         }
                     }
+                }
 
-                    if outside_content.is_empty() {
-                        self.set_status("No OUTSIDE entries found");
-                        return;
-                    }
-
-                    let content = outside_content.join("\n");
-                    match Clipboard::new() {
-                        Ok(mut clipboard) => match clipboard.set_text(content) {
-                            Ok(()) => self.set_status("Copied OUTSIDE section to clipboard"),
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                        },
-                        Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                    }
+                if outside_content.is_empty() {
+                    self.set_status("No OUTSIDE entries found");
                     return;
                 }
+
+                let content = outside_content.join("\n");
+                match self.clipboard_set_text(content) {
+                    Ok(()) => self.set_status("Copied OUTSIDE section to clipboard"),
+                    Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+                }
+                return;
             }
             self.set_status("Failed to parse JSON");
             return;
@@ -230,12 +211,9 @@ impl App {
                         let wrapper_value = Value::Object(wrapper);
 
                         match serde_json::to_string_pretty(&wrapper_value) {
-                            Ok(formatted) => match Clipboard::new() {
-                                Ok(mut clipboard) => match clipboard.set_text(formatted) {
-                                    Ok(()) => self.set_status("Copied outside data to clipboard"),
-                                    Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                                },
-                                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+                            Ok(formatted) => match self.clipboard_set_text(formatted) {
+                                Ok(()) => self.set_status("Copied outside data to clipboard"),
+                                Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                             },
                             Err(e) => {
                                 self.set_status(&format!("Error formatting outside data: {}", e))
@@ -248,7 +226,7 @@ impl App {
                     self.set_status("JSON is not an object");
                 }
             }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::InvalidJson, &[&e])),
         }
     }
 }
@@ -1,6 +1,6 @@
 use super::super::super::{App, FormatMode};
-use arboard::Clipboard;
 use serde_json::Value;
+use crate::i18n::Msg;
 
 impl App {
     /// Copy selected card(s) with rendering
@@ -9,7 +9,7 @@ impl App {
         // In Visual mode: copy selected range and exit Visual mode
         // In View mode (non-Visual): copy current card only
         if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
-            self.set_status("Not in card view mode");
+            self.set_status(self.msg(Msg::NotInCardView));
             return;
         }
 
@@ -25,20 +25,13 @@ impl App {
             0
         };
 
-        let (start_idx, end_idx) = if self.visual_mode {
-            let start = self.visual_start_index.min(self.visual_end_index);
-            let end = self.visual_start_index.max(self.visual_end_index);
-            (start, end)
-        } else {
-            // Single card mode
-            (self.selected_entry_index, self.selected_entry_index)
-        };
+        let selected_indices = self.visual_selected_indices();
 
         // Separate OUTSIDE and INSIDE entries
         let mut outside_lines = Vec::new();
         let mut inside_lines = Vec::new();
 
-        for idx in start_idx..=end_idx {
+        for idx in selected_indices.iter().copied() {
             if idx >= self.relf_entries.len() {
                 break;
             }
@@ -88,19 +81,16 @@ impl App {
         }
 
         let content = content_lines.join("\n");
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.set_text(content) {
-                Ok(()) => {
-                    let count = end_idx - start_idx + 1;
-                    self.set_status(&format!("Copied {} card(s)", count));
-                    // Exit Visual mode after copy
-                    if self.visual_mode {
-                        self.visual_mode = false;
-                    }
+        match self.clipboard_set_text(content) {
+            Ok(()) => {
+                let count = selected_indices.len();
+                self.set_status(&format!("Copied {} card(s)", count));
+                // Exit Visual mode after copy
+                if self.visual_mode {
+                    self.visual_mode = false;
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
         }
     }
 
@@ -110,88 +100,76 @@ impl App {
         // In Visual mode: copy selected range and exit Visual mode
         // In View mode (non-Visual): copy current card only
         if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
-            self.set_status("Not in card view mode");
+            self.set_status(self.msg(Msg::NotInCardView));
             return;
         }
 
-        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-            if let Some(obj) = json_value.as_object() {
-                let outside_count = obj
-                    .get("outside")
-                    .and_then(|v| v.as_array())
-                    .map(|arr| arr.len())
-                    .unwrap_or(0);
+        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+            && let Some(obj) = json_value.as_object() {
+            let outside_count = obj
+                .get("outside")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.len())
+                .unwrap_or(0);
 
-                let (start_idx, end_idx) = if self.visual_mode {
-                    let start = self.visual_start_index.min(self.visual_end_index);
-                    let end = self.visual_start_index.max(self.visual_end_index);
-                    (start, end)
-                } else {
-                    (self.selected_entry_index, self.selected_entry_index)
-                };
+            let selected_indices = self.visual_selected_indices();
 
-                // Collect selected entries from JSON
-                let mut selected_outside = Vec::new();
-                let mut selected_inside = Vec::new();
+            // Collect selected entries from JSON
+            let mut selected_outside = Vec::new();
+            let mut selected_inside = Vec::new();
 
-                for idx in start_idx..=end_idx {
-                    if idx >= self.relf_entries.len() {
-                        break;
-                    }
-                    let original_idx = self.relf_entries[idx].original_index;
+            for idx in selected_indices.iter().copied() {
+                if idx >= self.relf_entries.len() {
+                    break;
+                }
+                let original_idx = self.relf_entries[idx].original_index;
 
-                    if original_idx < outside_count {
-                        // Outside entry
-                        if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                            if original_idx < outside.len() {
-                                selected_outside.push(outside[original_idx].clone());
-                            }
-                        }
-                    } else {
-                        // Inside entry
-                        let inside_idx = original_idx - outside_count;
-                        if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                            if inside_idx < inside.len() {
-                                selected_inside.push(inside[inside_idx].clone());
-                            }
-                        }
+                if original_idx < outside_count {
+                    // Outside entry
+                    if let Some(outside) = obj.get("outside").and_then(|v| v.as_array())
+                        && original_idx < outside.len() {
+                        selected_outside.push(outside[original_idx].clone());
+                    }
+                } else {
+                    // Inside entry
+                    let inside_idx = original_idx - outside_count;
+                    if let Some(inside) = obj.get("inside").and_then(|v| v.as_array())
+                        && inside_idx < inside.len() {
+                        selected_inside.push(inside[inside_idx].clone());
                     }
                 }
+            }
 
-                // Build JSON object with selected entries
-                let mut result_obj = serde_json::Map::new();
-                if !selected_outside.is_empty() {
-                    result_obj.insert("outside".to_string(), Value::Array(selected_outside));
-                }
-                if !selected_inside.is_empty() {
-                    result_obj.insert("inside".to_string(), Value::Array(selected_inside));
-                }
+            // Build JSON object with selected entries
+            let mut result_obj = serde_json::Map::new();
+            if !selected_outside.is_empty() {
+                result_obj.insert("outside".to_string(), Value::Array(selected_outside));
+            }
+            if !selected_inside.is_empty() {
+                result_obj.insert("inside".to_string(), Value::Array(selected_inside));
+            }
 
-                if result_obj.is_empty() {
-                    self.set_status("No cards to copy");
-                    return;
-                }
+            if result_obj.is_empty() {
+                self.set_status("No cards to copy");
+                return;
+            }
 
-                // Convert to markdown format using helper function
-                match Self::json_to_markdown_string(&Value::Object(result_obj)) {
-                    Ok(markdown_str) => {
-                        match Clipboard::new() {
-                            Ok(mut clipboard) => match clipboard.set_text(markdown_str) {
-                                Ok(()) => {
-                                    let count = end_idx - start_idx + 1;
-                                    self.set_status(&format!("Copied {} card(s) as Markdown", count));
-                                    // Exit Visual mode after copy
-                                    if self.visual_mode {
-                                        self.visual_mode = false;
-                                    }
-                                }
-                                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                            },
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            // Convert to markdown format using helper function
+            match Self::json_to_markdown_string(&Value::Object(result_obj)) {
+                Ok(markdown_str) => {
+                    match self.clipboard_set_text(markdown_str) {
+                        Ok(()) => {
+                            let count = selected_indices.len();
+                            self.set_status(&format!("Copied {} card(s) as Markdown", count));
+                            // Exit Visual mode after copy
+                            if self.visual_mode {
+                                self.visual_mode = false;
+                            }
                         }
+                        Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                     }
-                    Err(e) => self.set_status(&format!("Markdown conversion error: {}", e)),
                 }
+                Err(e) => self.set_status(&format!("Markdown conversion error: {}", e)),
             }
         }
     }
@@ -202,87 +180,75 @@ impl App {
         // In Visual mode: copy selected range and exit Visual mode
         // In View mode (non-Visual): copy current card only
         if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
-            self.set_status("Not in card view mode");
+            self.set_status(self.msg(Msg::NotInCardView));
             return;
         }
 
-        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-            if let Some(obj) = json_value.as_object() {
-                let outside_count = obj
-                    .get("outside")
-                    .and_then(|v| v.as_array())
-                    .map(|arr| arr.len())
-                    .unwrap_or(0);
+        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+            && let Some(obj) = json_value.as_object() {
+            let outside_count = obj
+                .get("outside")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.len())
+                .unwrap_or(0);
 
-                let (start_idx, end_idx) = if self.visual_mode {
-                    let start = self.visual_start_index.min(self.visual_end_index);
-                    let end = self.visual_start_index.max(self.visual_end_index);
-                    (start, end)
-                } else {
-                    (self.selected_entry_index, self.selected_entry_index)
-                };
+            let selected_indices = self.visual_selected_indices();
 
-                // Collect selected entries from JSON
-                let mut selected_outside = Vec::new();
-                let mut selected_inside = Vec::new();
+            // Collect selected entries from JSON
+            let mut selected_outside = Vec::new();
+            let mut selected_inside = Vec::new();
 
-                for idx in start_idx..=end_idx {
-                    if idx >= self.relf_entries.len() {
-                        break;
-                    }
-                    let original_idx = self.relf_entries[idx].original_index;
+            for idx in selected_indices.iter().copied() {
+                if idx >= self.relf_entries.len() {
+                    break;
+                }
+                let original_idx = self.relf_entries[idx].original_index;
 
-                    if original_idx < outside_count {
-                        // Outside entry
-                        if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                            if original_idx < outside.len() {
-                                selected_outside.push(outside[original_idx].clone());
-                            }
-                        }
-                    } else {
-                        // Inside entry
-                        let inside_idx = original_idx - outside_count;
-                        if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                            if inside_idx < inside.len() {
-                                selected_inside.push(inside[inside_idx].clone());
-                            }
-                        }
+                if original_idx < outside_count {
+                    // Outside entry
+                    if let Some(outside) = obj.get("outside").and_then(|v| v.as_array())
+                        && original_idx < outside.len() {
+                        selected_outside.push(outside[original_idx].clone());
+                    }
+                } else {
+                    // Inside entry
+                    let inside_idx = original_idx - outside_count;
+                    if let Some(inside) = obj.get("inside").and_then(|v| v.as_array())
+                        && inside_idx < inside.len() {
+                        selected_inside.push(inside[inside_idx].clone());
                     }
                 }
+            }
 
-                // Build JSON object
-                let mut result_obj = serde_json::Map::new();
-                if !selected_outside.is_empty() {
-                    result_obj.insert("outside".to_string(), Value::Array(selected_outside));
-                }
-                if !selected_inside.is_empty() {
-                    result_obj.insert("inside".to_string(), Value::Array(selected_inside));
-                }
+            // Build JSON object
+            let mut result_obj = serde_json::Map::new();
+            if !selected_outside.is_empty() {
+                result_obj.insert("outside".to_string(), Value::Array(selected_outside));
+            }
+            if !selected_inside.is_empty() {
+                result_obj.insert("inside".to_string(), Value::Array(selected_inside));
+            }
 
-                if result_obj.is_empty() {
-                    self.set_status("No cards to copy");
-                    return;
-                }
+            if result_obj.is_empty() {
+                self.set_status("No cards to copy");
+                return;
+            }
 
-                match serde_json::to_string_pretty(&Value::Object(result_obj)) {
-                    Ok(json_str) => {
-                        match Clipboard::new() {
-                            Ok(mut clipboard) => match clipboard.set_text(json_str) {
-                                Ok(()) => {
-                                    let count = end_idx - start_idx + 1;
-                                    self.set_status(&format!("Copied {} card(s) as JSON", count));
-                                    // Exit Visual mode after copy
-                                    if self.visual_mode {
-                                        self.visual_mode = false;
-                                    }
-                                }
-                                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                            },
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            match serde_json::to_string_pretty(&Value::Object(result_obj)) {
+                Ok(json_str) => {
+                    match self.clipboard_set_text(json_str) {
+                        Ok(()) => {
+                            let count = selected_indices.len();
+                            self.set_status(&format!("Copied {} card(s) as JSON", count));
+                            // Exit Visual mode after copy
+                            if self.visual_mode {
+                                self.visual_mode = false;
+                            }
                         }
+                        Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                     }
-                    Err(e) => self.set_status(&format!("JSON error: {}", e)),
                 }
+                Err(e) => self.set_status(&format!("JSON error: {}", e)),
             }
         }
     }
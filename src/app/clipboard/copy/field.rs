@@ -0,0 +1,30 @@
+use super::super::super::{App, FormatMode};
+use crate::i18n::Msg;
+
+impl App {
+    /// Copy one field of the selected card (:yu, :yn, :y FIELD) rather than the whole card
+    pub fn copy_selected_field(&mut self, key: &str) {
+        if self.format_mode != FormatMode::View {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let Some(entry) = self.relf_entries.get(self.selected_entry_index) else {
+            self.set_status(self.msg(Msg::NoEntrySelected));
+            return;
+        };
+        let Some(value) = entry.field(key) else {
+            self.set_status(&format!("No {} on the selected card", key));
+            return;
+        };
+        // Multi-line values would flood the status bar
+        let shown = if value.contains('\n') {
+            format!("{} lines", value.lines().count())
+        } else {
+            value.clone()
+        };
+        match self.clipboard_set_text(value) {
+            Ok(()) => self.set_status(&format!("Copied {}: {}", key, shown)),
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+        }
+    }
+}
@@ -1,16 +1,13 @@
 use super::super::super::App;
-use arboard::Clipboard;
+use crate::i18n::Msg;
 
 impl App {
     /// Copy content as JSON format
     pub fn copy_json(&mut self) {
         // Copy current content as JSON (works in both Edit and View modes)
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.set_text(self.json_input.clone()) {
-                Ok(()) => self.set_status("Copied as JSON"),
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+        match self.clipboard_set_text(self.json_input.clone()) {
+            Ok(()) => self.set_status("Copied as JSON"),
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
         }
     }
 
@@ -19,12 +16,9 @@ impl App {
         // Copy current content as Markdown (works in both Edit and View modes)
         match self.convert_to_markdown() {
             Ok(markdown_content) => {
-                match Clipboard::new() {
-                    Ok(mut clipboard) => match clipboard.set_text(markdown_content) {
-                        Ok(()) => self.set_status("Copied as Markdown"),
-                        Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                    },
-                    Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+                match self.clipboard_set_text(markdown_content) {
+                    Ok(()) => self.set_status("Copied as Markdown"),
+                    Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                 }
             }
             Err(e) => self.set_status(&format!("Failed to convert to Markdown: {}", e)),
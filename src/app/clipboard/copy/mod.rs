@@ -1,4 +1,6 @@
 mod basic;
 mod cards;
+mod field;
 mod formats;
+mod template;
 mod url;
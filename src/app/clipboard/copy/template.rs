@@ -0,0 +1,65 @@
+use super::super::super::{App, FormatMode};
+use crate::fields;
+use crate::i18n::Msg;
+use serde_json::Value;
+
+impl App {
+    /// Selected card(s) filled into the template called `name` (the first template when
+    /// empty), one card per line
+    pub fn selected_cards_with_template(&self, name: &str) -> Result<String, String> {
+        let template = if name.is_empty() { self.templates.first() } else { self.templates.iter().find(|(n, _)| n == name) };
+        let Some((_, template)) = template else {
+            return Err(if self.templates.is_empty() {
+                "No templates: add `template NAME TEXT` to ~/.revwrc".to_string()
+            } else {
+                format!("No template named {}", name)
+            });
+        };
+
+        let json_value = serde_json::from_str::<Value>(&self.json_input).map_err(|_| "Error: Invalid JSON data".to_string())?;
+        let outside = json_value.get("outside").and_then(|v| v.as_array()).map(Vec::as_slice).unwrap_or_default();
+        let inside = json_value.get("inside").and_then(|v| v.as_array()).map(Vec::as_slice).unwrap_or_default();
+
+        let lines: Vec<String> = self
+            .visual_selected_indices()
+            .into_iter()
+            .filter_map(|idx| self.relf_entries.get(idx))
+            .filter_map(|entry| {
+                let original_idx = entry.original_index;
+                let item = if original_idx < outside.len() { outside.get(original_idx) } else { inside.get(original_idx - outside.len()) };
+                item.and_then(|item| item.as_object())
+            })
+            .map(|item_obj| fields::fill_template(template, item_obj))
+            .collect();
+        if lines.is_empty() {
+            return Err("No cards to copy".to_string());
+        }
+        Ok(lines.join("\n"))
+    }
+
+    /// :yank-template [NAME] - copy the selected card(s) formatted by a template from ~/.revwrc
+    pub fn copy_cards_template(&mut self, name: &str) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let text = match self.selected_cards_with_template(name) {
+            Ok(text) => text,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        let count = self.visual_selected_indices().len();
+        match self.clipboard_set_text(text) {
+            Ok(()) => {
+                self.set_status(&format!("Copied {} card(s) with a template", count));
+                // Exit Visual mode after copy
+                if self.visual_mode {
+                    self.visual_mode = false;
+                }
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+        }
+    }
+}
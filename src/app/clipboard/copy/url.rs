@@ -1,5 +1,5 @@
 use super::super::super::{App, FormatMode};
-use arboard::Clipboard;
+use crate::i18n::Msg;
 
 impl App {
     /// Copy URL from selected entry to clipboard
@@ -11,22 +11,19 @@ impl App {
                 let url = entry.lines.iter().find(|line| line.starts_with("http"));
 
                 if let Some(url_str) = url {
-                    match Clipboard::new() {
-                        Ok(mut clipboard) => match clipboard.set_text(url_str.clone()) {
-                            Ok(()) => self.set_status(&format!("Copied URL: {}", url_str)),
-                            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-                        },
-                        Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+                    match self.clipboard_set_text(url_str.clone()) {
+                        Ok(()) => self.set_status(&format!("Copied URL: {}", url_str)),
+                        Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
                     }
                 } else {
                     self.set_status("No URL found in selected entry");
                 }
             } else {
-                self.set_status("No entry selected");
+                self.set_status(self.msg(Msg::NoEntrySelected));
             }
             return;
         }
 
-        self.set_status("Not in card view mode");
+        self.set_status(self.msg(Msg::NotInCardView));
     }
 }
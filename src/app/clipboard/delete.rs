@@ -1,5 +1,6 @@
 use super::super::{App, FormatMode};
 use serde_json::Value;
+use crate::i18n::Msg;
 
 impl App {
     /// Clear the INSIDE section
@@ -7,7 +8,6 @@ impl App {
         // Clear INSIDE section
         self.save_undo_state();
         self.view_edit_mode = false;
-        self.markdown_highlight_cache.clear();
 
         // For Markdown files
         if self.is_markdown_file() {
@@ -45,7 +45,7 @@ impl App {
                     self.set_status("Current JSON is not an object");
                 }
             }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::InvalidJson, &[&e])),
         }
     }
 
@@ -54,7 +54,6 @@ impl App {
         // Clear OUTSIDE section
         self.save_undo_state();
         self.view_edit_mode = false;
-        self.markdown_highlight_cache.clear();
 
         // For Markdown files
         if self.is_markdown_file() {
@@ -92,7 +91,7 @@ impl App {
                     self.set_status("Current JSON is not an object");
                 }
             }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::InvalidJson, &[&e])),
         }
     }
 
@@ -102,21 +101,15 @@ impl App {
         // In Visual mode: delete selected range and exit Visual mode
         // In View mode (non-Visual): delete current card only
         if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
-            self.set_status("Not in card view mode");
+            self.set_status(self.msg(Msg::NotInCardView));
             return;
         }
 
-        let (start_idx, end_idx) = if self.visual_mode {
-            let start = self.visual_start_index.min(self.visual_end_index);
-            let end = self.visual_start_index.max(self.visual_end_index);
-            (start, end)
-        } else {
-            (self.selected_entry_index, self.selected_entry_index)
-        };
+        let selected_indices = self.visual_selected_indices();
 
         // Get original indices to delete
         let mut original_indices = Vec::new();
-        for idx in start_idx..=end_idx {
+        for idx in selected_indices.iter().copied() {
             if idx < self.relf_entries.len() {
                 original_indices.push(self.relf_entries[idx].original_index);
             }
@@ -128,73 +121,72 @@ impl App {
         }
 
         // Delete from JSON
-        if let Ok(mut json_value) = serde_json::from_str::<Value>(&self.json_input) {
-            if let Some(obj) = json_value.as_object_mut() {
-                let outside_count = obj
-                    .get("outside")
-                    .and_then(|v| v.as_array())
-                    .map(|arr| arr.len())
-                    .unwrap_or(0);
+        if let Ok(mut json_value) = serde_json::from_str::<Value>(&self.json_input)
+            && let Some(obj) = json_value.as_object_mut() {
+            let outside_count = obj
+                .get("outside")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.len())
+                .unwrap_or(0);
 
-                // Separate indices into outside and inside
-                let mut outside_to_delete = Vec::new();
-                let mut inside_to_delete = Vec::new();
+            // Separate indices into outside and inside
+            let mut outside_to_delete = Vec::new();
+            let mut inside_to_delete = Vec::new();
 
-                for original_idx in original_indices {
-                    if original_idx < outside_count {
-                        outside_to_delete.push(original_idx);
-                    } else {
-                        inside_to_delete.push(original_idx - outside_count);
-                    }
+            for original_idx in original_indices {
+                if original_idx < outside_count {
+                    outside_to_delete.push(original_idx);
+                } else {
+                    inside_to_delete.push(original_idx - outside_count);
                 }
+            }
 
-                // Sort in reverse to delete from end to start
-                outside_to_delete.sort_by(|a, b| b.cmp(a));
-                inside_to_delete.sort_by(|a, b| b.cmp(a));
+            // Sort in reverse to delete from end to start
+            outside_to_delete.sort_by(|a, b| b.cmp(a));
+            inside_to_delete.sort_by(|a, b| b.cmp(a));
 
-                // Delete from outside
-                if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
-                    for idx in outside_to_delete {
-                        if idx < outside.len() {
-                            outside.remove(idx);
-                        }
+            // Delete from outside
+            if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+                for idx in outside_to_delete {
+                    if idx < outside.len() {
+                        outside.remove(idx);
                     }
                 }
+            }
 
-                // Delete from inside
-                if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
-                    for idx in inside_to_delete {
-                        if idx < inside.len() {
-                            inside.remove(idx);
-                        }
+            // Delete from inside
+            if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+                for idx in inside_to_delete {
+                    if idx < inside.len() {
+                        inside.remove(idx);
                     }
                 }
+            }
 
-                // Update JSON and re-render
-                match serde_json::to_string_pretty(&json_value) {
-                    Ok(formatted) => {
-                        self.save_undo_state();
-                        self.json_input = formatted;
-                        self.is_modified = true;
-                        self.sync_markdown_from_json();
-                        self.convert_json();
+            // Update JSON and re-render
+            match serde_json::to_string_pretty(&json_value) {
+                Ok(formatted) => {
+                    self.save_undo_state();
+                    self.json_input = formatted;
+                    self.is_modified = true;
+                    self.sync_markdown_from_json();
+                    self.convert_json();
 
-                        // Adjust selected index
-                        if self.selected_entry_index >= self.relf_entries.len() && !self.relf_entries.is_empty() {
-                            self.selected_entry_index = self.relf_entries.len() - 1;
-                        }
+                    // Adjust selected index
+                    if self.selected_entry_index >= self.relf_entries.len() && !self.relf_entries.is_empty() {
+                        self.selected_entry_index = self.relf_entries.len() - 1;
+                    }
 
-                        let count = end_idx - start_idx + 1;
-                        self.set_status(&format!("Deleted {} card(s)", count));
+                    let count = selected_indices.len();
+                    self.set_status(&format!("Deleted {} card(s)", count));
 
-                        // Exit Visual mode and save
-                        if self.visual_mode {
-                            self.visual_mode = false;
-                        }
-                        self.save_file();
+                    // Exit Visual mode and save
+                    if self.visual_mode {
+                        self.visual_mode = false;
                     }
-                    Err(e) => self.set_status(&format!("Format error: {}", e)),
+                    self.autosave();
                 }
+                Err(e) => self.set_status(&format!("Format error: {}", e)),
             }
         }
     }
@@ -1,5 +1,6 @@
 use super::super::{App, FormatMode};
 use serde_json::Value;
+use crate::i18n::Msg;
 
 impl App {
     /// Duplicate the selected entry in View mode or current entry in Edit mode
@@ -10,61 +11,58 @@ impl App {
             let target_idx = self.relf_entries[self.selected_entry_index].original_index;
 
             // View mode: duplicate selected entry in JSON
-            if let Ok(mut json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object_mut() {
-                    let outside_count = obj
-                        .get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
+            if let Ok(mut json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object_mut() {
+                let outside_count = obj
+                    .get("outside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
 
-                    // Determine which section the selected entry belongs to
-                    if target_idx < outside_count {
-                        // Duplicate OUTSIDE entry
-                        if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
-                            if target_idx < outside.len() {
-                                let entry_clone = outside[target_idx].clone();
-                                outside.insert(target_idx + 1, entry_clone);
+                // Determine which section the selected entry belongs to
+                if target_idx < outside_count {
+                    // Duplicate OUTSIDE entry
+                    if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut())
+                        && target_idx < outside.len() {
+                        let entry_clone = outside[target_idx].clone();
+                        outside.insert(target_idx + 1, entry_clone);
 
-                                // Update JSON and re-render
-                                match serde_json::to_string_pretty(&json_value) {
-                                    Ok(formatted) => {
-                                        self.save_undo_state();
-                                        self.json_input = formatted;
-                                        self.is_modified = true;
-                                        self.sync_markdown_from_json();
-                                        self.convert_json();
-                                        self.selected_entry_index += 1; // Move to duplicated entry
-                                        self.set_status("Entry duplicated");
-                                        self.save_file(); // Auto-save in View mode
-                                    }
-                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                }
+                        // Update JSON and re-render
+                        match serde_json::to_string_pretty(&json_value) {
+                            Ok(formatted) => {
+                                self.save_undo_state();
+                                self.json_input = formatted;
+                                self.is_modified = true;
+                                self.sync_markdown_from_json();
+                                self.convert_json();
+                                self.selected_entry_index += 1; // Move to duplicated entry
+                                self.set_status(self.msg(Msg::EntryDuplicated));
+                                self.autosave();
                             }
+                            Err(e) => self.set_status(&format!("Format error: {}", e)),
                         }
-                    } else {
-                        // Duplicate INSIDE entry
-                        let inside_index = target_idx - outside_count;
-                        if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
-                            if inside_index < inside.len() {
-                                let entry_clone = inside[inside_index].clone();
-                                inside.insert(inside_index + 1, entry_clone);
+                    }
+                } else {
+                    // Duplicate INSIDE entry
+                    let inside_index = target_idx - outside_count;
+                    if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut())
+                        && inside_index < inside.len() {
+                        let entry_clone = inside[inside_index].clone();
+                        inside.insert(inside_index + 1, entry_clone);
 
-                                // Update JSON and re-render
-                                match serde_json::to_string_pretty(&json_value) {
-                                    Ok(formatted) => {
-                                        self.save_undo_state();
-                                        self.json_input = formatted;
-                                        self.is_modified = true;
-                                        self.sync_markdown_from_json();
-                                        self.convert_json();
-                                        self.selected_entry_index += 1; // Move to duplicated entry
-                                        self.set_status("Entry duplicated");
-                                        self.save_file(); // Auto-save in View mode
-                                    }
-                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                }
+                        // Update JSON and re-render
+                        match serde_json::to_string_pretty(&json_value) {
+                            Ok(formatted) => {
+                                self.save_undo_state();
+                                self.json_input = formatted;
+                                self.is_modified = true;
+                                self.sync_markdown_from_json();
+                                self.convert_json();
+                                self.selected_entry_index += 1; // Move to duplicated entry
+                                self.set_status(self.msg(Msg::EntryDuplicated));
+                                self.autosave();
                             }
+                            Err(e) => self.set_status(&format!("Format error: {}", e)),
                         }
                     }
                 }
@@ -86,7 +84,7 @@ impl App {
                 self.content_cursor_line,
                 &lines,
             ) {
-                Ok((formatted, message)) => {
+                Ok(formatted) => {
                     if self.is_markdown_file() {
                         self.markdown_input = formatted;
                         match self.parse_markdown(&self.markdown_input) {
@@ -103,7 +101,7 @@ impl App {
                     }
                     self.convert_json();
                     self.is_modified = true;
-                    self.set_status(&message);
+                    self.set_status(self.msg(Msg::EntryDuplicated));
                 }
                 Err(e) => self.set_status(&e),
             }
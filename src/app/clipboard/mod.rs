@@ -1,5 +1,8 @@
+mod backend;
 mod copy;
 mod delete;
 mod duplicate;
 mod helpers;
 mod paste;
+
+pub use backend::osc52_sequence;
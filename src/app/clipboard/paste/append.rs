@@ -1,277 +1,254 @@
 use super::super::super::App;
-use arboard::Clipboard;
+use crate::convert::Format;
 use serde_json::Value;
 
 impl App {
-    pub fn paste_inside_append(&mut self) {
-        // Get clipboard content
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.get_text() {
-                Ok(clipboard_text) => {
-                    // For Markdown files, check if clipboard contains JSON or Markdown
-                    if self.is_markdown_file() {
-                        let trimmed = clipboard_text.trim();
-
-                        // Try to parse as JSON first
-                        if trimmed.starts_with('{') || trimmed.starts_with('[') {
-                            if let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
-                                // Convert JSON to Markdown
-                                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
-                                    self.paste_markdown_section_append(&md_text, "INSIDE");
-                                    return;
-                                }
-                            }
-                        }
+    /// `:vai`: append the INSIDE entries of the clipboard text
+    pub(crate) fn paste_inside_append_text(&mut self, clipboard_text: String) {
+        // For Markdown files, check if clipboard contains JSON or Markdown
+        if self.is_markdown_file() {
+            let trimmed = clipboard_text.trim();
+
+            // Try to parse as JSON first
+            if Format::detect(trimmed) == Format::Json
+                && let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
+                // Convert JSON to Markdown
+                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
+                    self.paste_markdown_section_append(&md_text, "INSIDE");
+                    return;
+                }
+            }
 
-                        if clipboard_text.contains("## OUTSIDE") || clipboard_text.contains("## INSIDE") {
-                            self.paste_markdown_section_append(&clipboard_text, "INSIDE");
-                            return;
-                        }
+            if Format::detect(&clipboard_text) == Format::Md {
+                self.paste_markdown_section_append(&clipboard_text, "INSIDE");
+                return;
+            }
 
-                        // Otherwise treat as Markdown
-                        self.paste_markdown_section_append(&clipboard_text, "INSIDE");
-                        return;
-                    }
+            // Otherwise treat as Markdown
+            self.paste_markdown_section_append(&clipboard_text, "INSIDE");
+            return;
+        }
 
-                    // For JSON files, parse JSON format
-                    // Try to parse as JSON
-                    match self.clipboard_text_to_json_value(&clipboard_text) {
-                        Ok(clipboard_json) => {
-                            // Extract "inside" array from clipboard
-                            let new_inside = if let Some(obj) = clipboard_json.as_object() {
-                                obj.get("inside").and_then(|v| v.as_array()).cloned()
-                            } else {
-                                None
-                            };
-
-                            if let Some(new_inside_items) = new_inside {
-                                // Parse current JSON
-                                match serde_json::from_str::<Value>(&self.json_input) {
-                                    Ok(mut current_json) => {
-                                        if let Some(obj) = current_json.as_object_mut() {
-                                            // Get or create inside array
-                                            let inside_array = obj.entry("inside".to_string())
-                                                .or_insert(Value::Array(vec![]));
-
-                                            if let Some(arr) = inside_array.as_array_mut() {
-                                                // Insert new items at the beginning (like :ai)
-                                                for (idx, item) in new_inside_items.into_iter().enumerate() {
-                                                    arr.insert(idx, item);
-                                                }
-
-                                                // Format and save
-                                                match serde_json::to_string_pretty(&current_json) {
-                                                    Ok(formatted) => {
-                                                        self.json_input = formatted;
-                                                        self.is_modified = true;
-                                                        self.sync_markdown_from_json();
-                                                        self.convert_json();
-                                                        self.set_status("INSIDE entries inserted at top from clipboard");
-                                                    }
-                                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                                }
-                                            } else {
-                                                self.set_status("Current 'inside' is not an array");
-                                            }
-                                        } else {
-                                            self.set_status("Current JSON is not an object");
+        // For JSON files, parse JSON format
+        // Try to parse as JSON
+        match self.clipboard_text_to_json_value(&clipboard_text) {
+            Ok(clipboard_json) => {
+                // Extract "inside" array from clipboard
+                let new_inside = if let Some(obj) = clipboard_json.as_object() {
+                    obj.get("inside").and_then(|v| v.as_array()).cloned()
+                } else {
+                    None
+                };
+
+                if let Some(new_inside_items) = new_inside {
+                    // Parse current JSON
+                    match serde_json::from_str::<Value>(&self.json_input) {
+                        Ok(mut current_json) => {
+                            if let Some(obj) = current_json.as_object_mut() {
+                                // Get or create inside array
+                                let inside_array = obj.entry("inside".to_string())
+                                    .or_insert(Value::Array(vec![]));
+
+                                if let Some(arr) = inside_array.as_array_mut() {
+                                    // Insert new items at the beginning (like :ai)
+                                    for (idx, item) in new_inside_items.into_iter().enumerate() {
+                                        arr.insert(idx, item);
+                                    }
+
+                                    // Format and save
+                                    match serde_json::to_string_pretty(&current_json) {
+                                        Ok(formatted) => {
+                                            self.json_input = formatted;
+                                            self.is_modified = true;
+                                            self.sync_markdown_from_json();
+                                            self.convert_json();
+                                            self.set_status("INSIDE entries inserted at top from clipboard");
                                         }
+                                        Err(e) => self.set_status(&format!("Format error: {}", e)),
                                     }
-                                    Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
+                                } else {
+                                    self.set_status("Current 'inside' is not an array");
                                 }
                             } else {
-                                self.set_status("No 'inside' array in clipboard JSON");
+                                self.set_status("Current JSON is not an object");
                             }
                         }
-                        Err(e) => self.set_status(&e),
+                        Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
                     }
+                } else {
+                    self.set_status("No 'inside' array in clipboard JSON");
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&e),
         }
     }
 
-    pub fn paste_outside_append(&mut self) {
-        // Get clipboard content
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.get_text() {
-                Ok(clipboard_text) => {
-                    // For Markdown files, check if clipboard contains JSON or Markdown
-                    if self.is_markdown_file() {
-                        let trimmed = clipboard_text.trim();
-
-                        // Try to parse as JSON first
-                        if trimmed.starts_with('{') || trimmed.starts_with('[') {
-                            if let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
-                                // Convert JSON to Markdown
-                                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
-                                    self.paste_markdown_section_append(&md_text, "OUTSIDE");
-                                    return;
-                                }
-                            }
-                        }
+    /// `:vao`: append the OUTSIDE entries of the clipboard text
+    pub(crate) fn paste_outside_append_text(&mut self, clipboard_text: String) {
+        // Entries whose URL already exists: prompt for update-in-place instead of duplicating
+        if self.merge_outside_from_text(&clipboard_text) {
+            return;
+        }
 
-                        if clipboard_text.contains("## OUTSIDE") || clipboard_text.contains("## INSIDE") {
-                            self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
-                            return;
-                        }
+        // For Markdown files, check if clipboard contains JSON or Markdown
+        if self.is_markdown_file() {
+            let trimmed = clipboard_text.trim();
+
+            // Try to parse as JSON first
+            if Format::detect(trimmed) == Format::Json
+                && let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
+                // Convert JSON to Markdown
+                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
+                    self.paste_markdown_section_append(&md_text, "OUTSIDE");
+                    return;
+                }
+            }
 
-                        // Otherwise treat as Markdown
-                        self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
-                        return;
-                    }
+            if Format::detect(&clipboard_text) == Format::Md {
+                self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
+                return;
+            }
 
-                    // For JSON files, parse JSON format
-                    // Try to parse as JSON
-                    match self.clipboard_text_to_json_value(&clipboard_text) {
-                        Ok(clipboard_json) => {
-                            // Extract "outside" array from clipboard
-                            let new_outside = if let Some(obj) = clipboard_json.as_object() {
-                                obj.get("outside").and_then(|v| v.as_array()).cloned()
-                            } else {
-                                None
-                            };
-
-                            if let Some(new_outside_items) = new_outside {
-                                // Parse current JSON
-                                match serde_json::from_str::<Value>(&self.json_input) {
-                                    Ok(mut current_json) => {
-                                        if let Some(obj) = current_json.as_object_mut() {
-                                            // Get or create outside array
-                                            let outside_array = obj.entry("outside".to_string())
-                                                .or_insert(Value::Array(vec![]));
-
-                                            if let Some(arr) = outside_array.as_array_mut() {
-                                                // Append new items
-                                                for item in new_outside_items {
-                                                    arr.push(item);
-                                                }
-
-                                                // Format and save
-                                                match serde_json::to_string_pretty(&current_json) {
-                                                    Ok(formatted) => {
-                                                        self.json_input = formatted;
-                                                        self.is_modified = true;
-                                                        self.sync_markdown_from_json();
-                                                        self.convert_json();
-                                                        self.set_status("OUTSIDE entries appended from clipboard");
-                                                    }
-                                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                                }
-                                            } else {
-                                                self.set_status("Current 'outside' is not an array");
-                                            }
-                                        } else {
-                                            self.set_status("Current JSON is not an object");
+            // Otherwise treat as Markdown
+            self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
+            return;
+        }
+
+        // For JSON files, parse JSON format
+        // Try to parse as JSON
+        match self.clipboard_text_to_json_value(&clipboard_text) {
+            Ok(clipboard_json) => {
+                // Extract "outside" array from clipboard
+                let new_outside = if let Some(obj) = clipboard_json.as_object() {
+                    obj.get("outside").and_then(|v| v.as_array()).cloned()
+                } else {
+                    None
+                };
+
+                if let Some(new_outside_items) = new_outside {
+                    // Parse current JSON
+                    match serde_json::from_str::<Value>(&self.json_input) {
+                        Ok(mut current_json) => {
+                            if let Some(obj) = current_json.as_object_mut() {
+                                // Get or create outside array
+                                let outside_array = obj.entry("outside".to_string())
+                                    .or_insert(Value::Array(vec![]));
+
+                                if let Some(arr) = outside_array.as_array_mut() {
+                                    // Append new items
+                                    for item in new_outside_items {
+                                        arr.push(item);
+                                    }
+
+                                    // Format and save
+                                    match serde_json::to_string_pretty(&current_json) {
+                                        Ok(formatted) => {
+                                            self.json_input = formatted;
+                                            self.is_modified = true;
+                                            self.sync_markdown_from_json();
+                                            self.convert_json();
+                                            self.set_status("OUTSIDE entries appended from clipboard");
                                         }
+                                        Err(e) => self.set_status(&format!("Format error: {}", e)),
                                     }
-                                    Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
+                                } else {
+                                    self.set_status("Current 'outside' is not an array");
                                 }
                             } else {
-                                self.set_status("No 'outside' array in clipboard JSON");
+                                self.set_status("Current JSON is not an object");
                             }
                         }
-                        Err(e) => self.set_status(&e),
+                        Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
                     }
+                } else {
+                    self.set_status("No 'outside' array in clipboard JSON");
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&e),
         }
     }
 
-    pub fn paste_append_all(&mut self) {
-        // Append both inside and outside from clipboard
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.get_text() {
-                Ok(clipboard_text) => {
-                    if self.is_markdown_file() {
-                        let trimmed = clipboard_text.trim();
-
-                        if trimmed.starts_with('{') || trimmed.starts_with('[') {
-                            if let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
-                                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
-                                    self.paste_markdown_section_append(&md_text, "OUTSIDE");
-                                    self.paste_markdown_section_append(&md_text, "INSIDE");
-                                    return;
+    /// `:va`: append both sections of the clipboard text
+    pub(crate) fn paste_append_all_text(&mut self, clipboard_text: String) {
+        if self.is_markdown_file() {
+            let trimmed = clipboard_text.trim();
+
+            if Format::detect(trimmed) == Format::Json
+                && let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text)
+                    && let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
+                self.paste_markdown_section_append(&md_text, "OUTSIDE");
+                self.paste_markdown_section_append(&md_text, "INSIDE");
+                return;
+            }
+
+            if Format::detect(&clipboard_text) == Format::Md {
+                self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
+                self.paste_markdown_section_append(&clipboard_text, "INSIDE");
+                return;
+            }
+        }
+
+        match self.clipboard_text_to_json_value(&clipboard_text) {
+            Ok(clipboard_json) => {
+                if let Some(clipboard_obj) = clipboard_json.as_object() {
+                    // Parse current JSON
+                    match serde_json::from_str::<Value>(&self.json_input) {
+                        Ok(mut current_json) => {
+                            if let Some(current_obj) = current_json.as_object_mut() {
+                                let mut appended_sections = Vec::new();
+
+                                // Append INSIDE entries
+                                if let Some(clipboard_inside) = clipboard_obj.get("inside").and_then(|v| v.as_array()) {
+                                    let inside_array = current_obj.entry("inside".to_string())
+                                        .or_insert(Value::Array(vec![]));
+
+                                    if let Some(arr) = inside_array.as_array_mut() {
+                                        for item in clipboard_inside {
+                                            arr.push(item.clone());
+                                        }
+                                        appended_sections.push("INSIDE");
+                                    }
                                 }
-                            }
-                        }
 
-                        if clipboard_text.contains("## OUTSIDE") || clipboard_text.contains("## INSIDE") {
-                            self.paste_markdown_section_append(&clipboard_text, "OUTSIDE");
-                            self.paste_markdown_section_append(&clipboard_text, "INSIDE");
-                            return;
-                        }
-                    }
+                                // Append OUTSIDE entries
+                                if let Some(clipboard_outside) = clipboard_obj.get("outside").and_then(|v| v.as_array()) {
+                                    let outside_array = current_obj.entry("outside".to_string())
+                                        .or_insert(Value::Array(vec![]));
 
-                    match self.clipboard_text_to_json_value(&clipboard_text) {
-                        Ok(clipboard_json) => {
-                            if let Some(clipboard_obj) = clipboard_json.as_object() {
-                                // Parse current JSON
-                                match serde_json::from_str::<Value>(&self.json_input) {
-                                    Ok(mut current_json) => {
-                                        if let Some(current_obj) = current_json.as_object_mut() {
-                                            let mut appended_sections = Vec::new();
-
-                                            // Append INSIDE entries
-                                            if let Some(clipboard_inside) = clipboard_obj.get("inside").and_then(|v| v.as_array()) {
-                                                let inside_array = current_obj.entry("inside".to_string())
-                                                    .or_insert(Value::Array(vec![]));
-
-                                                if let Some(arr) = inside_array.as_array_mut() {
-                                                    for item in clipboard_inside {
-                                                        arr.push(item.clone());
-                                                    }
-                                                    appended_sections.push("INSIDE");
-                                                }
-                                            }
-
-                                            // Append OUTSIDE entries
-                                            if let Some(clipboard_outside) = clipboard_obj.get("outside").and_then(|v| v.as_array()) {
-                                                let outside_array = current_obj.entry("outside".to_string())
-                                                    .or_insert(Value::Array(vec![]));
-
-                                                if let Some(arr) = outside_array.as_array_mut() {
-                                                    for item in clipboard_outside {
-                                                        arr.push(item.clone());
-                                                    }
-                                                    appended_sections.push("OUTSIDE");
-                                                }
-                                            }
-
-                                            if !appended_sections.is_empty() {
-                                                // Format and save
-                                                match serde_json::to_string_pretty(&current_json) {
-                                                    Ok(formatted) => {
-                                                        self.json_input = formatted;
-                                                        self.is_modified = true;
-                                                        self.sync_markdown_from_json();
-                                                        self.convert_json();
-                                                        self.set_status(&format!("{} appended from clipboard", appended_sections.join(" and ")));
-                                                    }
-                                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                                }
-                                            } else {
-                                                self.set_status("No inside/outside arrays in clipboard");
-                                            }
-                                        } else {
-                                            self.set_status("Current JSON is not an object");
+                                    if let Some(arr) = outside_array.as_array_mut() {
+                                        for item in clipboard_outside {
+                                            arr.push(item.clone());
                                         }
+                                        appended_sections.push("OUTSIDE");
                                     }
-                                    Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
+                                }
+
+                                if !appended_sections.is_empty() {
+                                    // Format and save
+                                    match serde_json::to_string_pretty(&current_json) {
+                                        Ok(formatted) => {
+                                            self.json_input = formatted;
+                                            self.is_modified = true;
+                                            self.sync_markdown_from_json();
+                                            self.convert_json();
+                                            self.set_status(&format!("{} appended from clipboard", appended_sections.join(" and ")));
+                                        }
+                                        Err(e) => self.set_status(&format!("Format error: {}", e)),
+                                    }
+                                } else {
+                                    self.set_status("No inside/outside arrays in clipboard");
                                 }
                             } else {
-                                self.set_status("Clipboard JSON is not an object");
+                                self.set_status("Current JSON is not an object");
                             }
                         }
-                        Err(e) => self.set_status(&e),
+                        Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
                     }
+                } else {
+                    self.set_status("Clipboard JSON is not an object");
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&e),
         }
     }
 
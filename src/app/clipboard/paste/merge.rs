@@ -0,0 +1,135 @@
+use super::super::super::App;
+use crate::json_ops::JsonOperations;
+use serde_json::Value;
+
+impl App {
+    /// Smart paste for OUTSIDE entries: entries with a new URL are appended right away,
+    /// entries whose URL already exists are queued for a y/n/a/q prompt.
+    /// Returns false (and changes nothing) when no pasted URL is already in the file.
+    pub fn merge_outside_from_text(&mut self, text: &str) -> bool {
+        let incoming = match self.clipboard_text_to_json_value(text) {
+            Ok(value) => value,
+            Err(_) if self.is_markdown_file() => {
+                // Headerless Markdown is pasted into OUTSIDE
+                match self
+                    .parse_markdown(&format!("## OUTSIDE\n{}", text))
+                    .ok()
+                    .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+                {
+                    Some(value) => value,
+                    None => return false,
+                }
+            }
+            Err(_) => return false,
+        };
+        let Some(items) = incoming.get("outside").and_then(|v| v.as_array()) else {
+            return false;
+        };
+        let Ok(current) = serde_json::from_str::<Value>(&self.json_input) else {
+            return false;
+        };
+        let existing = current
+            .get("outside")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        let (duplicates, fresh): (Vec<Value>, Vec<Value>) = items.iter().cloned().partition(|item| {
+            let url = item.get("url").and_then(|v| v.as_str()).unwrap_or("");
+            JsonOperations::find_outside_by_url(&existing, url).is_some()
+        });
+        if duplicates.is_empty() {
+            return false;
+        }
+
+        self.save_undo_state();
+        if !fresh.is_empty()
+            && let Err(e) = self.modify_outside(|outside| outside.extend(fresh)) {
+            self.set_status(&e);
+            return true;
+        }
+        self.paste_merge_pending = duplicates;
+        self.prompt_paste_merge();
+        true
+    }
+
+    /// Answer the smart paste prompt for the first pending entry:
+    /// y = update in place, n = add as a new entry, a = update all remaining, q = skip remaining
+    pub fn handle_paste_merge_confirmation(&mut self, answer: char) {
+        if self.paste_merge_pending.is_empty() {
+            return;
+        }
+
+        let items: Vec<Value> = match answer {
+            'y' | 'n' => vec![self.paste_merge_pending.remove(0)],
+            'a' => std::mem::take(&mut self.paste_merge_pending),
+            'q' => {
+                let skipped = std::mem::take(&mut self.paste_merge_pending).len();
+                self.set_status(&format!("Smart paste: skipped {} existing entr{}", skipped, if skipped == 1 { "y" } else { "ies" }));
+                self.autosave();
+                return;
+            }
+            _ => return,
+        };
+
+        let result = self.modify_outside(|outside| {
+            for item in items {
+                let url = item.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                match JsonOperations::find_outside_by_url(outside, &url) {
+                    Some(idx) if answer != 'n' => JsonOperations::refresh_outside_entry(&mut outside[idx], &item),
+                    _ => outside.push(item),
+                }
+            }
+        });
+        if let Err(e) = result {
+            self.paste_merge_pending.clear();
+            self.set_status(&e);
+            return;
+        }
+
+        if self.paste_merge_pending.is_empty() {
+            self.set_status("Smart paste finished");
+            self.autosave();
+        } else {
+            self.prompt_paste_merge();
+        }
+    }
+
+    fn prompt_paste_merge(&mut self) {
+        if let Some(item) = self.paste_merge_pending.first() {
+            let name = item.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            let url = item.get("url").and_then(|v| v.as_str()).unwrap_or("");
+            self.set_status(&format!(
+                "URL exists: {} ({}) - update in place? (y/n/a/q) [{} left]",
+                name,
+                url,
+                self.paste_merge_pending.len()
+            ));
+        }
+    }
+
+    /// Apply `f` to the OUTSIDE array, then sync Markdown and re-render
+    fn modify_outside<F>(&mut self, f: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut Vec<Value>),
+    {
+        let mut json_value: Value = serde_json::from_str(&self.json_input)
+            .map_err(|e| format!("Invalid current JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside = obj
+            .entry("outside".to_string())
+            .or_insert(Value::Array(vec![]))
+            .as_array_mut()
+            .ok_or_else(|| "Current 'outside' is not an array".to_string())?;
+        f(outside);
+
+        self.json_input = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 mod append;
 mod basic;
+mod merge;
 mod overwrite;
 mod url;
@@ -1,163 +1,145 @@
 use super::super::super::App;
-use arboard::Clipboard;
+use crate::convert::Format;
 use serde_json::Value;
 
 impl App {
-    pub fn paste_inside_overwrite(&mut self) {
-        // Get clipboard content
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.get_text() {
-                Ok(clipboard_text) => {
-                    // For Markdown files, check if clipboard contains JSON or Markdown
-                    if self.is_markdown_file() {
-                        let trimmed = clipboard_text.trim();
-
-                        // Try to parse as JSON first
-                        if trimmed.starts_with('{') || trimmed.starts_with('[') {
-                            if let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
-                                // Convert JSON to Markdown
-                                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
-                                    self.paste_markdown_section_overwrite(&md_text, "INSIDE");
-                                    return;
-                                }
-                            }
-                        }
-
-                        if clipboard_text.contains("## OUTSIDE") || clipboard_text.contains("## INSIDE") {
-                            self.paste_markdown_section_overwrite(&clipboard_text, "INSIDE");
-                            return;
-                        }
-
-                        // Otherwise treat as Markdown
-                        self.paste_markdown_section_overwrite(&clipboard_text, "INSIDE");
-                        return;
-                    }
+    /// `:vi`: replace INSIDE with the entries in the clipboard text
+    pub(crate) fn paste_inside_overwrite_text(&mut self, clipboard_text: String) {
+        // For Markdown files, check if clipboard contains JSON or Markdown
+        if self.is_markdown_file() {
+            let trimmed = clipboard_text.trim();
+
+            // Try to parse as JSON first
+            if Format::detect(trimmed) == Format::Json
+                && let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
+                // Convert JSON to Markdown
+                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
+                    self.paste_markdown_section_overwrite(&md_text, "INSIDE");
+                    return;
+                }
+            }
 
-                    // For JSON files, parse JSON format
-                    // Try to parse as JSON
-                    match self.clipboard_text_to_json_value(&clipboard_text) {
-                        Ok(clipboard_json) => {
-                            // Extract "inside" array from clipboard
-                            let new_inside = if let Some(obj) = clipboard_json.as_object() {
-                                obj.get("inside").cloned()
-                            } else {
-                                None
-                            };
+            if Format::detect(&clipboard_text) == Format::Md {
+                self.paste_markdown_section_overwrite(&clipboard_text, "INSIDE");
+                return;
+            }
 
-                            if let Some(new_inside) = new_inside {
-                                // Parse current JSON
-                                match serde_json::from_str::<Value>(&self.json_input) {
-                                    Ok(mut current_json) => {
-                                        if let Some(obj) = current_json.as_object_mut() {
-                                            // Overwrite inside
-                                            obj.insert("inside".to_string(), new_inside);
+            // Otherwise treat as Markdown
+            self.paste_markdown_section_overwrite(&clipboard_text, "INSIDE");
+            return;
+        }
 
-                                            // Format and save
-                                            match serde_json::to_string_pretty(&current_json) {
-                                                Ok(formatted) => {
-                                                    self.json_input = formatted;
-                                                    self.is_modified = true;
-                                                    self.sync_markdown_from_json();
-                                                    self.convert_json();
-                                                    self.set_status("INSIDE section overwritten from clipboard");
-                                                }
-                                                Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                            }
-                                        } else {
-                                            self.set_status("Current JSON is not an object");
-                                        }
+        // For JSON files, parse JSON format
+        // Try to parse as JSON
+        match self.clipboard_text_to_json_value(&clipboard_text) {
+            Ok(clipboard_json) => {
+                // Extract "inside" array from clipboard
+                let new_inside = if let Some(obj) = clipboard_json.as_object() {
+                    obj.get("inside").cloned()
+                } else {
+                    None
+                };
+
+                if let Some(new_inside) = new_inside {
+                    // Parse current JSON
+                    match serde_json::from_str::<Value>(&self.json_input) {
+                        Ok(mut current_json) => {
+                            if let Some(obj) = current_json.as_object_mut() {
+                                // Overwrite inside
+                                obj.insert("inside".to_string(), new_inside);
+
+                                // Format and save
+                                match serde_json::to_string_pretty(&current_json) {
+                                    Ok(formatted) => {
+                                        self.json_input = formatted;
+                                        self.is_modified = true;
+                                        self.sync_markdown_from_json();
+                                        self.convert_json();
+                                        self.set_status("INSIDE section overwritten from clipboard");
                                     }
-                                    Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
+                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
                                 }
                             } else {
-                                self.set_status("No 'inside' field in clipboard JSON");
+                                self.set_status("Current JSON is not an object");
                             }
                         }
-                        Err(e) => self.set_status(&e),
+                        Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
                     }
+                } else {
+                    self.set_status("No 'inside' field in clipboard JSON");
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&e),
         }
     }
 
-    pub fn paste_outside_overwrite(&mut self) {
-        // Get clipboard content
-        match Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.get_text() {
-                Ok(clipboard_text) => {
-                    // For Markdown files, check if clipboard contains JSON or Markdown
-                    if self.is_markdown_file() {
-                        let trimmed = clipboard_text.trim();
-
-                        // Try to parse as JSON first
-                        if trimmed.starts_with('{') || trimmed.starts_with('[') {
-                            if let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
-                                // Convert JSON to Markdown
-                                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
-                                    self.paste_markdown_section_overwrite(&md_text, "OUTSIDE");
-                                    return;
-                                }
-                            }
-                        }
-
-                        if clipboard_text.contains("## OUTSIDE") || clipboard_text.contains("## INSIDE") {
-                            self.paste_markdown_section_overwrite(&clipboard_text, "OUTSIDE");
-                            return;
-                        }
-
-                        // Otherwise treat as Markdown
-                        self.paste_markdown_section_overwrite(&clipboard_text, "OUTSIDE");
-                        return;
-                    }
+    /// `:vo`: replace OUTSIDE with the entries in the clipboard text
+    pub(crate) fn paste_outside_overwrite_text(&mut self, clipboard_text: String) {
+        // For Markdown files, check if clipboard contains JSON or Markdown
+        if self.is_markdown_file() {
+            let trimmed = clipboard_text.trim();
+
+            // Try to parse as JSON first
+            if Format::detect(trimmed) == Format::Json
+                && let Ok(clipboard_json) = serde_json::from_str::<Value>(&clipboard_text) {
+                // Convert JSON to Markdown
+                if let Ok(md_text) = Self::json_to_markdown_string(&clipboard_json) {
+                    self.paste_markdown_section_overwrite(&md_text, "OUTSIDE");
+                    return;
+                }
+            }
 
-                    // For JSON files, parse JSON format
-                    // Try to parse as JSON
-                    match self.clipboard_text_to_json_value(&clipboard_text) {
-                        Ok(clipboard_json) => {
-                            // Extract "outside" array from clipboard
-                            let new_outside = if let Some(obj) = clipboard_json.as_object() {
-                                obj.get("outside").cloned()
-                            } else {
-                                None
-                            };
+            if Format::detect(&clipboard_text) == Format::Md {
+                self.paste_markdown_section_overwrite(&clipboard_text, "OUTSIDE");
+                return;
+            }
 
-                            if let Some(new_outside) = new_outside {
-                                // Parse current JSON
-                                match serde_json::from_str::<Value>(&self.json_input) {
-                                    Ok(mut current_json) => {
-                                        if let Some(obj) = current_json.as_object_mut() {
-                                            // Overwrite outside
-                                            obj.insert("outside".to_string(), new_outside);
+            // Otherwise treat as Markdown
+            self.paste_markdown_section_overwrite(&clipboard_text, "OUTSIDE");
+            return;
+        }
 
-                                            // Format and save
-                                            match serde_json::to_string_pretty(&current_json) {
-                                                Ok(formatted) => {
-                                                    self.json_input = formatted;
-                                                    self.is_modified = true;
-                                                    self.sync_markdown_from_json();
-                                                    self.convert_json();
-                                                    self.set_status("OUTSIDE section overwritten from clipboard");
-                                                }
-                                                Err(e) => self.set_status(&format!("Format error: {}", e)),
-                                            }
-                                        } else {
-                                            self.set_status("Current JSON is not an object");
-                                        }
+        // For JSON files, parse JSON format
+        // Try to parse as JSON
+        match self.clipboard_text_to_json_value(&clipboard_text) {
+            Ok(clipboard_json) => {
+                // Extract "outside" array from clipboard
+                let new_outside = if let Some(obj) = clipboard_json.as_object() {
+                    obj.get("outside").cloned()
+                } else {
+                    None
+                };
+
+                if let Some(new_outside) = new_outside {
+                    // Parse current JSON
+                    match serde_json::from_str::<Value>(&self.json_input) {
+                        Ok(mut current_json) => {
+                            if let Some(obj) = current_json.as_object_mut() {
+                                // Overwrite outside
+                                obj.insert("outside".to_string(), new_outside);
+
+                                // Format and save
+                                match serde_json::to_string_pretty(&current_json) {
+                                    Ok(formatted) => {
+                                        self.json_input = formatted;
+                                        self.is_modified = true;
+                                        self.sync_markdown_from_json();
+                                        self.convert_json();
+                                        self.set_status("OUTSIDE section overwritten from clipboard");
                                     }
-                                    Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
+                                    Err(e) => self.set_status(&format!("Format error: {}", e)),
                                 }
                             } else {
-                                self.set_status("No 'outside' field in clipboard JSON");
+                                self.set_status("Current JSON is not an object");
                             }
                         }
-                        Err(e) => self.set_status(&e),
+                        Err(e) => self.set_status(&format!("Invalid current JSON: {}", e)),
                     }
+                } else {
+                    self.set_status("No 'outside' field in clipboard JSON");
                 }
-                Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.set_status(&format!("Clipboard error: {}", e)),
+            }
+            Err(e) => self.set_status(&e),
         }
     }
 
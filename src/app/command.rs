@@ -1,63 +1,14 @@
-use super::{App, FormatMode};
-use crate::config::BorderStyle;
-use std::path::PathBuf;
+use super::{App, ConfirmAction, FormatMode};
+use super::file::typed_path;
+use crate::config::{AutosavePolicy, BorderStyle, ClipboardBackend, DefaultSection, Glyphs, InsideOrder, PasteSource};
+use crate::i18n::{Lang, Msg};
+use crate::rendering::COPY_FIELDS;
 
 impl App {
-    pub fn handle_vim_input(&mut self, c: char) -> bool {
-        self.vim_buffer.push(c);
-
-        // Handle explorer-specific commands
-        if self.explorer_open && self.explorer_has_focus {
-            if self.vim_buffer == "go" {
-                // Preview file without moving focus (NERDTree-like)
-                self.explorer_preview_entry();
-                self.vim_buffer.clear();
-                return true;
-            }
-        }
-
-        if self.vim_buffer == "gg" {
-            if self.showing_help {
-                // Allow scrolling to top in help mode (takes priority)
-                self.scroll_to_top();
-            } else if self.format_mode == FormatMode::Edit {
-                self.scroll_to_top();
-                self.content_cursor_line = 0;
-                self.content_cursor_col = 0;
-            } else if !self.relf_entries.is_empty() {
-                // Jump to first card
-                self.selected_entry_index = 0;
-            } else {
-                self.scroll_to_top();
-                self.content_cursor_line = 0;
-                self.content_cursor_col = 0;
-            }
-            self.vim_buffer.clear();
-            return true;
-        } else if self.vim_buffer == "g-" {
-            // Undo (vim-style, not in help mode)
-            if !self.showing_help && self.format_mode == FormatMode::Edit {
-                self.undo();
-            }
-            self.vim_buffer.clear();
-            return true;
-        } else if self.vim_buffer == "g+" {
-            // Redo (vim-style, not in help mode)
-            if !self.showing_help && self.format_mode == FormatMode::Edit {
-                self.redo();
-            }
-            self.vim_buffer.clear();
-            return true;
-        } else if self.vim_buffer.len() >= 2 {
-            self.vim_buffer.clear();
-        }
-
-        false
-    }
-
     pub fn execute_command(&mut self) -> bool {
         let cmd = self.command_buffer.clone();
         let cmd = cmd.trim();
+        tracing::info!(command = cmd, "command");
 
         // Handle explorer-specific commands when explorer has focus
         if self.explorer_open && self.explorer_has_focus {
@@ -81,9 +32,11 @@ impl App {
 
         if cmd == "w" {
             self.save_file();
+        } else if cmd == "w!" {
+            self.force_save_file();
         } else if cmd == "wq" {
             self.save_file();
-            return true; // Signal to quit
+            return !self.save_conflict; // Signal to quit unless the save awaits conflict resolution
         } else if cmd == "q" {
             // If explorer has focus, close explorer instead of quitting
             if self.explorer_open && self.explorer_has_focus {
@@ -118,23 +71,37 @@ impl App {
             if !filename.ends_with(".json") && !filename.ends_with(".md") {
                 self.set_status("Error: Filename must end with .json or .md");
             } else {
-                let path = PathBuf::from(filename);
-                self.load_file(path);
+                self.open_file(typed_path(&filename));
             }
+        } else if cmd == "new" || cmd.starts_with("new ") {
+            // Create a note file with empty sections and open it
+            self.new_file(cmd["new".len()..].trim());
         } else if cmd == "enew" {
             // Clear file window (like vim :enew)
+            self.flush_autosave(true);
             self.json_input = String::new();
             self.markdown_input = String::new();
             self.file_path = None;
             self.file_path_changed = false;
+            self.sources.clear();
             self.is_modified = false;
             self.content_cursor_line = 0;
             self.content_cursor_col = 0;
             self.scroll = 0;
             self.view_edit_mode = false;
-            self.markdown_highlight_cache.clear();
             self.convert_json();
+            self.record_journal_history();
             self.set_status("New empty buffer");
+        } else if cmd == "reload" || cmd == "reload!" {
+            self.reload_from_disk(cmd.ends_with('!'));
+        } else if cmd == "diff" {
+            // What the buffer changes compared to the file on disk
+            self.show_disk_diff();
+        } else if cmd == "rotate" || cmd.starts_with("rotate ") {
+            // Move old INSIDE notes into monthly archive files
+            self.rotate_inside(cmd["rotate".len()..].trim());
+        } else if cmd == "ar" && self.safe_mode {
+            self.set_status("Safe mode: the file is not watched");
         } else if cmd == "ar" {
             // Toggle auto-reload
             self.auto_reload = !self.auto_reload;
@@ -144,6 +111,11 @@ impl App {
                 "Auto-reload disabled"
             };
             self.set_status(status);
+        } else if cmd == "a" {
+            self.set_status("Usage: :a TEXT");
+        } else if let Some(text) = cmd.strip_prefix("a ") {
+            // Quick-add an entry to the default section
+            self.quick_add(text);
         } else if cmd == "ai" {
             // Add new inside entry at top
             self.append_inside();
@@ -155,6 +127,24 @@ impl App {
         } else if cmd == "op" {
             // Order by percentage only
             self.order_by_percentage();
+        } else if cmd == "sort priority" {
+            // Order by priority (highest first)
+            self.order_by_priority();
+        } else if cmd == "sort due" {
+            // Order by due date (soonest first)
+            self.order_by_due();
+        } else if cmd == "due" || cmd.starts_with("due ") {
+            // Filter to OUTSIDE cards due within N days (default due_soon_days), overdue included
+            let days = cmd.strip_prefix("due").unwrap().trim();
+            if self.format_mode != FormatMode::View {
+                self.set_status(self.msg(Msg::FilterViewOnly));
+            } else if days.is_empty() {
+                self.apply_filter(format!("due<={}", self.due_soon_days));
+            } else if days.parse::<i64>().is_ok() {
+                self.apply_filter(format!("due<={}", days));
+            } else {
+                self.set_status("Usage: :due [DAYS]");
+            }
         } else if cmd == "on" {
             // Order by name only
             self.order_by_name();
@@ -176,6 +166,12 @@ impl App {
         } else if cmd == "cu" {
             // Copy URL from selected entry
             self.copy_selected_url();
+        } else if let Some(key) = cmd.strip_prefix("y ") {
+            // Copy one field of the selected card by name (custom fields too)
+            self.copy_selected_field(key.trim());
+        } else if let Some(&(_, key)) = COPY_FIELDS.iter().find(|(letter, _)| cmd == format!("y{}", letter)) {
+            // :yu :yn :yc :yd :yp
+            self.copy_selected_field(key);
         } else if cmd == "cc" {
             // Copy card(s) with rendering
             self.copy_cards_rendered();
@@ -188,81 +184,148 @@ impl App {
         } else if cmd == "ccm" {
             // Copy card(s) as Markdown
             self.copy_cards_markdown();
+        } else if cmd == "yank-template" || cmd.starts_with("yank-template ") {
+            // Copy card(s) formatted by a template from ~/.revwrc
+            self.copy_cards_template(cmd["yank-template".len()..].trim());
         } else if cmd == "cm" {
             // Copy Markdown (current content in Markdown format)
             self.copy_markdown();
         } else if cmd == "dc" {
             // Delete card(s)
-            self.delete_cards();
+            self.confirm_action(ConfirmAction::DeleteCards);
         } else if cmd == "vu" {
             // Paste URL from clipboard to selected entry
             self.paste_url_to_selected();
+        } else if cmd == "vup" {
+            // Paste URL from the primary selection to selected entry
+            self.paste_url_from_primary();
         } else if cmd == "vi" {
             // Paste INSIDE from clipboard (overwrite)
-            self.paste_inside_overwrite();
+            self.confirm_action(ConfirmAction::PasteInside(None));
         } else if cmd == "vo" {
             // Paste OUTSIDE from clipboard (overwrite)
-            self.paste_outside_overwrite();
+            self.confirm_action(ConfirmAction::PasteOutside(None));
         } else if cmd == "va" {
             // Append from clipboard (both inside and outside)
-            self.paste_append_all();
+            self.confirm_action(ConfirmAction::PasteAppend(None));
         } else if cmd == "vai" {
             // Paste INSIDE from clipboard (append)
-            self.paste_inside_append();
+            self.confirm_action(ConfirmAction::PasteAppendInside(None));
         } else if cmd == "vao" {
             // Paste OUTSIDE from clipboard (append)
-            self.paste_outside_append();
+            self.confirm_action(ConfirmAction::PasteAppendOutside(None));
         } else if cmd == "xi" {
             // Clear INSIDE section
-            self.clear_inside();
+            self.confirm_action(ConfirmAction::ClearInside);
         } else if cmd == "xo" {
             // Clear OUTSIDE section
-            self.clear_outside();
+            self.confirm_action(ConfirmAction::ClearOutside);
         } else if cmd == "dd" {
             // Delete entry in both View and Edit modes
             if self.format_mode == FormatMode::Edit {
                 self.delete_current_entry();
                 self.is_modified = true;
             } else if !self.relf_entries.is_empty() {
-                self.delete_selected_entry();
-                self.is_modified = true;
-                // Auto-save after deletion in View mode
-                self.save_file();
+                self.confirm_action(ConfirmAction::DeleteCardsForward(1));
             }
         } else if cmd == "yy" {
             // Duplicate entry in both View and Edit modes
             self.duplicate_selected_entry();
-        } else if cmd == "noh" {
+        } else if cmd == "select all" {
+            // Select every card (like ggVG)
+            self.select_all_cards();
+        } else if cmd == "select invert" {
+            // Invert the Visual mode card selection
+            self.invert_card_selection();
+        } else if let Some(pct) = cmd.strip_prefix("set pct ") {
+            // Bulk edit: percentage of the selected cards
+            self.set_selected_percentage(pct);
+        } else if cmd == "done" {
+            // Bulk edit: toggle the selected OUTSIDE cards between done and active
+            self.toggle_selected_done();
+        } else if let Some(status) = cmd.strip_prefix("status ") {
+            // Bulk edit: status of the selected OUTSIDE cards
+            self.set_selected_status(status);
+        } else if let Some(level) = cmd.strip_prefix("priority ") {
+            // Bulk edit: priority of the selected cards
+            self.set_selected_priority(level);
+        } else if let Some(args) = cmd.strip_prefix("tag ") {
+            // Bulk edit: add/remove a tag on the selected cards
+            self.tag_selected(args);
+        } else if cmd == "autoname" {
+            // Bulk update: name unnamed entries after the first line of their context
+            self.autoname_entries();
+        } else if let Some(args) = cmd.strip_prefix("rename-tag ") {
+            // Bulk update: rename a tag in every entry
+            self.rename_tag(args);
+        } else if let Some(args) = cmd.strip_prefix("set-url-domain ") {
+            // Bulk update: move URLs from one domain to another
+            self.set_url_domain(args);
+        } else if let Some(target) = cmd.strip_prefix("move ") {
+            // Move the selected card within its section, or to the other section
+            match target.trim() {
+                section @ ("inside" | "outside") => self.move_selected_card_to(section),
+                offset => match offset.trim_start_matches('+').parse::<isize>() {
+                    Ok(offset) if offset != 0 => self.move_selected_card(offset),
+                    _ => self.set_status("Usage: :move +N, :move -N, :move inside or :move outside"),
+                },
+            }
+        } else if cmd == "attach" {
+            self.set_status("Usage: :attach <path>");
+        } else if let Some(path) = cmd.strip_prefix("attach ") {
+            // Attach a file to the selected card
+            self.attach_to_selected(path);
+        } else if cmd == "noh" || cmd == "nohl" {
             // Clear search highlighting
             self.clear_search_highlight();
         } else if cmd == "nof" {
             // Clear filter
             self.clear_filter();
-        } else if cmd.starts_with("f ") {
+        } else if cmd.starts_with("f ") || cmd.starts_with("filter ") {
             // Filter entries in View mode
             if self.format_mode == FormatMode::View {
-                let pattern = cmd.strip_prefix("f ").unwrap().trim().to_string();
+                let pattern = cmd.split_once(' ').unwrap().1.trim().to_string();
                 self.apply_filter(pattern);
             } else {
-                self.set_status("Filter only works in View mode");
+                self.set_status(self.msg(Msg::FilterViewOnly));
             }
         } else if cmd == "Lexplore" || cmd == "Lex" || cmd == "lx" {
             // Toggle file explorer (like vim netrw)
             self.toggle_explorer();
         } else if cmd == "h" {
             self.toggle_help();
+        } else if cmd == "matches" || cmd == "copen" {
+            // List all matches of the last search (quickfix-style)
+            self.open_match_list();
+        } else if cmd == "cclose" {
+            self.close_match_list();
+        } else if cmd == "progress" {
+            // Set every OUTSIDE percentage from its context's task checkboxes
+            self.update_checkbox_percentages();
+        } else if cmd == "recent" {
+            // Reopen a recently opened file
+            self.open_recent_list();
+        } else if cmd == "snapshots" {
+            // Browse, diff and restore named snapshots
+            self.open_snapshot_list();
+        } else if cmd == "snapshot" || cmd.starts_with("snapshot ") {
+            // Save the current document as a named snapshot
+            self.create_snapshot(cmd.strip_prefix("snapshot").unwrap_or("").trim());
         } else if cmd == "outline" || cmd == "ol" {
             // Toggle card outline
             self.toggle_outline();
+        } else if let Some(name) = cmd.strip_prefix("outline group ").or_else(|| cmd.strip_prefix("ol group ")) {
+            // Group the card outline by month, tag or priority
+            self.set_outline_group(name.trim());
         } else if cmd == "c" {
             // Copy all content to clipboard
             self.copy_to_clipboard();
         } else if cmd == "v" {
             // Paste from clipboard
-            self.paste_from_clipboard();
+            self.confirm_action(ConfirmAction::Paste(None));
         } else if cmd == "x" {
             // Clear all content
-            self.clear_content();
+            self.confirm_action(ConfirmAction::ClearAll);
         } else if cmd.starts_with("s/") || cmd.starts_with("%s/") {
             // Substitute command: :s/pattern/replacement/flags or :%s/pattern/replacement/flags
             self.execute_substitute(cmd);
@@ -286,7 +349,7 @@ impl App {
             // Set maximum visible cards
             if let Some(value_str) = cmd.strip_prefix("set card=") {
                 if let Ok(value) = value_str.trim().parse::<usize>() {
-                    if value >= 1 && value <= 10 {
+                    if (1..=10).contains(&value) {
                         self.max_visible_cards = value;
                         self.set_status(&format!("Max visible cards set to {}", value));
                     } else {
@@ -296,6 +359,19 @@ impl App {
                     self.set_status("Invalid card value");
                 }
             }
+        } else if let Some(value) = cmd.strip_prefix("set max_context_lines=") {
+            // Collapse card contexts longer than N lines (0 shows them in full)
+            match value.trim().parse::<usize>() {
+                Ok(0) => {
+                    self.max_context_lines = 0;
+                    self.set_status("Contexts shown in full");
+                }
+                Ok(value) => {
+                    self.max_context_lines = value;
+                    self.set_status(&format!("Contexts collapsed to {} lines (Tab expands a card)", value));
+                }
+                Err(_) => self.set_status("Invalid max_context_lines value"),
+            }
         } else if cmd == "set border=rounded" {
             // Switch to rounded borders
             self.border_style = BorderStyle::Rounded;
@@ -304,6 +380,13 @@ impl App {
             // Switch to plain borders
             self.border_style = BorderStyle::Plain;
             self.set_status("Border style set to plain");
+        } else if cmd == "set accessible" {
+            // Blank borders and plain ASCII markers, for screen readers and braille displays
+            self.accessible = true;
+            self.set_status("Accessible mode enabled");
+        } else if cmd == "set noaccessible" {
+            self.accessible = false;
+            self.set_status("Accessible mode disabled");
         } else if cmd == "set extension" {
             // Enable file extension display in explorer
             self.show_extension = true;
@@ -312,6 +395,214 @@ impl App {
             // Disable file extension display in explorer
             self.show_extension = false;
             self.set_status("File extensions disabled");
+        } else if cmd == "set preview" {
+            // Enable inline image preview for cards with image URLs
+            if crate::preview::ENABLED {
+                self.image_preview = true;
+                self.set_status("Image preview enabled");
+            } else {
+                self.set_status("Image preview not available (build with --features preview)");
+            }
+        } else if cmd == "set nopreview" {
+            // Disable inline image preview
+            self.image_preview = false;
+            self.set_status("Image preview disabled");
+        } else if cmd == "set strict" {
+            // Enable strict parsing and check the current buffer right away
+            self.strict_parsing = true;
+            match self.check_strict(&self.strict_source()) {
+                Ok(()) => self.set_status("Strict parsing enabled"),
+                Err(e) => self.set_status(&format!("Strict parsing enabled: {}", e)),
+            }
+        } else if cmd == "set nostrict" {
+            // Back to best-effort parsing
+            self.strict_parsing = false;
+            self.set_status("Strict parsing disabled");
+        } else if cmd == "set format_on_save" {
+            // Normalize JSON files whenever they are written
+            self.format_on_save = true;
+            self.set_status("Format on save enabled");
+        } else if cmd == "set noformat_on_save" {
+            self.format_on_save = false;
+            self.set_status("Format on save disabled");
+        } else if cmd == "set autoname_on_save" {
+            // Unnamed entries get a name from their context whenever the file is written
+            self.autoname_on_save = true;
+            self.set_status("Autoname on save enabled");
+        } else if cmd == "set noautoname_on_save" {
+            self.autoname_on_save = false;
+            self.set_status("Autoname on save disabled");
+        } else if cmd == "set atomic_save" {
+            // Write a temporary file and rename it over the original
+            self.atomic_save = true;
+            self.set_status("Atomic save enabled");
+        } else if cmd == "set noatomic_save" {
+            self.atomic_save = false;
+            self.set_status("Atomic save disabled");
+        } else if cmd == "set preserve_line_endings" {
+            // Save CRLF files with CRLF (and a BOM if they had one)
+            self.preserve_line_endings = true;
+            self.set_status("Preserve line endings enabled");
+        } else if cmd == "set nopreserve_line_endings" {
+            self.preserve_line_endings = false;
+            self.set_status("Preserve line endings disabled");
+        } else if cmd == "set checkbox_percentage" {
+            // Overlay saves set the percentage from the context's task checkboxes
+            self.checkbox_percentage = true;
+            self.set_status("Checkbox percentage enabled");
+        } else if cmd == "set nocheckbox_percentage" {
+            self.checkbox_percentage = false;
+            self.set_status("Checkbox percentage disabled");
+        } else if cmd == "set confirm_delete" {
+            // Ask y/n before :dc and dd delete cards
+            self.confirm_delete = true;
+            self.set_status("Confirm delete enabled");
+        } else if cmd == "set noconfirm_delete" {
+            self.confirm_delete = false;
+            self.set_status("Confirm delete disabled");
+        } else if cmd == "set confirm_clear" {
+            // Ask y/n before :xi, :xo and :x clear content
+            self.confirm_clear = true;
+            self.set_status("Confirm clear enabled");
+        } else if cmd == "set noconfirm_clear" {
+            self.confirm_clear = false;
+            self.set_status("Confirm clear disabled");
+        } else if cmd == "set confirm_paste" {
+            // Ask y/n before :v, :vi and :vo replace content with the clipboard
+            self.confirm_paste = true;
+            self.set_status("Confirm paste enabled");
+        } else if cmd == "set noconfirm_paste" {
+            self.confirm_paste = false;
+            self.set_status("Confirm paste disabled");
+        } else if cmd == "set smooth_scroll" {
+            // The card view glides to a new position over a few frames
+            self.smooth_scroll = true;
+            self.set_status("Smooth scroll enabled");
+        } else if cmd == "set nosmooth_scroll" {
+            self.smooth_scroll = false;
+            self.set_status("Smooth scroll disabled");
+        } else if let Some(value) = cmd.strip_prefix("set paste_limit=") {
+            // Pastes bringing in more entries than this ask first (0: never)
+            match value.trim().parse::<usize>() {
+                Ok(limit) => {
+                    self.paste_limit = limit;
+                    self.set_status(&format!("Paste limit set to {}", limit));
+                }
+                Err(_) => self.set_status("Paste limit must be a number (0 turns it off)"),
+            }
+        } else if cmd == "set show_dropped" {
+            // Dropped OUTSIDE entries are listed again
+            self.show_dropped = true;
+            self.convert_json();
+            self.set_status("Dropped entries shown");
+        } else if cmd == "set noshow_dropped" {
+            self.show_dropped = false;
+            self.convert_json();
+            self.set_status("Dropped entries hidden");
+        } else if let Some(option) = cmd.strip_prefix("set ").filter(|o| o.starts_with("url_") || o.starts_with("nourl_")) {
+            // Switch URL normalization rules for edited and pasted URLs
+            if self.url_rules.set(option) {
+                let (name, state) = match option.strip_prefix("no") {
+                    Some(name) => (name, "disabled"),
+                    None => (option, "enabled"),
+                };
+                self.set_status(&format!("{} {}", name, state));
+            } else {
+                self.set_status(&format!("Unknown URL rule: {}", option));
+            }
+        } else if let Some(option) = cmd.strip_prefix("set ").filter(|o| o.starts_with("explorer_") || o.starts_with("noexplorer_")) {
+            // Choose which files the explorer leaves out
+            if self.explorer_filter.set(option) {
+                let (name, state) = match option.strip_prefix("no") {
+                    Some(name) => (name, "disabled"),
+                    None => (option, "enabled"),
+                };
+                if self.explorer_open {
+                    self.reload_explorer_entries();
+                }
+                self.set_status(&format!("{} {}", name, state));
+            } else {
+                self.set_status(&format!("Unknown explorer option: {}", option));
+            }
+        } else if let Some(value) = cmd.strip_prefix("set autosave=") {
+            // Choose when mutating operations write the file
+            match AutosavePolicy::by_name(value.trim()) {
+                Some(policy) => {
+                    self.autosave = policy;
+                    self.set_status(&format!("Autosave set to {}", policy.name()));
+                }
+                None => self.set_status("Autosave must be always, on-action or manual"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set autosave_interval=") {
+            // Write at most once per N seconds (0 writes every change)
+            match value.trim().parse::<u64>() {
+                Ok(seconds) => {
+                    self.autosave_interval = std::time::Duration::from_secs(seconds);
+                    self.set_status(&format!("Autosave interval set to {}s", seconds));
+                }
+                Err(_) => self.set_status("Autosave interval must be a number of seconds"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set glyphs=") {
+            // Box drawing and symbols, or ASCII for terminals without good Unicode support
+            match Glyphs::by_name(value.trim()) {
+                Some(glyphs) => {
+                    self.glyphs = glyphs;
+                    self.set_status(&format!("Glyphs set to {}", glyphs.name()));
+                }
+                None => self.set_status("Glyphs must be unicode or ascii"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set clipboard=") {
+            // Choose where yanks go (osc52 reaches the local clipboard over SSH)
+            match ClipboardBackend::by_name(value.trim()) {
+                Some(backend) => {
+                    self.clipboard = backend;
+                    self.set_status(&format!("Clipboard set to {}", backend.name()));
+                }
+                None => self.set_status("Clipboard must be system, osc52 or auto"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set paste_source=") {
+            // Choose where pastes read from (primary is the middle-click selection)
+            match PasteSource::by_name(value.trim()) {
+                Some(source) => {
+                    self.paste_source = source;
+                    self.set_status(&format!("Paste source set to {}", source.name()));
+                }
+                None => self.set_status("Paste source must be clipboard or primary"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set default_section=") {
+            // Choose the section :a adds to
+            match DefaultSection::by_name(value.trim()) {
+                Some(section) => {
+                    self.default_section = section;
+                    self.set_status(&format!("Default section set to {}", section.name()));
+                }
+                None => self.set_status("Default section must be inside or outside"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set inside_order=") {
+            // Show INSIDE cards newest first or as in the file; the file is not reordered
+            match InsideOrder::by_name(value.trim()) {
+                Some(order) => {
+                    let selected = self.selected_original_index();
+                    self.inside_order = order;
+                    self.convert_json();
+                    if let Some(idx) = self.relf_entries.iter().position(|entry| Some(entry.original_index) == selected) {
+                        self.selected_entry_index = idx;
+                    }
+                    self.set_status(&format!("INSIDE order set to {}", order.name()));
+                }
+                None => self.set_status("INSIDE order must be newest_first or file_order"),
+            }
+        } else if let Some(value) = cmd.strip_prefix("set lang=") {
+            // Interface language; auto follows LANG
+            match Lang::by_name(value) {
+                Some(lang) => {
+                    self.lang = lang.resolve();
+                    self.set_status(&self.msg_with(Msg::LangSet, &[&self.lang.name()]));
+                }
+                None => self.set_status("Language must be en, ja or auto"),
+            }
+        } else if cmd == "set autosave" || cmd == "set autosave?" {
+            self.set_status(&format!("autosave={}", self.autosave.name()));
         } else if cmd.starts_with("colorscheme ") {
             // Change color scheme
             use super::ColorScheme;
@@ -322,6 +613,8 @@ impl App {
                 if let Some(highlighter) = &mut self.syntax_highlighter {
                     highlighter.update_colorscheme(scheme);
                 }
+                self.card_body_cache.clear();
+                self.edit_highlight_cache.clear();
                 self.set_status(&format!("Color scheme changed to {}", scheme_name));
             } else {
                 self.set_status(&format!("Unknown color scheme: {}", scheme_name));
@@ -350,6 +643,29 @@ impl App {
         } else if cmd == "json" {
             // Export current file to JSON format
             self.export_to_json();
+        } else if let Some(args) = cmd.strip_prefix("convert! ") {
+            // Convert to another format, replacing an existing target
+            self.convert_file(args, true);
+        } else if cmd == "convert" || cmd == "convert!" {
+            self.set_status("Usage: :convert md|json [path]");
+        } else if let Some(args) = cmd.strip_prefix("convert ") {
+            // Convert the open file to another format and reopen it
+            self.convert_file(args, false);
+        } else if cmd == "find" || cmd.starts_with("find ") {
+            // Fuzzy-find a file under the explorer's directory
+            self.open_finder(&cmd["find".len()..]);
+        } else if cmd == "stats" || cmd.starts_with("stats ") {
+            // Per-tag entry counts and average completion
+            self.show_stats(cmd["stats".len()..].trim());
+        } else if cmd == "sync" || cmd.starts_with("sync ") {
+            // Pull or push the saved file to the WebDAV folder in ~/.revwrc
+            self.sync_file(&cmd["sync".len()..]);
+        } else if cmd == "log" {
+            // Recent lines of the debug log
+            self.show_log();
+        } else if cmd == "report" {
+            // Copy a Markdown progress summary for a weekly review
+            self.copy_report();
         } else if cmd == "token" {
             // Show token count for all formats
             self.show_token_count();
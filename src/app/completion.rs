@@ -66,11 +66,12 @@ impl App {
         // Handle command name completion
         else {
             let commands = vec![
-                "w", "wq", "q", "e", "ai", "ao", "o", "op", "on", "dd", "yy",
-                "c", "ci", "co", "cj", "cm", "cu", "v", "vu", "vi", "vo", "va", "vai", "vao",
-                "xi", "xo", "gi", "go", "noh", "nof", "f", "cc", "ccj", "ccm", "dc",
-                "set", "colorscheme", "ar", "h", "a", "d", "m", "markdown", "json",
-                "Lexplore", "Lex", "lx", "outline", "ol", "token",
+                "w", "w!", "wq", "q", "e", "new", "ai", "ao", "o", "op", "on", "dd", "yy",
+                "c", "ci", "co", "cj", "cm", "cu", "v", "vu", "vup", "vi", "vo", "va", "vai", "vao",
+                "xi", "xo", "gi", "go", "attach", "select", "noh", "nohl", "nof", "f", "cc", "ccj", "ccm", "yank-template", "dc",
+                "set", "colorscheme", "ar", "reload", "reload!", "diff", "rotate", "h", "a", "d", "m", "markdown", "json",
+                "Lexplore", "Lex", "lx", "outline", "ol", "token", "snapshot", "snapshots", "progress",
+                "find", "log", "report", "stats", "sync", "priority", "due", "status", "done", "tag", "rename-tag", "set-url-domain", "autoname", "move", "convert", "recent",
             ];
 
             let mut matches: Vec<String> = commands.iter()
@@ -105,16 +106,12 @@ impl App {
             PathBuf::from(partial)
         };
 
-        let (dir, file_prefix) = if partial.ends_with('/') || partial.ends_with('\\') || partial.is_empty() {
-            (path_buf.clone(), String::new())
-        } else if path_buf.is_dir() {
+        let (dir, file_prefix) = if partial.ends_with('/') || partial.ends_with('\\') || partial.is_empty() || path_buf.is_dir() {
             (path_buf.clone(), String::new())
         } else {
-            let parent = path_buf.parent();
-            let dir = if parent.is_none() || parent == Some(std::path::Path::new("")) {
-                PathBuf::from(".")
-            } else {
-                parent.unwrap().to_path_buf()
+            let dir = match path_buf.parent() {
+                Some(parent) if parent != std::path::Path::new("") => parent.to_path_buf(),
+                _ => PathBuf::from("."),
             };
             let file_prefix = path_buf.file_name()
                 .and_then(|n| n.to_str())
@@ -128,7 +125,7 @@ impl App {
             Ok(entries) => {
             // Check if dir is current directory
             let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-            let is_current_dir = dir == PathBuf::from(".") || dir == current_dir;
+            let is_current_dir = dir.as_path() == std::path::Path::new(".") || dir == current_dir;
 
             let mut matches: Vec<String> = entries
                 .filter_map(|e| e.ok())
@@ -0,0 +1,174 @@
+use super::{App, FormatMode};
+use crate::i18n::Msg;
+use std::path::PathBuf;
+
+/// A destructive action held until the y/n prompt in the status bar is answered.
+/// Pastes hold the clipboard text counted for the prompt, so y pastes exactly that;
+/// `None` reads the clipboard when the paste runs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfirmAction {
+    /// `:dc` on the selected card or Visual selection
+    DeleteCards,
+    /// `[N]dd` / `:dd` in View mode, from the selected card on
+    DeleteCardsForward(usize),
+    ClearInside,
+    ClearOutside,
+    /// `:x`
+    ClearAll,
+    /// `:v`: the clipboard replaces the document
+    Paste(Option<String>),
+    PasteInside(Option<String>),
+    PasteOutside(Option<String>),
+    /// `:va`, `:vai`, `:vao`: only asked about past `paste_limit`
+    PasteAppend(Option<String>),
+    PasteAppendInside(Option<String>),
+    PasteAppendOutside(Option<String>),
+    /// A missing file given at startup: create it (from a skeleton) only on y
+    CreateFile(PathBuf),
+}
+
+impl ConfirmAction {
+    /// For clipboard pastes, the section they read (None for both)
+    fn paste_section(&self) -> Option<Option<&'static str>> {
+        match self {
+            ConfirmAction::Paste(_) | ConfirmAction::PasteAppend(_) => Some(None),
+            ConfirmAction::PasteInside(_) | ConfirmAction::PasteAppendInside(_) => Some(Some("inside")),
+            ConfirmAction::PasteOutside(_) | ConfirmAction::PasteAppendOutside(_) => Some(Some("outside")),
+            _ => None,
+        }
+    }
+
+    /// For clipboard pastes, the text to paste once confirmed
+    fn pasted_text(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            ConfirmAction::Paste(text)
+            | ConfirmAction::PasteInside(text)
+            | ConfirmAction::PasteOutside(text)
+            | ConfirmAction::PasteAppend(text)
+            | ConfirmAction::PasteAppendInside(text)
+            | ConfirmAction::PasteAppendOutside(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl App {
+    /// Whether the rc asks before `action`
+    fn needs_confirmation(&self, action: &ConfirmAction) -> bool {
+        match action {
+            ConfirmAction::DeleteCards | ConfirmAction::DeleteCardsForward(_) => self.confirm_delete,
+            ConfirmAction::ClearInside | ConfirmAction::ClearOutside | ConfirmAction::ClearAll => self.confirm_clear,
+            ConfirmAction::Paste(_) | ConfirmAction::PasteInside(_) | ConfirmAction::PasteOutside(_) => self.confirm_paste,
+            ConfirmAction::PasteAppend(_) | ConfirmAction::PasteAppendInside(_) | ConfirmAction::PasteAppendOutside(_) => false,
+            ConfirmAction::CreateFile(_) => true,
+        }
+    }
+
+    fn confirm_prompt(&self, action: &ConfirmAction) -> String {
+        let cards = |count: usize| format!("{} card{}", count, if count == 1 { "" } else { "s" });
+        match action {
+            ConfirmAction::DeleteCards => format!("Delete {}? (y/n)", cards(self.visual_selected_indices().len())),
+            ConfirmAction::DeleteCardsForward(count) => {
+                let left = self.relf_entries.len().saturating_sub(self.selected_entry_index);
+                format!("Delete {}? (y/n)", cards((*count).max(1).min(left)))
+            }
+            ConfirmAction::ClearInside => "Clear the INSIDE section? (y/n)".to_string(),
+            ConfirmAction::ClearOutside => "Clear the OUTSIDE section? (y/n)".to_string(),
+            ConfirmAction::ClearAll => "Clear all content? (y/n)".to_string(),
+            ConfirmAction::Paste(_) => "Replace the document with the clipboard? (y/n)".to_string(),
+            ConfirmAction::PasteInside(_) => "Replace INSIDE with the clipboard? (y/n)".to_string(),
+            ConfirmAction::PasteOutside(_) => "Replace OUTSIDE with the clipboard? (y/n)".to_string(),
+            ConfirmAction::PasteAppend(_) | ConfirmAction::PasteAppendInside(_) | ConfirmAction::PasteAppendOutside(_) => {
+                "Append the clipboard? (y/n)".to_string()
+            }
+            ConfirmAction::CreateFile(path) => {
+                let from = match self.skeleton_file(path) {
+                    Some(file) => file.display().to_string(),
+                    None => "empty sections".to_string(),
+                };
+                format!("{} does not exist. Create it from {}? (y/n)", path.display(), from)
+            }
+        }
+    }
+
+    /// The prompt for pasting `text` with `action` when it brings in more entries than
+    /// `paste_limit` (0 turns the check off)
+    pub fn paste_limit_prompt(&self, action: &ConfirmAction, text: &str) -> Option<String> {
+        let section = action.paste_section()?;
+        let count = self.pasted_entry_count(text, section).filter(|count| self.paste_limit > 0 && *count > self.paste_limit)?;
+        Some(format!("Paste {} entries (more than paste_limit={})? (y/n)", count, self.paste_limit))
+    }
+
+    /// Run `action`, or ask first when `set confirm_*` covers it or a paste brings in more
+    /// entries than `paste_limit`
+    pub fn confirm_action(&mut self, mut action: ConfirmAction) {
+        let nothing_to_delete = matches!(action, ConfirmAction::DeleteCards | ConfirmAction::DeleteCardsForward(_))
+            && (self.format_mode != FormatMode::View || self.relf_entries.is_empty());
+        let oversized = match action.paste_section() {
+            Some(_) if self.paste_limit > 0 => match self.clipboard_get_text() {
+                Ok(text) => {
+                    let prompt = self.paste_limit_prompt(&action, &text);
+                    if let Some(held) = action.pasted_text() {
+                        *held = Some(text);
+                    }
+                    prompt
+                }
+                Err(_) => None,
+            },
+            _ => None,
+        };
+        let prompt = match oversized {
+            Some(prompt) => prompt,
+            None if self.needs_confirmation(&action) && !nothing_to_delete => self.confirm_prompt(&action),
+            None => {
+                self.run_confirmed(action);
+                return;
+            }
+        };
+        self.confirm_pending = Some(action);
+        self.set_status(&prompt);
+    }
+
+    /// Answer the prompt: y runs the held action, anything else drops it
+    pub fn handle_confirmation(&mut self, answer: char) {
+        let Some(action) = self.confirm_pending.take() else {
+            return;
+        };
+        if answer == 'y' {
+            self.run_confirmed(action);
+        } else if let ConfirmAction::CreateFile(path) = &action {
+            self.set_status(&format!("Not created: {}", path.display()));
+        } else {
+            self.set_status("Cancelled");
+        }
+    }
+
+    fn run_confirmed(&mut self, action: ConfirmAction) {
+        match action {
+            ConfirmAction::DeleteCards => self.delete_cards(),
+            ConfirmAction::DeleteCardsForward(count) => self.delete_cards_forward(count),
+            ConfirmAction::ClearInside => self.clear_inside(),
+            ConfirmAction::ClearOutside => self.clear_outside(),
+            ConfirmAction::ClearAll => self.clear_content(),
+            ConfirmAction::Paste(text) => self.paste_held(text, Self::paste_text),
+            ConfirmAction::PasteInside(text) => self.paste_held(text, Self::paste_inside_overwrite_text),
+            ConfirmAction::PasteOutside(text) => self.paste_held(text, Self::paste_outside_overwrite_text),
+            ConfirmAction::PasteAppend(text) => self.paste_held(text, Self::paste_append_all_text),
+            ConfirmAction::PasteAppendInside(text) => self.paste_held(text, Self::paste_inside_append_text),
+            ConfirmAction::PasteAppendOutside(text) => self.paste_held(text, Self::paste_outside_append_text),
+            ConfirmAction::CreateFile(path) => self.create_note_file(&path),
+        }
+    }
+
+    /// Paste the text held by a confirmed action, or what the clipboard holds now
+    fn paste_held(&mut self, text: Option<String>, paste: fn(&mut App, String)) {
+        let text = match text {
+            Some(text) => Ok(text),
+            None => self.clipboard_get_text(),
+        };
+        match text {
+            Ok(text) => paste(self, text),
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+        }
+    }
+}
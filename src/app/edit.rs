@@ -1,6 +1,15 @@
 use super::{App, FormatMode};
+use crate::due;
+use crate::fields;
+use crate::graphemes;
+use crate::i18n::Msg;
+use crate::json_ops::JsonOperations;
+use crate::priority;
+use crate::sources;
+use crate::url_ops;
 use crate::wrap::layout_wrapped_text;
-use serde_json::Value;
+use chrono::Local;
+use serde_json::{Map, Value};
 
 impl App {
     pub fn set_overlay_viewport(&mut self, context_height: u16, context_width: u16, field_width: u16) {
@@ -9,6 +18,87 @@ impl App {
         self.overlay_field_width = field_width.max(1);
     }
 
+    /// JSON key of overlay field `idx` (also its placeholder text)
+    pub fn edit_field_key(&self, idx: usize) -> &str {
+        if let Some(key) = self.edit_field_keys.get(idx) {
+            return key;
+        }
+        // Buffers filled without keys hold only the standard fields
+        let standard = if self.edit_is_inside() { fields::INSIDE_FIELDS } else { fields::OUTSIDE_FIELDS };
+        standard.get(idx).copied().unwrap_or("")
+    }
+
+    /// Overlay cursor position one grapheme cluster to the left (see `graphemes`)
+    pub fn edit_prev_boundary(&self) -> usize {
+        let field = self.edit_buffer.get(self.edit_field_index).map_or("", String::as_str);
+        graphemes::prev_boundary(field, self.edit_cursor_pos)
+    }
+
+    /// Overlay cursor position one grapheme cluster to the right, at most the field's end
+    pub fn edit_next_boundary(&self) -> usize {
+        let field = self.edit_buffer.get(self.edit_field_index).map_or("", String::as_str);
+        graphemes::next_boundary(field, self.edit_cursor_pos)
+    }
+
+    /// True when the overlay is editing an INSIDE entry (date, context, ...)
+    pub fn edit_is_inside(&self) -> bool {
+        match self.edit_field_keys.first() {
+            Some(key) => key == "date",
+            None => self.edit_buffer.len() == 2,
+        }
+    }
+
+    /// Number of rows shown below the context in the overlay (priority, due and custom fields)
+    pub fn edit_extra_field_count(&self) -> usize {
+        let standard = if self.edit_is_inside() { 2 } else { 4 };
+        self.edit_buffer.len().saturating_sub(standard)
+    }
+
+    /// Append a row per custom field of `entry_obj` to the overlay buffers
+    fn push_custom_edit_fields(&mut self, entry_obj: &Map<String, Value>, section: &str) {
+        // The merged-view file label is kept as it is
+        for (key, value) in fields::custom_fields(entry_obj, section).into_iter().filter(|(key, _)| key.as_str() != sources::KEY) {
+            let text = fields::value_to_text(value);
+            let is_empty = text.is_empty() || value.is_null();
+            self.edit_buffer.push(if is_empty { key.clone() } else { text });
+            self.edit_buffer_is_placeholder.push(is_empty);
+            self.edit_field_keys.push(key.clone());
+        }
+    }
+
+    /// Write overlay priority, due and custom field rows back into `entry_obj`
+    fn store_custom_edit_fields(&self, entry_obj: &mut Map<String, Value>, standard: usize) {
+        for idx in standard..self.edit_buffer.len() {
+            let key = self.edit_field_key(idx).to_string();
+            let is_placeholder = self.edit_buffer_is_placeholder.get(idx).copied().unwrap_or(false);
+            if key == "priority" {
+                // Optional: an empty row removes it, unknown values are kept as text
+                let text = self.edit_buffer[idx].trim();
+                if is_placeholder || text.is_empty() {
+                    entry_obj.remove("priority");
+                } else {
+                    let value = priority::value_from_text(text).unwrap_or_else(|| Value::String(text.to_string()));
+                    entry_obj.insert(key, value);
+                }
+                continue;
+            }
+            if key == "due" {
+                // Optional: `today`, `tomorrow` and `+3d` become dates, unknown text is kept as typed
+                let text = self.edit_buffer[idx].trim();
+                if is_placeholder || text.is_empty() {
+                    entry_obj.remove("due");
+                } else {
+                    let date = due::parse_input(text, Local::now().date_naive())
+                        .map_or_else(|| text.to_string(), |date| date.format(due::FORMAT).to_string());
+                    entry_obj.insert(key, Value::String(date));
+                }
+                continue;
+            }
+            let value = if is_placeholder { Value::Null } else { fields::text_to_value(&self.edit_buffer[idx]) };
+            entry_obj.insert(key, value);
+        }
+    }
+
     pub fn open_entry_overlay(&mut self) {
         self.start_editing_entry();
     }
@@ -20,88 +110,101 @@ impl App {
         } else {
             return; // Invalid selection
         };
+        self.build_edit_completion_index();
 
         // Load fields from JSON (not from rendered lines) to include empty fields
-        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-            if let Some(obj) = json_value.as_object() {
-                let mut current_idx = 0;
-
-                // Check outside section
-                if let Some(outside) = obj.get("outside") {
-                    if let Some(outside_array) = outside.as_array() {
-                        if target_idx < current_idx + outside_array.len() {
-                            let local_idx = target_idx - current_idx;
-                            if let Some(entry_obj) = outside_array[local_idx].as_object() {
-                                // Load all fields including empty ones, use placeholder if empty
-                                let name = entry_obj.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                                let context = entry_obj.get("context").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                                let url = entry_obj.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                                let percentage = entry_obj.get("percentage").and_then(|v| v.as_i64());
-
-                                let name_is_empty = name.is_empty();
-                                let context_is_empty = context.is_empty();
-                                let url_is_empty = url.is_empty();
-
-                                self.edit_buffer = vec![
-                                    if name_is_empty { "name".to_string() } else { name },
-                                    if context_is_empty { "context".to_string() } else { context },
-                                    if url_is_empty { "url".to_string() } else { url },
-                                    if let Some(pct) = percentage { pct.to_string() } else { "percentage".to_string() },
-                                ];
-                                self.edit_buffer_is_placeholder = vec![
-                                    name_is_empty,
-                                    context_is_empty,
-                                    url_is_empty,
-                                    percentage.is_none(),
-                                ];
-                                self.edit_field_index = 0;
-                                self.editing_entry = true;
-                                self.edit_field_editing_mode = false;
-                                self.edit_insert_mode = false;
-                                self.edit_cursor_pos = 0;
-                                return;
-                            }
-                        }
-                        current_idx += outside_array.len();
+        if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+            && let Some(obj) = json_value.as_object() {
+            let mut current_idx = 0;
+
+            // Check outside section
+            if let Some(outside) = obj.get("outside")
+                && let Some(outside_array) = outside.as_array() {
+                if target_idx < current_idx + outside_array.len() {
+                    let local_idx = target_idx - current_idx;
+                    if let Some(entry_obj) = outside_array[local_idx].as_object() {
+                        // Load all fields including empty ones, use placeholder if empty
+                        let name = entry_obj.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                        let context = entry_obj.get("context").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                        let url = entry_obj.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                        let percentage = entry_obj.get("percentage").and_then(|v| v.as_i64());
+                        let priority = entry_obj.get("priority").filter(|v| !v.is_null()).map(fields::display_value);
+                        let due = entry_obj.get("due").filter(|v| !v.is_null()).map(fields::display_value);
+
+                        let name_is_empty = name.is_empty();
+                        let context_is_empty = context.is_empty();
+                        let url_is_empty = url.is_empty();
+
+                        self.edit_buffer = vec![
+                            if name_is_empty { "name".to_string() } else { name },
+                            if context_is_empty { "context".to_string() } else { context },
+                            if url_is_empty { "url".to_string() } else { url },
+                            if let Some(pct) = percentage { pct.to_string() } else { "percentage".to_string() },
+                            priority.clone().unwrap_or_else(|| "priority".to_string()),
+                            due.clone().unwrap_or_else(|| "due".to_string()),
+                        ];
+                        self.edit_buffer_is_placeholder = vec![
+                            name_is_empty,
+                            context_is_empty,
+                            url_is_empty,
+                            percentage.is_none(),
+                            priority.is_none(),
+                            due.is_none(),
+                        ];
+                        self.edit_field_keys = ["name", "context", "url", "percentage", "priority", "due"]
+                            .iter()
+                            .map(|k| k.to_string())
+                            .collect();
+                        self.push_custom_edit_fields(entry_obj, "outside");
+                        self.edit_field_index = 0;
+                        self.editing_entry = true;
+                        self.edit_field_editing_mode = false;
+                        self.edit_insert_mode = false;
+                        self.edit_cursor_pos = 0;
+                        return;
                     }
                 }
+                current_idx += outside_array.len();
+            }
 
-                // Check inside section
-                if let Some(inside) = obj.get("inside") {
-                    if let Some(inside_array) = inside.as_array() {
-                        if target_idx < current_idx + inside_array.len() {
-                            let local_idx = target_idx - current_idx;
-                            if let Some(entry_obj) = inside_array[local_idx].as_object() {
-                                // Load all fields including empty ones, use placeholder if empty
-                                let date = entry_obj.get("date").and_then(|v| v.as_str()).unwrap_or("").to_string();
-                                let context = entry_obj.get("context").and_then(|v| v.as_str()).unwrap_or("").to_string();
-
-                                let date_is_empty = date.is_empty();
-                                let context_is_empty = context.is_empty();
-
-                                self.edit_buffer = vec![
-                                    if date_is_empty { "date".to_string() } else { date },
-                                    if context_is_empty { "context".to_string() } else { context },
-                                ];
-                                self.edit_buffer_is_placeholder = vec![
-                                    date_is_empty,
-                                    context_is_empty,
-                                ];
-                                self.edit_field_index = 0;
-                                self.editing_entry = true;
-                                self.edit_field_editing_mode = false;
-                                self.edit_insert_mode = false;
-                                self.edit_cursor_pos = 0;
-                                return;
-                            }
-                        }
-                    }
+            // Check inside section
+            if let Some(inside) = obj.get("inside")
+                && let Some(inside_array) = inside.as_array()
+                    && target_idx < current_idx + inside_array.len() {
+                let local_idx = target_idx - current_idx;
+                if self.journal_refuses(&inside_array[local_idx]) {
+                    return;
+                }
+                if let Some(entry_obj) = inside_array[local_idx].as_object() {
+                    // Load all fields including empty ones, use placeholder if empty
+                    let date = entry_obj.get("date").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                    let context = entry_obj.get("context").and_then(|v| v.as_str()).unwrap_or("").to_string();
+
+                    let date_is_empty = date.is_empty();
+                    let context_is_empty = context.is_empty();
+
+                    self.edit_buffer = vec![
+                        if date_is_empty { "date".to_string() } else { date },
+                        if context_is_empty { "context".to_string() } else { context },
+                    ];
+                    self.edit_buffer_is_placeholder = vec![
+                        date_is_empty,
+                        context_is_empty,
+                    ];
+                    self.edit_field_keys = vec!["date".to_string(), "context".to_string()];
+                    self.push_custom_edit_fields(entry_obj, "inside");
+                    self.edit_field_index = 0;
+                    self.editing_entry = true;
+                    self.edit_field_editing_mode = false;
+                    self.edit_insert_mode = false;
+                    self.edit_cursor_pos = 0;
                 }
             }
         }
     }
 
     pub fn save_edited_entry(&mut self) {
+        self.editing_new_entry = None;
         // Save the edited entry back to JSON
         if self.edit_buffer.is_empty() {
             self.editing_entry = false;
@@ -121,74 +224,82 @@ impl App {
                 if let Some(obj) = json_value.as_object_mut() {
                     let mut current_idx = 0;
                     let mut found = false;
+                    let mut url_warning = None;
 
                     // Check outside section
-                    if let Some(outside) = obj.get_mut("outside") {
-                        if let Some(outside_array) = outside.as_array_mut() {
-                            if target_idx < current_idx + outside_array.len() {
-                                let local_idx = target_idx - current_idx;
-                                if let Some(entry_obj) = outside_array[local_idx].as_object_mut() {
-                                    // Update fields - use placeholder flags to determine if value is placeholder
-                                    if self.edit_buffer.len() >= 1 && self.edit_buffer_is_placeholder.len() >= 1 {
-                                        let name_val = &self.edit_buffer[0];
-                                        let is_placeholder = self.edit_buffer_is_placeholder[0];
-                                        entry_obj.insert("name".to_string(),
-                                            Value::String(if is_placeholder { String::new() } else { name_val.clone() }));
-                                    }
-                                    if self.edit_buffer.len() >= 2 && self.edit_buffer_is_placeholder.len() >= 2 {
-                                        let context_val = &self.edit_buffer[1];
-                                        let is_placeholder = self.edit_buffer_is_placeholder[1];
-                                        entry_obj.insert("context".to_string(),
-                                            Value::String(if is_placeholder { String::new() } else { context_val.clone() }));
-                                    }
-                                    if self.edit_buffer.len() >= 3 && self.edit_buffer_is_placeholder.len() >= 3 {
-                                        let url_val = &self.edit_buffer[2];
-                                        let is_placeholder = self.edit_buffer_is_placeholder[2];
-                                        entry_obj.insert("url".to_string(),
-                                            Value::String(if is_placeholder { String::new() } else { url_val.clone() }));
+                    if let Some(outside) = obj.get_mut("outside")
+                        && let Some(outside_array) = outside.as_array_mut() {
+                        if target_idx < current_idx + outside_array.len() {
+                            let local_idx = target_idx - current_idx;
+                            if let Some(entry_obj) = outside_array[local_idx].as_object_mut() {
+                                // Update fields - use placeholder flags to determine if value is placeholder
+                                if !self.edit_buffer.is_empty() && !self.edit_buffer_is_placeholder.is_empty() {
+                                    let name_val = &self.edit_buffer[0];
+                                    let is_placeholder = self.edit_buffer_is_placeholder[0];
+                                    entry_obj.insert("name".to_string(),
+                                        Value::String(if is_placeholder { String::new() } else { name_val.clone() }));
+                                }
+                                if self.edit_buffer.len() >= 2 && self.edit_buffer_is_placeholder.len() >= 2 {
+                                    let context_val = &self.edit_buffer[1];
+                                    let is_placeholder = self.edit_buffer_is_placeholder[1];
+                                    entry_obj.insert("context".to_string(),
+                                        Value::String(if is_placeholder { String::new() } else { context_val.clone() }));
+                                }
+                                if self.edit_buffer.len() >= 3 && self.edit_buffer_is_placeholder.len() >= 3 {
+                                    let is_placeholder = self.edit_buffer_is_placeholder[2];
+                                    let (url_val, valid) = if is_placeholder {
+                                        (String::new(), true)
+                                    } else {
+                                        url_ops::normalize(&self.edit_buffer[2], &self.url_rules)
+                                    };
+                                    if !valid {
+                                        url_warning = Some(format!("Entry updated; malformed URL \"{}\"", url_val));
                                     }
-                                    if self.edit_buffer.len() >= 4 && self.edit_buffer_is_placeholder.len() >= 4 {
-                                        // Parse percentage - save null if placeholder
-                                        let pct_val = &self.edit_buffer[3];
-                                        let is_placeholder = self.edit_buffer_is_placeholder[3];
-                                        if is_placeholder {
-                                            entry_obj.insert("percentage".to_string(), Value::Null);
-                                        } else if let Ok(pct) = pct_val.trim_end_matches('%').parse::<i64>() {
-                                            entry_obj.insert("percentage".to_string(), Value::Number(pct.into()));
-                                        }
+                                    entry_obj.insert("url".to_string(), Value::String(url_val));
+                                }
+                                if self.edit_buffer.len() >= 4 && self.edit_buffer_is_placeholder.len() >= 4 {
+                                    // Parse percentage - save null if placeholder
+                                    let pct_val = &self.edit_buffer[3];
+                                    let is_placeholder = self.edit_buffer_is_placeholder[3];
+                                    if is_placeholder {
+                                        entry_obj.insert("percentage".to_string(), Value::Null);
+                                    } else if let Ok(pct) = pct_val.trim_end_matches('%').parse::<i64>() {
+                                        entry_obj.insert("percentage".to_string(), Value::Number(pct.into()));
                                     }
-                                    found = true;
                                 }
-                            } else {
-                                current_idx += outside_array.len();
+                                self.store_custom_edit_fields(entry_obj, 4);
+                                if self.checkbox_percentage {
+                                    JsonOperations::apply_checkbox_percentage(entry_obj);
+                                }
+                                found = true;
                             }
+                        } else {
+                            current_idx += outside_array.len();
                         }
                     }
 
                     // Check inside section
-                    if !found {
-                        if let Some(inside) = obj.get_mut("inside") {
-                            if let Some(inside_array) = inside.as_array_mut() {
-                                let local_idx = target_idx - current_idx;
-                                if local_idx < inside_array.len() {
-                                    if let Some(entry_obj) = inside_array[local_idx].as_object_mut() {
-                                        // Update fields (date and context for inside) - use placeholder flags
-                                        if self.edit_buffer.len() >= 1 && self.edit_buffer_is_placeholder.len() >= 1 {
-                                            let date_val = &self.edit_buffer[0];
-                                            let is_placeholder = self.edit_buffer_is_placeholder[0];
-                                            entry_obj.insert("date".to_string(),
-                                                Value::String(if is_placeholder { String::new() } else { date_val.clone() }));
-                                        }
-                                        if self.edit_buffer.len() >= 2 && self.edit_buffer_is_placeholder.len() >= 2 {
-                                            let context_val = &self.edit_buffer[1];
-                                            let is_placeholder = self.edit_buffer_is_placeholder[1];
-                                            entry_obj.insert("context".to_string(),
-                                                Value::String(if is_placeholder { String::new() } else { context_val.clone() }));
-                                        }
-                                        found = true;
-                                    }
-                                }
+                    if !found
+                        && let Some(inside) = obj.get_mut("inside")
+                            && let Some(inside_array) = inside.as_array_mut() {
+                        let local_idx = target_idx - current_idx;
+                        if local_idx < inside_array.len()
+                            && let Some(entry_obj) = inside_array[local_idx].as_object_mut() {
+                            // Update fields (date and context for inside) - use placeholder flags
+                            if !self.edit_buffer.is_empty() && !self.edit_buffer_is_placeholder.is_empty() {
+                                let date_val = &self.edit_buffer[0];
+                                let is_placeholder = self.edit_buffer_is_placeholder[0];
+                                entry_obj.insert("date".to_string(),
+                                    Value::String(if is_placeholder { String::new() } else { date_val.clone() }));
+                            }
+                            if self.edit_buffer.len() >= 2 && self.edit_buffer_is_placeholder.len() >= 2 {
+                                let context_val = &self.edit_buffer[1];
+                                let is_placeholder = self.edit_buffer_is_placeholder[1];
+                                entry_obj.insert("context".to_string(),
+                                    Value::String(if is_placeholder { String::new() } else { context_val.clone() }));
                             }
+                            self.store_custom_edit_fields(entry_obj, 2);
+                            found = true;
                         }
                     }
 
@@ -211,16 +322,16 @@ impl App {
 
                                 self.is_modified = true;
                                 self.convert_json();
-                                self.set_status("Entry updated");
+                                self.set_status(url_warning.as_deref().unwrap_or("Entry updated"));
                                 // Auto-save after editing
-                                self.save_file();
+                                self.autosave();
                             }
                             Err(e) => self.set_status(&format!("Error formatting JSON: {}", e)),
                         }
                     }
                 }
             }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::InvalidJson, &[&e])),
         }
 
         self.editing_entry = false;
@@ -230,6 +341,7 @@ impl App {
         self.editing_entry = false;
         self.edit_buffer.clear();
         self.edit_buffer_is_placeholder.clear();
+        self.edit_field_keys.clear();
         self.edit_field_index = 0;
         self.edit_insert_mode = false;
         self.edit_cursor_pos = 0;
@@ -238,6 +350,13 @@ impl App {
         self.view_edit_mode = false;
         self.edit_field_editing_mode = false;
         self.edit_skip_normal_mode = false;
+        if let Some(previous_selection) = self.editing_new_entry.take() {
+            // The card was inserted with o / O just for this overlay; take it out again
+            self.undo();
+            self.redo_stack.pop();
+            self.selected_entry_index = previous_selection.min(self.relf_entries.len().saturating_sub(1));
+            self.set_status("New entry discarded");
+        }
     }
 
     pub fn insert_char(&mut self, c: char) {
@@ -338,12 +457,12 @@ impl App {
                 return;
             }
             if self.content_cursor_col > 0 && self.content_cursor_line < lines.len() {
-                // Remove character before cursor (handle multi-byte chars)
-                let mut chars: Vec<char> = lines[self.content_cursor_line].chars().collect();
-                if self.content_cursor_col > 0 && self.content_cursor_col <= chars.len() {
-                    chars.remove(self.content_cursor_col - 1);
-                    lines[self.content_cursor_line] = chars.into_iter().collect();
-                    self.content_cursor_col -= 1;
+                // Remove the grapheme cluster before the cursor (an emoji or accented letter)
+                let line = &lines[self.content_cursor_line];
+                if self.content_cursor_col <= line.chars().count() {
+                    let start = graphemes::prev_boundary(line, self.content_cursor_col);
+                    lines[self.content_cursor_line] = graphemes::remove_chars(line, start, self.content_cursor_col);
+                    self.content_cursor_col = start;
                     self.set_content_from_lines(lines);
                 }
             } else if self.content_cursor_col == 0 && self.content_cursor_line > 0 {
@@ -368,10 +487,10 @@ impl App {
                 return;
             }
             if self.content_cursor_line < lines.len() {
-                let mut chars: Vec<char> = lines[self.content_cursor_line].chars().collect();
-                if self.content_cursor_col < chars.len() {
-                    chars.remove(self.content_cursor_col);
-                    lines[self.content_cursor_line] = chars.into_iter().collect();
+                let line = &lines[self.content_cursor_line];
+                if self.content_cursor_col < line.chars().count() {
+                    let end = graphemes::next_boundary(line, self.content_cursor_col);
+                    lines[self.content_cursor_line] = graphemes::remove_chars(line, self.content_cursor_col, end);
                     self.set_content_from_lines(lines);
                 } else if self.content_cursor_line + 1 < lines.len() {
                     // Join with next line
@@ -450,7 +569,9 @@ impl App {
 
     pub fn move_cursor_left(&mut self) {
         if self.content_cursor_col > 0 {
-            self.content_cursor_col -= 1;
+            let lines = self.get_content_lines();
+            let line = lines.get(self.content_cursor_line).map_or("", String::as_str);
+            self.content_cursor_col = graphemes::prev_boundary(line, self.content_cursor_col);
         } else if self.content_cursor_line > 0 {
             self.content_cursor_line -= 1;
             let lines = self.get_content_lines();
@@ -469,7 +590,7 @@ impl App {
         if self.content_cursor_line < lines.len() {
             let line_len = lines[self.content_cursor_line].chars().count();
             if self.content_cursor_col < line_len {
-                self.content_cursor_col += 1;
+                self.content_cursor_col = graphemes::next_boundary(&lines[self.content_cursor_line], self.content_cursor_col);
             } else if self.content_cursor_line + 1 < lines.len() {
                 self.content_cursor_line += 1;
                 self.content_cursor_col = 0;
@@ -483,14 +604,12 @@ impl App {
             // Move by visual (wrapped) rows using overlay_context
             let lines = self.get_content_lines();
             if lines.is_empty() { return; }
-            let content = lines.join("\n");
-            let flat_pos = Self::cursor_to_flat(&lines, self.content_cursor_line, self.content_cursor_col);
+            let cursor = (self.content_cursor_line, self.content_cursor_col);
             let wrap_width = self.get_edit_wrap_width().max(1);
-            let new_pos = crate::wrap::move_cursor_vertical(&content, flat_pos, wrap_width, -1);
-            if new_pos != flat_pos {
-                let (nl, nc) = Self::flat_to_cursor(&lines, new_pos);
-                self.content_cursor_line = nl;
-                self.content_cursor_col = nc;
+            let new_cursor =
+                crate::wrap::move_cursor_vertical_in_lines(&lines, cursor.0, cursor.1, wrap_width, -1);
+            if new_cursor != cursor {
+                (self.content_cursor_line, self.content_cursor_col) = new_cursor;
             }
         } else {
             if self.content_cursor_line > 0 {
@@ -510,14 +629,12 @@ impl App {
             // Move by visual (wrapped) rows using overlay_context
             let lines = self.get_content_lines();
             if lines.is_empty() { return; }
-            let content = lines.join("\n");
-            let flat_pos = Self::cursor_to_flat(&lines, self.content_cursor_line, self.content_cursor_col);
+            let cursor = (self.content_cursor_line, self.content_cursor_col);
             let wrap_width = self.get_edit_wrap_width().max(1);
-            let new_pos = crate::wrap::move_cursor_vertical(&content, flat_pos, wrap_width, 1);
-            if new_pos != flat_pos {
-                let (nl, nc) = Self::flat_to_cursor(&lines, new_pos);
-                self.content_cursor_line = nl;
-                self.content_cursor_col = nc;
+            let new_cursor =
+                crate::wrap::move_cursor_vertical_in_lines(&lines, cursor.0, cursor.1, wrap_width, 1);
+            if new_cursor != cursor {
+                (self.content_cursor_line, self.content_cursor_col) = new_cursor;
             } else {
                 // Already at last visual row - scroll screen
                 if self.scroll < self.max_scroll {
@@ -583,17 +700,16 @@ impl App {
                 if self.format_mode == FormatMode::View {
                     // New inside entry is added at the beginning of inside array
                     // Index = outside.length (start of INSIDE section)
-                    if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                        if let Some(obj) = json_value.as_object() {
-                            let outside_count = obj
-                                .get("outside")
-                                .and_then(|v| v.as_array())
-                                .map(|arr| arr.len())
-                                .unwrap_or(0);
-                            // INSIDE section starts right after OUTSIDE
-                            self.selected_entry_index = outside_count;
-                            self.scroll = 0;
-                        }
+                    if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                        && let Some(obj) = json_value.as_object() {
+                        let outside_count = obj
+                            .get("outside")
+                            .and_then(|v| v.as_array())
+                            .map(|arr| arr.len())
+                            .unwrap_or(0);
+                        // INSIDE section starts right after OUTSIDE
+                        self.selected_entry_index = outside_count;
+                        self.scroll = 0;
                     }
                 } else if self.format_mode == FormatMode::Edit {
                     self.content_cursor_line = line;
@@ -602,7 +718,7 @@ impl App {
                 }
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -637,17 +753,16 @@ impl App {
                 if self.format_mode == FormatMode::View {
                     // New outside entry is added at the end of outside array
                     // Index = outside.length - 1 (last OUTSIDE entry)
-                    if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                        if let Some(obj) = json_value.as_object() {
-                            let outside_count = obj
-                                .get("outside")
-                                .and_then(|v| v.as_array())
-                                .map(|arr| arr.len())
-                                .unwrap_or(0);
-                            // Last outside entry
-                            self.selected_entry_index = outside_count.saturating_sub(1);
-                            self.scroll = 0;
-                        }
+                    if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                        && let Some(obj) = json_value.as_object() {
+                        let outside_count = obj
+                            .get("outside")
+                            .and_then(|v| v.as_array())
+                            .map(|arr| arr.len())
+                            .unwrap_or(0);
+                        // Last outside entry
+                        self.selected_entry_index = outside_count.saturating_sub(1);
+                        self.scroll = 0;
                     }
                 } else if self.format_mode == FormatMode::Edit {
                     self.content_cursor_line = line;
@@ -656,7 +771,7 @@ impl App {
                 }
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -697,12 +812,15 @@ impl App {
 
         // Vertical scrolling - Edit mode uses visual (wrapped) row for the cursor position
         let (cursor_line, total_rows) = if self.format_mode == FormatMode::Edit {
-            let lines_ref = self.get_content_lines();
-            let content = lines_ref.join("\n");
-            let flat_pos = Self::cursor_to_flat(&lines_ref, self.content_cursor_line, self.content_cursor_col);
+            // Only the cursor line is laid out; rows above it come from the memoized counts
             let wrap_width = self.get_edit_wrap_width().max(1);
-            let layout = crate::wrap::layout_wrapped_text(&content, flat_pos, wrap_width);
-            (layout.cursor.visual_row as u16, layout.rows.len())
+            let counts = crate::wrap::row_counts(&lines, wrap_width, &mut self.edit_wrap_cache);
+            let cursor_line = self.content_cursor_line;
+            let row_in_line = lines.get(cursor_line).map_or(0, |line| {
+                layout_wrapped_text(line, self.content_cursor_col, wrap_width).cursor.visual_row
+            });
+            let rows_above: usize = counts[..cursor_line].iter().sum();
+            ((rows_above + row_in_line) as u16, counts.iter().sum())
         } else {
             (self.calculate_cursor_visual_position().0, content_lines)
         };
@@ -756,13 +874,11 @@ impl App {
                 self.convert_json();
 
                 // Auto-save in view mode
-                if self.format_mode == FormatMode::View {
-                    self.save_file();
-                }
+                self.autosave();
 
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -794,13 +910,11 @@ impl App {
                 self.convert_json();
 
                 // Auto-save in view mode
-                if self.format_mode == FormatMode::View {
-                    self.save_file();
-                }
+                self.autosave();
 
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -832,13 +946,83 @@ impl App {
                 self.convert_json();
 
                 // Auto-save in view mode
-                if self.format_mode == FormatMode::View {
-                    self.save_file();
+                self.autosave();
+
+                self.set_status(&message);
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
+        }
+    }
+
+    pub fn order_by_priority(&mut self) {
+        let ops = self.get_operations();
+        let content = if self.is_markdown_file() && !self.markdown_input.is_empty() {
+            &self.markdown_input
+        } else {
+            &self.json_input
+        };
+
+        match ops.order_by_priority(content) {
+            Ok((formatted, message)) => {
+                if self.is_markdown_file() {
+                    self.markdown_input = formatted;
+                    match self.parse_markdown(&self.markdown_input) {
+                        Ok(json_content) => {
+                            self.json_input = json_content;
+                        }
+                        Err(e) => {
+                            eprintln!("Warning: Parse error: {}", e);
+                        }
+                    }
+                } else {
+                    self.json_input = formatted;
+                }
+
+                self.is_modified = true;
+                self.convert_json();
+
+                // Auto-save in view mode
+                self.autosave();
+
+                self.set_status(&message);
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
+        }
+    }
+
+    pub fn order_by_due(&mut self) {
+        let ops = self.get_operations();
+        let content = if self.is_markdown_file() && !self.markdown_input.is_empty() {
+            &self.markdown_input
+        } else {
+            &self.json_input
+        };
+
+        match ops.order_by_due(content) {
+            Ok((formatted, message)) => {
+                if self.is_markdown_file() {
+                    self.markdown_input = formatted;
+                    match self.parse_markdown(&self.markdown_input) {
+                        Ok(json_content) => {
+                            self.json_input = json_content;
+                        }
+                        Err(e) => {
+                            eprintln!("Warning: Parse error: {}", e);
+                        }
+                    }
+                } else {
+                    self.json_input = formatted;
                 }
 
+                self.is_modified = true;
+                self.convert_json();
+
+                // Auto-save in view mode
+                self.autosave();
+
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -870,13 +1054,34 @@ impl App {
                 self.convert_json();
 
                 // Auto-save in view mode
-                if self.format_mode == FormatMode::View {
-                    self.save_file();
-                }
+                self.autosave();
 
                 self.set_status(&message);
             }
-            Err(e) => self.set_status(&format!("Error: {}", e)),
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
+        }
+    }
+
+    /// Set OUTSIDE percentages from the task checkboxes in their contexts (:progress)
+    pub fn update_checkbox_percentages(&mut self) {
+        match JsonOperations::apply_checkbox_percentages(&self.json_input) {
+            Ok((_, 0)) => self.set_status("No percentages to update from checkboxes"),
+            Ok((formatted, updated)) => {
+                self.save_undo_state();
+                self.json_input = formatted;
+                if self.is_markdown_file() {
+                    match self.convert_to_markdown() {
+                        Ok(md_content) => self.markdown_input = md_content,
+                        Err(e) => eprintln!("Warning: Failed to convert to markdown: {}", e),
+                    }
+                }
+
+                self.is_modified = true;
+                self.convert_json();
+                self.autosave();
+                self.set_status(&format!("Updated {} percentages from checkboxes", updated));
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
         }
     }
 
@@ -894,8 +1099,7 @@ impl App {
         let cursor_pos = self.edit_cursor_pos;
 
         // Check if this is context field (index 1 in both INSIDE and OUTSIDE)
-        let is_context_field = (self.edit_buffer.len() == 2 && self.edit_field_index == 1) ||
-                               (self.edit_buffer.len() == 4 && self.edit_field_index == 1);
+        let is_context_field = self.edit_field_key(self.edit_field_index) == "context";
 
         if is_context_field && self.view_edit_mode {
             let layout = layout_wrapped_text(field, cursor_pos, self.overlay_context_width as usize);
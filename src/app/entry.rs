@@ -0,0 +1,238 @@
+use super::{App, FormatMode};
+use crate::i18n::Msg;
+use crate::json_ops::JsonOperations;
+use serde_json::{Map, Value};
+
+impl App {
+    /// Original JSON index of the selected card (accounts for filtering)
+    pub fn selected_original_index(&self) -> Option<usize> {
+        self.relf_entries
+            .get(self.selected_entry_index)
+            .map(|entry| entry.original_index)
+    }
+
+    /// True if card `idx` is part of the Visual mode selection
+    pub fn is_card_visually_selected(&self, idx: usize) -> bool {
+        if !self.visual_mode {
+            return false;
+        }
+        let start = self.visual_start_index.min(self.visual_end_index);
+        let end = self.visual_start_index.max(self.visual_end_index);
+        (start..=end).contains(&idx) != self.visual_inverted
+    }
+
+    /// Card indices targeted by bulk operations: the Visual selection, or the selected card
+    pub fn visual_selected_indices(&self) -> Vec<usize> {
+        if !self.visual_mode {
+            return vec![self.selected_entry_index];
+        }
+        (0..self.relf_entries.len())
+            .filter(|&idx| self.is_card_visually_selected(idx))
+            .collect()
+    }
+
+    /// :select all - select every card in Visual mode
+    pub fn select_all_cards(&mut self) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        self.visual_mode = true;
+        self.visual_inverted = false;
+        self.visual_start_index = 0;
+        self.visual_end_index = self.relf_entries.len() - 1;
+        self.selected_entry_index = self.visual_end_index;
+        self.set_status(&format!("-- VISUAL -- {} selected", self.relf_entries.len()));
+    }
+
+    /// :select invert - swap selected and unselected cards (enters Visual mode on the current card)
+    pub fn invert_card_selection(&mut self) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        if self.visual_mode {
+            self.visual_inverted = !self.visual_inverted;
+        } else {
+            self.visual_mode = true;
+            self.visual_inverted = true;
+            self.visual_start_index = self.selected_entry_index;
+            self.visual_end_index = self.selected_entry_index;
+        }
+        let count = self.visual_selected_indices().len();
+        self.set_status(&format!("-- VISUAL -- {} selected", count));
+    }
+
+    /// o / O in View mode - insert a blank card after / before the selected one
+    /// (in the same section) and open the edit overlay on it
+    pub fn insert_card(&mut self, after: bool) {
+        if self.format_mode != FormatMode::View {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let previous_selection = self.selected_entry_index;
+        let (formatted, new_index) =
+            match JsonOperations::insert_entry(&self.json_input, self.selected_original_index(), after) {
+                Ok(result) => result,
+                Err(e) => {
+                    self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                    return;
+                }
+            };
+
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        // A blank card never matches a filter; drop it so the new card can be shown
+        if !self.relf_entries.iter().any(|entry| entry.original_index == new_index) {
+            self.clear_filter();
+        }
+        let Some(idx) = self.relf_entries.iter().position(|entry| entry.original_index == new_index) else {
+            self.set_status("Could not find the new entry");
+            return;
+        };
+        self.selected_entry_index = idx;
+        self.start_editing_entry();
+        self.editing_new_entry = Some(previous_selection);
+    }
+
+    /// :a TEXT - add an entry to the default section without opening the overlay
+    pub fn quick_add(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            self.set_status("Usage: :a TEXT");
+            return;
+        }
+        let section = self.default_section.name();
+        let (formatted, new_index) = match JsonOperations::quick_add_entry(&self.json_input, section, text) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                return;
+            }
+        };
+
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        if let Some(idx) = self.relf_entries.iter().position(|entry| entry.original_index == new_index) {
+            self.selected_entry_index = idx;
+        }
+        self.autosave();
+        self.set_status(&format!("Added to {}", section.to_uppercase()));
+    }
+
+    /// [count]dd in View mode - delete the selected card and the ones after it, keeping them
+    /// in the card register for p / P
+    pub fn delete_cards_forward(&mut self, count: usize) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status("No entry to delete");
+            return;
+        }
+        let start = self.selected_entry_index.min(self.relf_entries.len() - 1);
+        let end = (start + count.max(1)).min(self.relf_entries.len());
+        let original_indices: Vec<usize> = self.relf_entries[start..end]
+            .iter()
+            .map(|entry| entry.original_index)
+            .collect();
+        let (formatted, removed) = match JsonOperations::remove_entries(&self.json_input, &original_indices) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                return;
+            }
+        };
+
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        self.selected_entry_index = start.min(self.relf_entries.len().saturating_sub(1));
+        self.autosave();
+        let deleted = removed.len();
+        self.card_register = removed;
+        self.set_status(&format!("{} card{} deleted", deleted, if deleted == 1 { "" } else { "s" }));
+    }
+
+    /// [count]p / [count]P in View mode - put the card register after / before the selected card
+    pub fn put_cards(&mut self, after: bool, count: usize) {
+        if self.format_mode != FormatMode::View {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        if self.card_register.is_empty() {
+            self.set_status("Card register is empty");
+            return;
+        }
+        let entries: Vec<(String, Value)> = (0..count.max(1))
+            .flat_map(|_| self.card_register.iter().cloned())
+            .collect();
+        let (formatted, first_index) =
+            match JsonOperations::put_entries(&self.json_input, self.selected_original_index(), after, &entries) {
+                Ok(result) => result,
+                Err(e) => {
+                    self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                    return;
+                }
+            };
+
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        // The put cards may be hidden by a filter; the selection then stays where it was
+        if let Some(idx) = self.relf_entries.iter().position(|entry| entry.original_index == first_index) {
+            self.selected_entry_index = idx;
+        }
+        self.autosave();
+        self.set_status(&format!("{} card{} put", entries.len(), if entries.len() == 1 { "" } else { "s" }));
+    }
+
+    /// Apply `f` to the JSON object of the entry at `original_index`
+    /// (outside entries first, then inside), then record undo, sync Markdown and re-render.
+    /// `f` receives the entry and its section name ("outside" or "inside").
+    pub(crate) fn modify_entry<F>(&mut self, original_index: usize, f: F) -> Result<(), String>
+    where
+        F: FnOnce(&mut Map<String, Value>, &str),
+    {
+        let mut json_value: Value = serde_json::from_str(&self.json_input)
+            .map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+
+        let outside_count = obj
+            .get("outside")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.len())
+            .unwrap_or(0);
+        let (section, local_idx) = if original_index < outside_count {
+            ("outside", original_index)
+        } else {
+            ("inside", original_index - outside_count)
+        };
+
+        let entry = obj
+            .get_mut(section)
+            .and_then(|v| v.as_array_mut())
+            .and_then(|arr| arr.get_mut(local_idx))
+            .and_then(|v| v.as_object_mut())
+            .ok_or_else(|| "Entry not found".to_string())?;
+        f(entry, section);
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        Ok(())
+    }
+}
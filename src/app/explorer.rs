@@ -1,6 +1,7 @@
 use super::{App, ExplorerEntry};
-use std::fs;
-use std::path::PathBuf;
+use crate::config::ExplorerFilter;
+use crate::file_index;
+use std::path::{Path, PathBuf};
 
 impl App {
     pub fn toggle_explorer(&mut self) {
@@ -48,58 +49,81 @@ impl App {
     }
 
     // Build tree structure recursively, only descending into expanded directories
-    fn build_tree_from_dir(&self, dir: &PathBuf, depth: usize) -> Vec<ExplorerEntry> {
+    fn build_tree_from_dir(&self, dir: &Path, depth: usize) -> Vec<ExplorerEntry> {
         let mut entries = Vec::new();
 
-        // Read directory entries
-        if let Ok(dir_entries) = fs::read_dir(dir) {
-            let mut dirs = Vec::new();
-            let mut files = Vec::new();
+        // Read directory entries, minus what the explorer filter hides
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
 
-            for entry in dir_entries.flatten() {
-                if let Ok(file_type) = entry.file_type() {
-                    let path = entry.path();
-                    if file_type.is_dir() {
-                        dirs.push(path);
-                    } else {
-                        files.push(path);
-                    }
-                }
+        for (path, is_dir) in self.list_directory(dir) {
+            if is_dir {
+                dirs.push(path);
+            } else {
+                files.push(path);
             }
+        }
 
-            // Sort directories and files separately
-            dirs.sort();
-            files.sort();
-
-            // Add directories first
-            for dir_path in dirs {
-                let is_expanded = self.is_directory_expanded(&dir_path);
-                entries.push(ExplorerEntry {
-                    path: dir_path.clone(),
-                    is_expanded,
-                    depth,
-                });
-
-                // If this directory is expanded, recursively add its children
-                if is_expanded {
-                    let children = self.build_tree_from_dir(&dir_path, depth + 1);
-                    entries.extend(children);
-                }
+        // Sort directories and files separately
+        dirs.sort();
+        files.sort();
+
+        // Add directories first
+        for dir_path in dirs {
+            let is_expanded = self.is_directory_expanded(&dir_path);
+            entries.push(ExplorerEntry {
+                path: dir_path.clone(),
+                is_expanded,
+                depth,
+            });
+
+            // If this directory is expanded, recursively add its children
+            if is_expanded {
+                let children = self.build_tree_from_dir(&dir_path, depth + 1);
+                entries.extend(children);
             }
+        }
 
-            // Then add files
-            for file_path in files {
-                entries.push(ExplorerEntry {
-                    path: file_path,
-                    is_expanded: false, // Files are never expanded
-                    depth,
-                });
-            }
+        // Then add files
+        for file_path in files {
+            entries.push(ExplorerEntry {
+                path: file_path,
+                is_expanded: false, // Files are never expanded
+                depth,
+            });
         }
 
         entries
     }
 
+    /// Children of `dir` with whether each is a directory, minus what `explorer_filter`
+    /// hides. .gitignore files from parent directories apply too, even outside a git repo.
+    fn list_directory(&self, dir: &Path) -> Vec<(PathBuf, bool)> {
+        let filter = if self.explorer_show_all {
+            ExplorerFilter { hide_ignored: false, hide_dotfiles: false, supported_only: false }
+        } else {
+            self.explorer_filter
+        };
+        file_index::walker(dir, filter)
+            .max_depth(Some(1))
+            .build()
+            .flatten()
+            .filter(|entry| entry.depth() == 1)
+            .filter_map(|entry| {
+                let is_dir = entry.file_type()?.is_dir();
+                let path = entry.into_path();
+                file_index::is_listed(&path, is_dir, filter).then_some((path, is_dir))
+            })
+            .collect()
+    }
+
+    /// gh: list every file, or go back to the explorer_filter settings
+    pub fn toggle_explorer_show_all(&mut self) {
+        self.explorer_show_all = !self.explorer_show_all;
+        self.reload_explorer_entries();
+        self.set_status(if self.explorer_show_all { "Explorer: showing all files" } else { "Explorer: filtered" });
+    }
+
     // Check if a directory is currently expanded in the tree
     fn is_directory_expanded(&self, dir_path: &PathBuf) -> bool {
         self.explorer_entries
@@ -143,7 +167,7 @@ impl App {
                 if let Some(extension) = selected.path.extension() {
                     if extension == "json" || extension == "md" {
                         // Use load_file to properly reset all cursor positions
-                        self.load_file(selected.path.clone());
+                        self.open_file(selected.path.clone());
                         // Move focus to file window
                         self.explorer_has_focus = false;
                     } else {
@@ -186,7 +210,7 @@ impl App {
                 if let Some(extension) = selected.path.extension() {
                     if extension == "json" || extension == "md" {
                         // Use load_file to properly reset all cursor positions
-                        self.load_file(selected.path.clone());
+                        self.open_file(selected.path.clone());
                         // Keep focus on explorer (unlike Enter which moves focus)
                     } else {
                         self.set_status(&format!("Error: Only JSON and Markdown files can be opened ({})", selected.path.display()));
@@ -290,23 +314,21 @@ impl App {
 
         let start_index = self.explorer_selected_index + 1;
         for i in start_index..self.explorer_entries.len() {
-            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str()) {
-                if filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
-                    self.explorer_selected_index = i;
-                    self.explorer_update_scroll();
-                    return;
-                }
+            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str())
+                && filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                self.explorer_selected_index = i;
+                self.explorer_update_scroll();
+                return;
             }
         }
 
         // Wrap around to beginning
         for i in 0..start_index {
-            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str()) {
-                if filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
-                    self.explorer_selected_index = i;
-                    self.explorer_update_scroll();
-                    return;
-                }
+            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str())
+                && filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                self.explorer_selected_index = i;
+                self.explorer_update_scroll();
+                return;
             }
         }
     }
@@ -331,23 +353,21 @@ impl App {
 
         // Search backwards from start_index to 0
         for i in (0..=start_index).rev() {
-            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str()) {
-                if filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
-                    self.explorer_selected_index = i;
-                    self.explorer_update_scroll();
-                    return;
-                }
+            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str())
+                && filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                self.explorer_selected_index = i;
+                self.explorer_update_scroll();
+                return;
             }
         }
 
         // Wrap around to end
         for i in (start_index + 1..self.explorer_entries.len()).rev() {
-            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str()) {
-                if filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
-                    self.explorer_selected_index = i;
-                    self.explorer_update_scroll();
-                    return;
-                }
+            if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str())
+                && filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                self.explorer_selected_index = i;
+                self.explorer_update_scroll();
+                return;
             }
         }
     }
@@ -1,4 +1,5 @@
 use super::{App, FileOperation};
+use crate::i18n::Msg;
 use std::fs;
 use std::path::Path;
 
@@ -301,17 +302,14 @@ impl App {
                 // If source is directory, no extension validation needed
 
                 // Create parent directories if moving to a subdirectory
-                if is_move_operation {
-                    if let Some(parent) = new_path.parent() {
-                        if !parent.exists() {
-                            if let Err(e) = fs::create_dir_all(parent) {
-                                self.set_status(&format!("Error creating directories: {}", e));
-                                self.file_op_pending = None;
-                                self.file_op_prompt_buffer.clear();
-                                return;
-                            }
-                        }
-                    }
+                if is_move_operation
+                    && let Some(parent) = new_path.parent()
+                        && !parent.exists()
+                            && let Err(e) = fs::create_dir_all(parent) {
+                    self.set_status(&format!("Error creating directories: {}", e));
+                    self.file_op_pending = None;
+                    self.file_op_prompt_buffer.clear();
+                    return;
                 }
 
                 // Check if destination already exists
@@ -329,7 +327,7 @@ impl App {
                             self.load_explorer_entries();
                         }
                         Err(e) => {
-                            self.set_status(&format!("Error: {}", e));
+                            self.set_status(&self.msg_with(Msg::Error, &[&e]));
                         }
                     }
                 }
@@ -0,0 +1,94 @@
+use super::{App, FormatMode, InputMode};
+use crate::i18n::Msg;
+use crate::url_ops;
+use serde_json::Value;
+
+impl App {
+    /// Open a one-line prompt at the status bar for one field of the selected card
+    /// (cn / cu / cp), prefilled with its current value
+    pub fn start_field_edit(&mut self, key: &'static str) {
+        if self.format_mode != FormatMode::View {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let Some(entry) = self.relf_entries.get(self.selected_entry_index) else {
+            self.set_status(self.msg(Msg::NoEntrySelected));
+            return;
+        };
+        if entry.name.is_none() {
+            self.set_status(&format!("INSIDE cards have no {}", key));
+            return;
+        }
+        self.field_edit_buffer = entry.field(key).unwrap_or_default();
+        self.field_edit_key = Some(key);
+        self.input_mode = InputMode::Field;
+        self.show_field_edit_status();
+    }
+
+    /// Enter: write the typed value to the card. An empty value clears the field.
+    pub fn accept_field_edit(&mut self) {
+        self.input_mode = InputMode::Normal;
+        let Some(key) = self.field_edit_key.take() else {
+            return;
+        };
+        let text = std::mem::take(&mut self.field_edit_buffer);
+        let Some(original_index) = self.selected_original_index() else {
+            self.set_status(self.msg(Msg::NoEntrySelected));
+            return;
+        };
+
+        let mut status = format!("{} updated", key);
+        let value = match key {
+            "percentage" if text.trim().is_empty() => Value::Null,
+            "percentage" => match text.trim().trim_end_matches('%').parse::<i64>().ok().filter(|n| (0..=100).contains(n)) {
+                Some(percentage) => Value::from(percentage),
+                None => {
+                    self.set_status("Percentage must be a number from 0 to 100");
+                    return;
+                }
+            },
+            "url" => {
+                let (url, valid) = url_ops::normalize(&text, &self.url_rules);
+                if !valid {
+                    status = format!("url updated; malformed URL \"{}\"", url);
+                }
+                Value::String(url)
+            }
+            _ => Value::String(text),
+        };
+
+        let current = self.relf_entries.get(self.selected_entry_index).and_then(|entry| entry.field(key));
+        let typed = match &value {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()).filter(|s| !s.is_empty()),
+            other => Some(other.to_string()),
+        };
+        if current == typed {
+            self.set_status(&format!("{} unchanged", key));
+            return;
+        }
+
+        match self.modify_entry(original_index, |entry, _| {
+            entry.insert(key.to_string(), value);
+        }) {
+            Ok(()) => {
+                self.autosave();
+                self.set_status(&status);
+            }
+            Err(e) => self.set_status(&e),
+        }
+    }
+
+    /// Esc: leave the prompt without touching the card
+    pub fn cancel_field_edit(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.field_edit_key = None;
+        self.field_edit_buffer.clear();
+        self.set_status("");
+    }
+
+    pub fn show_field_edit_status(&mut self) {
+        let status = format!("{}: {}", self.field_edit_key.unwrap_or_default(), self.field_edit_buffer);
+        self.set_status(&status);
+    }
+}
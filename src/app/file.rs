@@ -1,122 +1,225 @@
-use super::App;
-use std::{{fs, path::PathBuf, time::Instant}};
-use serde_json::json;
+use super::{App, DiskState, FormatMode};
+use crate::config::AutosavePolicy;
+use crate::convert::Format;
+use crate::hooks::HookEvent;
+use crate::i18n::Msg;
+use crate::json_ops::JsonOperations;
+use crate::line_endings::{self, TextFormat};
+use crate::paths;
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::{self, OpenOptions},
+    hash::{Hash, Hasher},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use serde_json::Value;
+
+pub(super) fn content_hash(content: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
 
-impl App {
-    pub fn load_file(&mut self, path: PathBuf) {
-        // Path cleaning - remove all kinds of quotes and whitespace
-        let path_display = path.display().to_string();
-        let cleaned_path_str = path_display
+/// Watcher events this soon after a save are taken for the save itself
+const OWN_SAVE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Status bar warning while `disk_changed` is set
+pub(crate) const DISK_CHANGED: &str = "File changed on disk (:reload! to load, :diff to compare)";
+
+impl DiskState {
+    /// State of `path` given the content just read from or written to it
+    pub fn of(path: &Path, content: &str) -> Self {
+        Self {
+            modified: fs::metadata(path).and_then(|m| m.modified()).ok(),
+            len: content.len() as u64,
+            hash: content_hash(content.as_bytes()),
+        }
+    }
+}
+
+/// Write `content` to `path` while holding an exclusive advisory lock on it.
+/// Unless `force` is set, nothing is written (Ok(None)) when the file changed on disk
+/// since `expected` was recorded. An unchanged mtime and size skip the content hash.
+/// With `atomic`, the file is replaced through `write_atomic` instead of rewritten in place.
+pub(super) fn write_locked(
+    path: &Path,
+    content: &str,
+    expected: Option<DiskState>,
+    force: bool,
+    atomic: bool,
+) -> io::Result<Option<DiskState>> {
+    let existed = path.exists();
+    let mut file = loop {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+        file.lock()?;
+        // An atomic save by another writer may have renamed a new file over the one we
+        // waited on; locking and checking that orphan would let this save overwrite it
+        if still_at(&file, path) {
+            break file;
+        }
+    };
+
+    if let (Some(expected), false, true) = (expected, force, existed) {
+        let metadata = file.metadata()?;
+        if metadata.modified().ok() != expected.modified || metadata.len() != expected.len {
+            let mut current = Vec::new();
+            file.read_to_end(&mut current)?;
+            if content_hash(&current) != expected.hash {
+                return Ok(None);
+            }
+        }
+    }
+
+    if atomic {
+        write_atomic(path, content.as_bytes())?;
+        return Ok(Some(DiskState::of(path, content)));
+    }
+
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(content.as_bytes())?;
+    file.flush()?;
+    Ok(Some(DiskState {
+        modified: file.metadata()?.modified().ok(),
+        len: content.len() as u64,
+        hash: content_hash(content.as_bytes()),
+    }))
+}
+
+/// Whether the locked `file` is still the one at `path`
+#[cfg(unix)]
+fn still_at(file: &fs::File, path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (file.metadata(), fs::metadata(path)) {
+        (Ok(locked), Ok(current)) => locked.dev() == current.dev() && locked.ino() == current.ino(),
+        _ => false,
+    }
+}
+
+/// Windows cannot rename over an open file, so the locked file is always the one at `path`
+#[cfg(not(unix))]
+fn still_at(_file: &fs::File, _path: &Path) -> bool {
+    true
+}
+
+/// Replace `path` with `content` so that a crash or full disk never leaves it half written:
+/// the content goes to a temporary file in the same directory, is synced to disk and renamed
+/// over the file, keeping its permissions. Symlinks are followed, so the link survives.
+fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    let target = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let dir = match target.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let name = target.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let temp = dir.join(format!(".{}.revw-{}.tmp", name, std::process::id()));
+
+    let written = (|| {
+        // Left behind by an earlier crash of this same process id
+        fs::remove_file(&temp).ok();
+        let mut file = OpenOptions::new().write(true).create_new(true).open(&temp)?;
+        file.write_all(content)?;
+        if let Ok(metadata) = fs::metadata(&target) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        file.sync_all()?;
+        fs::rename(&temp, &target)
+    })();
+    if written.is_err() {
+        fs::remove_file(&temp).ok();
+    }
+    written?;
+
+    // Make the rename itself durable; directories cannot be opened for syncing on Windows
+    #[cfg(unix)]
+    if let Ok(dir) = fs::File::open(&dir) {
+        dir.sync_all().ok();
+    }
+    Ok(())
+}
+
+/// Remove the quotes and whitespace a pasted or typed path may carry
+pub(crate) fn clean_path(path: &Path) -> PathBuf {
+    let path_display = path.display().to_string();
+    PathBuf::from(
+        path_display
             .trim()
             .trim_matches('"')
             .trim_matches('\'')
             .trim_matches('`')
-            .trim();
+            .trim(),
+    )
+}
 
-        let fixed_path = PathBuf::from(cleaned_path_str);
-        let final_path_display = fixed_path.display().to_string();
+/// A path the user typed (`:e`, `:new`): quotes trimmed, then `~` and `$VAR` expanded.
+/// Paths revw found itself (explorer, finder, recent files) are used as they are.
+pub(crate) fn typed_path(text: &str) -> PathBuf {
+    paths::expand(&clean_path(Path::new(text)).to_string_lossy())
+}
 
-        match fs::read_to_string(&fixed_path) {
-            Ok(content) => {
-                // Check file extension to determine format
-                let extension = fixed_path.extension()
-                    .and_then(|ext| ext.to_str())
-                    .map(|s| s.to_lowercase());
-
-                match extension.as_deref() {
-                    Some("md") => {
-                        self.file_mode = super::FileMode::Markdown;
-                        self.markdown_input = content.clone();
-                        // Parse Markdown and convert to JSON
-                        match self.parse_markdown(&content) {
-                            Ok(json_content) => {
-                                self.json_input = json_content;
-                            }
-                            Err(e) => {
-                                self.set_status(&format!("Error parsing markdown: {}", e));
-                                return;
-                            }
-                        }
-                    }
-                    _ => {
-                        self.file_mode = super::FileMode::Json;
-                        self.markdown_input = String::new();
-                        // Load as JSON directly
-                        self.json_input = content;
-                    }
-                }
+/// Parse file content for `App::apply_loaded_file`: the JSON of a Markdown file, `None` for
+/// JSON (used as is), or the status message for content that cannot be shown.
+/// The extension decides for .md/.json, the content for anything else.
+pub(crate) fn parse_file_content(path: &Path, content: &str) -> Result<Option<String>, String> {
+    let (content, _) = line_endings::normalize(content);
+    match Format::for_file(path, &content) {
+        Format::Toon => Err(format!("Error: {}: TOON files are not supported yet", path.display())),
+        Format::Md => crate::markdown_ops::MarkdownOperations::parse_to_json(&content)
+            .map(Some)
+            .map_err(|e| format!("Error parsing markdown: {}", e)),
+        _ => Ok(None),
+    }
+}
 
-                let path_changed = self.file_path.as_ref() != Some(&fixed_path);
-                self.file_path = Some(fixed_path.clone());
-                if path_changed {
-                    self.file_path_changed = true;
-                }
+impl App {
+    /// Validate content against the strict structure rules (no-op when strict parsing is off)
+    pub fn check_strict(&self, content: &str) -> Result<(), String> {
+        if !self.strict_parsing {
+            return Ok(());
+        }
+        self.get_operations().validate_strict(content)
+    }
 
-                self.set_status(&format!("Loaded: {}", final_path_display));
+    /// Source text of the current buffer in its on-disk format
+    pub(super) fn strict_source(&self) -> String {
+        if self.file_mode == super::FileMode::Markdown {
+            self.markdown_input.clone()
+        } else {
+            self.json_input.clone()
+        }
+    }
 
-                self.convert_json();
+    pub fn load_file(&mut self, path: PathBuf) {
+        self.flush_autosave(true);
+        let fixed_path = clean_path(&path);
+        let final_path_display = fixed_path.display().to_string();
 
-                // Reset card selection and cursor position when opening a new file
-                if path_changed {
-                    self.selected_entry_index = 0;
-                    self.hscroll = 0;
-                    self.content_cursor_line = 0;
-                    self.content_cursor_col = 0;
-                    self.scroll = 0;
-                    // Clear undo/redo history when switching files
-                    self.undo_stack.clear();
-                    self.redo_stack.clear();
-                    // Reset outline cursor
-                    self.outline_selected_index = 0;
-                    self.outline_scroll = 0;
-                    self.outline_horizontal_scroll = 0;
-                }
+        match fs::read_to_string(&fixed_path) {
+            Ok(content) => {
+                let parsed = parse_file_content(&fixed_path, &content);
+                self.apply_loaded_file(fixed_path, content, parsed);
             }
             Err(e) => {
-                // If file doesn't exist, create it with default entries
+                // If file doesn't exist, create it from the skeleton for its extension
                 if e.kind() == std::io::ErrorKind::NotFound {
                     // Check file extension to determine format
                     let extension = fixed_path.extension()
                         .and_then(|ext| ext.to_str())
                         .map(|s| s.to_lowercase());
 
-                    // Get current timestamp
-                    let now = chrono::Local::now();
-                    let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
-
-                    let default_content = match extension.as_deref() {
-                        Some("md") => {
-                            // Create Markdown format
-                            format!(
-                                "## OUTSIDE\n### \n\n**URL:** \n\n**Percentage:** \n\n## INSIDE\n### {}\n",
-                                timestamp
-                            )
-                        }
-                        _ => {
-                            // Create formatted JSON with proper indentation
-                            let default_value = json!({
-                                "outside": [
-                                    {
-                                        "name": "",
-                                        "context": "",
-                                        "url": "",
-                                        "percentage": null
-                                    }
-                                ],
-                                "inside": [
-                                    {
-                                        "date": timestamp,
-                                        "context": ""
-                                    }
-                                ]
-                            });
-                            serde_json::to_string_pretty(&default_value)
-                                .unwrap_or_else(|_| String::from(r#"{"outside":[],"inside":[]}"#))
+                    let default_content = match self.skeleton(&fixed_path) {
+                        Ok(skeleton) => skeleton,
+                        Err(e) => {
+                            self.set_status(&e);
+                            return;
                         }
                     };
 
                     match fs::write(&fixed_path, &default_content) {
                         Ok(()) => {
+                            let disk_state = DiskState::of(&fixed_path, &default_content);
                             match extension.as_deref() {
                                 Some("md") => {
                                     self.file_mode = super::FileMode::Markdown;
@@ -142,9 +245,15 @@ impl App {
                             self.file_path = Some(fixed_path.clone());
                             if path_changed {
                                 self.file_path_changed = true;
+                                self.expanded_cards.clear();
                             }
+                            self.sources.clear();
+                            self.preview_paths.clear();
+                            self.text_format = TextFormat::default();
+                            self.record_disk_state(disk_state);
                             self.set_status(&format!("Created new file: {}", final_path_display));
                             self.convert_json();
+                            self.record_journal_history();
                             // Reset card selection and cursor position when creating a new file
                             if path_changed {
                                 self.selected_entry_index = 0;
@@ -171,49 +280,276 @@ impl App {
             }
         }
     }
+
+    /// Show a file read (and, for Markdown, parsed) by `load_file` or a background load.
+    /// `parsed` is the JSON of a Markdown file, `None` for JSON, or the error to report.
+    pub(crate) fn apply_loaded_file(&mut self, fixed_path: PathBuf, content: String, parsed: Result<Option<String>, String>) {
+        let final_path_display = fixed_path.display().to_string();
+        let disk_state = DiskState::of(&fixed_path, &content);
+        let (content, text_format) = line_endings::normalize_owned(content);
+        match parsed {
+            Err(e) => {
+                tracing::warn!(path = %final_path_display, error = %e, "parse failed");
+                self.set_status(&e);
+                return;
+            }
+            Ok(Some(json_content)) => {
+                self.file_mode = super::FileMode::Markdown;
+                self.markdown_input = content;
+                self.json_input = json_content;
+            }
+            Ok(None) => {
+                self.file_mode = super::FileMode::Json;
+                self.markdown_input = String::new();
+                // Load as JSON directly
+                self.json_input = content;
+            }
+        }
+
+        let path_changed = self.file_path.as_ref() != Some(&fixed_path);
+        self.file_path = Some(fixed_path.clone());
+        if path_changed {
+            self.file_path_changed = true;
+            self.expanded_cards.clear();
+        }
+        self.sources.clear();
+        self.preview_paths.clear();
+        self.text_format = text_format;
+        self.record_disk_state(disk_state);
+        self.record_recent_file();
+
+        tracing::info!(path = %final_path_display, "loaded");
+        self.set_status(&format!("Loaded: {}", final_path_display));
+
+        self.convert_json();
+        self.record_journal_history();
+
+        if let Err(e) = self.check_strict(&self.strict_source()) {
+            self.set_status(&format!("Strict: {}", e));
+        }
+
+        // Reset card selection and cursor position when opening a new file
+        if path_changed {
+            self.selected_entry_index = 0;
+            self.hscroll = 0;
+            self.content_cursor_line = 0;
+            self.content_cursor_col = 0;
+            self.scroll = 0;
+            // Clear undo/redo history when switching files
+            self.undo_stack.clear();
+            self.redo_stack.clear();
+            // Reset outline cursor
+            self.outline_selected_index = 0;
+            self.outline_scroll = 0;
+            self.outline_horizontal_scroll = 0;
+            self.check_swap();
+        }
+    }
+
+    /// Whether the last save was less than `window` ago
+    pub fn saved_within(&self, window: Duration) -> bool {
+        self.last_save_time.is_some_and(|saved| saved.elapsed() < window)
+    }
+
+    /// Whether a file watcher event is likely revw's own latest save
+    pub fn just_saved(&self) -> bool {
+        self.saved_within(OWN_SAVE_WINDOW)
+    }
+
+    /// Unsaved changes autosave may write
+    pub(super) fn can_autosave(&self) -> bool {
+        self.is_modified
+            && (self.file_path.is_some() || !self.sources.is_empty())
+            && !self.save_conflict
+            && !self.autosave_held
+            && !self.safe_mode
+    }
+
+    /// Write pending changes if the autosave policy covers the current mode. Within
+    /// autosave_interval of the last save the write waits for `flush_autosave`.
+    pub fn autosave(&mut self) {
+        if !self.can_autosave() {
+            return;
+        }
+        let write = match self.autosave {
+            AutosavePolicy::Always => true,
+            AutosavePolicy::OnAction => self.format_mode == FormatMode::View,
+            AutosavePolicy::Manual => false,
+        };
+        if !write {
+            return;
+        }
+        if self.saved_within(self.autosave_interval) {
+            self.autosave_pending = true;
+            return;
+        }
+        self.autosave_pending = false;
+        self.autosave_file();
+    }
+
+    /// Write the changes autosave held back once autosave_interval has passed, or right
+    /// away with `now` (before quitting or opening another file). Called by the event loop.
+    pub fn flush_autosave(&mut self, now: bool) {
+        if !self.autosave_pending || (!now && self.saved_within(self.autosave_interval)) {
+            return;
+        }
+        self.autosave_pending = false;
+        if !self.can_autosave() {
+            return;
+        }
+        if now {
+            self.save_file();
+        } else {
+            self.autosave_file();
+        }
+    }
+
+    /// Autosave write; pre_save hooks of a single file run off the UI thread first
+    fn autosave_file(&mut self) {
+        if let Some(path) = self.file_path.clone().filter(|_| self.sources.is_empty())
+            && self.hooks.commands(HookEvent::PreSave).next().is_some() {
+            let format = self.hook_format();
+            self.autosave_after_pre_save(path, format);
+            return;
+        }
+        self.save_file();
+    }
+
+    /// JSON to write, in canonical form when format_on_save is set (the buffer follows)
+    fn json_to_save(&mut self) -> Result<String, String> {
+        if !self.format_on_save || self.json_input.trim().is_empty() {
+            return Ok(self.json_input.clone());
+        }
+        let canonical = JsonOperations::to_canonical(&self.json_input)?;
+        if canonical != self.json_input {
+            self.json_input = canonical.clone();
+            // Line count may change; keep the Edit-mode cursor on a real line
+            let last_line = self.get_content_lines().len().saturating_sub(1);
+            self.content_cursor_line = self.content_cursor_line.min(last_line);
+        }
+        Ok(canonical)
+    }
+
+    /// `content` as written to disk: with the file's CRLF line endings and BOM when
+    /// preserve_line_endings is set, plain `\n` otherwise
+    pub fn with_line_endings(&self, format: TextFormat, content: &str) -> String {
+        if self.preserve_line_endings {
+            format.apply(content).into_owned()
+        } else {
+            content.to_string()
+        }
+    }
+
+    /// Remember the on-disk state of the open file; a fresh state ends any conflict.
+    /// Called with `json_input` holding what is on disk.
+    fn record_disk_state(&mut self, state: DiskState) {
+        self.disk_state = Some(state);
+        self.merge_base = serde_json::from_str(&self.json_input).ok();
+        self.save_conflict = false;
+        self.disk_changed = false;
+        self.autosave_held = false;
+    }
+
+    /// `{format}` passed to hooks for the open file
+    fn hook_format(&self) -> &'static str {
+        if self.is_markdown_file() { "md" } else { "json" }
+    }
+
+    /// "Saved: PATH", and the post_save hooks started on a worker thread
+    fn report_saved(&mut self, path: &Path) {
+        tracing::info!(path = %path.display(), "saved");
+        match self.hooks.commands(HookEvent::PostSave).count() {
+            0 => self.set_status(&self.msg_with(Msg::Saved, &[&path.display()])),
+            count => self.set_status(&format!(
+                "Saved: {} (running {} post_save hook{})",
+                path.display(),
+                count,
+                if count == 1 { "" } else { "s" }
+            )),
+        }
+        let format = self.hook_format();
+        self.queue_post_save_hooks(path.to_path_buf(), format);
+    }
+
     pub fn save_file(&mut self) {
-        if let Some(ref path) = self.file_path {
-            // Check file extension to determine format
-            let extension = path.extension()
-                .and_then(|ext| ext.to_str())
-                .map(|s| s.to_lowercase());
-
-            let content_to_save = match extension.as_deref() {
-                Some("md") => {
-                    // Convert to markdown if we don't have markdown content yet
-                    if self.markdown_input.is_empty() {
-                        match self.convert_to_markdown() {
-                            Ok(md_content) => {
-                                self.markdown_input = md_content.clone();
-                                md_content
-                            }
-                            Err(e) => {
-                                self.set_status(&format!("Error converting to markdown: {}", e));
-                                return;
-                            }
+        self.write_file(false, true);
+    }
+
+    /// Save even if the file changed on disk (`:w!`)
+    pub fn force_save_file(&mut self) {
+        self.write_file(true, true);
+    }
+
+    /// Save the open file; unless `force` is set, a file changed on disk since it was
+    /// loaded or last saved is left alone and the reload/overwrite/merge prompt opens.
+    /// `pre_save` is false when the pre_save hooks already passed.
+    pub(super) fn write_file(&mut self, force: bool, pre_save: bool) {
+        if self.autoname_on_save {
+            self.autoname_before_save();
+        }
+        if !self.sources.is_empty() {
+            self.write_sources(force);
+            return;
+        }
+        if let Some(path) = self.file_path.clone() {
+            // Write back in the format the file was loaded as
+            let content_to_save = if self.is_markdown_file() {
+                // Convert to markdown if we don't have markdown content yet
+                if self.markdown_input.is_empty() {
+                    match self.convert_to_markdown() {
+                        Ok(md_content) => {
+                            self.markdown_input = md_content.clone();
+                            md_content
+                        }
+                        Err(e) => {
+                            self.set_status(&format!("Error converting to markdown: {}", e));
+                            return;
                         }
-                    } else {
-                        self.markdown_input.clone()
                     }
+                } else {
+                    self.markdown_input.clone()
                 }
-                _ => {
-                    // Save as JSON
-                    self.json_input.clone()
+            } else {
+                // Save as JSON
+                match self.json_to_save() {
+                    Ok(json) => json,
+                    Err(e) => {
+                        self.set_status(&format!("Error formatting JSON: {}", e));
+                        return;
+                    }
                 }
             };
 
-            match fs::write(path, &content_to_save) {
-                Ok(()) => {
+            if let Err(e) = self.check_strict(&content_to_save) {
+                self.set_status(&format!("Strict: save rejected, {}", e));
+                return;
+            }
+            if pre_save
+                && let Err(e) = self.hooks.run(HookEvent::PreSave, &path, self.hook_format()) {
+                self.set_status(&self.msg_with(Msg::NotSaved, &[&e]));
+                return;
+            }
+
+            let content_to_save = self.with_line_endings(self.text_format, &content_to_save);
+            match write_locked(&path, &content_to_save, self.disk_state, force, self.atomic_save) {
+                Ok(None) => {
+                    tracing::warn!(path = %path.display(), "not saved: file changed on disk");
+                    self.save_conflict = true;
+                    self.set_status(self.msg(Msg::ChangedOnDisk));
+                }
+                Ok(Some(disk_state)) => {
                     self.is_modified = false;
                     self.last_save_time = Some(Instant::now());
-                    self.set_status(&format!("Saved: {}", path.display()));
+                    self.record_disk_state(disk_state);
+                    self.report_saved(&path);
                     // Reload explorer if open (without resetting cursor position)
                     if self.explorer_open {
                         self.reload_explorer_entries();
                     }
                 }
                 Err(e) => {
-                    self.set_status(&format!("Error saving: {}", e));
+                    tracing::error!(path = %path.display(), error = %e, "save failed");
+                    self.set_status(&self.msg_with(Msg::SaveError, &[&e]));
                 }
             }
         } else {
@@ -222,7 +558,7 @@ impl App {
     }
 
     pub fn save_file_as(&mut self, filename: &str) {
-        let path = PathBuf::from(filename);
+        let path = paths::expand(filename);
 
         // Check file extension to determine format
         let extension = path.extension()
@@ -251,27 +587,50 @@ impl App {
             }
             _ => {
                 // Save as JSON
-                self.json_input.clone()
+                match self.json_to_save() {
+                    Ok(json) => json,
+                    Err(e) => {
+                        self.set_status(&format!("Error formatting JSON: {}", e));
+                        return;
+                    }
+                }
             }
         };
 
-        match fs::write(&path, &content_to_save) {
-            Ok(()) => {
-                let path_changed = self.file_path.as_ref() != Some(&path);
+        let format = if extension.as_deref() == Some("md") { "md" } else { "json" };
+        if let Err(e) = self.hooks.run(HookEvent::PreSave, &path, format) {
+            self.set_status(&self.msg_with(Msg::NotSaved, &[&e]));
+            return;
+        }
+
+        // Writing to another file replaces it outright; only the open file is conflict-checked
+        let path_changed = self.file_path.as_ref() != Some(&path);
+        let content_to_save = self.with_line_endings(self.text_format, &content_to_save);
+        match write_locked(&path, &content_to_save, self.disk_state, path_changed, self.atomic_save) {
+            Ok(None) => {
+                tracing::warn!(path = %path.display(), "not saved: file changed on disk");
+                self.save_conflict = true;
+                self.set_status(self.msg(Msg::ChangedOnDisk));
+            }
+            Ok(Some(disk_state)) => {
                 self.file_path = Some(path.clone());
+                // A merged view saved under one name becomes that file
+                self.sources.clear();
                 self.is_modified = false;
                 self.last_save_time = Some(Instant::now());
+                self.record_disk_state(disk_state);
                 if path_changed {
                     self.file_path_changed = true;
                 }
-                self.set_status(&format!("Saved: {}", path.display()));
+                self.report_saved(&path);
                 // Reload explorer if open
                 if self.explorer_open {
                     self.load_explorer_entries();
                 }
             }
             Err(e) => {
-                self.set_status(&format!("Error saving: {}", e));
+                tracing::error!(path = %path.display(), error = %e, "save failed");
+                self.set_status(&self.msg_with(Msg::SaveError, &[&e]));
             }
         }
     }
@@ -280,34 +639,36 @@ impl App {
         if let Some(path) = self.file_path.clone() {
             match fs::read_to_string(&path) {
                 Ok(content) => {
-                    // Check file extension to determine format
-                    let extension = path.extension()
-                        .and_then(|ext| ext.to_str())
-                        .map(|s| s.to_lowercase());
-
-                    match extension.as_deref() {
-                        Some("md") => {
-                            self.markdown_input = content.clone();
-                            // Parse Markdown and convert to JSON
-                            match self.parse_markdown(&content) {
-                                Ok(json_content) => {
-                                    self.json_input = json_content;
-                                }
-                                Err(e) => {
-                                    self.set_status(&format!("Error parsing markdown: {}", e));
-                                    return;
-                                }
+                    let disk_state = DiskState::of(&path, &content);
+                    let (content, text_format) = line_endings::normalize_owned(content);
+                    self.text_format = text_format;
+                    let anchor = self.card_anchor();
+                    if self.is_markdown_file() {
+                        self.markdown_input = content.clone();
+                        // Parse Markdown and convert to JSON
+                        match self.parse_markdown(&content) {
+                            Ok(json_content) => {
+                                self.json_input = json_content;
+                            }
+                            Err(e) => {
+                                tracing::warn!(path = %path.display(), error = %e, "parse failed");
+                                self.set_status(&format!("Error parsing markdown: {}", e));
+                                return;
                             }
                         }
-                        _ => {
-                            self.markdown_input = String::new();
-                            self.json_input = content;
-                        }
+                    } else {
+                        self.markdown_input = String::new();
+                        self.json_input = content;
                     }
 
                     self.is_modified = false;
+                    self.preview_paths.clear();
+                    self.record_disk_state(disk_state);
                     self.convert_json();
+                    self.restore_card_anchor(anchor);
+                    self.record_journal_history();
 
+                    tracing::info!(path = %path.display(), "reloaded");
                     self.set_status(&format!("Reloaded: {}", path.display()));
                 }
                 Err(e) => {
@@ -319,10 +680,202 @@ impl App {
         }
     }
 
+    /// Auto-reload found the file changed while the buffer has unsaved changes: keep the
+    /// buffer and warn, unless the disk content is still what was loaded or saved
+    pub fn note_disk_change(&mut self) {
+        let Some(path) = self.file_path.clone() else {
+            return;
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return;
+        };
+        if self.disk_state.is_some_and(|state| state.hash == content_hash(content.as_bytes())) {
+            return;
+        }
+        self.disk_changed = true;
+        self.set_status(DISK_CHANGED);
+    }
+
+    /// :reload loads the file from disk unless the buffer has unsaved changes;
+    /// :reload! drops them (undoable)
+    pub fn reload_from_disk(&mut self, force: bool) {
+        if self.is_modified && !force {
+            self.set_status(self.msg(Msg::UnsavedChanges));
+            return;
+        }
+        if self.snapshots_open && self.snapshots.is_empty() {
+            // The :diff panel shows what is being replaced
+            self.close_snapshot_list();
+        }
+        if self.is_modified {
+            self.save_undo_state();
+        }
+        self.reload_file();
+    }
+
+    /// Answer the save conflict prompt: r = reload from disk (undoable), o = overwrite,
+    /// m = merge the disk version with the buffer and save, c = cancel and hold autosave
+    pub fn handle_save_conflict(&mut self, answer: char) {
+        if !self.save_conflict {
+            return;
+        }
+        match answer {
+            'r' => {
+                self.save_undo_state();
+                self.reload_file();
+            }
+            'o' => {
+                self.save_conflict = false;
+                self.force_save_file();
+            }
+            'm' => {
+                self.save_conflict = false;
+                self.merge_from_disk();
+            }
+            'c' => {
+                self.save_conflict = false;
+                self.autosave_held = true;
+                self.set_status("Save cancelled; autosave paused until the next :w");
+            }
+            _ => {}
+        }
+    }
+
+    /// Merge the file on disk into the buffer against the entries as last loaded or saved, then save
+    fn merge_from_disk(&mut self) {
+        let Some(path) = self.file_path.clone() else {
+            return;
+        };
+        let disk_content = match fs::read_to_string(&path) {
+            Ok(content) => line_endings::normalize_owned(content).0,
+            Err(e) => {
+                self.set_status(&format!("Error reading file on disk: {}", e));
+                return;
+            }
+        };
+        let disk_json = if self.is_markdown_file() {
+            self.parse_markdown(&disk_content)
+        } else {
+            Ok(disk_content)
+        };
+        let parsed = disk_json.and_then(|disk| {
+            let disk: Value = serde_json::from_str(&disk).map_err(|e| format!("Invalid JSON on disk: {}", e))?;
+            let ours: Value = serde_json::from_str(&self.json_input).map_err(|e| format!("Invalid current JSON: {}", e))?;
+            Ok((disk, ours))
+        });
+        let (disk, ours) = match parsed {
+            Ok(values) => values,
+            Err(e) => {
+                self.set_status(&format!("Merge failed: {}", e));
+                return;
+            }
+        };
+
+        let merged = JsonOperations::merge_concurrent(self.merge_base.as_ref(), &disk, &ours);
+        let Ok(merged_json) = serde_json::to_string_pretty(&merged) else {
+            return;
+        };
+        self.save_undo_state();
+        self.json_input = merged_json;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        self.write_file(true, true);
+        if !self.is_modified {
+            self.set_status(&format!("Merged with changes on disk and saved: {}", path.display()));
+        }
+    }
+
+    /// :convert md|json [path] - write the open file in another format (next to it unless a
+    /// path is given), reopen it from there and ask whether to delete the original
+    pub fn convert_file(&mut self, args: &str, overwrite: bool) {
+        let (format, target) = match args.trim().split_once(char::is_whitespace) {
+            Some((format, target)) => (format, Some(target.trim())),
+            None => (args.trim(), None),
+        };
+        let extension = match format {
+            "md" | "markdown" => "md",
+            "json" => "json",
+            "toon" => {
+                self.set_status("Error: TOON is not supported yet; use :convert md or :convert json");
+                return;
+            }
+            _ => {
+                self.set_status("Usage: :convert md|json [path]");
+                return;
+            }
+        };
+        let Some(current) = self.file_path.clone() else {
+            self.set_status(self.msg(Msg::NoFileOpen));
+            return;
+        };
+        let target = target.map(paths::expand).unwrap_or_else(|| current.with_extension(extension));
+        if !target.extension().is_some_and(|ext| ext.eq_ignore_ascii_case(extension)) {
+            self.set_status(&format!("Error: {} must end with .{}", target.display(), extension));
+            return;
+        }
+        if target == current {
+            self.set_status(&format!("Already a .{} file", extension));
+            return;
+        }
+        if target.exists() && !overwrite {
+            self.set_status(&format!("Error: {} exists (use :convert! to overwrite)", target.display()));
+            return;
+        }
+
+        let content = if extension == "md" {
+            self.convert_to_markdown()
+        } else {
+            serde_json::from_str::<Value>(&self.json_input)
+                .map_err(|e| format!("Invalid JSON: {}", e))
+                .and_then(|value| serde_json::to_string_pretty(&value).map_err(|e| e.to_string()))
+        };
+        let content = match content {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_status(&format!("Error converting: {}", e));
+                return;
+            }
+        };
+        if let Err(e) = fs::write(&target, content) {
+            self.set_status(&format!("Error writing {}: {}", target.display(), e));
+            return;
+        }
+
+        let selected = self.selected_entry_index;
+        self.load_file(target.clone());
+        self.selected_entry_index = selected.min(self.relf_entries.len().saturating_sub(1));
+        if self.explorer_open {
+            self.reload_explorer_entries();
+        }
+        self.set_status(&format!("Converted to {}; delete {}? (y/n)", target.display(), current.display()));
+        self.convert_delete_pending = Some(current);
+    }
+
+    /// Answer the :convert prompt: y deletes the original file, n keeps it
+    pub fn handle_convert_delete_confirmation(&mut self, answer: char) {
+        let Some(original) = self.convert_delete_pending.take() else {
+            return;
+        };
+        if answer != 'y' {
+            self.set_status(&format!("Kept {}", original.display()));
+            return;
+        }
+        match fs::remove_file(&original) {
+            Ok(()) => {
+                self.set_status(&format!("Deleted {}", original.display()));
+                if self.explorer_open {
+                    self.reload_explorer_entries();
+                }
+            }
+            Err(e) => self.set_status(&format!("Error deleting {}: {}", original.display(), e)),
+        }
+    }
+
     pub fn export_to_json(&mut self) {
         // Check if a file is currently open
         if self.file_path.is_none() {
-            self.set_status("Error: No file open");
+            self.set_status(self.msg(Msg::NoFileOpen));
             return;
         }
 
@@ -358,7 +911,7 @@ impl App {
     pub fn export_to_markdown(&mut self) {
         // Check if a file is currently open
         if self.file_path.is_none() {
-            self.set_status("Error: No file open");
+            self.set_status(self.msg(Msg::NoFileOpen));
             return;
         }
 
@@ -367,91 +920,8 @@ impl App {
         // Create markdown filename (same name, different extension)
         let md_path = json_path.with_extension("md");
 
-        // Generate markdown content
-        let mut output_lines = Vec::new();
-
-        // Parse JSON to determine which section each entry belongs to
-        if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.json_input) {
-            if let Some(obj) = json_value.as_object() {
-                // OUTSIDE section
-                if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                    if !outside.is_empty() {
-                        output_lines.push("## OUTSIDE".to_string());
-                        output_lines.push("".to_string());
-
-                        for item in outside {
-                            if let Some(item_obj) = item.as_object() {
-                                let name = item_obj.get("name").and_then(|v| v.as_str()).unwrap_or("");
-                                let context = item_obj.get("context").and_then(|v| v.as_str()).unwrap_or("");
-                                let url = item_obj.get("url").and_then(|v| v.as_str());
-                                let percentage = item_obj.get("percentage").and_then(|v| v.as_i64());
-
-                                if !name.is_empty() {
-                                    output_lines.push(format!("### {}", name));
-                                }
-
-                                // Replace literal \n with actual newlines in context
-                                if !context.is_empty() {
-                                    let formatted_context = context.replace("\\n", "\n");
-                                    output_lines.push(formatted_context);
-                                }
-
-                                // Only output URL if it's not null and not empty
-                                if let Some(url_str) = url {
-                                    if !url_str.is_empty() {
-                                        output_lines.push("".to_string());
-                                        output_lines.push(format!("**URL:** {}", url_str));
-                                    }
-                                }
-
-                                // Only output percentage if it's not null
-                                if let Some(pct) = percentage {
-                                    output_lines.push("".to_string());
-                                    output_lines.push(format!("**Percentage:** {}%", pct));
-                                }
-
-                                // Only add blank line if we had any content
-                                if !name.is_empty() || !context.is_empty() || url.is_some() || percentage.is_some() {
-                                    output_lines.push("".to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // INSIDE section
-                if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                    if !inside.is_empty() {
-                        output_lines.push("## INSIDE".to_string());
-                        output_lines.push("".to_string());
-
-                        for item in inside {
-                            if let Some(item_obj) = item.as_object() {
-                                let date = item_obj.get("date").and_then(|v| v.as_str()).unwrap_or("");
-                                let context = item_obj.get("context").and_then(|v| v.as_str()).unwrap_or("");
-
-                                if !date.is_empty() {
-                                    output_lines.push(format!("### {}", date));
-                                }
-
-                                // Replace literal \n with actual newlines in context
-                                if !context.is_empty() {
-                                    let formatted_context = context.replace("\\n", "\n");
-                                    output_lines.push(formatted_context);
-                                }
-
-                                // Only add blank line if we had content
-                                if !date.is_empty() || !context.is_empty() {
-                                    output_lines.push("".to_string());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-
-        let markdown_content = output_lines.join("\n");
+        // Same rendering as saving a .md file, so contexts keep their line breaks as stored
+        let markdown_content = self.convert_to_markdown().unwrap_or_default();
 
         // Write to file
         match fs::write(&md_path, markdown_content) {
@@ -468,5 +938,24 @@ impl App {
         }
     }
 
+    /// :new PATH - create a note file with empty OUTSIDE and INSIDE sections, in the format
+    /// its extension names, and open it. Missing parent directories are created.
+    pub fn new_file(&mut self, filename: &str) {
+        if filename.is_empty() {
+            self.set_status("Usage: :new path/to/file.md (or .json)");
+            return;
+        }
+        let path = typed_path(filename);
+        if !matches!(path.extension().and_then(|ext| ext.to_str()).map(str::to_lowercase).as_deref(), Some("md" | "json")) {
+            self.set_status("Error: Filename must end with .json or .md");
+            return;
+        }
+        if path.exists() {
+            self.set_status(&format!("Error: {} already exists (:e opens it)", path.display()));
+            return;
+        }
+        self.create_note_file(&path);
+    }
+
 }
 
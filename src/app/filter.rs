@@ -0,0 +1,46 @@
+use super::{App, FormatMode, InputMode};
+use crate::i18n::Msg;
+
+impl App {
+    /// Open the live filter prompt (View mode); starts from the active filter, if any
+    pub fn start_live_filter(&mut self) {
+        if self.format_mode != FormatMode::View {
+            self.set_status(self.msg(Msg::FilterViewOnly));
+            return;
+        }
+        self.input_mode = InputMode::Filter;
+        self.show_live_filter_status();
+    }
+
+    /// Re-filter the cards for the pattern typed so far
+    pub fn update_live_filter(&mut self, pattern: String) {
+        self.filter_pattern = pattern;
+        self.convert_json();
+        self.show_live_filter_status();
+    }
+
+    /// Enter: leave the prompt and keep the filter active
+    pub fn accept_live_filter(&mut self) {
+        self.input_mode = InputMode::Normal;
+        if self.filter_pattern.is_empty() {
+            self.set_status("");
+        } else {
+            self.set_status(&self.msg_with(Msg::FilterCount, &[&self.filter_pattern, &self.relf_entries.len()]));
+        }
+    }
+
+    /// Esc: leave the prompt and show all cards again
+    pub fn cancel_live_filter(&mut self) {
+        self.input_mode = InputMode::Normal;
+        if self.filter_pattern.is_empty() {
+            self.set_status("");
+        } else {
+            self.clear_filter();
+        }
+    }
+
+    fn show_live_filter_status(&mut self) {
+        let status = format!("filter: {} ({} entries)", self.filter_pattern, self.relf_entries.len());
+        self.set_status(&status);
+    }
+}
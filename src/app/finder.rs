@@ -0,0 +1,86 @@
+use super::App;
+use crate::file_index::FileIndex;
+use crate::fuzzy;
+
+/// Matches kept for the finder list
+const MAX_RESULTS: usize = 100;
+
+impl App {
+    /// Ctrl+p / :find [QUERY]: fuzzy-find a file under the explorer's directory.
+    /// The index starts over each time, so new files are found.
+    pub fn open_finder(&mut self, query: &str) {
+        self.finder_index = Some(FileIndex::spawn(self.explorer_current_dir.clone(), self.explorer_filter));
+        self.finder_open = true;
+        self.finder_query = query.trim().to_string();
+        self.finder_matches.clear();
+        self.finder_selected = 0;
+        self.poll_finder_index();
+        self.set_status("Find file (Enter: open, Esc: close)");
+    }
+
+    pub fn close_finder(&mut self) {
+        self.finder_open = false;
+        self.finder_index = None;
+        self.finder_query.clear();
+        self.finder_matches.clear();
+        self.finder_selected = 0;
+    }
+
+    /// Take in files the indexer found since the last frame. Called by the event loop.
+    pub fn poll_finder_index(&mut self) {
+        let Some(index) = self.finder_index.as_mut() else {
+            return;
+        };
+        let was_done = index.done;
+        if index.poll() || (index.done && !was_done) {
+            self.update_finder_matches();
+        }
+    }
+
+    /// Rank the indexed files against the query; the selection goes back to the best match
+    pub fn update_finder_matches(&mut self) {
+        let Some(index) = self.finder_index.as_ref() else {
+            return;
+        };
+        self.finder_matches = fuzzy::rank(&self.finder_query, &index.files);
+        self.finder_matches.truncate(MAX_RESULTS);
+        self.finder_selected = 0;
+    }
+
+    pub fn finder_push_char(&mut self, c: char) {
+        self.finder_query.push(c);
+        self.update_finder_matches();
+    }
+
+    pub fn finder_backspace(&mut self) {
+        if self.finder_query.pop().is_some() {
+            self.update_finder_matches();
+        }
+    }
+
+    pub fn finder_clear_query(&mut self) {
+        self.finder_query.clear();
+        self.update_finder_matches();
+    }
+
+    pub fn finder_move(&mut self, step: isize) {
+        let len = self.finder_matches.len() as isize;
+        if len > 0 {
+            self.finder_selected = (self.finder_selected as isize + step).clamp(0, len - 1) as usize;
+        }
+    }
+
+    /// Enter: open the selected file and close the finder
+    pub fn open_selected_finder_file(&mut self) {
+        let path = self.finder_index.as_ref().and_then(|index| {
+            let (file, _) = self.finder_matches.get(self.finder_selected)?;
+            Some(index.root.join(&index.files[*file]))
+        });
+        let Some(path) = path else {
+            self.set_status("No matching file");
+            return;
+        };
+        self.close_finder();
+        self.open_file(path);
+    }
+}
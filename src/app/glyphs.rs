@@ -0,0 +1,34 @@
+use super::App;
+use crate::config::Glyphs;
+use ratatui::symbols::border;
+
+/// Borders for `glyphs=ascii`
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+impl App {
+    /// Border symbols for panels and cards: the `border=` style, `+-|` with `glyphs=ascii`,
+    /// or blank space in accessible mode so screen readers and braille displays only meet text
+    pub fn border_set(&self) -> border::Set<'static> {
+        if self.accessible {
+            border::EMPTY
+        } else if self.glyphs == Glyphs::Ascii {
+            ASCII_BORDER
+        } else {
+            self.border_style.to_border_type().to_border_set()
+        }
+    }
+
+    /// `decorated` normally, the plain ASCII `plain` with `glyphs=ascii` or in accessible mode
+    pub fn glyph<'a>(&self, decorated: &'a str, plain: &'a str) -> &'a str {
+        if self.accessible || self.glyphs == Glyphs::Ascii { plain } else { decorated }
+    }
+}
@@ -1,4 +1,8 @@
-pub fn get_help_content() -> Vec<String> {
+use crate::i18n::{self, Lang, Msg};
+
+/// The help screen, with its headings in `lang`
+pub fn get_help_content(lang: Lang) -> Vec<String> {
+    let tr = |msg| i18n::text(lang, msg).to_string();
     vec![
         "".to_string(),
         "  ██████╗ ███████╗██╗   ██╗██╗    ██╗".to_string(),
@@ -8,25 +12,35 @@ pub fn get_help_content() -> Vec<String> {
         "  ██║  ██║███████╗ ╚████╔╝ ╚███╔███╔╝".to_string(),
         "  ╚═╝  ╚═╝╚══════╝  ╚═══╝   ╚══╝╚══╝ ".to_string(),
         "".to_string(),
-        "  A vim-like TUI for managing notes and resources".to_string(),
+        tr(Msg::Tagline),
         "".to_string(),
         "═══════════════════════════════════════════════════════════════".to_string(),
         "".to_string(),
-        "CLI USAGE".to_string(),
+        tr(Msg::CliUsage),
         "".to_string(),
         "  revw file.json / file.md          - open in interactive mode".to_string(),
+        "  revw a.json b.md c.json           - merged view, saves each card to its own file".to_string(),
+        "  revw --safe file.json             - no autosave, watching, hooks or clipboard reads".to_string(),
         "  revw --stdout file.json           - output to stdout".to_string(),
         "  revw --stdout --markdown file.json - output as Markdown".to_string(),
         "  revw --stdout --json file.md       - output as JSON".to_string(),
+        "  revw --stdout --table file.md      - OUTSIDE as aligned columns".to_string(),
+        "  revw --stdout --tsv file.md        - OUTSIDE as tab-separated values".to_string(),
+        "  revw --plain --fields url file.md  - one line per entry for scripts".to_string(),
+        "  revw --stdout --color always file  - colored output even when piped".to_string(),
         "  revw --stdout --inside file.json   - output INSIDE section only".to_string(),
         "  revw --stdout --filter pat file    - filter and output".to_string(),
         "  revw --stdout --filter pat --context 100 file - show 100 chars around match".to_string(),
         "  cat file.json | revw --stdout      - read from stdin".to_string(),
         "  revw --token file.json             - show token counts".to_string(),
+        "  revw --report file.md              - print a weekly review summary".to_string(),
+        "  revw --diff old.json new.md        - entries added, removed and changed (--json too)".to_string(),
+        "  revw --log-file revw.log file.md   - log watcher events, saves, parse errors, commands".to_string(),
         "".to_string(),
         "  # Order entries (writes in-place)".to_string(),
         "  revw --order file.md".to_string(),
         "  revw --order-percentage file.json".to_string(),
+        "  revw --order-priority file.md".to_string(),
         "  revw --order-name file.md".to_string(),
         "  revw --order-random file.json".to_string(),
         "".to_string(),
@@ -34,6 +48,8 @@ pub fn get_help_content() -> Vec<String> {
         "  cat new.json | revw --append file.json".to_string(),
         "  cat new.json | revw --append --inside file.json".to_string(),
         "  cat new.md   | revw --append --outside file.md".to_string(),
+        "  cat new.md   | revw --append --merge-strategy update file.md".to_string(),
+        "  revw --append --input bookmarks.html --map folder=tag file.md - import browser bookmarks".to_string(),
         "".to_string(),
         "  # Delete entries by field (writes in-place)".to_string(),
         "  revw --delete-outside-name pattern file.json".to_string(),
@@ -43,80 +59,145 @@ pub fn get_help_content() -> Vec<String> {
         "".to_string(),
         "═══════════════════════════════════════════════════════════════".to_string(),
         "".to_string(),
-        "VIEW MODE CONTROLS".to_string(),
+        tr(Msg::ViewModeControls),
         "".to_string(),
-        "Navigation:".to_string(),
+        tr(Msg::Navigation),
         "  j/k or ↑/↓   - select card (or mouse wheel)".to_string(),
         "  h/l or f/b   - scroll card content".to_string(),
         "  gg           - select first card".to_string(),
         "  G            - select last card".to_string(),
+        "  Ctrl+f/b     - page down/up through cards".to_string(),
+        "  { / }        - previous/next section (OUTSIDE/INSIDE)".to_string(),
+        "  [[ / ]]      - first/last card of the section".to_string(),
         "  :gi          - jump to first INSIDE entry".to_string(),
         "  :go          - jump to first OUTSIDE entry".to_string(),
         "  /            - search forward".to_string(),
-        "  n/N          - next/prev match (jumps to card)".to_string(),
-        "  :noh         - clear search highlighting".to_string(),
+        "  /name:x url:y - search within fields (name:, url:, ctx:, date:; all terms match)".to_string(),
+        "  n/N          - next/prev match (jumps to card; status bar shows match 3/17)".to_string(),
+        "  :matches     - list all matches of the last search (:cclose to close)".to_string(),
+        "  :noh / :nohl - clear search highlighting".to_string(),
+        "  z            - focus mode: selected card full-screen (j/k scroll, ]/[ cards, z/Esc leave)".to_string(),
         "".to_string(),
-        "Editing:".to_string(),
+        tr(Msg::Editing),
         "  Enter        - open edit overlay for selected card".to_string(),
+        "  cn / cu / cp - edit name / URL / percentage at the status bar".to_string(),
+        "  Tab          - expand / collapse a long context (max_context_lines)".to_string(),
+        "  o / O        - new card after/before the selected one (Esc discards it)".to_string(),
         "  :ai          - add new INSIDE entry (jumps to it)".to_string(),
         "  :ao          - add new OUTSIDE entry (jumps to it)".to_string(),
+        "  :a TEXT      - quick-add an entry to the default section".to_string(),
+        "  J / K        - move card down/up (across OUTSIDE/INSIDE at the edge)".to_string(),
+        "  :move +N/-N  - move card N places within its section".to_string(),
+        "  :move inside/outside - move card to that section (prompts f/d/c)".to_string(),
         "  :o           - order entries (by percentage then name) and auto-save".to_string(),
         "  :op          - order by percentage only and auto-save".to_string(),
         "  :on          - order by name only and auto-save".to_string(),
+        "  :sort priority - order by priority (highest first) and auto-save".to_string(),
+        "  :sort due    - order by due date (soonest first) and auto-save".to_string(),
+        "  :progress    - set percentages from context task checkboxes and auto-save".to_string(),
         "  :or          - order randomly and auto-save".to_string(),
         "".to_string(),
-        "Copy/Paste:".to_string(),
+        tr(Msg::CopyPaste),
         "  :c           - copy all rendered content (with OUTSIDE/INSIDE headers)".to_string(),
         "  :ci          - copy INSIDE section only".to_string(),
         "  :co          - copy OUTSIDE section only".to_string(),
         "  :cj          - copy all content (JSON format)".to_string(),
         "  :cm          - copy all content (Markdown format)".to_string(),
         "  :cu          - copy URL from selected card".to_string(),
+        "  :yu :yn :yc :yd :yp - copy URL / name / context / date / % (also keys yu ...)".to_string(),
+        "  :y FIELD     - copy one field of the selected card".to_string(),
         "  :v           - paste file path or JSON content".to_string(),
         "  :vu          - paste URL from clipboard to selected card".to_string(),
+        "  :vup         - paste URL from the primary selection (Linux)".to_string(),
         "  :vi          - paste INSIDE from clipboard (overwrite)".to_string(),
         "  :vo          - paste OUTSIDE from clipboard (overwrite)".to_string(),
         "  :va          - paste both INSIDE and OUTSIDE from clipboard (append)".to_string(),
         "  :vai         - paste INSIDE from clipboard (append)".to_string(),
         "  :vao         - paste OUTSIDE from clipboard (append)".to_string(),
+        "                 known URL: y update / n add / a update all / q skip rest".to_string(),
         "  :xi          - clear INSIDE section".to_string(),
         "  :xo          - clear OUTSIDE section".to_string(),
         "".to_string(),
-        "Entry Operations:".to_string(),
+        tr(Msg::EntryOperations),
         "  :dd          - delete selected entry".to_string(),
+        "  [N]dd        - delete card(s) into the card register".to_string(),
+        "  [N]p / [N]P  - put the card register after / before".to_string(),
         "  :yy          - duplicate selected entry".to_string(),
+        "  :attach path - attach a file to selected entry".to_string(),
+        "  ga           - open attachments of selected entry".to_string(),
+        "  gl           - link hints: letter copies a link, Shift+letter opens it".to_string(),
         "".to_string(),
-        "Visual Mode (multi-card selection):".to_string(),
+        tr(Msg::VisualMode),
         "  v            - enter Visual mode".to_string(),
         "  j/k          - extend selection".to_string(),
+        "  :select all  - select every card".to_string(),
+        "  :select invert - invert the selection".to_string(),
         "  :cc          - copy selected cards (rendered)".to_string(),
         "  :ccj         - copy selected cards (JSON)".to_string(),
         "  :ccm         - copy selected cards (Markdown)".to_string(),
+        "  :yank-template [NAME] - copy selected cards through a `template NAME TEXT` from ~/.revwrc".to_string(),
         "  :dc          - delete selected cards".to_string(),
+        "  :set pct N   - set percentage of selected cards (one undo step)".to_string(),
+        "  :priority P  - set priority (high/medium/low/1-5, none clears)".to_string(),
+        "  :done        - toggle done (dimmed) / active".to_string(),
+        "  :status S    - set status (active/done/dropped; dropped cards are hidden)".to_string(),
+        "  :tag add T / :tag remove T - edit the tags of selected cards".to_string(),
+        "  :rename-tag A B - rename a tag in all entries (asks y/n)".to_string(),
+        "  :set-url-domain A B - change the URL domain in all entries (asks y/n)".to_string(),
+        "  :autoname    - name unnamed entries after their context's first line (asks y/n)".to_string(),
         "  Esc/Ctrl+[   - exit Visual mode".to_string(),
         "".to_string(),
-        "Filter (View mode only):".to_string(),
+        tr(Msg::FilterHeading),
         "  :f pattern   - filter entries by pattern".to_string(),
+        "  F            - live filter (Enter keeps, Esc clears)".to_string(),
+        "  :filter priority>=high - filter by priority (words or 1-5)".to_string(),
+        "  :due [DAYS]  - entries due within DAYS (default due_soon_days) or overdue".to_string(),
+        "  :filter due<0 - filter by days until due (due<0 is overdue)".to_string(),
+        "  :f name:x ctx:y - filter by field (same prefixes as search)".to_string(),
         "  :nof         - clear filter".to_string(),
         "".to_string(),
-        "Settings:".to_string(),
+        tr(Msg::Settings),
         "  :set number / :set nu       - enable line numbers (Edit mode)".to_string(),
         "  :set nonumber / :set nonu   - disable line numbers".to_string(),
         "  :set relativenumber / :set rnu - enable relative line numbers (Edit mode)".to_string(),
         "  :set norelativenumber / :set nornu - disable relative line numbers".to_string(),
         "  :set card=N                 - set max visible cards (1-10, default: 5)".to_string(),
+        "  :set max_context_lines=N    - collapse card contexts after N lines (0 = full)".to_string(),
         "  :set border=rounded         - use rounded border style (default)".to_string(),
         "  :set border=plain           - use plain border style".to_string(),
+        "  :set accessible / noaccessible - blank borders and ASCII markers for screen readers".to_string(),
+        "  :set glyphs=unicode|ascii   - draw borders, cursors and markers with ASCII (default unicode)".to_string(),
         "  :set extension              - show file extensions in explorer and window title".to_string(),
         "  :set noextension            - hide file extensions in explorer and window title".to_string(),
+        "  :set preview / nopreview    - toggle image preview for local image URLs".to_string(),
+        "  :set strict / nostrict      - toggle strict parsing (reject unknown structure)".to_string(),
+        "  :set format_on_save / noformat_on_save - write JSON in canonical key order and indent".to_string(),
+        "  :set autoname_on_save / noautoname_on_save - :autoname before every save".to_string(),
+        "  :set atomic_save / noatomic_save - save via temp file + rename (default) or in place".to_string(),
+        "  :set preserve_line_endings / nopreserve_line_endings - save CRLF/BOM files as loaded or with \\n (default)".to_string(),
+        "  :set checkbox_percentage / nocheckbox_percentage - overlay saves set percentage from - [x] tasks".to_string(),
+        "  :set show_dropped / noshow_dropped - list or hide cards whose status is dropped".to_string(),
+        "  :set confirm_delete / confirm_clear / confirm_paste (no... to disable) - ask y/n before destructive actions".to_string(),
+        "  :set paste_limit=N          - ask before a paste brings in more than N entries (default 100, 0 never)".to_string(),
+        "  :set smooth_scroll / nosmooth_scroll - glide the card view when it scrolls (default instant)".to_string(),
+        "  :set clipboard=BACKEND      - system, osc52 (terminal clipboard, works over SSH) or auto (default)".to_string(),
+        "  :set paste_source=SOURCE    - clipboard (default) or primary (mouse selection, Linux)".to_string(),
+        "  :set url_add_scheme / url_encode_spaces / url_strip_tracking (no... to disable) - URL clean-up on save".to_string(),
+        "  :set explorer_hide_ignored / explorer_hide_dotfiles / explorer_supported_only (no... to disable) - explorer filters".to_string(),
+        "  :set autosave=POLICY        - always, on-action (default) or manual; [+] marks unsaved changes".to_string(),
+        "  :set autosave_interval=N    - autosave at most every N seconds (default 2, 0 = every change)".to_string(),
+        "  :set default_section=SECTION - inside (default) or outside, where :a TEXT adds".to_string(),
+        "  :set inside_order=ORDER     - file_order (default) or newest_first: how INSIDE cards are shown".to_string(),
+        "  :set lang=LANG              - en, ja or auto (default, follows LANG): help, messages, overlay titles".to_string(),
         "".to_string(),
-        "File Explorer Commands (when explorer has focus):".to_string(),
+        tr(Msg::ExplorerCommands),
         "  j/k or ↑/↓   - navigate files/directories".to_string(),
         "  h/l or ←/→   - scroll left/right (for long filenames)".to_string(),
         "  gg           - jump to first entry".to_string(),
         "  G            - jump to last entry".to_string(),
         "  /            - search files by name".to_string(),
         "  n/N          - next/prev search match".to_string(),
+        "  gh           - show all files / back to the filtered list".to_string(),
         "  Enter or o   - open file or expand/collapse directory".to_string(),
         "  ..           - navigate to parent directory".to_string(),
         "  :a           - create new JSON file".to_string(),
@@ -124,34 +205,48 @@ pub fn get_help_content() -> Vec<String> {
         "  :m           - rename file".to_string(),
         "  :dd          - delete file (confirms with yes/no)".to_string(),
         "  :yy          - copy file (asks destination)".to_string(),
+        "  mouse        - click opens a file or folds a folder; wheel scrolls the explorer".to_string(),
         "".to_string(),
-        "Other:".to_string(),
-        "  r            - toggle View/Edit mode".to_string(),
+        tr(Msg::Other),
+        "  r            - toggle View/Edit mode (keeps the current entry)".to_string(),
         "  :Lexplore / :Lex / :lx - toggle file explorer (left)".to_string(),
         "  :outline / :ol - toggle card outline panel (right)".to_string(),
+        "  :snapshot NAME - save a named snapshot of the document".to_string(),
+        "  :snapshots   - browse, diff and restore snapshots".to_string(),
+        "  :recent      - reopen a recently opened file".to_string(),
+        "  Ctrl+p / :find [QUERY] - fuzzy-find a file under the explorer directory".to_string(),
+        "  Space / Ctrl+k - command palette: find and run an action".to_string(),
         "  Ctrl+w w     - cycle between windows".to_string(),
         "  Ctrl+w h     - move to explorer (left)".to_string(),
         "  Ctrl+w l     - move to outline (right)".to_string(),
         "  Ctrl+w j/k   - move to file (center)".to_string(),
         "  :h or ?      - help".to_string(),
+        "  g? or F1     - cheat sheet of keys for the current mode".to_string(),
         "  q or Esc     - quit".to_string(),
         "".to_string(),
-        "EDIT MODE CONTROLS".to_string(),
+        tr(Msg::EditModeControls),
         "".to_string(),
-        "Navigation:".to_string(),
+        tr(Msg::Navigation),
         "  h/j/k/l or arrow keys - move cursor".to_string(),
         "  e            - next word end".to_string(),
         "  b            - previous word start".to_string(),
         "  gg           - jump to top".to_string(),
         "  G            - jump to bottom".to_string(),
+        "  { / }        - previous/next entry".to_string(),
+        "  [[ / ]]      - previous/next section".to_string(),
+        "  5j / 3w / 4x - a count repeats a motion or x/X".to_string(),
         "  :gi          - jump to first INSIDE entry".to_string(),
         "  :go          - jump to first OUTSIDE entry".to_string(),
         "".to_string(),
-        "Editing:".to_string(),
+        tr(Msg::Editing),
         "  i            - enter insert mode".to_string(),
         "  x            - delete character at cursor".to_string(),
         "  X            - delete character before cursor".to_string(),
         "  dd           - delete current line".to_string(),
+        "  dw/de/d$/diw - delete word/to word end/to line end/inner word".to_string(),
+        "  D            - delete to end of line".to_string(),
+        "  cc/cw/ce/c$/ciw - change (delete then insert)".to_string(),
+        "  C            - change to end of line".to_string(),
         "  yy           - yank (copy) current line".to_string(),
         "  p            - paste yanked line after current line".to_string(),
         "  Esc or Ctrl+[ - exit insert mode".to_string(),
@@ -160,17 +255,28 @@ pub fn get_help_content() -> Vec<String> {
         "  g-           - undo".to_string(),
         "  g+           - redo".to_string(),
         "".to_string(),
-        "Search:".to_string(),
+        tr(Msg::VisualSelection),
+        "  v / V        - select characters / lines".to_string(),
+        "  y            - yank selection".to_string(),
+        "  d or x       - delete selection".to_string(),
+        "  > / <        - indent / outdent selected lines".to_string(),
+        "  Esc/Ctrl+[   - cancel selection".to_string(),
+        "".to_string(),
+        tr(Msg::Search),
         "  /            - search forward".to_string(),
-        "  n/N          - next/prev match".to_string(),
-        "  :noh         - clear search highlighting".to_string(),
+        "  n/N          - next/prev match (status bar shows match 3/17)".to_string(),
+        "  :matches     - list all matches of the last search (:cclose to close)".to_string(),
+        "  :noh / :nohl - clear search highlighting".to_string(),
         "".to_string(),
-        "Commands:".to_string(),
+        tr(Msg::Commands),
         "  :ai          - add INSIDE entry".to_string(),
         "  :ao          - add OUTSIDE entry".to_string(),
         "  :o           - order entries (by percentage then name)".to_string(),
         "  :op          - order by percentage only".to_string(),
         "  :on          - order by name only".to_string(),
+        "  :sort priority - order by priority".to_string(),
+        "  :sort due    - order by due date".to_string(),
+        "  :progress    - set percentages from context task checkboxes".to_string(),
         "  :or          - order randomly".to_string(),
         "  :dd          - delete current entry (entire object)".to_string(),
         "  :yy          - duplicate current entry (entire object)".to_string(),
@@ -183,35 +289,87 @@ pub fn get_help_content() -> Vec<String> {
         "  :va          - paste both INSIDE and OUTSIDE from clipboard (append)".to_string(),
         "  :vai         - paste INSIDE from clipboard (append)".to_string(),
         "  :vao         - paste OUTSIDE from clipboard (append)".to_string(),
+        "                 known URL: y update / n add / a update all / q skip rest".to_string(),
         "  :xi          - clear INSIDE section".to_string(),
         "  :xo          - clear OUTSIDE section".to_string(),
         "  :w           - save".to_string(),
+        "                 changed on disk: r reload / o overwrite / m merge / c cancel".to_string(),
+        "  :w!          - save, overwriting changes on disk".to_string(),
         "  :wq          - save and quit".to_string(),
         "  :q           - quit".to_string(),
         "  :e           - reload file".to_string(),
+        "  :new PATH    - create a .md or .json note file (set skeleton_md/skeleton_json=FILE) and open it".to_string(),
+        "                 paths expand ~ and $VAR / ${VAR}".to_string(),
+        "  :reload[!]   - reload file (! discards unsaved changes)".to_string(),
+        "  :diff        - compare the buffer with the file on disk".to_string(),
         "  :ar          - toggle auto-reload (default: on)".to_string(),
         "  :markdown    - export to Markdown (same folder, .md extension)".to_string(),
+        "  :convert md|json [path] - convert and reopen; asks to delete the original".to_string(),
 "  :token       - show token counts for all formats (Markdown/JSON)".to_string(),
+"  :report      - copy a Markdown progress summary for a weekly review".to_string(),
+"  :sync [pull|push] - sync the saved file with the WebDAV folder in ~/.revwrc".to_string(),
+"  :stats tags  - entries and average completion per tag, lagging tags first".to_string(),
+"  :log         - newest lines of the debug log (--log-file, set log_file=PATH)".to_string(),
+        "  :rotate monthly - move notes before this month to notes-YYYY-MM files".to_string(),
         "  :f pattern   - filter entries".to_string(),
         "  :nof         - clear filter".to_string(),
         "  :Lexplore / :Lex / :lx - toggle file explorer (left)".to_string(),
         "  :outline / :ol - toggle card outline panel (right)".to_string(),
+        "  :outline group date|tag|priority|none - group the card outline (Enter folds groups)".to_string(),
         "  Ctrl+w w     - cycle between windows".to_string(),
         "  Ctrl+w h     - move to explorer (left)".to_string(),
         "  Ctrl+w l     - move to outline (right)".to_string(),
         "  Ctrl+w j/k   - move to file (center)".to_string(),
         "  :h or ?      - help".to_string(),
+        "  g? or F1     - cheat sheet of keys for the current mode".to_string(),
         "".to_string(),
-        "Outline Panel (when focused):".to_string(),
+        tr(Msg::OutlinePanel),
         "  j/k          - navigate entries".to_string(),
         "  go           - preview entry (jump without closing)".to_string(),
-        "  Enter        - jump to entry and release focus".to_string(),
+        "  Enter        - jump to entry and release focus (on a group: fold/unfold)".to_string(),
         "  /            - search entries".to_string(),
         "  n/N          - next/prev search match".to_string(),
         "  gg/G         - jump to first/last entry".to_string(),
         "  q            - close outline".to_string(),
+        "  mouse        - click jumps to an entry (or folds a group); wheel scrolls the outline".to_string(),
+        "  With a search or filter active, entries show match counts (none: dimmed)".to_string(),
+        "".to_string(),
+        tr(Msg::MatchList),
+        "  j/k          - navigate matches".to_string(),
+        "  gg/G         - jump to first/last match".to_string(),
+        "  Enter        - jump to match and release focus".to_string(),
+        "  q or Esc     - close match list".to_string(),
+        "".to_string(),
+        tr(Msg::SnapshotBrowser),
+        "  j/k          - navigate snapshots / scroll the diff".to_string(),
+        "  d or Tab     - toggle diff from snapshot to buffer".to_string(),
+        "  Enter        - restore snapshot (u to undo)".to_string(),
+        "  q or Esc     - close diff, then the browser".to_string(),
+        "".to_string(),
+        tr(Msg::RecentFiles),
+        "  j/k          - navigate files".to_string(),
+        "  Enter        - open file".to_string(),
+        "  q or Esc     - close the list".to_string(),
+        "".to_string(),
+        tr(Msg::CrashRecovery),
+        "  unsaved changes go to a swap file every 4 seconds until saved".to_string(),
+        "  r / d / Esc  - recover / discard / keep for later a swap file left by a crash".to_string(),
+        "".to_string(),
+        tr(Msg::FileFinder),
+        "  type         - fuzzy-match file paths".to_string(),
+        "  ↑/↓ or Ctrl+n/Ctrl+p - move through matches".to_string(),
+        "  Ctrl+u       - clear the query".to_string(),
+        "  Enter        - open file".to_string(),
+        "  Esc          - close the finder".to_string(),
+        "".to_string(),
+        tr(Msg::CommandPalette),
+        "  type         - fuzzy-match action names (keys shown on the right)".to_string(),
+        "  ↑/↓ or Ctrl+n/Ctrl+p - move through matches".to_string(),
+        "  Ctrl+u       - clear the query".to_string(),
+        "  Enter        - run the action".to_string(),
+        "  Esc / Ctrl+k - close the palette".to_string(),
         "".to_string(),
-        "Settings:".to_string(),
+        tr(Msg::Settings),
         "  :set number / :set nu       - enable line numbers".to_string(),
         "  :set nonumber / :set nonu   - disable line numbers".to_string(),
         "  :set relativenumber / :set rnu - enable relative line numbers".to_string(),
@@ -219,15 +377,18 @@ pub fn get_help_content() -> Vec<String> {
         "  :set card=N                 - set max visible cards (1-10, default: 5)".to_string(),
         "  :set border=rounded         - use rounded border style (default)".to_string(),
         "  :set border=plain           - use plain border style".to_string(),
+        "  :set accessible / noaccessible - blank borders and ASCII markers for screen readers".to_string(),
+        "  :set glyphs=unicode|ascii   - draw borders, cursors and markers with ASCII (default unicode)".to_string(),
         "  :set extension              - show file extensions in explorer and window title".to_string(),
         "  :set noextension            - hide file extensions in explorer and window title".to_string(),
         "  :set json                   - set format to JSON (for unnamed files)".to_string(),
         "  :set markdown               - set format to Markdown (for unnamed files)".to_string(),
         "".to_string(),
-        "Substitute:".to_string(),
+        tr(Msg::Substitute),
         "  :s/foo/bar/     - substitute first occurrence in current line".to_string(),
         "  :s/foo/bar/g    - substitute all occurrences in current line".to_string(),
         "  :%s/foo/bar/    - substitute first occurrence in all lines".to_string(),
         "  :%s/foo/bar/g   - substitute all occurrences in all lines".to_string(),
+        "  In the edit overlay (field Normal mode), :s/foo/bar/[g] substitutes within the field".to_string(),
     ]
 }
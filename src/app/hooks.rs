@@ -0,0 +1,93 @@
+use super::App;
+use crate::hooks::HookEvent;
+use crate::i18n::Msg;
+use crate::task::{Apply, BackgroundTask};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+impl App {
+    /// Run the post_save hooks of a file just written on a worker thread, once the hooks
+    /// still running have finished
+    pub(super) fn queue_post_save_hooks(&mut self, path: PathBuf, format: &'static str) {
+        if self.hooks.commands(HookEvent::PostSave).next().is_none() {
+            return;
+        }
+        self.hook_queue.retain(|(queued, _)| *queued != path);
+        self.hook_queue.push((path, format));
+        self.start_queued_hooks();
+    }
+
+    fn start_queued_hooks(&mut self) {
+        if self.hook_task.is_some() || self.hook_queue.is_empty() {
+            return;
+        }
+        let (path, format) = self.hook_queue.remove(0);
+        let hooks = self.hooks.clone();
+        self.hook_task = Some(BackgroundTask::spawn("post_save hooks", move |_| {
+            let result = hooks.run(HookEvent::PostSave, &path, format);
+            let apply: Apply = Box::new(move |app: &mut App| app.report_post_save(&path, result));
+            Ok(apply)
+        }));
+    }
+
+    /// Autosave of a file with pre_save hooks: run them on a worker thread and write the
+    /// buffer once they pass, unless it was saved or another file opened meanwhile
+    pub(super) fn autosave_after_pre_save(&mut self, path: PathBuf, format: &'static str) {
+        if self.hook_task.is_some() {
+            // Retried by flush_autosave once the running hooks are done
+            self.autosave_pending = true;
+            return;
+        }
+        let hooks = self.hooks.clone();
+        self.hook_task = Some(BackgroundTask::spawn("pre_save hooks", move |_| {
+            let result = hooks.run(HookEvent::PreSave, &path, format);
+            let apply: Apply = Box::new(move |app: &mut App| {
+                if app.file_path.as_ref() != Some(&path) || !app.can_autosave() {
+                    return;
+                }
+                match result {
+                    Ok(_) => app.write_file(false, false),
+                    Err(e) => app.set_status(&app.msg_with(Msg::NotSaved, &[&e])),
+                }
+            });
+            Ok(apply)
+        }));
+    }
+
+    fn report_post_save(&mut self, path: &Path, result: Result<usize, String>) {
+        match result {
+            Ok(count) => self.set_status(&format!(
+                "Saved: {} (ran {} post_save hook{})",
+                path.display(),
+                count,
+                if count == 1 { "" } else { "s" }
+            )),
+            Err(e) => self.set_status(&format!("Saved: {}; {}", path.display(), e)),
+        }
+    }
+
+    /// Take in finished hooks and start the next queued ones. Called by the event loop.
+    pub fn poll_hook_task(&mut self) {
+        let Some(task) = self.hook_task.as_mut() else {
+            return;
+        };
+        let Some(result) = task.poll() else {
+            return;
+        };
+        self.hook_task = None;
+        match result {
+            Ok(apply) => apply(self),
+            Err(e) => self.set_status(&e),
+        }
+        self.start_queued_hooks();
+    }
+
+    /// Wait for running and queued hooks, before quitting
+    pub fn finish_hooks(&mut self) {
+        while self.hook_task.is_some() {
+            self.poll_hook_task();
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::App;
+use crate::journal;
+use crate::markdown_ops::MarkdownOperations;
+use serde_json::Value;
+
+const READ_ONLY: &str = "Journal: past INSIDE entries are read-only";
+
+impl App {
+    /// Remember the INSIDE entries of a freshly loaded file when it is a journal
+    pub fn record_journal_history(&mut self) {
+        self.journal_history = self.journal_document().filter(journal::is_journal).map(|value| journal::inside_entries(&value));
+        self.journal_checked = (self.json_input.clone(), self.markdown_input.clone());
+    }
+
+    pub fn is_journal(&self) -> bool {
+        self.journal_history.is_some()
+    }
+
+    /// Whether `entry` is one of the INSIDE entries the journal was opened with
+    pub fn journal_protects(&self, entry: &Value) -> bool {
+        self.journal_history.as_ref().is_some_and(|history| history.contains(entry))
+    }
+
+    /// Refuse to touch a protected entry up front; true when the caller should stop
+    pub fn journal_refuses(&mut self, entry: &Value) -> bool {
+        if self.journal_protects(entry) {
+            self.set_status(READ_ONLY);
+            return true;
+        }
+        false
+    }
+
+    /// Roll back whatever the last key did if it edited, deleted or un-flagged the
+    /// journal's history. Content that doesn't parse yet (raw typing) is left alone.
+    pub fn enforce_journal(&mut self) {
+        let Some(history) = &self.journal_history else {
+            return;
+        };
+        if self.journal_checked.0 == self.json_input && self.journal_checked.1 == self.markdown_input {
+            return;
+        }
+        let Some(current) = self.journal_document() else {
+            return;
+        };
+        let intact = journal::is_journal(&current) && journal::missing_entry(history, &journal::inside_entries(&current)).is_none();
+        if intact {
+            self.journal_checked = (self.json_input.clone(), self.markdown_input.clone());
+            return;
+        }
+
+        let (json_input, markdown_input) = self.journal_checked.clone();
+        // The undo state saved for the refused change is the content being restored
+        if self.undo_stack.last().is_some_and(|state| state.json_input == json_input && state.markdown_input == markdown_input) {
+            self.undo_stack.pop();
+        }
+        self.json_input = json_input;
+        self.markdown_input = markdown_input;
+        let last_line = self.get_content_lines().len().saturating_sub(1);
+        self.content_cursor_line = self.content_cursor_line.min(last_line);
+        self.convert_json();
+        self.set_status(READ_ONLY);
+    }
+
+    /// The open document as JSON, from the Markdown source for Markdown files
+    fn journal_document(&self) -> Option<Value> {
+        let json = if self.is_markdown_file() && !self.markdown_input.is_empty() {
+            MarkdownOperations::parse_to_json(&self.markdown_input).ok()?
+        } else {
+            self.json_input.clone()
+        };
+        serde_json::from_str(&json).ok()
+    }
+}
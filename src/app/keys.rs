@@ -0,0 +1,244 @@
+use super::{App, ConfirmAction, FormatMode, InputMode, Motion, TextVisualMode};
+use crate::i18n::Msg;
+use crate::keymap::{Action, Key, KeyMode, KeyOutcome};
+use crossterm::event::KeyEvent;
+use std::time::Instant;
+
+impl App {
+    /// Which keymap Normal-mode keys resolve in
+    pub fn key_mode(&self) -> KeyMode {
+        if self.format_mode == FormatMode::Edit {
+            KeyMode::Edit
+        } else if self.focus_mode && self.format_mode == FormatMode::View && !self.showing_help {
+            KeyMode::Focus
+        } else {
+            KeyMode::View
+        }
+    }
+
+    /// Feed a Normal-mode key through the keymap and run the action it completes.
+    /// Returns true when the action quits.
+    pub fn press_key(&mut self, key: KeyEvent) -> bool {
+        let counts = !self.showing_help && !self.visual_mode;
+        match self.keymap.feed(self.key_mode(), Key::from_event(key), counts, Instant::now()) {
+            KeyOutcome::Run(action, count) => self.run_action(action, count),
+            KeyOutcome::Pending | KeyOutcome::Unbound => false,
+        }
+    }
+
+    /// Resolve `key` in a list or the entry overlay, which run their actions themselves.
+    /// None while a sequence (`gg`, `go`, `dd`) is half-typed or for an unbound key.
+    pub fn resolve_key(&mut self, mode: KeyMode, key: KeyEvent) -> Option<Action> {
+        match self.keymap.feed(mode, Key::from_event(key), false, Instant::now()) {
+            KeyOutcome::Run(action, _) => Some(action),
+            KeyOutcome::Pending | KeyOutcome::Unbound => None,
+        }
+    }
+
+    /// Run `action`, `count` times for motions and character deletes. Returns true to quit.
+    pub fn run_action(&mut self, action: Action, count: Option<usize>) -> bool {
+        if self.showing_help {
+            // Help only scrolls; the rest waits until it is closed
+            match action {
+                Action::Down => self.scroll_down(),
+                Action::Up => self.scroll_up(),
+                Action::Top => self.scroll_to_top(),
+                Action::Bottom => self.scroll_to_bottom(),
+                Action::PageDown | Action::PageCards(true) => self.page_down(),
+                Action::PageUp | Action::PageCards(false) => self.page_up(),
+                Action::Quit | Action::ToggleHelp | Action::CommandLine | Action::CheatSheet => {
+                    return self.run_once(action, count);
+                }
+                _ => {}
+            }
+            return false;
+        }
+        let repeats = match action {
+            Action::Down
+            | Action::Up
+            | Action::Left
+            | Action::Right
+            | Action::WordForward
+            | Action::WordEnd
+            | Action::WordBack
+            | Action::ScrollCard(_)
+            | Action::DeleteChar
+            | Action::Backspace => count.unwrap_or(1),
+            _ => 1,
+        };
+        let mut quit = false;
+        for _ in 0..repeats {
+            quit |= self.run_once(action, count);
+        }
+        quit
+    }
+
+    fn run_once(&mut self, action: Action, count: Option<usize>) -> bool {
+        let mode = self.key_mode();
+        let view = self.format_mode == FormatMode::View;
+        match action {
+            Action::Quit => {
+                // Leave Visual mode if active, otherwise quit
+                if !self.visual_mode {
+                    return true;
+                }
+                self.visual_mode = false;
+                self.set_status("");
+            }
+            Action::CommandLine => {
+                // Allowed while help shows, for :h
+                self.input_mode = InputMode::Command;
+                self.command_buffer = String::new();
+                self.command_history_index = None;
+                self.set_status(":");
+            }
+            Action::ToggleHelp => self.toggle_help(),
+            Action::CheatSheet => self.toggle_cheatsheet(),
+            // Toggle between View and Edit, keeping the selected entry in sight
+            Action::ToggleFormat => self.toggle_format_mode(),
+            Action::Search => self.start_search(),
+            Action::NextMatch => self.next_match(),
+            Action::PrevMatch => self.prev_match(),
+            Action::Down | Action::Up if mode == KeyMode::Focus => {
+                self.focus_scroll(if action == Action::Down { 1 } else { -1 })
+            }
+            Action::Down if mode == KeyMode::Edit => self.move_cursor_down(),
+            Action::Up if mode == KeyMode::Edit => self.move_cursor_up(),
+            Action::Down | Action::Up => self.select_card_by(if action == Action::Down { 1 } else { -1 }),
+            Action::Left => self.move_cursor_left(),
+            Action::Right => self.move_cursor_right(),
+            Action::Top => match mode {
+                KeyMode::Focus => self.hscroll = 0,
+                KeyMode::Edit => {
+                    self.scroll_to_top();
+                    self.content_cursor_line = 0;
+                    self.content_cursor_col = 0;
+                }
+                KeyMode::View if !self.relf_entries.is_empty() => self.selected_entry_index = 0,
+                _ => {
+                    self.scroll_to_top();
+                    self.content_cursor_line = 0;
+                    self.content_cursor_col = 0;
+                }
+            },
+            Action::Bottom => match mode {
+                KeyMode::Focus => self.hscroll = self.relf_max_hscroll(),
+                KeyMode::Edit => {
+                    let lines = self.get_content_lines();
+                    if !lines.is_empty() {
+                        self.content_cursor_line = lines.len() - 1;
+                        self.content_cursor_col = lines.last().map(|l| l.chars().count()).unwrap_or(0);
+                    }
+                    self.ensure_cursor_visible();
+                }
+                KeyMode::View if !self.relf_entries.is_empty() => {
+                    self.selected_entry_index = self.relf_entries.len() - 1;
+                }
+                _ => self.scroll_to_bottom(),
+            },
+            Action::PageDown if mode == KeyMode::Focus => self.focus_scroll(self.focus_page_rows()),
+            Action::PageUp if mode == KeyMode::Focus => self.focus_scroll(-self.focus_page_rows()),
+            Action::PageDown => self.page_down(),
+            Action::PageUp => self.page_up(),
+            Action::HalfPageDown => self.focus_scroll(self.focus_page_rows() / 2),
+            Action::HalfPageUp => self.focus_scroll(-self.focus_page_rows() / 2),
+            Action::PageCards(forward) => self.page_cards(forward),
+            Action::ScrollCard(rows) => self.relf_hscroll_by(rows),
+            Action::Bracket(motion) => self.bracket_motion(motion),
+            Action::WordForward => self.move_to_next_word_start(),
+            Action::WordEnd => self.move_to_next_word_end(),
+            Action::WordBack => self.move_to_previous_word_start(),
+            Action::LineStart => {
+                self.content_cursor_col = 0;
+                self.ensure_cursor_visible();
+            }
+            Action::LineEnd => {
+                let lines = self.get_content_lines();
+                if let Some(line) = lines.get(self.content_cursor_line) {
+                    self.content_cursor_col = line.chars().count();
+                    self.ensure_cursor_visible();
+                }
+            }
+            Action::Insert | Action::Append | Action::OpenLineBelow => {
+                match action {
+                    Action::Append => self.move_cursor_right(),
+                    Action::OpenLineBelow => self.open_line_below(),
+                    _ => {}
+                }
+                self.input_mode = InputMode::Insert;
+                self.ensure_cursor_visible();
+                self.set_status(self.msg(Msg::Insert));
+            }
+            Action::NewCard(after) if !self.visual_mode => self.insert_card(after),
+            // Past a section end the card moves into the other section
+            Action::MoveCard(delta) if !self.visual_mode => self.move_selected_card(delta),
+            Action::DeleteChar | Action::Backspace => {
+                if action == Action::DeleteChar {
+                    self.delete_char();
+                } else {
+                    self.backspace();
+                }
+                self.is_modified = true;
+            }
+            // Wait for a motion (dd, dw, de, d$, diw, cc, cw, ...)
+            Action::Operator(op) => self.pending_operator = op.to_string(),
+            Action::OperatorToLineEnd(op) => self.apply_operator(op, Motion::LineEnd),
+            Action::YankLine => self.yank_line(),
+            Action::PasteLine => self.paste_line(),
+            // The selected card and count - 1 after it go to the card register
+            Action::DeleteCards if !self.visual_mode => {
+                self.confirm_action(ConfirmAction::DeleteCardsForward(count.unwrap_or(0)))
+            }
+            Action::PutCards(after) if !self.visual_mode => self.put_cards(after, count.unwrap_or(0)),
+            Action::Undo => self.undo(),
+            Action::Redo => self.redo(),
+            Action::VisualCards if !self.relf_entries.is_empty() => {
+                self.visual_mode = true;
+                self.visual_inverted = false;
+                self.visual_start_index = self.selected_entry_index;
+                self.visual_end_index = self.selected_entry_index;
+                self.set_status("-- VISUAL --");
+            }
+            Action::VisualChars => self.start_text_visual(TextVisualMode::Char),
+            Action::VisualLines => self.start_text_visual(TextVisualMode::Line),
+            Action::OpenCard if !self.relf_entries.is_empty() => self.start_editing_entry(),
+            Action::ToggleExpansion if !self.relf_entries.is_empty() => self.toggle_card_expansion(),
+            Action::ToggleFocus if mode == KeyMode::Focus || (view && !self.visual_mode) => self.toggle_focus_mode(),
+            Action::FocusNextCard(forward) => self.focus_next_card(forward),
+            Action::LiveFilter => self.start_live_filter(),
+            Action::Attachments => self.open_selected_attachments(),
+            // Label the selected card's links to copy or open one
+            Action::LinkHints => self.start_link_hints(),
+            Action::CopyField(key) => self.copy_selected_field(key),
+            Action::QuickEdit(key) => self.start_field_edit(key),
+            Action::Palette => self.open_palette(),
+            Action::Command(cmd) => {
+                self.command_buffer = cmd.to_string();
+                return self.execute_command();
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// j / k over the cards, extending a Visual selection
+    fn select_card_by(&mut self, delta: isize) {
+        if self.relf_entries.is_empty() {
+            if delta > 0 {
+                self.relf_jump_down();
+            } else {
+                self.relf_jump_up();
+            }
+            return;
+        }
+        let next = self.selected_entry_index.saturating_add_signed(delta);
+        if next != self.selected_entry_index && next < self.relf_entries.len() {
+            self.selected_entry_index = next;
+            // Reset horizontal scroll when changing cards
+            self.hscroll = 0;
+            if self.visual_mode {
+                self.visual_end_index = self.selected_entry_index;
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::attachments::open_with_system;
+use super::{App, FormatMode};
+use crate::content_ops;
+use crate::i18n::Msg;
+use std::path::Path;
+
+/// Hint letters, home row first
+const HINT_LABELS: &str = "asdfghjklqwertyuiopzxcvbnm";
+
+impl App {
+    /// gl: label every link of the selected card (its URL, then links in the context)
+    pub fn start_link_hints(&mut self) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let Some(entry) = self.relf_entries.get(self.selected_entry_index) else {
+            return;
+        };
+        let mut links: Vec<String> = entry.url.iter().filter(|url| !url.is_empty()).cloned().collect();
+        for url in content_ops::find_urls(entry.context.as_deref().unwrap_or("")) {
+            if !links.contains(&url) {
+                links.push(url);
+            }
+        }
+        if links.is_empty() {
+            self.set_status("No links in selected entry");
+            return;
+        }
+        self.link_hints = HINT_LABELS.chars().zip(links).collect();
+        self.set_status("Link hints: letter copies, Shift+letter opens, Esc cancels");
+    }
+
+    /// A key while link hints are shown: copy (lowercase) or open (uppercase) the labelled link
+    pub fn handle_link_hint(&mut self, c: char) {
+        let hints = std::mem::take(&mut self.link_hints);
+        let label = c.to_ascii_lowercase();
+        let Some((_, url)) = hints.iter().find(|(hint, _)| *hint == label) else {
+            self.set_status(&format!("No link labelled {}", c));
+            return;
+        };
+        if c.is_ascii_uppercase() {
+            match open_with_system(Path::new(url)) {
+                Ok(()) => self.set_status(&format!("Opened URL: {}", url)),
+                Err(e) => self.set_status(&format!("Failed to open {}: {}", url, e)),
+            }
+        } else {
+            match self.clipboard_set_text(url.clone()) {
+                Ok(()) => self.set_status(&format!("Copied URL: {}", url)),
+                Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+            }
+        }
+    }
+
+    pub fn cancel_link_hints(&mut self) {
+        self.link_hints.clear();
+        self.set_status("Link hints cancelled");
+    }
+}
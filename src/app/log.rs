@@ -0,0 +1,21 @@
+use super::App;
+use crate::logging;
+
+impl App {
+    /// :log - show the most recent lines of the debug log
+    pub fn show_log(&mut self) {
+        let Some(path) = self.log_file.clone() else {
+            self.set_status("Logging is off (start with --log-file PATH or set log_file=PATH)");
+            return;
+        };
+        match logging::tail(&path, logging::TAIL_LINES) {
+            Ok(lines) if lines.is_empty() => self.set_status("Log is empty"),
+            Ok(lines) => self.log_lines = Some(lines),
+            Err(e) => self.set_status(&e),
+        }
+    }
+
+    pub fn close_log(&mut self) {
+        self.log_lines = None;
+    }
+}
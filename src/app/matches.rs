@@ -0,0 +1,147 @@
+use super::{App, FormatMode};
+use crate::i18n::Msg;
+
+/// Characters of context kept before a match in the list snippet
+const SNIPPET_LEAD: usize = 20;
+
+/// One row of the `:matches` list
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchItem {
+    pub match_index: usize, // Index into search_matches
+    pub label: String,      // Entry name/date (or line number)
+    pub snippet: String,    // Matched line, starting a little before the match
+}
+
+impl App {
+    /// Open the match list for the last search (like vim's :copen) and focus it
+    pub fn open_match_list(&mut self) {
+        if self.search_query.is_empty() {
+            self.set_status("No search: use /pattern first");
+            return;
+        }
+        self.find_matches();
+        if self.search_matches.is_empty() {
+            self.set_status(&self.msg_with(Msg::PatternNotFound, &[&self.search_query]));
+            return;
+        }
+        self.matches_open = true;
+        self.matches_has_focus = true;
+        self.matches_selected_index = self.current_match_index.unwrap_or(0).min(self.search_matches.len() - 1);
+        self.set_status(&format!("{} matches for '{}'", self.search_matches.len(), self.search_query));
+    }
+
+    pub fn close_match_list(&mut self) {
+        self.matches_open = false;
+        self.matches_has_focus = false;
+        self.matches_selected_index = 0;
+    }
+
+    pub fn match_list_move_down(&mut self) {
+        if self.matches_selected_index + 1 < self.search_matches.len() {
+            self.matches_selected_index += 1;
+        }
+    }
+
+    pub fn match_list_move_up(&mut self) {
+        self.matches_selected_index = self.matches_selected_index.saturating_sub(1);
+    }
+
+    /// Jump to the selected match and give focus back to the content
+    pub fn match_list_jump(&mut self) {
+        if self.matches_selected_index >= self.search_matches.len() {
+            return;
+        }
+        self.current_match_index = Some(self.matches_selected_index);
+        self.jump_to_current_match();
+        self.hscroll = 0;
+        self.matches_has_focus = false;
+        self.show_match_position();
+    }
+
+    /// Rows of the match list, in the same order as search_matches
+    pub fn match_list_items(&self) -> Vec<MatchItem> {
+        let mut items = Vec::new();
+
+        if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
+            for entry in &self.relf_entries {
+                let label = entry.name.clone().or_else(|| entry.date.clone()).unwrap_or_default();
+                for (line_idx, col) in self.card_match_positions(entry) {
+                    let line = entry.lines.get(line_idx).map(String::as_str).unwrap_or("");
+                    items.push(MatchItem { match_index: items.len(), label: label.clone(), snippet: snippet(line, col) });
+                }
+            }
+            return items;
+        }
+
+        let lines = if self.format_mode == FormatMode::Edit {
+            self.get_content_lines()
+        } else {
+            self.rendered_content.clone()
+        };
+        for &(line_idx, col) in &self.search_matches {
+            let line = lines.get(line_idx).map(String::as_str).unwrap_or("");
+            let label = entry_title_above(&lines, line_idx)
+                .map(|title| format!("{} (line {})", title, line_idx + 1))
+                .unwrap_or_else(|| format!("line {}", line_idx + 1));
+            items.push(MatchItem { match_index: items.len(), label, snippet: snippet(line, col) });
+        }
+        items
+    }
+
+    /// Char positions of every case-insensitive occurrence of `query_lower` in `line`
+    pub(super) fn line_match_positions(line: &str, query_lower: &str) -> Vec<usize> {
+        let mut positions = Vec::new();
+        if query_lower.is_empty() {
+            return positions;
+        }
+        let line_lower = line.to_lowercase();
+        let mut byte_pos = 0;
+
+        while byte_pos < line_lower.len() {
+            if let Some(match_pos) = line_lower[byte_pos..].find(query_lower) {
+                let actual_byte_pos = byte_pos + match_pos;
+                // Convert byte position to char position
+                positions.push(line[..actual_byte_pos.min(line.len())].chars().count());
+                // Move past this match, ensuring we stay on char boundary
+                byte_pos = actual_byte_pos + query_lower.len();
+                while byte_pos < line_lower.len() && !line_lower.is_char_boundary(byte_pos) {
+                    byte_pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+        positions
+    }
+}
+
+/// Line of `text` holding char `col`, from a little before the match, with `…` when cut at the front
+fn snippet(text: &str, col: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let col = col.min(chars.len());
+    // Multi-line contexts: keep only the line with the match
+    let line_start = chars[..col].iter().rposition(|&c| c == '\n').map_or(0, |p| p + 1);
+    let line_end = chars[col..].iter().position(|&c| c == '\n').map_or(chars.len(), |p| col + p);
+    let start = line_start.max(col.saturating_sub(SNIPPET_LEAD));
+    let line: String = chars[start..line_end].iter().collect();
+    if start > line_start {
+        format!("…{}", line.trim())
+    } else {
+        line.trim().to_string()
+    }
+}
+
+/// Name/date of the entry a content line belongs to (Markdown `###` header or JSON key)
+fn entry_title_above(lines: &[String], line_idx: usize) -> Option<String> {
+    lines.iter().take(line_idx + 1).rev().find_map(|line| {
+        let trimmed = line.trim();
+        if let Some(title) = trimmed.strip_prefix("### ") {
+            return Some(title.trim().to_string());
+        }
+        ["\"name\":", "\"date\":"].iter().find_map(|key| {
+            let value = &trimmed[trimmed.find(key)? + key.len()..];
+            // Read just the string after the key; the rest of the line may hold more JSON
+            serde_json::Deserializer::from_str(value).into_iter::<String>().next()?.ok()
+        })
+    })
+}
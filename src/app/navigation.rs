@@ -1,7 +1,16 @@
 use super::{App, FormatMode};
+use crate::i18n::Msg;
 use crate::navigation::Navigator;
+use crate::rendering::RelfEntry;
 use serde_json::Value;
 
+/// One frame of a smooth scroll from `from` towards `to`: a third of the way, at least
+/// one card, so long jumps glide fast and settle gently
+fn scroll_step(from: usize, to: usize) -> usize {
+    let step = from.abs_diff(to).div_ceil(3);
+    if from < to { from + step } else { from - step }
+}
+
 impl App {
     pub fn relf_is_entry_start(&self, line: &str) -> bool {
         Navigator::relf_is_entry_start(line)
@@ -87,9 +96,9 @@ impl App {
             // Use the pre-computed visual row count (updated each render frame)
             let total_vis_rows = self.card_context_rows;
             // Visible rows per card: (total inner height / cards), minus 2 for card borders
-            let card_inner_height =
-                (self.visible_height as usize / self.max_visible_cards.max(1)).saturating_sub(2);
-            return total_vis_rows.saturating_sub(card_inner_height) as u16;
+            let cards = if self.focus_mode { 1 } else { self.max_visible_cards.max(1) };
+            let card_inner_height = (self.visible_height as usize / cards).saturating_sub(2);
+            total_vis_rows.saturating_sub(card_inner_height) as u16
         } else {
             // Edit mode: horizontal scroll
             let w = self.get_content_width() as usize;
@@ -110,6 +119,119 @@ impl App {
         }
     }
 
+    /// Context lines a card shows, or None when it is shown in full
+    /// (`max_context_lines` is 0 or the card was expanded with Tab)
+    pub fn context_line_limit(&self, entry: &RelfEntry) -> Option<usize> {
+        (self.max_context_lines > 0 && !self.expanded_cards.contains(&entry.identity())).then_some(self.max_context_lines)
+    }
+
+    /// Tab: show the selected card's whole context, or collapse it again
+    pub fn toggle_card_expansion(&mut self) {
+        if self.max_context_lines == 0 {
+            self.set_status("Contexts are shown in full (:set max_context_lines=N to collapse)");
+            return;
+        }
+        let Some(entry) = self.relf_entries.get(self.selected_entry_index) else {
+            return;
+        };
+        // By identity, so the card stays open when cards are deleted, moved or ordered
+        let identity = entry.identity();
+        self.hscroll = 0;
+        if self.expanded_cards.remove(&identity) {
+            self.set_status("Card collapsed");
+        } else {
+            self.expanded_cards.insert(identity);
+            self.set_status("Card expanded");
+        }
+    }
+
+    /// Cards shown in the card view as (start, end exclusive).
+    /// Starts at `card_scroll`, shifted just enough to keep the selection on screen.
+    pub fn visible_card_range(&self) -> (usize, usize) {
+        let page = self.max_visible_cards.max(1);
+        let total = self.relf_entries.len();
+        let selected = self.selected_entry_index;
+        let start = self
+            .card_scroll
+            .min(selected)
+            .max((selected + 1).saturating_sub(page))
+            .min(total.saturating_sub(page));
+        (start, (start + page).min(total))
+    }
+
+    /// First card to draw this frame: `card_scroll` right away, or with `smooth_scroll` a
+    /// step of the way there from what the last frame showed
+    pub fn next_card_scroll_frame(&mut self) -> usize {
+        let (target, _) = self.visible_card_range();
+        self.card_scroll = target;
+        let shown = if self.smooth_scroll { scroll_step(self.card_scroll_shown, target) } else { target };
+        self.card_scroll_shown = shown;
+        self.scroll_animating = shown != target;
+        shown
+    }
+
+    /// f in View mode - show only the selected card, using the whole content area
+    pub fn toggle_focus_mode(&mut self) {
+        if self.focus_mode {
+            self.focus_mode = false;
+            self.hscroll = 0;
+            self.set_status("");
+            return;
+        }
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        self.focus_mode = true;
+        self.hscroll = 0;
+        self.set_status("-- FOCUS -- j/k scroll, ]/[ next/previous card, f or Esc to leave");
+    }
+
+    /// Scroll the focused card by `rows` (negative scrolls up)
+    pub fn focus_scroll(&mut self, rows: i16) {
+        self.relf_hscroll_by(rows);
+    }
+
+    /// Rows of the focused card's body that fit on screen, for paging
+    pub fn focus_page_rows(&self) -> i16 {
+        self.visible_height.saturating_sub(2).max(1) as i16
+    }
+
+    /// Focus the next (`forward`) or previous card, starting at its top
+    pub fn focus_next_card(&mut self, forward: bool) {
+        let last = self.relf_entries.len().saturating_sub(1);
+        let next = if forward {
+            (self.selected_entry_index + 1).min(last)
+        } else {
+            self.selected_entry_index.saturating_sub(1)
+        };
+        if next != self.selected_entry_index {
+            self.selected_entry_index = next;
+            self.hscroll = 0;
+        }
+    }
+
+    /// Move the card selection a full screen of cards (Ctrl-f / Ctrl-b in View mode)
+    pub fn page_cards(&mut self, forward: bool) {
+        if self.relf_entries.is_empty() {
+            return;
+        }
+        let page = self.max_visible_cards.max(1);
+        let last = self.relf_entries.len() - 1;
+        let (start, _) = self.visible_card_range();
+        if forward {
+            self.selected_entry_index = (self.selected_entry_index + page).min(last);
+            self.card_scroll = start + page;
+        } else {
+            self.selected_entry_index = self.selected_entry_index.saturating_sub(page);
+            self.card_scroll = start.saturating_sub(page);
+        }
+        self.hscroll = 0;
+        if self.visual_mode {
+            self.visual_end_index = self.selected_entry_index;
+        }
+    }
+
     pub fn relf_content_max_scroll(&self) -> u16 {
         let total = self.rendered_content.len() as u16;
         let vis = self.get_visible_height();
@@ -170,80 +292,6 @@ impl App {
         self.scroll = self.max_scroll;
     }
 
-    pub fn delete_selected_entry(&mut self) {
-        // Delete the selected entry from relf_entries by removing it from JSON
-        if self.relf_entries.is_empty() || self.selected_entry_index >= self.relf_entries.len() {
-            self.set_status("No entry to delete");
-            return;
-        }
-
-        // Get the original index from the selected entry (accounts for filtering)
-        let target_idx = self.relf_entries[self.selected_entry_index].original_index;
-
-        match serde_json::from_str::<Value>(&self.json_input) {
-            Ok(mut json_value) => {
-                if let Some(obj) = json_value.as_object_mut() {
-                    // Count entries to find which section and index
-                    let mut current_idx = 0;
-                    let mut found = false;
-
-                    // Check outside section first
-                    if let Some(outside) = obj.get_mut("outside") {
-                        if let Some(outside_array) = outside.as_array_mut() {
-                            let outside_count = outside_array.len();
-                            if target_idx < current_idx + outside_count {
-                                let local_idx = target_idx - current_idx;
-                                outside_array.remove(local_idx);
-                                found = true;
-                            } else {
-                                current_idx += outside_count;
-                            }
-                        }
-                    }
-
-                    // Check inside section if not found
-                    if !found {
-                        if let Some(inside) = obj.get_mut("inside") {
-                            if let Some(inside_array) = inside.as_array_mut() {
-                                let local_idx = target_idx - current_idx;
-                                if local_idx < inside_array.len() {
-                                    inside_array.remove(local_idx);
-                                    found = true;
-                                }
-                            }
-                        }
-                    }
-
-                    if found {
-                        // Update JSON and re-render
-                        match serde_json::to_string_pretty(&json_value) {
-                            Ok(formatted) => {
-                                self.json_input = formatted;
-
-                                // If working with a markdown file, sync markdown_input
-                                if self.is_markdown_file() {
-                                    if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                                        self.markdown_input = Self::json_to_markdown_string(&json_value).unwrap_or_default();
-                                    }
-                                }
-
-                                self.convert_json();
-
-                                self.set_status("Entry deleted");
-                            }
-                            Err(e) => self.set_status(&format!("Error formatting JSON: {}", e)),
-                        }
-                    } else {
-                        self.set_status("Could not find entry to delete");
-                    }
-                } else {
-                    self.set_status("JSON is not an object");
-                }
-            }
-            Err(e) => self.set_status(&format!("Invalid JSON: {}", e)),
-        }
-    }
-
     pub fn delete_current_entry(&mut self) {
         // Save undo state before modification
         self.save_undo_state();
@@ -291,6 +339,46 @@ impl App {
         }
     }
 
+    /// Bracket motions. Cards: `{`/`}` previous/next section, `[[`/`]]` first/last card of
+    /// the section. Edit mode: `{`/`}` previous/next entry, `[[`/`]]` previous/next section.
+    pub fn bracket_motion(&mut self, motion: &str) {
+        if self.format_mode == FormatMode::Edit {
+            let lines = self.get_content_lines();
+            let markdown = self.is_markdown_file();
+            let line = self.content_cursor_line;
+            let target = match motion {
+                "}" => Navigator::next_start(&Navigator::entry_start_lines(&lines, markdown), line),
+                "{" => Navigator::prev_start(&Navigator::entry_start_lines(&lines, markdown), line),
+                "]]" => Navigator::next_start(&Navigator::section_start_lines(&lines, markdown), line),
+                "[[" => Navigator::prev_start(&Navigator::section_start_lines(&lines, markdown), line),
+                _ => None,
+            };
+            if let Some(target) = target {
+                self.content_cursor_line = target;
+                self.content_cursor_col = 0;
+                self.ensure_cursor_visible();
+            }
+        } else if !self.relf_entries.is_empty() {
+            let is_outside: Vec<bool> = self.relf_entries.iter().map(|e| e.name.is_some()).collect();
+            let idx = self.selected_entry_index.min(is_outside.len() - 1);
+            let target = match motion {
+                "}" => Navigator::next_card_section(&is_outside, idx),
+                "{" => Navigator::prev_card_section(&is_outside, idx),
+                "[[" => Navigator::card_section_bounds(&is_outside, idx).0,
+                "]]" => Navigator::card_section_bounds(&is_outside, idx).1,
+                _ => idx,
+            };
+            if target != self.selected_entry_index {
+                self.selected_entry_index = target;
+                self.hscroll = 0;
+                // In Visual mode, extend selection
+                if self.visual_mode {
+                    self.visual_end_index = target;
+                }
+            }
+        }
+    }
+
     pub fn jump_to_first_outside(&mut self) {
         if self.format_mode == FormatMode::Edit {
             // In Edit mode, find the first outside entry
@@ -310,23 +398,66 @@ impl App {
             self.set_status("No OUTSIDE entries found");
         } else {
             // In View mode, jump to first card in outside section
-            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    if let Some(outside) = obj.get("outside") {
-                        if let Some(outside_array) = outside.as_array() {
-                            if !outside_array.is_empty() {
-                                self.selected_entry_index = 0;
-                                self.set_status("Jumped to first OUTSIDE entry");
-                                return;
-                            }
-                        }
-                    }
-                }
+            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object()
+                    && let Some(outside) = obj.get("outside")
+                        && let Some(outside_array) = outside.as_array()
+                            && !outside_array.is_empty() {
+                self.selected_entry_index = 0;
+                self.set_status("Jumped to first OUTSIDE entry");
+                return;
             }
             self.set_status("No OUTSIDE entries found");
         }
     }
 
+    /// r: switch between View and Edit. The card selected in View puts the Edit cursor on
+    /// its first line; the entry under the Edit cursor becomes the selected card.
+    pub fn toggle_format_mode(&mut self) {
+        // Clear filter when toggling modes
+        if !self.filter_pattern.is_empty() {
+            self.filter_pattern.clear();
+        }
+        self.text_visual = None;
+
+        let markdown = self.is_markdown_file();
+        let entry_lines = |app: &Self| Navigator::entry_lines_by_index(&app.get_content_lines(), markdown);
+        // Toggle between View and Edit only (not Help)
+        match self.format_mode {
+            FormatMode::View => {
+                let selected = self.selected_original_index();
+                self.format_mode = FormatMode::Edit;
+                self.convert_json();
+                if let Some(line) = selected.and_then(|index| entry_lines(self).get(index).copied()) {
+                    self.content_cursor_line = line;
+                    self.content_cursor_col = 0;
+                    self.scroll = line as u16;
+                    self.ensure_cursor_visible();
+                }
+                self.set_status("Edit mode");
+            }
+            FormatMode::Edit | FormatMode::Help => {
+                let lines = entry_lines(self);
+                let cursor = self.content_cursor_line;
+                let sections = Navigator::section_start_lines(&self.get_content_lines(), markdown);
+                // The entry starting last at or above the cursor, unless a section header is in between
+                let under_cursor = (0..lines.len())
+                    .filter(|&index| lines[index] <= cursor)
+                    .max_by_key(|&index| lines[index])
+                    .filter(|&index| !sections.iter().any(|&s| s > lines[index] && s <= cursor));
+                self.format_mode = FormatMode::View;
+                self.hscroll = 0;
+                self.convert_json();
+                if let Some(position) =
+                    under_cursor.and_then(|index| self.relf_entries.iter().position(|e| e.original_index == index))
+                {
+                    self.selected_entry_index = position;
+                }
+                self.set_status("View mode");
+            }
+        }
+    }
+
     pub fn jump_to_first_inside(&mut self) {
         if self.format_mode == FormatMode::Edit {
             // In Edit mode, find the first inside entry
@@ -346,23 +477,20 @@ impl App {
             self.set_status("No INSIDE entries found");
         } else {
             // In View mode, jump to first card in inside section
-            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    let outside_count = obj
-                        .get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-
-                    if let Some(inside) = obj.get("inside") {
-                        if let Some(inside_array) = inside.as_array() {
-                            if !inside_array.is_empty() && outside_count < self.relf_entries.len() {
-                                self.selected_entry_index = outside_count;
-                                self.set_status("Jumped to first INSIDE entry");
-                                return;
-                            }
-                        }
-                    }
+            if let Ok(json_value) = serde_json::from_str::<Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object() {
+                let outside_count = obj
+                    .get("outside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
+
+                if let Some(inside) = obj.get("inside")
+                    && let Some(inside_array) = inside.as_array()
+                        && !inside_array.is_empty() && outside_count < self.relf_entries.len() {
+                    self.selected_entry_index = outside_count;
+                    self.set_status("Jumped to first INSIDE entry");
+                    return;
                 }
             }
             self.set_status("No INSIDE entries found");
@@ -581,3 +709,62 @@ impl App {
     }
 
 }
+
+/// The card selection by entry identity, taken before a reload
+pub(super) struct CardAnchor {
+    /// Identity and occurrence among equal identities of every card, in order
+    ids: Vec<(String, usize)>,
+    selected: usize,
+    /// Rows of cards above the selection on screen
+    offset: usize,
+    hscroll: u16,
+}
+
+fn card_ids(entries: &[RelfEntry]) -> Vec<(String, usize)> {
+    let mut seen = std::collections::HashMap::new();
+    entries
+        .iter()
+        .map(|entry| {
+            let id = entry.identity();
+            let n = seen.entry(id.clone()).or_insert(0);
+            *n += 1;
+            (id, *n)
+        })
+        .collect()
+}
+
+impl App {
+    pub(super) fn card_anchor(&self) -> CardAnchor {
+        let (start, _) = self.visible_card_range();
+        CardAnchor {
+            ids: card_ids(&self.relf_entries),
+            selected: self.selected_entry_index,
+            offset: self.selected_entry_index.saturating_sub(start),
+            hscroll: self.hscroll,
+        }
+    }
+
+    /// Select the card `anchor` had selected, or the nearest one that is still there,
+    /// keeping it at the same place on screen
+    pub(super) fn restore_card_anchor(&mut self, anchor: CardAnchor) {
+        if self.format_mode != FormatMode::View || anchor.ids.is_empty() || self.relf_entries.is_empty() {
+            return;
+        }
+        let ids = card_ids(&self.relf_entries);
+        let find = |i: usize| ids.iter().position(|id| *id == anchor.ids[i]);
+        let selected = anchor.selected.min(anchor.ids.len() - 1);
+        // The card itself, then the ones after it, then the ones before it
+        let found = find(selected).map(|index| (index, true)).or_else(|| {
+            (selected + 1..anchor.ids.len())
+                .chain((0..selected).rev())
+                .find_map(find)
+                .map(|index| (index, false))
+        });
+        let Some((index, same)) = found else {
+            return;
+        };
+        self.selected_entry_index = index;
+        self.card_scroll = index.saturating_sub(anchor.offset);
+        self.hscroll = if same { anchor.hscroll.min(self.relf_max_hscroll()) } else { 0 };
+    }
+}
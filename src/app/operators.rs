@@ -0,0 +1,159 @@
+use super::{App, FormatMode, InputMode};
+use crate::i18n::Msg;
+use crate::navigation::Navigator;
+
+/// Text covered by an operator (d/c) in Edit mode
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Motion {
+    WordStart, // w: up to the next word start (stays on the line)
+    WordEnd,   // e: through the end of the word
+    LineEnd,   // $: to the end of the line
+    InnerWord, // iw: the word (or run of spaces/punctuation) under the cursor
+    Line,      // dd / cc: the whole line
+}
+
+impl App {
+    /// Feed a key to the pending operator (d, c, di, ci).
+    /// Returns false when the key doesn't complete or extend the operator (it is then cancelled).
+    pub fn handle_operator_key(&mut self, c: char) -> bool {
+        let op = std::mem::take(&mut self.pending_operator);
+        let motion = match (op.as_str(), c) {
+            ("d", 'd') | ("c", 'c') => Motion::Line,
+            ("d" | "c", 'w') => Motion::WordStart,
+            ("d" | "c", 'e') => Motion::WordEnd,
+            ("d" | "c", '$') => Motion::LineEnd,
+            ("d" | "c", 'i') => {
+                self.pending_operator = format!("{}i", op);
+                return true;
+            }
+            ("di" | "ci", 'w') => Motion::InnerWord,
+            _ => return false,
+        };
+        let operator = op.chars().next().unwrap_or('d');
+        self.apply_operator(operator, motion);
+        true
+    }
+
+    /// Run `operator` ('d' delete or 'c' change) over `motion` from the cursor as one undo step
+    pub fn apply_operator(&mut self, operator: char, motion: Motion) {
+        if self.format_mode != FormatMode::Edit {
+            return;
+        }
+        if operator == 'd' && motion == Motion::Line {
+            self.delete_line();
+            return;
+        }
+
+        // cw on a word behaves like ce (vim)
+        let motion = if operator == 'c' && motion == Motion::WordStart && self.cursor_on_word_char() {
+            Motion::WordEnd
+        } else {
+            motion
+        };
+
+        let Some((start, end)) = self.motion_range(motion) else {
+            return;
+        };
+
+        if operator == 'c' {
+            self.begin_undo_group();
+        } else {
+            self.save_undo_state();
+        }
+
+        let lines = self.get_content_lines();
+        let mut chars: Vec<char> = lines.join("\n").chars().collect();
+        let removed: String = chars.drain(start..end).collect();
+        let lines: Vec<String> = chars.into_iter().collect::<String>().split('\n').map(|l| l.to_string()).collect();
+        (self.content_cursor_line, self.content_cursor_col) = Self::flat_to_cursor(&lines, start);
+        if !removed.is_empty() {
+            self.line_yank_buffer = removed;
+        }
+        self.set_content_from_lines(lines);
+        self.is_modified = true;
+
+        if operator == 'c' {
+            self.input_mode = InputMode::Insert;
+            self.set_status(self.msg(Msg::Insert));
+        } else {
+            // Normal mode cursor can't rest past the last char
+            let line_len = self
+                .get_content_lines()
+                .get(self.content_cursor_line)
+                .map(|l| l.chars().count())
+                .unwrap_or(0);
+            self.content_cursor_col = self.content_cursor_col.min(line_len.saturating_sub(1));
+        }
+        self.ensure_cursor_visible();
+    }
+
+    fn cursor_on_word_char(&self) -> bool {
+        self.get_content_lines()
+            .get(self.content_cursor_line)
+            .and_then(|l| l.chars().nth(self.content_cursor_col))
+            .is_some_and(Navigator::is_word_char)
+    }
+
+    /// Flat char range `[start, end)` covered by `motion` from the cursor
+    fn motion_range(&mut self, motion: Motion) -> Option<(usize, usize)> {
+        let lines = self.get_content_lines();
+        let line_idx = self.content_cursor_line.min(lines.len().saturating_sub(1));
+        let line: Vec<char> = lines.get(line_idx)?.chars().collect();
+        let line_start = Self::cursor_to_flat(&lines, line_idx, 0);
+        let line_end = line_start + line.len();
+        let cursor = line_start + self.content_cursor_col.min(line.len());
+
+        let range = match motion {
+            Motion::Line => {
+                // cc keeps the indentation
+                let indent = line.iter().take_while(|c| c.is_whitespace()).count();
+                (line_start + indent, line_end)
+            }
+            Motion::LineEnd => (cursor, line_end),
+            Motion::WordStart | Motion::WordEnd => {
+                let saved = (self.content_cursor_line, self.content_cursor_col);
+                if motion == Motion::WordStart {
+                    self.move_to_next_word_start();
+                } else {
+                    self.move_to_next_word_end();
+                }
+                let target = self.cursor_flat_pos();
+                let moved = (self.content_cursor_line, self.content_cursor_col) != saved;
+                (self.content_cursor_line, self.content_cursor_col) = saved;
+
+                let end = if !moved {
+                    line_end
+                } else if motion == Motion::WordEnd {
+                    target + 1
+                } else {
+                    // dw never joins lines
+                    target.min(line_end)
+                };
+                (cursor, end.max(cursor))
+            }
+            Motion::InnerWord => {
+                let col = self.content_cursor_col.min(line.len().saturating_sub(1));
+                let &ch = line.get(col)?;
+                let class = |c: char| {
+                    if Navigator::is_word_char(c) {
+                        0
+                    } else if c.is_whitespace() {
+                        1
+                    } else {
+                        2
+                    }
+                };
+                let mut from = col;
+                while from > 0 && class(line[from - 1]) == class(ch) {
+                    from -= 1;
+                }
+                let mut to = col + 1;
+                while to < line.len() && class(line[to]) == class(ch) {
+                    to += 1;
+                }
+                (line_start + from, line_start + to)
+            }
+        };
+        Some(range)
+    }
+}
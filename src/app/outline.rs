@@ -1,4 +1,98 @@
 use super::{App, FormatMode};
+use crate::content_ops::UNTAGGED;
+use crate::rendering::{RelfEntry, Renderer};
+use std::ops::Range;
+
+/// How the card outline is arranged (`:outline group ...`)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OutlineGroup {
+    /// One row per card
+    #[default]
+    None,
+    /// INSIDE cards by month; OUTSIDE cards together
+    Date,
+    /// Cards under each of their tags
+    Tag,
+    /// OUTSIDE cards by priority, highest first; INSIDE cards together
+    Priority,
+}
+
+impl OutlineGroup {
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(OutlineGroup::None),
+            "date" | "month" => Some(OutlineGroup::Date),
+            "tag" | "tags" => Some(OutlineGroup::Tag),
+            "priority" => Some(OutlineGroup::Priority),
+            _ => None,
+        }
+    }
+
+    /// Groups a card goes under, with a sort key (groups with equal keys keep card order)
+    fn keys(self, entry: &RelfEntry) -> Vec<(u8, String)> {
+        let inside = entry.date.is_some();
+        match self {
+            OutlineGroup::None => Vec::new(),
+            OutlineGroup::Date => match &entry.date {
+                Some(date) => {
+                    // `YYYY-MM` of the `YYYY-MM-DD HH:MM:SS` date
+                    let month = date.get(..7).filter(|month| month.as_bytes()[4] == b'-' && month.replace('-', "").bytes().all(|b| b.is_ascii_digit()));
+                    vec![(0, month.unwrap_or("(no date)").to_string())]
+                }
+                None => vec![(0, "OUTSIDE".to_string())],
+            },
+            OutlineGroup::Tag => {
+                let mut tags: Vec<String> = match entry.field("tags") {
+                    Some(text) => serde_json::from_str::<Vec<String>>(&text).unwrap_or_else(|_| vec![text]),
+                    None => Vec::new(),
+                };
+                tags.retain(|tag| !tag.trim().is_empty());
+                tags.dedup();
+                if tags.is_empty() {
+                    return vec![(1, UNTAGGED.to_string())];
+                }
+                tags.into_iter().map(|tag| (0, tag)).collect()
+            }
+            OutlineGroup::Priority if inside => vec![(7, "INSIDE".to_string())],
+            OutlineGroup::Priority => match entry.priority {
+                Some(rank) => {
+                    let label = match rank {
+                        5 => "high".to_string(),
+                        3 => "medium".to_string(),
+                        1 => "low".to_string(),
+                        n => n.to_string(),
+                    };
+                    vec![(5 - rank, format!("priority {}", label))]
+                }
+                None => vec![(6, "(no priority)".to_string())],
+            },
+        }
+    }
+}
+
+/// One row of a grouped outline
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum OutlineRow {
+    /// A group header with the cards (indices into `relf_entries`) under it
+    Group { title: String, cards: Vec<usize> },
+    Card(usize),
+}
+
+/// First line of a card (name or date), truncated for the outline panel
+fn card_outline_title(entry: &RelfEntry) -> String {
+    let title = entry.lines.first().cloned().unwrap_or_default();
+    if title.len() > 80 {
+        // Use char_indices to safely truncate at UTF-8 boundary
+        let truncate_at = title.char_indices()
+            .take(77)
+            .last()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        format!("{}...", &title[..truncate_at])
+    } else {
+        title
+    }
+}
 
 impl App {
     pub fn toggle_outline(&mut self) {
@@ -24,12 +118,82 @@ impl App {
         }
     }
 
+    /// Rows of the grouped outline; None for the flat one (no grouping, or not on cards)
+    pub(crate) fn outline_rows(&self) -> Option<Vec<OutlineRow>> {
+        if self.outline_group == OutlineGroup::None || self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            return None;
+        }
+        let mut groups: Vec<(u8, String, Vec<usize>)> = Vec::new();
+        for (idx, entry) in self.relf_entries.iter().enumerate() {
+            for (order, title) in self.outline_group.keys(entry) {
+                match groups.iter_mut().find(|(_, existing, _)| *existing == title) {
+                    Some((_, _, cards)) => cards.push(idx),
+                    None => groups.push((order, title, vec![idx])),
+                }
+            }
+        }
+        // Stable, so groups of equal rank stay in card order
+        groups.sort_by_key(|(order, _, _)| *order);
+
+        let mut rows = Vec::new();
+        for (_, title, cards) in groups {
+            let collapsed = self.outline_collapsed.contains(&title);
+            let card_rows: Vec<OutlineRow> = if collapsed { Vec::new() } else { cards.iter().map(|&idx| OutlineRow::Card(idx)).collect() };
+            rows.push(OutlineRow::Group { title, cards });
+            rows.extend(card_rows);
+        }
+        Some(rows)
+    }
+
+    /// :outline group date|tag|priority|none
+    pub fn set_outline_group(&mut self, name: &str) {
+        let Some(group) = OutlineGroup::by_name(name) else {
+            self.set_status("Usage: :outline group date|tag|priority|none");
+            return;
+        };
+        self.outline_group = group;
+        self.outline_collapsed.clear();
+        self.outline_selected_index = 0;
+        self.outline_scroll = 0;
+        if !self.outline_open {
+            self.toggle_outline();
+        }
+        self.set_status(&format!("Outline grouped by {}", name));
+    }
+
+    /// Card index of an outline row (the row itself when the outline is flat)
+    fn outline_card(&self, row: usize) -> Option<usize> {
+        match self.outline_rows() {
+            Some(rows) => match rows.get(row)? {
+                OutlineRow::Card(idx) => Some(*idx),
+                OutlineRow::Group { .. } => None,
+            },
+            None => Some(row),
+        }
+    }
+
+    /// Fold or unfold the group on the selected row; false when it is not a group
+    pub fn outline_toggle_group(&mut self) -> bool {
+        let Some(rows) = self.outline_rows() else {
+            return false;
+        };
+        let Some(OutlineRow::Group { title, .. }) = rows.get(self.outline_selected_index) else {
+            return false;
+        };
+        if let Some(pos) = self.outline_collapsed.iter().position(|t| t == title) {
+            self.outline_collapsed.remove(pos);
+        } else {
+            self.outline_collapsed.push(title.clone());
+        }
+        true
+    }
+
     /// Preview entry from outline without closing (like go in explorer)
     pub fn outline_preview_entry(&mut self) {
         if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
             // Jump to selected card in View mode without closing outline
-            if self.outline_selected_index < self.relf_entries.len() {
-                self.selected_entry_index = self.outline_selected_index;
+            if let Some(card) = self.outline_card(self.outline_selected_index).filter(|&card| card < self.relf_entries.len()) {
+                self.selected_entry_index = card;
             }
         } else if self.format_mode == FormatMode::Edit {
             // Jump to selected entry in Edit mode without closing outline
@@ -49,7 +213,7 @@ impl App {
 
     pub fn outline_move_down(&mut self) {
         let max_index = if self.format_mode == FormatMode::View {
-            self.relf_entries.len().saturating_sub(1)
+            self.outline_len().saturating_sub(1)
         } else {
             // In Edit mode, get entry count from markdown/json
             self.get_entry_count_from_content().saturating_sub(1)
@@ -62,7 +226,7 @@ impl App {
 
     pub fn outline_page_down(&mut self) {
         let max_index = if self.format_mode == FormatMode::View {
-            self.relf_entries.len().saturating_sub(1)
+            self.outline_len().saturating_sub(1)
         } else {
             self.get_entry_count_from_content().saturating_sub(1)
         };
@@ -80,8 +244,8 @@ impl App {
     pub fn outline_jump_to_selected(&mut self) {
         if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
             // Jump to selected card in View mode (keep outline open)
-            if self.outline_selected_index < self.relf_entries.len() {
-                self.selected_entry_index = self.outline_selected_index;
+            if let Some(card) = self.outline_card(self.outline_selected_index).filter(|&card| card < self.relf_entries.len()) {
+                self.selected_entry_index = card;
                 // Reset horizontal scroll when jumping to new card
                 self.hscroll = 0;
             }
@@ -119,30 +283,33 @@ impl App {
             count
         } else {
             // Count entries in JSON (outside + inside)
-            if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.json_input) {
-                if let Some(obj) = json_value.as_object() {
-                    let outside_count = obj.get("outside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-                    let inside_count = obj.get("inside")
-                        .and_then(|v| v.as_array())
-                        .map(|arr| arr.len())
-                        .unwrap_or(0);
-                    return outside_count + inside_count;
-                }
+            if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.json_input)
+                && let Some(obj) = json_value.as_object() {
+                let outside_count = obj.get("outside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
+                let inside_count = obj.get("inside")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.len())
+                    .unwrap_or(0);
+                return outside_count + inside_count;
             }
             0
         }
     }
 
     fn get_entry_start_line(&self, entry_index: usize) -> Option<usize> {
+        self.entry_start_lines().get(entry_index).copied()
+    }
+
+    /// Line each entry starts on in the Edit-mode content, in outline order
+    fn entry_start_lines(&self) -> Vec<usize> {
+        let mut starts = Vec::new();
         if self.is_markdown_file() {
-            // Find the nth ### header, excluding code blocks
-            let lines = self.markdown_input.lines().collect::<Vec<_>>();
+            // ### headers, excluding code blocks
             let mut in_code_block = false;
-            let mut count = 0;
-            for (i, line) in lines.iter().enumerate() {
+            for (i, line) in self.markdown_input.lines().enumerate() {
                 // Track code block state
                 if line.trim_start().starts_with("```") {
                     in_code_block = !in_code_block;
@@ -155,59 +322,126 @@ impl App {
                 }
 
                 if line.trim_start().starts_with("### ") {
-                    if count == entry_index {
-                        return Some(i);
-                    }
-                    count += 1;
+                    starts.push(i);
                 }
             }
         } else {
-            // For JSON, find entry in rendered content
+            // For JSON, name/date fields indicate entry boundaries
             // This is approximate but should work
-            let lines = self.json_input.lines().collect::<Vec<_>>();
-            let mut entries_found = 0;
-
-            // Look for name/date fields which indicate entry boundaries
-            for (i, line) in lines.iter().enumerate() {
+            for (i, line) in self.json_input.lines().enumerate() {
                 let trimmed = line.trim();
-                if (trimmed.starts_with("\"name\":") || trimmed.starts_with("\"date\":"))
-                    && entries_found == entry_index {
-                    return Some(i);
-                }
                 if trimmed.starts_with("\"name\":") || trimmed.starts_with("\"date\":") {
-                    entries_found += 1;
+                    starts.push(i);
                 }
             }
         }
-        None
+        starts
+    }
+
+    /// Matches of the search (or, without one, of a text filter) in each outline row of
+    /// `range`; None when neither is active
+    pub fn outline_match_counts(&self, range: Range<usize>) -> Option<Vec<usize>> {
+        let Some(rows) = self.outline_rows() else {
+            return self.card_match_counts(range);
+        };
+        // A group counts the matches of its cards
+        let per_card = self.card_match_counts(0..self.relf_entries.len())?;
+        let rows = rows.get(range)?;
+        Some(
+            rows.iter()
+                .map(|row| match row {
+                    OutlineRow::Card(idx) => per_card[*idx],
+                    OutlineRow::Group { cards, .. } => cards.iter().map(|&idx| per_card[idx]).sum(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Match counts per flat outline row (card or Edit-mode entry) in `range`
+    fn card_match_counts(&self, range: Range<usize>) -> Option<Vec<usize>> {
+        let cards = self.format_mode == FormatMode::View && !self.relf_entries.is_empty();
+        let mut counts = vec![0; range.len()];
+        let mut count = |row: usize| {
+            if range.contains(&row) {
+                counts[row - range.start] += 1;
+            }
+        };
+        if !self.search_query.is_empty() {
+            if cards {
+                // Card matches are stored as (card, char position)
+                for &(card, _) in &self.search_matches {
+                    count(card);
+                }
+            } else if self.format_mode == FormatMode::Edit {
+                // A line belongs to the last entry starting at or above it
+                let starts = self.entry_start_lines();
+                for &(line, _) in &self.search_matches {
+                    if let Some(entry) = starts.partition_point(|&start| start <= line).checked_sub(1) {
+                        count(entry);
+                    }
+                }
+            } else {
+                return None;
+            }
+            return Some(counts);
+        }
+        let re = Renderer::filter_regex(&self.filter_pattern).filter(|_| cards)?;
+        for (offset, entry) in self.relf_entries.get(range.clone())?.iter().enumerate() {
+            for line in entry.lines.iter().chain(&entry.field_lines()) {
+                for _ in re.find_iter(line) {
+                    count(range.start + offset);
+                }
+            }
+        }
+        Some(counts)
+    }
+
+    /// Number of outline rows; in View mode no titles are built to count them
+    pub fn outline_len(&self) -> usize {
+        if let Some(rows) = self.outline_rows() {
+            rows.len()
+        } else if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
+            self.relf_entries.len()
+        } else {
+            self.get_outline_entries().len()
+        }
+    }
+
+    /// Outline rows in `range`; in View mode only these titles are built
+    pub fn outline_window(&self, range: Range<usize>) -> Vec<String> {
+        if let Some(rows) = self.outline_rows() {
+            rows.get(range)
+                .map(|rows| {
+                    rows.iter()
+                        .map(|row| match row {
+                            OutlineRow::Group { title, cards } => {
+                                let marker = if self.outline_collapsed.contains(title) { self.glyph("▸", "+") } else { self.glyph("▾", "-") };
+                                format!("{} {} [{}]", marker, title, cards.len())
+                            }
+                            OutlineRow::Card(idx) => format!("  {}", card_outline_title(&self.relf_entries[*idx])),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
+            self.relf_entries
+                .get(range)
+                .map(|entries| entries.iter().map(card_outline_title).collect())
+                .unwrap_or_default()
+        } else {
+            self.get_outline_entries().get(range).map(<[String]>::to_vec).unwrap_or_default()
+        }
     }
 
     pub fn get_outline_entries(&self) -> Vec<String> {
         let mut entries = Vec::new();
 
-        if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
+        if self.outline_rows().is_some() {
+            // Group headers and indented cards, as shown
+            entries.extend(self.outline_window(0..self.outline_len()));
+        } else if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
             // Use relf_entries for View mode
-            for entry in self.relf_entries.iter() {
-                // Get the first line as the title/summary
-                let title = entry.lines.first()
-                    .map(|s| s.clone())
-                    .unwrap_or_else(|| "".to_string());
-
-                // Truncate if too long
-                let display_title = if title.len() > 80 {
-                    // Use char_indices to safely truncate at UTF-8 boundary
-                    let truncate_at = title.char_indices()
-                        .take(77)
-                        .last()
-                        .map(|(i, _)| i)
-                        .unwrap_or(0);
-                    format!("{}...", &title[..truncate_at])
-                } else {
-                    title
-                };
-
-                entries.push(display_title);
-            }
+            entries.extend(self.relf_entries.iter().map(card_outline_title));
         } else if self.format_mode == FormatMode::Edit {
             // Parse from markdown or JSON
             if self.is_markdown_file() {
@@ -242,51 +476,50 @@ impl App {
                 }
             } else {
                 // Parse JSON
-                if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.json_input) {
-                    if let Some(obj) = json_value.as_object() {
-                        // Add OUTSIDE entries
-                        if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                            for item in outside {
-                                if let Some(item_obj) = item.as_object() {
-                                    let name = item_obj.get("name")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("Unnamed");
-                                    let display_title = if name.len() > 80 {
-                                        // Use char_indices to safely truncate at UTF-8 boundary
-                                        let truncate_at = name.char_indices()
-                                            .take(77)
-                                            .last()
-                                            .map(|(i, _)| i)
-                                            .unwrap_or(0);
-                                        format!("{}...", &name[..truncate_at])
-                                    } else {
-                                        name.to_string()
-                                    };
-                                    entries.push(display_title);
-                                }
+                if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.json_input)
+                    && let Some(obj) = json_value.as_object() {
+                    // Add OUTSIDE entries
+                    if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
+                        for item in outside {
+                            if let Some(item_obj) = item.as_object() {
+                                let name = item_obj.get("name")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("Unnamed");
+                                let display_title = if name.len() > 80 {
+                                    // Use char_indices to safely truncate at UTF-8 boundary
+                                    let truncate_at = name.char_indices()
+                                        .take(77)
+                                        .last()
+                                        .map(|(i, _)| i)
+                                        .unwrap_or(0);
+                                    format!("{}...", &name[..truncate_at])
+                                } else {
+                                    name.to_string()
+                                };
+                                entries.push(display_title);
                             }
                         }
+                    }
 
-                        // Add INSIDE entries
-                        if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                            for item in inside {
-                                if let Some(item_obj) = item.as_object() {
-                                    let date = item_obj.get("date")
-                                        .and_then(|v| v.as_str())
-                                        .unwrap_or("No date");
-                                    let display_title = if date.len() > 80 {
-                                        // Use char_indices to safely truncate at UTF-8 boundary
-                                        let truncate_at = date.char_indices()
-                                            .take(77)
-                                            .last()
-                                            .map(|(i, _)| i)
-                                            .unwrap_or(0);
-                                        format!("{}...", &date[..truncate_at])
-                                    } else {
-                                        date.to_string()
-                                    };
-                                    entries.push(display_title);
-                                }
+                    // Add INSIDE entries
+                    if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
+                        for item in inside {
+                            if let Some(item_obj) = item.as_object() {
+                                let date = item_obj.get("date")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("No date");
+                                let display_title = if date.len() > 80 {
+                                    // Use char_indices to safely truncate at UTF-8 boundary
+                                    let truncate_at = date.char_indices()
+                                        .take(77)
+                                        .last()
+                                        .map(|(i, _)| i)
+                                        .unwrap_or(0);
+                                    format!("{}...", &date[..truncate_at])
+                                } else {
+                                    date.to_string()
+                                };
+                                entries.push(display_title);
                             }
                         }
                     }
@@ -313,16 +546,16 @@ impl App {
         let start_index = self.outline_selected_index + 1;
 
         // Search forward from current position
-        for i in start_index..entries.len() {
-            if entries[i].to_lowercase().contains(&search_pattern.to_lowercase()) {
+        for (i, entry) in entries.iter().enumerate().skip(start_index) {
+            if entry.to_lowercase().contains(&search_pattern.to_lowercase()) {
                 self.outline_selected_index = i;
                 return;
             }
         }
 
         // Wrap around to beginning
-        for i in 0..start_index {
-            if entries[i].to_lowercase().contains(&search_pattern.to_lowercase()) {
+        for (i, entry) in entries.iter().enumerate().take(start_index) {
+            if entry.to_lowercase().contains(&search_pattern.to_lowercase()) {
                 self.outline_selected_index = i;
                 return;
             }
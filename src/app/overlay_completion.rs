@@ -0,0 +1,104 @@
+use super::App;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+/// Suggestions shown at most
+const MAX_COMPLETIONS: usize = 8;
+
+impl App {
+    /// Names and tags already in the document, for completing overlay fields
+    pub(super) fn build_edit_completion_index(&mut self) {
+        let mut names = BTreeSet::new();
+        let mut tags = BTreeSet::new();
+        if let Ok(Value::Object(obj)) = serde_json::from_str::<Value>(&self.json_input) {
+            for (section, entries) in obj.iter().filter_map(|(key, value)| Some((key, value.as_array()?))) {
+                for entry in entries {
+                    if section == "outside"
+                        && let Some(name) = entry.get("name").and_then(Value::as_str).filter(|n| !n.is_empty()) {
+                        names.insert(name.to_string());
+                    }
+                    match entry.get("tags") {
+                        Some(Value::Array(list)) => tags.extend(list.iter().filter_map(Value::as_str).map(str::to_string)),
+                        Some(Value::String(tag)) if !tag.is_empty() => {
+                            tags.insert(tag.clone());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        self.edit_completion_index = HashMap::from([
+            ("name".to_string(), names.into_iter().collect()),
+            ("tags".to_string(), tags.into_iter().collect()),
+        ]);
+        self.edit_completions.clear();
+    }
+
+    /// Start (in chars) and text of what is being completed: the whole name up to the
+    /// cursor, or the tag word the cursor is in (`["rust", "bo|`)
+    fn edit_completion_prefix(&self) -> Option<(usize, String)> {
+        let field: Vec<char> = self.edit_buffer.get(self.edit_field_index)?.chars().collect();
+        let cursor = self.edit_cursor_pos.min(field.len());
+        let start = match self.edit_field_key(self.edit_field_index) {
+            "name" if !self.edit_is_inside() => 0,
+            "tags" => field[..cursor]
+                .iter()
+                .rposition(|&c| matches!(c, '[' | ']' | '"' | ',') || c.is_whitespace())
+                .map_or(0, |i| i + 1),
+            _ => return None,
+        };
+        let prefix: String = field[start..cursor].iter().collect();
+        (!prefix.trim().is_empty()).then_some((start, prefix))
+    }
+
+    /// Refresh the suggestions after the field changed in Insert mode
+    pub fn update_edit_completions(&mut self) {
+        self.edit_completions.clear();
+        self.edit_completion_selected = 0;
+        let Some((_, prefix)) = self.edit_completion_prefix() else {
+            return;
+        };
+        let key = self.edit_field_key(self.edit_field_index);
+        let Some(values) = self.edit_completion_index.get(key) else {
+            return;
+        };
+        let lower = prefix.to_lowercase();
+        let field = &self.edit_buffer[self.edit_field_index];
+        self.edit_completions = values
+            .iter()
+            .filter(|value| value.to_lowercase().starts_with(&lower) && **value != prefix)
+            // A tag already in the list is not offered again
+            .filter(|value| key != "tags" || !field.contains(&format!("\"{}\"", value)))
+            .take(MAX_COMPLETIONS)
+            .cloned()
+            .collect();
+    }
+
+    /// Ctrl+n / Ctrl+p: move through the suggestions
+    pub fn cycle_edit_completion(&mut self, step: isize) {
+        let len = self.edit_completions.len() as isize;
+        if len > 0 {
+            self.edit_completion_selected = (self.edit_completion_selected as isize + step).rem_euclid(len) as usize;
+        }
+    }
+
+    /// Tab: replace the completed text with the selected suggestion
+    pub fn accept_edit_completion(&mut self) {
+        let Some(value) = self.edit_completions.get(self.edit_completion_selected).cloned() else {
+            return;
+        };
+        let Some((start, _)) = self.edit_completion_prefix() else {
+            return;
+        };
+        let field = &mut self.edit_buffer[self.edit_field_index];
+        let mut chars: Vec<char> = field.chars().collect();
+        let cursor = self.edit_cursor_pos.min(chars.len());
+        chars.splice(start..cursor, value.chars());
+        *field = chars.into_iter().collect();
+        self.edit_cursor_pos = start + value.chars().count();
+        if let Some(is_placeholder) = self.edit_buffer_is_placeholder.get_mut(self.edit_field_index) {
+            *is_placeholder = false;
+        }
+        self.edit_completions.clear();
+    }
+}
@@ -0,0 +1,72 @@
+use super::App;
+use crate::fuzzy;
+use crate::keymap;
+
+impl App {
+    /// Space / Ctrl+k: list the actions of the current mode to fuzzy-find and run one
+    pub fn open_palette(&mut self) {
+        self.palette_items = keymap::palette_items(self.key_mode());
+        self.palette_open = true;
+        self.palette_query.clear();
+        self.update_palette_matches();
+        self.set_status("Command palette (Enter: run, Esc: close)");
+    }
+
+    pub fn close_palette(&mut self) {
+        self.palette_open = false;
+        self.palette_query.clear();
+        self.palette_items.clear();
+        self.palette_matches.clear();
+        self.palette_selected = 0;
+    }
+
+    /// Rank the actions against the query, keeping palette order while it is empty
+    pub fn update_palette_matches(&mut self) {
+        self.palette_matches = if self.palette_query.trim().is_empty() {
+            (0..self.palette_items.len()).map(|index| (index, Vec::new())).collect()
+        } else {
+            let titles: Vec<String> = self.palette_items.iter().map(|item| item.title.to_string()).collect();
+            fuzzy::rank(&self.palette_query, &titles)
+        };
+        self.palette_selected = 0;
+    }
+
+    pub fn palette_push_char(&mut self, c: char) {
+        self.palette_query.push(c);
+        self.update_palette_matches();
+    }
+
+    pub fn palette_backspace(&mut self) {
+        if self.palette_query.pop().is_some() {
+            self.update_palette_matches();
+        }
+    }
+
+    pub fn palette_clear_query(&mut self) {
+        self.palette_query.clear();
+        self.update_palette_matches();
+    }
+
+    pub fn palette_move(&mut self, step: isize) {
+        let len = self.palette_matches.len() as isize;
+        if len > 0 {
+            self.palette_selected = (self.palette_selected as isize + step).clamp(0, len - 1) as usize;
+        }
+    }
+
+    /// Enter: close the palette and run the selected action. Returns true to quit.
+    pub fn run_selected_palette_item(&mut self) -> bool {
+        let action = self
+            .palette_matches
+            .get(self.palette_selected)
+            .and_then(|(index, _)| self.palette_items.get(*index))
+            .map(|item| item.action);
+        let Some(action) = action else {
+            self.set_status("No matching action");
+            return false;
+        };
+        self.close_palette();
+        self.set_status("");
+        self.run_action(action, None)
+    }
+}
@@ -0,0 +1,59 @@
+use super::App;
+use ratatui::layout::{Margin, Position, Rect};
+
+/// Row of a bordered list panel drawn in `area` and scrolled by `scroll` under a mouse
+/// position; None off the panel, on its border, or past its last row
+fn list_row(area: Rect, scroll: usize, len: usize, column: u16, row: u16) -> Option<usize> {
+    let inner = area.inner(Margin::new(1, 1));
+    if !inner.contains(Position::new(column, row)) {
+        return None;
+    }
+    let index = scroll + (row - inner.y) as usize;
+    (index < len).then_some(index)
+}
+
+impl App {
+    /// The mouse is over the explorer panel as last drawn
+    pub fn over_explorer(&self, column: u16, row: u16) -> bool {
+        self.explorer_open && self.explorer_area.contains(Position::new(column, row))
+    }
+
+    /// The mouse is over the outline panel as last drawn
+    pub fn over_outline(&self, column: u16, row: u16) -> bool {
+        self.outline_open && self.outline_area.contains(Position::new(column, row))
+    }
+
+    /// Click in the explorer: select the entry under the mouse and open the file or fold
+    /// the folder. False when the click is not on the explorer.
+    pub fn explorer_click(&mut self, column: u16, row: u16) -> bool {
+        if !self.over_explorer(column, row) {
+            return false;
+        }
+        self.focus_explorer();
+        let total = self.explorer_entries.len();
+        let scroll = (self.explorer_scroll as usize).min(total.saturating_sub(1));
+        if let Some(index) = list_row(self.explorer_area, scroll, total, column, row) {
+            self.explorer_selected_index = index;
+            self.explorer_select_entry();
+        }
+        true
+    }
+
+    /// Click in the outline: fold the group under the mouse, or jump to its entry and give
+    /// focus back to the content like Enter. False when the click is not on the outline.
+    pub fn outline_click(&mut self, column: u16, row: u16) -> bool {
+        if !self.over_outline(column, row) {
+            return false;
+        }
+        self.focus_outline();
+        let total = self.outline_len();
+        if let Some(index) = list_row(self.outline_area, self.outline_scroll as usize, total, column, row) {
+            self.outline_selected_index = index;
+            if !self.outline_toggle_group() {
+                self.outline_jump_to_selected();
+                self.outline_has_focus = false;
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,27 @@
+use super::App;
+use crate::preview;
+use std::path::{Path, PathBuf};
+
+impl App {
+    /// Local file behind the image URL of the selected card. Resolving stats the
+    /// filesystem, so it is done once per URL rather than every frame
+    pub fn preview_path(&mut self, url: &str) -> Option<PathBuf> {
+        let base_dir = self.file_path.as_deref().and_then(Path::parent).map(Path::to_path_buf);
+        self.preview_paths
+            .entry((url.to_string(), base_dir))
+            .or_insert_with_key(|(url, base_dir)| preview::local_image_path(url, base_dir.as_deref()))
+            .clone()
+    }
+
+    /// Called by the card renderer with the selected card's http(s) image URL, if any:
+    /// remote images are not fetched, so say so once when such a card gets selected
+    pub fn note_remote_preview(&mut self, url: Option<String>) {
+        if url == self.preview_remote {
+            return;
+        }
+        if let Some(url) = &url {
+            self.set_status(&format!("Remote images are not previewed, only local files: {}", url));
+        }
+        self.preview_remote = url;
+    }
+}
@@ -0,0 +1,58 @@
+use super::App;
+use crate::recent;
+
+impl App {
+    /// Put the open file at the top of the recent files list
+    pub(super) fn record_recent_file(&mut self) {
+        if let (Some(list_path), Some(file_path)) = (&self.recent_list_path, &self.file_path) {
+            // A list that cannot be written is not worth an error over the file that did load
+            let _ = recent::record(list_path, file_path);
+        }
+    }
+
+    /// Open the recent files list (:recent, and at startup without a file).
+    /// Files that no longer exist and the open file itself are left out.
+    pub fn open_recent_list(&mut self) {
+        let Some(list_path) = self.recent_list_path.clone() else {
+            self.set_status("No data directory for recent files");
+            return;
+        };
+        let current = self.file_path.as_ref().and_then(|path| path.canonicalize().ok());
+        self.recent_files = recent::load(&list_path)
+            .into_iter()
+            .filter(|path| path.is_file() && Some(path) != current.as_ref())
+            .collect();
+        if self.recent_files.is_empty() {
+            self.set_status("No recent files");
+            return;
+        }
+        self.recent_open = true;
+        self.recent_selected_index = 0;
+        self.set_status(&format!("{} recent files (Enter: open, Esc: close)", self.recent_files.len()));
+    }
+
+    pub fn close_recent_list(&mut self) {
+        self.recent_open = false;
+        self.recent_files.clear();
+        self.recent_selected_index = 0;
+    }
+
+    pub fn recent_list_move_down(&mut self) {
+        if self.recent_selected_index + 1 < self.recent_files.len() {
+            self.recent_selected_index += 1;
+        }
+    }
+
+    pub fn recent_list_move_up(&mut self) {
+        self.recent_selected_index = self.recent_selected_index.saturating_sub(1);
+    }
+
+    /// Open the selected file and close the list
+    pub fn open_selected_recent_file(&mut self) {
+        let Some(path) = self.recent_files.get(self.recent_selected_index).cloned() else {
+            return;
+        };
+        self.close_recent_list();
+        self.open_file(path);
+    }
+}
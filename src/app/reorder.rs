@@ -0,0 +1,158 @@
+use super::{App, FormatMode};
+use crate::config::InsideOrder;
+use crate::i18n::Msg;
+use crate::json_ops::JsonOperations;
+
+impl App {
+    /// J / K / :move N - move the selected card within its section. A card already at the
+    /// section's edge moves on into the other section (OUTSIDE below, INSIDE above).
+    pub fn move_selected_card(&mut self, offset: isize) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let Some(original_index) = self.selected_original_index() else {
+            return;
+        };
+        let outside_count = self.outside_count();
+        let inside = original_index >= outside_count;
+        let at_top = original_index == 0 || original_index == outside_count;
+        let at_bottom = original_index + 1 == outside_count || original_index + 1 == self.entry_count();
+
+        // Moving within a date-sorted INSIDE list would not show
+        if inside && self.inside_order == InsideOrder::NewestFirst {
+            self.set_status("INSIDE cards are shown newest first (:set inside_order=file_order to move them)");
+            return;
+        }
+
+        if offset > 0 && at_bottom {
+            if inside {
+                self.set_status("Already the last card");
+            } else {
+                self.move_card_across(original_index);
+            }
+            return;
+        }
+        if offset < 0 && at_top {
+            if inside {
+                self.move_card_across(original_index);
+            } else {
+                self.set_status("Already the first card");
+            }
+            return;
+        }
+
+        match JsonOperations::move_entry(&self.json_input, original_index, offset) {
+            Ok((formatted, new_index)) => {
+                let moved = new_index.abs_diff(original_index);
+                let direction = if offset < 0 { "up" } else { "down" };
+                self.apply_card_move(formatted, new_index);
+                if moved == 1 {
+                    self.set_status(&format!("Moved card {}", direction));
+                } else {
+                    self.set_status(&format!("Moved card {} {} places", direction, moved));
+                }
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
+        }
+    }
+
+    /// :move inside / :move outside - move the selected card to that section
+    pub fn move_selected_card_to(&mut self, section: &str) {
+        if self.format_mode != FormatMode::View || self.relf_entries.is_empty() {
+            self.set_status(self.msg(Msg::NotInCardView));
+            return;
+        }
+        let Some(original_index) = self.selected_original_index() else {
+            return;
+        };
+        let inside = original_index >= self.outside_count();
+        if (section == "inside") == inside {
+            self.set_status(&format!("Card is already in {}", section.to_uppercase()));
+            return;
+        }
+        self.move_card_across(original_index);
+    }
+
+    /// Move to the other section right away when nothing would be lost, otherwise ask first
+    fn move_card_across(&mut self, original_index: usize) {
+        let lost = match JsonOperations::fields_lost_across_sections(&self.json_input, original_index) {
+            Ok(lost) => lost,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                return;
+            }
+        };
+        if lost.is_empty() {
+            self.finish_card_move_across(original_index, false);
+            return;
+        }
+        let to = if original_index < self.outside_count() { "INSIDE" } else { "OUTSIDE" };
+        self.card_move_pending = Some(original_index);
+        self.set_status(&format!(
+            "{} has no {}: (f)old into context, (d)rop, (c)ancel?",
+            to,
+            lost.join(", ")
+        ));
+    }
+
+    /// Answer the move prompt: f = fold the fields into the context, d = drop them, c = cancel
+    pub fn handle_card_move_confirmation(&mut self, answer: char) {
+        let Some(original_index) = self.card_move_pending else {
+            return;
+        };
+        match answer {
+            'f' | 'd' => {
+                self.card_move_pending = None;
+                self.finish_card_move_across(original_index, answer == 'f');
+            }
+            'c' => {
+                self.card_move_pending = None;
+                self.set_status("Move cancelled");
+            }
+            _ => {}
+        }
+    }
+
+    fn finish_card_move_across(&mut self, original_index: usize, fold: bool) {
+        let to = if original_index < self.outside_count() { "INSIDE" } else { "OUTSIDE" };
+        match JsonOperations::move_entry_across(&self.json_input, original_index, fold) {
+            Ok((formatted, new_index)) => {
+                self.apply_card_move(formatted, new_index);
+                self.set_status(&format!("Moved to {}", to));
+            }
+            Err(e) => self.set_status(&self.msg_with(Msg::Error, &[&e])),
+        }
+    }
+
+    fn apply_card_move(&mut self, formatted: String, new_index: usize) {
+        self.save_undo_state();
+        self.json_input = formatted;
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        if let Some(idx) = self.relf_entries.iter().position(|entry| entry.original_index == new_index) {
+            self.selected_entry_index = idx;
+        }
+        self.autosave();
+    }
+
+    fn outside_count(&self) -> usize {
+        serde_json::from_str::<serde_json::Value>(&self.json_input)
+            .ok()
+            .and_then(|v| v.get("outside").and_then(|o| o.as_array()).map(|arr| arr.len()))
+            .unwrap_or(0)
+    }
+
+    fn entry_count(&self) -> usize {
+        serde_json::from_str::<serde_json::Value>(&self.json_input)
+            .ok()
+            .map(|v| {
+                ["outside", "inside"]
+                    .iter()
+                    .filter_map(|section| v.get(section).and_then(|s| s.as_array()).map(|arr| arr.len()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+}
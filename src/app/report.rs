@@ -0,0 +1,22 @@
+use super::App;
+use crate::i18n::Msg;
+use crate::report;
+use chrono::Local;
+
+impl App {
+    /// :report - copy the weekly review summary of the document to the clipboard
+    pub fn copy_report(&mut self) {
+        let text = match report::generate(&self.json_input, Local::now().date_naive()) {
+            Ok(text) => text,
+            Err(e) => {
+                self.set_status(&format!("Report error: {}", e));
+                return;
+            }
+        };
+        let lines = text.lines().count();
+        match self.clipboard_set_text(text) {
+            Ok(()) => self.set_status(&format!("Report copied to clipboard ({} lines)", lines)),
+            Err(e) => self.set_status(&self.msg_with(Msg::ClipboardError, &[&e])),
+        }
+    }
+}
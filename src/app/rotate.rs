@@ -0,0 +1,52 @@
+use super::App;
+use crate::i18n::Msg;
+use crate::rotate;
+use chrono::Local;
+use serde_json::Value;
+
+impl App {
+    /// :rotate monthly - move INSIDE notes from before this month into monthly archive
+    /// files next to the open file, then save it
+    pub fn rotate_inside(&mut self, period: &str) {
+        if period != "monthly" {
+            self.set_status("Usage: :rotate monthly");
+            return;
+        }
+        let Some(path) = self.file_path.clone() else {
+            self.set_status("No file to rotate");
+            return;
+        };
+        if self.is_journal() {
+            self.set_status("Journal files keep their INSIDE history");
+            return;
+        }
+        let value: Value = match serde_json::from_str(&self.json_input) {
+            Ok(value) => value,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::Error, &[&e]));
+                return;
+            }
+        };
+        let (kept, archives) = match rotate::rotate_monthly(&path, &value, Local::now().date_naive()) {
+            Ok(result) => result,
+            Err(e) => {
+                self.set_status(&format!("Rotate error: {}", e));
+                return;
+            }
+        };
+        if archives.is_empty() {
+            self.set_status(&rotate::summary(&archives));
+            return;
+        }
+
+        self.save_undo_state();
+        self.json_input = serde_json::to_string_pretty(&kept).unwrap_or_default();
+        self.is_modified = true;
+        self.sync_markdown_from_json();
+        self.convert_json();
+        self.save_file();
+        if !self.save_conflict {
+            self.set_status(&rotate::summary(&archives));
+        }
+    }
+}
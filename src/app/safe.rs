@@ -0,0 +1,17 @@
+use super::App;
+use crate::config::AutosavePolicy;
+use crate::hooks::Hooks;
+
+impl App {
+    /// --safe: inspect a file without anything happening behind your back. Nothing is
+    /// written unless you :w, the file is not watched, hooks do not run and pastes do not
+    /// read the clipboard. The recent files list is left alone too.
+    pub fn enter_safe_mode(&mut self) {
+        self.safe_mode = true;
+        self.autosave = AutosavePolicy::Manual;
+        self.auto_reload = false;
+        self.hooks = Hooks::default();
+        self.recent_list_path = None;
+        self.swap_root = None;
+    }
+}
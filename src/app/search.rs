@@ -1,4 +1,7 @@
 use super::{App, FormatMode, InputMode};
+use crate::i18n::Msg;
+use crate::rendering::RelfEntry;
+use crate::search::{SearchField, SearchQuery, Searchable};
 
 impl App {
     pub fn start_search(&mut self) {
@@ -21,16 +24,16 @@ impl App {
             let search_pattern = self.search_buffer.clone();
             let entries = self.get_outline_entries();
 
-            for i in 0..entries.len() {
-                if entries[i].to_lowercase().contains(&search_pattern.to_lowercase()) {
-                    let found_name = entries[i].clone();
+            for (i, entry) in entries.iter().enumerate() {
+                if entry.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                    let found_name = entry.clone();
                     self.outline_selected_index = i;
                     self.set_status(&format!("Found: {}", found_name));
                     return;
                 }
             }
 
-            self.set_status(&format!("Pattern not found: {}", search_pattern));
+            self.set_status(&self.msg_with(Msg::PatternNotFound, &[&search_pattern]));
             return;
         }
 
@@ -41,36 +44,116 @@ impl App {
             let search_pattern = self.search_buffer.clone();
 
             for i in 0..self.explorer_entries.len() {
-                if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str()) {
-                    if filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
-                        let found_name = filename.to_string();
-                        self.explorer_selected_index = i;
-                        self.explorer_update_scroll();
-                        self.set_status(&format!("Found: {}", found_name));
-                        return;
-                    }
+                if let Some(filename) = self.explorer_entries[i].path.file_name().and_then(|n| n.to_str())
+                    && filename.to_lowercase().contains(&search_pattern.to_lowercase()) {
+                    let found_name = filename.to_string();
+                    self.explorer_selected_index = i;
+                    self.explorer_update_scroll();
+                    self.set_status(&format!("Found: {}", found_name));
+                    return;
                 }
             }
 
-            self.set_status(&format!("Pattern not found: {}", search_pattern));
+            self.set_status(&self.msg_with(Msg::PatternNotFound, &[&search_pattern]));
             return;
         }
 
         self.search_query = self.search_buffer.clone();
         self.find_matches();
         self.input_mode = InputMode::Normal;
+        self.matches_selected_index = 0;
 
         if !self.search_matches.is_empty() {
             self.current_match_index = Some(0);
             self.jump_to_current_match();
-            self.set_status(&format!(
-                "Found {} matches for '{}'",
-                self.search_matches.len(),
-                self.search_query
-            ));
+            self.show_match_position();
         } else {
             self.current_match_index = None;
-            self.set_status(&format!("Pattern not found: {}", self.search_query));
+            self.set_status(&self.msg_with(Msg::PatternNotFound, &[&self.search_query]));
+        }
+    }
+
+    /// Matches of the search in a card as (line in `entry.lines`, char position).
+    /// A field query (`name:rust url:github`) only matches cards where every term does, at
+    /// the terms that apply to each line; a card matched only through a field that has no
+    /// line of its own (a custom field) gets one match at its first line.
+    pub(super) fn card_match_positions(&self, entry: &RelfEntry) -> Vec<(usize, usize)> {
+        let Some(query) = SearchQuery::parse(&self.search_query) else {
+            let query_lower = self.search_query.to_lowercase();
+            return entry
+                .lines
+                .iter()
+                .enumerate()
+                .flat_map(|(line_idx, line)| {
+                    Self::line_match_positions(line, &query_lower).into_iter().map(move |col| (line_idx, col))
+                })
+                .collect();
+        };
+        if !query.matches(&Self::searchable(entry)) {
+            return Vec::new();
+        }
+
+        let mut positions = Vec::new();
+        for (line_idx, line) in entry.lines.iter().enumerate() {
+            let field = [
+                (SearchField::Name, &entry.name),
+                (SearchField::Url, &entry.url),
+                (SearchField::Context, &entry.context),
+                (SearchField::Date, &entry.date),
+            ]
+            .into_iter()
+            .find(|(_, value)| value.as_deref() == Some(line.as_str()))
+            .map(|(field, _)| field);
+            let mut cols: Vec<usize> = query
+                .terms
+                .iter()
+                .filter(|term| term.field.is_none() || term.field == field)
+                .flat_map(|term| Self::line_match_positions(line, &term.text))
+                .collect();
+            cols.sort_unstable();
+            cols.dedup();
+            positions.extend(cols.into_iter().map(|col| (line_idx, col)));
+        }
+        if positions.is_empty() {
+            positions.push((0, 0));
+        }
+        positions
+    }
+
+    /// Texts to highlight for the search in `field` (`None`: a line that is no particular field)
+    pub fn search_highlight_texts(&self, field: Option<SearchField>) -> Vec<String> {
+        match SearchQuery::parse(&self.search_query) {
+            Some(query) => query.highlights(field).into_iter().map(String::from).collect(),
+            None if self.search_query.is_empty() => Vec::new(),
+            None => vec![self.search_query.clone()],
+        }
+    }
+
+    fn searchable(entry: &RelfEntry) -> Searchable {
+        let mut other: Vec<String> = entry.fields.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+        if let Some(percentage) = entry.percentage {
+            other.push(format!("{}%", percentage));
+        }
+        Searchable {
+            name: entry.name.clone().unwrap_or_default(),
+            url: entry.url.clone().unwrap_or_default(),
+            context: entry.context.clone().unwrap_or_default(),
+            date: entry.date.clone().unwrap_or_default(),
+            other,
+        }
+    }
+
+    /// `match 3/17` for the current search match; None without one
+    pub fn match_position(&self) -> Option<String> {
+        let idx = self.current_match_index.filter(|&idx| idx < self.search_matches.len())?;
+        Some(format!("match {}/{}", idx + 1, self.search_matches.len()))
+    }
+
+    /// Status after a search, n / N or a jump from the match list
+    pub(super) fn show_match_position(&mut self) {
+        if let Some(position) = self.match_position() {
+            let status = format!("{} for '{}'", position, self.search_query);
+            self.set_status(&status);
         }
     }
 
@@ -86,31 +169,14 @@ impl App {
 
         // For card view, search within entry content
         if self.format_mode == FormatMode::View && !self.relf_entries.is_empty() {
-            let query_lower = self.search_query.to_lowercase();
-
+            let mut matches = Vec::new();
             for (entry_idx, entry) in self.relf_entries.iter().enumerate() {
-                for (_line_idx, line) in entry.lines.iter().enumerate() {
-                    let line_lower = line.to_lowercase();
-                    let mut byte_pos = 0;
-
-                    while byte_pos < line_lower.len() {
-                        if let Some(match_pos) = line_lower[byte_pos..].find(&query_lower) {
-                            let actual_byte_pos = byte_pos + match_pos;
-                            // Convert byte position to char position
-                            let char_pos = line[..actual_byte_pos.min(line.len())].chars().count();
-                            // Store entry_idx in line position, and char position in col position
-                            self.search_matches.push((entry_idx, char_pos));
-                            // Move past this match, ensuring we stay on char boundary
-                            byte_pos = actual_byte_pos + query_lower.len();
-                            while byte_pos < line_lower.len() && !line_lower.is_char_boundary(byte_pos) {
-                                byte_pos += 1;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
+                // Store entry_idx in line position, and char position in col position
+                for (_, char_pos) in self.card_match_positions(entry) {
+                    matches.push((entry_idx, char_pos));
                 }
             }
+            self.search_matches = matches;
             return;
         }
 
@@ -122,33 +188,19 @@ impl App {
 
         let query_lower = self.search_query.to_lowercase();
 
+        let mut matches = Vec::new();
         for (line_idx, line) in search_content.iter().enumerate() {
-            let line_lower = line.to_lowercase();
-            let mut byte_pos = 0;
-
-            while byte_pos < line_lower.len() {
-                if let Some(match_pos) = line_lower[byte_pos..].find(&query_lower) {
-                    let actual_byte_pos = byte_pos + match_pos;
-                    // Convert byte position to char position for storage
-                    let char_pos = line[..actual_byte_pos.min(line.len())].chars().count();
-                    self.search_matches.push((line_idx, char_pos));
-                    // Move past this match, ensuring we stay on char boundary
-                    byte_pos = actual_byte_pos + query_lower.len();
-                    // If we're not on a char boundary, find the next one
-                    while byte_pos < line_lower.len() && !line_lower.is_char_boundary(byte_pos) {
-                        byte_pos += 1;
-                    }
-                } else {
-                    break;
-                }
+            for char_pos in Self::line_match_positions(line, &query_lower) {
+                matches.push((line_idx, char_pos));
             }
         }
+        self.search_matches = matches;
     }
 
     pub fn next_match(&mut self) {
         if self.search_matches.is_empty() {
             if !self.search_query.is_empty() {
-                self.set_status(&format!("No matches for '{}'", self.search_query));
+                self.set_status(&self.msg_with(Msg::NoMatches, &[&self.search_query]));
             }
             return;
         }
@@ -162,18 +214,13 @@ impl App {
 
         self.current_match_index = Some(next_idx);
         self.jump_to_current_match();
-        self.set_status(&format!(
-            "Match {} of {} for '{}'",
-            next_idx + 1,
-            self.search_matches.len(),
-            self.search_query
-        ));
+        self.show_match_position();
     }
 
     pub fn prev_match(&mut self) {
         if self.search_matches.is_empty() {
             if !self.search_query.is_empty() {
-                self.set_status(&format!("No matches for '{}'", self.search_query));
+                self.set_status(&self.msg_with(Msg::NoMatches, &[&self.search_query]));
             }
             return;
         }
@@ -187,37 +234,31 @@ impl App {
 
         self.current_match_index = Some(prev_idx);
         self.jump_to_current_match();
-        self.set_status(&format!(
-            "Match {} of {} for '{}'",
-            prev_idx + 1,
-            self.search_matches.len(),
-            self.search_query
-        ));
+        self.show_match_position();
     }
 
     pub fn jump_to_current_match(&mut self) {
-        if let Some(match_idx) = self.current_match_index {
-            if let Some(&(line, col)) = self.search_matches.get(match_idx) {
-                if self.format_mode == FormatMode::Edit {
-                    self.content_cursor_line = line;
-                    self.content_cursor_col = col;
-                    self.ensure_cursor_visible();
-                } else if !self.relf_entries.is_empty() {
-                    // For card view, jump to the entry
-                    self.selected_entry_index = line;
-                } else {
-                    // For View mode, just scroll to the line
-                    self.scroll = line as u16;
-                    let max_scroll = self
-                        .rendered_content
-                        .len()
-                        .saturating_sub(self.get_visible_height() as usize)
-                        as u16;
-                    if self.scroll > max_scroll {
-                        self.scroll = max_scroll;
-                    }
-                    self.ensure_cursor_visible();
+        if let Some(match_idx) = self.current_match_index
+            && let Some(&(line, col)) = self.search_matches.get(match_idx) {
+            if self.format_mode == FormatMode::Edit {
+                self.content_cursor_line = line;
+                self.content_cursor_col = col;
+                self.ensure_cursor_visible();
+            } else if !self.relf_entries.is_empty() {
+                // For card view, jump to the entry
+                self.selected_entry_index = line;
+            } else {
+                // For View mode, just scroll to the line
+                self.scroll = line as u16;
+                let max_scroll = self
+                    .rendered_content
+                    .len()
+                    .saturating_sub(self.get_visible_height() as usize)
+                    as u16;
+                if self.scroll > max_scroll {
+                    self.scroll = max_scroll;
                 }
+                self.ensure_cursor_visible();
             }
         }
     }
@@ -0,0 +1,75 @@
+use super::{App, ConfirmAction};
+use super::file::clean_path;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+impl App {
+    /// The user's skeleton file for new notes at `path`: `skeleton_md` for .md, else
+    /// `skeleton_json`
+    pub(super) fn skeleton_file(&self, path: &Path) -> Option<&PathBuf> {
+        let is_markdown = path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+        if is_markdown { self.skeleton_md.as_ref() } else { self.skeleton_json.as_ref() }
+    }
+
+    /// What a new note file at `path` starts as: the skeleton file set in ~/.revwrc with
+    /// `{date}` replaced by the current time, or empty OUTSIDE and INSIDE sections
+    pub fn skeleton(&self, path: &Path) -> Result<String, String> {
+        if let Some(file) = self.skeleton_file(path) {
+            let text = fs::read_to_string(file).map_err(|e| format!("Error reading skeleton '{}': {}", file.display(), e))?;
+            let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            return Ok(text.replace("{date}", &now));
+        }
+        let is_markdown = path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+        if is_markdown {
+            Ok("## OUTSIDE\n\n## INSIDE\n".to_string())
+        } else {
+            serde_json::to_string_pretty(&json!({ "outside": [], "inside": [] })).map_err(|e| e.to_string())
+        }
+    }
+
+    /// Write the skeleton to `path` (creating missing folders) and open it
+    pub(super) fn create_note_file(&mut self, path: &Path) {
+        let skeleton = match self.skeleton(path) {
+            Ok(skeleton) => skeleton,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty())
+            && let Err(e) = fs::create_dir_all(parent) {
+            self.set_status(&format!("Error creating '{}': {}", parent.display(), e));
+            return;
+        }
+        if let Err(e) = fs::write(path, skeleton) {
+            self.set_status(&format!("Error creating '{}': {}", path.display(), e));
+            return;
+        }
+
+        self.load_file(path.to_path_buf());
+        if self.file_path.as_deref() != Some(path) {
+            return; // load_file reported what went wrong
+        }
+        self.selected_entry_index = 0;
+        self.scroll = 0;
+        self.content_cursor_line = 0;
+        self.content_cursor_col = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        if self.explorer_open {
+            self.load_explorer_entries();
+        }
+        self.set_status(&format!("Created new file: {}", path.display()));
+    }
+
+    /// Open the file given on the command line; a missing one is only created after a y/n
+    pub fn open_or_offer(&mut self, path: PathBuf) {
+        let path = clean_path(&path);
+        if path.exists() {
+            self.open_file(path);
+            return;
+        }
+        self.confirm_action(ConfirmAction::CreateFile(path));
+    }
+}
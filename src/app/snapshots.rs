@@ -0,0 +1,185 @@
+use super::App;
+use crate::snapshot::{self, DiffLine, SnapshotStore};
+
+/// Unchanged lines shown around each change in the snapshot diff
+const DIFF_CONTEXT: usize = 2;
+
+impl App {
+    /// Rows of the shown snapshot diff: changes with context, `None` for skipped lines
+    pub fn snapshot_diff_rows(&self) -> Vec<Option<&DiffLine>> {
+        self.snapshot_diff
+            .as_deref()
+            .map(|diff| snapshot::diff_hunks(diff, DIFF_CONTEXT))
+            .unwrap_or_default()
+    }
+
+    fn snapshot_store(&self) -> Result<SnapshotStore, String> {
+        let root = self
+            .snapshot_root
+            .clone()
+            .ok_or_else(|| "No data directory for snapshots".to_string())?;
+        Ok(SnapshotStore::new(&root, self.file_path.as_deref()))
+    }
+
+    /// Save the current document as a named snapshot (:snapshot NAME)
+    pub fn create_snapshot(&mut self, name: &str) {
+        if name.trim().is_empty() {
+            self.set_status("Usage: :snapshot NAME");
+            return;
+        }
+        let extension = if self.is_markdown_file() { "md" } else { "json" };
+        let result = self
+            .snapshot_store()
+            .and_then(|store| store.save(name, &self.strict_source(), extension));
+        match result {
+            Ok(saved) => self.set_status(&format!("Snapshot '{}' saved", saved.name)),
+            Err(e) => self.set_status(&format!("Snapshot error: {}", e)),
+        }
+    }
+
+    /// Open the snapshot browser (:snapshots)
+    pub fn open_snapshot_list(&mut self) {
+        let store = match self.snapshot_store() {
+            Ok(store) => store,
+            Err(e) => {
+                self.set_status(&format!("Snapshot error: {}", e));
+                return;
+            }
+        };
+        self.snapshots = store.list();
+        if self.snapshots.is_empty() {
+            self.set_status("No snapshots: use :snapshot NAME first");
+            return;
+        }
+        self.snapshots_open = true;
+        self.snapshots_selected_index = 0;
+        self.snapshot_diff = None;
+        self.set_status(&format!("{} snapshots", self.snapshots.len()));
+    }
+
+    pub fn close_snapshot_list(&mut self) {
+        self.snapshots_open = false;
+        self.snapshots.clear();
+        self.snapshots_selected_index = 0;
+        self.snapshot_diff = None;
+    }
+
+    /// j: next snapshot, or scroll the diff down when it is shown
+    pub fn snapshot_list_move_down(&mut self) {
+        if self.snapshot_diff.is_some() {
+            if self.snapshot_diff_scroll + 1 < self.snapshot_diff_rows().len() {
+                self.snapshot_diff_scroll += 1;
+            }
+        } else if self.snapshots_selected_index + 1 < self.snapshots.len() {
+            self.snapshots_selected_index += 1;
+        }
+    }
+
+    /// k: previous snapshot, or scroll the diff up when it is shown
+    pub fn snapshot_list_move_up(&mut self) {
+        if self.snapshot_diff.is_some() {
+            self.snapshot_diff_scroll = self.snapshot_diff_scroll.saturating_sub(1);
+        } else {
+            self.snapshots_selected_index = self.snapshots_selected_index.saturating_sub(1);
+        }
+    }
+
+    /// Show (or hide) what changed between the selected snapshot and the buffer
+    pub fn toggle_snapshot_diff(&mut self) {
+        if self.snapshots.is_empty() {
+            // The :diff panel has no list to go back to
+            return;
+        }
+        if self.snapshot_diff.take().is_some() {
+            return;
+        }
+        let Some(selected) = self.snapshots.get(self.snapshots_selected_index).cloned() else {
+            return;
+        };
+        match self.snapshot_store().and_then(|store| store.read(&selected)) {
+            Ok(content) => {
+                let diff = snapshot::diff(&content, &self.strict_source());
+                let (added, removed) = diff.iter().fold((0, 0), |(a, r), line| match line {
+                    DiffLine::Added(_) => (a + 1, r),
+                    DiffLine::Removed(_) => (a, r + 1),
+                    DiffLine::Same(_) => (a, r),
+                });
+                self.set_status(&format!("'{}' → buffer: +{} -{} lines", selected.name, added, removed));
+                self.snapshot_diff = Some(diff);
+                self.snapshot_diff_scroll = 0;
+            }
+            Err(e) => self.set_status(&format!("Snapshot error: {}", e)),
+        }
+    }
+
+    /// Show what changed between the file on disk and the buffer (:diff), in the snapshot panel
+    pub fn show_disk_diff(&mut self) {
+        let Some(path) = self.file_path.clone() else {
+            self.set_status("No file to compare");
+            return;
+        };
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_status(&format!("Error reading: {}", e));
+                return;
+            }
+        };
+        let diff = snapshot::diff(&content, &self.strict_source());
+        let (added, removed) = diff.iter().fold((0, 0), |(a, r), line| match line {
+            DiffLine::Added(_) => (a + 1, r),
+            DiffLine::Removed(_) => (a, r + 1),
+            DiffLine::Same(_) => (a, r),
+        });
+        if added + removed == 0 {
+            self.set_status("Buffer matches the file on disk");
+            return;
+        }
+        self.snapshots_open = true;
+        self.snapshots.clear();
+        self.snapshots_selected_index = 0;
+        self.snapshot_diff = Some(diff);
+        self.snapshot_diff_scroll = 0;
+        self.set_status(&format!("disk → buffer: +{} -{} lines", added, removed));
+    }
+
+    /// Replace the document with the selected snapshot (undoable with u)
+    pub fn restore_selected_snapshot(&mut self) {
+        let Some(selected) = self.snapshots.get(self.snapshots_selected_index).cloned() else {
+            return;
+        };
+        let content = match self.snapshot_store().and_then(|store| store.read(&selected)) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_status(&format!("Snapshot error: {}", e));
+                return;
+            }
+        };
+
+        let json = if self.is_markdown_file() {
+            match self.parse_markdown(&content) {
+                Ok(json) => json,
+                Err(e) => {
+                    self.set_status(&format!("Snapshot error: {}", e));
+                    return;
+                }
+            }
+        } else {
+            content.clone()
+        };
+
+        self.save_undo_state();
+        if self.is_markdown_file() {
+            self.markdown_input = content;
+        }
+        self.json_input = json;
+
+        self.close_snapshot_list();
+        self.is_modified = true;
+        self.convert_json();
+        let last_line = self.get_content_lines().len().saturating_sub(1);
+        self.content_cursor_line = self.content_cursor_line.min(last_line);
+        self.autosave();
+        self.set_status(&format!("Restored snapshot '{}' (u to undo)", selected.name));
+    }
+}
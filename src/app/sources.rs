@@ -0,0 +1,170 @@
+use super::file::{clean_path, content_hash, parse_file_content, write_locked};
+use super::{App, DiskState, FileMode, SourceFile};
+use crate::hooks::HookEvent;
+use crate::i18n::Msg;
+use crate::journal;
+use crate::line_endings;
+use crate::markdown_ops::MarkdownOperations;
+use crate::sources;
+use serde_json::Value;
+use std::{fs, path::PathBuf, time::Instant};
+
+impl App {
+    /// Open `paths` as one merged view: each file's cards form a group, labelled with
+    /// the file, and saving writes every entry back to the file it came from
+    pub fn open_sources(&mut self, paths: Vec<PathBuf>) {
+        self.flush_autosave(true);
+        let paths: Vec<PathBuf> = paths.iter().map(|path| clean_path(path)).collect();
+        let mut files = Vec::new();
+        let mut docs = Vec::new();
+        for (path, label) in paths.iter().zip(sources::labels(&paths)) {
+            let raw = match fs::read_to_string(path) {
+                Ok(content) => content,
+                Err(e) => {
+                    self.set_status(&format!("Error loading '{}': {}", path.display(), e));
+                    return;
+                }
+            };
+            let disk_state = DiskState::of(path, &raw);
+            let (content, text_format) = line_endings::normalize_owned(raw);
+            let parsed = match parse_file_content(path, &content) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    self.set_status(&e);
+                    return;
+                }
+            };
+            let markdown = parsed.is_some();
+            let value: Value = match serde_json::from_str(parsed.as_deref().unwrap_or(&content)) {
+                Ok(value @ Value::Object(_)) => value,
+                Ok(_) => {
+                    self.set_status(&format!("'{}' is not a revw file", path.display()));
+                    return;
+                }
+                Err(e) => {
+                    self.set_status(&format!("Invalid JSON in '{}': {}", path.display(), e));
+                    return;
+                }
+            };
+            // A merged view can't hold a journal's history read-only, so journals open alone
+            if journal::is_journal(&value) {
+                self.set_status(&format!("'{}' is a journal: open it on its own, not in a merged view", path.display()));
+                return;
+            }
+            docs.push((label.clone(), value.clone()));
+            files.push(SourceFile {
+                path: path.clone(),
+                label,
+                markdown,
+                base: value,
+                disk_state: Some(disk_state),
+                text_format,
+            });
+        }
+
+        self.json_input = serde_json::to_string_pretty(&sources::combine(&docs)).unwrap_or_default();
+        self.markdown_input = String::new();
+        self.file_mode = FileMode::Json;
+        self.file_path = None;
+        self.file_path_changed = true;
+        self.expanded_cards.clear();
+        self.disk_state = None;
+        self.merge_base = None;
+        self.is_modified = false;
+        let labels: Vec<&str> = files.iter().map(|file| file.label.as_str()).collect();
+        self.set_status(&format!("Merged view of {} files: {}", files.len(), labels.join(", ")));
+        self.sources = files;
+        self.convert_json();
+
+        self.selected_entry_index = 0;
+        self.hscroll = 0;
+        self.content_cursor_line = 0;
+        self.content_cursor_col = 0;
+        self.scroll = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    /// Save a merged view: split the buffer by file and write the files whose entries
+    /// changed. Unless `force` is set, a file changed on disk since it was loaded is left
+    /// alone and named in the status bar.
+    pub(super) fn write_sources(&mut self, force: bool) {
+        let combined: Value = match serde_json::from_str(&self.json_input) {
+            Ok(value) => value,
+            Err(e) => {
+                self.set_status(&format!("Not saved: invalid JSON: {}", e));
+                return;
+            }
+        };
+        let bases: Vec<(String, Value)> =
+            self.sources.iter().map(|file| (file.label.clone(), file.base.clone())).collect();
+        let docs = match sources::split(&combined, &bases) {
+            Ok(docs) => docs,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::NotSaved, &[&e]));
+                return;
+            }
+        };
+
+        // Render and check every file before writing any of them
+        let mut contents = Vec::new();
+        for (file, doc) in self.sources.iter().zip(&docs) {
+            let content = if file.markdown {
+                MarkdownOperations::render_from_json(doc)
+            } else {
+                serde_json::to_string_pretty(doc).unwrap_or_default()
+            };
+            if let Err(e) = self.check_strict(&content) {
+                self.set_status(&format!("Strict: save rejected, {}: {}", file.label, e));
+                return;
+            }
+            contents.push(self.with_line_endings(file.text_format, &content));
+        }
+
+        let mut saved = Vec::new();
+        let mut conflicts = Vec::new();
+        for (index, (content, doc)) in contents.into_iter().zip(docs).enumerate() {
+            let file = &self.sources[index];
+            if file.disk_state.is_some_and(|state| state.hash == content_hash(content.as_bytes())) {
+                continue;
+            }
+            let format = if file.markdown { "md" } else { "json" };
+            if let Err(e) = self.hooks.run(HookEvent::PreSave, &file.path, format) {
+                self.set_status(&format!("Not saved: {}: {}", file.label, e));
+                return;
+            }
+            match write_locked(&file.path, &content, file.disk_state, force, self.atomic_save) {
+                Ok(None) => {
+                    tracing::warn!(path = %file.path.display(), "not saved: file changed on disk");
+                    conflicts.push(file.label.clone());
+                }
+                Ok(Some(disk_state)) => {
+                    tracing::info!(path = %file.path.display(), "saved");
+                    let path = file.path.clone();
+                    let file = &mut self.sources[index];
+                    file.disk_state = Some(disk_state);
+                    file.base = doc;
+                    saved.push(file.label.clone());
+                    self.queue_post_save_hooks(path, format);
+                }
+                Err(e) => {
+                    tracing::error!(path = %file.path.display(), error = %e, "save failed");
+                    self.set_status(&format!("Error saving {}: {}", file.label, e));
+                    return;
+                }
+            }
+        }
+
+        if !conflicts.is_empty() {
+            self.set_status(&format!("Changed on disk since loaded, not saved: {} (:w! overwrites)", conflicts.join(", ")));
+            return;
+        }
+        self.is_modified = false;
+        self.last_save_time = Some(Instant::now());
+        if saved.is_empty() {
+            self.set_status(self.msg(Msg::SavedNothing));
+        } else {
+            self.set_status(&self.msg_with(Msg::Saved, &[&saved.join(", ")]));
+        }
+    }
+}
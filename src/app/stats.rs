@@ -0,0 +1,31 @@
+use super::App;
+use crate::content_ops;
+use crate::i18n::Msg;
+use serde_json::Value;
+
+impl App {
+    /// :stats tags - show entry counts and average completion per tag in a table
+    pub fn show_stats(&mut self, args: &str) {
+        if args != "tags" {
+            self.set_status("Usage: :stats tags");
+            return;
+        }
+        let value: Value = match serde_json::from_str(&self.json_input) {
+            Ok(value) => value,
+            Err(e) => {
+                self.set_status(&self.msg_with(Msg::InvalidJson, &[&e]));
+                return;
+            }
+        };
+        let stats = content_ops::tag_stats(&value);
+        if stats.is_empty() {
+            self.set_status("No OUTSIDE entries to count");
+            return;
+        }
+        self.tag_stats = Some(stats);
+    }
+
+    pub fn close_stats(&mut self) {
+        self.tag_stats = None;
+    }
+}
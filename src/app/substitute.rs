@@ -1,34 +1,76 @@
 use super::{App, FormatMode, SubstituteMatch};
+use crate::i18n::Msg;
+
+/// Pattern, replacement and flags of `s/pattern/replacement/[flags]` (or `%s/...`)
+fn parse_substitute(cmd: &str) -> Result<(&str, &str, &str), &'static str> {
+    let cmd_prefix = if cmd.starts_with("%s/") { "%s/" } else { "s/" };
+    let cmd_rest = cmd.strip_prefix(cmd_prefix).unwrap_or("");
+
+    // Split by '/' to get pattern, replacement, and flags
+    let parts: Vec<&str> = cmd_rest.splitn(3, '/').collect();
+    if parts.len() < 2 {
+        return Err("Invalid substitute syntax. Use :s/pattern/replacement/[flags]");
+    }
+    if parts[0].is_empty() {
+        return Err("Empty pattern");
+    }
+    Ok((parts[0], parts[1], parts.get(2).copied().unwrap_or("")))
+}
 
 impl App {
-    pub fn execute_substitute(&mut self, cmd: &str) {
-        // Only works in Edit mode
-        if self.format_mode != FormatMode::Edit {
-            self.set_status("Substitute only works in Edit mode");
+    /// Command typed with `:` in the edit overlay. Only `:s/old/new/[g]` is available: it
+    /// replaces within the field being edited (`%s` means the same), the first occurrence or
+    /// every one with `g`.
+    pub fn execute_overlay_command(&mut self, cmd: &str) {
+        let cmd = cmd.trim();
+        if !cmd.starts_with("s/") && !cmd.starts_with("%s/") {
+            self.set_status("Only :s/old/new/[g] works in the edit overlay");
             return;
         }
+        let (pattern, replacement, flags) = match parse_substitute(cmd) {
+            Ok(parts) => parts,
+            Err(e) => {
+                self.set_status(e);
+                return;
+            }
+        };
 
-        // Parse the substitute command
-        let is_global_file = cmd.starts_with("%s/");
-        let cmd_prefix = if is_global_file { "%s/" } else { "s/" };
-        let cmd_rest = cmd.strip_prefix(cmd_prefix).unwrap_or("");
-
-        // Split by '/' to get pattern, replacement, and flags
-        let parts: Vec<&str> = cmd_rest.splitn(3, '/').collect();
-        if parts.len() < 2 {
-            self.set_status("Invalid substitute syntax. Use :s/pattern/replacement/[flags]");
+        let index = self.edit_field_index;
+        let is_placeholder = self.edit_buffer_is_placeholder.get(index).copied().unwrap_or(false);
+        let Some(field) = self.edit_buffer.get_mut(index).filter(|field| !is_placeholder && field.contains(pattern)) else {
+            self.set_status(&self.msg_with(Msg::PatternNotFound, &[&pattern]));
             return;
-        }
+        };
 
-        let pattern = parts[0];
-        let replacement = parts[1];
-        let flags = if parts.len() == 3 { parts[2] } else { "" };
+        let count = if flags.contains('g') {
+            let count = field.matches(pattern).count();
+            *field = field.replace(pattern, replacement);
+            count
+        } else {
+            *field = field.replacen(pattern, replacement, 1);
+            1
+        };
+        self.edit_cursor_pos = self.edit_cursor_pos.min(field.chars().count());
+        self.ensure_overlay_cursor_visible();
+        self.set_status(&format!("{} substitution{} made", count, if count == 1 { "" } else { "s" }));
+    }
 
-        if pattern.is_empty() {
-            self.set_status("Empty pattern");
+    pub fn execute_substitute(&mut self, cmd: &str) {
+        // Only works in Edit mode
+        if self.format_mode != FormatMode::Edit {
+            self.set_status("Substitute only works in Edit mode");
             return;
         }
 
+        let is_global_file = cmd.starts_with("%s/");
+        let (pattern, replacement, flags) = match parse_substitute(cmd) {
+            Ok(parts) => parts,
+            Err(e) => {
+                self.set_status(e);
+                return;
+            }
+        };
+
         let global_line = flags.contains('g');
         let confirm = flags.contains('c');
 
@@ -39,7 +81,7 @@ impl App {
             // Build list of all matches for confirmation
             self.build_substitute_confirmations(pattern, replacement, is_global_file, global_line);
             if self.substitute_confirmations.is_empty() {
-                self.set_status(&format!("Pattern not found: {}", pattern));
+                self.set_status(&self.msg_with(Msg::PatternNotFound, &[&pattern]));
             } else {
                 self.current_substitute_index = 0;
                 self.set_status(&format!(
@@ -57,7 +99,7 @@ impl App {
                 self.convert_json();
                 self.set_status(&format!("{} substitution{} made", count, if count == 1 { "" } else { "s" }));
             } else {
-                self.set_status(&format!("Pattern not found: {}", pattern));
+                self.set_status(&self.msg_with(Msg::PatternNotFound, &[&pattern]));
                 // Remove the undo state we just saved since nothing changed
                 self.undo_stack.pop();
             }
@@ -0,0 +1,123 @@
+use super::App;
+use crate::line_endings;
+use crate::swap;
+use std::{fs, path::PathBuf, time::Instant};
+
+impl App {
+    /// Swap file of the open file; None for merged views and unnamed buffers
+    fn swap_path(&self) -> Option<PathBuf> {
+        if !self.sources.is_empty() {
+            return None;
+        }
+        Some(swap::path_for(self.swap_root.as_ref()?, self.file_path.as_ref()?))
+    }
+
+    /// Keep the swap file in step with the buffer: rewritten every few seconds while there
+    /// are unsaved changes, removed once there are none. Called by the event loop.
+    pub fn update_swap(&mut self) {
+        let path = self.swap_path().filter(|path| self.is_modified && self.swap_kept.as_ref() != Some(path));
+        if self.swap_file != path {
+            if let Some(old) = self.swap_file.take() {
+                swap::remove(&old);
+            }
+            self.swap_written = None;
+        }
+        let Some(path) = path else {
+            return;
+        };
+        if self.swap_written.is_some_and(|written| written.elapsed() < swap::INTERVAL) {
+            return;
+        }
+        if let Err(e) = swap::write(&path, &self.strict_source()) {
+            tracing::warn!(error = %e, "swap file not written");
+        }
+        self.swap_file = Some(path);
+        self.swap_written = Some(Instant::now());
+    }
+
+    /// Remove the swap file on a normal exit
+    pub fn remove_swap(&mut self) {
+        if let Some(path) = self.swap_file.take() {
+            swap::remove(&path);
+        }
+    }
+
+    /// After a file is opened: offer to recover a swap file left by a session that did not
+    /// exit normally. One matching the file on disk is just removed; one another running
+    /// revw keeps is left to it.
+    pub fn check_swap(&mut self) {
+        self.swap_recovery_pending = None;
+        self.swap_kept = None;
+        let Some(path) = self.swap_path() else {
+            return;
+        };
+        let Some(found) = swap::read(&path) else {
+            return;
+        };
+        if found.in_use() {
+            tracing::info!(swap = %path.display(), pid = ?found.pid, "swap file in use");
+            self.swap_kept = Some(path);
+            self.set_status(&format!(
+                "Also open in another revw (pid {}): its unsaved changes are not shown here",
+                found.pid.unwrap_or_default()
+            ));
+            return;
+        }
+        let content = found.content;
+        let on_disk = self.file_path.as_ref().and_then(|file| fs::read_to_string(file).ok());
+        if on_disk.is_some_and(|disk| line_endings::normalize_owned(disk).0 == content) {
+            swap::remove(&path);
+            return;
+        }
+        tracing::info!(swap = %path.display(), "swap file found");
+        self.swap_recovery_pending = Some(content);
+        self.set_status("Unsaved changes from a session that did not exit normally: (r)ecover, (d)iscard, Esc later?");
+    }
+
+    /// Esc at the recovery prompt: decide later. The swap file stays as it is, and this
+    /// session writes none over it, so opening the file again asks again.
+    pub fn defer_swap_recovery(&mut self) {
+        if self.swap_recovery_pending.take().is_none() {
+            return;
+        }
+        self.swap_kept = self.swap_path();
+        self.set_status("Swap file kept: open the file again to recover or discard it");
+    }
+
+    /// Answer the swap recovery prompt: r = load the swap content into the buffer
+    /// (undoable, left unsaved), d = delete the swap file
+    pub fn handle_swap_recovery(&mut self, answer: char) {
+        let Some(content) = self.swap_recovery_pending.take() else {
+            return;
+        };
+        match answer {
+            'r' => {
+                let json = if self.is_markdown_file() {
+                    match self.parse_markdown(&content) {
+                        Ok(json) => json,
+                        Err(e) => {
+                            self.set_status(&format!("Swap file not recovered: {}", e));
+                            return;
+                        }
+                    }
+                } else {
+                    content.clone()
+                };
+                self.save_undo_state();
+                if self.is_markdown_file() {
+                    self.markdown_input = content;
+                }
+                self.json_input = json;
+                self.is_modified = true;
+                self.convert_json();
+                self.set_status("Recovered unsaved changes (:w saves them, :diff compares, u undoes)");
+            }
+            _ => {
+                if let Some(path) = self.swap_path() {
+                    swap::remove(&path);
+                }
+                self.set_status("Swap file discarded");
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::file::write_locked;
+use super::App;
+use crate::i18n::Msg;
+use crate::sync::{self, Direction, Outcome, PutError, Remote, RemoteFile, WebDav};
+use crate::task::{Apply, BackgroundTask, Progress};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+impl App {
+    /// :sync [pull|push] - sync the saved file with its copy in the WebDAV folder
+    /// configured in ~/.revwrc
+    pub fn sync_file(&mut self, args: &str) {
+        let Some(direction) = Direction::by_name(args.trim()) else {
+            self.set_status("Usage: :sync [pull|push]");
+            return;
+        };
+        let Some(file_name) = self.file_path.as_deref().and_then(Path::file_name) else {
+            self.set_status(self.msg(Msg::NoFileOpen));
+            return;
+        };
+        let remote = match WebDav::from_config(&self.sync, &file_name.to_string_lossy()) {
+            Ok(remote) => remote,
+            Err(e) => {
+                self.set_status(&e);
+                return;
+            }
+        };
+        let config = self.sync.clone();
+        self.sync_with(direction, move || remote.unlock(&config));
+    }
+
+    /// Sync the open file with the remote `connect` opens, on a worker thread (Esc
+    /// cancels). Works on the file as saved, so unsaved changes have to be written first;
+    /// a pulled copy replaces the file and is loaded (undoable).
+    pub fn sync_with<R, F>(&mut self, direction: Direction, connect: F)
+    where
+        R: Remote,
+        F: FnOnce() -> Result<R, String> + Send + 'static,
+    {
+        let Some(path) = self.file_path.clone() else {
+            self.set_status(self.msg(Msg::NoFileOpen));
+            return;
+        };
+        if !self.sources.is_empty() {
+            self.set_status("Sync works on a single file, not a merged view");
+            return;
+        }
+        if self.is_modified {
+            self.set_status("Unsaved changes: :w before :sync");
+            return;
+        }
+        if self.background_task.is_some() {
+            self.set_status("Another operation is still running");
+            return;
+        }
+        let local = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_status(&format!("Error reading file: {}", e));
+                return;
+            }
+        };
+        let base = self.sync_root.as_deref().and_then(|root| sync::load_base(root, &path));
+        let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+
+        self.background_task = Some(BackgroundTask::spawn(&format!("Syncing {}", name), move |progress| {
+            let remote = Cancellable { remote: connect()?, progress };
+            let outcome = sync::sync(&remote, &local, base, direction)?;
+            let apply: Apply = Box::new(move |app: &mut App| app.finish_sync(path, name, local, outcome));
+            Ok(apply)
+        }));
+    }
+
+    /// Apply what the worker found out, on the UI thread
+    fn finish_sync(&mut self, path: PathBuf, name: String, local: String, outcome: Outcome) {
+        match outcome {
+            Outcome::UpToDate => self.record_sync(&path, &local, &format!("Sync: {} is up to date", name)),
+            Outcome::Pushed => self.record_sync(&path, &local, &format!("Sync: pushed {}", name)),
+            Outcome::Pulled(_) if self.file_path.as_ref() != Some(&path) || self.is_modified => {
+                self.set_status(&format!("Sync: {} changed while syncing, nothing pulled", name));
+            }
+            Outcome::Pulled(content) => match write_locked(&path, &content, self.disk_state, false, self.atomic_save) {
+                Ok(Some(_)) => {
+                    self.save_undo_state();
+                    self.reload_file();
+                    self.record_sync(&path, &content, &format!("Sync: pulled {}", name));
+                }
+                Ok(None) => self.set_status("File changed on disk since it was loaded: :reload, then :sync"),
+                Err(e) => self.set_status(&self.msg_with(Msg::SaveError, &[&e])),
+            },
+            Outcome::Conflict => self.set_status(
+                "Sync conflict: the file changed here and on the server since the last sync (:sync pull or :sync push picks a side)",
+            ),
+        }
+    }
+
+    /// Remember what both sides have now, so the next sync can tell who changed
+    fn record_sync(&mut self, path: &Path, content: &str, done: &str) {
+        let recorded = match self.sync_root.as_deref() {
+            Some(root) => sync::save_base(root, path, sync::content_hash(content)),
+            None => Err("no data directory".to_string()),
+        };
+        match recorded {
+            Ok(()) => self.set_status(done),
+            Err(e) => self.set_status(&format!("{} (last sync not recorded: {})", done, e)),
+        }
+    }
+}
+
+/// `remote` as a cancelled sync sees it: nothing is uploaded once Esc was pressed
+struct Cancellable<'a, R> {
+    remote: R,
+    progress: &'a Progress,
+}
+
+impl<R: Remote> Remote for Cancellable<'_, R> {
+    fn get(&self) -> Result<Option<RemoteFile>, String> {
+        self.remote.get()
+    }
+
+    fn put(&self, content: &str, etag: Option<&str>) -> Result<(), PutError> {
+        if self.progress.is_cancelled() {
+            return Err(PutError::Failed("Cancelled".to_string()));
+        }
+        self.remote.put(content, etag)
+    }
+}
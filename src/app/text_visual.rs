@@ -0,0 +1,155 @@
+use super::{App, FormatMode, TextVisualMode};
+
+/// Indentation added/removed by > and < on a selection
+const INDENT: &str = "  ";
+
+impl App {
+    /// Start (or switch) visual text selection at the cursor (v / V in Edit mode)
+    pub fn start_text_visual(&mut self, mode: TextVisualMode) {
+        if self.format_mode != FormatMode::Edit {
+            return;
+        }
+        if self.text_visual == Some(mode) {
+            // Pressing the same key again leaves Visual mode (like vim)
+            self.exit_text_visual();
+            return;
+        }
+        if self.text_visual.is_none() {
+            self.text_visual_anchor = (self.content_cursor_line, self.content_cursor_col);
+        }
+        self.text_visual = Some(mode);
+        self.set_status(match mode {
+            TextVisualMode::Char => "-- VISUAL --",
+            TextVisualMode::Line => "-- VISUAL LINE --",
+        });
+    }
+
+    pub fn exit_text_visual(&mut self) {
+        self.text_visual = None;
+        self.set_status("");
+    }
+
+    /// Selected logical line range (inclusive) of the current text selection
+    fn text_visual_lines(&self) -> (usize, usize) {
+        let anchor = self.text_visual_anchor.0;
+        let cursor = self.content_cursor_line;
+        (anchor.min(cursor), anchor.max(cursor))
+    }
+
+    /// Selected range as flat char offsets `[start, end)` into the content.
+    /// Character selection includes the char under the cursor; line selection covers whole lines.
+    pub fn text_visual_range(&self) -> Option<(usize, usize)> {
+        let mode = self.text_visual?;
+        let lines = self.get_content_lines();
+        let total = Self::cursor_to_flat(&lines, lines.len(), 0).saturating_sub(1);
+        match mode {
+            TextVisualMode::Char => {
+                let (anchor_line, anchor_col) = self.text_visual_anchor;
+                let anchor = Self::cursor_to_flat(&lines, anchor_line, anchor_col);
+                let cursor = self.cursor_flat_pos();
+                Some((anchor.min(cursor), (anchor.max(cursor) + 1).min(total)))
+            }
+            TextVisualMode::Line => {
+                let (first, last) = self.text_visual_lines();
+                let last = last.min(lines.len().saturating_sub(1));
+                let start = Self::cursor_to_flat(&lines, first, 0);
+                let end = Self::cursor_to_flat(&lines, last, usize::MAX);
+                Some((start, end))
+            }
+        }
+    }
+
+    /// Selected text (line selections are whole lines joined with '\n')
+    fn text_visual_content(&self) -> Option<String> {
+        let (start, end) = self.text_visual_range()?;
+        let flat = self.get_content_lines().join("\n");
+        Some(flat.chars().skip(start).take(end - start).collect())
+    }
+
+    /// y in Visual mode: copy the selection into the yank buffer
+    pub fn yank_text_selection(&mut self) {
+        let Some(text) = self.text_visual_content() else {
+            return;
+        };
+        let line_count = text.split('\n').count();
+        self.line_yank_buffer = text;
+        // Cursor returns to the start of the selection, like vim
+        if let Some((start, _)) = self.text_visual_range() {
+            let lines = self.get_content_lines();
+            (self.content_cursor_line, self.content_cursor_col) = Self::flat_to_cursor(&lines, start);
+        }
+        self.exit_text_visual();
+        if line_count > 1 {
+            self.set_status(&format!("Yanked {} lines", line_count));
+        } else {
+            self.set_status("Yanked selection");
+        }
+    }
+
+    /// d / x in Visual mode: delete the selection (copied into the yank buffer)
+    pub fn delete_text_selection(&mut self) {
+        let Some(mode) = self.text_visual else {
+            return;
+        };
+        let Some(text) = self.text_visual_content() else {
+            return;
+        };
+        self.save_undo_state();
+        self.line_yank_buffer = text;
+
+        let mut lines = self.get_content_lines();
+        match mode {
+            TextVisualMode::Line => {
+                let (first, last) = self.text_visual_lines();
+                let last = last.min(lines.len().saturating_sub(1));
+                lines.drain(first..=last);
+                if lines.is_empty() {
+                    lines.push(String::new());
+                }
+                self.content_cursor_line = first.min(lines.len() - 1);
+                self.content_cursor_col = 0;
+            }
+            TextVisualMode::Char => {
+                let (start, end) = self.text_visual_range().unwrap_or((0, 0));
+                let flat: Vec<char> = lines.join("\n").chars().collect();
+                let remaining: String = flat[..start].iter().chain(flat[end..].iter()).collect();
+                lines = remaining.split('\n').map(|l| l.to_string()).collect();
+                (self.content_cursor_line, self.content_cursor_col) = Self::flat_to_cursor(&lines, start);
+            }
+        }
+
+        self.text_visual = None;
+        self.set_content_from_lines(lines);
+        self.is_modified = true;
+        self.ensure_cursor_visible();
+        self.set_status("Deleted selection");
+    }
+
+    /// > / < in Visual mode: indent or outdent every selected line
+    pub fn indent_text_selection(&mut self, outdent: bool) {
+        if self.text_visual.is_none() {
+            return;
+        }
+        self.save_undo_state();
+        let (first, last) = self.text_visual_lines();
+        let mut lines = self.get_content_lines();
+        let last = last.min(lines.len().saturating_sub(1));
+
+        for line in &mut lines[first..=last] {
+            if outdent {
+                let strip = line.chars().take(INDENT.len()).take_while(|c| *c == ' ').count();
+                line.drain(..strip);
+            } else if !line.is_empty() {
+                line.insert_str(0, INDENT);
+            }
+        }
+
+        self.content_cursor_line = first;
+        self.content_cursor_col = 0;
+        self.text_visual = None;
+        self.set_content_from_lines(lines);
+        self.is_modified = true;
+        let count = last - first + 1;
+        self.set_status(&format!("{} {} line(s)", if outdent { "Outdented" } else { "Indented" }, count));
+    }
+}
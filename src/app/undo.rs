@@ -1,7 +1,12 @@
 use super::{App, UndoState};
+use crate::i18n::Msg;
 
 impl App {
     pub fn save_undo_state(&mut self) {
+        if self.undo_group_active {
+            // Already saved when the group started
+            return;
+        }
         let state = UndoState {
             json_input: self.json_input.clone(),
             markdown_input: self.markdown_input.clone(),
@@ -21,6 +26,17 @@ impl App {
         self.redo_stack.clear();
     }
 
+    /// Save one undo state for a multi-step edit; later edits join it until `end_undo_group`
+    pub fn begin_undo_group(&mut self) {
+        self.undo_group_active = false;
+        self.save_undo_state();
+        self.undo_group_active = true;
+    }
+
+    pub fn end_undo_group(&mut self) {
+        self.undo_group_active = false;
+    }
+
     pub fn undo(&mut self) {
         if let Some(state) = self.undo_stack.pop() {
             // Save current state to redo stack
@@ -41,7 +57,7 @@ impl App {
             self.scroll = state.scroll;
 
             self.convert_json();
-            self.set_status("Undo");
+            self.set_status(self.msg(Msg::Undo));
         } else {
             self.set_status("Nothing to undo");
         }
@@ -67,7 +83,7 @@ impl App {
             self.scroll = state.scroll;
 
             self.convert_json();
-            self.set_status("Redo");
+            self.set_status(self.msg(Msg::Redo));
         } else {
             self.set_status("Nothing to redo");
         }
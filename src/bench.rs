@@ -0,0 +1,114 @@
+//! Synthetic documents and load-stage timings for performance work (`benches/`, `--bench-load`).
+
+use crate::app::{App, FileMode};
+use crate::convert::{Converter, Format};
+use crate::line_endings;
+use crate::markdown_ops::MarkdownOperations;
+use ratatui::{backend::TestBackend, Terminal};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Terminal size the draw stage renders into
+const DRAW_WIDTH: u16 = 120;
+const DRAW_HEIGHT: u16 = 40;
+
+/// A document with `entries` cards, split evenly between OUTSIDE and INSIDE
+#[allow(dead_code)] // library API; only benches/ and tests build documents
+pub fn synthetic_value(entries: usize) -> Value {
+    let outside: Vec<Value> = (0..entries.div_ceil(2))
+        .map(|i| {
+            json!({
+                "name": format!("Resource {}", i),
+                "context": format!("Notes on resource {}\n- [x] read\n- [ ] summarize", i),
+                "url": format!("https://example.com/{}", i),
+                "percentage": (i * 7) % 101,
+            })
+        })
+        .collect();
+    let inside: Vec<Value> = (0..entries / 2)
+        .map(|i| {
+            json!({
+                "date": format!("2025-{:02}-{:02} {:02}:{:02}:00", i % 12 + 1, i % 28 + 1, i % 24, i % 60),
+                "context": format!("Journal note {} about resource {}", i, i * 3),
+            })
+        })
+        .collect();
+    json!({ "outside": outside, "inside": inside })
+}
+
+/// `synthetic_value` as pretty-printed JSON
+#[allow(dead_code)]
+pub fn synthetic_json(entries: usize) -> String {
+    serde_json::to_string_pretty(&synthetic_value(entries)).unwrap_or_default()
+}
+
+/// `synthetic_value` as Markdown
+#[allow(dead_code)]
+pub fn synthetic_markdown(entries: usize) -> String {
+    Converter::new().to(Format::Md).convert_value(&synthetic_value(entries)).unwrap_or_default()
+}
+
+/// Draw one frame of `app` into an off-screen terminal
+pub fn draw(app: &mut App) {
+    let Ok(mut terminal) = Terminal::new(TestBackend::new(DRAW_WIDTH, DRAW_HEIGHT));
+    let _ = terminal.draw(|f| crate::ui::ui(f, app));
+}
+
+/// Open `path` in `app` the way startup does, timing each stage.
+/// The filter stage only runs when a pattern is given.
+pub fn time_load(app: &mut App, path: &Path, filter: Option<&str>) -> Result<Vec<(&'static str, Duration)>, String> {
+    let mut stages = Vec::new();
+
+    let start = Instant::now();
+    let content = fs::read_to_string(path).map_err(|e| format!("Cannot read '{}': {}", path.display(), e))?;
+    let (content, text_format) = line_endings::normalize_owned(content);
+    stages.push(("read", start.elapsed()));
+
+    let start = Instant::now();
+    let format = Format::for_file(path, &content);
+    match format {
+        Format::Md => {
+            app.json_input = MarkdownOperations::parse_to_json(&content)?;
+            app.markdown_input = content;
+            app.file_mode = FileMode::Markdown;
+        }
+        Format::Toon => return Err(format!("{}: TOON input is not supported yet", path.display())),
+        _ => {
+            serde_json::from_str::<Value>(&content).map_err(|e| format!("Invalid JSON: {}", e))?;
+            app.json_input = content;
+            app.file_mode = FileMode::Json;
+        }
+    }
+    app.file_path = Some(path.to_path_buf());
+    app.text_format = text_format;
+    stages.push(("parse", start.elapsed()));
+
+    let start = Instant::now();
+    app.convert_json();
+    stages.push(("render", start.elapsed()));
+
+    if let Some(pattern) = filter {
+        let start = Instant::now();
+        app.apply_filter(pattern.to_string());
+        stages.push(("filter", start.elapsed()));
+    }
+
+    let start = Instant::now();
+    draw(app);
+    stages.push(("draw", start.elapsed()));
+
+    Ok(stages)
+}
+
+/// One `stage  12.345 ms` line per stage, then the total
+pub fn format_timings(stages: &[(&'static str, Duration)]) -> String {
+    let total: Duration = stages.iter().map(|(_, elapsed)| *elapsed).sum();
+    stages
+        .iter()
+        .copied()
+        .chain(std::iter::once(("total", total)))
+        .map(|(stage, elapsed)| format!("{:<8}{:>10.3} ms\n", stage, elapsed.as_secs_f64() * 1000.0))
+        .collect()
+}
@@ -38,8 +38,8 @@ pub struct ColorScheme {
     pub md_text: Color,                      // Markdown normal text color
 }
 
-impl ColorScheme {
-    pub fn default() -> Self {
+impl Default for ColorScheme {
+    fn default() -> Self {
         Self {
             name: "Default",
             background: Color::Black,
@@ -77,7 +77,9 @@ impl ColorScheme {
             md_text: Color::White,
         }
     }
+}
 
+impl ColorScheme {
     pub fn morning() -> Self {
         Self {
             name: "Morning",
@@ -273,6 +275,87 @@ impl ColorScheme {
         }
     }
 
+    /// Bright text on pure black with no dim grays, for low vision and bright rooms
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "HighContrast",
+            background: Color::Black,
+            border: Color::White,
+            window_border: Color::White,
+            window_title: Color::LightYellow,
+            explorer_border: Color::White,
+            explorer_title: Color::LightYellow,
+            card_border: Color::White,
+            text: Color::White,
+            text_dim: Color::Gray,
+            line_number: Color::LightYellow,
+            highlight: Color::LightYellow,
+            selected: Color::LightCyan,
+            card_selected: Color::LightYellow,
+            card_visual: Color::LightMagenta,
+            card_title: Color::LightCyan,
+            card_content: Color::White,
+            overlay_field_active: Color::LightYellow,
+            overlay_field_selected: Color::LightCyan,
+            overlay_field_placeholder: Color::Gray,
+            overlay_field_normal: Color::White,
+            explorer_folder: Color::LightCyan,
+            explorer_file: Color::White,
+            explorer_file_selected: Color::LightYellow,
+            status_bar: Color::White,
+            key: Color::LightCyan,
+            string: Color::LightGreen,
+            number: Color::LightYellow,
+            boolean: Color::LightMagenta,
+            bracket: Color::White,
+            md_header: Color::LightYellow,
+            md_bold: Color::White,
+            md_url: Color::LightCyan,
+            md_text: Color::White,
+        }
+    }
+
+    /// No hues, for printing and low-vision use: text in the terminal's own color, structure
+    /// from gray borders and, in `--stdout` listings, bold headers and underlined URLs
+    pub fn mono() -> Self {
+        Self {
+            name: "Mono",
+            background: Color::Reset,
+            border: Color::DarkGray,
+            window_border: Color::DarkGray,
+            window_title: Color::Reset,
+            explorer_border: Color::DarkGray,
+            explorer_title: Color::Reset,
+            card_border: Color::DarkGray,
+            text: Color::Reset,
+            text_dim: Color::DarkGray,
+            line_number: Color::DarkGray,
+            highlight: Color::White,
+            selected: Color::White,
+            card_selected: Color::White,
+            card_visual: Color::Gray,
+            card_title: Color::Reset,
+            card_content: Color::Reset,
+            overlay_field_active: Color::White,
+            overlay_field_selected: Color::Gray,
+            overlay_field_placeholder: Color::DarkGray,
+            overlay_field_normal: Color::Reset,
+            explorer_folder: Color::Reset,
+            explorer_file: Color::Reset,
+            explorer_file_selected: Color::White,
+            status_bar: Color::Reset,
+            key: Color::Reset,
+            string: Color::Reset,
+            number: Color::Reset,
+            boolean: Color::Reset,
+            bracket: Color::DarkGray,
+            md_header: Color::Reset,
+            md_bold: Color::Reset,
+            md_url: Color::Reset,
+            md_text: Color::Reset,
+        }
+    }
+
     pub fn by_name(name: &str) -> Option<Self> {
         match name.to_lowercase().as_str() {
             "default" => Some(Self::default()),
@@ -281,6 +364,8 @@ impl ColorScheme {
             "pablo" => Some(Self::pablo()),
             "ron" => Some(Self::ron()),
             "blue" => Some(Self::blue()),
+            "mono" => Some(Self::mono()),
+            "highcontrast" | "high-contrast" => Some(Self::high_contrast()),
             _ => None,
         }
     }
@@ -294,6 +379,8 @@ impl ColorScheme {
             Self::pablo(),
             Self::ron(),
             Self::blue(),
+            Self::mono(),
+            Self::high_contrast(),
         ]
     }
 
@@ -305,6 +392,8 @@ impl ColorScheme {
             "Pablo",
             "Ron",
             "Blue",
+            "Mono",
+            "HighContrast",
         ]
     }
 }
@@ -2,4 +2,4 @@ pub mod colorscheme;
 pub mod rc;
 
 pub use colorscheme::ColorScheme;
-pub use rc::{BorderStyle, RcConfig};
+pub use rc::{AutosavePolicy, BorderStyle, ClipboardBackend, DefaultSection, ExplorerFilter, Glyphs, InsideOrder, PasteSource, RcConfig};
@@ -1,19 +1,19 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use super::colorscheme::ColorScheme;
+use crate::hooks::{HookEvent, Hooks};
+use crate::i18n::Lang;
+use crate::paths;
+use crate::sync::SyncConfig;
+use crate::url_ops::UrlRules;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum BorderStyle {
+    #[default]
     Rounded,
     Plain,
 }
 
-impl Default for BorderStyle {
-    fn default() -> Self {
-        BorderStyle::Rounded
-    }
-}
-
 impl BorderStyle {
     /// Convert to ratatui BorderType
     pub fn to_border_type(self) -> ratatui::widgets::BorderType {
@@ -24,15 +24,267 @@ impl BorderStyle {
     }
 }
 
+/// Characters the UI draws with: box drawing and symbols, or plain ASCII for terminals and
+/// fonts without good Unicode support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Glyphs {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl Glyphs {
+    /// Parse a `glyphs=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "unicode" => Some(Glyphs::Unicode),
+            "ascii" => Some(Glyphs::Ascii),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Glyphs::Unicode => "unicode",
+            Glyphs::Ascii => "ascii",
+        }
+    }
+}
+
+/// When mutating operations write the file without an explicit :w
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AutosavePolicy {
+    /// Write after every change, in View and Edit mode
+    Always,
+    /// Write after View-mode operations (delete, duplicate, paste, order, ...)
+    #[default]
+    OnAction,
+    /// Only write on :w
+    Manual,
+}
+
+impl AutosavePolicy {
+    /// Parse an `autosave=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "always" => Some(AutosavePolicy::Always),
+            "on-action" => Some(AutosavePolicy::OnAction),
+            "manual" => Some(AutosavePolicy::Manual),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AutosavePolicy::Always => "always",
+            AutosavePolicy::OnAction => "on-action",
+            AutosavePolicy::Manual => "manual",
+        }
+    }
+}
+
+/// Where yanks are written: the system clipboard or the terminal (OSC 52)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ClipboardBackend {
+    /// System clipboard (X11/Wayland/macOS/Windows)
+    System,
+    /// OSC 52 escape sequence, forwarded by the terminal to the local clipboard (works over SSH)
+    Osc52,
+    /// OSC 52 in SSH sessions without a display, the system clipboard otherwise
+    #[default]
+    Auto,
+}
+
+impl ClipboardBackend {
+    /// Parse a `clipboard=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "system" => Some(ClipboardBackend::System),
+            "osc52" => Some(ClipboardBackend::Osc52),
+            "auto" => Some(ClipboardBackend::Auto),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ClipboardBackend::System => "system",
+            ClipboardBackend::Osc52 => "osc52",
+            ClipboardBackend::Auto => "auto",
+        }
+    }
+}
+
+/// Where pastes read from: the clipboard or, on Linux, the primary selection
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PasteSource {
+    /// The clipboard filled by explicit copies (Ctrl+C and the like)
+    #[default]
+    Clipboard,
+    /// The X11/Wayland primary selection: whatever text was last selected (middle-click paste)
+    Primary,
+}
+
+impl PasteSource {
+    /// Parse a `paste_source=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "clipboard" => Some(PasteSource::Clipboard),
+            "primary" => Some(PasteSource::Primary),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PasteSource::Clipboard => "clipboard",
+            PasteSource::Primary => "primary",
+        }
+    }
+}
+
+/// Section `:a TEXT` adds its entry to
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DefaultSection {
+    /// A dated INSIDE note with the text as its context
+    #[default]
+    Inside,
+    /// An OUTSIDE entry named after the text (or with it as the URL)
+    Outside,
+}
+
+impl DefaultSection {
+    /// Parse a `default_section=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "inside" => Some(DefaultSection::Inside),
+            "outside" => Some(DefaultSection::Outside),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DefaultSection::Inside => "inside",
+            DefaultSection::Outside => "outside",
+        }
+    }
+}
+
+/// Order INSIDE cards are shown in; the file keeps its own order either way
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InsideOrder {
+    /// As they appear in the file
+    #[default]
+    FileOrder,
+    /// Latest date first
+    NewestFirst,
+}
+
+impl InsideOrder {
+    /// Parse an `inside_order=` value
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "file_order" => Some(InsideOrder::FileOrder),
+            "newest_first" => Some(InsideOrder::NewestFirst),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InsideOrder::FileOrder => "file_order",
+            InsideOrder::NewestFirst => "newest_first",
+        }
+    }
+}
+
+/// Which files the explorer leaves out (`gh` shows everything for the session)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExplorerFilter {
+    /// Skip files matched by .gitignore (and .git/info/exclude)
+    pub hide_ignored: bool,
+    /// Skip names starting with a dot
+    pub hide_dotfiles: bool,
+    /// List only .json, .md and .toon files (directories are always listed)
+    pub supported_only: bool,
+}
+
+impl Default for ExplorerFilter {
+    fn default() -> Self {
+        Self { hide_ignored: true, hide_dotfiles: true, supported_only: true }
+    }
+}
+
+impl ExplorerFilter {
+    /// Apply an `explorer_hide_ignored` / `noexplorer_hide_ignored` style option; false if it is not one
+    pub fn set(&mut self, option: &str) -> bool {
+        let (name, enabled) = match option.strip_prefix("no") {
+            Some(name) => (name, false),
+            None => (option, true),
+        };
+        match name {
+            "explorer_hide_ignored" => self.hide_ignored = enabled,
+            "explorer_hide_dotfiles" => self.hide_dotfiles = enabled,
+            "explorer_supported_only" => self.supported_only = enabled,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Name of the project config looked for from the opened file's folder upward
+pub const PROJECT_RC: &str = ".revwrc";
+
 #[derive(Debug, Clone)]
 pub struct RcConfig {
     pub show_line_numbers: bool,
     pub show_relative_line_numbers: bool,
     pub colorscheme: ColorScheme,
     pub max_visible_cards: usize,
+    pub max_context_lines: usize,
     pub show_extension: bool,
     pub default_format: Option<String>,
     pub border_style: BorderStyle,
+    pub image_preview: bool,
+    pub strict_parsing: bool,
+    pub autosave: AutosavePolicy,
+    /// Autosave writes at most once per this long; later changes are saved when it has passed
+    pub autosave_interval: std::time::Duration,
+    pub format_on_save: bool,
+    pub autoname_on_save: bool,
+    pub atomic_save: bool,
+    pub preserve_line_endings: bool,
+    pub clipboard: ClipboardBackend,
+    pub paste_source: PasteSource,
+    pub checkbox_percentage: bool,
+    pub show_dropped: bool,
+    pub confirm_delete: bool,
+    pub confirm_clear: bool,
+    pub confirm_paste: bool,
+    pub smooth_scroll: bool,
+    pub accessible: bool,
+    pub glyphs: Glyphs,
+    pub paste_limit: usize,
+    /// Files new .md / .json note files start from (built-in empty sections when unset)
+    pub skeleton_md: Option<PathBuf>,
+    pub skeleton_json: Option<PathBuf>,
+    pub due_soon_days: i64,
+    pub default_section: DefaultSection,
+    pub inside_order: InsideOrder,
+    pub lang: Lang,
+    pub url_rules: UrlRules,
+    pub explorer_filter: ExplorerFilter,
+    pub hooks: Hooks,
+    pub sync: SyncConfig,
+    /// `:yank-template` templates as (name, text), in rc order
+    pub templates: Vec<(String, String)>,
+    /// File opened when revw starts without one (relative to the folder of its rc file)
+    pub default_file: Option<PathBuf>,
+    /// Debug log written when revw starts without --log-file
+    pub log_file: Option<PathBuf>,
+    /// Project config applied over ~/.revwrc, if one was found
+    pub project_rc: Option<PathBuf>,
 }
 
 impl Default for RcConfig {
@@ -42,9 +294,43 @@ impl Default for RcConfig {
             show_relative_line_numbers: false,
             colorscheme: ColorScheme::default(),
             max_visible_cards: 5,
+            max_context_lines: 0,
             show_extension: true,
             default_format: None,
             border_style: BorderStyle::default(),
+            image_preview: false,
+            strict_parsing: false,
+            autosave: AutosavePolicy::default(),
+            autosave_interval: std::time::Duration::from_secs(2),
+            format_on_save: false,
+            autoname_on_save: false,
+            atomic_save: true,
+            preserve_line_endings: false,
+            clipboard: ClipboardBackend::default(),
+            paste_source: PasteSource::default(),
+            checkbox_percentage: false,
+            show_dropped: false,
+            confirm_delete: false,
+            confirm_clear: false,
+            confirm_paste: false,
+            smooth_scroll: false,
+            accessible: false,
+            glyphs: Glyphs::default(),
+            paste_limit: 100,
+            skeleton_md: None,
+            skeleton_json: None,
+            due_soon_days: 3,
+            default_section: DefaultSection::default(),
+            inside_order: InsideOrder::default(),
+            lang: Lang::default(),
+            url_rules: UrlRules::default(),
+            explorer_filter: ExplorerFilter::default(),
+            hooks: Hooks::default(),
+            sync: SyncConfig::default(),
+            templates: Vec::new(),
+            default_file: None,
+            log_file: None,
+            project_rc: None,
         }
     }
 }
@@ -54,15 +340,52 @@ impl RcConfig {
     pub fn load() -> Self {
         let mut config = Self::default();
 
-        if let Some(rc_path) = Self::get_rc_path() {
-            if let Ok(contents) = fs::read_to_string(&rc_path) {
-                config.parse(&contents);
+        if let Some(rc_path) = Self::get_rc_path()
+            && let Ok(contents) = fs::read_to_string(&rc_path) {
+            config.parse(&contents);
+            if let Some(home) = dirs::home_dir() {
+                resolve_relative(&mut config.default_file, None, &home);
+                resolve_relative(&mut config.log_file, None, &home);
             }
         }
 
         config
     }
 
+    /// ~/.revwrc with the nearest project `.revwrc` in `dir` or a folder above it applied
+    /// on top, so a notes folder can have its own theme, autosave and the like
+    pub fn load_for(dir: &Path) -> Self {
+        let mut config = Self::load();
+        let dir = std::path::absolute(dir).unwrap_or_else(|_| dir.to_path_buf());
+        if let Some(path) = find_project_rc(&dir, Self::get_rc_path().as_deref()) {
+            config.apply_project(&path);
+        }
+        config
+    }
+
+    /// Apply the project config at `path` over what is set so far. Hooks, sync settings and
+    /// log_file are skipped: a project file comes along with the notes, so it may not run
+    /// commands or pick a file to append to. Relative paths in it are relative to its folder.
+    pub fn apply_project(&mut self, path: &Path) {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return;
+        };
+        let allowed: Vec<&str> = contents
+            .lines()
+            .filter(|line| !matches!(line.split_whitespace().next(), Some("hook" | "sync")))
+            .collect();
+        let before = (self.default_file.clone(), self.skeleton_md.clone(), self.skeleton_json.clone());
+        let log_file = self.log_file.take();
+        self.parse(&allowed.join("\n"));
+        self.log_file = log_file;
+
+        let base = path.parent().unwrap_or(Path::new("."));
+        resolve_relative(&mut self.default_file, before.0.as_ref(), base);
+        resolve_relative(&mut self.skeleton_md, before.1.as_ref(), base);
+        resolve_relative(&mut self.skeleton_json, before.2.as_ref(), base);
+        self.project_rc = Some(path.to_path_buf());
+    }
+
     /// Get the path to ~/.revwrc
     fn get_rc_path() -> Option<PathBuf> {
         dirs::home_dir().map(|mut path| {
@@ -94,14 +417,36 @@ impl RcConfig {
         }
 
         match parts[0] {
-            "set" => {
-                if parts.len() >= 2 {
-                    self.handle_set(&parts[1..]);
+            "set" if parts.len() >= 2 => {
+                self.handle_set(&parts[1..]);
+            }
+            "colorscheme" if parts.len() >= 2 => {
+                self.handle_colorscheme(parts[1]);
+            }
+            "hook" => {
+                // hook EVENT COMMAND: the command is the rest of the line, spaces and all
+                let rest = line["hook".len()..].trim_start();
+                if let Some((event, command)) = rest.split_once(char::is_whitespace)
+                    && let Some(event) = HookEvent::by_name(event) {
+                    self.hooks.add(event, command.trim());
                 }
             }
-            "colorscheme" => {
-                if parts.len() >= 2 {
-                    self.handle_colorscheme(parts[1]);
+            "sync" => {
+                // sync KEY VALUE: the value is the rest of the line, spaces and all
+                let rest = line["sync".len()..].trim_start();
+                if let Some((key, value)) = rest.split_once(char::is_whitespace) {
+                    self.sync.set(key, value);
+                }
+            }
+            "template" => {
+                // template NAME TEXT: the text is the rest of the line, `\n` starts a new line
+                let rest = line["template".len()..].trim_start();
+                if let Some((name, text)) = rest.split_once(char::is_whitespace) {
+                    let text = text.trim().replace("\\n", "\n");
+                    match self.templates.iter_mut().find(|(existing, _)| existing == name) {
+                        Some(template) => template.1 = text,
+                        None => self.templates.push((name.to_string(), text)),
+                    }
                 }
             }
             _ => {
@@ -143,13 +488,109 @@ impl RcConfig {
             "markdown" => {
                 self.default_format = Some("markdown".to_string());
             }
+            "preview" => {
+                self.image_preview = true;
+            }
+            "nopreview" => {
+                self.image_preview = false;
+            }
+            "strict" => {
+                self.strict_parsing = true;
+            }
+            "nostrict" => {
+                self.strict_parsing = false;
+            }
+            "format_on_save" => {
+                self.format_on_save = true;
+            }
+            "noformat_on_save" => {
+                self.format_on_save = false;
+            }
+            "autoname_on_save" => {
+                self.autoname_on_save = true;
+            }
+            "noautoname_on_save" => {
+                self.autoname_on_save = false;
+            }
+            "atomic_save" => {
+                self.atomic_save = true;
+            }
+            "noatomic_save" => {
+                self.atomic_save = false;
+            }
+            "preserve_line_endings" => {
+                self.preserve_line_endings = true;
+            }
+            "nopreserve_line_endings" => {
+                self.preserve_line_endings = false;
+            }
+            "checkbox_percentage" => {
+                self.checkbox_percentage = true;
+            }
+            "nocheckbox_percentage" => {
+                self.checkbox_percentage = false;
+            }
+            "show_dropped" => {
+                self.show_dropped = true;
+            }
+            "noshow_dropped" => {
+                self.show_dropped = false;
+            }
+            "confirm_delete" => {
+                self.confirm_delete = true;
+            }
+            "noconfirm_delete" => {
+                self.confirm_delete = false;
+            }
+            "confirm_clear" => {
+                self.confirm_clear = true;
+            }
+            "noconfirm_clear" => {
+                self.confirm_clear = false;
+            }
+            "confirm_paste" => {
+                self.confirm_paste = true;
+            }
+            "noconfirm_paste" => {
+                self.confirm_paste = false;
+            }
+            "smooth_scroll" => {
+                self.smooth_scroll = true;
+            }
+            "nosmooth_scroll" => {
+                self.smooth_scroll = false;
+            }
+            "accessible" => {
+                self.accessible = true;
+            }
+            "noaccessible" => {
+                self.accessible = false;
+            }
             _ => {
                 // Check for card=N format
                 if let Some(value_str) = option.strip_prefix("card=") {
+                    if let Ok(value) = value_str.parse::<usize>()
+                        && (1..=10).contains(&value) {
+                        self.max_visible_cards = value;
+                    }
+                }
+                // Check for max_context_lines=N format (0 shows contexts in full)
+                else if let Some(value_str) = option.strip_prefix("max_context_lines=") {
+                    if let Ok(value) = value_str.parse::<usize>() {
+                        self.max_context_lines = value;
+                    }
+                }
+                // Check for paste_limit=N format (larger pastes ask first, 0 never asks)
+                else if let Some(value_str) = option.strip_prefix("paste_limit=") {
                     if let Ok(value) = value_str.parse::<usize>() {
-                        if value >= 1 && value <= 10 {
-                            self.max_visible_cards = value;
-                        }
+                        self.paste_limit = value;
+                    }
+                }
+                // Check for due_soon_days=N format (cards due within N days turn yellow)
+                else if let Some(value_str) = option.strip_prefix("due_soon_days=") {
+                    if let Ok(value) = value_str.parse::<i64>()
+                        && value >= 0 {
+                        self.due_soon_days = value;
                     }
                 }
                 // Check for border=rounded/plain format
@@ -160,7 +601,80 @@ impl RcConfig {
                         _ => {} // Unknown border style, ignore
                     }
                 }
-                // Unknown option, ignore
+                // Check for autosave=always/on-action/manual format
+                else if let Some(value_str) = option.strip_prefix("autosave=") {
+                    if let Some(policy) = AutosavePolicy::by_name(value_str) {
+                        self.autosave = policy;
+                    }
+                }
+                // Check for autosave_interval=N (seconds, 0 writes every change) format
+                else if let Some(value_str) = option.strip_prefix("autosave_interval=") {
+                    if let Ok(value) = value_str.parse::<u64>() {
+                        self.autosave_interval = std::time::Duration::from_secs(value);
+                    }
+                }
+                // Check for skeleton_md=PATH / skeleton_json=PATH format (`~` and `$VAR` expand)
+                else if let Some(value_str) = option.strip_prefix("skeleton_md=") {
+                    self.skeleton_md = Some(paths::expand(value_str));
+                }
+                else if let Some(value_str) = option.strip_prefix("skeleton_json=") {
+                    self.skeleton_json = Some(paths::expand(value_str));
+                }
+                // Check for glyphs=unicode/ascii format
+                else if let Some(value_str) = option.strip_prefix("glyphs=") {
+                    if let Some(glyphs) = Glyphs::by_name(value_str) {
+                        self.glyphs = glyphs;
+                    }
+                }
+                // Check for default_file=PATH format
+                else if let Some(value_str) = option.strip_prefix("default_file=") {
+                    self.default_file = Some(paths::expand(value_str));
+                }
+                // Check for log_file=PATH format
+                else if let Some(value_str) = option.strip_prefix("log_file=") {
+                    self.log_file = Some(paths::expand(value_str));
+                }
+                // Check for hook_timeout=N (seconds) format
+                else if let Some(value_str) = option.strip_prefix("hook_timeout=") {
+                    if let Ok(value) = value_str.parse::<u64>()
+                        && value >= 1 {
+                        self.hooks.timeout = std::time::Duration::from_secs(value);
+                    }
+                }
+                // Check for clipboard=system/osc52/auto format
+                else if let Some(value_str) = option.strip_prefix("clipboard=") {
+                    if let Some(backend) = ClipboardBackend::by_name(value_str) {
+                        self.clipboard = backend;
+                    }
+                }
+                // Check for paste_source=clipboard/primary format
+                else if let Some(value_str) = option.strip_prefix("paste_source=") {
+                    if let Some(source) = PasteSource::by_name(value_str) {
+                        self.paste_source = source;
+                    }
+                }
+                // Check for default_section=inside/outside format
+                else if let Some(value_str) = option.strip_prefix("default_section=") {
+                    if let Some(section) = DefaultSection::by_name(value_str) {
+                        self.default_section = section;
+                    }
+                }
+                // Check for inside_order=file_order/newest_first format
+                else if let Some(value_str) = option.strip_prefix("inside_order=") {
+                    if let Some(order) = InsideOrder::by_name(value_str) {
+                        self.inside_order = order;
+                    }
+                }
+                // Check for lang=en/ja/auto format
+                else if let Some(value_str) = option.strip_prefix("lang=") {
+                    if let Some(lang) = Lang::by_name(value_str) {
+                        self.lang = lang;
+                    }
+                }
+                // url_add_scheme, noexplorer_hide_dotfiles, ...; anything else is ignored
+                else if !self.url_rules.set(option) {
+                    self.explorer_filter.set(option);
+                }
             }
         }
     }
@@ -173,6 +687,25 @@ impl RcConfig {
     }
 }
 
+/// The nearest project config in `dir` or a folder above it; `global` (~/.revwrc) does not
+/// count as one
+pub fn find_project_rc(dir: &Path, global: Option<&Path>) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|folder| folder.join(PROJECT_RC))
+        .filter(|path| Some(path.as_path()) != global)
+        .find(|path| path.is_file())
+}
+
+/// Make a relative path set by an rc file (anything other than `before`) relative to `base`
+fn resolve_relative(path: &mut Option<PathBuf>, before: Option<&PathBuf>, base: &Path) {
+    if path.as_ref() == before {
+        return;
+    }
+    if let Some(path) = path.as_mut().filter(|path| path.is_relative()) {
+        *path = base.join(&*path);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,12 +719,309 @@ mod tests {
 
     #[test]
     fn test_parse_set_nonumber() {
-        let mut config = RcConfig::default();
-        config.show_line_numbers = true;
+        let mut config = RcConfig { show_line_numbers: true, ..Default::default() };
         config.parse("set nonumber");
         assert!(!config.show_line_numbers);
     }
 
+    #[test]
+    fn test_parse_set_preview() {
+        let mut config = RcConfig::default();
+        assert!(!config.image_preview);
+        config.parse("set preview");
+        assert!(config.image_preview);
+        config.parse("set nopreview");
+        assert!(!config.image_preview);
+    }
+
+    #[test]
+    fn test_parse_set_strict() {
+        let mut config = RcConfig::default();
+        assert!(!config.strict_parsing);
+        config.parse("set strict");
+        assert!(config.strict_parsing);
+        config.parse("set nostrict");
+        assert!(!config.strict_parsing);
+    }
+
+    #[test]
+    fn test_parse_set_autosave() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.autosave, AutosavePolicy::OnAction);
+        config.parse("set autosave=manual");
+        assert_eq!(config.autosave, AutosavePolicy::Manual);
+        config.parse("set autosave=always");
+        assert_eq!(config.autosave, AutosavePolicy::Always);
+        config.parse("set autosave=sometimes"); // Unknown policy, ignored
+        assert_eq!(config.autosave, AutosavePolicy::Always);
+        assert_eq!(config.autosave_interval, std::time::Duration::from_secs(2));
+        config.parse("set autosave_interval=0");
+        assert_eq!(config.autosave_interval, std::time::Duration::ZERO);
+        config.parse("set autosave_interval=soon"); // Not a number, ignored
+        assert_eq!(config.autosave_interval, std::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn test_parse_set_format_on_save() {
+        let mut config = RcConfig::default();
+        assert!(!config.format_on_save);
+        config.parse("set format_on_save");
+        assert!(config.format_on_save);
+        config.parse("set noformat_on_save");
+        assert!(!config.format_on_save);
+    }
+
+    #[test]
+    fn test_parse_set_autoname_on_save() {
+        let mut config = RcConfig::default();
+        assert!(!config.autoname_on_save);
+        config.parse("set autoname_on_save");
+        assert!(config.autoname_on_save);
+        config.parse("set noautoname_on_save");
+        assert!(!config.autoname_on_save);
+    }
+
+    #[test]
+    fn test_parse_set_atomic_save() {
+        let mut config = RcConfig::default();
+        assert!(config.atomic_save);
+        config.parse("set noatomic_save");
+        assert!(!config.atomic_save);
+        config.parse("set atomic_save");
+        assert!(config.atomic_save);
+    }
+
+    #[test]
+    fn test_parse_set_preserve_line_endings() {
+        let mut config = RcConfig::default();
+        assert!(!config.preserve_line_endings);
+        config.parse("set preserve_line_endings");
+        assert!(config.preserve_line_endings);
+        config.parse("set nopreserve_line_endings");
+        assert!(!config.preserve_line_endings);
+    }
+
+    #[test]
+    fn test_parse_set_clipboard() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.clipboard, ClipboardBackend::Auto);
+        config.parse("set clipboard=osc52");
+        assert_eq!(config.clipboard, ClipboardBackend::Osc52);
+        config.parse("set clipboard=system");
+        assert_eq!(config.clipboard, ClipboardBackend::System);
+        config.parse("set clipboard=x11"); // Unknown backend, ignored
+        assert_eq!(config.clipboard, ClipboardBackend::System);
+    }
+
+    #[test]
+    fn test_parse_set_url_rules() {
+        let mut config = RcConfig::default();
+        assert!(config.url_rules.add_scheme && config.url_rules.encode_spaces);
+        assert!(!config.url_rules.strip_tracking);
+        config.parse("set url_strip_tracking");
+        config.parse("set nourl_add_scheme");
+        config.parse("set nourl_encode_spaces");
+        assert!(config.url_rules.strip_tracking);
+        assert!(!config.url_rules.add_scheme);
+        assert!(!config.url_rules.encode_spaces);
+    }
+
+    #[test]
+    fn test_parse_set_paste_source() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.paste_source, PasteSource::Clipboard);
+        config.parse("set paste_source=primary");
+        assert_eq!(config.paste_source, PasteSource::Primary);
+        config.parse("set paste_source=secondary"); // Unknown source, ignored
+        assert_eq!(config.paste_source, PasteSource::Primary);
+    }
+
+    #[test]
+    fn test_parse_set_checkbox_percentage() {
+        let mut config = RcConfig::default();
+        assert!(!config.checkbox_percentage);
+        config.parse("set checkbox_percentage");
+        assert!(config.checkbox_percentage);
+        config.parse("set nocheckbox_percentage");
+        assert!(!config.checkbox_percentage);
+    }
+
+    #[test]
+    fn test_parse_set_explorer_filter() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.explorer_filter, ExplorerFilter::default());
+        config.parse("set noexplorer_hide_ignored");
+        config.parse("set noexplorer_supported_only");
+        assert!(!config.explorer_filter.hide_ignored);
+        assert!(config.explorer_filter.hide_dotfiles);
+        assert!(!config.explorer_filter.supported_only);
+        config.parse("set explorer_supported_only");
+        assert!(config.explorer_filter.supported_only);
+    }
+
+    #[test]
+    fn test_parse_set_due_soon_days() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.due_soon_days, 3);
+        config.parse("set due_soon_days=7");
+        assert_eq!(config.due_soon_days, 7);
+        config.parse("set due_soon_days=-1");
+        assert_eq!(config.due_soon_days, 7);
+    }
+
+    #[test]
+    fn test_parse_set_confirm() {
+        let mut config = RcConfig::default();
+        assert!(!config.confirm_delete && !config.confirm_clear && !config.confirm_paste);
+        config.parse("set confirm_delete\nset confirm_clear\nset confirm_paste");
+        assert!(config.confirm_delete && config.confirm_clear && config.confirm_paste);
+        config.parse("set noconfirm_clear");
+        assert!(!config.confirm_clear);
+    }
+
+    #[test]
+    fn test_parse_default_file() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.default_file, None);
+        config.parse("set default_file=/notes/inbox.md");
+        assert_eq!(config.default_file, Some(PathBuf::from("/notes/inbox.md")));
+    }
+
+    #[test]
+    fn test_parse_log_file() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.log_file, None);
+        config.parse("set log_file=/tmp/revw.log");
+        assert_eq!(config.log_file, Some(PathBuf::from("/tmp/revw.log")));
+    }
+
+    #[test]
+    fn test_parse_set_accessible() {
+        let mut config = RcConfig::default();
+        assert!(!config.accessible);
+        config.parse("set accessible");
+        assert!(config.accessible);
+        config.parse("set noaccessible");
+        assert!(!config.accessible);
+    }
+
+    #[test]
+    fn test_parse_glyphs() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.glyphs, Glyphs::Unicode);
+        config.parse("set glyphs=ascii");
+        assert_eq!(config.glyphs, Glyphs::Ascii);
+        config.parse("set glyphs=fancy");
+        assert_eq!(config.glyphs, Glyphs::Ascii);
+    }
+
+    #[test]
+    fn test_parse_set_smooth_scroll() {
+        let mut config = RcConfig::default();
+        assert!(!config.smooth_scroll);
+        config.parse("set smooth_scroll");
+        assert!(config.smooth_scroll);
+        config.parse("set nosmooth_scroll");
+        assert!(!config.smooth_scroll);
+    }
+
+    #[test]
+    fn test_parse_skeletons() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.skeleton_md, None);
+        config.parse("set skeleton_md=/notes/templates/note.md\nset skeleton_json=~/templates/note.json");
+        assert_eq!(config.skeleton_md, Some(PathBuf::from("/notes/templates/note.md")));
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(config.skeleton_json, Some(home.join("templates/note.json")));
+        }
+    }
+
+    #[test]
+    fn test_parse_set_paste_limit() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.paste_limit, 100);
+        config.parse("set paste_limit=500");
+        assert_eq!(config.paste_limit, 500);
+        config.parse("set paste_limit=lots");
+        assert_eq!(config.paste_limit, 500);
+        config.parse("set paste_limit=0");
+        assert_eq!(config.paste_limit, 0);
+    }
+
+    #[test]
+    fn test_parse_set_show_dropped() {
+        let mut config = RcConfig::default();
+        assert!(!config.show_dropped);
+        config.parse("set show_dropped");
+        assert!(config.show_dropped);
+        config.parse("set noshow_dropped");
+        assert!(!config.show_dropped);
+    }
+
+    #[test]
+    fn test_parse_set_default_section() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.default_section, DefaultSection::Inside);
+        config.parse("set default_section=outside");
+        assert_eq!(config.default_section, DefaultSection::Outside);
+        config.parse("set default_section=elsewhere"); // Unknown section, ignored
+        assert_eq!(config.default_section, DefaultSection::Outside);
+    }
+
+    #[test]
+    fn test_parse_set_inside_order() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.inside_order, InsideOrder::FileOrder);
+        config.parse("set inside_order=newest_first");
+        assert_eq!(config.inside_order, InsideOrder::NewestFirst);
+        config.parse("set inside_order=sideways"); // Unknown order, ignored
+        assert_eq!(config.inside_order, InsideOrder::NewestFirst);
+        config.parse("set inside_order=file_order");
+        assert_eq!(config.inside_order, InsideOrder::FileOrder);
+    }
+
+    #[test]
+    fn test_parse_set_lang() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.lang, Lang::Auto);
+        config.parse("set lang=ja");
+        assert_eq!(config.lang, Lang::Ja);
+        config.parse("set lang=klingon"); // Unknown language, ignored
+        assert_eq!(config.lang, Lang::Ja);
+        config.parse("set lang=auto");
+        assert_eq!(config.lang, Lang::Auto);
+    }
+
+    #[test]
+    fn test_parse_hooks() {
+        let mut config = RcConfig::default();
+        config.parse("hook post_save git add {file} && git commit -m 'notes'\nhook on_open echo hi\nset hook_timeout=3");
+        let commands: Vec<&str> = config.hooks.commands(HookEvent::PostSave).collect();
+        assert_eq!(commands, vec!["git add {file} && git commit -m 'notes'"]);
+        assert_eq!(config.hooks.commands(HookEvent::PreSave).count(), 0);
+        assert_eq!(config.hooks.timeout, std::time::Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_parse_sync() {
+        let mut config = RcConfig::default();
+        config.parse("sync url https://cloud.example.com/dav/notes/\nsync user me\nsync password_cmd secret-tool lookup service nextcloud\nsync token abc");
+        assert_eq!(config.sync.url.as_deref(), Some("https://cloud.example.com/dav/notes/"));
+        assert_eq!(config.sync.user.as_deref(), Some("me"));
+        assert_eq!(config.sync.password_cmd.as_deref(), Some("secret-tool lookup service nextcloud"));
+        assert_eq!(config.sync.password_env, None);
+    }
+
+    #[test]
+    fn test_parse_templates() {
+        let mut config = RcConfig::default();
+        config.parse("template link - [{name}]({url}) \u{2014} {percentage}%\ntemplate note {date}\\n{context}\ntemplate link [{name}]({url})\ntemplate empty");
+        assert_eq!(
+            config.templates,
+            vec![("link".to_string(), "[{name}]({url})".to_string()), ("note".to_string(), "{date}\n{context}".to_string())]
+        );
+    }
+
     #[test]
     fn test_parse_comments() {
         let mut config = RcConfig::default();
@@ -217,6 +1047,16 @@ mod tests {
         assert_eq!(config.max_visible_cards, 4);
     }
 
+    #[test]
+    fn test_parse_set_max_context_lines() {
+        let mut config = RcConfig::default();
+        assert_eq!(config.max_context_lines, 0);
+        config.parse("set max_context_lines=3");
+        assert_eq!(config.max_context_lines, 3);
+        config.parse("set max_context_lines=many"); // Not a number, ignored
+        assert_eq!(config.max_context_lines, 3);
+    }
+
     #[test]
     fn test_parse_set_card_invalid() {
         let mut config = RcConfig::default();
@@ -239,8 +1079,7 @@ mod tests {
 
     #[test]
     fn test_parse_border_rounded() {
-        let mut config = RcConfig::default();
-        config.border_style = BorderStyle::Plain; // Start with plain
+        let mut config = RcConfig { border_style: BorderStyle::Plain, ..Default::default() }; // Start with plain
         config.parse("set border=rounded");
         assert_eq!(config.border_style, BorderStyle::Rounded);
     }
@@ -274,16 +1113,14 @@ mod tests {
 
     #[test]
     fn test_parse_set_norelativenumber() {
-        let mut config = RcConfig::default();
-        config.show_relative_line_numbers = true;
+        let mut config = RcConfig { show_relative_line_numbers: true, ..Default::default() };
         config.parse("set norelativenumber");
         assert!(!config.show_relative_line_numbers);
     }
 
     #[test]
     fn test_parse_set_nornu() {
-        let mut config = RcConfig::default();
-        config.show_relative_line_numbers = true;
+        let mut config = RcConfig { show_relative_line_numbers: true, ..Default::default() };
         config.parse("set nornu");
         assert!(!config.show_relative_line_numbers);
     }
@@ -1,3 +1,6 @@
+use crate::status::{self, EntryStatus};
+use serde_json::Value;
+
 /// Unified interface for content operations (JSON and Markdown)
 pub trait ContentOperations {
     /// Add a new inside entry
@@ -20,7 +23,7 @@ pub trait ContentOperations {
         content: &str,
         cursor_line: usize,
         lines: &[String],
-    ) -> Result<(String, String), String>;
+    ) -> Result<String, String>;
 
     /// Order entries (outside by percentage desc + name asc, inside by date desc)
     fn order_entries(&self, content: &str) -> Result<(String, String), String>;
@@ -28,9 +31,144 @@ pub trait ContentOperations {
     /// Order entries by percentage only (outside) and date (inside)
     fn order_by_percentage(&self, content: &str) -> Result<(String, String), String>;
 
+    /// Order entries by priority (outside, highest first) and date (inside)
+    fn order_by_priority(&self, content: &str) -> Result<(String, String), String>;
+
+    /// Order entries by due date (outside, soonest first) and date (inside)
+    fn order_by_due(&self, content: &str) -> Result<(String, String), String>;
+
     /// Order entries by name only (outside) and date (inside)
     fn order_by_name(&self, content: &str) -> Result<(String, String), String>;
 
     /// Order entries randomly (outside only, inside by date)
     fn order_random(&self, content: &str) -> Result<(String, String), String>;
+
+    /// Check content against the strict structure rules
+    fn validate_strict(&self, content: &str) -> Result<(), String>;
+}
+
+/// Markdown task checkboxes (`- [ ]` / `- [x]`) in a context as (checked, total).
+/// Boxes inside fenced code blocks are ignored; None when there are none.
+pub fn checkbox_progress(context: &str) -> Option<(usize, usize)> {
+    let mut in_code_block = false;
+    let (mut checked, mut total) = (0, 0);
+    for line in context.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let Some(rest) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) else {
+            continue;
+        };
+        if rest.starts_with("[ ]") {
+            total += 1;
+        } else if rest.starts_with("[x]") || rest.starts_with("[X]") {
+            checked += 1;
+            total += 1;
+        }
+    }
+    if total == 0 { None } else { Some((checked, total)) }
+}
+
+/// Share of checked task boxes as a rounded percentage, None without checkboxes
+pub fn checkbox_percentage(context: &str) -> Option<i64> {
+    checkbox_progress(context).map(|(checked, total)| ((checked * 100 + total / 2) / total) as i64)
+}
+
+/// Label of the row for OUTSIDE entries without tags in `tag_stats`
+pub const UNTAGGED: &str = "(untagged)";
+
+/// One row of `:stats tags`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagStats {
+    pub tag: String,
+    pub entries: usize,
+    /// Mean completion of the entries, in percent
+    pub average: f64,
+}
+
+/// Entry count and average completion per tag over the OUTSIDE entries, lagging tags
+/// first. An entry counts towards each of its tags; a missing percentage counts as 0,
+/// done entries as 100, and dropped entries not at all. Untagged entries share a last
+/// `(untagged)` row.
+pub fn tag_stats(json: &Value) -> Vec<TagStats> {
+    let empty = Vec::new();
+    let outside = json.get("outside").and_then(Value::as_array).unwrap_or(&empty);
+    // (tag, entries, sum of percentages) in order of first appearance
+    let mut totals: Vec<(String, usize, i64)> = Vec::new();
+    let mut untagged = (0usize, 0i64);
+    for entry in outside {
+        let percentage = match EntryStatus::of(entry.get(status::KEY)) {
+            EntryStatus::Dropped => continue,
+            EntryStatus::Done => 100,
+            EntryStatus::Active => entry.get("percentage").and_then(Value::as_i64).unwrap_or(0).clamp(0, 100),
+        };
+        let mut tags: Vec<&str> = match entry.get("tags") {
+            Some(Value::Array(list)) => list.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(tag)) => vec![tag.as_str()],
+            _ => Vec::new(),
+        };
+        tags.retain(|tag| !tag.trim().is_empty());
+        tags.sort_unstable();
+        tags.dedup();
+        if tags.is_empty() {
+            untagged.0 += 1;
+            untagged.1 += percentage;
+        }
+        for tag in tags {
+            match totals.iter_mut().find(|(name, _, _)| name == tag) {
+                Some((_, count, sum)) => {
+                    *count += 1;
+                    *sum += percentage;
+                }
+                None => totals.push((tag.to_string(), 1, percentage)),
+            }
+        }
+    }
+
+    let row = |tag: String, entries: usize, sum: i64| TagStats { tag, entries, average: sum as f64 / entries as f64 };
+    let mut stats: Vec<TagStats> = totals.into_iter().map(|(tag, entries, sum)| row(tag, entries, sum)).collect();
+    stats.sort_by(|a, b| a.average.total_cmp(&b.average).then_with(|| a.tag.cmp(&b.tag)));
+    if untagged.0 > 0 {
+        stats.push(row(UNTAGGED.to_string(), untagged.0, untagged.1));
+    }
+    stats
+}
+
+/// http(s) URLs in a text, in order of appearance and without duplicates. A URL ends at
+/// whitespace, quotes or an unbalanced `)` / `]` (Markdown links), and loses trailing
+/// sentence punctuation.
+pub fn find_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = [rest.find("http://"), rest.find("https://")].into_iter().flatten().min() {
+        let candidate = &rest[start..];
+        let mut depth = 0i32;
+        let end = candidate
+            .char_indices()
+            .find(|&(_, c)| match c {
+                '(' | '[' => {
+                    depth += 1;
+                    false
+                }
+                ')' | ']' if depth == 0 => true,
+                ')' | ']' => {
+                    depth -= 1;
+                    false
+                }
+                _ => c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '`'),
+            })
+            .map_or(candidate.len(), |(i, _)| i);
+        let url = candidate[..end].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+        let has_host = url.split_once("://").is_some_and(|(_, host)| !host.is_empty());
+        if has_host && !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+        }
+        rest = &candidate[end.max(1)..];
+    }
+    urls
 }
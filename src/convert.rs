@@ -0,0 +1,264 @@
+//! Headless conversion between the formats revw understands.
+//!
+//! ```
+//! use revw::convert::{Converter, Format, Sections};
+//!
+//! let md = "## INSIDE\n\n### 2025-01-01 10:00:00\nnote\n";
+//! let json = Converter::new()
+//!     .from(Format::Md)
+//!     .to(Format::Json)
+//!     .sections(Sections::InsideOnly)
+//!     .pretty(false)
+//!     .convert(md)
+//!     .unwrap();
+//! assert_eq!(json, r#"{"inside":[{"date":"2025-01-01 10:00:00","context":"note"}]}"#);
+//! ```
+
+use crate::ansi::{self, Painter};
+use crate::config::ColorScheme;
+use crate::json_ops::JsonOperations;
+use crate::markdown_ops::MarkdownOperations;
+use crate::output;
+use serde_json::Value;
+use std::path::Path;
+
+/// Document format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Md,
+    /// Plain text listing (output only), as printed by `revw --stdout`
+    Text,
+    /// OUTSIDE entries as aligned name/percentage/url columns (output only)
+    Table,
+    /// OUTSIDE entries as tab-separated name/percentage/url rows (output only)
+    Tsv,
+    /// One line per entry with chosen fields and separator, for scripts (output only)
+    Plain,
+    /// TOON tables (`outside[2]{name,url}:`); recognised by `detect` but not read or written yet
+    Toon,
+}
+
+impl Format {
+    /// Format implied by a file extension (`.md` is Markdown, anything else JSON)
+    pub fn from_path(path: &Path) -> Format {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("md") => Format::Md,
+            _ => Format::Json,
+        }
+    }
+
+    /// Format of a file: `.md` and `.json` decide by extension, anything else by content
+    pub fn for_file(path: &Path, content: &str) -> Format {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("json") => Format::from_path(path),
+            _ => Format::detect(content),
+        }
+    }
+
+    /// Guess the format of raw content: a leading `{`/`[` is JSON; a leading `## ` section
+    /// header or `### ` entry heading, or a `## OUTSIDE`/`## INSIDE` line anywhere, is Markdown;
+    /// an `outside[N]`/`inside[N]` table is TOON. Anything else is taken as JSON.
+    pub fn detect(content: &str) -> Format {
+        let trimmed = content.trim_start();
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            return Format::Json;
+        }
+        let first_line = trimmed.lines().next().unwrap_or("").trim_end();
+        if first_line.starts_with("## ")
+            || first_line.starts_with("### ")
+            || trimmed.lines().any(|line| matches!(line.trim_end(), "## OUTSIDE" | "## INSIDE"))
+        {
+            return Format::Md;
+        }
+        if is_toon_header(first_line) {
+            return Format::Toon;
+        }
+        Format::Json
+    }
+}
+
+/// `outside[3]:`, `inside[2]{date,context}:` and similar TOON array headers
+fn is_toon_header(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix("outside[").or_else(|| line.strip_prefix("inside[")) else {
+        return false;
+    };
+    let Some((count, rest)) = rest.split_once(']') else {
+        return false;
+    };
+    if count.is_empty() || !count.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let rest = match rest.strip_prefix('{') {
+        Some(fields) => match fields.split_once('}') {
+            Some((_, rest)) => rest,
+            None => return false,
+        },
+        None => rest,
+    };
+    rest.starts_with(':')
+}
+
+/// Which sections to keep in the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Sections {
+    #[default]
+    All,
+    InsideOnly,
+    OutsideOnly,
+}
+
+/// Builder for a single conversion.
+/// Input format is detected from the content unless set with `from`.
+#[derive(Debug, Clone)]
+pub struct Converter {
+    from: Option<Format>,
+    to: Format,
+    sections: Sections,
+    pretty: bool,
+    filter: Option<String>,
+    context_chars: Option<usize>,
+    fields: Vec<String>,
+    separator: String,
+    colors: Option<ColorScheme>,
+    width: Option<usize>,
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Converter {
+    pub fn new() -> Self {
+        Self {
+            from: None,
+            to: Format::Json,
+            sections: Sections::All,
+            pretty: true,
+            filter: None,
+            context_chars: None,
+            fields: Vec::new(),
+            separator: "\t".to_string(),
+            colors: None,
+            width: None,
+        }
+    }
+
+    pub fn from(mut self, format: Format) -> Self {
+        self.from = Some(format);
+        self
+    }
+
+    pub fn to(mut self, format: Format) -> Self {
+        self.to = format;
+        self
+    }
+
+    pub fn sections(mut self, sections: Sections) -> Self {
+        self.sections = sections;
+        self
+    }
+
+    /// Pretty-print JSON output (default: true)
+    #[allow(dead_code)] // library API; the CLI always pretty-prints
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Keep only entries matching `pattern` (same matching as `--filter`)
+    pub fn filter(mut self, pattern: impl Into<String>) -> Self {
+        self.filter = Some(pattern.into());
+        self
+    }
+
+    /// Trim context fields to N chars around the filter match (same as `--context`)
+    pub fn context_chars(mut self, chars: usize) -> Self {
+        self.context_chars = Some(chars);
+        self
+    }
+
+    /// Keys `Format::Plain` prints, in order (default: name/url/percentage, date/context)
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// What `Format::Plain` puts between fields (default: a tab)
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Color Text, Markdown and Table output with ANSI codes from `scheme` (`--color`);
+    /// machine formats (JSON, TSV, plain rows) are never colored
+    pub fn colors(mut self, scheme: ColorScheme) -> Self {
+        self.colors = Some(scheme);
+        self
+    }
+
+    /// Fit Table rows in `cols` terminal columns, cutting long names and URLs with `…`
+    /// (`--table` on a terminal; piped output keeps full values)
+    pub fn width(mut self, cols: usize) -> Self {
+        self.width = Some(cols);
+        self
+    }
+
+    /// Parse `input` into a JSON value
+    pub fn parse(&self, input: &str) -> Result<Value, String> {
+        let json = match self.from.unwrap_or_else(|| Format::detect(input)) {
+            Format::Json => input.to_string(),
+            Format::Md => MarkdownOperations::parse_to_json(input)?,
+            Format::Toon => return Err("TOON input is not supported yet".to_string()),
+            output => return Err(format!("{:?} is an output-only format", output)),
+        };
+        serde_json::from_str(&json).map_err(|_| "Invalid JSON".to_string())
+    }
+
+    /// Convert `input` to the target format
+    pub fn convert(&self, input: &str) -> Result<String, String> {
+        let value = self.parse(input)?;
+        self.convert_value(&value)
+    }
+
+    /// Apply filter/section options to an already parsed document and render it
+    pub fn convert_value(&self, value: &Value) -> Result<String, String> {
+        let value = self.select(value);
+        let painter = self.colors.as_ref().map_or_else(Painter::plain, Painter::new);
+        match self.to {
+            Format::Json if self.pretty => serde_json::to_string_pretty(&value),
+            Format::Json => serde_json::to_string(&value),
+            Format::Md => return Ok(ansi::paint_markdown(&MarkdownOperations::render_from_json(&value), &painter)),
+            Format::Text => return Ok(output::render_text(&value, &painter)),
+            Format::Table => return Ok(output::render_table(&value, &painter, self.width)),
+            Format::Tsv => return Ok(output::render_tsv(&value)),
+            Format::Plain => return Ok(output::render_plain(&value, &self.fields, &self.separator)),
+            Format::Toon => return Err("TOON output is not supported yet".to_string()),
+        }
+        .map_err(|e| format!("JSON serialization error: {}", e))
+    }
+
+    fn select(&self, value: &Value) -> Value {
+        let mut value = match &self.filter {
+            Some(pattern) => JsonOperations::filter_entries(value, pattern),
+            None => value.clone(),
+        };
+        if let (Some(pattern), Some(chars)) = (&self.filter, self.context_chars) {
+            value = JsonOperations::trim_context_around_match(&value, pattern, chars);
+        }
+        if let Some(obj) = value.as_object_mut() {
+            match self.sections {
+                Sections::All => {}
+                Sections::InsideOnly => {
+                    obj.remove("outside");
+                }
+                Sections::OutsideOnly => {
+                    obj.remove("inside");
+                }
+            }
+        }
+        value
+    }
+}
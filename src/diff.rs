@@ -0,0 +1,229 @@
+//! Entry-level diff of two documents (`--diff OLD NEW`).
+//!
+//! Entries are paired up by what identifies them: the date of an INSIDE entry and the URL
+//! of an OUTSIDE one, then by context or name for entries whose date or URL changed.
+//! Paired entries with different fields are changed, the rest added or removed. Order
+//! is ignored, and a missing field, `null` and `""` count as the same, so a JSON file and
+//! its Markdown conversion compare equal.
+
+use crate::fields;
+use serde_json::{json, Map, Value};
+
+const SECTIONS: [&str; 2] = ["outside", "inside"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        }
+    }
+
+    fn marker(self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Changed => '~',
+        }
+    }
+}
+
+/// A field of a changed entry; `None` when the entry lacks it
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    pub key: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryChange {
+    pub section: &'static str,
+    pub kind: ChangeKind,
+    /// The entry as in the old document (`None` when added)
+    pub old: Option<Value>,
+    /// The entry as in the new document (`None` when removed)
+    pub new: Option<Value>,
+    /// Differing fields, for changed entries
+    pub fields: Vec<FieldChange>,
+}
+
+impl EntryChange {
+    /// Name of an OUTSIDE entry (its URL when unnamed) or date of an INSIDE one
+    pub fn label(&self) -> String {
+        let entry = self.new.as_ref().or(self.old.as_ref());
+        let text = |key: &str| entry.and_then(|e| present(e, key)).map(fields::display_value);
+        let label = if self.section == "inside" { text("date") } else { text("name").or_else(|| text("url")) };
+        label.unwrap_or_else(|| "(untitled)".to_string())
+    }
+}
+
+/// A field's value, treating `null` and `""` as absent
+fn present<'a>(entry: &'a Value, key: &str) -> Option<&'a Value> {
+    entry.get(key).filter(|value| !value.is_null() && value.as_str() != Some(""))
+}
+
+/// Keys entries are paired up by, most specific first
+fn match_keys(section: &str) -> [&'static str; 2] {
+    if section == "inside" { ["date", "context"] } else { ["url", "name"] }
+}
+
+fn field_changes(old: &Value, new: &Value) -> Vec<FieldChange> {
+    let empty = Map::new();
+    let old_obj = old.as_object().unwrap_or(&empty);
+    let new_obj = new.as_object().unwrap_or(&empty);
+    let mut keys: Vec<&String> = new_obj.keys().collect();
+    keys.extend(old_obj.keys().filter(|key| !new_obj.contains_key(*key)));
+    keys.into_iter()
+        .filter_map(|key| {
+            let (old, new) = (present(old, key), present(new, key));
+            (old != new).then(|| FieldChange { key: key.clone(), old: old.cloned(), new: new.cloned() })
+        })
+        .collect()
+}
+
+/// Changes from `old` to `new`, OUTSIDE first, in the order of the new document
+/// (removed entries follow in their old order)
+pub fn diff(old: &Value, new: &Value) -> Vec<EntryChange> {
+    let mut changes = Vec::new();
+    for section in SECTIONS {
+        let empty = Vec::new();
+        let old_entries = old.get(section).and_then(Value::as_array).unwrap_or(&empty);
+        let new_entries = new.get(section).and_then(Value::as_array).unwrap_or(&empty);
+
+        // pairs[new index] = old index
+        let mut pairs: Vec<Option<usize>> = vec![None; new_entries.len()];
+        let mut paired = vec![false; old_entries.len()];
+        for key in match_keys(section) {
+            for (new_idx, entry) in new_entries.iter().enumerate() {
+                let Some(value) = present(entry, key).filter(|_| pairs[new_idx].is_none()) else {
+                    continue;
+                };
+                let found = old_entries
+                    .iter()
+                    .enumerate()
+                    .position(|(old_idx, old)| !paired[old_idx] && present(old, key) == Some(value));
+                if let Some(old_idx) = found {
+                    pairs[new_idx] = Some(old_idx);
+                    paired[old_idx] = true;
+                }
+            }
+        }
+
+        for (new_idx, entry) in new_entries.iter().enumerate() {
+            match pairs[new_idx] {
+                Some(old_idx) => {
+                    let fields = field_changes(&old_entries[old_idx], entry);
+                    if !fields.is_empty() {
+                        changes.push(EntryChange {
+                            section,
+                            kind: ChangeKind::Changed,
+                            old: Some(old_entries[old_idx].clone()),
+                            new: Some(entry.clone()),
+                            fields,
+                        });
+                    }
+                }
+                None => changes.push(EntryChange {
+                    section,
+                    kind: ChangeKind::Added,
+                    old: None,
+                    new: Some(entry.clone()),
+                    fields: Vec::new(),
+                }),
+            }
+        }
+        for (old_idx, entry) in old_entries.iter().enumerate() {
+            if !paired[old_idx] {
+                changes.push(EntryChange {
+                    section,
+                    kind: ChangeKind::Removed,
+                    old: Some(entry.clone()),
+                    new: None,
+                    fields: Vec::new(),
+                });
+            }
+        }
+    }
+    changes
+}
+
+/// "2 added, 1 removed, 3 changed"
+pub fn summary(changes: &[EntryChange]) -> String {
+    if changes.is_empty() {
+        return "No differences".to_string();
+    }
+    [ChangeKind::Added, ChangeKind::Removed, ChangeKind::Changed]
+        .iter()
+        .map(|kind| format!("{} {}", changes.iter().filter(|c| c.kind == *kind).count(), kind.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Readable listing: a `+`/`-`/`~` line per entry under its section heading, the
+/// changed fields indented below, and the summary last
+pub fn render_text(changes: &[EntryChange]) -> String {
+    let mut out = String::new();
+    for section in SECTIONS {
+        let in_section: Vec<&EntryChange> = changes.iter().filter(|c| c.section == section).collect();
+        if in_section.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("{}\n", section.to_uppercase()));
+        for change in in_section {
+            out.push_str(&format!("{} {}\n", change.kind.marker(), change.label()));
+            for field in &change.fields {
+                let text = |value: &Option<Value>| value.as_ref().map_or("(none)".to_string(), fields::value_to_text);
+                out.push_str(&format!("    {}: {} → {}\n", field.key, text(&field.old), text(&field.new)));
+            }
+        }
+        out.push('\n');
+    }
+    out.push_str(&summary(changes));
+    out
+}
+
+/// `{"changes": [...], "summary": {"added": n, "removed": n, "changed": n}}` for tooling
+pub fn to_json(changes: &[EntryChange]) -> Value {
+    let count = |kind: ChangeKind| changes.iter().filter(|c| c.kind == kind).count();
+    let list: Vec<Value> = changes
+        .iter()
+        .map(|change| {
+            let mut obj = Map::new();
+            obj.insert("section".to_string(), json!(change.section));
+            obj.insert("change".to_string(), json!(change.kind.name()));
+            obj.insert("label".to_string(), json!(change.label()));
+            if let Some(old) = &change.old {
+                obj.insert("old".to_string(), old.clone());
+            }
+            if let Some(new) = &change.new {
+                obj.insert("new".to_string(), new.clone());
+            }
+            if change.kind == ChangeKind::Changed {
+                let fields: Vec<Value> = change
+                    .fields
+                    .iter()
+                    .map(|field| json!({"key": field.key, "old": field.old, "new": field.new}))
+                    .collect();
+                obj.insert("fields".to_string(), Value::Array(fields));
+            }
+            Value::Object(obj)
+        })
+        .collect();
+    json!({
+        "changes": list,
+        "summary": {
+            "added": count(ChangeKind::Added),
+            "removed": count(ChangeKind::Removed),
+            "changed": count(ChangeKind::Changed),
+        }
+    })
+}
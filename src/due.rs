@@ -0,0 +1,144 @@
+//! Optional `due` date of OUTSIDE entries.
+//!
+//! Stored as `YYYY-MM-DD` (a `**Due:**` line in Markdown). Cards due within
+//! `due_soon_days` turn yellow and overdue ones red unless they are done or dropped;
+//! filters such as `due<=7` compare the number of days left.
+
+use crate::status::{self, EntryStatus};
+use chrono::{Duration, NaiveDate};
+use ratatui::style::Color;
+use serde_json::Value;
+
+/// Layout due dates are written in
+pub const FORMAT: &str = "%Y-%m-%d";
+
+/// Date of a `YYYY-MM-DD` text; a trailing time (`2025-01-01 10:00`) is ignored
+pub fn parse(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    let date = text.split_whitespace().next().unwrap_or(text);
+    NaiveDate::parse_from_str(date, FORMAT).ok()
+}
+
+/// Date typed in the edit overlay: `YYYY-MM-DD`, `today`, `tomorrow`, or `+N` days
+/// (`+2w` for weeks) from `today`
+pub fn parse_input(text: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let text = text.trim().to_ascii_lowercase();
+    match text.as_str() {
+        "today" => return Some(today),
+        "tomorrow" => return Some(today + Duration::days(1)),
+        _ => {}
+    }
+    if let Some(offset) = text.strip_prefix('+') {
+        let (number, unit) = match offset.strip_suffix('w') {
+            Some(weeks) => (weeks, 7),
+            None => (offset.strip_suffix('d').unwrap_or(offset), 1),
+        };
+        let count: i64 = number.parse().ok()?;
+        return today.checked_add_signed(Duration::days(count * unit));
+    }
+    parse(&text)
+}
+
+/// Due date of a JSON `due` value
+pub fn of(value: Option<&Value>) -> Option<NaiveDate> {
+    value.and_then(Value::as_str).and_then(parse)
+}
+
+/// Days from `today` until `due` (negative when overdue)
+pub fn days_left(due: NaiveDate, today: NaiveDate) -> i64 {
+    (due - today).num_days()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueState {
+    Overdue,
+    /// Due today or within the `due_soon_days` window
+    Soon,
+    Later,
+}
+
+impl DueState {
+    /// Where a card stands; done and dropped entries are never overdue
+    pub fn of(due: NaiveDate, status: EntryStatus, today: NaiveDate, soon_days: i64) -> Self {
+        let days = days_left(due, today);
+        if status != EntryStatus::Active || days > soon_days {
+            DueState::Later
+        } else if days < 0 {
+            DueState::Overdue
+        } else {
+            DueState::Soon
+        }
+    }
+
+    /// Warning color for the card, `None` when nothing is pressing
+    pub fn color(self) -> Option<Color> {
+        match self {
+            DueState::Overdue => Some(Color::Red),
+            DueState::Soon => Some(Color::Yellow),
+            DueState::Later => None,
+        }
+    }
+}
+
+/// Card label: `overdue 3d`, `due today`, `due tomorrow`, `due in 5d`, or the date when
+/// more than a week away
+pub fn label(due: NaiveDate, today: NaiveDate) -> String {
+    match days_left(due, today) {
+        days if days < 0 => format!("overdue {}d", -days),
+        0 => "due today".to_string(),
+        1 => "due tomorrow".to_string(),
+        days if days <= 7 => format!("due in {}d", days),
+        _ => format!("due {}", due.format(FORMAT)),
+    }
+}
+
+/// Active OUTSIDE entries past their due date
+pub fn overdue_count(json_value: &Value, today: NaiveDate) -> usize {
+    json_value
+        .get("outside")
+        .and_then(Value::as_array)
+        .map(|outside| {
+            outside
+                .iter()
+                .filter(|item| EntryStatus::of(item.get(status::KEY)) == EntryStatus::Active)
+                .filter_map(|item| of(item.get("due")))
+                .filter(|due| *due < today)
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Comparison parsed from filters such as `due<=7` (due within a week, overdue included)
+/// or `due<0` (overdue), counted in days from today
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DueFilter {
+    op: &'static str,
+    days: i64,
+}
+
+impl DueFilter {
+    pub fn parse(pattern: &str) -> Option<Self> {
+        let rest = pattern.trim().strip_prefix("due")?.trim_start();
+        let op = [">=", "<=", "!=", "=", ">", "<"]
+            .into_iter()
+            .find(|op| rest.starts_with(op))?;
+        let days = rest[op.len()..].trim().parse().ok()?;
+        Some(Self { op, days })
+    }
+
+    /// Entries without a due date never match
+    pub fn matches(&self, due: Option<NaiveDate>, today: NaiveDate) -> bool {
+        let Some(due) = due else {
+            return false;
+        };
+        let days = days_left(due, today);
+        match self.op {
+            ">=" => days >= self.days,
+            "<=" => days <= self.days,
+            "!=" => days != self.days,
+            ">" => days > self.days,
+            "<" => days < self.days,
+            _ => days == self.days,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Custom fields: extra key/value pairs on entries beyond the standard ones.
+//!
+//! They are kept in file order, written to Markdown as `**key:** value` lines
+//! and shown below the context on cards and in the edit overlay.
+
+use serde_json::{Map, Value};
+
+/// Keys with a dedicated place in OUTSIDE entries; anything else is a custom field
+pub const OUTSIDE_FIELDS: &[&str] = &["name", "context", "url", "percentage", "priority", "due", "attachments", "tags"];
+/// Keys with a dedicated place in INSIDE entries; anything else is a custom field
+pub const INSIDE_FIELDS: &[&str] = &["date", "context", "attachments", "tags"];
+/// Standard keys that are still shown, edited and written to Markdown as `**key:** value`
+/// lines, the way custom fields are
+pub const LINE_FIELDS: &[&str] = &["tags"];
+
+/// Standard keys of a section ("outside" or "inside")
+pub fn standard_fields(section: &str) -> &'static [&'static str] {
+    if section == "inside" {
+        INSIDE_FIELDS
+    } else {
+        OUTSIDE_FIELDS
+    }
+}
+
+/// Extra key/value pairs of an entry, in file order, along with its `LINE_FIELDS`
+pub fn custom_fields<'a>(item_obj: &'a Map<String, Value>, section: &str) -> Vec<(&'a String, &'a Value)> {
+    let standard = standard_fields(section);
+    item_obj
+        .iter()
+        .filter(|(key, _)| !standard.contains(&key.as_str()) || LINE_FIELDS.contains(&key.as_str()))
+        .collect()
+}
+
+/// Keys usable as `**key:** value` lines in Markdown: lowercase identifiers that are not
+/// standard fields (other than `LINE_FIELDS`), so bold labels such as `**Note:**` inside a
+/// context stay plain text
+pub fn is_custom_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        && (LINE_FIELDS.contains(&key) || (!OUTSIDE_FIELDS.contains(&key) && !INSIDE_FIELDS.contains(&key)))
+}
+
+/// Single-line text for a value. Plain strings are written as-is; strings that would read
+/// back as another type (or span lines) and non-string values are written as JSON.
+pub fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) if !s.contains('\n') && serde_json::from_str::<Value>(s).is_err() => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Inverse of `value_to_text`
+pub fn text_to_value(text: &str) -> Value {
+    serde_json::from_str(text.trim()).unwrap_or_else(|_| Value::String(text.trim().to_string()))
+}
+
+/// Text shown on cards: strings without quotes, everything else as JSON
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Fill the `{key}` placeholders of a `:yank-template` template from an entry. Any key of
+/// the entry works, standard or custom, shown as on cards; missing or null fields leave
+/// nothing. Braces around anything that is not a key are kept as written.
+pub fn fill_template(template: &str, item_obj: &Map<String, Value>) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let key = after.find('}').map(|close| &after[..close]).filter(|key| {
+            !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        });
+        match key {
+            Some(key) => {
+                if let Some(value) = item_obj.get(key).filter(|v| !v.is_null()) {
+                    out.push_str(&display_value(value));
+                }
+                rest = &after[key.len() + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// `**key:** value` Markdown line for a custom field
+pub fn markdown_line(key: &str, value: &Value) -> String {
+    format!("**{}:** {}", key, value_to_text(value))
+}
+
+/// Split a `**key:** value` Markdown line into key and value text
+pub fn parse_markdown_line(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix("**")?;
+    let (key, value) = rest.split_once(":**")?;
+    is_custom_key(key).then(|| (key, value.trim()))
+}
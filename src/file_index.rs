@@ -0,0 +1,114 @@
+//! Recursive list of the files under a directory, built on a worker thread.
+//!
+//! The Ctrl+p finder matches against the paths while they arrive: the worker sends them
+//! in batches over a channel that the event loop drains between frames, so typing never
+//! waits for a large tree. Dropping the index stops the worker.
+
+use crate::config::ExplorerFilter;
+use ignore::WalkBuilder;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+
+/// Files sent to the UI per message
+const BATCH: usize = 256;
+/// Stop listing after this many files
+pub const MAX_FILES: usize = 50_000;
+
+/// Extensions revw opens; the rest are hidden when `supported_only` is on
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["json", "md", "toon"];
+
+/// Walker over `root` honoring the explorer's ignore and dotfile settings
+pub fn walker(root: &Path, filter: ExplorerFilter) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(filter.hide_dotfiles)
+        .git_ignore(filter.hide_ignored)
+        .git_exclude(filter.hide_ignored)
+        .git_global(false)
+        .ignore(false)
+        .parents(filter.hide_ignored)
+        .require_git(false);
+    builder
+}
+
+/// Whether a file is listed under `filter` (directories always are)
+pub fn is_listed(path: &Path, is_dir: bool, filter: ExplorerFilter) -> bool {
+    is_dir
+        || !filter.supported_only
+        || path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+pub struct FileIndex {
+    pub root: PathBuf,
+    /// Paths relative to `root`, with `/` separators, in the order found
+    pub files: Vec<String>,
+    /// The walk has finished (or hit `MAX_FILES`)
+    pub done: bool,
+    receiver: Receiver<Vec<String>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl FileIndex {
+    /// Start listing the files under `root`
+    pub fn spawn(root: PathBuf, filter: ExplorerFilter) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let stop = Arc::clone(&cancelled);
+        let walk_root = root.clone();
+        thread::spawn(move || {
+            let mut batch = Vec::with_capacity(BATCH);
+            let mut count = 0;
+            for entry in walker(&walk_root, filter).build().flatten() {
+                if stop.load(Ordering::Relaxed) || count >= MAX_FILES {
+                    break;
+                }
+                let Some(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() || !is_listed(entry.path(), false, filter) {
+                    continue;
+                }
+                let Ok(relative) = entry.path().strip_prefix(&walk_root) else {
+                    continue;
+                };
+                batch.push(relative.to_string_lossy().replace('\\', "/"));
+                count += 1;
+                if batch.len() == BATCH && sender.send(std::mem::take(&mut batch)).is_err() {
+                    return;
+                }
+            }
+            if !batch.is_empty() {
+                let _ = sender.send(batch);
+            }
+        });
+        Self { root, files: Vec::new(), done: false, receiver, cancelled }
+    }
+
+    /// Take in the paths found since the last call; true if there were any
+    pub fn poll(&mut self) -> bool {
+        let before = self.files.len();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(batch) => self.files.extend(batch),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.done = true;
+                    break;
+                }
+            }
+        }
+        self.files.len() > before
+    }
+}
+
+impl Drop for FileIndex {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,95 @@
+//! Fuzzy matching of file paths for the Ctrl+p finder and of action titles for the
+//! command palette.
+//!
+//! The query's characters must appear in order (case-insensitive). Among all such
+//! placements the best scoring one wins: runs of consecutive characters and characters
+//! at the start of a path component or word score extra, gaps cost a little.
+
+/// Every matched character
+const MATCH: i64 = 16;
+/// The previous query character matched the previous path character
+const CONSECUTIVE: i64 = 12;
+/// Matched at the start of the path, a component (`/name`) or a word (`_`, `-`, `.`, ` `)
+const BOUNDARY: i64 = 10;
+
+/// Score of `query` against `candidate` and the char positions it matched, or None when
+/// the query's characters do not all appear in order. Whitespace in the query is ignored.
+pub fn score(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let original: Vec<char> = candidate.chars().collect();
+    let text: Vec<char> = original.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+    let (m, n) = (query.len(), text.len());
+    if m > n {
+        return None;
+    }
+
+    let bonus = |j: usize| -> i64 {
+        if j == 0 || matches!(original[j - 1], '/' | '\\' | '_' | '-' | '.' | ' ') {
+            BOUNDARY
+        } else {
+            0
+        }
+    };
+
+    // best[i][j]: best score with query[i] placed at text[j]; back[i][j]: where query[i - 1] went
+    let mut best = vec![vec![None::<i64>; n]; m];
+    let mut back = vec![vec![0usize; n]; m];
+    for j in 0..n {
+        if text[j] == query[0] {
+            best[0][j] = Some(MATCH + bonus(j));
+        }
+    }
+    for i in 1..m {
+        // Running best of best[i - 1][k] + k over k < j, so a gap of j - k - 1 costs that much
+        let mut running: Option<(i64, usize)> = None;
+        for j in 1..n {
+            if let Some(prev) = best[i - 1][j - 1] {
+                let candidate = prev + (j - 1) as i64;
+                if running.is_none_or(|(value, _)| candidate > value) {
+                    running = Some((candidate, j - 1));
+                }
+            }
+            if text[j] != query[i] {
+                continue;
+            }
+            let gapped = running.map(|(value, k)| (value - (j - 1) as i64, k));
+            let consecutive = best[i - 1][j - 1].map(|prev| (prev + CONSECUTIVE, j - 1));
+            let from = match (gapped, consecutive) {
+                (Some(a), Some(b)) => Some(if b.0 >= a.0 { b } else { a }),
+                (a, b) => a.or(b),
+            };
+            if let Some((value, k)) = from {
+                best[i][j] = Some(value + MATCH + bonus(j));
+                back[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, total) = (0..n).filter_map(|j| Some((j, best[m - 1][j]?))).max_by_key(|&(j, value)| (value, std::cmp::Reverse(j)))?;
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = back[i][j];
+        }
+    }
+    Some((total, positions))
+}
+
+/// Candidates matching `query`, best first (shorter paths win ties), with matched positions
+pub fn rank(query: &str, candidates: &[String]) -> Vec<(usize, Vec<usize>)> {
+    let mut matches: Vec<(i64, usize, Vec<usize>)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| score(query, candidate).map(|(score, positions)| (score, index, positions)))
+        .collect();
+    matches.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| candidates[a.1].len().cmp(&candidates[b.1].len()))
+            .then_with(|| candidates[a.1].cmp(&candidates[b.1]))
+    });
+    matches.into_iter().map(|(_, index, positions)| (index, positions)).collect()
+}
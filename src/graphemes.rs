@@ -0,0 +1,36 @@
+//! Grapheme clusters for cursor math and display widths.
+//!
+//! Cursors count chars, but an emoji with a skin tone, a flag or a letter with a
+//! combining accent is several chars shown as one. Cursors stay on cluster boundaries,
+//! so moving and deleting treat such a cluster as a single character.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Display columns of a cluster; control characters take none
+pub fn width(grapheme: &str) -> usize {
+    if grapheme.chars().all(char::is_control) { 0 } else { grapheme.width() }
+}
+
+/// Char positions where clusters end, in order
+fn ends(text: &str) -> impl Iterator<Item = usize> + '_ {
+    text.graphemes(true).scan(0, |pos, grapheme| {
+        *pos += grapheme.chars().count();
+        Some(*pos)
+    })
+}
+
+/// Char position of the cluster boundary before `pos` (0 at the start)
+pub fn prev_boundary(text: &str, pos: usize) -> usize {
+    ends(text).take_while(|end| *end < pos).last().unwrap_or(0)
+}
+
+/// Char position of the cluster boundary after `pos` (the char count at the end)
+pub fn next_boundary(text: &str, pos: usize) -> usize {
+    ends(text).find(|end| *end > pos).unwrap_or_else(|| text.chars().count())
+}
+
+/// `text` without the chars in `start..end`
+pub fn remove_chars(text: &str, start: usize, end: usize) -> String {
+    text.chars().take(start).chain(text.chars().skip(end)).collect()
+}
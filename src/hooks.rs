@@ -0,0 +1,185 @@
+//! Shell commands run at points in a document's life, configured in `~/.revwrc`:
+//!
+//! ```text
+//! hook post_save git add {file} && git commit -qm notes
+//! set hook_timeout=5
+//! ```
+//!
+//! `{file}` becomes the shell-quoted path and `{format}` becomes `md` or `json`;
+//! both are also passed to the command as `$1` and `$2`.
+
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a hook may run before it is killed, unless `set hook_timeout=N` says otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a failed hook's stderr is waited for once the hook itself has exited
+const STDERR_GRACE: Duration = Duration::from_millis(200);
+
+/// Points at which hooks run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookEvent {
+    /// Before the open file is written; a failing hook cancels the write
+    PreSave,
+    /// After the open file was written
+    PostSave,
+    /// After `revw --append` wrote entries into a file
+    PostImport,
+}
+
+impl HookEvent {
+    /// Parse the event name of a `hook EVENT COMMAND` line
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "pre_save" => Some(HookEvent::PreSave),
+            "post_save" => Some(HookEvent::PostSave),
+            "post_import" => Some(HookEvent::PostImport),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HookEvent::PreSave => "pre_save",
+            HookEvent::PostSave => "post_save",
+            HookEvent::PostImport => "post_import",
+        }
+    }
+}
+
+/// Configured hook commands, run in the order they were given
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hooks {
+    commands: Vec<(HookEvent, String)>,
+    pub timeout: Duration,
+}
+
+impl Default for Hooks {
+    fn default() -> Self {
+        Self { commands: Vec::new(), timeout: DEFAULT_TIMEOUT }
+    }
+}
+
+impl Hooks {
+    pub fn add(&mut self, event: HookEvent, command: &str) {
+        self.commands.push((event, command.to_string()));
+    }
+
+    /// Commands configured for `event`
+    pub fn commands(&self, event: HookEvent) -> impl Iterator<Item = &str> {
+        self.commands
+            .iter()
+            .filter(move |(e, _)| *e == event)
+            .map(|(_, command)| command.as_str())
+    }
+
+    /// Run the commands for `event` on `file`, stopping at the first that fails.
+    /// Returns how many ran.
+    pub fn run(&self, event: HookEvent, file: &Path, format: &str) -> Result<usize, String> {
+        let mut count = 0;
+        for command in self.commands(event) {
+            run_command(command, file, format, self.timeout)
+                .map_err(|e| format!("{} hook {}", event.name(), e))?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Run one hook command through the shell, killing it after `timeout`
+fn run_command(command: &str, file: &Path, format: &str, timeout: Duration) -> Result<(), String> {
+    let file = file.display().to_string();
+    let expanded = command.replace("{file}", &shell_quote(&file)).replace("{format}", format);
+
+    #[cfg(windows)]
+    let mut shell = {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C").arg(&expanded);
+        shell
+    };
+    #[cfg(not(windows))]
+    let mut shell = {
+        let mut shell = Command::new("sh");
+        shell.arg("-c").arg(&expanded).arg("revw").arg(&file).arg(format);
+        shell
+    };
+
+    // Its own process group, so a timeout also stops whatever the hook started
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut shell, 0);
+
+    let mut child = shell
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not start: {}", e))?;
+
+    // Read stderr on the side so a chatty hook cannot fill the pipe and stall. Lines are
+    // sent as they come: a background process of the hook may keep the pipe open.
+    let (sender, lines) = mpsc::channel();
+    if let Some(pipe) = child.stderr.take() {
+        thread::spawn(move || {
+            for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                kill_group(&mut child);
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", timeout.as_secs()));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(10)),
+            Err(e) => return Err(format!("failed: {}", e)),
+        }
+    };
+    if status.success() {
+        return Ok(());
+    }
+
+    // Whatever stderr the hook wrote, waiting briefly for lines still in the pipe
+    let mut stderr = Vec::new();
+    let deadline = Instant::now() + STDERR_GRACE;
+    while let Ok(line) = lines.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+        stderr.push(line);
+    }
+    let code = status.code().map(|code| code.to_string()).unwrap_or_else(|| "signal".to_string());
+    match stderr.iter().rev().map(|line| line.trim()).find(|line| !line.is_empty()) {
+        Some(line) => Err(format!("failed ({}): {}", code, line)),
+        None => Err(format!("failed ({})", code)),
+    }
+}
+
+/// Kill a timed-out hook with everything it started
+fn kill_group(child: &mut Child) {
+    #[cfg(unix)]
+    if let Ok(pid) = i32::try_from(child.id()) {
+        // SAFETY: signals the process group the hook leads, created by `process_group(0)`
+        unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        }
+    }
+    let _ = child.kill();
+}
+
+/// Quote `text` for the shell so paths with spaces or quotes stay one word
+fn shell_quote(text: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", text)
+    } else {
+        format!("'{}'", text.replace('\'', r"'\''"))
+    }
+}
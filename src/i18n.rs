@@ -0,0 +1,329 @@
+//! Message catalogs for help headings, overlay titles and the common status messages.
+//! The help text under the headings and the other status messages are English literals
+//! at their call sites.
+//!
+//! Call sites name a message by its [`Msg`] id. `text` looks it up in the catalog of the
+//! chosen language and falls back to the English text, so messages nobody translated yet
+//! still show. A message with `{}` placeholders is filled by `format`, its arguments
+//! going into the translation in the same order.
+
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Lang {
+    /// Follow `LC_ALL` / `LC_MESSAGES` / `LANG`; English until resolved
+    #[default]
+    Auto,
+    En,
+    Ja,
+}
+
+impl Lang {
+    /// `en`, `ja` or `auto`; locale names such as `ja_JP.UTF-8` work too
+    pub fn by_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        if name == "auto" {
+            return Some(Lang::Auto);
+        }
+        match name.split(['_', '.', '-', '@']).next().unwrap_or("") {
+            "en" | "c" | "posix" => Some(Lang::En),
+            "ja" => Some(Lang::Ja),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::Auto => "auto",
+            Lang::En => "en",
+            Lang::Ja => "ja",
+        }
+    }
+
+    /// The language of the first locale variable that is set; English for unknown locales
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Lang::by_name(&value))
+            .filter(|lang| *lang != Lang::Auto)
+            .unwrap_or(Lang::En)
+    }
+
+    /// `Auto` resolved against the environment; other languages as they are
+    pub fn resolve(self) -> Self {
+        if self == Lang::Auto { Lang::from_env() } else { self }
+    }
+
+    fn catalog(self) -> &'static [(Msg, &'static str)] {
+        match self {
+            Lang::Auto | Lang::En => &[],
+            Lang::Ja => JA,
+        }
+    }
+}
+
+/// A translatable message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    // Help headings
+    CliUsage,
+    ViewModeControls,
+    EditModeControls,
+    Navigation,
+    Editing,
+    CopyPaste,
+    EntryOperations,
+    VisualMode,
+    FilterHeading,
+    Settings,
+    ExplorerCommands,
+    Other,
+    VisualSelection,
+    Search,
+    Commands,
+    OutlinePanel,
+    MatchList,
+    SnapshotBrowser,
+    RecentFiles,
+    CrashRecovery,
+    FileFinder,
+    CommandPalette,
+    Substitute,
+    Tagline,
+    // Overlay titles and key contexts
+    OutlineTitle,
+    LinksTitle,
+    RecentTitle,
+    FinderTitle,
+    PaletteTitle,
+    MatchesTitle,
+    KeysTitle,
+    TagsTitle,
+    LogTitle,
+    TagColumn,
+    EntriesColumn,
+    AverageColumn,
+    ViewKeys,
+    VisualKeys,
+    FocusKeys,
+    EditKeys,
+    SelectionKeys,
+    OverlayKeys,
+    OverlayFieldKeys,
+    // Status messages
+    Insert,
+    Undo,
+    Redo,
+    EntryDuplicated,
+    Copied,
+    NothingToCopy,
+    NoEntrySelected,
+    NotInCardView,
+    NoFileOpen,
+    FilterViewOnly,
+    SavedNothing,
+    Saved,
+    NotSaved,
+    SaveError,
+    PatternNotFound,
+    NoMatches,
+    MatchPosition,
+    FilterCount,
+    ClipboardError,
+    InvalidJson,
+    Error,
+    UnsavedChanges,
+    ChangedOnDisk,
+    LangSet,
+}
+
+impl Msg {
+    /// Every message, for checking the catalogs
+    #[allow(dead_code)] // library API; only tests walk the catalogs
+    pub const ALL: &'static [Msg] = &[
+        Msg::CliUsage, Msg::ViewModeControls, Msg::EditModeControls, Msg::Navigation, Msg::Editing,
+        Msg::CopyPaste, Msg::EntryOperations, Msg::VisualMode, Msg::FilterHeading, Msg::Settings,
+        Msg::ExplorerCommands, Msg::Other, Msg::VisualSelection, Msg::Search, Msg::Commands,
+        Msg::OutlinePanel, Msg::MatchList, Msg::SnapshotBrowser, Msg::RecentFiles,
+        Msg::CrashRecovery, Msg::FileFinder, Msg::CommandPalette, Msg::Substitute, Msg::Tagline,
+        Msg::OutlineTitle, Msg::LinksTitle, Msg::RecentTitle, Msg::FinderTitle, Msg::PaletteTitle,
+        Msg::MatchesTitle, Msg::KeysTitle, Msg::TagsTitle, Msg::LogTitle, Msg::TagColumn,
+        Msg::EntriesColumn, Msg::AverageColumn, Msg::ViewKeys, Msg::VisualKeys, Msg::FocusKeys,
+        Msg::EditKeys, Msg::SelectionKeys, Msg::OverlayKeys, Msg::OverlayFieldKeys, Msg::Insert,
+        Msg::Undo, Msg::Redo, Msg::EntryDuplicated, Msg::Copied, Msg::NothingToCopy,
+        Msg::NoEntrySelected, Msg::NotInCardView, Msg::NoFileOpen, Msg::FilterViewOnly,
+        Msg::SavedNothing, Msg::Saved, Msg::NotSaved, Msg::SaveError, Msg::PatternNotFound,
+        Msg::NoMatches, Msg::MatchPosition, Msg::FilterCount, Msg::ClipboardError, Msg::InvalidJson,
+        Msg::Error, Msg::UnsavedChanges, Msg::ChangedOnDisk, Msg::LangSet,
+    ];
+
+    /// The English text; `{}` marks where an argument goes
+    pub fn english(self) -> &'static str {
+        match self {
+            Msg::CliUsage => "CLI USAGE",
+            Msg::ViewModeControls => "VIEW MODE CONTROLS",
+            Msg::EditModeControls => "EDIT MODE CONTROLS",
+            Msg::Navigation => "Navigation:",
+            Msg::Editing => "Editing:",
+            Msg::CopyPaste => "Copy/Paste:",
+            Msg::EntryOperations => "Entry Operations:",
+            Msg::VisualMode => "Visual Mode (multi-card selection):",
+            Msg::FilterHeading => "Filter (View mode only):",
+            Msg::Settings => "Settings:",
+            Msg::ExplorerCommands => "File Explorer Commands (when explorer has focus):",
+            Msg::Other => "Other:",
+            Msg::VisualSelection => "Visual Selection:",
+            Msg::Search => "Search:",
+            Msg::Commands => "Commands:",
+            Msg::OutlinePanel => "Outline Panel (when focused):",
+            Msg::MatchList => "Match List (:matches, when focused):",
+            Msg::SnapshotBrowser => "Snapshot Browser (:snapshots):",
+            Msg::RecentFiles => "Recent Files (:recent):",
+            Msg::CrashRecovery => "Crash Recovery:",
+            Msg::FileFinder => "File Finder (Ctrl+p, :find):",
+            Msg::CommandPalette => "Command Palette (Space, Ctrl+k):",
+            Msg::Substitute => "Substitute:",
+            Msg::Tagline => "  A vim-like TUI for managing notes and resources",
+            Msg::OutlineTitle => " Outline ",
+            Msg::LinksTitle => " Links (letter copies, Shift+letter opens) ",
+            Msg::RecentTitle => " Recent files ({}) (Enter: open, Esc: close) ",
+            Msg::FinderTitle => " Find file ({}/{}{}) ",
+            Msg::PaletteTitle => " Command palette ({}/{}) ",
+            Msg::MatchesTitle => " Matches: '{}' ({}) ",
+            Msg::KeysTitle => " Keys: {} (any key closes) ",
+            Msg::TagsTitle => " Tags (any key closes) ",
+            Msg::LogTitle => " Log (any key closes) ",
+            Msg::TagColumn => "TAG",
+            Msg::EntriesColumn => "ENTRIES",
+            Msg::AverageColumn => "AVG %",
+            Msg::ViewKeys => "View mode",
+            Msg::VisualKeys => "Visual mode (cards)",
+            Msg::FocusKeys => "Focus mode (one card)",
+            Msg::EditKeys => "Edit mode",
+            Msg::SelectionKeys => "Visual selection (Edit mode)",
+            Msg::OverlayKeys => "Edit overlay: fields",
+            Msg::OverlayFieldKeys => "Edit overlay: field Normal mode",
+            Msg::Insert => "-- INSERT --",
+            Msg::Undo => "Undo",
+            Msg::Redo => "Redo",
+            Msg::EntryDuplicated => "Entry duplicated",
+            Msg::Copied => "Copied to clipboard",
+            Msg::NothingToCopy => "Nothing to copy",
+            Msg::NoEntrySelected => "No entry selected",
+            Msg::NotInCardView => "Not in card view mode",
+            Msg::NoFileOpen => "Error: No file open",
+            Msg::FilterViewOnly => "Filter only works in View mode",
+            Msg::SavedNothing => "Saved: no file changed",
+            Msg::Saved => "Saved: {}",
+            Msg::NotSaved => "Not saved: {}",
+            Msg::SaveError => "Error saving: {}",
+            Msg::PatternNotFound => "Pattern not found: {}",
+            Msg::NoMatches => "No matches for '{}'",
+            Msg::MatchPosition => "match {}/{} for '{}'",
+            Msg::FilterCount => "Filter: {} ({} entries)",
+            Msg::ClipboardError => "Clipboard error: {}",
+            Msg::InvalidJson => "Invalid JSON: {}",
+            Msg::Error => "Error: {}",
+            Msg::UnsavedChanges => "Unsaved changes (:reload! discards them, :diff compares)",
+            Msg::ChangedOnDisk => "File changed on disk since it was loaded: (r)eload, (o)verwrite, (m)erge (an entry edited on both sides is kept twice), (c)ancel?",
+            Msg::LangSet => "Language set to {}",
+        }
+    }
+}
+
+/// Whether `lang`'s catalog translates `msg`
+#[allow(dead_code)] // library API; only tests walk the catalogs
+pub fn has_translation(lang: Lang, msg: Msg) -> bool {
+    lang.catalog().iter().any(|(id, _)| *id == msg)
+}
+
+/// `msg` in `lang`, in English when the catalog has no entry for it
+pub fn text(lang: Lang, msg: Msg) -> &'static str {
+    lang.catalog().iter().find(|(id, _)| *id == msg).map_or(msg.english(), |(_, translated)| translated)
+}
+
+/// `msg` in `lang` with its `{}` placeholders filled by `args`, in order
+pub fn format(lang: Lang, msg: Msg, args: &[&dyn Display]) -> String {
+    let mut pieces = text(lang, msg).split("{}");
+    let mut out = pieces.next().unwrap_or("").to_string();
+    for (piece, arg) in pieces.zip(args.iter().map(|arg| arg.to_string()).chain(std::iter::repeat(String::new()))) {
+        out.push_str(&arg);
+        out.push_str(piece);
+    }
+    out
+}
+
+const JA: &[(Msg, &str)] = &[
+    // Help headings
+    (Msg::CliUsage, "コマンドラインでの使い方"),
+    (Msg::ViewModeControls, "View モードの操作"),
+    (Msg::EditModeControls, "Edit モードの操作"),
+    (Msg::Navigation, "移動:"),
+    (Msg::Editing, "編集:"),
+    (Msg::CopyPaste, "コピー/貼り付け:"),
+    (Msg::EntryOperations, "エントリ操作:"),
+    (Msg::VisualMode, "Visual モード (複数カードの選択):"),
+    (Msg::FilterHeading, "フィルタ (View モードのみ):"),
+    (Msg::Settings, "設定:"),
+    (Msg::ExplorerCommands, "ファイルエクスプローラ (フォーカス時):"),
+    (Msg::Other, "その他:"),
+    (Msg::VisualSelection, "Visual 選択:"),
+    (Msg::Search, "検索:"),
+    (Msg::Commands, "コマンド:"),
+    (Msg::OutlinePanel, "アウトライン (フォーカス時):"),
+    (Msg::MatchList, "マッチ一覧 (:matches、フォーカス時):"),
+    (Msg::SnapshotBrowser, "スナップショット (:snapshots):"),
+    (Msg::RecentFiles, "最近のファイル (:recent):"),
+    (Msg::CrashRecovery, "クラッシュからの復旧:"),
+    (Msg::FileFinder, "ファイル検索 (Ctrl+p, :find):"),
+    (Msg::CommandPalette, "コマンドパレット (Space, Ctrl+k):"),
+    (Msg::Substitute, "置換:"),
+    (Msg::Tagline, "  ノートと資料を管理する vim 風 TUI"),
+    // Overlay titles and key contexts
+    (Msg::OutlineTitle, " アウトライン "),
+    (Msg::LinksTitle, " リンク (文字でコピー、Shift+文字で開く) "),
+    (Msg::RecentTitle, " 最近のファイル ({}) (Enter: 開く, Esc: 閉じる) "),
+    (Msg::FinderTitle, " ファイル検索 ({}/{}{}) "),
+    (Msg::PaletteTitle, " コマンドパレット ({}/{}) "),
+    (Msg::MatchesTitle, " マッチ: '{}' ({}) "),
+    (Msg::KeysTitle, " キー: {} (任意のキーで閉じる) "),
+    (Msg::TagsTitle, " タグ (任意のキーで閉じる) "),
+    (Msg::LogTitle, " ログ (任意のキーで閉じる) "),
+    (Msg::TagColumn, "タグ"),
+    (Msg::EntriesColumn, "件数"),
+    (Msg::AverageColumn, "平均 %"),
+    (Msg::ViewKeys, "View モード"),
+    (Msg::VisualKeys, "Visual モード (カード)"),
+    (Msg::FocusKeys, "Focus モード (1 枚のカード)"),
+    (Msg::EditKeys, "Edit モード"),
+    (Msg::SelectionKeys, "Visual 選択 (Edit モード)"),
+    (Msg::OverlayKeys, "編集オーバーレイ: フィールド"),
+    (Msg::OverlayFieldKeys, "編集オーバーレイ: フィールドの Normal モード"),
+    // Status messages
+    (Msg::Insert, "-- 挿入 --"),
+    (Msg::Undo, "元に戻しました"),
+    (Msg::Redo, "やり直しました"),
+    (Msg::EntryDuplicated, "エントリを複製しました"),
+    (Msg::Copied, "クリップボードにコピーしました"),
+    (Msg::NothingToCopy, "コピーするものがありません"),
+    (Msg::NoEntrySelected, "エントリが選択されていません"),
+    (Msg::NotInCardView, "カード表示ではありません"),
+    (Msg::NoFileOpen, "エラー: ファイルが開かれていません"),
+    (Msg::FilterViewOnly, "フィルタは View モードでのみ使えます"),
+    (Msg::SavedNothing, "保存: 変更されたファイルはありません"),
+    (Msg::Saved, "保存しました: {}"),
+    (Msg::NotSaved, "保存していません: {}"),
+    (Msg::SaveError, "保存エラー: {}"),
+    (Msg::PatternNotFound, "見つかりません: {}"),
+    (Msg::NoMatches, "'{}' に一致するものはありません"),
+    (Msg::MatchPosition, "一致 {}/{} ('{}')"),
+    (Msg::FilterCount, "フィルタ: {} ({} 件)"),
+    (Msg::ClipboardError, "クリップボードのエラー: {}"),
+    (Msg::InvalidJson, "不正な JSON: {}"),
+    (Msg::Error, "エラー: {}"),
+    (Msg::UnsavedChanges, "未保存の変更があります (:reload! で破棄、:diff で比較)"),
+    (Msg::ChangedOnDisk, "読み込み後にファイルが変更されました: (r)再読込, (o)上書き, (m)マージ(両方で編集したエントリは両方残ります), (c)キャンセル?"),
+    (Msg::LangSet, "言語を {} に設定しました"),
+];
@@ -0,0 +1,403 @@
+//! Importers for browser bookmark exports, used by `revw --append`, and for folders of
+//! daily notes, used by `revw --import-obsidian`.
+//!
+//! Chrome/Firefox HTML exports (the Netscape bookmark format), Chrome's `Bookmarks`
+//! JSON file and Firefox JSON backups are recognised by content. Each bookmark becomes
+//! an OUTSIDE entry; `--map SOURCE=FIELD` decides where its title, URL and folder go.
+//!
+//! Daily notes are one Markdown file per day with the date in the file name
+//! (`2025-03-02.md`, `2025_03_02 Sunday.md`, `20250302.md`), as Obsidian writes them.
+//! Each becomes an INSIDE entry dated that day with the note's body as its context.
+
+use chrono::NaiveDate;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::fields;
+use crate::line_endings;
+
+/// Bookmark export formats that `detect` recognises
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BookmarkFormat {
+    /// Netscape bookmark file, exported as HTML by Chrome and Firefox
+    Html,
+    /// Chrome's `Bookmarks` JSON file (`roots` with `bookmark_bar`, `other`, ...)
+    ChromeJson,
+    /// Firefox bookmark backup (`text/x-moz-place-container` tree)
+    FirefoxJson,
+}
+
+/// One bookmark: title, URL and the folders it sits in (outermost first)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bookmark {
+    pub title: String,
+    pub url: String,
+    pub folders: Vec<String>,
+}
+
+/// What each part of a bookmark is written to, from `--map SOURCE=FIELD` options.
+/// By default the title goes to `name`, the URL to `url` and the folder is dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookmarkMapping {
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub folder: Option<String>,
+}
+
+impl Default for BookmarkMapping {
+    fn default() -> Self {
+        Self {
+            title: Some("name".to_string()),
+            url: Some("url".to_string()),
+            folder: None,
+        }
+    }
+}
+
+impl BookmarkMapping {
+    /// Apply `--map` options such as `folder=tag` or `title=context`.
+    /// FIELD is `name`, `context`, `url`, `tag` (adds to `tags`), `none`, or a custom field name.
+    pub fn from_specs<'a>(specs: impl IntoIterator<Item = &'a str>) -> Result<Self, String> {
+        let mut mapping = Self::default();
+        for spec in specs {
+            let (source, field) = spec
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid mapping '{}': expected SOURCE=FIELD", spec))?;
+            let field = match field.trim() {
+                "none" => None,
+                "tag" | "tags" => Some("tags".to_string()),
+                field @ ("name" | "context" | "url") => Some(field.to_string()),
+                field if fields::is_custom_key(field) => Some(field.to_string()),
+                field => return Err(format!("Cannot map {} to '{}'", source.trim(), field)),
+            };
+            match source.trim() {
+                "title" => mapping.title = field,
+                "url" => mapping.url = field,
+                "folder" => mapping.folder = field,
+                source => return Err(format!("Unknown mapping source '{}': use title, url or folder", source)),
+            }
+        }
+        Ok(mapping)
+    }
+}
+
+/// Recognise a bookmark export; `None` for anything else (e.g. revw JSON or Markdown)
+pub fn detect(content: &str) -> Option<BookmarkFormat> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('{') {
+        let value: Value = serde_json::from_str(trimmed).ok()?;
+        if value.get("roots").is_some_and(|roots| roots.is_object()) {
+            return Some(BookmarkFormat::ChromeJson);
+        }
+        if value.get("type").and_then(|t| t.as_str()) == Some("text/x-moz-place-container") {
+            return Some(BookmarkFormat::FirefoxJson);
+        }
+        return None;
+    }
+    let head: String = trimmed.chars().take(512).collect::<String>().to_ascii_uppercase();
+    if head.contains("NETSCAPE-BOOKMARK-FILE") || (head.starts_with('<') && head.contains("<DL")) {
+        return Some(BookmarkFormat::Html);
+    }
+    None
+}
+
+/// Bookmarks of an export in `format`, in file order. Separators, folders and
+/// non-web URLs (`place:`, `javascript:`, ...) are skipped.
+pub fn parse(content: &str, format: BookmarkFormat) -> Result<Vec<Bookmark>, String> {
+    let mut bookmarks = match format {
+        BookmarkFormat::Html => parse_html(content),
+        BookmarkFormat::ChromeJson | BookmarkFormat::FirefoxJson => {
+            let value: Value = serde_json::from_str(content).map_err(|e| format!("Invalid bookmark JSON: {}", e))?;
+            let mut bookmarks = Vec::new();
+            if format == BookmarkFormat::ChromeJson {
+                // Root folders (Bookmarks bar, Other bookmarks, ...) add no folder name
+                let roots = value.get("roots").and_then(|v| v.as_object()).into_iter().flat_map(|roots| roots.values());
+                for child in roots.filter_map(|root| root.get("children")?.as_array()).flatten() {
+                    collect_chrome(child, &mut Vec::new(), &mut bookmarks);
+                }
+            } else {
+                collect_firefox(&value, &mut Vec::new(), &mut bookmarks);
+            }
+            bookmarks
+        }
+    };
+    bookmarks.retain(|b| is_web_url(&b.url));
+    Ok(bookmarks)
+}
+
+/// `{"outside": [...]}` with one entry per bookmark, ready for `JsonOperations::append_entries_with`
+pub fn bookmarks_to_entries(bookmarks: &[Bookmark], mapping: &BookmarkMapping) -> Value {
+    let outside: Vec<Value> = bookmarks
+        .iter()
+        .map(|bookmark| {
+            let mut entry = Map::new();
+            entry.insert("name".to_string(), json!(""));
+            entry.insert("context".to_string(), json!(""));
+            entry.insert("url".to_string(), json!(""));
+            entry.insert("percentage".to_string(), Value::Null);
+            let folder = bookmark.folders.join("/");
+            for (field, text) in [(&mapping.title, &bookmark.title), (&mapping.url, &bookmark.url), (&mapping.folder, &folder)] {
+                let Some(field) = field else { continue };
+                if text.is_empty() {
+                    continue;
+                }
+                if field == "tags" {
+                    let tags = entry.entry("tags").or_insert_with(|| json!([]));
+                    if let Some(tags) = tags.as_array_mut() {
+                        tags.push(json!(text));
+                    }
+                } else if field == "context" && entry.get("context").and_then(|v| v.as_str()).is_some_and(|c| !c.is_empty()) {
+                    // Several sources mapped to the context: one per line
+                    let context = format!("{}\n{}", entry["context"].as_str().unwrap_or(""), text);
+                    entry.insert("context".to_string(), json!(context));
+                } else {
+                    entry.insert(field.clone(), json!(text));
+                }
+            }
+            Value::Object(entry)
+        })
+        .collect();
+    json!({ "outside": outside })
+}
+
+fn is_web_url(url: &str) -> bool {
+    let lower = url.trim().to_ascii_lowercase();
+    ["http://", "https://", "ftp://", "file://"].iter().any(|scheme| lower.starts_with(scheme))
+}
+
+/// Netscape bookmark file: `<DT><H3>Folder</H3><DL>...</DL>` nests, `<DT><A HREF="...">Title</A>` is a bookmark
+fn parse_html(content: &str) -> Vec<Bookmark> {
+    let token = Regex::new(r"(?is)<h3([^>]*)>(.*?)</h3>|<a\s([^>]*)>(.*?)</a>|<dl\b[^>]*>|</dl\s*>").unwrap();
+    let href = Regex::new(r#"(?is)\bhref\s*=\s*"([^"]*)""#).unwrap();
+    let tag = Regex::new(r"(?s)<[^>]*>").unwrap();
+
+    let mut bookmarks = Vec::new();
+    // Open folders; `None` for lists that add no folder name (the top list, browser root folders)
+    let mut stack: Vec<Option<String>> = Vec::new();
+    let mut pending: Option<Option<String>> = None;
+    for caps in token.captures_iter(content) {
+        let text = |i: usize| decode_entities(tag.replace_all(&caps[i], "").trim());
+        if let Some(attrs) = caps.get(1) {
+            let attrs = attrs.as_str().to_ascii_uppercase();
+            let is_root = attrs.contains("PERSONAL_TOOLBAR_FOLDER") || attrs.contains("UNFILED_BOOKMARKS_FOLDER");
+            pending = Some(if is_root { None } else { Some(text(2)) });
+        } else if let Some(attrs) = caps.get(3) {
+            if let Some(url) = href.captures(attrs.as_str()) {
+                bookmarks.push(Bookmark {
+                    title: text(4),
+                    url: decode_entities(url[1].trim()),
+                    folders: stack.iter().flatten().cloned().collect(),
+                });
+            }
+        } else if caps[0].starts_with("</") {
+            stack.pop();
+        } else {
+            stack.push(pending.take().flatten());
+        }
+    }
+    bookmarks
+}
+
+fn collect_chrome(node: &Value, folders: &mut Vec<String>, out: &mut Vec<Bookmark>) {
+    let name = node.get("name").and_then(|v| v.as_str()).unwrap_or("");
+    match node.get("type").and_then(|v| v.as_str()) {
+        Some("url") => out.push(Bookmark {
+            title: name.to_string(),
+            url: node.get("url").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            folders: folders.clone(),
+        }),
+        Some("folder") => {
+            folders.push(name.to_string());
+            for child in node.get("children").and_then(|v| v.as_array()).into_iter().flatten() {
+                collect_chrome(child, folders, out);
+            }
+            folders.pop();
+        }
+        _ => {}
+    }
+}
+
+fn collect_firefox(node: &Value, folders: &mut Vec<String>, out: &mut Vec<Bookmark>) {
+    let title = node.get("title").and_then(|v| v.as_str()).unwrap_or("");
+    match node.get("type").and_then(|v| v.as_str()) {
+        Some("text/x-moz-place") => {
+            if let Some(uri) = node.get("uri").and_then(|v| v.as_str()) {
+                out.push(Bookmark {
+                    title: title.to_string(),
+                    url: uri.to_string(),
+                    folders: folders.clone(),
+                });
+            }
+        }
+        Some("text/x-moz-place-container") => {
+            // Built-in containers (menu, toolbar, unfiled, mobile) carry a `root` key
+            let named = node.get("root").is_none();
+            if named {
+                folders.push(title.to_string());
+            }
+            for child in node.get("children").and_then(|v| v.as_array()).into_iter().flatten() {
+                collect_firefox(child, folders, out);
+            }
+            if named {
+                folders.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Decode the HTML entities browsers write in bookmark titles and URLs
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// One daily note: the day from its file name and its body
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyNote {
+    pub date: NaiveDate,
+    pub body: String,
+}
+
+/// Notes found by `read_daily_notes`, newest first, and the Markdown files without a date in their name
+#[derive(Debug, Default)]
+pub struct DailyNotes {
+    pub notes: Vec<DailyNote>,
+    pub skipped: Vec<PathBuf>,
+}
+
+/// The first `YYYY-MM-DD` (also `_`, `.` or no separator) in a file name that is a real date
+pub fn date_from_filename(name: &str) -> Option<NaiveDate> {
+    let re = Regex::new(r"(\d{4})[-_.]?(\d{2})[-_.]?(\d{2})").ok()?;
+    re.captures_iter(name).find_map(|caps| {
+        NaiveDate::from_ymd_opt(caps[1].parse().ok()?, caps[2].parse().ok()?, caps[3].parse().ok()?)
+    })
+}
+
+/// Read every `.md` file under `dir` (subfolders included, hidden ones such as `.obsidian`
+/// skipped). Empty notes are left out; notes for the same day are joined in file name order.
+pub fn read_daily_notes(dir: &Path) -> Result<DailyNotes, String> {
+    let mut files = Vec::new();
+    collect_markdown_files(dir, &mut files)?;
+    files.sort();
+
+    let mut result = DailyNotes::default();
+    for path in files {
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        let Some(date) = date_from_filename(&name) else {
+            result.skipped.push(path);
+            continue;
+        };
+        let content = fs::read_to_string(&path).map_err(|e| format!("Cannot read '{}': {}", path.display(), e))?;
+        let (content, _) = line_endings::normalize(&content);
+        let body = note_body(&content);
+        if body.is_empty() {
+            continue;
+        }
+        match result.notes.iter_mut().find(|note| note.date == date) {
+            Some(note) => note.body = format!("{}\n\n{}", note.body, body),
+            None => result.notes.push(DailyNote { date, body }),
+        }
+    }
+    result.notes.sort_by_key(|note| std::cmp::Reverse(note.date));
+    Ok(result)
+}
+
+/// `{"inside": [...]}` with one entry per note, leaving out days `current` already has an
+/// INSIDE entry for (by its date, as this importer writes it). Returns the entries and how
+/// many notes were left out.
+pub fn daily_notes_to_entries(notes: &[DailyNote], current: &Value) -> (Value, usize) {
+    let existing: Vec<&str> = current
+        .get("inside")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("date").and_then(|v| v.as_str()))
+        .collect();
+    let mut duplicates = 0;
+    let inside: Vec<Value> = notes
+        .iter()
+        .filter_map(|note| {
+            let date = note.date.format("%Y-%m-%d 00:00:00").to_string();
+            if existing.contains(&date.as_str()) {
+                duplicates += 1;
+                return None;
+            }
+            Some(json!({ "date": date, "context": note.body }))
+        })
+        .collect();
+    (json!({ "inside": inside }), duplicates)
+}
+
+fn collect_markdown_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Cannot read '{}': {}", dir.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_markdown_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// A note's text without YAML front matter. Headings up to `###` become `####` so they
+/// stay part of the context instead of starting a revw section or entry.
+fn note_body(content: &str) -> String {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut lines: Vec<&str> = content.lines().collect();
+    if lines.first().is_some_and(|line| line.trim_end() == "---")
+        && let Some(end) = lines.iter().skip(1).position(|line| line.trim_end() == "---") {
+        lines.drain(..end + 2);
+    }
+    let body: Vec<String> = lines
+        .iter()
+        .map(|line| {
+            let level = line.chars().take_while(|&c| c == '#').count();
+            match line[level..].strip_prefix(' ') {
+                Some(title) if (1..=3).contains(&level) => format!("#### {}", title),
+                _ => line.to_string(),
+            }
+        })
+        .collect();
+    body.join("\n").trim().to_string()
+}
@@ -19,7 +19,10 @@ pub fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
             // Add to history before executing
             app.add_to_command_history(app.command_buffer.clone());
 
-            if app.execute_command() {
+            if app.editing_entry {
+                let cmd = app.command_buffer.clone();
+                app.execute_overlay_command(&cmd);
+            } else if app.execute_command() {
                 return Ok(true); // Quit the application
             }
             app.input_mode = crate::app::InputMode::Normal;
@@ -12,6 +12,21 @@ pub fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut ratatui::Terminal<B>,
     mut app: App,
 ) -> Result<()>
+where
+    <B as ratatui::backend::Backend>::Error: Send + Sync + 'static,
+{
+    let result = event_loop(terminal, &mut app);
+    // Changes autosave was still holding back are written before leaving
+    app.flush_autosave(true);
+    app.finish_hooks();
+    // Only a normal exit drops the swap file; after an error it is there to recover from
+    if result.is_ok() {
+        app.remove_swap();
+    }
+    result
+}
+
+fn event_loop<B: ratatui::backend::Backend>(terminal: &mut ratatui::Terminal<B>, app: &mut App) -> Result<()>
 where
     <B as ratatui::backend::Backend>::Error: Send + Sync + 'static,
 {
@@ -24,8 +39,8 @@ where
             }
         })?;
 
-    // Watch the file if it exists
-    if let Some(ref path) = app.file_path {
+    // Watch the file if it exists (not in safe mode)
+    if let Some(path) = app.file_path.as_ref().filter(|_| !app.safe_mode) {
         let _ = watcher.watch(path, RecursiveMode::NonRecursive);
     }
 
@@ -35,8 +50,28 @@ where
     }
 
     loop {
-        terminal.draw(|f| crate::ui::ui(f, &mut app))?;
+        app.poll_background_task();
+        app.poll_hook_task();
+        app.poll_finder_index();
+        terminal.draw(|f| crate::ui::ui(f, app))?;
         app.update_status();
+        app.flush_autosave(false);
+        app.update_swap();
+
+        // Paint the inline image preview outside of ratatui's buffer
+        if app.preview_request != app.preview_drawn {
+            if app.preview_drawn.take().is_some() {
+                // Full repaint to wipe the previous image
+                terminal.clear()?;
+                terminal.draw(|f| crate::ui::ui(f, app))?;
+            }
+            if let Some((path, area)) = app.preview_request.clone() {
+                if let Err(e) = crate::preview::draw_image(&path, area) {
+                    app.set_status(&e);
+                }
+                app.preview_drawn = Some((path, area));
+            }
+        }
 
         // Update watcher if file path or explorer directory changed
         if app.file_path_changed || app.explorer_dir_changed {
@@ -50,7 +85,7 @@ where
             })?;
 
             // Watch the new file
-            if let Some(ref path) = app.file_path {
+            if let Some(path) = app.file_path.as_ref().filter(|_| !app.safe_mode) {
                 let _ = watcher.watch(path, RecursiveMode::NonRecursive);
             }
 
@@ -69,18 +104,19 @@ where
         if app.auto_reload {
             match rx.try_recv() {
                 Ok(event) => {
+                    tracing::info!(kind = ?event.kind, paths = ?event.paths, "file watcher event");
                     // Check if it's a modify event for files
                     if matches!(event.kind, notify::EventKind::Modify(_)) {
-                        // Ignore file changes within 1 second after saving (to avoid reloading our own save)
-                        let should_reload = if let Some(last_save) = app.last_save_time {
-                            last_save.elapsed() > Duration::from_millis(1000)
-                        } else {
-                            true
-                        };
+                        // Ignore file changes right after saving (to avoid reloading our own save)
+                        let should_reload = !app.just_saved();
 
                         // Only reload if not modified by user and not recently saved
                         if !app.is_modified && should_reload && app.file_path.is_some() {
+                            tracing::info!("reloading after an outside change");
                             app.reload_file();
+                        } else if app.is_modified && should_reload {
+                            // Unsaved changes are kept; say so instead of skipping silently
+                            app.note_disk_change();
                         }
                     }
                     // Check for create/delete/modify events in explorer directory
@@ -94,7 +130,9 @@ where
             }
         }
 
-        if event::poll(Duration::from_millis(100))? {
+        // Redraw at about 60 fps while the card view glides to a new position
+        let wait = if app.scroll_animating { 16 } else { 100 };
+        if event::poll(Duration::from_millis(wait))? {
             match event::read()? {
                 Event::Key(key) => {
                     // Filter out key repeat events on Windows to prevent duplicate input
@@ -105,6 +143,13 @@ where
                     if key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('c') {
                         return Ok(());
                     }
+                    // While a long operation runs, Esc cancels it and other keys wait
+                    if app.background_task.is_some() {
+                        if key.code == KeyCode::Esc {
+                            app.cancel_background_task();
+                        }
+                        continue;
+                    }
                     if key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('r') {
                         app.redo();
                         continue;
@@ -113,108 +158,147 @@ where
                     // Handle Ctrl+w window commands
                     if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('w') {
                         // Wait for next key (1000ms timeout)
-                        loop {
-                            if let Ok(true) = event::poll(Duration::from_millis(1000)) {
-                                if let Ok(Event::Key(next_key)) = event::read() {
-                                    #[cfg(target_os = "windows")]
-                                    {
-                                        // Skip release events on Windows
-                                        if next_key.kind != KeyEventKind::Press {
-                                            continue;
-                                        }
+                        while let Ok(true) = event::poll(Duration::from_millis(1000)) {
+                            if let Ok(Event::Key(next_key)) = event::read() {
+                                #[cfg(target_os = "windows")]
+                                {
+                                    // Skip release events on Windows
+                                    if next_key.kind != KeyEventKind::Press {
+                                        continue;
                                     }
+                                }
 
-                                    match next_key.code {
-                                        KeyCode::Char('w') => {
-                                            // Ctrl+w w: cycle between windows (accept with or without Ctrl)
-                                            app.switch_window_focus();
-                                            let focus_msg = if app.explorer_has_focus {
-                                                "Focused explorer"
-                                            } else if app.outline_has_focus {
-                                                "Focused outline"
-                                            } else {
-                                                "Focused file window"
-                                            };
-                                            app.set_status(focus_msg);
-                                            break;
-                                        }
-                                        KeyCode::Char('h') => {
-                                            // Ctrl+w h: move to left window (explorer)
-                                            app.focus_explorer();
-                                            app.set_status("Focused explorer");
-                                            break;
-                                        }
-                                        KeyCode::Char('l') => {
-                                            // Ctrl+w l: move to right window (outline or file)
-                                            if app.outline_open {
-                                                app.focus_outline();
-                                                app.set_status("Focused outline");
-                                            } else {
-                                                app.focus_file();
-                                                app.set_status("Focused file window");
-                                            }
-                                            break;
-                                        }
-                                        KeyCode::Char('j') | KeyCode::Char('k') => {
-                                            // Ctrl+w j/k: move to center window (file content)
+                                match next_key.code {
+                                    KeyCode::Char('w') => {
+                                        // Ctrl+w w: cycle between windows (accept with or without Ctrl)
+                                        app.switch_window_focus();
+                                        let focus_msg = if app.explorer_has_focus {
+                                            "Focused explorer"
+                                        } else if app.outline_has_focus {
+                                            "Focused outline"
+                                        } else {
+                                            "Focused file window"
+                                        };
+                                        app.set_status(focus_msg);
+                                        break;
+                                    }
+                                    KeyCode::Char('h') => {
+                                        // Ctrl+w h: move to left window (explorer)
+                                        app.focus_explorer();
+                                        app.set_status("Focused explorer");
+                                        break;
+                                    }
+                                    KeyCode::Char('l') => {
+                                        // Ctrl+w l: move to right window (outline or file)
+                                        if app.outline_open {
+                                            app.focus_outline();
+                                            app.set_status("Focused outline");
+                                        } else {
                                             app.focus_file();
                                             app.set_status("Focused file window");
-                                            break;
-                                        }
-                                        _ => {
-                                            // Any other key - cancel
-                                            break;
                                         }
+                                        break;
+                                    }
+                                    KeyCode::Char('j') | KeyCode::Char('k') => {
+                                        // Ctrl+w j/k: move to center window (file content)
+                                        app.focus_file();
+                                        app.set_status("Focused file window");
+                                        break;
+                                    }
+                                    _ => {
+                                        // Any other key - cancel
+                                        break;
                                     }
                                 }
-                            } else {
-                                // Timeout
-                                break;
                             }
                         }
                         continue;
                     }
 
+                    // The :stats table closes on any key
+                    if app.tag_stats.is_some() {
+                        app.close_stats();
+                        continue;
+                    }
+
+                    // So does the :log view
+                    if app.log_lines.is_some() {
+                        app.close_log();
+                        continue;
+                    }
+
+                    // The cheat sheet closes on any key; F1 toggles it from anywhere
+                    if app.cheatsheet_open || key.code == KeyCode::F(1) {
+                        app.toggle_cheatsheet();
+                        continue;
+                    }
+
                     // Delegate to mode-specific handlers
                     use crate::app::InputMode;
 
                     // Handle Search mode globally (including in overlay)
                     if app.input_mode == InputMode::Search {
-                        super::search_mode::handle_search_mode(&mut app, key);
+                        super::search_mode::handle_search_mode(app, key);
+                        continue;
+                    }
+
+                    // The overlay's command line only runs :s on the field being edited
+                    if app.editing_entry && app.input_mode == InputMode::Command {
+                        super::command_mode::handle_command_mode(app, key)?;
                         continue;
                     }
 
                     // Handle editing overlay input separately
                     if app.editing_entry {
-                        super::overlay_mode::handle_overlay_keyboard(&mut app, key);
+                        super::overlay_mode::handle_overlay_keyboard(app, key);
                         continue;
                     }
 
                     match app.input_mode {
                         InputMode::Normal => {
-                            if super::normal_mode::handle_normal_mode(&mut app, key)? {
+                            if super::normal_mode::handle_normal_mode(app, key)? {
                                 return Ok(());
                             }
                         }
                         InputMode::Insert => {
-                            super::insert_mode::handle_insert_mode(&mut app, key);
+                            super::insert_mode::handle_insert_mode(app, key);
                         }
                         InputMode::Command => {
-                            if super::command_mode::handle_command_mode(&mut app, key)? {
+                            if super::command_mode::handle_command_mode(app, key)? {
                                 return Ok(());
                             }
                         }
                         InputMode::Search => {
-                            super::search_mode::handle_search_mode(&mut app, key);
+                            super::search_mode::handle_search_mode(app, key);
+                        }
+                        InputMode::Filter => {
+                            super::filter_mode::handle_filter_mode(app, key);
+                        }
+                        InputMode::Field => {
+                            super::field_mode::handle_field_mode(app, key);
                         }
                     }
+
+                    // Roll back changes to a journal's history before anything is written
+                    app.enforce_journal();
+
+                    // Write pending changes per the autosave policy once the key is handled
+                    if app.input_mode == InputMode::Normal {
+                        app.autosave();
+                    }
                 }
+                Event::Mouse(_) if app.background_task.is_some() => {}
                 Event::Mouse(mouse) => {
-                    super::mouse::handle_mouse_event(&mut app, mouse, terminal)?;
+                    super::mouse::handle_mouse_event(app, mouse, terminal)?;
+                    app.enforce_journal();
                 }
                 Event::Paste(_) => {
                     // Paste events not supported - use 'v' key instead
                 }
+                Event::Resize(_, _) => {
+                    // Terminal is cleared on resize, so the preview must be repainted
+                    app.preview_drawn = None;
+                }
                 _ => {}
             }
         }
@@ -0,0 +1,23 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+pub fn handle_field_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.cancel_field_edit();
+        }
+        KeyCode::Enter => {
+            app.accept_field_edit();
+        }
+        KeyCode::Char(c) => {
+            app.field_edit_buffer.push(c);
+            app.show_field_edit_status();
+        }
+        KeyCode::Backspace => {
+            app.field_edit_buffer.pop();
+            app.show_field_edit_status();
+        }
+        _ => {}
+    }
+}
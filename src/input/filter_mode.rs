@@ -0,0 +1,25 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+
+pub fn handle_filter_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.cancel_live_filter();
+        }
+        KeyCode::Enter => {
+            app.accept_live_filter();
+        }
+        KeyCode::Char(c) => {
+            let mut pattern = app.filter_pattern.clone();
+            pattern.push(c);
+            app.update_live_filter(pattern);
+        }
+        KeyCode::Backspace => {
+            let mut pattern = app.filter_pattern.clone();
+            pattern.pop();
+            app.update_live_filter(pattern);
+        }
+        _ => {}
+    }
+}
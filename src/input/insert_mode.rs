@@ -8,6 +8,7 @@ pub fn handle_insert_mode(app: &mut App, key: KeyEvent) {
         && key.code == KeyCode::Char('[')
     {
         app.input_mode = crate::app::InputMode::Normal;
+        app.end_undo_group();
         app.set_status("");
         return;
     }
@@ -15,6 +16,7 @@ pub fn handle_insert_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Esc => {
             app.input_mode = crate::app::InputMode::Normal;
+            app.end_undo_group();
             app.set_status("");
         }
         KeyCode::Enter => {
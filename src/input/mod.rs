@@ -1,5 +1,7 @@
 mod command_mode;
 mod event_loop;
+mod field_mode;
+mod filter_mode;
 mod insert_mode;
 mod mouse;
 mod normal_mode;
@@ -18,80 +18,76 @@ pub fn handle_mouse_event<B: ratatui::backend::Backend>(
     match mouse.kind {
         MouseEventKind::ScrollLeft => {
             // Horizontal scroll left
-            if app.format_mode == FormatMode::View {
-                app.relf_hscroll_by(-8);
-            } else if app.format_mode == FormatMode::Edit {
+            if matches!(app.format_mode, FormatMode::View | FormatMode::Edit) {
                 app.relf_hscroll_by(-8);
             }
         }
         MouseEventKind::ScrollRight => {
             // Horizontal scroll right
-            if app.format_mode == FormatMode::View {
-                app.relf_hscroll_by(8);
-            } else if app.format_mode == FormatMode::Edit {
+            if matches!(app.format_mode, FormatMode::View | FormatMode::Edit) {
                 app.relf_hscroll_by(8);
             }
         }
-        MouseEventKind::ScrollUp => {
-            // Don't scroll vertically if horizontal scrollbar is being dragged
-            if app.dragging_scrollbar != Some(ScrollbarType::Horizontal) {
-                // If outline has focus, scroll outline
-                if app.outline_open && app.outline_has_focus {
-                    app.outline_move_up();
-                // If explorer has focus, scroll explorer
-                } else if app.explorer_open && app.explorer_has_focus {
-                    app.explorer_move_up();
-                } else if app.format_mode == FormatMode::Edit {
-                    // Scroll and move cursor together
-                    for _ in 0..5 {
-                        if app.content_cursor_line > 0 {
-                            app.move_cursor_up();
-                        } else {
-                            app.scroll_up();
-                        }
-                    }
-                } else if !app.relf_entries.is_empty() {
-                    // Card view: move selection up
-                    if app.selected_entry_index > 0 {
-                        app.selected_entry_index -= 1;
-                        // Reset vertical scroll when changing cards (hscroll is misused as vscroll for cards)
-                        app.hscroll = 0;
+        // Don't scroll vertically if horizontal scrollbar is being dragged
+        MouseEventKind::ScrollUp if app.dragging_scrollbar != Some(ScrollbarType::Horizontal) => {
+            // Over a side panel, scroll that panel
+            if app.over_outline(mouse.column, mouse.row) {
+                app.outline_move_up();
+            } else if app.over_explorer(mouse.column, mouse.row) {
+                app.explorer_move_up();
+            } else if app.format_mode == FormatMode::Edit {
+                // Scroll and move cursor together
+                for _ in 0..5 {
+                    if app.content_cursor_line > 0 {
+                        app.move_cursor_up();
+                    } else {
+                        app.scroll_up();
                     }
-                } else {
-                    // Relf: clamp to content bounds
-                    let dec = 5u16;
-                    app.scroll = app.scroll.saturating_sub(dec);
                 }
+            } else if app.focus_mode && !app.relf_entries.is_empty() {
+                // Focus mode: scroll the card
+                app.focus_scroll(-3);
+            } else if !app.relf_entries.is_empty() {
+                // Card view: move selection up
+                if app.selected_entry_index > 0 {
+                    app.selected_entry_index -= 1;
+                    // Reset vertical scroll when changing cards (hscroll is misused as vscroll for cards)
+                    app.hscroll = 0;
+                }
+            } else {
+                // Relf: clamp to content bounds
+                let dec = 5u16;
+                app.scroll = app.scroll.saturating_sub(dec);
             }
         }
-        MouseEventKind::ScrollDown => {
-            // Don't scroll vertically if horizontal scrollbar is being dragged
-            if app.dragging_scrollbar != Some(ScrollbarType::Horizontal) {
-                // If outline has focus, scroll outline
-                if app.outline_open && app.outline_has_focus {
-                    app.outline_move_down();
-                // If explorer has focus, scroll explorer
-                } else if app.explorer_open && app.explorer_has_focus {
-                    app.explorer_move_down();
-                } else if app.format_mode == FormatMode::Edit {
-                    // Scroll and move cursor together
-                    for _ in 0..5 {
-                        app.move_cursor_down();
-                    }
-                } else if !app.relf_entries.is_empty() {
-                    // Card view: move selection down
-                    if app.selected_entry_index + 1 < app.relf_entries.len() {
-                        app.selected_entry_index += 1;
-                        // Reset vertical scroll when changing cards (hscroll is misused as vscroll for cards)
-                        app.hscroll = 0;
-                    }
-                } else {
-                    // Relf: clamp to last content page
-                    let inc = 5u16;
-                    let max_off = app.relf_content_max_scroll();
-                    let new_val = app.scroll.saturating_add(inc);
-                    app.scroll = std::cmp::min(new_val, max_off);
+        // Don't scroll vertically if horizontal scrollbar is being dragged
+        MouseEventKind::ScrollDown if app.dragging_scrollbar != Some(ScrollbarType::Horizontal) => {
+            // Over a side panel, scroll that panel
+            if app.over_outline(mouse.column, mouse.row) {
+                app.outline_move_down();
+            } else if app.over_explorer(mouse.column, mouse.row) {
+                app.explorer_move_down();
+            } else if app.format_mode == FormatMode::Edit {
+                // Scroll and move cursor together
+                for _ in 0..5 {
+                    app.move_cursor_down();
+                }
+            } else if app.focus_mode && !app.relf_entries.is_empty() {
+                // Focus mode: scroll the card
+                app.focus_scroll(3);
+            } else if !app.relf_entries.is_empty() {
+                // Card view: move selection down
+                if app.selected_entry_index + 1 < app.relf_entries.len() {
+                    app.selected_entry_index += 1;
+                    // Reset vertical scroll when changing cards (hscroll is misused as vscroll for cards)
+                    app.hscroll = 0;
                 }
+            } else {
+                // Relf: clamp to last content page
+                let inc = 5u16;
+                let max_off = app.relf_content_max_scroll();
+                let new_val = app.scroll.saturating_add(inc);
+                app.scroll = std::cmp::min(new_val, max_off);
             }
         }
         MouseEventKind::Down(MouseButton::Left) => {
@@ -197,6 +193,13 @@ fn handle_left_mouse_down<B: ratatui::backend::Backend>(
     mouse: MouseEvent,
     terminal: &mut ratatui::Terminal<B>,
 ) -> Result<()> {
+    // Clicks on a side panel select (and open) the entry under the mouse
+    if app.explorer_click(mouse.column, mouse.row) || app.outline_click(mouse.column, mouse.row) {
+        app.dragging_scrollbar = None;
+        app.last_click_time = None;
+        return Ok(());
+    }
+
     // Disable scrollbar dragging in Edit mode
     if app.format_mode == FormatMode::Edit {
         return Ok(());
@@ -237,7 +240,8 @@ fn handle_left_mouse_down<B: ratatui::backend::Backend>(
         let new_scroll = (app.max_scroll as f32 * click_ratio) as u16;
         app.scroll = new_scroll.min(app.max_scroll);
     } else {
-        // Not on any scrollbar - check for double-click
+        // Not on any scrollbar: clicking the content focuses it; check for double-click
+        app.focus_file();
         // Check for double-click (clicks within 500ms)
         let now = Instant::now();
         let is_double_click = if let Some(last_time) = app.last_click_time {
@@ -247,10 +251,7 @@ fn handle_left_mouse_down<B: ratatui::backend::Backend>(
         };
 
         if is_double_click {
-            // If explorer has focus, open file and move to file window
-            if app.explorer_open && app.explorer_has_focus {
-                app.explorer_select_entry();
-            } else if app.format_mode == FormatMode::View && !app.relf_entries.is_empty() {
+            if app.format_mode == FormatMode::View && !app.relf_entries.is_empty() {
                 // Double-click: open the overlay for the currently selected entry
                 app.open_entry_overlay();
             }
@@ -290,29 +291,27 @@ fn handle_left_mouse_drag<B: ratatui::backend::Backend>(
                 app.scroll = new_scroll.min(app.max_scroll);
             }
         }
-        Some(ScrollbarType::Horizontal) => {
-            // Continue horizontal scrollbar drag (Edit mode only)
-            if app.format_mode == FormatMode::Edit {
-                let click_x = mouse.column;
-                let terminal_width =
-                    terminal.size().map(|s| s.width).unwrap_or(80);
+        // Continue horizontal scrollbar drag (Edit mode only)
+        Some(ScrollbarType::Horizontal) if app.format_mode == FormatMode::Edit => {
+            let click_x = mouse.column;
+            let terminal_width =
+                terminal.size().map(|s| s.width).unwrap_or(80);
 
-                if click_x > 0 && click_x < terminal_width - 1 {
-                    let max_hscroll = app.relf_max_hscroll();
+            if click_x > 0 && click_x < terminal_width - 1 {
+                let max_hscroll = app.relf_max_hscroll();
 
-                    if max_hscroll > 0 {
-                        let scrollbar_width = (terminal_width - 2) as f32;
-                        let click_ratio =
-                            (click_x - 1) as f32 / scrollbar_width;
-                        let new_hscroll =
-                            (max_hscroll as f32 * click_ratio) as u16;
-                        app.hscroll = new_hscroll.min(max_hscroll);
-                    }
+                if max_hscroll > 0 {
+                    let scrollbar_width = (terminal_width - 2) as f32;
+                    let click_ratio =
+                        (click_x - 1) as f32 / scrollbar_width;
+                    let new_hscroll =
+                        (max_hscroll as f32 * click_ratio) as u16;
+                    app.hscroll = new_hscroll.min(max_hscroll);
                 }
             }
         }
-        None => {
-            // Not dragging any scrollbar, ignore
+        _ => {
+            // Not dragging any scrollbar (or not in Edit mode), ignore
         }
     }
 
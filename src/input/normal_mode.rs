@@ -1,7 +1,8 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::{App, FileOperation, FormatMode};
+use crate::app::{App, FileOperation, FormatMode, TextVisualMode};
+use crate::keymap::{Action, KeyMode};
 
 pub fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     // Handle file operation confirmation/prompt if active
@@ -26,350 +27,186 @@ pub fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
     }
 
-    // Handle explorer navigation if explorer has focus
-    if app.explorer_open && app.explorer_has_focus {
-        return handle_explorer_navigation(app, key);
+    // Handle the save conflict prompt if active
+    if app.save_conflict {
+        match key.code {
+            KeyCode::Char(c @ ('r' | 'o' | 'm' | 'c')) => app.handle_save_conflict(c),
+            KeyCode::Esc => app.handle_save_conflict('c'),
+            _ => {}
+        }
+        return Ok(false);
     }
 
-    // Handle outline navigation if outline has focus
-    if app.outline_open && app.outline_has_focus {
-        return handle_outline_navigation(app, key);
+    // Handle smart paste confirmation if active
+    if !app.paste_merge_pending.is_empty() {
+        match key.code {
+            KeyCode::Char(c @ ('y' | 'n' | 'a' | 'q')) => app.handle_paste_merge_confirmation(c),
+            KeyCode::Esc => app.handle_paste_merge_confirmation('q'),
+            _ => {}
+        }
+        return Ok(false);
     }
 
-    // Main normal mode keyboard handling
-    match key.code {
-        KeyCode::Char('u') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.undo();
-            }
-        }
-        KeyCode::Char('v') => {
-            // Enter Visual/Select mode in View mode
-            if !app.showing_help && app.format_mode == FormatMode::View && !app.relf_entries.is_empty() {
-                app.visual_mode = true;
-                app.visual_start_index = app.selected_entry_index;
-                app.visual_end_index = app.selected_entry_index;
-                app.set_status("-- VISUAL --");
-            }
-        }
-        KeyCode::Char('?') => {
-            // Toggle help
-            app.toggle_help();
-        }
-        KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('[') => {
-            // Check for Ctrl+[ to exit Visual mode
-            if key.code == KeyCode::Char('[') && !key.modifiers.contains(KeyModifiers::CONTROL) {
-                // Not Ctrl+[, ignore
-            } else {
-                // Exit Visual mode if active, otherwise quit
-                if app.visual_mode {
-                    app.visual_mode = false;
-                    app.set_status("");
-                } else {
-                    return Ok(true);
-                }
-            }
-        }
-        KeyCode::Char('w') => {
-            // Vim-like: move to start of next word (Edit mode)
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.move_to_next_word_start();
-            }
-        }
-        KeyCode::Char('e') => {
-            // Vim-like: move to end of next word (Edit mode)
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.move_to_next_word_end();
-            }
-        }
-        KeyCode::Char('b') => {
-            // Ctrl+b: page up (vim-like) or page scroll in View mode
-            if key.modifiers.contains(KeyModifiers::CONTROL) {
-                if !app.showing_help && app.format_mode == FormatMode::View {
-                    // Page scroll up (5 lines at a time, like Ctrl+h)
-                    app.relf_hscroll_by(-5);
-                } else {
-                    app.page_up();
-                }
-            } else {
-                // Vim-like: move to start of previous word (Edit mode)
-                // Or scroll card content up in View mode (like h)
-                if !app.showing_help {
-                    if app.format_mode == FormatMode::Edit {
-                        app.move_to_previous_word_start();
-                    } else {
-                        // Scroll card content up (like h key)
-                        app.relf_hscroll_by(-1);
-                    }
-                }
-            }
-        }
-        KeyCode::Char('f') => {
-            // Ctrl+f: page down (vim-like) or page scroll in View mode
-            if key.modifiers.contains(KeyModifiers::CONTROL) {
-                if !app.showing_help && app.format_mode == FormatMode::View {
-                    // Page scroll down (5 lines at a time, like Ctrl+l)
-                    app.relf_hscroll_by(5);
-                } else {
-                    app.page_down();
-                }
-            } else {
-                // Scroll card content down in View mode (like l key)
-                if !app.showing_help && app.format_mode == FormatMode::View {
-                    app.relf_hscroll_by(1);
-                }
-            }
+    // Handle the move-to-other-section prompt if active
+    if app.card_move_pending.is_some() {
+        match key.code {
+            KeyCode::Char(c @ ('f' | 'd' | 'c')) => app.handle_card_move_confirmation(c),
+            KeyCode::Esc => app.handle_card_move_confirmation('c'),
+            _ => {}
         }
-        KeyCode::Char('r') => {
-            if !app.showing_help {
-                // Clear filter when toggling modes
-                if !app.filter_pattern.is_empty() {
-                    app.filter_pattern.clear();
-                }
+        return Ok(false);
+    }
 
-                // Toggle between View and Edit only (not Help)
-                app.format_mode = match app.format_mode {
-                    FormatMode::View => FormatMode::Edit,
-                    FormatMode::Edit => FormatMode::View,
-                    FormatMode::Help => FormatMode::View, // If somehow in Help, go to View
-                };
-                let mode_name = match app.format_mode {
-                    FormatMode::View => "View",
-                    FormatMode::Edit => "Edit",
-                    FormatMode::Help => "Help",
-                };
-                if app.format_mode == FormatMode::View {
-                    app.hscroll = 0;
-                }
-                app.convert_json();
-                app.set_status(&format!("{} mode", mode_name));
-            }
-        }
-        KeyCode::Char('i') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.input_mode = crate::app::InputMode::Insert;
-                app.ensure_cursor_visible();
-                app.set_status("-- INSERT --");
-            }
-        }
-        KeyCode::Char('a') if !app.substitute_confirmations.is_empty() => {
-            // Handle substitute confirmation 'a' (replace all)
-            // This case is handled elsewhere
-        }
-        KeyCode::Char('a') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                // Append: move cursor right then enter insert mode
-                app.move_cursor_right();
-                app.input_mode = crate::app::InputMode::Insert;
-                app.ensure_cursor_visible();
-                app.set_status("-- INSERT --");
-            }
-        }
-        KeyCode::Char('o') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                // Open line below: insert new line and enter insert mode
-                app.open_line_below();
-                app.input_mode = crate::app::InputMode::Insert;
-                app.set_status("-- INSERT --");
-            }
-        }
-        KeyCode::Char('x') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.delete_char();
-                app.is_modified = true;
-            }
-        }
-        KeyCode::Char('X') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.backspace();
-                app.is_modified = true;
-            }
-        }
-        KeyCode::Char('d') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                // Handle dd (delete line)
-                app.dd_count += 1;
-                if app.dd_count == 2 {
-                    app.delete_line();
-                    app.dd_count = 0;
-                }
-            }
-        }
-        KeyCode::Char('y') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                // Handle yy (yank line)
-                app.yy_count += 1;
-                if app.yy_count == 2 {
-                    app.yank_line();
-                    app.yy_count = 0;
-                }
-            }
-        }
-        KeyCode::Char('p') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                // Paste yanked line
-                app.paste_line();
-            }
+    // Handle link hints (gl) if shown
+    if !app.link_hints.is_empty() {
+        match key.code {
+            KeyCode::Char(c) => app.handle_link_hint(c),
+            KeyCode::Esc => app.cancel_link_hints(),
+            _ => {}
         }
-        KeyCode::Char(':') => {
-            // Allow command mode even when showing help (for :h to toggle)
-            app.input_mode = crate::app::InputMode::Command;
-            app.command_buffer = String::new();
-            app.command_history_index = None;
-            app.set_status(":");
+        return Ok(false);
+    }
+
+    // Handle the y/n prompt of a destructive action (or of creating a missing file) if active
+    if app.confirm_pending.is_some() {
+        match key.code {
+            KeyCode::Char(c @ ('y' | 'n')) => app.handle_confirmation(c),
+            KeyCode::Esc => app.handle_confirmation('n'),
+            _ => {}
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.showing_help {
-                // Allow scrolling in help mode (takes priority)
-                app.scroll_up();
-            } else if app.format_mode == FormatMode::Edit {
-                app.move_cursor_up();
-            } else if !app.relf_entries.is_empty() {
-                // Move selection up in card view
-                if app.selected_entry_index > 0 {
-                    app.selected_entry_index -= 1;
-                    // Reset horizontal scroll when changing cards
-                    app.hscroll = 0;
-                    // In Visual mode, extend selection
-                    if app.visual_mode {
-                        app.visual_end_index = app.selected_entry_index;
-                    }
-                }
-            } else {
-                app.relf_jump_up();
-            }
+        return Ok(false);
+    }
+
+    // Handle the bulk update prompt if active
+    if app.bulk_update_pending.is_some() {
+        match key.code {
+            KeyCode::Char(c @ ('y' | 'n')) => app.handle_bulk_update_confirmation(c),
+            KeyCode::Esc => app.handle_bulk_update_confirmation('n'),
+            _ => {}
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.showing_help {
-                // Allow scrolling in help mode (takes priority)
-                app.scroll_down();
-            } else if app.format_mode == FormatMode::Edit {
-                app.move_cursor_down();
-            } else if !app.relf_entries.is_empty() {
-                // Move selection down in card view
-                if app.selected_entry_index + 1 < app.relf_entries.len() {
-                    app.selected_entry_index += 1;
-                    // Reset horizontal scroll when changing cards
-                    app.hscroll = 0;
-                    // In Visual mode, extend selection
-                    if app.visual_mode {
-                        app.visual_end_index = app.selected_entry_index;
-                    }
-                }
-            } else {
-                app.relf_jump_down();
-            }
+        return Ok(false);
+    }
+
+    // Handle the :convert delete-original prompt if active
+    if app.convert_delete_pending.is_some() {
+        match key.code {
+            KeyCode::Char(c @ ('y' | 'n')) => app.handle_convert_delete_confirmation(c),
+            KeyCode::Esc => app.handle_convert_delete_confirmation('n'),
+            _ => {}
         }
-        KeyCode::Left | KeyCode::Char('h') => {
-            if !app.showing_help {
-                if app.format_mode == FormatMode::Edit {
-                    app.move_cursor_left();
-                } else {
-                    // Check for Ctrl modifier for page scroll
-                    if key.modifiers.contains(KeyModifiers::CONTROL) {
-                        // Page scroll up (e.g., 5 lines at a time)
-                        app.relf_hscroll_by(-5);
-                    } else {
-                        // Vertical scroll up in View mode (card context)
-                        app.relf_hscroll_by(-1);
-                    }
-                }
-            }
+        return Ok(false);
+    }
+
+    // Handle the swap file recovery prompt if active
+    if app.swap_recovery_pending.is_some() {
+        match key.code {
+            KeyCode::Char(c @ ('r' | 'd')) => app.handle_swap_recovery(c),
+            KeyCode::Esc => app.defer_swap_recovery(),
+            _ => {}
         }
-        KeyCode::Right | KeyCode::Char('l') => {
-            if !app.showing_help {
-                if app.format_mode == FormatMode::Edit {
-                    app.move_cursor_right();
-                } else {
-                    // Check for Ctrl modifier for page scroll
-                    if key.modifiers.contains(KeyModifiers::CONTROL) {
-                        // Page scroll down (e.g., 5 lines at a time)
-                        app.relf_hscroll_by(5);
-                    } else {
-                        // Vertical scroll down in View mode (card context)
-                        app.relf_hscroll_by(1);
-                    }
-                }
+        return Ok(false);
+    }
+
+    // The file finder types into its query, whichever window has focus
+    if app.finder_open {
+        return handle_finder_input(app, key);
+    }
+
+    // So does the command palette
+    if app.palette_open {
+        return handle_palette_input(app, key);
+    }
+
+    // Ctrl+p: fuzzy-find a file under the explorer's directory
+    if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) && !app.showing_help {
+        app.open_finder("");
+        return Ok(false);
+    }
+
+    // Handle explorer navigation if explorer has focus
+    if app.explorer_open && app.explorer_has_focus {
+        return handle_explorer_navigation(app, key);
+    }
+
+    // Handle outline navigation if outline has focus
+    if app.outline_open && app.outline_has_focus {
+        return handle_outline_navigation(app, key);
+    }
+
+    // The snapshot browser takes all keys while open
+    if app.snapshots_open {
+        return handle_snapshot_list_navigation(app, key);
+    }
+
+    // So does the recent files list
+    if app.recent_open {
+        return handle_recent_list_navigation(app, key);
+    }
+
+    // Focus mode: keys scroll the one visible card
+    if app.focus_mode && app.format_mode == FormatMode::View && !app.showing_help {
+        return handle_focus_mode(app, key);
+    }
+
+    // Handle match list navigation if the match list has focus
+    if app.matches_open && app.matches_has_focus {
+        return handle_match_list_navigation(app, key);
+    }
+
+    // Operators on a Visual text selection (Edit mode); motions fall through to the main handler
+    if app.text_visual.is_some() && app.format_mode == FormatMode::Edit {
+        match key.code {
+            KeyCode::Char('y') => {
+                app.yank_text_selection();
+                return Ok(false);
             }
-        }
-        KeyCode::Char('0') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                app.content_cursor_col = 0;
-                app.ensure_cursor_visible();
+            KeyCode::Char('d') | KeyCode::Char('x') => {
+                app.delete_text_selection();
+                return Ok(false);
             }
-        }
-        KeyCode::Char('$') => {
-            if !app.showing_help && app.format_mode == FormatMode::Edit {
-                let lines = app.get_content_lines();
-                if app.content_cursor_line < lines.len() {
-                    app.content_cursor_col =
-                        lines[app.content_cursor_line].chars().count();
-                    app.ensure_cursor_visible();
-                }
+            KeyCode::Char('>') => {
+                app.indent_text_selection(false);
+                return Ok(false);
             }
-        }
-        KeyCode::PageUp => app.page_up(),
-        KeyCode::PageDown => app.page_down(),
-        KeyCode::Char('G') => {
-            if app.showing_help {
-                // Allow scrolling to bottom in help mode (takes priority)
-                app.scroll_to_bottom();
-            } else if app.format_mode == FormatMode::Edit {
-                let lines = app.get_content_lines();
-                if !lines.is_empty() {
-                    app.content_cursor_line = lines.len() - 1;
-                    app.content_cursor_col = lines.last().map(|l| l.chars().count()).unwrap_or(0);
-                }
-                app.ensure_cursor_visible();
-            } else if !app.relf_entries.is_empty() {
-                // Jump to last card
-                app.selected_entry_index = app.relf_entries.len() - 1;
-            } else {
-                app.scroll_to_bottom();
+            KeyCode::Char('<') => {
+                app.indent_text_selection(true);
+                return Ok(false);
             }
-        }
-        KeyCode::Char('/') => {
-            if !app.showing_help {
-                app.start_search();
+            KeyCode::Char('V') => {
+                app.start_text_visual(TextVisualMode::Line);
+                return Ok(false);
             }
-        }
-        KeyCode::Char('n') => {
-            if !app.showing_help {
-                app.next_match();
+            KeyCode::Esc | KeyCode::Char('q') => {
+                app.exit_text_visual();
+                return Ok(false);
             }
-        }
-        KeyCode::Char('N') => {
-            if !app.showing_help {
-                app.prev_match();
+            KeyCode::Char('[') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.exit_text_visual();
+                return Ok(false);
             }
-        }
-        KeyCode::Enter => {
-            // Open edit overlay for selected card (only in View mode)
-            if !app.showing_help && !app.relf_entries.is_empty() && app.format_mode == FormatMode::View {
-                app.start_editing_entry();
+            KeyCode::Char('i' | 'a' | 'o' | 'p' | 'X' | 'u' | '-' | '+') => {
+                // Editing keys are disabled while a selection is active
+                return Ok(false);
             }
+            _ => {}
         }
-        KeyCode::Char(c)
-            if c == 'g'
-                || c == '-'
-                || c == '+'
-                || app.vim_buffer.starts_with('g') =>
-        {
-            // Allow gg in help mode for scrolling to top
-            app.handle_vim_input(c);
+    }
+
+    // Operator-pending (d, c, di, ci) in Edit mode: the next key picks the motion
+    if !app.pending_operator.is_empty() && app.format_mode == FormatMode::Edit {
+        if let KeyCode::Char(c) = key.code
+            && app.handle_operator_key(c) {
+            return Ok(false);
         }
-        _ => {
-            // Reset dd/yy count if any other key is pressed
-            let should_clear = app.dd_count > 0 || app.yy_count > 0;
-            app.dd_count = 0;
-            app.yy_count = 0;
-            if should_clear {
-                app.vim_buffer.clear();
-            }
+        // Any other key cancels the operator
+        app.pending_operator.clear();
+        if key.code == KeyCode::Esc {
+            return Ok(false);
         }
     }
 
-    Ok(false)
+    // Everything else goes through the keymap: sequences (gg, dd), counts (3dd, 5j)
+    Ok(app.press_key(key))
 }
 
 fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Result<bool> {
@@ -379,7 +216,7 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
             match key.code {
                 KeyCode::Esc => {
                     app.cancel_file_operation();
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Enter => {
                     let input = app.file_op_prompt_buffer.trim().to_lowercase();
@@ -391,7 +228,7 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
                         app.set_status("Invalid input. Type 'yes' or 'no'");
                         app.file_op_prompt_buffer.clear();
                     }
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Char(c) => {
                     app.file_op_prompt_buffer.push(c);
@@ -403,7 +240,7 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
                         String::new()
                     };
                     app.set_status(&path_display);
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Backspace => {
                     if !app.file_op_prompt_buffer.is_empty() {
@@ -419,9 +256,9 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
                     } else {
                         app.cancel_file_operation();
                     }
-                    return Ok(false);
+                    Ok(false)
                 }
-                _ => return Ok(false),
+                _ => Ok(false),
             }
         }
         FileOperation::Create | FileOperation::CreateDir | FileOperation::Copy(_) | FileOperation::Rename(_) => {
@@ -429,11 +266,11 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
             match key.code {
                 KeyCode::Esc => {
                     app.cancel_file_operation();
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Enter => {
                     app.execute_file_operation();
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Char(c) => {
                     app.file_op_prompt_buffer.push(c);
@@ -454,7 +291,7 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
                         _ => "",
                     };
                     app.set_status(&format!("{} {}", prompt_msg, app.file_op_prompt_buffer));
-                    return Ok(false);
+                    Ok(false)
                 }
                 KeyCode::Backspace => {
                     if !app.file_op_prompt_buffer.is_empty() {
@@ -479,217 +316,190 @@ fn handle_file_operation(app: &mut App, key: KeyEvent, op: &FileOperation) -> Re
                     } else {
                         app.cancel_file_operation();
                     }
-                    return Ok(false);
+                    Ok(false)
                 }
-                _ => return Ok(false),
+                _ => Ok(false),
             }
         }
     }
 }
 
 fn handle_explorer_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let Some(action) = app.resolve_key(KeyMode::Explorer, key) else {
+        return Ok(false);
+    };
+    match action {
+        Action::CommandLine => start_command_line(app),
+        Action::Down => app.explorer_move_down(),
+        Action::Up => app.explorer_move_up(),
+        Action::Left => app.explorer_horizontal_scroll = app.explorer_horizontal_scroll.saturating_sub(1),
+        Action::Right => app.explorer_horizontal_scroll = (app.explorer_horizontal_scroll + 1).min(100),
+        Action::Top => {
+            app.explorer_selected_index = 0;
+            app.explorer_update_scroll();
+        }
+        Action::Bottom if !app.explorer_entries.is_empty() => {
+            app.explorer_selected_index = app.explorer_entries.len() - 1;
+            app.explorer_update_scroll();
+        }
+        Action::Search => start_search(app),
+        Action::NextMatch => app.explorer_next_match(),
+        Action::PrevMatch => app.explorer_prev_match(),
+        // Open file and move focus to right
+        Action::Select => app.explorer_select_entry(),
+        // Preview file without moving focus (NERDTree-like)
+        Action::Preview => app.explorer_preview_entry(),
+        Action::ShowAllFiles => app.toggle_explorer_show_all(),
+        Action::Quit => return Ok(true),
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// `:` from a list: the command line, keeping the list's focus
+fn start_command_line(app: &mut App) {
+    app.input_mode = crate::app::InputMode::Command;
+    app.command_buffer = String::new();
+    app.command_history_index = None;
+    app.set_status(":");
+}
+
+/// `/` from a list
+fn start_search(app: &mut App) {
+    app.input_mode = crate::app::InputMode::Search;
+    app.search_buffer = String::new();
+    app.search_history_index = None;
+    app.set_status("/");
+}
+
+fn handle_focus_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.relf_entries.is_empty() {
+        app.toggle_focus_mode();
+        return Ok(false);
+    }
+    Ok(app.press_key(key))
+}
+
+fn handle_snapshot_list_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
-        KeyCode::Char(':') => {
-            // Allow command mode from explorer
-            app.input_mode = crate::app::InputMode::Command;
-            app.command_buffer = String::new();
-            app.command_history_index = None;
-            app.set_status(":");
-            return Ok(false);
-        }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.explorer_move_down();
-            return Ok(false);
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.explorer_move_up();
-            return Ok(false);
-        }
-        KeyCode::Char('h') | KeyCode::Left => {
-            // Scroll left
-            if app.explorer_horizontal_scroll > 0 {
-                app.explorer_horizontal_scroll -= 1;
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('l') | KeyCode::Right => {
-            // Scroll right (max 100)
-            if app.explorer_horizontal_scroll < 100 {
-                app.explorer_horizontal_scroll += 1;
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('G') => {
-            // Go to bottom
-            if !app.explorer_entries.is_empty() {
-                app.explorer_selected_index = app.explorer_entries.len() - 1;
-                app.explorer_update_scroll();
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('/') => {
-            // Start search mode
-            app.input_mode = crate::app::InputMode::Search;
-            app.search_buffer = String::new();
-            app.search_history_index = None;
-            app.set_status("/");
-            return Ok(false);
-        }
-        KeyCode::Char('n') => {
-            // Next search match
-            app.explorer_next_match();
-            return Ok(false);
-        }
-        KeyCode::Char('N') => {
-            // Previous search match
-            app.explorer_prev_match();
-            return Ok(false);
-        }
-        KeyCode::Enter => {
-            // Open file and move focus to right
-            app.explorer_select_entry();
-            return Ok(false);
-        }
-        KeyCode::Char('o') => {
-            // Check if this might be part of 'go'
-            if app.vim_buffer == "g" {
-                // Let handle_vim_input process 'go'
-                app.handle_vim_input('o');
+        KeyCode::Char(':') => start_command_line(app),
+        KeyCode::Char('j') | KeyCode::Down => app.snapshot_list_move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.snapshot_list_move_up(),
+        KeyCode::Char('d') | KeyCode::Tab => app.toggle_snapshot_diff(),
+        KeyCode::Enter => app.restore_selected_snapshot(),
+        KeyCode::Char('q') | KeyCode::Esc => {
+            if app.snapshot_diff.is_some() && !app.snapshots.is_empty() {
+                // Back from the diff to the list
+                app.toggle_snapshot_diff();
             } else {
-                // Standalone 'o' - open file
-                app.explorer_select_entry();
+                app.close_snapshot_list();
             }
-            return Ok(false);
-        }
-        KeyCode::Char('q') => {
-            // Quit program
-            return Ok(true);
-        }
-        KeyCode::Char('g') => {
-            // Start of potential 'go' or 'gg' or 'G'
-            if app.vim_buffer == "g" {
-                // Second 'g' - go to top
-                app.explorer_selected_index = 0;
-                app.explorer_update_scroll();
-                app.vim_buffer.clear();
-            } else {
-                app.handle_vim_input('g');
-            }
-            return Ok(false);
         }
         _ => {}
     }
     Ok(false)
 }
 
-fn handle_outline_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
+fn handle_palette_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
     match key.code {
-        KeyCode::Char(':') => {
-            // Allow command mode from outline
-            app.input_mode = crate::app::InputMode::Command;
-            app.command_buffer = String::new();
-            app.command_history_index = None;
-            app.set_status(":");
-            return Ok(false);
-        }
-        KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            // Ctrl+w: window switching - handled by common handler
-            return Ok(false);
-        }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.outline_move_down();
-            return Ok(false);
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.outline_move_up();
-            return Ok(false);
-        }
-        KeyCode::Char('h') | KeyCode::Left => {
-            // Scroll left
-            if app.outline_horizontal_scroll > 0 {
-                app.outline_horizontal_scroll -= 1;
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('l') | KeyCode::Right => {
-            // Scroll right
-            if app.outline_horizontal_scroll < 100 {
-                app.outline_horizontal_scroll += 1;
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('o') => {
-            // Check if this might be part of 'go'
-            if app.vim_buffer == "g" {
-                // 'go' - preview entry without closing outline
-                app.outline_preview_entry();
-                app.vim_buffer.clear();
-            }
-            return Ok(false);
-        }
-        KeyCode::Char('f') => {
-            // Ctrl+f: page down
-            if key.modifiers.contains(KeyModifiers::CONTROL) {
-                app.outline_page_down();
-                return Ok(false);
-            }
-        }
-        KeyCode::Char('b') => {
-            // Ctrl+b: page up
-            if key.modifiers.contains(KeyModifiers::CONTROL) {
-                app.outline_page_up();
-                return Ok(false);
-            }
-        }
-        KeyCode::Char('G') => {
-            // Go to bottom
+        KeyCode::Esc => app.close_palette(),
+        KeyCode::Char('k') if ctrl => app.close_palette(),
+        KeyCode::Enter => return Ok(app.run_selected_palette_item()),
+        KeyCode::Down | KeyCode::Tab => app.palette_move(1),
+        KeyCode::Up | KeyCode::BackTab => app.palette_move(-1),
+        KeyCode::Char('n') if ctrl => app.palette_move(1),
+        KeyCode::Char('p') if ctrl => app.palette_move(-1),
+        KeyCode::Char('u') if ctrl => app.palette_clear_query(),
+        KeyCode::Backspace => app.palette_backspace(),
+        KeyCode::Char(c) if !ctrl => app.palette_push_char(c),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_finder_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => app.close_finder(),
+        KeyCode::Enter => app.open_selected_finder_file(),
+        KeyCode::Down | KeyCode::Tab => app.finder_move(1),
+        KeyCode::Up | KeyCode::BackTab => app.finder_move(-1),
+        KeyCode::Char('n') if ctrl => app.finder_move(1),
+        KeyCode::Char('p') if ctrl => app.finder_move(-1),
+        KeyCode::Char('u') if ctrl => app.finder_clear_query(),
+        KeyCode::Backspace => app.finder_backspace(),
+        KeyCode::Char(c) if !ctrl => app.finder_push_char(c),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_recent_list_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char(':') => start_command_line(app),
+        KeyCode::Char('j') | KeyCode::Down => app.recent_list_move_down(),
+        KeyCode::Char('k') | KeyCode::Up => app.recent_list_move_up(),
+        KeyCode::Enter => app.open_selected_recent_file(),
+        KeyCode::Char('q') | KeyCode::Esc => app.close_recent_list(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_match_list_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let Some(action) = app.resolve_key(KeyMode::Matches, key) else {
+        return Ok(false);
+    };
+    match action {
+        Action::CommandLine => start_command_line(app),
+        Action::Down => app.match_list_move_down(),
+        Action::Up => app.match_list_move_up(),
+        Action::Top => app.matches_selected_index = 0,
+        Action::Bottom => app.matches_selected_index = app.search_matches.len().saturating_sub(1),
+        // Jump to the match and move focus to content
+        Action::Select => app.match_list_jump(),
+        Action::Quit => app.close_match_list(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_outline_navigation(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let Some(action) = app.resolve_key(KeyMode::Outline, key) else {
+        return Ok(false);
+    };
+    match action {
+        Action::CommandLine => start_command_line(app),
+        Action::Down => app.outline_move_down(),
+        Action::Up => app.outline_move_up(),
+        Action::Left => app.outline_horizontal_scroll = app.outline_horizontal_scroll.saturating_sub(1),
+        Action::Right => app.outline_horizontal_scroll = (app.outline_horizontal_scroll + 1).min(100),
+        Action::PageDown => app.outline_page_down(),
+        Action::PageUp => app.outline_page_up(),
+        Action::Top => app.outline_selected_index = 0,
+        Action::Bottom => {
             let max_index = if app.format_mode == FormatMode::View {
-                app.relf_entries.len().saturating_sub(1)
+                app.outline_len().saturating_sub(1)
             } else {
                 app.get_entry_count_from_content().saturating_sub(1)
             };
             app.outline_selected_index = max_index;
-            return Ok(false);
         }
-        KeyCode::Char('g') => {
-            if app.vim_buffer == "g" {
-                // gg - go to top
-                app.outline_selected_index = 0;
-                app.vim_buffer.clear();
-            } else {
-                app.handle_vim_input('g');
+        Action::Search => start_search(app),
+        Action::NextMatch => app.outline_next_match(),
+        Action::PrevMatch => app.outline_prev_match(),
+        // Preview entry without closing outline
+        Action::Preview => app.outline_preview_entry(),
+        Action::Select => {
+            // Fold/unfold a group, or jump to the selected entry and move focus to content
+            let toggled = app.outline_toggle_group();
+            if !toggled {
+                app.outline_jump_to_selected();
+                app.outline_has_focus = false;
             }
-            return Ok(false);
-        }
-        KeyCode::Char('/') => {
-            // Start search mode
-            app.input_mode = crate::app::InputMode::Search;
-            app.search_buffer = String::new();
-            app.search_history_index = None;
-            app.set_status("/");
-            return Ok(false);
-        }
-        KeyCode::Char('n') => {
-            // Next search match
-            app.outline_next_match();
-            return Ok(false);
-        }
-        KeyCode::Char('N') => {
-            // Previous search match
-            app.outline_prev_match();
-            return Ok(false);
-        }
-        KeyCode::Enter => {
-            // Jump to selected entry and move focus to content
-            app.outline_jump_to_selected();
-            app.outline_has_focus = false;
-            return Ok(false);
-        }
-        KeyCode::Char('q') => {
-            // Close outline
-            app.toggle_outline();
-            return Ok(false);
         }
+        Action::Quit => app.toggle_outline(),
         _ => {}
     }
     Ok(false)
@@ -1,9 +1,37 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::app::App;
+use crate::graphemes;
+use crate::keymap::{Action, KeyMode};
 use crate::wrap::{move_cursor_vertical, total_rows};
 
 pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
+    // Completion popup for names and tags: Tab accepts, Ctrl+n / Ctrl+p choose
+    if app.edit_insert_mode && !app.edit_completions.is_empty() {
+        match key.code {
+            KeyCode::Tab => {
+                app.accept_edit_completion();
+                return;
+            }
+            KeyCode::Char(c @ ('n' | 'p')) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.cycle_edit_completion(if c == 'n' { 1 } else { -1 });
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    handle_overlay_key(app, key);
+
+    // Suggestions follow typing; any other key closes them
+    if app.edit_insert_mode && matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete) {
+        app.update_edit_completions();
+    } else {
+        app.edit_completions.clear();
+    }
+}
+
+fn handle_overlay_key(app: &mut App, key: KeyEvent) {
     if app.edit_insert_mode {
         // Insert mode: typing edits current field
         match key.code {
@@ -23,23 +51,9 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
                     if app.edit_field_index < app.edit_buffer.len() {
                         let field = &app.edit_buffer[app.edit_field_index];
                         if field.is_empty() {
-                            let placeholder = if app.edit_buffer.len() == 2 {
-                                match app.edit_field_index {
-                                    0 => "date",
-                                    1 => "context",
-                                    _ => "",
-                                }
-                            } else {
-                                match app.edit_field_index {
-                                    0 => "name",
-                                    1 => "context",
-                                    2 => "url",
-                                    3 => "percentage",
-                                    _ => "",
-                                }
-                            };
+                            let placeholder = app.edit_field_key(app.edit_field_index).to_string();
                             if !placeholder.is_empty() {
-                                app.edit_buffer[app.edit_field_index] = placeholder.to_string();
+                                app.edit_buffer[app.edit_field_index] = placeholder;
                                 if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
                                     app.edit_buffer_is_placeholder[app.edit_field_index] = true;
                                 }
@@ -52,27 +66,24 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
             }
             KeyCode::Backspace => {
                 if app.edit_field_index < app.edit_buffer.len() && app.edit_cursor_pos > 0 {
+                    // Delete the character before the cursor (including newline character),
+                    // a whole emoji or accented letter at once
+                    let start = app.edit_prev_boundary();
                     let field = &mut app.edit_buffer[app.edit_field_index];
-
-                    // Delete single character (including newline character)
-                    let char_indices: Vec<_> = field.char_indices().collect();
-                    if app.edit_cursor_pos > 0 && app.edit_cursor_pos <= char_indices.len() {
-                        let byte_pos = char_indices[app.edit_cursor_pos - 1].0;
-                        field.remove(byte_pos);
-                        app.edit_cursor_pos -= 1;
+                    if app.edit_cursor_pos <= field.chars().count() {
+                        *field = graphemes::remove_chars(field, start, app.edit_cursor_pos);
+                        app.edit_cursor_pos = start;
                     }
                 }
                 app.ensure_overlay_cursor_visible();
             }
             KeyCode::Delete => {
                 if app.edit_field_index < app.edit_buffer.len() {
-                    let field = &mut app.edit_buffer[app.edit_field_index];
-
                     // Delete character at cursor position
-                    let char_indices: Vec<_> = field.char_indices().collect();
-                    if app.edit_cursor_pos < char_indices.len() {
-                        let byte_pos = char_indices[app.edit_cursor_pos].0;
-                        field.remove(byte_pos);
+                    let end = app.edit_next_boundary();
+                    let field = &mut app.edit_buffer[app.edit_field_index];
+                    if app.edit_cursor_pos < field.chars().count() {
+                        *field = graphemes::remove_chars(field, app.edit_cursor_pos, end);
                         // Cursor position stays the same
                     }
                 }
@@ -105,7 +116,7 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
 
                         if col_in_line > 0 {
                             // Move left within current line
-                            app.edit_cursor_pos -= 1;
+                            app.edit_cursor_pos = app.edit_prev_boundary();
                         } else if current_line > 0 {
                             // Move to end of previous line
                             let mut new_pos = 0;
@@ -119,7 +130,7 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
                         }
                     }
                 } else if app.edit_cursor_pos > 0 {
-                    app.edit_cursor_pos -= 1;
+                    app.edit_cursor_pos = app.edit_prev_boundary();
                 }
                 app.ensure_overlay_cursor_visible();
             }
@@ -155,13 +166,13 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
 
                             if col_in_line < current_line_len {
                                 // Move right within current line
-                                app.edit_cursor_pos += 1;
+                                app.edit_cursor_pos = app.edit_next_boundary();
                             } else if current_line + 1 < lines.len() {
                                 // Move to start of next line (skip over newline)
                                 app.edit_cursor_pos += 1; // Skip newline (1 character)
                             }
                         } else {
-                            app.edit_cursor_pos += 1;
+                            app.edit_cursor_pos = app.edit_next_boundary();
                         }
                     }
                 }
@@ -252,8 +263,11 @@ pub fn handle_overlay_keyboard(app: &mut App, key: KeyEvent) {
 }
 
 fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Esc | KeyCode::Char('[') if key.code == KeyCode::Esc || key.modifiers.contains(KeyModifiers::CONTROL) => {
+    let Some(action) = app.resolve_key(KeyMode::OverlayField, key) else {
+        return;
+    };
+    match action {
+        Action::Quit => {
             // If in View Edit mode, exit View Edit mode first (go back to field selection)
             if app.view_edit_mode {
                 app.view_edit_mode = false;
@@ -269,26 +283,10 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             if app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
                 if field.is_empty() {
-                    // Determine placeholder based on edit_buffer length
-                    let placeholder = if app.edit_buffer.len() == 3 {
-                        // INSIDE entry: date, context, Exit
-                        match app.edit_field_index {
-                            0 => "date",
-                            1 => "context",
-                            _ => "",
-                        }
-                    } else {
-                        // OUTSIDE entry: name, context, url, percentage, Exit
-                        match app.edit_field_index {
-                            0 => "name",
-                            1 => "context",
-                            2 => "url",
-                            3 => "percentage",
-                            _ => "",
-                        }
-                    };
+                    // The field's key doubles as its placeholder
+                    let placeholder = app.edit_field_key(app.edit_field_index).to_string();
                     if !placeholder.is_empty() {
-                        app.edit_buffer[app.edit_field_index] = placeholder.to_string();
+                        app.edit_buffer[app.edit_field_index] = placeholder;
                         if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
                             app.edit_buffer_is_placeholder[app.edit_field_index] = true;
                         }
@@ -296,8 +294,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                 }
             }
         }
-        KeyCode::Char('h') | KeyCode::Left => {
-            app.vim_buffer.clear();
+        Action::Left => {
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 // In View Edit mode, handle multi-line navigation
                 let field = &app.edit_buffer[app.edit_field_index];
@@ -321,7 +318,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                 }
 
                 if col_in_line > 0 {
-                    app.edit_cursor_pos -= 1;
+                    app.edit_cursor_pos = app.edit_prev_boundary();
                 } else if current_line > 0 {
                     // Move to end of previous line
                     let mut new_pos = 0;
@@ -334,12 +331,11 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                     app.edit_cursor_pos = new_pos;
                 }
             } else if app.edit_cursor_pos > 0 {
-                app.edit_cursor_pos -= 1;
+                app.edit_cursor_pos = app.edit_prev_boundary();
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('l') | KeyCode::Right => {
-            app.vim_buffer.clear();
+        Action::Right => {
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 // In View Edit mode, handle multi-line navigation
                 let field = &app.edit_buffer[app.edit_field_index];
@@ -367,7 +363,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                     let current_line_len = lines[current_line].chars().count();
 
                     if col_in_line < current_line_len {
-                        app.edit_cursor_pos += 1;
+                        app.edit_cursor_pos = app.edit_next_boundary();
                     } else if current_line + 1 < lines.len() {
                         app.edit_cursor_pos += 1; // Skip newline
                     }
@@ -375,13 +371,12 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             } else if app.edit_field_index < app.edit_buffer.len() {
                 let field_len = app.edit_buffer[app.edit_field_index].chars().count();
                 if app.edit_cursor_pos < field_len {
-                    app.edit_cursor_pos += 1;
+                    app.edit_cursor_pos = app.edit_next_boundary();
                 }
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.vim_buffer.clear();
+        Action::Down => {
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
                 app.edit_cursor_pos = move_cursor_vertical(
@@ -393,8 +388,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.vim_buffer.clear();
+        Action::Up => {
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
                 app.edit_cursor_pos = move_cursor_vertical(
@@ -406,18 +400,18 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('0') => {
+        Action::LineStart => {
             app.edit_cursor_pos = 0;
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('$') => {
+        Action::LineEnd => {
             if app.edit_field_index < app.edit_buffer.len() {
                 let field_len = app.edit_buffer[app.edit_field_index].chars().count();
                 app.edit_cursor_pos = field_len;
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('w') => {
+        Action::WordForward => {
             // Move to next word (simplified: skip to next space)
             if app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
@@ -435,7 +429,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('b') => {
+        Action::WordBack => {
             // Move to previous word
             if app.edit_cursor_pos > 0 {
                 let field = &app.edit_buffer[app.edit_field_index];
@@ -453,7 +447,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('e') => {
+        Action::WordEnd => {
             // Move to end of current or next word
             if app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
@@ -473,39 +467,31 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
 
                     // Position on last character of word (not the space after)
                     if pos > 0 {
-                        app.edit_cursor_pos = pos - 1;
+                        app.edit_cursor_pos = graphemes::prev_boundary(field, pos);
                     }
                 }
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('g') => {
-            // Handle gg (go to start) - requires double press
-            if app.vim_buffer == "g" {
-                app.edit_cursor_pos = 0;
-                app.vim_buffer.clear();
-            } else {
-                app.vim_buffer = "g".to_string();
-            }
+        Action::Top => {
+            app.edit_cursor_pos = 0;
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('G') => {
+        Action::Bottom => {
             // Go to end
             if app.edit_field_index < app.edit_buffer.len() {
                 let field_len = app.edit_buffer[app.edit_field_index].chars().count();
                 app.edit_cursor_pos = field_len;
             }
-            app.vim_buffer.clear();
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('x') => {
+        Action::DeleteChar => {
             // Delete character at cursor
             if app.edit_field_index < app.edit_buffer.len() {
+                let end = app.edit_next_boundary();
                 let field = &mut app.edit_buffer[app.edit_field_index];
-                let mut chars: Vec<char> = field.chars().collect();
-                if app.edit_cursor_pos < chars.len() {
-                    chars.remove(app.edit_cursor_pos);
-                    *field = chars.into_iter().collect();
+                if app.edit_cursor_pos < field.chars().count() {
+                    *field = graphemes::remove_chars(field, app.edit_cursor_pos, end);
                     // Mark as no longer a placeholder if it was
                     if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
                         app.edit_buffer_is_placeholder[app.edit_field_index] = false;
@@ -514,15 +500,14 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('X') => {
+        Action::Backspace => {
             // Delete character before cursor
             if app.edit_field_index < app.edit_buffer.len() && app.edit_cursor_pos > 0 {
+                let start = app.edit_prev_boundary();
                 let field = &mut app.edit_buffer[app.edit_field_index];
-                let mut chars: Vec<char> = field.chars().collect();
-                if app.edit_cursor_pos > 0 && app.edit_cursor_pos <= chars.len() {
-                    chars.remove(app.edit_cursor_pos - 1);
-                    *field = chars.into_iter().collect();
-                    app.edit_cursor_pos -= 1;
+                if app.edit_cursor_pos <= field.chars().count() {
+                    *field = graphemes::remove_chars(field, start, app.edit_cursor_pos);
+                    app.edit_cursor_pos = start;
                     // Mark as no longer a placeholder if it was
                     if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
                         app.edit_buffer_is_placeholder[app.edit_field_index] = false;
@@ -531,7 +516,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('i') => {
+        Action::Insert => {
             // Enter insert mode (from normal mode within field)
             app.edit_insert_mode = true;
             // edit_skip_normal_mode stays false because we're already in normal mode
@@ -543,14 +528,14 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                 app.edit_cursor_pos = 0;
             }
         }
-        KeyCode::Char('a') => {
+        Action::Append => {
             // Append after cursor (like vim 'a')
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 let field = &app.edit_buffer[app.edit_field_index];
                 let field_len = field.chars().count();
                 // Move cursor right (if not at end)
                 if app.edit_cursor_pos < field_len {
-                    app.edit_cursor_pos += 1;
+                    app.edit_cursor_pos = app.edit_next_boundary();
                 }
             }
             app.edit_insert_mode = true;
@@ -561,7 +546,7 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                 app.edit_cursor_pos = 0;
             }
         }
-        KeyCode::Char('o') => {
+        Action::OpenLineBelow => {
             // Open line below (like vim 'o')
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
                 let field = &mut app.edit_buffer[app.edit_field_index];
@@ -585,8 +570,8 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
 
                 // Calculate position at end of current line
                 let mut end_of_line_pos = 0;
-                for i in 0..=current_line {
-                    let line_len = lines[i].chars().count();
+                for (i, line) in lines.iter().enumerate().take(current_line + 1) {
+                    let line_len = line.chars().count();
                     end_of_line_pos += line_len;
                     if i < current_line {
                         end_of_line_pos += 1; // newline
@@ -611,68 +596,13 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('d') => {
-            // dd: delete current line (in View Edit mode) - requires double press
-            if app.vim_buffer == "d" {
-                app.vim_buffer.clear();
-                if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
-                    let field = &mut app.edit_buffer[app.edit_field_index];
-                    let mut lines: Vec<String> = field.split('\n').map(|s| s.to_string()).collect();
-
-                    if lines.len() > 1 {
-                        // Find current line
-                        let mut char_count = 0;
-                        let mut current_line = 0;
-
-                        for (line_idx, line) in lines.iter().enumerate() {
-                            let line_len = line.chars().count();
-                            let separator_len = if line_idx < lines.len() - 1 { 1 } else { 0 };
-
-                            if app.edit_cursor_pos <= char_count + line_len {
-                                current_line = line_idx;
-                                break;
-                            }
-
-                            char_count += line_len + separator_len;
-                        }
-
-                        // Yank the line before deleting
-                        app.edit_yank_buffer = lines[current_line].clone();
-
-                        // Remove the line
-                        lines.remove(current_line);
-                        *field = lines.join("\n");
-
-                        // Adjust cursor position
-                        if current_line >= lines.len() {
-                            current_line = lines.len().saturating_sub(1);
-                        }
-
-                        // Move cursor to start of current line
-                        let mut new_pos = 0;
-                        for i in 0..current_line {
-                            new_pos += lines[i].chars().count() + 1;
-                        }
-                        app.edit_cursor_pos = new_pos;
-
-                        if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
-                            app.edit_buffer_is_placeholder[app.edit_field_index] = false;
-                        }
-                    }
-                }
-            } else {
-                app.vim_buffer = "d".to_string();
-            }
-            app.ensure_overlay_cursor_visible();
-        }
-        KeyCode::Char('y') => {
-            // yy: yank current line (in View Edit mode) - requires double press
-            if app.vim_buffer == "y" {
-                app.vim_buffer.clear();
-                if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
-                    let field = &app.edit_buffer[app.edit_field_index];
-                    let lines: Vec<&str> = field.split('\n').collect();
+        Action::DeleteLine => {
+            // dd: delete current line (in View Edit mode)
+            if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() {
+                let field = &mut app.edit_buffer[app.edit_field_index];
+                let mut lines: Vec<String> = field.split('\n').map(|s| s.to_string()).collect();
 
+                if lines.len() > 1 {
                     // Find current line
                     let mut char_count = 0;
                     let mut current_line = 0;
@@ -689,15 +619,57 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
                         char_count += line_len + separator_len;
                     }
 
-                    // Yank the line
-                    app.edit_yank_buffer = lines[current_line].to_string();
+                    // Yank the line before deleting
+                    app.edit_yank_buffer = lines[current_line].clone();
+
+                    // Remove the line
+                    lines.remove(current_line);
+                    *field = lines.join("\n");
+
+                    // Adjust cursor position
+                    if current_line >= lines.len() {
+                        current_line = lines.len().saturating_sub(1);
+                    }
+
+                    // Move cursor to start of current line
+                    let mut new_pos = 0;
+                    for line in lines.iter().take(current_line) {
+                        new_pos += line.chars().count() + 1;
+                    }
+                    app.edit_cursor_pos = new_pos;
+
+                    if app.edit_field_index < app.edit_buffer_is_placeholder.len() {
+                        app.edit_buffer_is_placeholder[app.edit_field_index] = false;
+                    }
+                }
+            }
+            app.ensure_overlay_cursor_visible();
+        }
+        // yy: yank current line (in View Edit mode)
+        Action::YankLine if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() => {
+            let field = &app.edit_buffer[app.edit_field_index];
+            let lines: Vec<&str> = field.split('\n').collect();
+
+            // Find current line
+            let mut char_count = 0;
+            let mut current_line = 0;
+
+            for (line_idx, line) in lines.iter().enumerate() {
+                let line_len = line.chars().count();
+                let separator_len = if line_idx < lines.len() - 1 { 1 } else { 0 };
+
+                if app.edit_cursor_pos <= char_count + line_len {
+                    current_line = line_idx;
+                    break;
                 }
-            } else {
-                app.vim_buffer = "y".to_string();
+
+                char_count += line_len + separator_len;
             }
+
+            // Yank the line
+            app.edit_yank_buffer = lines[current_line].to_string();
         }
-        KeyCode::Char('p') => {
-            app.vim_buffer.clear();
+        Action::PasteLine => {
             // p: paste yanked line below current line (in View Edit mode)
             if app.view_edit_mode && app.edit_field_index < app.edit_buffer.len() && !app.edit_yank_buffer.is_empty() {
                 let field = &mut app.edit_buffer[app.edit_field_index];
@@ -721,8 +693,8 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
 
                 // Calculate position at end of current line
                 let mut end_of_line_pos = 0;
-                for i in 0..=current_line {
-                    let line_len = lines[i].chars().count();
+                for (i, line) in lines.iter().enumerate().take(current_line + 1) {
+                    let line_len = line.chars().count();
                     end_of_line_pos += line_len;
                     if i < current_line {
                         end_of_line_pos += 1;
@@ -750,51 +722,62 @@ fn handle_field_editing_mode(app: &mut App, key: KeyEvent) {
             }
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('/') => {
+        Action::Search => {
             // Start search mode
             app.input_mode = crate::app::InputMode::Search;
             app.search_buffer = String::new();
             app.search_history_index = None;
             app.set_status("/");
         }
-        KeyCode::Char('n') => {
+        Action::CommandLine => {
+            // Command line for :s/old/new/ within this field
+            app.input_mode = crate::app::InputMode::Command;
+            app.command_buffer.clear();
+            app.command_history_index = None;
+            app.set_status(":");
+        }
+        Action::NextMatch => {
             app.overlay_next_match();
         }
-        KeyCode::Char('N') => {
+        Action::PrevMatch => {
             app.overlay_prev_match();
         }
+        Action::CheatSheet => {
+            app.toggle_cheatsheet();
+        }
         _ => {}
     }
 }
 
 fn handle_field_selection_mode(app: &mut App, key: KeyEvent) {
-    match key.code {
-        KeyCode::Esc | KeyCode::Char('q') => {
+    let Some(action) = app.resolve_key(KeyMode::Overlay, key) else {
+        return;
+    };
+    match action {
+        Action::Quit => {
             app.cancel_editing_entry();
         }
-        KeyCode::Char('w') => {
+        Action::SaveEntry => {
             app.save_edited_entry();
         }
-        KeyCode::Up | KeyCode::Char('k') => {
-            if app.edit_field_index > 0 {
-                app.edit_field_index -= 1;
-                app.edit_cursor_pos = 0;
-                app.edit_hscroll = 0;
-                app.edit_vscroll = 0;
-            }
+        Action::CheatSheet => {
+            app.toggle_cheatsheet();
         }
-        KeyCode::Down | KeyCode::Char('j') => {
-            if app.edit_field_index + 1 < app.edit_buffer.len() {
-                app.edit_field_index += 1;
-                app.edit_cursor_pos = 0;
-                app.edit_hscroll = 0;
-                app.edit_vscroll = 0;
-            }
+        Action::Up if app.edit_field_index > 0 => {
+            app.edit_field_index -= 1;
+            app.edit_cursor_pos = 0;
+            app.edit_hscroll = 0;
+            app.edit_vscroll = 0;
+        }
+        Action::Down if app.edit_field_index + 1 < app.edit_buffer.len() => {
+            app.edit_field_index += 1;
+            app.edit_cursor_pos = 0;
+            app.edit_hscroll = 0;
+            app.edit_vscroll = 0;
         }
-        KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('b') => {
-            // Check if this is context field (index 1)
-            let is_context_field = (app.edit_buffer.len() == 2 && app.edit_field_index == 1) ||
-                                   (app.edit_buffer.len() == 4 && app.edit_field_index == 1);
+        Action::ScrollCard(-1) => {
+            // Check if this is the context field
+            let is_context_field = app.edit_field_key(app.edit_field_index) == "context";
 
             if is_context_field {
                 // Vertical scroll up for context field
@@ -804,10 +787,9 @@ fn handle_field_selection_mode(app: &mut App, key: KeyEvent) {
                 app.edit_hscroll = app.edit_hscroll.saturating_sub(4);
             }
         }
-        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('f') => {
-            // Check if this is context field (index 1)
-            let is_context_field = (app.edit_buffer.len() == 2 && app.edit_field_index == 1) ||
-                                   (app.edit_buffer.len() == 4 && app.edit_field_index == 1);
+        Action::ScrollCard(1) => {
+            // Check if this is the context field
+            let is_context_field = app.edit_field_key(app.edit_field_index) == "context";
 
             if is_context_field {
                 // Vertical scroll down for context field
@@ -832,7 +814,7 @@ fn handle_field_selection_mode(app: &mut App, key: KeyEvent) {
                 }
             }
         }
-        KeyCode::Enter => {
+        Action::Select => {
             // Enter View Edit mode in Normal mode (renders \n as newlines)
             // Clear placeholder text when entering normal mode
             if app.edit_field_index < app.edit_buffer.len() {
@@ -854,7 +836,7 @@ fn handle_field_selection_mode(app: &mut App, key: KeyEvent) {
             // Ensure cursor is visible in the window
             app.ensure_overlay_cursor_visible();
         }
-        KeyCode::Char('i') => {
+        Action::Insert => {
             // Enter View Edit mode in Insert mode directly (renders \n as newlines)
             app.view_edit_mode = true;
             app.edit_field_editing_mode = true;
@@ -0,0 +1,84 @@
+//! Append-only journal files.
+//!
+//! A file is a journal when its JSON has `"journal": true` at the top level, or its
+//! Markdown starts with `journal: true` front matter. In a journal, the INSIDE entries
+//! a file was opened with are history: the UI may add new notes and reorder them, but
+//! any change that edits or deletes one of them is rolled back.
+
+use serde_json::Value;
+
+/// Top-level JSON key and front matter key of the flag
+pub const KEY: &str = "journal";
+
+/// Front matter written at the top of a journal's Markdown
+pub const FRONT_MATTER: &str = "---\njournal: true\n---";
+
+/// Whether a parsed document is flagged as a journal
+pub fn is_journal(value: &Value) -> bool {
+    value.get(KEY).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Front matter at the very top of a Markdown file: a `---` line, `key: value` lines and
+/// a closing `---`
+pub struct FrontMatter {
+    /// (1-based line, key, value)
+    pub pairs: Vec<(usize, String, String)>,
+    /// Lines the block spans, delimiters included
+    pub lines: usize,
+}
+
+/// The front matter `markdown` starts with, if any
+pub fn front_matter(markdown: &str) -> Option<FrontMatter> {
+    let mut lines = markdown.lines();
+    if lines.next()?.trim() != "---" {
+        return None;
+    }
+    let mut pairs = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line_no = i + 2;
+        let trimmed = line.trim();
+        if trimmed == "---" {
+            return Some(FrontMatter { pairs, lines: line_no });
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+        let (key, value) = trimmed.split_once(':').unwrap_or((trimmed, ""));
+        pairs.push((line_no, key.trim().to_string(), value.trim().to_string()));
+    }
+    // Never closed: not front matter
+    None
+}
+
+/// Whether Markdown front matter sets `journal: true`
+pub fn markdown_is_journal(markdown: &str) -> bool {
+    front_matter(markdown).is_some_and(|front| {
+        front.pairs.iter().any(|(_, key, value)| key == KEY && value == "true")
+    })
+}
+
+/// The front matter lines of `markdown` (with delimiters), empty when it has none
+pub fn front_matter_lines(markdown: &str) -> Vec<&str> {
+    match front_matter(markdown) {
+        Some(front) => markdown.lines().take(front.lines).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// INSIDE entries of a parsed document
+pub fn inside_entries(value: &Value) -> Vec<Value> {
+    value.get("inside").and_then(Value::as_array).cloned().unwrap_or_default()
+}
+
+/// The first `history` entry no longer found in `current`. Each current entry can stand
+/// for one history entry only, so new and reordered notes pass but edits and deletions don't.
+pub fn missing_entry<'a>(history: &'a [Value], current: &[Value]) -> Option<&'a Value> {
+    let mut unused: Vec<&Value> = current.iter().collect();
+    history.iter().find(|entry| match unused.iter().position(|candidate| candidate == entry) {
+        Some(pos) => {
+            unused.swap_remove(pos);
+            false
+        }
+        None => true,
+    })
+}
@@ -1,10 +1,44 @@
 use chrono::Local;
-use serde_json::Value;
+use serde_json::{Map, Value};
 use regex::RegexBuilder;
-use crate::content_ops::ContentOperations;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use crate::content_ops::{self, ContentOperations};
+use crate::due::{self, DueFilter};
+use crate::fields;
+use crate::journal;
+use crate::priority::{self, PriorityFilter};
+use crate::search::{SearchQuery, Searchable};
+use crate::status::{self, EntryStatus};
 
 pub struct JsonOperations;
 
+/// Longest name `:autoname` gives, in characters
+pub const AUTONAME_CHARS: usize = 60;
+
+/// How appended OUTSIDE entries whose URL already exists in the file are handled
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MergeStrategy {
+    /// Always add a new entry (may create duplicates)
+    #[default]
+    Append,
+    /// Refresh context/percentage of the existing entry in place
+    Update,
+    /// Keep the existing entry and drop the incoming one
+    Skip,
+}
+
+impl MergeStrategy {
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "append" => Some(MergeStrategy::Append),
+            "update" => Some(MergeStrategy::Update),
+            "skip" => Some(MergeStrategy::Skip),
+            _ => None,
+        }
+    }
+}
+
 impl JsonOperations {
     pub fn delete_entry_at_cursor(
         json_input: &str,
@@ -63,14 +97,12 @@ impl JsonOperations {
                             item_index += 1;
                         } else if i >= cursor_line {
                             // Cursor is in this object
-                            if let Some(ref key) = current_key {
-                                if let Some(arr) = obj.get_mut(key).and_then(|v| v.as_array_mut()) {
-                                    if item_index < arr.len() {
-                                        arr.remove(item_index);
-                                        deleted = true;
-                                        break;
-                                    }
-                                }
+                            if let Some(ref key) = current_key
+                                && let Some(arr) = obj.get_mut(key).and_then(|v| v.as_array_mut())
+                                    && item_index < arr.len() {
+                                arr.remove(item_index);
+                                deleted = true;
+                                break;
                             }
                         }
                         in_object = false;
@@ -181,11 +213,337 @@ impl JsonOperations {
         }
     }
 
+    /// Add an entry holding `text` without opening the overlay: a dated INSIDE note at the top
+    /// (newest first), or an OUTSIDE entry at the end with `text` as its URL when it is one and
+    /// as its name otherwise. Returns the formatted JSON and the original index of the new entry.
+    pub fn quick_add_entry(json_input: &str, section: &str, text: &str) -> Result<(String, usize), String> {
+        let mut json_value: Value = if json_input.trim().is_empty() {
+            serde_json::json!({ "outside": [], "inside": [] })
+        } else {
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?
+        };
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+
+        let (new_entry, local_idx) = if section == "inside" {
+            let entry = serde_json::json!({
+                "date": Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                "context": text
+            });
+            (entry, 0)
+        } else {
+            let is_url = text.starts_with("http://") || text.starts_with("https://");
+            let entry = serde_json::json!({
+                "name": if is_url { "" } else { text },
+                "context": "",
+                "url": if is_url { text } else { "" },
+                "percentage": null
+            });
+            (entry, outside_count)
+        };
+        obj.entry(section)
+            .or_insert(Value::Array(vec![]))
+            .as_array_mut()
+            .ok_or_else(|| format!("'{}' is not an array", section))?
+            .insert(local_idx, new_entry);
+
+        let new_index = if section == "inside" { outside_count } else { local_idx };
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, new_index))
+    }
+
+    /// Insert a blank entry next to the entry at `original_index` (outside entries first, then
+    /// inside), in the same section: after it if `after`, otherwise before it.
+    /// With no `original_index` (no cards yet) the entry is appended to OUTSIDE.
+    /// Returns the formatted JSON and the original index of the new entry.
+    pub fn insert_entry(json_input: &str, original_index: Option<usize>, after: bool) -> Result<(String, usize), String> {
+        let mut json_value: Value = if json_input.trim().is_empty() {
+            serde_json::json!({ "outside": [], "inside": [] })
+        } else {
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?
+        };
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+
+        let (section, local_idx) = match original_index {
+            Some(idx) if idx < outside_count => ("outside", idx + after as usize),
+            Some(idx) => ("inside", idx - outside_count + after as usize),
+            None => ("outside", outside_count),
+        };
+        let new_entry = if section == "outside" {
+            serde_json::json!({
+                "name": "",
+                "context": "",
+                "url": "",
+                "percentage": null
+            })
+        } else {
+            serde_json::json!({
+                "date": Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                "context": ""
+            })
+        };
+
+        let array = obj
+            .entry(section)
+            .or_insert(Value::Array(vec![]))
+            .as_array_mut()
+            .ok_or_else(|| format!("'{}' is not an array", section))?;
+        if local_idx > array.len() {
+            return Err("Entry not found".to_string());
+        }
+        array.insert(local_idx, new_entry);
+
+        let new_index = if section == "outside" { local_idx } else { outside_count + local_idx };
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, new_index))
+    }
+
+    /// Remove the entries at `original_indices` (outside entries first, then inside).
+    /// Returns the formatted JSON and the removed entries with their section names, in order.
+    pub fn remove_entries(json_input: &str, original_indices: &[usize]) -> Result<(String, Vec<(String, Value)>), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+
+        let mut indices = original_indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        let mut removed = Vec::with_capacity(indices.len());
+        // From the back so earlier indices stay valid
+        for &original_index in indices.iter().rev() {
+            let (section, local_idx) = if original_index < outside_count {
+                ("outside", original_index)
+            } else {
+                ("inside", original_index - outside_count)
+            };
+            let array = obj
+                .get_mut(section)
+                .and_then(|v| v.as_array_mut())
+                .filter(|arr| local_idx < arr.len())
+                .ok_or_else(|| "Entry not found".to_string())?;
+            removed.push((section.to_string(), array.remove(local_idx)));
+        }
+        removed.reverse();
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, removed))
+    }
+
+    /// Insert `entries` (section name and entry) next to the entry at `original_index`:
+    /// after it if `after`, otherwise before it. Entries of the other section go to the end of
+    /// OUTSIDE or the start of INSIDE, the place closest to that entry.
+    /// Returns the formatted JSON and the original index of the first inserted entry.
+    pub fn put_entries(
+        json_input: &str,
+        original_index: Option<usize>,
+        after: bool,
+        entries: &[(String, Value)],
+    ) -> Result<(String, usize), String> {
+        let mut json_value: Value = if json_input.trim().is_empty() {
+            serde_json::json!({ "outside": [], "inside": [] })
+        } else {
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?
+        };
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+
+        let target = original_index.map(|idx| {
+            if idx < outside_count {
+                ("outside", idx + after as usize)
+            } else {
+                ("inside", idx - outside_count + after as usize)
+            }
+        });
+        let outside: Vec<Value> = entries.iter().filter(|(s, _)| s == "outside").map(|(_, e)| e.clone()).collect();
+        let inside: Vec<Value> = entries.iter().filter(|(s, _)| s != "outside").map(|(_, e)| e.clone()).collect();
+
+        let outside_at = match target {
+            Some(("outside", local_idx)) => local_idx,
+            _ => outside_count,
+        };
+        let inside_at = match target {
+            Some(("inside", local_idx)) => local_idx,
+            _ => 0,
+        };
+        for (section, at, new_entries) in [("outside", outside_at, &outside), ("inside", inside_at, &inside)] {
+            if new_entries.is_empty() {
+                continue;
+            }
+            let array = obj
+                .entry(section)
+                .or_insert(Value::Array(vec![]))
+                .as_array_mut()
+                .ok_or_else(|| format!("'{}' is not an array", section))?;
+            if at > array.len() {
+                return Err("Entry not found".to_string());
+            }
+            array.splice(at..at, new_entries.iter().cloned());
+        }
+
+        let first = if outside.is_empty() {
+            outside_count + inside_at
+        } else {
+            outside_at
+        };
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, first))
+    }
+
+    /// Move the entry at `original_index` by `offset` places within its section, stopping at
+    /// the section's ends. Returns the formatted JSON and the entry's new original index.
+    pub fn move_entry(json_input: &str, original_index: usize, offset: isize) -> Result<(String, usize), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+        let (section, local_idx, base) = if original_index < outside_count {
+            ("outside", original_index, 0)
+        } else {
+            ("inside", original_index - outside_count, outside_count)
+        };
+
+        let array = obj
+            .get_mut(section)
+            .and_then(|v| v.as_array_mut())
+            .filter(|arr| local_idx < arr.len())
+            .ok_or_else(|| "Entry not found".to_string())?;
+        let target = local_idx.saturating_add_signed(offset).min(array.len() - 1);
+        let entry = array.remove(local_idx);
+        array.insert(target, entry);
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, base + target))
+    }
+
+    /// Non-empty fields of the entry at `original_index` that the other section has no place for
+    pub fn fields_lost_across_sections(json_input: &str, original_index: usize) -> Result<Vec<String>, String> {
+        let json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let outside_count = json_value.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+        let (section, local_idx) = if original_index < outside_count {
+            ("outside", original_index)
+        } else {
+            ("inside", original_index - outside_count)
+        };
+        let entry = json_value
+            .get(section)
+            .and_then(|v| v.get(local_idx))
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| "Entry not found".to_string())?;
+        let target = fields::standard_fields(if section == "outside" { "inside" } else { "outside" });
+
+        Ok(fields::standard_fields(section)
+            .iter()
+            .filter(|key| !target.contains(key))
+            .filter(|key| match entry.get(**key) {
+                None | Some(Value::Null) => false,
+                Some(Value::String(s)) => !s.trim().is_empty(),
+                Some(_) => true,
+            })
+            .map(|key| key.to_string())
+            .collect())
+    }
+
+    /// Move the entry at `original_index` to the other section: OUTSIDE entries become the first
+    /// INSIDE entry (dated now), INSIDE entries the last OUTSIDE entry. Standard fields the other
+    /// section has no place for are appended to the context as `key: value` lines if `fold`,
+    /// otherwise dropped; custom fields are kept. Returns the formatted JSON and the new original index.
+    pub fn move_entry_across(json_input: &str, original_index: usize, fold: bool) -> Result<(String, usize), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+        let (from, to, local_idx) = if original_index < outside_count {
+            ("outside", "inside", original_index)
+        } else {
+            ("inside", "outside", original_index - outside_count)
+        };
+
+        let entry = obj
+            .get_mut(from)
+            .and_then(|v| v.as_array_mut())
+            .filter(|arr| local_idx < arr.len())
+            .map(|arr| arr.remove(local_idx))
+            .ok_or_else(|| "Entry not found".to_string())?;
+        let Value::Object(mut entry) = entry else {
+            return Err("Entry is not an object".to_string());
+        };
+
+        let target_fields = fields::standard_fields(to);
+        let mut folded = Vec::new();
+        for key in fields::standard_fields(from) {
+            if target_fields.contains(key) {
+                continue;
+            }
+            match entry.remove(*key) {
+                None | Some(Value::Null) => {}
+                Some(Value::String(s)) if s.trim().is_empty() => {}
+                Some(value) => folded.push(format!("{}: {}", key, fields::display_value(&value))),
+            }
+        }
+
+        let mut context = entry.remove("context").and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default();
+        if fold && !folded.is_empty() {
+            if !context.is_empty() {
+                context.push_str("\n\n");
+            }
+            context.push_str(&folded.join("\n"));
+        }
+
+        let mut converted = Map::new();
+        if to == "inside" {
+            converted.insert("date".to_string(), Value::String(Local::now().format("%Y-%m-%d %H:%M:%S").to_string()));
+            converted.insert("context".to_string(), Value::String(context));
+        } else {
+            converted.insert("name".to_string(), Value::String(String::new()));
+            converted.insert("context".to_string(), Value::String(context));
+            converted.insert("url".to_string(), Value::String(String::new()));
+            converted.insert("percentage".to_string(), Value::Null);
+        }
+        converted.extend(entry);
+
+        let target = obj
+            .entry(to)
+            .or_insert(Value::Array(vec![]))
+            .as_array_mut()
+            .ok_or_else(|| format!("'{}' is not an array", to))?;
+        let new_index = if to == "inside" {
+            target.insert(0, Value::Object(converted));
+            outside_count - 1
+        } else {
+            target.push(Value::Object(converted));
+            target.len() - 1
+        };
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, new_index))
+    }
+
     pub fn duplicate_entry_at_cursor(
         json_input: &str,
         cursor_line: usize,
         lines: &[String],
-    ) -> Result<(String, String), String> {
+    ) -> Result<String, String> {
         let mut json_value: Value =
             serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
 
@@ -238,15 +596,13 @@ impl JsonOperations {
                             item_index += 1;
                         } else if i >= cursor_line {
                             // Cursor is in this object
-                            if let Some(ref key) = current_key {
-                                if let Some(arr) = obj.get_mut(key).and_then(|v| v.as_array_mut()) {
-                                    if item_index < arr.len() {
-                                        let entry_clone = arr[item_index].clone();
-                                        arr.insert(item_index + 1, entry_clone);
-                                        duplicated = true;
-                                        break;
-                                    }
-                                }
+                            if let Some(ref key) = current_key
+                                && let Some(arr) = obj.get_mut(key).and_then(|v| v.as_array_mut())
+                                    && item_index < arr.len() {
+                                let entry_clone = arr[item_index].clone();
+                                arr.insert(item_index + 1, entry_clone);
+                                duplicated = true;
+                                break;
                             }
                         }
                         in_object = false;
@@ -258,7 +614,7 @@ impl JsonOperations {
         if duplicated {
             let formatted = serde_json::to_string_pretty(&json_value)
                 .map_err(|e| format!("Failed to format JSON: {}", e))?;
-            Ok((formatted, "Entry duplicated".to_string()))
+            Ok(formatted)
         } else {
             Err("Could not duplicate entry at cursor position".to_string())
         }
@@ -296,7 +652,7 @@ impl JsonOperations {
                         .unwrap_or("");
 
                     // First by percentage (descending), then by name (ascending)
-                    b_percent.cmp(&a_percent).then_with(|| a_name.cmp(&b_name))
+                    b_percent.cmp(&a_percent).then_with(|| a_name.cmp(b_name))
                 });
                 messages.push("Ordered outside entries");
             }
@@ -314,7 +670,7 @@ impl JsonOperations {
                         .and_then(|o| o.get("date"))
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
-                    b_date.cmp(&a_date) // Descending order (newest first)
+                    b_date.cmp(a_date) // Descending order (newest first)
                 });
                 messages.push("Ordered inside entries");
             }
@@ -372,7 +728,7 @@ impl JsonOperations {
                         .and_then(|o| o.get("date"))
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
-                    b_date.cmp(&a_date) // Descending order (newest first)
+                    b_date.cmp(a_date) // Descending order (newest first)
                 });
                 messages.push("Ordered inside entries by date");
             }
@@ -390,6 +746,88 @@ impl JsonOperations {
         Ok((formatted, message.to_string()))
     }
 
+    pub fn order_by_priority(json_input: &str) -> Result<(String, String), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        let mut messages = Vec::new();
+
+        if let Some(obj) = json_value.as_object_mut() {
+            // Order outside entries by priority (highest first, entries without one last)
+            if let Some(outside_array) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+                outside_array.sort_by_key(|item| {
+                    std::cmp::Reverse(item.get("priority").and_then(priority::rank).unwrap_or(0))
+                });
+                messages.push("Ordered outside entries by priority");
+            }
+
+            // Order inside entries by date (newest first)
+            if let Some(inside_array) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+                inside_array.sort_by(|a, b| {
+                    let a_date = a
+                        .as_object()
+                        .and_then(|o| o.get("date"))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("");
+                    let b_date = b
+                        .as_object()
+                        .and_then(|o| o.get("date"))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("");
+                    b_date.cmp(a_date) // Descending order (newest first)
+                });
+                messages.push("Ordered inside entries by date");
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Failed to format JSON: {}", e))?;
+
+        let message = if messages.is_empty() {
+            "No entries"
+        } else {
+            "Ordered by priority"
+        };
+
+        Ok((formatted, message.to_string()))
+    }
+
+    pub fn order_by_due(json_input: &str) -> Result<(String, String), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        let mut messages = Vec::new();
+
+        if let Some(obj) = json_value.as_object_mut() {
+            // Order outside entries by due date (soonest first, entries without one last)
+            if let Some(outside_array) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+                outside_array.sort_by_key(|item| due::of(item.get("due")).map_or((1, None), |date| (0, Some(date))));
+                messages.push("Ordered outside entries by due date");
+            }
+
+            // Order inside entries by date (newest first)
+            if let Some(inside_array) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+                inside_array.sort_by(|a, b| {
+                    let a_date = a.get("date").and_then(|v| v.as_str()).unwrap_or("");
+                    let b_date = b.get("date").and_then(|v| v.as_str()).unwrap_or("");
+                    b_date.cmp(a_date) // Descending order (newest first)
+                });
+                messages.push("Ordered inside entries by date");
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Failed to format JSON: {}", e))?;
+
+        let message = if messages.is_empty() {
+            "No entries"
+        } else {
+            "Ordered by due date"
+        };
+
+        Ok((formatted, message.to_string()))
+    }
+
     pub fn order_by_name(json_input: &str) -> Result<(String, String), String> {
         let mut json_value: Value =
             serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
@@ -412,7 +850,7 @@ impl JsonOperations {
                         .unwrap_or("");
 
                     // Order by name ascending
-                    a_name.cmp(&b_name)
+                    a_name.cmp(b_name)
                 });
                 messages.push("Ordered outside entries by name");
             }
@@ -430,7 +868,7 @@ impl JsonOperations {
                         .and_then(|o| o.get("date"))
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
-                    b_date.cmp(&a_date) // Descending order (newest first)
+                    b_date.cmp(a_date) // Descending order (newest first)
                 });
                 messages.push("Ordered inside entries by date");
             }
@@ -477,7 +915,7 @@ impl JsonOperations {
                         .and_then(|o| o.get("date"))
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
-                    b_date.cmp(&a_date) // Descending order (newest first)
+                    b_date.cmp(a_date) // Descending order (newest first)
                 });
                 messages.push("Ordered inside entries by date");
             }
@@ -494,6 +932,43 @@ impl JsonOperations {
 
         Ok((formatted, message.to_string()))
     }
+
+    /// Set the percentage of every OUTSIDE entry whose context has task checkboxes to the
+    /// checked ratio. Returns the new JSON and the number of entries that changed.
+    pub fn apply_checkbox_percentages(json_input: &str) -> Result<(String, usize), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        let mut updated = 0;
+        if let Some(outside_array) = json_value.get_mut("outside").and_then(|v| v.as_array_mut()) {
+            for entry_obj in outside_array.iter_mut().filter_map(|v| v.as_object_mut()) {
+                if Self::apply_checkbox_percentage(entry_obj) {
+                    updated += 1;
+                }
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Failed to format JSON: {}", e))?;
+        Ok((formatted, updated))
+    }
+
+    /// Set one entry's percentage from its context checkboxes; true when it changed
+    pub fn apply_checkbox_percentage(entry_obj: &mut serde_json::Map<String, Value>) -> bool {
+        let Some(pct) = entry_obj
+            .get("context")
+            .and_then(|v| v.as_str())
+            .and_then(content_ops::checkbox_percentage)
+        else {
+            return false;
+        };
+        if entry_obj.get("percentage").and_then(|v| v.as_i64()) == Some(pct) {
+            return false;
+        }
+        entry_obj.insert("percentage".to_string(), Value::Number(pct.into()));
+        true
+    }
+
     /// Expand entries: produce one entry per match in `context`, each with that snippet.
     pub fn trim_context_around_match(json_value: &Value, pattern: &str, chars: usize) -> Value {
         if pattern.is_empty() {
@@ -564,6 +1039,51 @@ impl JsonOperations {
             return json_value.clone();
         }
 
+        // `priority>=high` keeps OUTSIDE entries whose priority compares true, and no INSIDE ones
+        if let Some(filter) = PriorityFilter::parse(pattern) {
+            let mut result = json_value.clone();
+            if let Some(obj) = result.as_object_mut() {
+                if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+                    outside.retain(|item| filter.matches(item.get("priority").and_then(priority::rank)));
+                }
+                if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+                    inside.clear();
+                }
+            }
+            return result;
+        }
+
+        // `due<=7` keeps OUTSIDE entries due within that many days, and no INSIDE ones
+        if let Some(filter) = DueFilter::parse(pattern) {
+            let today = Local::now().date_naive();
+            let mut result = json_value.clone();
+            if let Some(obj) = result.as_object_mut() {
+                if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+                    outside.retain(|item| filter.matches(due::of(item.get("due")), today));
+                }
+                if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+                    inside.clear();
+                }
+            }
+            return result;
+        }
+
+        // `name:rust url:github` limits terms to fields; every term has to match
+        if let Some(query) = SearchQuery::parse(pattern) {
+            let mut result = json_value.clone();
+            if let Some(obj) = result.as_object_mut() {
+                for section in ["outside", "inside"] {
+                    if let Some(entries) = obj.get_mut(section).and_then(|v| v.as_array_mut()) {
+                        entries.retain(|item| {
+                            item.as_object()
+                                .is_some_and(|item_obj| query.matches(&Searchable::from_object(item_obj, section)))
+                        });
+                    }
+                }
+            }
+            return result;
+        }
+
         let re = RegexBuilder::new(pattern)
             .case_insensitive(true)
             .build()
@@ -630,16 +1150,15 @@ impl JsonOperations {
     pub fn delete_outside_by_name(json_value: &Value, pattern: &str) -> Value {
         let re = Self::build_re(pattern);
         let mut result = json_value.clone();
-        if let Some(obj) = result.as_object_mut() {
-            if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
-                outside.retain(|item| {
-                    let name = item.as_object()
-                        .and_then(|o| o.get("name"))
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("");
-                    !re.is_match(name)
-                });
-            }
+        if let Some(obj) = result.as_object_mut()
+            && let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+            outside.retain(|item| {
+                let name = item.as_object()
+                    .and_then(|o| o.get("name"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                !re.is_match(name)
+            });
         }
         result
     }
@@ -648,16 +1167,15 @@ impl JsonOperations {
     pub fn delete_outside_by_context(json_value: &Value, pattern: &str) -> Value {
         let re = Self::build_re(pattern);
         let mut result = json_value.clone();
-        if let Some(obj) = result.as_object_mut() {
-            if let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
-                outside.retain(|item| {
-                    let context = item.as_object()
-                        .and_then(|o| o.get("context"))
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("");
-                    !re.is_match(context)
-                });
-            }
+        if let Some(obj) = result.as_object_mut()
+            && let Some(outside) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+            outside.retain(|item| {
+                let context = item.as_object()
+                    .and_then(|o| o.get("context"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                !re.is_match(context)
+            });
         }
         result
     }
@@ -666,16 +1184,15 @@ impl JsonOperations {
     pub fn delete_inside_by_date(json_value: &Value, pattern: &str) -> Value {
         let re = Self::build_re(pattern);
         let mut result = json_value.clone();
-        if let Some(obj) = result.as_object_mut() {
-            if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
-                inside.retain(|item| {
-                    let date = item.as_object()
-                        .and_then(|o| o.get("date"))
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("");
-                    !re.is_match(date)
-                });
-            }
+        if let Some(obj) = result.as_object_mut()
+            && let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+            inside.retain(|item| {
+                let date = item.as_object()
+                    .and_then(|o| o.get("date"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                !re.is_match(date)
+            });
         }
         result
     }
@@ -684,16 +1201,15 @@ impl JsonOperations {
     pub fn delete_inside_by_context(json_value: &Value, pattern: &str) -> Value {
         let re = Self::build_re(pattern);
         let mut result = json_value.clone();
-        if let Some(obj) = result.as_object_mut() {
-            if let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
-                inside.retain(|item| {
-                    let context = item.as_object()
-                        .and_then(|o| o.get("context"))
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("");
-                    !re.is_match(context)
-                });
-            }
+        if let Some(obj) = result.as_object_mut()
+            && let Some(inside) = obj.get_mut("inside").and_then(|v| v.as_array_mut()) {
+            inside.retain(|item| {
+                let context = item.as_object()
+                    .and_then(|o| o.get("context"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                !re.is_match(context)
+            });
         }
         result
     }
@@ -701,28 +1217,65 @@ impl JsonOperations {
     /// Append entries from new_json into current_json.
     /// inside_only/outside_only control which sections are merged.
     /// Inside entries are prepended (newest first); outside entries are appended.
+    #[allow(dead_code)] // library API; the CLI goes through append_entries_with
     pub fn append_entries(current_json: &Value, new_json: &Value, inside_only: bool, outside_only: bool) -> Value {
+        Self::append_entries_with(current_json, new_json, inside_only, outside_only, MergeStrategy::Append)
+    }
+
+    /// Index of the OUTSIDE entry whose (non-empty) URL equals `url`
+    pub fn find_outside_by_url(outside: &[Value], url: &str) -> Option<usize> {
+        let url = url.trim();
+        if url.is_empty() {
+            return None;
+        }
+        outside.iter().position(|entry| {
+            entry.get("url").and_then(|v| v.as_str()).map(|u| u.trim()) == Some(url)
+        })
+    }
+
+    /// Refresh context and percentage of an existing OUTSIDE entry from an incoming one
+    pub fn refresh_outside_entry(existing: &mut Value, incoming: &Value) {
+        if let (Some(dst), Some(src)) = (existing.as_object_mut(), incoming.as_object()) {
+            for key in ["context", "percentage"] {
+                if let Some(value) = src.get(key) {
+                    dst.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+    }
+
+    /// Like `append_entries`, but OUTSIDE entries whose URL already exists follow `strategy`
+    pub fn append_entries_with(
+        current_json: &Value,
+        new_json: &Value,
+        inside_only: bool,
+        outside_only: bool,
+        strategy: MergeStrategy,
+    ) -> Value {
         let mut result = current_json.clone();
         let both = !inside_only && !outside_only;
 
         if let Some(obj) = result.as_object_mut() {
-            if inside_only || both {
-                if let Some(new_inside) = new_json.get("inside").and_then(|v| v.as_array()) {
-                    let inside_arr = obj.entry("inside".to_string()).or_insert(Value::Array(vec![]));
-                    if let Some(arr) = inside_arr.as_array_mut() {
-                        for (i, item) in new_inside.iter().enumerate() {
-                            arr.insert(i, item.clone());
-                        }
+            if (inside_only || both)
+                && let Some(new_inside) = new_json.get("inside").and_then(|v| v.as_array()) {
+                let inside_arr = obj.entry("inside".to_string()).or_insert(Value::Array(vec![]));
+                if let Some(arr) = inside_arr.as_array_mut() {
+                    for (i, item) in new_inside.iter().enumerate() {
+                        arr.insert(i, item.clone());
                     }
                 }
             }
 
-            if outside_only || both {
-                if let Some(new_outside) = new_json.get("outside").and_then(|v| v.as_array()) {
-                    let outside_arr = obj.entry("outside".to_string()).or_insert(Value::Array(vec![]));
-                    if let Some(arr) = outside_arr.as_array_mut() {
-                        for item in new_outside {
-                            arr.push(item.clone());
+            if (outside_only || both)
+                && let Some(new_outside) = new_json.get("outside").and_then(|v| v.as_array()) {
+                let outside_arr = obj.entry("outside".to_string()).or_insert(Value::Array(vec![]));
+                if let Some(arr) = outside_arr.as_array_mut() {
+                    for item in new_outside {
+                        let url = item.get("url").and_then(|v| v.as_str()).unwrap_or("");
+                        match (strategy, Self::find_outside_by_url(arr, url)) {
+                            (MergeStrategy::Update, Some(idx)) => Self::refresh_outside_entry(&mut arr[idx], item),
+                            (MergeStrategy::Skip, Some(_)) => {}
+                            _ => arr.push(item.clone()),
                         }
                     }
                 }
@@ -731,6 +1284,355 @@ impl JsonOperations {
 
         result
     }
+
+    /// Apply `f` to each entry at `original_indices` (OUTSIDE entries first, then INSIDE) in one pass.
+    /// `f` gets the entry and its section name and returns whether it changed the entry.
+    /// Returns the re-formatted JSON and the number of changed entries.
+    pub fn modify_entries<F>(json_input: &str, original_indices: &[usize], mut f: F) -> Result<(String, usize), String>
+    where
+        F: FnMut(&mut Map<String, Value>, &str) -> bool,
+    {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+        let outside_count = obj.get("outside").and_then(|v| v.as_array()).map_or(0, |arr| arr.len());
+
+        let mut changed = 0;
+        for &original_index in original_indices {
+            let (section, local_idx) = if original_index < outside_count {
+                ("outside", original_index)
+            } else {
+                ("inside", original_index - outside_count)
+            };
+            let entry = obj
+                .get_mut(section)
+                .and_then(|v| v.as_array_mut())
+                .and_then(|arr| arr.get_mut(local_idx))
+                .and_then(|v| v.as_object_mut());
+            if let Some(entry) = entry
+                && f(entry, section) {
+                changed += 1;
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, changed))
+    }
+
+    /// Rewrite `field` in every entry of both sections that has it.
+    /// `f` gets the current value and returns the replacement, or `None` to leave it alone.
+    /// Returns the re-formatted JSON and the number of changed entries.
+    pub fn transform_field<F>(json_input: &str, field: &str, mut f: F) -> Result<(String, usize), String>
+    where
+        F: FnMut(&Value) -> Option<Value>,
+    {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+
+        let mut changed = 0;
+        for section in ["outside", "inside"] {
+            let Some(entries) = obj.get_mut(section).and_then(|v| v.as_array_mut()) else {
+                continue;
+            };
+            for entry in entries.iter_mut().filter_map(|v| v.as_object_mut()) {
+                let Some(new_value) = entry.get(field).and_then(&mut f) else {
+                    continue;
+                };
+                if entry.get(field) == Some(&new_value) {
+                    continue;
+                }
+                entry.insert(field.to_string(), new_value);
+                changed += 1;
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, changed))
+    }
+
+    /// Name for an unnamed entry: the first non-empty line of its context, cut to
+    /// AUTONAME_CHARS characters with `...`
+    pub fn name_from_context(context: &str) -> Option<String> {
+        let line = context.lines().map(str::trim).find(|line| !line.is_empty())?;
+        if line.chars().count() <= AUTONAME_CHARS {
+            return Some(line.to_string());
+        }
+        let cut: String = line.chars().take(AUTONAME_CHARS - 3).collect();
+        Some(format!("{}...", cut.trim_end()))
+    }
+
+    /// Fill the empty `name` of each OUTSIDE entry from its context (`:autoname`).
+    /// Returns the re-formatted JSON and the names given, in entry order.
+    pub fn autoname(json_input: &str) -> Result<(String, Vec<String>), String> {
+        let mut json_value: Value =
+            serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object_mut()
+            .ok_or_else(|| "Current JSON is not an object".to_string())?;
+
+        let mut names = Vec::new();
+        if let Some(entries) = obj.get_mut("outside").and_then(|v| v.as_array_mut()) {
+            for entry in entries.iter_mut().filter_map(|v| v.as_object_mut()) {
+                let unnamed = entry.get("name").is_none_or(|name| name.is_null() || name.as_str().is_some_and(|s| s.trim().is_empty()));
+                if !unnamed {
+                    continue;
+                }
+                let Some(name) = entry.get("context").and_then(|v| v.as_str()).and_then(Self::name_from_context) else {
+                    continue;
+                };
+                entry.insert("name".to_string(), Value::String(name.clone()));
+                names.push(name);
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("Format error: {}", e))?;
+        Ok((formatted, names))
+    }
+
+    /// Three-way merge of a file that changed on disk with the buffer's version of it, against
+    /// `base`, the file as it was last loaded or saved (None when unknown: nothing counts as
+    /// deleted then). Entries are matched whole, so an entry edited on one side is that side's
+    /// deletion plus addition:
+    /// - entries the buffer deleted leave the disk version too, other disk entries stay
+    /// - an entry the buffer edited takes the place of the one it replaced (same URL for
+    ///   OUTSIDE, date for INSIDE) if that is still on disk
+    /// - other buffer entries are added (INSIDE first, OUTSIDE last), once if disk added
+    ///   the same entry
+    ///
+    /// An entry edited on both sides comes out twice, once per version.
+    pub fn merge_concurrent(base: Option<&Value>, disk: &Value, ours: &Value) -> Value {
+        let (Some(disk_obj), Some(ours_obj)) = (disk.as_object(), ours.as_object()) else {
+            return ours.clone();
+        };
+        let base_obj = base.and_then(Value::as_object);
+        let base_of = |key: &str| base_obj.and_then(|obj| obj.get(key));
+
+        let mut result = disk_obj.clone();
+        for (key, ours_value) in ours_obj {
+            let merged = match (disk_obj.get(key), ours_value.as_array()) {
+                (Some(Value::Array(disk_entries)), Some(ours_entries)) => {
+                    let base_entries = base_of(key).and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+                    Value::Array(Self::merge_entries(key, base_entries, disk_entries, ours_entries))
+                }
+                // Keys other than the sections: whichever side changed them
+                (Some(disk_value), _) if base_of(key) == Some(ours_value) => disk_value.clone(),
+                _ => ours_value.clone(),
+            };
+            result.insert(key.clone(), merged);
+        }
+        for (key, base_value) in base_obj.into_iter().flatten() {
+            if !ours_obj.contains_key(key) && disk_obj.get(key) == Some(base_value) {
+                result.remove(key);
+            }
+        }
+        Value::Object(result)
+    }
+
+    fn merge_entries(section: &str, base: &[Value], disk: &[Value], ours: &[Value]) -> Vec<Value> {
+        fn counts(entries: &[Value]) -> HashMap<String, usize> {
+            let mut counts = HashMap::new();
+            for entry in entries {
+                *counts.entry(entry.to_string()).or_insert(0) += 1;
+            }
+            counts
+        }
+        fn take(counts: &mut HashMap<String, usize>, entry: &str) -> bool {
+            match counts.get_mut(entry) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            }
+        }
+        let key_field = if section == "inside" { "date" } else { "url" };
+        let key_of = |entry: &Value| entry.get(key_field).and_then(Value::as_str).map(str::trim).unwrap_or("").to_string();
+
+        // Base entries the buffer no longer has, and buffer entries the base did not have
+        let mut dropped = counts(base);
+        let added: Vec<&Value> = ours.iter().filter(|entry| !take(&mut dropped, &entry.to_string())).collect();
+        let mut left = dropped.clone();
+        let unpaired: Vec<&Value> = base.iter().filter(|entry| take(&mut left, &entry.to_string())).collect();
+
+        // A dropped entry was edited into an added one with its key, the most alike when
+        // several share it
+        let mut fresh = added;
+        let mut edits: Vec<(String, &Value)> = Vec::new();
+        for old in unpaired {
+            let key = key_of(old);
+            let text = old.to_string();
+            let alike = |entry: &Value| text.bytes().zip(entry.to_string().bytes()).take_while(|(a, b)| a == b).count();
+            let best = fresh
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| !key.is_empty() && key_of(entry) == key)
+                .max_by_key(|&(i, entry)| (alike(entry), Reverse(i)))
+                .map(|(i, _)| i);
+            if let Some(idx) = best {
+                edits.push((text, fresh.remove(idx)));
+            }
+        }
+
+        // Entries disk added since the base, so one the buffer added as well is kept once
+        let mut from_base = counts(base);
+        let mut disk_added = HashMap::new();
+        for entry in disk {
+            let text = entry.to_string();
+            if !take(&mut from_base, &text) {
+                *disk_added.entry(text).or_insert(0) += 1;
+            }
+        }
+
+        let mut merged = Vec::with_capacity(disk.len() + fresh.len());
+        for entry in disk {
+            let text = entry.to_string();
+            if !take(&mut dropped, &text) {
+                merged.push(entry.clone());
+            } else if let Some(idx) = edits.iter().position(|(old, _)| *old == text) {
+                merged.push(edits.remove(idx).1.clone());
+            }
+        }
+        // Edits of entries that changed or went away on disk as well
+        fresh.extend(edits.into_iter().map(|(_, entry)| entry));
+        let fresh: Vec<Value> = fresh
+            .into_iter()
+            .filter(|entry| !take(&mut disk_added, &entry.to_string()))
+            .cloned()
+            .collect();
+        if section == "inside" {
+            merged.splice(0..0, fresh);
+        } else {
+            merged.extend(fresh);
+        }
+        merged
+    }
+
+    /// Strict structure check: only known sections and fields with the expected types.
+    /// Errors point at the offending entry, e.g. `outside[2]: unknown field "isbn"`.
+    pub fn validate_strict(json_input: &str) -> Result<(), String> {
+        let json_value: Value = serde_json::from_str(json_input)
+            .map_err(|e| format!("Invalid JSON: {}", e))?;
+        let obj = json_value
+            .as_object()
+            .ok_or_else(|| "Top level must be an object".to_string())?;
+
+        for (key, section) in obj {
+            if key == journal::KEY {
+                if !section.is_boolean() {
+                    return Err(format!("\"{}\" must be true or false", key));
+                }
+                continue;
+            }
+            let fields: &[&str] = match key.as_str() {
+                "outside" => fields::OUTSIDE_FIELDS,
+                "inside" => fields::INSIDE_FIELDS,
+                _ => return Err(format!("Unknown section \"{}\"", key)),
+            };
+            let entries = section
+                .as_array()
+                .ok_or_else(|| format!("\"{}\" must be an array", key))?;
+
+            for (i, entry) in entries.iter().enumerate() {
+                let entry_obj = entry
+                    .as_object()
+                    .ok_or_else(|| format!("{}[{}]: entry must be an object", key, i))?;
+
+                for (field, value) in entry_obj {
+                    // status is stored like a custom field but has a fixed set of values
+                    let is_status = key == "outside" && field == status::KEY;
+                    if !fields.contains(&field.as_str()) && !is_status {
+                        return Err(format!("{}[{}]: unknown field \"{}\"", key, i, field));
+                    }
+                    let type_ok = match field.as_str() {
+                        "url" => value.is_string() || value.is_null(),
+                        "percentage" => value.is_i64() || value.is_null(),
+                        "priority" => priority::rank(value).is_some(),
+                        "due" => value.as_str().and_then(due::parse).is_some(),
+                        _ if is_status => value.as_str().and_then(EntryStatus::by_name).is_some(),
+                        "attachments" | "tags" => value
+                            .as_array()
+                            .is_some_and(|arr| arr.iter().all(|v| v.is_string())),
+                        _ => value.is_string(),
+                    };
+                    if !type_ok {
+                        return Err(format!("{}[{}]: field \"{}\" has the wrong type", key, i, field));
+                    }
+                }
+
+                let required = if key == "outside" { "name" } else { "date" };
+                if !entry_obj.contains_key(required) {
+                    return Err(format!("{}[{}]: missing field \"{}\"", key, i, required));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Canonical form used by `format_on_save`: `outside` before `inside`, standard fields
+    /// in a fixed order (name, context, url, percentage, ...) followed by custom fields in
+    /// file order, 2-space indent and a trailing newline.
+    pub fn to_canonical(json_input: &str) -> Result<String, String> {
+        let json_value: Value = serde_json::from_str(json_input)
+            .map_err(|e| format!("Invalid JSON: {}", e))?;
+        let Some(obj) = json_value.as_object() else {
+            let formatted = serde_json::to_string_pretty(&json_value)
+                .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+            return Ok(formatted + "\n");
+        };
+
+        let mut canonical = serde_json::Map::new();
+        for section in ["outside", "inside"] {
+            if let Some(value) = obj.get(section) {
+                canonical.insert(section.to_string(), Self::canonical_section(value, section));
+            }
+        }
+        for (key, value) in obj {
+            if !canonical.contains_key(key) {
+                canonical.insert(key.clone(), value.clone());
+            }
+        }
+
+        let formatted = serde_json::to_string_pretty(&Value::Object(canonical))
+            .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
+        Ok(formatted + "\n")
+    }
+
+    fn canonical_section(section_value: &Value, section: &str) -> Value {
+        let Some(entries) = section_value.as_array() else {
+            return section_value.clone();
+        };
+        let standard = fields::standard_fields(section);
+
+        Value::Array(
+            entries
+                .iter()
+                .map(|entry| {
+                    let Some(entry_obj) = entry.as_object() else {
+                        return entry.clone();
+                    };
+                    let mut ordered = serde_json::Map::new();
+                    for key in standard {
+                        if let Some(value) = entry_obj.get(*key) {
+                            ordered.insert(key.to_string(), value.clone());
+                        }
+                    }
+                    for (key, value) in fields::custom_fields(entry_obj, section) {
+                        ordered.insert(key.clone(), value.clone());
+                    }
+                    Value::Object(ordered)
+                })
+                .collect(),
+        )
+    }
 }
 
 // Implement ContentOperations trait for JsonOperations
@@ -757,7 +1659,7 @@ impl ContentOperations for JsonOperations {
         content: &str,
         cursor_line: usize,
         lines: &[String],
-    ) -> Result<(String, String), String> {
+    ) -> Result<String, String> {
         JsonOperations::duplicate_entry_at_cursor(content, cursor_line, lines)
     }
 
@@ -769,6 +1671,14 @@ impl ContentOperations for JsonOperations {
         JsonOperations::order_by_percentage(content)
     }
 
+    fn order_by_priority(&self, content: &str) -> Result<(String, String), String> {
+        JsonOperations::order_by_priority(content)
+    }
+
+    fn order_by_due(&self, content: &str) -> Result<(String, String), String> {
+        JsonOperations::order_by_due(content)
+    }
+
     fn order_by_name(&self, content: &str) -> Result<(String, String), String> {
         JsonOperations::order_by_name(content)
     }
@@ -776,4 +1686,8 @@ impl ContentOperations for JsonOperations {
     fn order_random(&self, content: &str) -> Result<(String, String), String> {
         JsonOperations::order_random(content)
     }
+
+    fn validate_strict(&self, content: &str) -> Result<(), String> {
+        JsonOperations::validate_strict(content)
+    }
 }
@@ -0,0 +1,109 @@
+//! Path of the JSON value under the Edit-mode cursor, e.g. `outside[3].context`.
+//!
+//! A single forward scan over the buffer text tracks the open objects and arrays with
+//! their current key or index. It never builds a tree and tolerates broken JSON, so the
+//! status bar can call it on every frame while the user types.
+
+enum Frame {
+    /// Inside `{...}`: the key of the member the scan is in, once its name was read
+    Object { key: Option<String> },
+    /// Inside `[...]`: index of the current element
+    Array { index: usize },
+}
+
+/// Path at `line` / `col` (0-based, `col` in characters). When the cursor sits before a
+/// member's key on its line (indentation, after a comma), that member is used. None outside
+/// any container.
+pub fn path_at(text: &str, line: usize, col: usize) -> Option<String> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut chars = text.chars().peekable();
+    let (mut cur_line, mut cur_col) = (0usize, 0usize);
+    let mut rest_of_line = String::new();
+
+    while let Some(c) = chars.next() {
+        if (cur_line, cur_col) >= (line, col) {
+            // Whatever is left on the cursor's line, to look for a key after the cursor
+            rest_of_line.push(c);
+            rest_of_line.extend(chars.by_ref().take_while(|&c| c != '\n'));
+            break;
+        }
+        advance(c, &mut cur_line, &mut cur_col);
+        match c {
+            '{' => stack.push(Frame::Object { key: None }),
+            '[' => stack.push(Frame::Array { index: 0 }),
+            '}' | ']' => {
+                stack.pop();
+            }
+            ',' => match stack.last_mut() {
+                Some(Frame::Object { key }) => *key = None,
+                Some(Frame::Array { index }) => *index += 1,
+                None => {}
+            },
+            '"' => {
+                // Read the whole string, even past the cursor: a key is named once it closes
+                let mut string = String::new();
+                while let Some(c) = chars.next() {
+                    advance(c, &mut cur_line, &mut cur_col);
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                advance(escaped, &mut cur_line, &mut cur_col);
+                                string.push(escaped);
+                            }
+                        }
+                        _ => string.push(c),
+                    }
+                }
+                if let Some(Frame::Object { key: key @ None }) = stack.last_mut() {
+                    *key = Some(string);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(Frame::Object { key: key @ None }) = stack.last_mut() {
+        *key = key_ahead(&rest_of_line);
+    }
+    if stack.is_empty() {
+        return None;
+    }
+    let mut path = String::new();
+    for frame in &stack {
+        match frame {
+            Frame::Object { key: Some(key) } if is_identifier(key) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Frame::Object { key: Some(key) } => path.push_str(&format!("[{:?}]", key)),
+            Frame::Object { key: None } => {}
+            Frame::Array { index } => path.push_str(&format!("[{}]", index)),
+        }
+    }
+    Some(if path.is_empty() { "$".to_string() } else { path })
+}
+
+fn advance(c: char, line: &mut usize, col: &mut usize) {
+    if c == '\n' {
+        *line += 1;
+        *col = 0;
+    } else {
+        *col += 1;
+    }
+}
+
+/// `"name": ...` right after the cursor (past spaces and a comma)
+fn key_ahead(rest: &str) -> Option<String> {
+    let rest = rest.trim_start_matches([' ', '\t', ',']).strip_prefix('"')?;
+    let end = rest.find('"')?;
+    rest[end + 1..].trim_start().starts_with(':').then(|| rest[..end].to_string())
+}
+
+fn is_identifier(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -0,0 +1,800 @@
+//! Key bindings per input context.
+//!
+//! The cheat sheet (`g?`, `F1`) is generated from these tables, so a binding
+//! added or changed here shows up there for the context it applies in.
+//!
+//! [`Keymap`] resolves Normal-mode keys in View, Edit and focus mode, the
+//! explorer, outline and match lists, and the entry overlay: each mode maps key
+//! sequences to an [`Action`], which View, Edit and focus mode run with the count
+//! typed before the sequence. The command palette (`Space`, `Ctrl+k`) lists the same
+//! actions with the keys bound to them.
+
+use crate::i18n::Msg;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
+
+/// Where keys are being read, as far as the available bindings differ
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyContext {
+    View,
+    Visual,
+    Focus,
+    Edit,
+    Selection,
+    Overlay,
+    OverlayField,
+}
+
+impl KeyContext {
+    pub fn title(self) -> Msg {
+        match self {
+            KeyContext::View => Msg::ViewKeys,
+            KeyContext::Visual => Msg::VisualKeys,
+            KeyContext::Focus => Msg::FocusKeys,
+            KeyContext::Edit => Msg::EditKeys,
+            KeyContext::Selection => Msg::SelectionKeys,
+            KeyContext::Overlay => Msg::OverlayKeys,
+            KeyContext::OverlayField => Msg::OverlayFieldKeys,
+        }
+    }
+}
+
+/// One or more keys and what they do
+#[derive(Clone, Debug, PartialEq)]
+pub struct Binding {
+    pub keys: String,
+    pub action: &'static str,
+}
+
+// Visual mode runs `:` commands on the selection, and a text selection reuses
+// the Edit motions, so neither has an action table to show
+const VISUAL: &[(&str, &str)] = &[
+    ("j / k", "extend selection"),
+    (":cc :ccj :ccm", "copy selection (text / JSON / Markdown)"),
+    (":dc", "delete selected cards"),
+    (":set pct N", "set percentage of selected cards"),
+    (":priority P", "set priority (none clears)"),
+    (":tag add/remove T", "edit tags of selected cards"),
+    (":select all", "select every card"),
+    (":select invert", "invert the selection"),
+    ("Esc / Ctrl+[", "leave Visual mode"),
+];
+
+const SELECTION: &[(&str, &str)] = &[
+    ("h j k l w b e", "extend selection"),
+    ("y", "yank selection"),
+    ("d / x", "delete selection"),
+    ("> / <", "indent / outdent lines"),
+    ("V", "select whole lines"),
+    ("Esc / Ctrl+[", "cancel selection"),
+];
+
+/// Bindings available in `context`, in cheat sheet order: one row per description,
+/// with every key bound to it
+pub fn bindings(context: KeyContext) -> Vec<Binding> {
+    let mode = match context {
+        KeyContext::View => KeyMode::View,
+        KeyContext::Focus => KeyMode::Focus,
+        KeyContext::Edit => KeyMode::Edit,
+        KeyContext::Overlay => KeyMode::Overlay,
+        KeyContext::OverlayField => KeyMode::OverlayField,
+        KeyContext::Visual | KeyContext::Selection => {
+            let rows = if context == KeyContext::Visual { VISUAL } else { SELECTION };
+            return rows.iter().map(|&(keys, action)| Binding { keys: keys.to_string(), action }).collect();
+        }
+    };
+    let mut rows: Vec<(&'static str, Vec<&'static str>)> = Vec::new();
+    for (keys, action) in key_table(mode) {
+        let description = action.description(mode);
+        match rows.iter_mut().find(|(d, _)| *d == description) {
+            Some((_, row)) => row.push(keys),
+            None => rows.push((description, vec![keys])),
+        }
+    }
+    rows.into_iter()
+        .map(|(action, keys)| {
+            // Arrow and page keys and `Ctrl+[` only repeat a key shown next to them,
+            // unless nothing else is bound to the row
+            let shown: Vec<&str> = keys.iter().copied().filter(|k| !is_repeat_key(k)).collect();
+            let shown = if shown.is_empty() { keys } else { shown };
+            let keys: Vec<String> = shown.into_iter().map(display_keys).collect();
+            Binding { keys: keys.join(" / "), action }
+        })
+        .collect()
+}
+
+fn is_repeat_key(keys: &str) -> bool {
+    matches!(keys, "<Up>" | "<Down>" | "<Left>" | "<Right>" | "<PageUp>" | "<PageDown>" | "<C-[>")
+}
+
+/// `<C-f>` as the cheat sheet writes it (`Ctrl+f`)
+fn display_keys(keys: &str) -> String {
+    match keys.strip_prefix('<').and_then(|k| k.strip_suffix('>')) {
+        Some(name) => match name.strip_prefix("C-") {
+            Some(key) => format!("Ctrl+{}", key),
+            None => name.to_string(),
+        },
+        None => keys.to_string(),
+    }
+}
+
+/// Keys the input handlers resolve through [`Keymap`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyMode {
+    View,
+    Edit,
+    Focus,
+    Explorer,
+    Outline,
+    /// The `:matches` list
+    Matches,
+    /// The entry overlay, moving between fields
+    Overlay,
+    /// Normal mode inside one field of the entry overlay
+    OverlayField,
+}
+
+/// What a key sequence does once complete
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    CommandLine,
+    ToggleHelp,
+    CheatSheet,
+    ToggleFormat,
+    Search,
+    NextMatch,
+    PrevMatch,
+    Down,
+    Up,
+    Left,
+    Right,
+    Top,
+    Bottom,
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    /// Ctrl+f / Ctrl+b over the cards
+    PageCards(bool),
+    /// Card content scroll in View mode, in rows
+    ScrollCard(i16),
+    /// `{`, `}`, `[[`, `]]`
+    Bracket(&'static str),
+    WordForward,
+    WordEnd,
+    WordBack,
+    LineStart,
+    LineEnd,
+    Insert,
+    Append,
+    OpenLineBelow,
+    /// New card after (`o`) or before (`O`) the selected one
+    NewCard(bool),
+    MoveCard(isize),
+    DeleteChar,
+    Backspace,
+    /// `d` or `c` waiting for a motion
+    Operator(char),
+    /// `D` / `C`
+    OperatorToLineEnd(char),
+    YankLine,
+    PasteLine,
+    DeleteCards,
+    /// Card register put after (`p`) or before (`P`)
+    PutCards(bool),
+    Undo,
+    Redo,
+    VisualCards,
+    VisualChars,
+    VisualLines,
+    OpenCard,
+    ToggleExpansion,
+    ToggleFocus,
+    /// `]` / `[` in focus mode
+    FocusNextCard(bool),
+    LiveFilter,
+    Attachments,
+    LinkHints,
+    /// One field of the selected card to the clipboard
+    CopyField(&'static str),
+    /// One field of the selected card edited at the status bar
+    QuickEdit(&'static str),
+    /// Fuzzy launcher over the actions of the current mode
+    Palette,
+    /// A `:` command, run from the palette
+    Command(&'static str),
+    /// Enter in the explorer, outline, match list and overlay
+    Select,
+    /// `go`: show the explorer file or outline entry, keeping focus on the list
+    Preview,
+    /// `gh` in the explorer
+    ShowAllFiles,
+    /// `w` in the overlay
+    SaveEntry,
+    /// `dd` in an overlay field
+    DeleteLine,
+}
+
+const COMMON_KEYS: &[(&str, Action)] = &[
+    ("q", Action::Quit),
+    ("<Esc>", Action::Quit),
+    ("<C-[>", Action::Quit),
+    (":", Action::CommandLine),
+    ("?", Action::ToggleHelp),
+    ("g?", Action::CheatSheet),
+    ("<Space>", Action::Palette),
+    ("<C-k>", Action::Palette),
+    ("r", Action::ToggleFormat),
+    ("/", Action::Search),
+    ("n", Action::NextMatch),
+    ("N", Action::PrevMatch),
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("<PageUp>", Action::PageUp),
+    ("<PageDown>", Action::PageDown),
+    ("{", Action::Bracket("{")),
+    ("}", Action::Bracket("}")),
+    ("[[", Action::Bracket("[[")),
+    ("]]", Action::Bracket("]]")),
+    ("<C-p>", Action::Command("find")),
+];
+
+const VIEW_KEYS: &[(&str, Action)] = &[
+    ("h", Action::ScrollCard(-1)),
+    ("<Left>", Action::ScrollCard(-1)),
+    ("b", Action::ScrollCard(-1)),
+    ("l", Action::ScrollCard(1)),
+    ("<Right>", Action::ScrollCard(1)),
+    ("f", Action::ScrollCard(1)),
+    ("<C-h>", Action::ScrollCard(-5)),
+    ("<C-l>", Action::ScrollCard(5)),
+    ("<C-f>", Action::PageCards(true)),
+    ("<C-b>", Action::PageCards(false)),
+    ("<Enter>", Action::OpenCard),
+    ("<Tab>", Action::ToggleExpansion),
+    ("o", Action::NewCard(true)),
+    ("O", Action::NewCard(false)),
+    ("J", Action::MoveCard(1)),
+    ("K", Action::MoveCard(-1)),
+    ("dd", Action::DeleteCards),
+    ("p", Action::PutCards(true)),
+    ("P", Action::PutCards(false)),
+    ("v", Action::VisualCards),
+    ("z", Action::ToggleFocus),
+    ("F", Action::LiveFilter),
+    ("ga", Action::Attachments),
+    ("gl", Action::LinkHints),
+    ("yu", Action::CopyField("url")),
+    ("yn", Action::CopyField("name")),
+    ("yc", Action::CopyField("context")),
+    ("yd", Action::CopyField("date")),
+    ("yp", Action::CopyField("percentage")),
+    ("cn", Action::QuickEdit("name")),
+    ("cu", Action::QuickEdit("url")),
+    ("cp", Action::QuickEdit("percentage")),
+];
+
+const EDIT_KEYS: &[(&str, Action)] = &[
+    ("h", Action::Left),
+    ("<Left>", Action::Left),
+    ("l", Action::Right),
+    ("<Right>", Action::Right),
+    ("w", Action::WordForward),
+    ("e", Action::WordEnd),
+    ("b", Action::WordBack),
+    ("0", Action::LineStart),
+    ("$", Action::LineEnd),
+    ("<C-f>", Action::PageDown),
+    ("<C-b>", Action::PageUp),
+    ("i", Action::Insert),
+    ("a", Action::Append),
+    ("o", Action::OpenLineBelow),
+    ("x", Action::DeleteChar),
+    ("X", Action::Backspace),
+    ("d", Action::Operator('d')),
+    ("c", Action::Operator('c')),
+    ("D", Action::OperatorToLineEnd('d')),
+    ("C", Action::OperatorToLineEnd('c')),
+    ("yy", Action::YankLine),
+    ("p", Action::PasteLine),
+    ("u", Action::Undo),
+    ("g-", Action::Undo),
+    ("g+", Action::Redo),
+    ("v", Action::VisualChars),
+    ("V", Action::VisualLines),
+];
+
+const FOCUS_KEYS: &[(&str, Action)] = &[
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("<C-f>", Action::PageDown),
+    ("<PageDown>", Action::PageDown),
+    ("<Space>", Action::PageDown),
+    ("<C-b>", Action::PageUp),
+    ("<PageUp>", Action::PageUp),
+    ("<C-d>", Action::HalfPageDown),
+    ("<C-u>", Action::HalfPageUp),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("]", Action::FocusNextCard(true)),
+    ("[", Action::FocusNextCard(false)),
+    ("<Enter>", Action::OpenCard),
+    (":", Action::CommandLine),
+    ("<C-k>", Action::Palette),
+    ("z", Action::ToggleFocus),
+    ("q", Action::ToggleFocus),
+    ("<Esc>", Action::ToggleFocus),
+];
+
+const EXPLORER_KEYS: &[(&str, Action)] = &[
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("h", Action::Left),
+    ("<Left>", Action::Left),
+    ("l", Action::Right),
+    ("<Right>", Action::Right),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("<Enter>", Action::Select),
+    ("o", Action::Select),
+    ("go", Action::Preview),
+    ("gh", Action::ShowAllFiles),
+    ("/", Action::Search),
+    ("n", Action::NextMatch),
+    ("N", Action::PrevMatch),
+    (":", Action::CommandLine),
+    ("q", Action::Quit),
+];
+
+const OUTLINE_KEYS: &[(&str, Action)] = &[
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("h", Action::Left),
+    ("<Left>", Action::Left),
+    ("l", Action::Right),
+    ("<Right>", Action::Right),
+    ("<C-f>", Action::PageDown),
+    ("<C-b>", Action::PageUp),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("<Enter>", Action::Select),
+    ("go", Action::Preview),
+    ("/", Action::Search),
+    ("n", Action::NextMatch),
+    ("N", Action::PrevMatch),
+    (":", Action::CommandLine),
+    ("q", Action::Quit),
+];
+
+const MATCHES_KEYS: &[(&str, Action)] = &[
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("<Enter>", Action::Select),
+    (":", Action::CommandLine),
+    ("q", Action::Quit),
+    ("<Esc>", Action::Quit),
+];
+
+const OVERLAY_KEYS: &[(&str, Action)] = &[
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("h", Action::ScrollCard(-1)),
+    ("<Left>", Action::ScrollCard(-1)),
+    ("b", Action::ScrollCard(-1)),
+    ("l", Action::ScrollCard(1)),
+    ("<Right>", Action::ScrollCard(1)),
+    ("f", Action::ScrollCard(1)),
+    ("<Enter>", Action::Select),
+    ("i", Action::Insert),
+    ("w", Action::SaveEntry),
+    ("?", Action::CheatSheet),
+    ("<Esc>", Action::Quit),
+    ("q", Action::Quit),
+];
+
+const OVERLAY_FIELD_KEYS: &[(&str, Action)] = &[
+    ("h", Action::Left),
+    ("<Left>", Action::Left),
+    ("j", Action::Down),
+    ("<Down>", Action::Down),
+    ("k", Action::Up),
+    ("<Up>", Action::Up),
+    ("l", Action::Right),
+    ("<Right>", Action::Right),
+    ("w", Action::WordForward),
+    ("e", Action::WordEnd),
+    ("b", Action::WordBack),
+    ("0", Action::LineStart),
+    ("$", Action::LineEnd),
+    ("gg", Action::Top),
+    ("G", Action::Bottom),
+    ("x", Action::DeleteChar),
+    ("X", Action::Backspace),
+    ("dd", Action::DeleteLine),
+    ("yy", Action::YankLine),
+    ("p", Action::PasteLine),
+    ("i", Action::Insert),
+    ("a", Action::Append),
+    ("o", Action::OpenLineBelow),
+    ("/", Action::Search),
+    ("n", Action::NextMatch),
+    ("N", Action::PrevMatch),
+    (":", Action::CommandLine),
+    ("?", Action::CheatSheet),
+    ("<Esc>", Action::Quit),
+    ("<C-[>", Action::Quit),
+];
+
+/// Key sequences bound in `mode`, in the `<C-x>` / `<Esc>` notation
+pub fn key_table(mode: KeyMode) -> Vec<(&'static str, Action)> {
+    match mode {
+        KeyMode::View => VIEW_KEYS.iter().chain(COMMON_KEYS).copied().collect(),
+        KeyMode::Edit => EDIT_KEYS.iter().chain(COMMON_KEYS).copied().collect(),
+        KeyMode::Focus => FOCUS_KEYS.to_vec(),
+        KeyMode::Explorer => EXPLORER_KEYS.to_vec(),
+        KeyMode::Outline => OUTLINE_KEYS.to_vec(),
+        KeyMode::Matches => MATCHES_KEYS.to_vec(),
+        KeyMode::Overlay => OVERLAY_KEYS.to_vec(),
+        KeyMode::OverlayField => OVERLAY_FIELD_KEYS.to_vec(),
+    }
+}
+
+impl Action {
+    /// What the action does in `mode`, as the cheat sheet says it. Actions sharing a
+    /// description share a row, so pairs like `j` / `k` describe both directions.
+    pub fn description(self, mode: KeyMode) -> &'static str {
+        use Action::*;
+        match (mode, self) {
+            (_, Quit) => match mode {
+                KeyMode::Focus => "leave focus mode",
+                KeyMode::Overlay => "cancel",
+                KeyMode::OverlayField => "back to fields",
+                KeyMode::Matches => "close the list",
+                KeyMode::View => "quit (leaves Visual mode first)",
+                _ => "quit",
+            },
+            (KeyMode::OverlayField, CommandLine) => "replace in field (:s/old/new/[g])",
+            (_, CommandLine) => "command line",
+            (_, ToggleHelp) => "full help",
+            (_, CheatSheet) => "key cheat sheet",
+            (_, Palette) => "command palette",
+            (KeyMode::Edit, ToggleFormat) => "switch to View mode",
+            (_, ToggleFormat) => "switch to Edit mode",
+            (KeyMode::OverlayField, Search) => "search in field",
+            (_, Search) => "search",
+            (_, NextMatch | PrevMatch) => "next / previous match",
+            (KeyMode::View, Down | Up) => "select next / previous card",
+            (KeyMode::View, Top | Bottom) => "first / last card",
+            (KeyMode::Focus, Down | Up) => "scroll card",
+            (KeyMode::Focus, Top | Bottom) => "top / bottom of card",
+            (KeyMode::Overlay, Down | Up) => "next / previous field",
+            (KeyMode::OverlayField, Top | Bottom) => "start / end of field",
+            (KeyMode::Edit | KeyMode::OverlayField, Down | Up | Left | Right) => "move cursor",
+            (_, Down | Up) => "next / previous item",
+            (_, Left | Right) => "scroll sideways",
+            (_, Top | Bottom) => "top / bottom",
+            (KeyMode::Focus, PageUp | PageDown) => "page through card",
+            (KeyMode::Focus, HalfPageUp | HalfPageDown) => "half a page through card",
+            (_, PageUp | PageDown | HalfPageUp | HalfPageDown) => "page up / down",
+            (_, PageCards(_)) => "page through cards",
+            (KeyMode::Overlay, ScrollCard(_)) => "scroll field",
+            (_, ScrollCard(_)) => "scroll card content",
+            (KeyMode::Edit, Bracket("{" | "}")) => "previous / next entry",
+            (KeyMode::Edit, Bracket(_)) => "previous / next section",
+            (_, Bracket("{" | "}")) => "previous / next section",
+            (_, Bracket(_)) => "first / last card of section",
+            (_, WordForward | WordEnd | WordBack) => "word start / end / back",
+            (_, LineStart | LineEnd) => "line start / end",
+            (KeyMode::Overlay, Insert) => "field Insert mode",
+            (_, Insert | Append | OpenLineBelow) => "Insert mode",
+            (_, NewCard(_)) => "new card after / before selected",
+            (_, MoveCard(_)) => "move card down / up",
+            (_, DeleteChar | Backspace) => "delete character / before cursor",
+            (_, Operator('c') | OperatorToLineEnd('c')) => "change (cw ce c$ ciw cc)",
+            (_, Operator(_) | OperatorToLineEnd(_)) => "delete (dw de d$ diw dd)",
+            (KeyMode::OverlayField, DeleteLine | YankLine | PasteLine) => "delete / yank / paste line",
+            (_, YankLine | PasteLine | DeleteLine) => "yank line / paste",
+            (_, DeleteCards) => "delete card(s) into the card register ([N]dd)",
+            (_, PutCards(_)) => "put deleted cards after / before ([N]p)",
+            (_, Undo | Redo) => "undo / redo",
+            (_, VisualCards) => "Visual mode (select cards)",
+            (_, VisualChars | VisualLines) => "select characters / lines",
+            (KeyMode::View, OpenCard) => "edit selected card",
+            (_, OpenCard) => "edit card",
+            (_, ToggleExpansion) => "expand / collapse a long context",
+            (KeyMode::View, ToggleFocus) => "focus mode (selected card full-screen)",
+            (_, ToggleFocus) => "leave focus mode",
+            (_, FocusNextCard(_)) => "next / previous card",
+            (_, LiveFilter) => "live filter",
+            (_, Attachments) => "open attachments",
+            (_, LinkHints) => "link hints: copy / open one of the card's links",
+            (_, CopyField(_)) => "copy URL / name / context / date / percentage",
+            (_, QuickEdit(_)) => "edit name / URL / percentage in the status bar",
+            (_, Command("find")) => "fuzzy-find a file to open",
+            (_, Command(_)) => "run a command",
+            (KeyMode::Overlay, Select) => "field Normal mode",
+            (KeyMode::Outline, Select) => "fold a group / jump to the entry",
+            (KeyMode::Matches, Select) => "jump to the match",
+            (_, Select) => "open",
+            (KeyMode::Outline, Preview) => "show the entry, keep focus here",
+            (_, Preview) => "open the file, keep focus here",
+            (_, ShowAllFiles) => "show all files / only revw files",
+            (_, SaveEntry) => "save entry",
+        }
+    }
+}
+
+/// What the command palette lists, in order. Actions show only in modes that bind
+/// them; commands show everywhere.
+const PALETTE: &[(&str, Action)] = &[
+    ("Edit selected card", Action::OpenCard),
+    ("New card after selected", Action::NewCard(true)),
+    ("New card before selected", Action::NewCard(false)),
+    ("Move card down", Action::MoveCard(1)),
+    ("Move card up", Action::MoveCard(-1)),
+    ("Delete card into the card register", Action::DeleteCards),
+    ("Put deleted cards after", Action::PutCards(true)),
+    ("Put deleted cards before", Action::PutCards(false)),
+    ("Expand / collapse a long context", Action::ToggleExpansion),
+    ("Visual mode (select cards)", Action::VisualCards),
+    ("Focus mode", Action::ToggleFocus),
+    ("Live filter", Action::LiveFilter),
+    ("Open attachments", Action::Attachments),
+    ("Link hints", Action::LinkHints),
+    ("Copy URL", Action::CopyField("url")),
+    ("Copy name", Action::CopyField("name")),
+    ("Copy context", Action::CopyField("context")),
+    ("Copy date", Action::CopyField("date")),
+    ("Copy percentage", Action::CopyField("percentage")),
+    ("Edit name", Action::QuickEdit("name")),
+    ("Edit URL", Action::QuickEdit("url")),
+    ("Edit percentage", Action::QuickEdit("percentage")),
+    ("Insert mode", Action::Insert),
+    ("Select characters", Action::VisualChars),
+    ("Select lines", Action::VisualLines),
+    ("Yank line", Action::YankLine),
+    ("Paste line", Action::PasteLine),
+    ("Undo", Action::Undo),
+    ("Redo", Action::Redo),
+    ("Search", Action::Search),
+    ("Next match", Action::NextMatch),
+    ("Previous match", Action::PrevMatch),
+    ("Top", Action::Top),
+    ("Bottom", Action::Bottom),
+    ("Switch View / Edit mode", Action::ToggleFormat),
+    ("Save", Action::Command("w")),
+    ("Find file", Action::Command("find")),
+    ("Recent files", Action::Command("recent")),
+    ("Toggle file explorer", Action::Command("Lexplore")),
+    ("Toggle outline", Action::Command("outline")),
+    ("List search matches", Action::Command("matches")),
+    ("Clear search highlight", Action::Command("noh")),
+    ("Clear filter", Action::Command("nof")),
+    ("Reload file", Action::Command("reload")),
+    ("Compare with the file on disk", Action::Command("diff")),
+    ("Browse snapshots", Action::Command("snapshots")),
+    ("Set percentages from checkboxes", Action::Command("progress")),
+    ("Tag statistics", Action::Command("stats tags")),
+    ("Copy weekly review", Action::Command("report")),
+    ("Copy all content", Action::Command("c")),
+    ("Export to Markdown", Action::Command("markdown")),
+    ("Export to JSON", Action::Command("json")),
+    ("Token counts", Action::Command("token")),
+    ("Command line", Action::CommandLine),
+    ("Key cheat sheet", Action::CheatSheet),
+    ("Full help", Action::ToggleHelp),
+    ("Quit", Action::Quit),
+];
+
+/// A row of the command palette
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteItem {
+    pub title: &'static str,
+    /// Key sequences bound to the action in the mode (`gg`, `<C-f>`), or the `:` command
+    pub keys: String,
+    pub action: Action,
+}
+
+/// Actions available in `mode`, with their bindings, in palette order
+pub fn palette_items(mode: KeyMode) -> Vec<PaletteItem> {
+    let table = key_table(mode);
+    PALETTE
+        .iter()
+        .filter_map(|&(title, action)| {
+            let keys = match action {
+                Action::Command(cmd) => format!(":{}", cmd),
+                _ => {
+                    let keys: Vec<&str> = table.iter().filter(|(_, a)| *a == action).map(|(keys, _)| *keys).collect();
+                    if keys.is_empty() {
+                        return None;
+                    }
+                    keys.join(" ")
+                }
+            };
+            Some(PaletteItem { title, keys, action })
+        })
+        .collect()
+}
+
+/// A key as bindings see it: Shift is part of the character, other modifiers but Ctrl are ignored
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl Key {
+    pub fn from_event(event: KeyEvent) -> Self {
+        Key { code: event.code, ctrl: event.modifiers.contains(KeyModifiers::CONTROL) }
+    }
+}
+
+/// Parse `gg`, `<C-f>`, `<Esc>`, ... into keys; None for an unknown `<name>`
+pub fn parse_keys(text: &str) -> Option<Vec<Key>> {
+    let mut keys = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c == '<' && rest.len() > 2
+            && let Some(end) = rest.find('>') {
+            keys.push(parse_named(&rest[1..end])?);
+            rest = &rest[end + 1..];
+            continue;
+        }
+        keys.push(Key { code: KeyCode::Char(c), ctrl: false });
+        rest = &rest[c.len_utf8()..];
+    }
+    Some(keys)
+}
+
+fn parse_named(name: &str) -> Option<Key> {
+    if let Some(inner) = name.strip_prefix("C-") {
+        let key = parse_named(inner)?;
+        return Some(Key { ctrl: true, ..key });
+    }
+    let code = match name {
+        "Esc" => KeyCode::Esc,
+        "Enter" | "CR" => KeyCode::Enter,
+        "Tab" => KeyCode::Tab,
+        "Space" => KeyCode::Char(' '),
+        "BS" => KeyCode::Backspace,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "lt" => KeyCode::Char('<'),
+        _ => {
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(Key { code, ctrl: false })
+}
+
+/// Result of feeding one key to the [`Keymap`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyOutcome {
+    /// Part of a count or of a longer sequence
+    Pending,
+    /// A complete sequence, with the count typed before it
+    Run(Action, Option<usize>),
+    Unbound,
+}
+
+/// Resolves keys into actions per mode: multi-key sequences, counts and the
+/// timeout after which a half-typed sequence is dropped
+pub struct Keymap {
+    bindings: Vec<(KeyMode, Vec<Key>, Action)>,
+    pending: Vec<Key>,
+    count: Option<usize>,
+    last_key: Option<Instant>,
+    pub timeout: Duration,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let modes = [
+            KeyMode::View,
+            KeyMode::Edit,
+            KeyMode::Focus,
+            KeyMode::Explorer,
+            KeyMode::Outline,
+            KeyMode::Matches,
+            KeyMode::Overlay,
+            KeyMode::OverlayField,
+        ];
+        let bindings = modes
+            .iter()
+            .flat_map(|&mode| {
+                key_table(mode)
+                    .into_iter()
+                    .filter_map(move |(text, action)| parse_keys(text).map(|keys| (mode, keys, action)))
+            })
+            .collect();
+        Keymap { bindings, pending: Vec::new(), count: None, last_key: None, timeout: Duration::from_millis(1000) }
+    }
+}
+
+impl Keymap {
+    /// Feed `key` typed in `mode`; digits start a count only when `counts` is set
+    pub fn feed(&mut self, mode: KeyMode, key: Key, counts: bool, now: Instant) -> KeyOutcome {
+        if self.last_key.is_some_and(|last| now.duration_since(last) > self.timeout) {
+            self.reset();
+        }
+        self.last_key = Some(now);
+
+        // Ctrl+j and friends act like the plain key unless bound themselves
+        let key = if key.ctrl && !self.binds(mode, key) { Key { ctrl: false, ..key } } else { key };
+
+        if counts && self.pending.is_empty() && !key.ctrl
+            && let KeyCode::Char(c @ '0'..='9') = key.code
+                && (c != '0' || self.count.is_some()) {
+            let digit = c.to_digit(10).unwrap_or(0) as usize;
+            self.count = Some((self.count.unwrap_or(0) * 10 + digit).min(9999));
+            return KeyOutcome::Pending;
+        }
+
+        self.pending.push(key);
+        match self.lookup(mode) {
+            Some(Some(action)) => {
+                let count = self.count;
+                self.reset();
+                KeyOutcome::Run(action, count)
+            }
+            Some(None) => KeyOutcome::Pending,
+            None if self.pending.len() > 1 => {
+                // A broken sequence starts over from its last key
+                self.reset();
+                self.feed(mode, key, counts, now)
+            }
+            None => {
+                self.reset();
+                KeyOutcome::Unbound
+            }
+        }
+    }
+
+    /// Drop a half-typed sequence and count
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.count = None;
+        self.last_key = None;
+    }
+
+    /// Some(Some(action)) for a complete sequence, Some(None) for a prefix of one
+    fn lookup(&self, mode: KeyMode) -> Option<Option<Action>> {
+        let mut prefix = false;
+        for (_, keys, action) in self.bindings.iter().filter(|(m, _, _)| *m == mode) {
+            if *keys == self.pending {
+                return Some(Some(*action));
+            }
+            prefix |= keys.starts_with(&self.pending);
+        }
+        prefix.then_some(None)
+    }
+
+    fn binds(&self, mode: KeyMode, key: Key) -> bool {
+        self.bindings.iter().any(|(m, keys, _)| *m == mode && keys.contains(&key))
+    }
+}
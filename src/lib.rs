@@ -1,11 +1,45 @@
+pub mod ansi;
 pub mod app;
+pub mod base64;
+pub mod bench;
 pub mod config;
 pub mod content_ops;
+pub mod convert;
+pub mod diff;
+pub mod due;
+pub mod fields;
+pub mod file_index;
+pub mod fuzzy;
+pub mod graphemes;
+pub mod hooks;
+pub mod i18n;
+pub mod import_ops;
 pub mod input;
+pub mod journal;
 pub mod json_ops;
+pub mod json_path;
+pub mod keymap;
+pub mod line_endings;
+pub mod logging;
 pub mod markdown_ops;
 pub mod navigation;
+pub mod output;
+pub mod paths;
+pub mod preview;
+pub mod priority;
+pub mod recent;
 pub mod wrap;
 pub mod rendering;
+pub mod report;
+pub mod rotate;
+pub mod search;
+pub mod snapshot;
+pub mod sources;
+pub mod status;
+pub mod swap;
+pub mod sync;
 pub mod syntax_highlight;
+pub mod task;
 pub mod ui;
+pub mod url_ops;
+pub mod validate;
@@ -0,0 +1,62 @@
+//! Windows line endings and byte order marks.
+//!
+//! Loaded text is normalized to `\n` without a BOM, so the parsers never see a stray
+//! `\r`. `TextFormat` remembers what the file used, for writing it back the same way
+//! when `preserve_line_endings` is set.
+
+use std::borrow::Cow;
+
+const BOM: char = '\u{feff}';
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextFormat {
+    /// Lines ended in `\r\n`
+    pub crlf: bool,
+    /// The text started with a UTF-8 byte order mark
+    pub bom: bool,
+}
+
+impl TextFormat {
+    /// `text` (with `\n` line endings) in this format
+    pub fn apply(self, text: &str) -> Cow<'_, str> {
+        if !self.crlf && !self.bom {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len() + 3);
+        if self.bom {
+            out.push(BOM);
+        }
+        if self.crlf {
+            out.push_str(&text.replace('\n', "\r\n"));
+        } else {
+            out.push_str(text);
+        }
+        Cow::Owned(out)
+    }
+}
+
+/// `content` without a BOM and with `\r\n` line endings turned into `\n`, and the
+/// format it had. Borrowed when there was nothing to change.
+pub fn normalize(content: &str) -> (Cow<'_, str>, TextFormat) {
+    let stripped = content.strip_prefix(BOM);
+    let text = stripped.unwrap_or(content);
+    let format = TextFormat { crlf: text.contains("\r\n"), bom: stripped.is_some() };
+    let text = if format.crlf {
+        Cow::Owned(text.replace("\r\n", "\n"))
+    } else if format.bom {
+        Cow::Owned(text.to_string())
+    } else {
+        Cow::Borrowed(text)
+    };
+    (text, format)
+}
+
+/// `normalize` for an owned string, reused as is when nothing changes
+pub fn normalize_owned(content: String) -> (String, TextFormat) {
+    let (text, format) = normalize(&content);
+    let text = match text {
+        Cow::Owned(text) => Some(text),
+        Cow::Borrowed(_) => None,
+    };
+    (text.unwrap_or(content), format)
+}
@@ -0,0 +1,51 @@
+//! Debug log of file watcher events, loads, saves, parse errors and commands
+//! (`--log-file`, `set log_file=PATH`, `:log`).
+//!
+//! Events go through `tracing`; without a log file nothing is subscribed and they cost
+//! next to nothing.
+
+use chrono::Local;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::path::Path;
+use std::sync::Mutex;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+
+/// Lines `:log` reads from the end of the file
+pub const TAIL_LINES: usize = 500;
+
+/// Local time in the same format as INSIDE dates
+struct LocalTime;
+
+impl FormatTime for LocalTime {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        write!(w, "{}", Local::now().format("%Y-%m-%d %H:%M:%S"))
+    }
+}
+
+/// Append log events to `path` for the rest of the process
+pub fn init(path: &Path) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create '{}': {}", dir.display(), e))?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Cannot open log file '{}': {}", path.display(), e))?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_ansi(false)
+        .with_target(false)
+        .with_timer(LocalTime)
+        .try_init()
+        .map_err(|e| format!("Cannot start logging: {}", e))
+}
+
+/// The last `limit` lines of the log at `path`, oldest first
+pub fn tail(path: &Path, limit: usize) -> Result<Vec<String>, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Cannot read log file '{}': {}", path.display(), e))?;
+    let lines: Vec<&str> = content.lines().collect();
+    Ok(lines[lines.len().saturating_sub(limit)..].iter().map(|line| line.to_string()).collect())
+}
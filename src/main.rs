@@ -1,14 +1,48 @@
+mod ansi;
 mod app;
+mod base64;
+mod bench;
 mod config;
 mod content_ops;
+mod convert;
+mod diff;
+mod due;
+mod fields;
+mod file_index;
+mod fuzzy;
+mod graphemes;
+mod hooks;
+mod i18n;
+mod import_ops;
 mod input;
+mod journal;
 mod json_ops;
+mod json_path;
+mod keymap;
+mod line_endings;
+mod logging;
 mod markdown_ops;
 mod navigation;
+mod output;
+mod paths;
+mod preview;
+mod priority;
+mod recent;
 mod wrap;
 mod rendering;
+mod report;
+mod rotate;
+mod search;
+mod snapshot;
+mod sources;
+mod status;
+mod swap;
+mod sync;
 mod syntax_highlight;
+mod task;
 mod ui;
+mod url_ops;
+mod validate;
 
 use anyhow::Result;
 use clap::{Arg, ArgGroup, Command};
@@ -19,9 +53,17 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{fs, io::{self, stdout, Read}, panic, path::PathBuf};
+use std::{fs, io::{self, stdout, Read}, panic, path::{Path, PathBuf}};
 
+use ansi::ColorMode;
 use app::{App, FormatMode};
+use convert::{Converter, Format, Sections};
+
+/// ~/.revwrc layered with the project config nearest to `file` (or the current folder)
+fn project_config(file: Option<&str>) -> config::RcConfig {
+    let dir = file.map(Path::new).and_then(Path::parent).filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    config::RcConfig::load_for(dir)
+}
 
 fn main() -> Result<()> {
     // Set up panic handler to properly clean up terminal on crash
@@ -44,12 +86,22 @@ fn main() -> Result<()> {
             # Open file in interactive mode\n  \
             revw file.md\n  \
             revw file.json\n\n  \
+            # Inspect an untrusted file: no autosave, watching, hooks or clipboard reads\n  \
+            revw --safe file.json\n\n  \
             # Output to stdout\n  \
             revw --stdout file.md\n  \
             revw --stdout file.json\n\n  \
             # Format conversion\n  \
             revw --stdout --json file.md\n  \
             revw --stdout --markdown file.json\n\n  \
+            # OUTSIDE entries as columns (name, percentage, url)\n  \
+            revw --stdout --table file.md\n  \
+            revw --stdout --tsv file.md | cut -f1,3\n\n  \
+            # Keep colors when piping into a pager\n  \
+            revw --stdout --color always file.md | less -R\n\n  \
+            # One line per entry for scripts (no headers, chosen fields)\n  \
+            revw --plain --outside --fields url file.json\n  \
+            revw --plain --fields name,percentage --separator , file.md\n\n  \
             # Pipe from stdin\n  \
             cat file.md | revw --stdout\n  \
             cat file.json | revw --stdout\n\n  \
@@ -58,15 +110,32 @@ fn main() -> Result<()> {
             revw --stdout --filter pattern file.json\n  \
             revw --stdout --filter pattern --inside file.md\n  \
             revw --stdout --filter pattern --context 100 file.md\n\n  \
+            # Strict parsing (fail on unknown structure)\n  \
+            revw --stdout --strict file.md\n\n  \
+            # Check files for schema issues (exits 1 on problems, for pre-commit hooks)\n  \
+            revw --check file.md file.json\n\n  \
+            # Entries added, removed and changed between two files (JSON or Markdown)\n  \
+            revw --diff old.json new.md\n  \
+            revw --diff --json old.json new.md\n\n  \
+            # Weekly review summary in Markdown\n  \
+            revw --report file.md\n\n  \
             # Order entries (writes back in-place)\n  \
             revw --order file.md\n  \
             revw --order-percentage file.json\n  \
+            revw --order-priority file.md\n  \
             revw --order-name file.md\n  \
             revw --order-random file.json\n\n  \
             # Append entries from stdin (JSON or Markdown) into file\n  \
             cat new.md   | revw --append file.md\n  \
             cat new.json | revw --append file.json\n  \
-            cat new.md   | revw --append --inside file.md\n\n  \
+            cat new.md   | revw --append --inside file.md\n  \
+            revw --append --input new.md file.md\n  \
+            revw --append --input bookmarks.html --map folder=tag file.md\n  \
+            cat new.md   | revw --append --merge-strategy update file.md\n\n  \
+            # Import a folder of daily notes (one Markdown file per day) as INSIDE entries\n  \
+            revw --import-obsidian ~/vault/daily/ file.md\n\n  \
+            # Move INSIDE notes from before this month into notes-YYYY-MM archive files\n  \
+            revw --rotate monthly notes.json\n\n  \
             # Delete entries by field (writes back in-place)\n  \
             revw --delete-outside-name pattern file.md\n  \
             revw --delete-outside-context pattern file.json\n  \
@@ -91,7 +160,7 @@ fn main() -> Result<()> {
         )
         .arg(
             Arg::new("file")
-                .help("JSON or Markdown file(s) to view (supports multiple files / shell globs)")
+                .help("JSON or Markdown file(s) to view (several files open as one merged view; supports shell globs)")
                 .num_args(0..)
                 .index(1),
         )
@@ -101,6 +170,12 @@ fn main() -> Result<()> {
                 .help("Use Edit mode")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("safe")
+                .long("safe")
+                .help("Open read-mostly: no autosave, file watching, hooks, clipboard reads or recent-files updates; :w still writes")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("stdout")
                 .long("stdout")
@@ -133,17 +208,104 @@ fn main() -> Result<()> {
                 .help("Output in JSON format")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("table")
+                .long("table")
+                .help("Output OUTSIDE entries as aligned name/percentage/url columns")
+                .conflicts_with("inside")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("tsv")
+                .long("tsv")
+                .help("Output OUTSIDE entries as tab-separated name/percentage/url rows")
+                .conflicts_with("inside")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("plain")
+                .long("plain")
+                .help("Print one line per entry with no headers or blank lines, for scripts (implies --stdout)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("fields")
+                .long("fields")
+                .help("Keys --plain prints, comma-separated (default: name,url,percentage for OUTSIDE, date,context for INSIDE)")
+                .requires("plain")
+                .value_name("KEYS")
+                .value_delimiter(','),
+        )
+        .arg(
+            Arg::new("separator")
+                .long("separator")
+                .help("What --plain puts between fields (default: tab; \\t is a tab)")
+                .requires("plain")
+                .value_name("SEP"),
+        )
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .help("Color --stdout listings with the rc colorscheme: auto (terminal only), always, never, or mono (bold and underline only, for printing)")
+                .value_name("WHEN")
+                .value_parser(["auto", "always", "never", "mono"])
+                .default_value("auto"),
+        )
+        .arg(
+            Arg::new("no-color")
+                .long("no-color")
+                .help("Same as --color never")
+                .action(clap::ArgAction::SetTrue),
+        )
         .group(
             ArgGroup::new("output_format")
-                .args(["markdown", "json"])
+                .args(["markdown", "json", "table", "tsv", "plain"])
                 .multiple(false),
         )
+        .arg(
+            Arg::new("strict")
+                .long("strict")
+                .help("Reject files with unknown sections, fields or entry layout instead of parsing best-effort")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .help("Check files for parse errors, bad dates, out-of-range percentages and malformed URLs; exit 1 on problems")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("log-file")
+                .long("log-file")
+                .help("Append file watcher events, loads, saves, parse errors and commands to PATH (see :log)")
+                .value_name("PATH"),
+        )
+        .arg(
+            Arg::new("bench-load")
+                .long("bench-load")
+                .help("Print how long reading, parsing, rendering and drawing FILE take, then exit")
+                .value_name("FILE")
+                .hide(true),
+        )
         .arg(
             Arg::new("token")
                 .long("token")
                 .help("Show token counts for all formats and exit")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("diff")
+                .long("diff")
+                .help("Print the entries added, removed and changed from the first file to the second (with --json as JSON) and exit; exits 1 when they differ")
+                .conflicts_with_all(["append", "stdout", "import-obsidian", "rotate"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("report")
+                .long("report")
+                .help("Print a Markdown progress summary (completion buckets, recent notes, untouched resources) and exit")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("filter")
                 .long("filter")
@@ -164,6 +326,44 @@ fn main() -> Result<()> {
                 .help("Append entries from stdin (JSON or Markdown) into file; use with --inside/--outside to limit section")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .help("Read entries for --append from FILE instead of stdin")
+                .value_name("FILE")
+                .conflicts_with("stdout"),
+        )
+        .arg(
+            Arg::new("map")
+                .long("map")
+                .help("For --append of a browser bookmark export: write a bookmark's title, url or folder to FIELD (name, context, url, tag, none or a custom field); repeatable")
+                .value_name("SOURCE=FIELD")
+                .action(clap::ArgAction::Append)
+                .requires("append"),
+        )
+        .arg(
+            Arg::new("merge-strategy")
+                .long("merge-strategy")
+                .help("For --append: how OUTSIDE entries whose URL already exists are handled: append (default), update, skip")
+                .value_name("STRATEGY")
+                .value_parser(["append", "update", "skip"])
+                .requires("append"),
+        )
+        .arg(
+            Arg::new("import-obsidian")
+                .long("import-obsidian")
+                .help("Import a folder of daily notes (date in each file name) into file as INSIDE entries; days the file already has are skipped")
+                .value_name("DIR")
+                .conflicts_with_all(["append", "stdout"]),
+        )
+        .arg(
+            Arg::new("rotate")
+                .long("rotate")
+                .help("Move INSIDE entries older than the current month into archive files next to file (notes-2025-01.json) and write back in-place")
+                .value_name("PERIOD")
+                .value_parser(["monthly"])
+                .conflicts_with_all(["append", "stdout", "import-obsidian"]),
+        )
         .arg(
             Arg::new("order")
                 .long("order")
@@ -176,6 +376,12 @@ fn main() -> Result<()> {
                 .help("Order entries by percentage only and write back in-place")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("order-priority")
+                .long("order-priority")
+                .help("Order entries by priority (highest first) and write back in-place")
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("order-name")
                 .long("order-name")
@@ -190,7 +396,7 @@ fn main() -> Result<()> {
         )
         .group(
             ArgGroup::new("order_ops")
-                .args(["order", "order-percentage", "order-name", "order-random"])
+                .args(["order", "order-percentage", "order-priority", "order-name", "order-random"])
                 .multiple(false),
         )
         .arg(
@@ -230,19 +436,37 @@ fn main() -> Result<()> {
         FormatMode::View
     };
 
-    let stdout_mode = matches.get_flag("stdout");
+    let plain_mode = matches.get_flag("plain");
+    let stdout_mode = matches.get_flag("stdout") || plain_mode;
     let inside_only = matches.get_flag("inside");
     let outside_only = matches.get_flag("outside");
     let markdown_mode = matches.get_flag("markdown");
     let json_mode = matches.get_flag("json");
+    let table_mode = matches.get_flag("table");
+    let tsv_mode = matches.get_flag("tsv");
     let token_mode = matches.get_flag("token");
+    let report_mode = matches.get_flag("report");
+    let diff_mode = matches.get_flag("diff");
+    let strict_mode = matches.get_flag("strict");
+    let safe_mode = matches.get_flag("safe");
+    let check_mode = matches.get_flag("check");
     let filter_pattern = matches.get_one::<String>("filter");
     let context_chars = matches.get_one::<usize>("context").copied();
     let append_mode = matches.get_flag("append");
+    let append_input = matches.get_one::<String>("input").map(|path| paths::expand(path));
+    let bookmark_maps: Vec<&str> = matches.get_many::<String>("map").into_iter().flatten().map(String::as_str).collect();
+    let import_dir = matches.get_one::<String>("import-obsidian").map(|dir| paths::expand(dir));
+    let rotate_period = matches.get_one::<String>("rotate");
+    let merge_strategy = matches
+        .get_one::<String>("merge-strategy")
+        .and_then(|name| json_ops::MergeStrategy::by_name(name))
+        .unwrap_or_default();
     let order_op: Option<&str> = if matches.get_flag("order") {
         Some("order")
     } else if matches.get_flag("order-percentage") {
         Some("order-percentage")
+    } else if matches.get_flag("order-priority") {
+        Some("order-priority")
     } else if matches.get_flag("order-name") {
         Some("order-name")
     } else if matches.get_flag("order-random") {
@@ -259,20 +483,44 @@ fn main() -> Result<()> {
         .or_else(|| delete_inside_date.map(|p| ("inside-date", p.as_str())))
         .or_else(|| delete_inside_context.map(|p| ("inside-context", p.as_str())));
 
+    let log_file = matches.get_one::<String>("log-file").map(|path| paths::expand(path));
+    if let Some(path) = &log_file
+        && let Err(e) = logging::init(path) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+
     // Detect if stdin is a pipe (not a tty)
     use std::io::IsTerminal;
     let stdin_piped = !io::stdin().is_terminal();
 
     // Helper: load content into app from a string, detecting format by path or content
     let load_content = |app: &mut App, content: String, path: Option<PathBuf>| {
-        let is_markdown = path.as_ref()
-            .and_then(|p| p.extension())
-            .and_then(|ext| ext.to_str())
-            .map(|ext| ext.eq_ignore_ascii_case("md"))
-            .unwrap_or_else(|| {
-                // Heuristic: if no extension, check content
-                content.trim_start().starts_with("## ")
-            });
+        let (content, text_format) = line_endings::normalize_owned(content);
+        app.text_format = text_format;
+        let format = match path.as_ref() {
+            Some(p) => Format::for_file(p, &content),
+            None => Format::detect(&content),
+        };
+        if format == Format::Toon {
+            let source = path.as_ref().map(|p| p.display().to_string()).unwrap_or_else(|| "stdin".to_string());
+            eprintln!("Error: {}: TOON input is not supported yet", source);
+            std::process::exit(1);
+        }
+        let is_markdown = format == Format::Md;
+
+        if strict_mode || app.strict_parsing {
+            let checked = if is_markdown {
+                markdown_ops::MarkdownOperations::validate_strict(&content)
+            } else {
+                json_ops::JsonOperations::validate_strict(&content)
+            };
+            if let Err(e) = checked {
+                let source = path.as_ref().map(|p| p.display().to_string()).unwrap_or_else(|| "stdin".to_string());
+                eprintln!("Error: {}: {}", source, e);
+                std::process::exit(1);
+            }
+        }
 
         if is_markdown {
             app.file_path = path;
@@ -287,291 +535,162 @@ fn main() -> Result<()> {
         app.convert_json();
     };
 
-    // Collect file paths
+    // Collect file paths, with ~ and $VAR expanded as for paths typed in the UI
     let file_paths: Vec<String> = matches
         .get_many::<String>("file")
         .unwrap_or_default()
-        .cloned()
+        .map(|path| paths::expand(path).to_string_lossy().into_owned())
         .collect();
 
+    // Conversion options shared by every --stdout output
+    let mut converter = Converter::new()
+        .from(Format::Json)
+        .to(if markdown_mode {
+            Format::Md
+        } else if json_mode {
+            Format::Json
+        } else if table_mode {
+            Format::Table
+        } else if tsv_mode {
+            Format::Tsv
+        } else if plain_mode {
+            Format::Plain
+        } else {
+            Format::Text
+        })
+        .sections(if inside_only {
+            Sections::InsideOnly
+        } else if outside_only {
+            Sections::OutsideOnly
+        } else {
+            Sections::All
+        });
+    if let Some(pattern) = filter_pattern {
+        converter = converter.filter(pattern.as_str());
+    }
+    if let Some(chars) = context_chars {
+        converter = converter.context_chars(chars);
+    }
+    if let Some(fields) = matches.get_many::<String>("fields") {
+        converter = converter.fields(fields.map(|key| key.trim().to_string()).filter(|key| !key.is_empty()).collect());
+    }
+    if let Some(separator) = matches.get_one::<String>("separator") {
+        converter = converter.separator(separator.replace("\\t", "\t"));
+    }
+    let color_mode = if matches.get_flag("no-color") {
+        ColorMode::Never
+    } else {
+        matches.get_one::<String>("color").and_then(|name| ColorMode::by_name(name)).unwrap_or_default()
+    };
+    if color_mode == ColorMode::Mono {
+        converter = converter.colors(config::ColorScheme::mono());
+    } else if color_mode.enabled(io::stdout().is_terminal()) {
+        converter = converter.colors(config::RcConfig::load().colorscheme);
+    }
+
+    // Tables on a terminal fit its width; piped tables keep full names and URLs
+    if table_mode && io::stdout().is_terminal()
+        && let Ok((cols, _)) = crossterm::terminal::size() {
+        converter = converter.width(cols as usize);
+    }
+
+    // --plain prints nothing at all for a file without entries
+    let print_output = |output: &str| {
+        if !(plain_mode && output.is_empty()) {
+            println!("{}", output);
+        }
+    };
+
     // Generate text output for a loaded app
     let generate_output = |app: &App| -> String {
         if format_mode == FormatMode::Edit {
-                // In Edit mode, output the JSON as-is
-                app.json_input.clone()
+            // In Edit mode, output the JSON as-is
+            return app.json_input.clone();
+        }
+        let json_value = converter.parse(&app.json_input).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
+        if !markdown_mode && !json_mode && !table_mode && !tsv_mode && !plain_mode && app.relf_entries.is_empty() {
+            // No entries parsed, output raw content or rendered lines
+            return if !app.rendered_content.is_empty() {
+                app.rendered_content.join("\n")
             } else {
-                // Parse JSON once for all output modes
-                let json_value = match serde_json::from_str::<serde_json::Value>(&app.json_input) {
-                    Ok(val) => val,
-                    Err(_) => {
-                        eprintln!("Error: Invalid JSON");
-                        std::process::exit(1);
-                    }
-                };
+                app.json_input.clone()
+            };
+        }
+        converter.convert_value(&json_value).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        })
+    };
 
-                // Apply entry-level filter if --filter was provided
-                let json_value = if let Some(pattern) = &filter_pattern {
-                    json_ops::JsonOperations::filter_entries(&json_value, pattern)
-                } else {
-                    json_value
-                };
+    // --bench-load: time each stage of opening a file (filtered too when --filter is given)
+    if let Some(path) = matches.get_one::<String>("bench-load") {
+        let mut app = App::new(format_mode);
+        match bench::time_load(&mut app, &paths::expand(path), filter_pattern.map(String::as_str)) {
+            Ok(stages) => {
+                print!("{}", bench::format_timings(&stages));
+                println!("entries {}", app.relf_entries.len());
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
 
-                // Trim context fields around match if --context N was provided
-                let json_value = if let (Some(pattern), Some(chars)) = (&filter_pattern, context_chars) {
-                    json_ops::JsonOperations::trim_context_around_match(&json_value, pattern, chars)
-                } else {
-                    json_value
-                };
+    // --check: report schema issues as `source:line: message`, exit 1 if any
+    if check_mode {
+        let mut sources: Vec<(String, String, Format)> = Vec::new();
+        if file_paths.is_empty() {
+            if !stdin_piped {
+                eprintln!("Error: --check requires a file argument or stdin input");
+                std::process::exit(1);
+            }
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content)?;
+            let content = line_endings::normalize_owned(content).0;
+            let format = Format::detect(&content);
+            sources.push(("stdin".to_string(), content, format));
+        }
+        for file_path in &file_paths {
+            let path = PathBuf::from(file_path);
+            let content = fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Error: Cannot read '{}': {}", file_path, e);
+                std::process::exit(1);
+            });
+            let content = line_endings::normalize_owned(content).0;
+            let format = Format::for_file(&path, &content);
+            sources.push((file_path.clone(), content, format));
+        }
 
-                // Return appropriate output based on mode
-                if markdown_mode {
-                    // Markdown mode: format entries as Markdown
-                    let mut output_lines = Vec::new();
-
-                    if let Some(obj) = json_value.as_object() {
-                        // OUTSIDE section
-                        if !inside_only {
-                            if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                                if !outside.is_empty() {
-                                    output_lines.push("## OUTSIDE".to_string());
-                                    output_lines.push("".to_string());
-
-                                    for item in outside {
-                                        if let Some(item_obj) = item.as_object() {
-                                            let name = item_obj
-                                                .get("name")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("");
-                                            let context = item_obj
-                                                .get("context")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("");
-                                            let url = item_obj.get("url").and_then(|v| v.as_str());
-                                            let percentage =
-                                                item_obj.get("percentage").and_then(|v| v.as_i64());
-
-                                            if !name.is_empty() {
-                                                output_lines.push(format!("### {}", name));
-                                            }
-
-                                            // Replace literal \n with actual newlines in context
-                                            if !context.is_empty() {
-                                                let formatted_context =
-                                                    context.replace("\\n", "\n");
-                                                output_lines.push(formatted_context);
-                                            }
-
-                                            // Only output URL if it's not null and not empty
-                                            if let Some(url_str) = url {
-                                                if !url_str.is_empty() {
-                                                    output_lines.push("".to_string());
-                                                    output_lines
-                                                        .push(format!("**URL:** {}", url_str));
-                                                }
-                                            }
-
-                                            // Only output percentage if it's not null
-                                            if let Some(pct) = percentage {
-                                                output_lines.push("".to_string());
-                                                output_lines
-                                                    .push(format!("**Percentage:** {}%", pct));
-                                            }
-
-                                            // Only add blank line if we had any content
-                                            if !name.is_empty()
-                                                || !context.is_empty()
-                                                || url.is_some()
-                                                || percentage.is_some()
-                                            {
-                                                output_lines.push("".to_string());
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-
-                        // INSIDE section
-                        if !outside_only {
-                            if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                                if !inside.is_empty() {
-                                    output_lines.push("## INSIDE".to_string());
-                                    output_lines.push("".to_string());
-
-                                    for item in inside {
-                                        if let Some(item_obj) = item.as_object() {
-                                            let date = item_obj
-                                                .get("date")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("");
-                                            let context = item_obj
-                                                .get("context")
-                                                .and_then(|v| v.as_str())
-                                                .unwrap_or("");
-
-                                            if !date.is_empty() {
-                                                output_lines.push(format!("### {}", date));
-                                            }
-
-                                            // Replace literal \n with actual newlines in context
-                                            if !context.is_empty() {
-                                                let formatted_context =
-                                                    context.replace("\\n", "\n");
-                                                output_lines.push(formatted_context);
-                                            }
-
-                                            // Only add blank line if we had content
-                                            if !date.is_empty() || !context.is_empty() {
-                                                output_lines.push("".to_string());
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    output_lines.join("\n")
-                } else if json_mode {
-                    // JSON mode: output as JSON
-                    // Apply section filtering if needed
-                    let filtered_json = if inside_only || outside_only {
-                        let mut json_clone = json_value.clone();
-                        if let Some(obj) = json_clone.as_object_mut() {
-                            if inside_only {
-                                obj.remove("outside");
-                            }
-                            if outside_only {
-                                obj.remove("inside");
-                            }
-                        }
-                        json_clone
-                    } else {
-                        json_value.clone()
-                    };
-
-                    serde_json::to_string_pretty(&filtered_json)
-                        .unwrap_or_else(|_| app.json_input.clone())
+        let mut failed = false;
+        for (source, content, format) in &sources {
+            let issues = validate::check(content, *format);
+            for issue in &issues {
+                println!("{}:{}: {}", source, issue.line, issue.message);
+            }
+            let mut clean = issues.is_empty();
+            if strict_mode {
+                let checked = if *format == Format::Md {
+                    markdown_ops::MarkdownOperations::validate_strict(content)
                 } else {
-                    // In View mode, format the entries for text output
-                    if app.relf_entries.is_empty() {
-                        // No entries parsed, output raw content or rendered lines
-                        if !app.rendered_content.is_empty() {
-                            app.rendered_content.join("\n")
-                        } else {
-                            app.json_input.clone()
-                        }
-                    } else {
-                        // Format entries as text
-                        let mut output_lines = Vec::new();
-                        let mut outside_entries: Vec<String> = Vec::new();
-                        let mut inside_entries: Vec<String> = Vec::new();
-
-                        // Use already parsed JSON
-                        if let Some(obj) = json_value.as_object() {
-                            if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
-                                for item in outside {
-                                    if let Some(item_obj) = item.as_object() {
-                                        let name = item_obj
-                                            .get("name")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let context = item_obj
-                                            .get("context")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let url = item_obj
-                                            .get("url")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let percentage =
-                                            item_obj.get("percentage").and_then(|v| v.as_i64());
-
-                                        let mut entry = String::new();
-                                        entry.push_str(name);
-                                        if !context.is_empty() {
-                                            entry.push_str(&format!("\n{}", context));
-                                        }
-                                        if !url.is_empty() {
-                                            entry.push_str(&format!("\n{}", url));
-                                        }
-                                        // Only add percentage if not null
-                                        if let Some(pct) = percentage {
-                                            entry.push_str(&format!("\n{}%", pct));
-                                        }
-                                        outside_entries.push(entry);
-                                    }
-                                }
-                            }
-
-                            if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
-                                for item in inside {
-                                    if let Some(item_obj) = item.as_object() {
-                                        let mut entry_parts = Vec::new();
-                                        for (_key, value) in item_obj {
-                                            let value_str = match value {
-                                                serde_json::Value::String(s) => s.clone(),
-                                                serde_json::Value::Number(n) => n.to_string(),
-                                                serde_json::Value::Bool(b) => b.to_string(),
-                                                _ => value.to_string(),
-                                            };
-                                            if !value_str.is_empty() {
-                                                entry_parts.push(value_str);
-                                            }
-                                        }
-                                        inside_entries.push(entry_parts.join("\n"));
-                                    }
-                                }
-                            }
-                        }
-
-                        // Filter based on --inside or --outside flags
-                        if inside_only && !outside_only {
-                            // Only INSIDE section
-                            if !inside_entries.is_empty() {
-                                output_lines.push("INSIDE".to_string());
-                                output_lines.push("".to_string());
-                                for entry in inside_entries {
-                                    output_lines.push(entry);
-                                    output_lines.push("".to_string());
-                                }
-                            }
-                        } else if outside_only && !inside_only {
-                            // Only OUTSIDE section
-                            if !outside_entries.is_empty() {
-                                output_lines.push("OUTSIDE".to_string());
-                                output_lines.push("".to_string());
-                                for entry in outside_entries {
-                                    output_lines.push(entry);
-                                    output_lines.push("".to_string());
-                                }
-                            }
-                        } else {
-                            // Both sections (default behavior)
-                            if !outside_entries.is_empty() {
-                                output_lines.push("OUTSIDE".to_string());
-                                output_lines.push("".to_string());
-                                for entry in outside_entries {
-                                    output_lines.push(entry);
-                                    output_lines.push("".to_string());
-                                }
-                            }
-
-                            if !inside_entries.is_empty() {
-                                output_lines.push("INSIDE".to_string());
-                                output_lines.push("".to_string());
-                                for entry in inside_entries {
-                                    output_lines.push(entry);
-                                    output_lines.push("".to_string());
-                                }
-                            }
-                        }
-
-                        output_lines.join("\n")
-                    }
+                    json_ops::JsonOperations::validate_strict(content)
+                };
+                if let Err(e) = checked {
+                    println!("{}: strict: {}", source, e);
+                    clean = false;
                 }
             }
-    };
+            failed |= !clean;
+        }
+        if failed {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     // --order / --order-percentage / --order-name / --order-random
     if let Some(op) = order_op {
@@ -586,6 +705,7 @@ fn main() -> Result<()> {
             match op {
                 "order"            => app.order_entries(),
                 "order-percentage" => app.order_by_percentage(),
+                "order-priority"   => app.order_by_priority(),
                 "order-name"       => app.order_by_name(),
                 "order-random"     => app.order_random(),
                 _ => unreachable!(),
@@ -610,16 +730,43 @@ fn main() -> Result<()> {
             eprintln!("Error: --append requires a file argument");
             std::process::exit(1);
         }
-        if !stdin_piped {
-            eprintln!("Error: --append requires stdin input");
+        let mut stdin_content = String::new();
+        if let Some(input_path) = &append_input {
+            stdin_content = fs::read_to_string(input_path).unwrap_or_else(|e| {
+                eprintln!("Error: Cannot read '{}': {}", input_path.display(), e); std::process::exit(1);
+            });
+        } else if stdin_piped {
+            io::stdin().read_to_string(&mut stdin_content)?;
+        }
+        if stdin_content.trim().is_empty() {
+            eprintln!("Error: --append requires --input FILE or stdin input");
             std::process::exit(1);
         }
-        let mut stdin_content = String::new();
-        io::stdin().read_to_string(&mut stdin_content)?;
+        let stdin_content = line_endings::normalize_owned(stdin_content).0;
 
-        // Parse stdin as JSON or Markdown using a temp app
-        let tmp = App::new(format_mode);
-        let stdin_json: serde_json::Value = if stdin_content.trim_start().starts_with('{') || stdin_content.trim_start().starts_with('[') {
+        // Parse stdin as a browser bookmark export, JSON or Markdown
+        let bookmark_format = import_ops::detect(&stdin_content);
+        let input_format = match &append_input {
+            Some(input_path) => Format::for_file(input_path, &stdin_content),
+            None => Format::detect(&stdin_content),
+        };
+        if bookmark_format.is_none() && input_format == Format::Toon {
+            eprintln!("Error: TOON input is not supported yet");
+            std::process::exit(1);
+        }
+        if bookmark_format.is_none() && !bookmark_maps.is_empty() {
+            eprintln!("Error: --map only applies to browser bookmark exports (HTML or JSON)");
+            std::process::exit(1);
+        }
+        let stdin_json: serde_json::Value = if let Some(format) = bookmark_format {
+            let mapping = import_ops::BookmarkMapping::from_specs(bookmark_maps.iter().copied()).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e); std::process::exit(1);
+            });
+            let bookmarks = import_ops::parse(&stdin_content, format).unwrap_or_else(|e| {
+                eprintln!("Error: {}", e); std::process::exit(1);
+            });
+            import_ops::bookmarks_to_entries(&bookmarks, &mapping)
+        } else if input_format == Format::Json {
             let v: serde_json::Value = match serde_json::from_str(&stdin_content) {
                 Ok(v) => v,
                 Err(e) => { eprintln!("Error: stdin is not valid JSON: {}", e); std::process::exit(1); }
@@ -652,7 +799,7 @@ fn main() -> Result<()> {
             } else {
                 stdin_content.clone()
             };
-            match tmp.parse_markdown(&processed) {
+            match Converter::new().from(Format::Md).to(Format::Json).convert(&processed) {
                 Ok(json_str) => match serde_json::from_str(&json_str) {
                     Ok(v) => v,
                     Err(e) => { eprintln!("Error parsing stdin Markdown: {}", e); std::process::exit(1); }
@@ -672,21 +819,130 @@ fn main() -> Result<()> {
                 eprintln!("Error: Invalid JSON in '{}': {}", file_path, e); std::process::exit(1);
             });
 
-            let merged = json_ops::JsonOperations::append_entries(&current, &stdin_json, inside_only, outside_only);
+            let merged = json_ops::JsonOperations::append_entries_with(&current, &stdin_json, inside_only, outside_only, merge_strategy);
             let output = serde_json::to_string_pretty(&merged).unwrap();
 
             if app.is_markdown_file() {
                 // Write back as Markdown
                 app.json_input = output;
                 app.sync_markdown_from_json();
-                fs::write(&path, &app.markdown_input).unwrap_or_else(|e| {
+                fs::write(&path, app.with_line_endings(app.text_format, &app.markdown_input)).unwrap_or_else(|e| {
                     eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
                 });
             } else {
-                fs::write(&path, output).unwrap_or_else(|e| {
+                fs::write(&path, app.with_line_endings(app.text_format, &output)).unwrap_or_else(|e| {
                     eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
                 });
             }
+
+            let format = if app.is_markdown_file() { "md" } else { "json" };
+            if safe_mode {
+                continue;
+            }
+            if let Err(e) = app.hooks.run(hooks::HookEvent::PostImport, &path, format) {
+                eprintln!("Error: {}: {}", file_path, e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // --import-obsidian: daily notes from a folder become INSIDE entries of file(s)
+    if let Some(dir) = import_dir {
+        if file_paths.is_empty() {
+            eprintln!("Error: --import-obsidian requires a file argument");
+            std::process::exit(1);
+        }
+        let daily = import_ops::read_daily_notes(&dir).unwrap_or_else(|e| {
+            eprintln!("Error: {}", e); std::process::exit(1);
+        });
+        for path in &daily.skipped {
+            eprintln!("Skipped {}: no date in the file name", path.display());
+        }
+
+        for file_path in &file_paths {
+            let path = PathBuf::from(file_path);
+            let mut app = App::new(format_mode);
+            load_content(&mut app, fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Error: Cannot read '{}': {}", file_path, e); std::process::exit(1);
+            }), Some(path.clone()));
+
+            let current: serde_json::Value = serde_json::from_str(&app.json_input).unwrap_or_else(|e| {
+                eprintln!("Error: Invalid JSON in '{}': {}", file_path, e); std::process::exit(1);
+            });
+            let (entries, duplicates) = import_ops::daily_notes_to_entries(&daily.notes, &current);
+            let merged = json_ops::JsonOperations::append_entries(&current, &entries, true, false);
+            let output = serde_json::to_string_pretty(&merged).unwrap();
+
+            if app.is_markdown_file() {
+                app.json_input = output;
+                app.sync_markdown_from_json();
+                fs::write(&path, app.with_line_endings(app.text_format, &app.markdown_input)).unwrap_or_else(|e| {
+                    eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
+                });
+            } else {
+                fs::write(&path, app.with_line_endings(app.text_format, &output)).unwrap_or_else(|e| {
+                    eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
+                });
+            }
+            println!(
+                "{}: imported {} note(s), {} already there",
+                file_path,
+                daily.notes.len() - duplicates,
+                duplicates
+            );
+
+            let format = if app.is_markdown_file() { "md" } else { "json" };
+            if safe_mode {
+                continue;
+            }
+            if let Err(e) = app.hooks.run(hooks::HookEvent::PostImport, &path, format) {
+                eprintln!("Error: {}: {}", file_path, e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    // --rotate monthly: old INSIDE notes move into monthly archive files
+    if rotate_period.is_some() {
+        if file_paths.is_empty() {
+            eprintln!("Error: --rotate requires a file argument");
+            std::process::exit(1);
+        }
+        let today = chrono::Local::now().date_naive();
+        for file_path in &file_paths {
+            let path = PathBuf::from(file_path);
+            let mut app = App::new(format_mode);
+            load_content(&mut app, fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Error: Cannot read '{}': {}", file_path, e); std::process::exit(1);
+            }), Some(path.clone()));
+
+            let current: serde_json::Value = serde_json::from_str(&app.json_input).unwrap_or_else(|e| {
+                eprintln!("Error: Invalid JSON in '{}': {}", file_path, e); std::process::exit(1);
+            });
+            if journal::is_journal(&current) {
+                eprintln!("Error: {}: journal files keep their INSIDE history", file_path);
+                std::process::exit(1);
+            }
+            let (kept, archives) = rotate::rotate_monthly(&path, &current, today).unwrap_or_else(|e| {
+                eprintln!("Error: {}: {}", file_path, e); std::process::exit(1);
+            });
+            if !archives.is_empty() {
+                let output = serde_json::to_string_pretty(&kept).unwrap();
+                if app.is_markdown_file() {
+                    app.json_input = output;
+                    app.sync_markdown_from_json();
+                    fs::write(&path, &app.markdown_input).unwrap_or_else(|e| {
+                        eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
+                    });
+                } else {
+                    fs::write(&path, output).unwrap_or_else(|e| {
+                        eprintln!("Error: Cannot write '{}': {}", file_path, e); std::process::exit(1);
+                    });
+                }
+            }
+            println!("{}: {}", file_path, rotate::summary(&archives));
         }
         return Ok(());
     }
@@ -734,14 +990,13 @@ fn main() -> Result<()> {
 
     // Helper: apply filter to app's json_input (and sync markdown if needed)
     let apply_filter_to_app = |app: &mut App| {
-        if let Some(pattern) = &filter_pattern {
-            if let Ok(json_val) = serde_json::from_str::<serde_json::Value>(&app.json_input) {
-                let filtered = json_ops::JsonOperations::filter_entries(&json_val, pattern);
-                if let Ok(s) = serde_json::to_string_pretty(&filtered) {
-                    app.json_input = s;
-                    if app.is_markdown_file() {
-                        app.sync_markdown_from_json();
-                    }
+        if let Some(pattern) = &filter_pattern
+            && let Ok(json_val) = serde_json::from_str::<serde_json::Value>(&app.json_input) {
+            let filtered = json_ops::JsonOperations::filter_entries(&json_val, pattern);
+            if let Ok(s) = serde_json::to_string_pretty(&filtered) {
+                app.json_input = s;
+                if app.is_markdown_file() {
+                    app.sync_markdown_from_json();
                 }
             }
         }
@@ -774,6 +1029,73 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    // --diff: entry-level changes from the first file to the second
+    if diff_mode {
+        if file_paths.len() != 2 {
+            eprintln!("Error: --diff needs two files: revw --diff OLD NEW");
+            std::process::exit(1);
+        }
+        let mut documents = Vec::new();
+        for file_path in &file_paths {
+            let path = PathBuf::from(file_path);
+            let content = fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Error: {}: {}", file_path, e);
+                std::process::exit(1);
+            });
+            let mut app = App::new(format_mode);
+            load_content(&mut app, content, Some(path));
+            let value: serde_json::Value = serde_json::from_str(&app.json_input).unwrap_or_else(|e| {
+                eprintln!("Error: {}: {}", file_path, e);
+                std::process::exit(1);
+            });
+            documents.push(value);
+        }
+        let changes = diff::diff(&documents[0], &documents[1]);
+        if json_mode {
+            println!("{}", serde_json::to_string_pretty(&diff::to_json(&changes)).unwrap_or_default());
+        } else {
+            println!("{}", diff::render_text(&changes));
+        }
+        if !changes.is_empty() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // --report: weekly review summary of each file
+    if report_mode {
+        let mut apps = Vec::new();
+        if file_paths.is_empty() && stdin_piped {
+            let mut app = App::new(format_mode);
+            let mut content = String::new();
+            io::stdin().read_to_string(&mut content)?;
+            load_content(&mut app, content, None);
+            apps.push(("stdin".to_string(), app));
+        } else if file_paths.is_empty() {
+            eprintln!("Error: --report requires a file argument or stdin input");
+            std::process::exit(1);
+        }
+        for file_path in &file_paths {
+            let mut app = App::new(format_mode);
+            app.load_file(PathBuf::from(file_path));
+            apps.push((file_path.clone(), app));
+        }
+        let today = chrono::Local::now().date_naive();
+        for (idx, (source, app)) in apps.iter().enumerate() {
+            if idx > 0 {
+                println!();
+            }
+            match report::generate(&app.json_input, today) {
+                Ok(text) => print!("{}", text),
+                Err(e) => {
+                    eprintln!("Error: {}: {}", source, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        return Ok(());
+    }
+
     if stdout_mode || stdin_piped {
         if file_paths.is_empty() && stdin_piped {
             // Read from stdin
@@ -781,7 +1103,7 @@ fn main() -> Result<()> {
             let mut content = String::new();
             io::stdin().read_to_string(&mut content)?;
             load_content(&mut app, content, None);
-            println!("{}", generate_output(&app));
+            print_output(&generate_output(&app));
         } else if file_paths.is_empty() {
             eprintln!("Error: No input file specified and no stdin data");
             std::process::exit(1);
@@ -795,23 +1117,55 @@ fn main() -> Result<()> {
                         std::process::exit(1);
                     })
                     .unwrap();
-                let mut app = App::new(format_mode);
+                let mut app = App::with_config(format_mode, project_config(Some(file_path)));
                 load_content(&mut app, content, Some(path));
-                if file_paths.len() > 1 {
+                if file_paths.len() > 1 && !plain_mode {
                     if idx > 0 { println!(); }
                     println!("=== {} ===", file_path);
                 }
-                println!("{}", generate_output(&app));
+                print_output(&generate_output(&app));
             }
         }
     } else {
         // Interactive mode with better error handling
-        let mut app = App::new(format_mode);
+        let rc_config = project_config(file_paths.first().map(|path| path.as_str()));
+        let default_file = rc_config.default_file.clone();
+        let mut app = App::with_config(format_mode, rc_config);
+        if strict_mode {
+            app.strict_parsing = true;
+        }
 
-        // Load file if provided (first file only for interactive mode)
-        if let Some(file_path) = file_paths.first() {
+        // --log-file is already logging; otherwise the rc file may name one
+        let mut log_error = None;
+        if log_file.is_some() {
+            app.log_file = log_file;
+        } else if let Some(path) = app.log_file.clone()
+            && let Err(e) = logging::init(&path) {
+            app.log_file = None;
+            log_error = Some(e);
+        }
+
+        app.recent_list_path = recent::default_path();
+        app.swap_root = swap::default_root();
+        app.lang = app.lang.resolve();
+        if safe_mode {
+            app.enter_safe_mode();
+        }
+
+        // Load the file if provided, several files as one merged view,
+        // otherwise start on the recent files list when there is one
+        if file_paths.len() > 1 {
+            app.open_sources(file_paths.iter().map(PathBuf::from).collect());
+        } else if let Some(file_path) = file_paths.first() {
             let path = PathBuf::from(file_path);
-            app.load_file(path);
+            app.open_or_offer(path);
+        } else if let Some(path) = default_file {
+            app.open_or_offer(path);
+        } else {
+            app.open_recent_list();
+            if !app.recent_open {
+                app.set_status("");
+            }
         }
 
         // Pre-apply filter from --filter flag
@@ -819,6 +1173,9 @@ fn main() -> Result<()> {
             app.filter_pattern = pattern.to_string();
             app.convert_json();
         }
+        if let Some(e) = log_error {
+            app.set_status(&e);
+        }
 
         // Set up terminal with error handling
         let setup_result = (|| -> Result<Terminal<CrosstermBackend<std::io::Stdout>>> {
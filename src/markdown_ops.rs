@@ -1,5 +1,12 @@
 use chrono::Local;
+use std::borrow::Cow;
+use serde_json::{json, Map, Value};
 use crate::content_ops::ContentOperations;
+use crate::due;
+use crate::fields;
+use crate::journal;
+use crate::priority;
+use crate::status::{self, EntryStatus};
 
 pub struct MarkdownOperations;
 
@@ -31,8 +38,8 @@ impl MarkdownOperations {
             }
 
             // Check for entry headers (### Title) or any non-empty line as implicit entry
-            let (title, has_header) = if line.starts_with("### ") {
-                (line[4..].trim().to_string(), true)
+            let (title, has_header) = if let Some(rest) = line.strip_prefix("### ") {
+                (rest.trim().to_string(), true)
             } else if current_section.is_some() {
                 // Treat first line as implicit title for entries without ###
                 (line.to_string(), false)
@@ -47,6 +54,10 @@ impl MarkdownOperations {
                 let mut content_lines = Vec::new();
                 let mut url = String::new();
                 let mut percentage: Option<i64> = None;
+                let mut priority: Option<String> = None;
+                let mut due: Option<String> = None;
+                let mut attachments = Vec::new();
+                let mut custom_lines = Vec::new();
 
                 // For entries without headers, the first line might contain content
                 if !has_header {
@@ -80,14 +91,14 @@ impl MarkdownOperations {
                         }
                     }
 
-                    if trimmed.starts_with("**URL:**") {
-                        url = trimmed[8..].trim().to_string();
+                    if let Some(rest) = trimmed.strip_prefix("**URL:**") {
+                        url = rest.trim().to_string();
                         i += 1;
                         continue;
                     }
 
-                    if trimmed.starts_with("**Percentage:**") {
-                        let pct_str = trimmed[15..].trim().trim_end_matches('%');
+                    if let Some(rest) = trimmed.strip_prefix("**Percentage:**") {
+                        let pct_str = rest.trim().trim_end_matches('%');
                         if let Ok(pct) = pct_str.parse::<i64>() {
                             percentage = Some(pct);
                         }
@@ -95,11 +106,41 @@ impl MarkdownOperations {
                         continue;
                     }
 
+                    if let Some(rest) = trimmed.strip_prefix("**Priority:**") {
+                        if !rest.trim().is_empty() {
+                            priority = Some(rest.trim().to_string());
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    if let Some(rest) = Self::strip_due_label(trimmed) {
+                        if !rest.trim().is_empty() {
+                            due = Some(rest.trim().to_string());
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    if let Some(rest) = trimmed.strip_prefix("**Attachment:**") {
+                        if !rest.trim().is_empty() {
+                            attachments.push(rest.trim().to_string());
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    if fields::parse_markdown_line(trimmed).is_some() {
+                        custom_lines.push(trimmed.to_string());
+                        i += 1;
+                        continue;
+                    }
+
                     content_lines.push(content_line);
                     i += 1;
                 }
 
-                while content_lines.last().map_or(false, |l| l.trim().is_empty()) {
+                while content_lines.last().is_some_and(|l| l.trim().is_empty()) {
                     content_lines.pop();
                 }
 
@@ -113,6 +154,10 @@ impl MarkdownOperations {
                         context,
                         url,
                         percentage,
+                        priority,
+                        due,
+                        attachments,
+                        custom_lines,
                         start_line,
                         end_line,
                     });
@@ -125,6 +170,427 @@ impl MarkdownOperations {
         entries
     }
 
+    /// Parse Markdown content and convert to JSON format
+    pub fn parse_to_json(content: &str) -> Result<String, String> {
+        let mut outside_entries = Vec::new();
+        let mut inside_entries = Vec::new();
+
+        let lines: Vec<&str> = content.lines().collect();
+        // Front matter carries file flags, not entries
+        let mut i = journal::front_matter(content).map_or(0, |front| front.lines);
+        let mut current_section = None; // "OUTSIDE" or "INSIDE"
+        let mut in_code_block = false;
+
+        while i < lines.len() {
+            let line = lines[i].trim();
+
+            // Check for code block markers (```)
+            if line.starts_with("```") {
+                in_code_block = !in_code_block;
+                i += 1;
+                continue;
+            }
+
+            // Skip lines inside code blocks
+            if in_code_block {
+                i += 1;
+                continue;
+            }
+
+            // Check for section headers
+            if line == "## OUTSIDE" {
+                current_section = Some("OUTSIDE");
+                i += 1;
+                continue;
+            } else if line == "## INSIDE" {
+                current_section = Some("INSIDE");
+                i += 1;
+                continue;
+            }
+
+            // Skip empty lines
+            if line.is_empty() {
+                i += 1;
+                continue;
+            }
+
+            // Check for entry headers (### Title) or any non-empty line as implicit entry
+            // Reject #### or higher (only allow ### for entries)
+            let (title, has_header) = if line.starts_with("#### ") || (line.starts_with("####") && !line.starts_with("### ") && !line.starts_with("###")) {
+                // Ignore #### or higher level headers
+                i += 1;
+                continue;
+            } else if let Some(rest) = line.strip_prefix("### ") {
+                let trimmed = rest.trim();
+                // If only "###" with nothing after it, treat as empty string
+                (trimmed.to_string(), true)
+            } else if let Some(rest) = line.strip_prefix("###") {
+                // Handle "###" without space (edge case)
+                let trimmed = rest.trim();
+                (trimmed.to_string(), true)
+            } else if current_section.is_some() {
+                // Treat first line as implicit title for entries without ###
+                (line.to_string(), false)
+            } else {
+                i += 1;
+                continue;
+            };
+
+            if has_header || current_section.is_some() {
+                // Collect content until next header or end
+                let mut content_lines: Vec<Cow<str>> = Vec::new();
+                let mut url: Option<String> = None;
+                let mut percentage: Option<i64> = None;
+                let mut priority: Option<Value> = None;
+                let mut due: Option<String> = None;
+                let mut attachments: Vec<String> = Vec::new();
+                let mut custom: Vec<(String, Value)> = Vec::new();
+
+                // For entries without headers, the first line might contain content
+                if !has_header {
+                    // The title line itself is the content for headerless entries
+                    // We'll move to next line
+                    i += 1;
+                } else {
+                    i += 1;
+                }
+
+                while i < lines.len() {
+                    let content_line = lines[i];
+                    let trimmed = content_line.trim();
+
+                    // Track code blocks within content
+                    if trimmed.starts_with("```") {
+                        in_code_block = !in_code_block;
+                        // Include the code block markers in content
+                        content_lines.push(content_line.into());
+                        i += 1;
+                        continue;
+                    }
+
+                    // Only check for headers outside of code blocks
+                    if !in_code_block {
+                        // Stop at next section or entry header (## or ###, but not ####)
+                        if trimmed.starts_with("## ") || (trimmed.starts_with("### ") || (trimmed.starts_with("###") && !trimmed.starts_with("####"))) {
+                            break;
+                        }
+                    }
+
+                    // Stop at blank lines followed by non-empty lines (potential new entry)
+                    // This only applies to entries WITHOUT ### headers
+                    if !has_header && trimmed.is_empty() && i + 1 < lines.len() {
+                        let next_line = lines[i + 1].trim();
+                        if !next_line.is_empty()
+                            && !next_line.starts_with("**")
+                            && !next_line.starts_with("## ")
+                            && !next_line.starts_with("####")
+                            && !next_line.starts_with("###") {
+                            // Next entry starts after this blank line
+                            i += 1; // Skip the blank line
+                            break;
+                        }
+                    }
+
+                    // Check for URL
+                    if let Some(rest) = trimmed.strip_prefix("**URL:**") {
+                        url = Some(rest.trim().to_string());
+                        i += 1;
+                        continue;
+                    }
+
+                    // Check for Attachment (one per line)
+                    if let Some(rest) = trimmed.strip_prefix("**Attachment:**") {
+                        let path = rest.trim();
+                        if !path.is_empty() {
+                            attachments.push(path.to_string());
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    // Check for Percentage
+                    if let Some(rest) = trimmed.strip_prefix("**Percentage:**") {
+                        let pct_str = rest.trim().trim_end_matches('%');
+                        if let Ok(pct) = pct_str.parse::<i64>() {
+                            percentage = Some(pct);
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    // Check for Priority (high/medium/low or 1-5)
+                    if let Some(rest) = trimmed.strip_prefix("**Priority:**") {
+                        let text = rest.trim();
+                        if !text.is_empty() {
+                            // Unknown values are kept as text; strict mode reports them
+                            priority = Some(priority::value_from_text(text).unwrap_or_else(|| json!(text)));
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    // Check for Due date (YYYY-MM-DD; other text is kept for strict mode to report)
+                    if let Some(rest) = Self::strip_due_label(trimmed) {
+                        if !rest.trim().is_empty() {
+                            due = Some(rest.trim().to_string());
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    // Check for custom fields (`**key:** value`, lowercase keys only)
+                    if !in_code_block
+                        && let Some((key, value)) = fields::parse_markdown_line(trimmed) {
+                        custom.push((key.to_string(), fields::text_to_value(value)));
+                        i += 1;
+                        continue;
+                    }
+
+                    // Skip empty lines at the end
+                    if !trimmed.is_empty() || !content_lines.is_empty() {
+                        content_lines.push(Self::unescape_line(content_line, in_code_block));
+                    }
+
+                    i += 1;
+                }
+
+                // Remove trailing empty lines
+                while content_lines.last().is_some_and(|l| l.trim().is_empty()) {
+                    content_lines.pop();
+                }
+
+                let context = content_lines.join("\n");
+
+                match current_section {
+                    Some("OUTSIDE") => {
+                        let mut entry = json!({
+                            "name": title,
+                            "context": context,
+                            "url": url.unwrap_or_default(),
+                            "percentage": percentage
+                        });
+                        if let Some(priority) = priority {
+                            entry["priority"] = priority;
+                        }
+                        if let Some(due) = due {
+                            entry["due"] = json!(due);
+                        }
+                        if !attachments.is_empty() {
+                            entry["attachments"] = json!(attachments);
+                        }
+                        for (key, value) in custom {
+                            entry[key] = value;
+                        }
+                        outside_entries.push(entry);
+                    }
+                    Some("INSIDE") => {
+                        let mut entry = json!({
+                            "date": title,
+                            "context": context
+                        });
+                        if !attachments.is_empty() {
+                            entry["attachments"] = json!(attachments);
+                        }
+                        // Not an INSIDE field, but kept like any other custom field
+                        if let Some(due) = due {
+                            entry["due"] = json!(due);
+                        }
+                        for (key, value) in custom {
+                            entry[key] = value;
+                        }
+                        inside_entries.push(entry);
+                    }
+                    Some(_) | None => {
+                        // Entry outside of any section or unknown section, skip
+                    }
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut json_value = Map::new();
+        if journal::markdown_is_journal(content) {
+            json_value.insert(journal::KEY.to_string(), json!(true));
+        }
+        json_value.insert("outside".to_string(), json!(outside_entries));
+        json_value.insert("inside".to_string(), json!(inside_entries));
+
+        serde_json::to_string_pretty(&json_value)
+            .map_err(|e| format!("JSON serialization error: {}", e))
+    }
+
+    /// Convert JSON value to Markdown string format
+    pub fn render_from_json(json_value: &Value) -> String {
+        let mut output_lines = Vec::new();
+
+        if journal::is_journal(json_value) {
+            output_lines.push(journal::FRONT_MATTER.to_string());
+            output_lines.push("".to_string());
+        }
+
+        if let Some(obj) = json_value.as_object() {
+            // OUTSIDE section
+            if let Some(outside) = obj.get("outside").and_then(|v| v.as_array())
+                && !outside.is_empty() {
+                output_lines.push("## OUTSIDE".to_string());
+                output_lines.push("".to_string());
+
+                for item in outside {
+                    if let Some(item_obj) = item.as_object() {
+                        let name = item_obj.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                        let context = item_obj.get("context").and_then(|v| v.as_str()).unwrap_or("");
+                        let url = item_obj.get("url").and_then(|v| v.as_str());
+                        let percentage = item_obj.get("percentage").and_then(|v| v.as_i64());
+
+                        // Always emit the header so an empty name can't turn the context into a title
+                        output_lines.push(format!("### {}", name));
+
+                        if !context.is_empty() {
+                            output_lines.push(Self::escape_context(context));
+                        }
+
+                        // Only output URL if it's not null and not empty
+                        if let Some(url_str) = url
+                            && !url_str.is_empty() {
+                            output_lines.push("".to_string());
+                            output_lines.push(format!("**URL:** {}", url_str));
+                        }
+
+                        // Only output percentage if it's not null
+                        if let Some(pct) = percentage {
+                            output_lines.push("".to_string());
+                            output_lines.push(format!("**Percentage:** {}%", pct));
+                        }
+
+                        if let Some(priority) = item_obj.get("priority").filter(|v| !v.is_null()) {
+                            output_lines.push("".to_string());
+                            output_lines.push(format!("**Priority:** {}", fields::display_value(priority)));
+                        }
+
+                        if let Some(due) = item_obj.get("due").filter(|v| !v.is_null()) {
+                            output_lines.push("".to_string());
+                            output_lines.push(format!("**Due:** {}", fields::display_value(due)));
+                        }
+
+                        Self::push_attachment_lines(&mut output_lines, item_obj);
+                        Self::push_custom_field_lines(&mut output_lines, item_obj, "outside");
+
+                        output_lines.push("".to_string());
+                    }
+                }
+            }
+
+            // INSIDE section
+            if let Some(inside) = obj.get("inside").and_then(|v| v.as_array())
+                && !inside.is_empty() {
+                output_lines.push("## INSIDE".to_string());
+                output_lines.push("".to_string());
+
+                for item in inside {
+                    if let Some(item_obj) = item.as_object() {
+                        let date = item_obj.get("date").and_then(|v| v.as_str()).unwrap_or("");
+                        let context = item_obj.get("context").and_then(|v| v.as_str()).unwrap_or("");
+
+                        output_lines.push(format!("### {}", date));
+
+                        if !context.is_empty() {
+                            output_lines.push(Self::escape_context(context));
+                        }
+
+                        Self::push_attachment_lines(&mut output_lines, item_obj);
+                        Self::push_custom_field_lines(&mut output_lines, item_obj, "inside");
+
+                        output_lines.push("".to_string());
+                    }
+                }
+            }
+        }
+
+        output_lines.join("\n")
+    }
+
+    /// Context as Markdown lines. A line that would read back as a section or entry
+    /// header, a `**label:**` field or an unclosed code fence gets a `\` in front, and
+    /// so does one that already starts with such an escape.
+    fn escape_context(context: &str) -> String {
+        let lines: Vec<&str> = context.lines().collect();
+        let mut in_fence = false;
+        let mut escaped = Vec::with_capacity(lines.len());
+        for (idx, line) in lines.iter().enumerate() {
+            let text = line.trim_start();
+            let escape = if text.starts_with("```") {
+                // A fence without a partner would swallow the entries after it
+                let closes = in_fence || lines[idx + 1..].iter().any(|l| l.trim_start().starts_with("```"));
+                if closes {
+                    in_fence = !in_fence;
+                }
+                !closes
+            } else {
+                Self::needs_escape(text, in_fence)
+            };
+            if escape {
+                let indent = &line[..line.len() - text.len()];
+                escaped.push(format!("{}\\{}", indent, text));
+            } else {
+                escaped.push(line.to_string());
+            }
+        }
+        escaped.join("\n")
+    }
+
+    /// Undo `escape_context` for one context line
+    fn unescape_line(line: &str, in_fence: bool) -> Cow<'_, str> {
+        let text = line.trim_start();
+        match text.strip_prefix('\\') {
+            Some(rest) if Self::needs_escape(rest, in_fence) => {
+                let indent = &line[..line.len() - text.len()];
+                Cow::Owned(format!("{}{}", indent, rest))
+            }
+            _ => Cow::Borrowed(line),
+        }
+    }
+
+    /// Whether a context line (without its indent) would be read as something else
+    fn needs_escape(text: &str, in_fence: bool) -> bool {
+        if let Some(rest) = text.strip_prefix('\\') {
+            return Self::needs_escape(rest, in_fence);
+        }
+        text.starts_with("```")
+            || (!in_fence && (text.starts_with("## ") || text.starts_with("###")))
+            || ["**URL:**", "**Attachment:**", "**Percentage:**", "**Priority:**"].iter().any(|label| text.starts_with(label))
+            || Self::strip_due_label(text).is_some()
+            || fields::parse_markdown_line(text).is_some()
+    }
+
+    /// Append `**Attachment:**` lines for an entry's attachments (if any)
+    fn push_attachment_lines(output_lines: &mut Vec<String>, item_obj: &Map<String, Value>) {
+        let attachments: Vec<&str> = item_obj
+            .get("attachments")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        if attachments.is_empty() {
+            return;
+        }
+        output_lines.push("".to_string());
+        for path in attachments {
+            output_lines.push(format!("**Attachment:** {}", path));
+        }
+    }
+
+    /// Append `**key:** value` lines for an entry's custom fields (if any)
+    fn push_custom_field_lines(output_lines: &mut Vec<String>, item_obj: &Map<String, Value>, section: &str) {
+        let custom = fields::custom_fields(item_obj, section);
+        if custom.is_empty() {
+            return;
+        }
+        output_lines.push("".to_string());
+        for (key, value) in custom {
+            output_lines.push(fields::markdown_line(key, value));
+        }
+    }
+
     /// Delete an entry at the cursor position
     pub fn delete_entry_at_cursor(
         markdown_input: &str,
@@ -191,14 +657,13 @@ impl MarkdownOperations {
             }
 
             // Insert after "## INSIDE" header
-            if let Some(section_start) = inside_section_start {
-                if i == section_start && !inserted {
-                    result_lines.push("".to_string());
-                    result_lines.push(new_entry.trim().to_string());
-                    insert_line = result_lines.len() - 1;
-                    result_lines.push("".to_string());
-                    inserted = true;
-                }
+            if let Some(section_start) = inside_section_start
+                && i == section_start && !inserted {
+                result_lines.push("".to_string());
+                result_lines.push(new_entry.trim().to_string());
+                insert_line = result_lines.len() - 1;
+                result_lines.push("".to_string());
+                inserted = true;
             }
         }
 
@@ -268,11 +733,16 @@ impl MarkdownOperations {
             result_lines.push("".to_string());
         }
 
-        // If no OUTSIDE section exists, create it at the beginning
+        // If no OUTSIDE section exists, create it at the beginning (below any front matter)
         if outside_section_start.is_none() {
-            let mut new_result = vec!["## OUTSIDE".to_string(), "".to_string(), new_entry.to_string(), "".to_string()];
-            insert_line = 2;
-            new_result.extend(result_lines);
+            let front_matter = journal::front_matter(markdown_input).map_or(0, |front| front.lines);
+            let mut new_result: Vec<String> = result_lines.drain(..front_matter).collect();
+            if front_matter > 0 {
+                new_result.push("".to_string());
+            }
+            insert_line = new_result.len() + 2;
+            new_result.extend(["## OUTSIDE".to_string(), "".to_string(), new_entry.to_string(), "".to_string()]);
+            new_result.extend(result_lines.into_iter().skip_while(|line| front_matter > 0 && line.trim().is_empty()));
             result_lines = new_result;
         }
 
@@ -286,7 +756,7 @@ impl MarkdownOperations {
     pub fn duplicate_entry_at_cursor(
         markdown_input: &str,
         cursor_line: usize,
-    ) -> Result<(String, String), String> {
+    ) -> Result<String, String> {
         let entries = Self::parse_entries(markdown_input);
 
         let entry_to_duplicate = entries.iter().position(|entry| {
@@ -318,10 +788,20 @@ impl MarkdownOperations {
                         result_lines.push("".to_string());
                         result_lines.push(format!("**Percentage:** {}%", pct));
                     }
+                    if let Some(priority) = &entry.priority {
+                        result_lines.push("".to_string());
+                        result_lines.push(format!("**Priority:** {}", priority));
+                    }
+                    if let Some(due) = &entry.due {
+                        result_lines.push("".to_string());
+                        result_lines.push(format!("**Due:** {}", due));
+                    }
+                    Self::push_attachments(&mut result_lines, &entry.attachments);
+                    Self::push_custom_lines(&mut result_lines, &entry.custom_lines);
                 }
             }
 
-            Ok((result_lines.join("\n"), "Entry duplicated".to_string()))
+            Ok(result_lines.join("\n"))
         } else {
             Err("Could not duplicate entry at cursor position".to_string())
         }
@@ -351,7 +831,7 @@ impl MarkdownOperations {
         // Sort inside by date desc (newest first)
         inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
 
-        Ok((Self::reconstruct_markdown(&outside_entries, &inside_entries), "Ordered".to_string()))
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Ordered".to_string()))
     }
 
     /// Order entries by percentage only
@@ -376,7 +856,55 @@ impl MarkdownOperations {
         // Sort inside by date desc
         inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
 
-        Ok((Self::reconstruct_markdown(&outside_entries, &inside_entries), "Ordered by percentage".to_string()))
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Ordered by percentage".to_string()))
+    }
+
+    /// Order entries by priority only (highest first, entries without one last)
+    pub fn order_by_priority(markdown_input: &str) -> Result<(String, String), String> {
+        let entries = Self::parse_entries(markdown_input);
+
+        let mut outside_entries: Vec<_> = entries.iter()
+            .filter(|e| matches!(e.section, Section::Outside))
+            .cloned()
+            .collect();
+
+        let mut inside_entries: Vec<_> = entries.iter()
+            .filter(|e| matches!(e.section, Section::Inside))
+            .cloned()
+            .collect();
+
+        // Sort outside by priority rank desc only
+        outside_entries.sort_by_key(|e| {
+            std::cmp::Reverse(e.priority.as_deref().and_then(priority::rank_from_text).unwrap_or(0))
+        });
+
+        // Sort inside by date desc
+        inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
+
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Ordered by priority".to_string()))
+    }
+
+    /// Order entries by due date (soonest first, entries without one last)
+    pub fn order_by_due(markdown_input: &str) -> Result<(String, String), String> {
+        let entries = Self::parse_entries(markdown_input);
+
+        let mut outside_entries: Vec<_> = entries.iter()
+            .filter(|e| matches!(e.section, Section::Outside))
+            .cloned()
+            .collect();
+
+        let mut inside_entries: Vec<_> = entries.iter()
+            .filter(|e| matches!(e.section, Section::Inside))
+            .cloned()
+            .collect();
+
+        // Sort outside by due date asc, undated last
+        outside_entries.sort_by_key(|e| e.due.as_deref().and_then(due::parse).map_or((1, None), |date| (0, Some(date))));
+
+        // Sort inside by date desc
+        inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
+
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Ordered by due date".to_string()))
     }
 
     /// Order entries by name only
@@ -399,7 +927,7 @@ impl MarkdownOperations {
         // Sort inside by date desc
         inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
 
-        Ok((Self::reconstruct_markdown(&outside_entries, &inside_entries), "Ordered by name".to_string()))
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Ordered by name".to_string()))
     }
 
     /// Order entries randomly (outside only)
@@ -425,21 +953,22 @@ impl MarkdownOperations {
         // Sort inside by date desc
         inside_entries.sort_by(|a, b| b.title.cmp(&a.title));
 
-        Ok((Self::reconstruct_markdown(&outside_entries, &inside_entries), "Randomized outside entries".to_string()))
+        Ok((Self::reconstruct_markdown(markdown_input, &outside_entries, &inside_entries), "Randomized outside entries".to_string()))
     }
 
-    /// Reconstruct markdown from sorted entries
-    fn reconstruct_markdown(outside_entries: &[Entry], inside_entries: &[Entry]) -> String {
-        let mut lines = Vec::new();
+    /// Reconstruct markdown from sorted entries, keeping the front matter of `markdown_input`
+    fn reconstruct_markdown(markdown_input: &str, outside_entries: &[Entry], inside_entries: &[Entry]) -> String {
+        let mut lines: Vec<String> = journal::front_matter_lines(markdown_input).into_iter().map(String::from).collect();
+        if !lines.is_empty() {
+            lines.push("".to_string());
+        }
 
         if !outside_entries.is_empty() {
             lines.push("## OUTSIDE".to_string());
             lines.push("".to_string());
 
             for entry in outside_entries {
-                if !entry.title.is_empty() {
-                    lines.push(format!("### {}", entry.title));
-                }
+                lines.push(format!("### {}", entry.title));
                 if !entry.context.is_empty() {
                     lines.push(entry.context.clone());
                 }
@@ -451,6 +980,16 @@ impl MarkdownOperations {
                     lines.push("".to_string());
                     lines.push(format!("**Percentage:** {}%", pct));
                 }
+                if let Some(priority) = &entry.priority {
+                    lines.push("".to_string());
+                    lines.push(format!("**Priority:** {}", priority));
+                }
+                if let Some(due) = &entry.due {
+                    lines.push("".to_string());
+                    lines.push(format!("**Due:** {}", due));
+                }
+                Self::push_attachments(&mut lines, &entry.attachments);
+                Self::push_custom_lines(&mut lines, &entry.custom_lines);
                 lines.push("".to_string());
             }
         }
@@ -460,18 +999,147 @@ impl MarkdownOperations {
             lines.push("".to_string());
 
             for entry in inside_entries {
-                if !entry.title.is_empty() {
-                    lines.push(format!("### {}", entry.title));
-                }
+                lines.push(format!("### {}", entry.title));
                 if !entry.context.is_empty() {
                     lines.push(entry.context.clone());
                 }
+                Self::push_attachments(&mut lines, &entry.attachments);
+                Self::push_custom_lines(&mut lines, &entry.custom_lines);
                 lines.push("".to_string());
             }
         }
 
         lines.join("\n")
     }
+
+    /// Text after a `**Due:**` label (`**due:**` from files that kept it as a custom field)
+    fn strip_due_label(line: &str) -> Option<&str> {
+        line.strip_prefix("**Due:**").or_else(|| line.strip_prefix("**due:**"))
+    }
+
+    /// Append `**Attachment:**` lines (preceded by a blank line) when there are any
+    fn push_attachments(lines: &mut Vec<String>, attachments: &[String]) {
+        if attachments.is_empty() {
+            return;
+        }
+        lines.push("".to_string());
+        for path in attachments {
+            lines.push(format!("**Attachment:** {}", path));
+        }
+    }
+
+    /// Append raw custom field lines (preceded by a blank line) when there are any
+    fn push_custom_lines(lines: &mut Vec<String>, custom_lines: &[String]) {
+        if custom_lines.is_empty() {
+            return;
+        }
+        lines.push("".to_string());
+        lines.extend(custom_lines.iter().cloned());
+    }
+
+    /// Strict structure check: known sections, `###` headers for every entry,
+    /// and URL/Percentage lines only in OUTSIDE. Errors carry 1-based line numbers.
+    pub fn validate_strict(markdown_input: &str) -> Result<(), String> {
+        let mut current_section = None;
+        let mut in_entry = false;
+
+        let front_matter = match journal::front_matter(markdown_input) {
+            Some(front) => {
+                for (line_no, key, value) in front.pairs {
+                    if key != journal::KEY {
+                        return Err(format!("line {}: unknown front matter key \"{}\"", line_no, key));
+                    }
+                    if value != "true" && value != "false" {
+                        return Err(format!("line {}: journal must be true or false", line_no));
+                    }
+                }
+                front.lines
+            }
+            None => 0,
+        };
+
+        for (idx, raw_line) in markdown_input.lines().enumerate().skip(front_matter) {
+            let line_no = idx + 1;
+            let line = raw_line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("## ") {
+                current_section = match name.trim() {
+                    "OUTSIDE" => Some(Section::Outside),
+                    "INSIDE" => Some(Section::Inside),
+                    other => return Err(format!("line {}: unknown section \"{}\"", line_no, other)),
+                };
+                in_entry = false;
+                continue;
+            }
+
+            let section = current_section
+                .ok_or_else(|| format!("line {}: content outside of a ## section", line_no))?;
+
+            if line.starts_with("####") {
+                return Err(format!("line {}: unsupported header level", line_no));
+            }
+            if line.starts_with("###") {
+                in_entry = true;
+                continue;
+            }
+            if !in_entry {
+                return Err(format!("line {}: entry without ### header", line_no));
+            }
+
+            if let Some(rest) = line.strip_prefix("**Percentage:**") {
+                if section != Section::Outside {
+                    return Err(format!("line {}: **Percentage:** is only allowed in OUTSIDE", line_no));
+                }
+                let pct_str = rest.trim().trim_end_matches('%');
+                if !pct_str.is_empty() && pct_str.parse::<i64>().is_err() {
+                    return Err(format!("line {}: invalid percentage \"{}\"", line_no, rest.trim()));
+                }
+            } else if let Some(rest) = line.strip_prefix("**Priority:**") {
+                if section != Section::Outside {
+                    return Err(format!("line {}: **Priority:** is only allowed in OUTSIDE", line_no));
+                }
+                if priority::rank_from_text(rest).is_none() {
+                    return Err(format!("line {}: invalid priority \"{}\"", line_no, rest.trim()));
+                }
+            } else if let Some(rest) = Self::strip_due_label(line) {
+                if section != Section::Outside {
+                    return Err(format!("line {}: **Due:** is only allowed in OUTSIDE", line_no));
+                }
+                if due::parse(rest).is_none() {
+                    return Err(format!("line {}: invalid due date \"{}\"", line_no, rest.trim()));
+                }
+            } else if let Some(rest) = line.strip_prefix("**Attachment:**") {
+                if rest.trim().is_empty() {
+                    return Err(format!("line {}: empty attachment path", line_no));
+                }
+            } else if line.starts_with("**URL:**") && section != Section::Outside {
+                return Err(format!("line {}: **URL:** is only allowed in OUTSIDE", line_no));
+            } else if let Some(("tags", value)) = fields::parse_markdown_line(line) {
+                let is_list = fields::text_to_value(value)
+                    .as_array()
+                    .is_some_and(|arr| arr.iter().all(|v| v.is_string()));
+                if !is_list {
+                    return Err(format!("line {}: tags must be a list of strings", line_no));
+                }
+            } else if let Some((key, value)) = fields::parse_markdown_line(line) {
+                if key != status::KEY {
+                    return Err(format!("line {}: unknown field \"{}\"", line_no, key));
+                }
+                if section != Section::Outside {
+                    return Err(format!("line {}: **status:** is only allowed in OUTSIDE", line_no));
+                }
+                if EntryStatus::by_name(value).is_none() {
+                    return Err(format!("line {}: invalid status \"{}\"", line_no, value.trim()));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -487,6 +1155,10 @@ struct Entry {
     context: String,
     url: String,
     percentage: Option<i64>,
+    priority: Option<String>, // Raw `**Priority:**` text
+    due: Option<String>, // Raw `**Due:**` text
+    attachments: Vec<String>,
+    custom_lines: Vec<String>, // Raw `**key:** value` lines
     start_line: usize,
     end_line: usize,
 }
@@ -515,7 +1187,7 @@ impl ContentOperations for MarkdownOperations {
         content: &str,
         cursor_line: usize,
         _lines: &[String],
-    ) -> Result<(String, String), String> {
+    ) -> Result<String, String> {
         MarkdownOperations::duplicate_entry_at_cursor(content, cursor_line)
     }
 
@@ -527,6 +1199,14 @@ impl ContentOperations for MarkdownOperations {
         MarkdownOperations::order_by_percentage(content)
     }
 
+    fn order_by_priority(&self, content: &str) -> Result<(String, String), String> {
+        MarkdownOperations::order_by_priority(content)
+    }
+
+    fn order_by_due(&self, content: &str) -> Result<(String, String), String> {
+        MarkdownOperations::order_by_due(content)
+    }
+
     fn order_by_name(&self, content: &str) -> Result<(String, String), String> {
         MarkdownOperations::order_by_name(content)
     }
@@ -534,4 +1214,8 @@ impl ContentOperations for MarkdownOperations {
     fn order_random(&self, content: &str) -> Result<(String, String), String> {
         MarkdownOperations::order_random(content)
     }
+
+    fn validate_strict(&self, content: &str) -> Result<(), String> {
+        MarkdownOperations::validate_strict(content)
+    }
 }
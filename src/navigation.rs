@@ -28,10 +28,124 @@ impl Navigator {
         if content_len == 0 {
             return 1;
         }
-        ((content_len + avail - 1) / avail).max(1) as u16
+        content_len.div_ceil(avail).max(1) as u16
     }
 
     pub fn is_word_char(c: char) -> bool {
         c.is_alphanumeric() || c == '_'
     }
+
+    /// First and last card of the section (run of OUTSIDE or INSIDE cards) holding `idx`
+    pub fn card_section_bounds(is_outside: &[bool], idx: usize) -> (usize, usize) {
+        if idx >= is_outside.len() {
+            return (idx, idx);
+        }
+        let section = is_outside[idx];
+        let first = is_outside[..idx].iter().rposition(|&s| s != section).map_or(0, |p| p + 1);
+        let last = is_outside[idx..].iter().position(|&s| s != section).map_or(is_outside.len(), |p| idx + p) - 1;
+        (first, last)
+    }
+
+    /// `}` on cards: first card of the next section, or the last card when there is none
+    pub fn next_card_section(is_outside: &[bool], idx: usize) -> usize {
+        let (_, last) = Self::card_section_bounds(is_outside, idx);
+        (last + 1).min(is_outside.len().saturating_sub(1))
+    }
+
+    /// `{` on cards: first card of the current section, or of the previous one when already there
+    pub fn prev_card_section(is_outside: &[bool], idx: usize) -> usize {
+        let (first, _) = Self::card_section_bounds(is_outside, idx);
+        if first < idx || first == 0 {
+            first
+        } else {
+            Self::card_section_bounds(is_outside, first - 1).0
+        }
+    }
+
+    /// Lines where entries start: `###` headings in Markdown, entry objects in JSON
+    pub fn entry_start_lines(lines: &[String], markdown: bool) -> Vec<usize> {
+        if markdown {
+            Self::markdown_heading_lines(lines, "### ")
+        } else {
+            // Entry objects open at depth 2 (top-level object > section array)
+            Self::json_lines_at_depth(lines, 2, |t| t.starts_with('{'))
+        }
+    }
+
+    /// Lines where sections start: `##` headings in Markdown, top-level keys in JSON
+    pub fn section_start_lines(lines: &[String], markdown: bool) -> Vec<usize> {
+        if markdown {
+            Self::markdown_heading_lines(lines, "## ")
+        } else {
+            Self::json_lines_at_depth(lines, 1, |t| t.starts_with('"'))
+        }
+    }
+
+    /// Start line of every entry by its original index: OUTSIDE entries first, then INSIDE,
+    /// whichever section comes first in the text
+    pub fn entry_lines_by_index(lines: &[String], markdown: bool) -> Vec<usize> {
+        let sections = Self::section_start_lines(lines, markdown);
+        let (inside, outside): (Vec<usize>, Vec<usize>) =
+            Self::entry_start_lines(lines, markdown).into_iter().partition(|&start| {
+                Self::prev_start(&sections, start)
+                    .is_some_and(|section| lines[section].to_lowercase().contains("inside"))
+            });
+        outside.into_iter().chain(inside).collect()
+    }
+
+    /// First start after `line`
+    pub fn next_start(starts: &[usize], line: usize) -> Option<usize> {
+        starts.iter().copied().find(|&s| s > line)
+    }
+
+    /// Last start before `line`
+    pub fn prev_start(starts: &[usize], line: usize) -> Option<usize> {
+        starts.iter().copied().rev().find(|&s| s < line)
+    }
+
+    fn markdown_heading_lines(lines: &[String], prefix: &str) -> Vec<usize> {
+        let mut in_code_block = false;
+        let mut starts = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let t = line.trim_start();
+            if t.starts_with("```") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block && t.starts_with(prefix) {
+                starts.push(i);
+            }
+        }
+        starts
+    }
+
+    /// Lines whose first token sits at nesting `depth` and passes `accept`
+    fn json_lines_at_depth(lines: &[String], depth: usize, accept: impl Fn(&str) -> bool) -> Vec<usize> {
+        let mut level = 0usize;
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut starts = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let t = line.trim_start();
+            if !in_string && level == depth && accept(t) {
+                starts.push(i);
+            }
+            for c in line.chars() {
+                if in_string {
+                    match c {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => in_string = false,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match c {
+                    '"' => in_string = true,
+                    '{' | '[' => level += 1,
+                    '}' | ']' => level = level.saturating_sub(1),
+                    _ => {}
+                }
+            }
+        }
+        starts
+    }
 }
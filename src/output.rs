@@ -0,0 +1,186 @@
+//! Listing formats for `revw --stdout`: the plain text dump, OUTSIDE tables and the
+//! one-line-per-entry `--plain` rows for scripts.
+//!
+//! These only write; `convert::Converter` picks one through its target `Format`.
+
+use crate::ansi::Painter;
+use crate::rendering::Renderer;
+use serde_json::{Map, Value};
+use unicode_width::UnicodeWidthStr;
+
+/// Plain text listing: section title, then one block per entry; `painter` decorates
+/// section titles, names, dates and URLs
+pub fn render_text(value: &Value, painter: &Painter) -> String {
+    let mut outside_entries: Vec<String> = Vec::new();
+    let mut inside_entries: Vec<String> = Vec::new();
+
+    if let Some(obj) = value.as_object() {
+        if let Some(outside) = obj.get("outside").and_then(|v| v.as_array()) {
+            for item_obj in outside.iter().filter_map(|item| item.as_object()) {
+                let name = item_obj.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                let context = item_obj.get("context").and_then(|v| v.as_str()).unwrap_or("");
+                let url = item_obj.get("url").and_then(|v| v.as_str()).unwrap_or("");
+                let percentage = item_obj.get("percentage").and_then(|v| v.as_i64());
+
+                let mut entry = String::new();
+                entry.push_str(&painter.title(name));
+                if !context.is_empty() {
+                    entry.push_str(&format!("\n{}", context));
+                }
+                if !url.is_empty() {
+                    entry.push_str(&format!("\n{}", painter.url(url)));
+                }
+                // Only add percentage if not null
+                if let Some(pct) = percentage {
+                    entry.push_str(&format!("\n{}", painter.dim(&format!("{}%", pct))));
+                }
+                if let Some(due) = item_obj.get("due").and_then(|v| v.as_str()).filter(|s| !s.is_empty()) {
+                    entry.push_str(&format!("\ndue {}", due));
+                }
+                outside_entries.push(entry);
+            }
+        }
+
+        if let Some(inside) = obj.get("inside").and_then(|v| v.as_array()) {
+            for item_obj in inside.iter().filter_map(|item| item.as_object()) {
+                let entry_parts: Vec<String> = item_obj
+                    .iter()
+                    .map(|(key, value)| {
+                        let text = match value {
+                            Value::String(s) => s.clone(),
+                            Value::Number(n) => n.to_string(),
+                            Value::Bool(b) => b.to_string(),
+                            _ => value.to_string(),
+                        };
+                        if key == "date" { painter.title(&text) } else { text }
+                    })
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                inside_entries.push(entry_parts.join("\n"));
+            }
+        }
+    }
+
+    let mut output_lines = Vec::new();
+    for (title, entries) in [("OUTSIDE", outside_entries), ("INSIDE", inside_entries)] {
+        if entries.is_empty() {
+            continue;
+        }
+        output_lines.push(painter.section(title));
+        output_lines.push("".to_string());
+        for entry in entries {
+            output_lines.push(entry);
+            output_lines.push("".to_string());
+        }
+    }
+    output_lines.join("\n")
+}
+
+/// Table columns for OUTSIDE entries: name, percentage (empty when unset) and url
+fn outside_rows(value: &Value) -> Vec<[String; 3]> {
+    let field = |item: &Map<String, Value>, key: &str| -> String {
+        // One row per entry: line breaks and tabs become spaces
+        item.get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .split(['\n', '\r', '\t'])
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    value
+        .get("outside")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|item| item.as_object())
+        .map(|item| {
+            let percentage = item.get("percentage").and_then(|v| v.as_i64()).map(|n| n.to_string()).unwrap_or_default();
+            [field(item, "name"), percentage, field(item, "url")]
+        })
+        .collect()
+}
+
+/// OUTSIDE entries as `name<TAB>percentage<TAB>url` rows under a header line
+pub fn render_tsv(value: &Value) -> String {
+    let mut lines = vec!["name\tpercentage\turl".to_string()];
+    lines.extend(outside_rows(value).iter().map(|row| row.join("\t")));
+    lines.join("\n")
+}
+
+/// OUTSIDE entries as aligned columns: name, percentage (right-aligned, `-` when unset), url;
+/// `painter` decorates the header row and URLs after padding so the columns still line up.
+/// With a `width`, long names and URLs are cut with `…` so each row fits and the
+/// percentage column stays in place.
+pub fn render_table(value: &Value, painter: &Painter, width: Option<usize>) -> String {
+    let rows: Vec<[String; 3]> = outside_rows(value)
+        .into_iter()
+        .map(|[name, percentage, url]| {
+            let percentage = if percentage.is_empty() { "-".to_string() } else { format!("{}%", percentage) };
+            [name, percentage, url]
+        })
+        .collect();
+    let header = ["NAME".to_string(), "PERCENTAGE".to_string(), "URL".to_string()];
+    let mut name_width = rows.iter().chain([&header]).map(|row| row[0].width()).max().unwrap_or(0);
+    let percentage_width = rows.iter().chain([&header]).map(|row| row[1].width()).max().unwrap_or(0);
+    // Columns left for the URL after name, percentage and the gaps between them
+    let mut url_width = None;
+    if let Some(width) = width {
+        let free = width.saturating_sub(percentage_width + 4);
+        // Names get at most two thirds of the free space when there are URLs to show
+        let has_urls = rows.iter().any(|row| !row[2].is_empty());
+        let name_cap = if has_urls { free * 2 / 3 } else { free };
+        name_width = name_width.min(name_cap.max(header[0].width()));
+        url_width = Some(free.saturating_sub(name_width));
+    }
+
+    std::iter::once(&header)
+        .chain(rows.iter())
+        .enumerate()
+        .map(|(i, [name, percentage, url])| {
+            let name = Renderer::elide(name, name_width);
+            let url = url_width.map_or_else(|| url.clone(), |width| Renderer::elide(url, width));
+            let name_pad = " ".repeat(name_width.saturating_sub(name.width()));
+            let percentage_pad = " ".repeat(percentage_width - percentage.width());
+            let line = format!("{}{}  {}{}  ", name, name_pad, percentage_pad, percentage);
+            if i == 0 {
+                painter.section(format!("{}{}", line, url).trim_end())
+            } else if url.is_empty() {
+                line.trim_end().to_string()
+            } else {
+                format!("{}{}", line, painter.url(&url))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Fields `--plain` prints when `--fields` is not given
+const PLAIN_OUTSIDE_FIELDS: &[&str] = &["name", "url", "percentage"];
+const PLAIN_INSIDE_FIELDS: &[&str] = &["date", "context"];
+
+/// One line per entry (OUTSIDE, then INSIDE) with the `fields` joined by `separator`; no
+/// headers or blank lines. Without `fields`, OUTSIDE prints name/url/percentage and INSIDE
+/// date/context. Line breaks inside values become spaces, lists are joined with commas.
+pub fn render_plain(value: &Value, fields: &[String], separator: &str) -> String {
+    let mut lines = Vec::new();
+    for (section, defaults) in [("outside", PLAIN_OUTSIDE_FIELDS), ("inside", PLAIN_INSIDE_FIELDS)] {
+        let keys: Vec<&str> = if fields.is_empty() { defaults.to_vec() } else { fields.iter().map(String::as_str).collect() };
+        let items = value.get(section).and_then(|v| v.as_array()).into_iter().flatten();
+        for item in items.filter_map(|item| item.as_object()) {
+            let row: Vec<String> = keys.iter().map(|key| plain_value(item.get(*key))).collect();
+            lines.push(row.join(separator));
+        }
+    }
+    lines.join("\n")
+}
+
+fn plain_value(value: Option<&Value>) -> String {
+    let text = match value {
+        None | Some(Value::Null) => return String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(items)) => items.iter().map(|item| plain_value(Some(item))).collect::<Vec<_>>().join(","),
+        Some(other) => other.to_string(),
+    };
+    text.split(['\n', '\r']).filter(|part| !part.is_empty()).collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,38 @@
+//! Paths typed by the user (`:e`, `:w`, `--input`, rc values, pasted paths) expand the same
+//! way everywhere: a leading `~` is the home directory and `$VAR` or `${VAR}` the value of
+//! an environment variable. Variables that are not set are left as written.
+
+use std::path::PathBuf;
+
+/// `path` with `~` and environment variables expanded
+pub fn expand(path: &str) -> PathBuf {
+    let mut out = String::new();
+    let mut rest = path;
+    if let Some(after) = path.strip_prefix('~').filter(|after| after.is_empty() || after.starts_with(['/', '\\']))
+        && let Some(home) = dirs::home_dir() {
+        out.push_str(&home.to_string_lossy());
+        rest = after;
+    }
+
+    while let Some(dollar) = rest.find('$') {
+        out.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+        let (name, len) = match after.strip_prefix('{') {
+            Some(braced) => match braced.find('}') {
+                Some(end) => (&braced[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = after.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(after.len());
+                (&after[..end], end)
+            }
+        };
+        match std::env::var(name).ok().filter(|_| !name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit())) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[dollar..dollar + 1 + len]),
+        }
+        rest = &after[len..];
+    }
+    out.push_str(rest);
+    PathBuf::from(out)
+}
@@ -0,0 +1,69 @@
+//! Inline image preview for cards whose URL points at an image.
+//!
+//! Drawing is only available when built with `--features preview`; the
+//! URL detection helpers are always compiled so the UI can show a hint.
+
+use ratatui::layout::Rect;
+use std::path::{Path, PathBuf};
+
+/// File extensions treated as previewable images
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "bmp"];
+
+/// True when the binary was built with the `preview` feature
+pub const ENABLED: bool = cfg!(feature = "preview");
+
+/// Check whether a URL ends in a common image extension (query/fragment ignored)
+pub fn is_image_url(url: &str) -> bool {
+    let path = url.split(['?', '#']).next().unwrap_or("");
+    match path.rsplit_once('.') {
+        Some((_, ext)) => IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()),
+        None => false,
+    }
+}
+
+/// An http(s) image URL; these are not fetched, so the UI says so instead of previewing
+pub fn is_remote_image(url: &str) -> bool {
+    is_image_url(url) && (url.starts_with("http://") || url.starts_with("https://"))
+}
+
+/// Resolve an image URL to a local file path.
+/// Supports `file://` URLs and plain paths (relative paths resolve against `base_dir`).
+/// Remote http(s) URLs are not fetched and return None.
+pub fn local_image_path(url: &str, base_dir: Option<&Path>) -> Option<PathBuf> {
+    if !is_image_url(url) || is_remote_image(url) {
+        return None;
+    }
+
+    let raw = url.strip_prefix("file://").unwrap_or(url);
+    let path = PathBuf::from(raw);
+    let path = if path.is_relative() {
+        base_dir.map(|dir| dir.join(&path)).unwrap_or(path)
+    } else {
+        path
+    };
+
+    if path.is_file() { Some(path) } else { None }
+}
+
+/// Draw an image directly to the terminal inside `area`.
+/// Uses the kitty or iTerm protocol when available, half-blocks otherwise.
+#[cfg(feature = "preview")]
+pub fn draw_image(path: &Path, area: Rect) -> Result<(), String> {
+    let config = viuer::Config {
+        absolute_offset: true,
+        x: area.x,
+        y: area.y as i16,
+        width: Some(area.width as u32),
+        height: Some(area.height as u32),
+        restore_cursor: true,
+        ..Default::default()
+    };
+    viuer::print_from_file(path, &config)
+        .map(|_| ())
+        .map_err(|e| format!("Preview error: {}", e))
+}
+
+#[cfg(not(feature = "preview"))]
+pub fn draw_image(_path: &Path, _area: Rect) -> Result<(), String> {
+    Err("Image preview not available (build with --features preview)".to_string())
+}
@@ -0,0 +1,78 @@
+//! Optional `priority` of OUTSIDE entries.
+//!
+//! Stored as `"high"`, `"medium"`, `"low"` or a number from 1 (lowest) to 5 (highest);
+//! everything is compared on that 1-5 rank, with the words mapping to 5, 3 and 1.
+
+use ratatui::style::Color;
+use serde_json::Value;
+
+/// Rank (1-5) of a priority written as a word or number
+pub fn rank_from_text(text: &str) -> Option<u8> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "high" => Some(5),
+        "medium" => Some(3),
+        "low" => Some(1),
+        other => other.parse::<u8>().ok().filter(|n| (1..=5).contains(n)),
+    }
+}
+
+/// Rank (1-5) of a JSON `priority` value
+pub fn rank(value: &Value) -> Option<u8> {
+    match value {
+        Value::String(s) => rank_from_text(s),
+        Value::Number(n) => n.as_u64().and_then(|n| rank_from_text(&n.to_string())),
+        _ => None,
+    }
+}
+
+/// JSON value for a priority typed as text: words stay strings, digits become numbers
+pub fn value_from_text(text: &str) -> Option<Value> {
+    let text = text.trim();
+    rank_from_text(text)?;
+    Some(match text.parse::<u64>() {
+        Ok(n) => Value::from(n),
+        Err(_) => Value::String(text.to_ascii_lowercase()),
+    })
+}
+
+/// Card color for a rank: red for high (4-5), yellow for medium (3), green for low (1-2)
+pub fn color(rank: u8) -> Color {
+    match rank {
+        4.. => Color::Red,
+        3 => Color::Yellow,
+        _ => Color::Green,
+    }
+}
+
+/// Comparison parsed from filters such as `priority>=high` or `priority=2`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriorityFilter {
+    op: &'static str,
+    rank: u8,
+}
+
+impl PriorityFilter {
+    pub fn parse(pattern: &str) -> Option<Self> {
+        let rest = pattern.trim().strip_prefix("priority")?.trim_start();
+        let op = [">=", "<=", "!=", "=", ">", "<"]
+            .into_iter()
+            .find(|op| rest.starts_with(op))?;
+        let rank = rank_from_text(&rest[op.len()..])?;
+        Some(Self { op, rank })
+    }
+
+    /// Entries without a priority never match
+    pub fn matches(&self, rank: Option<u8>) -> bool {
+        let Some(rank) = rank else {
+            return false;
+        };
+        match self.op {
+            ">=" => rank >= self.rank,
+            "<=" => rank <= self.rank,
+            "!=" => rank != self.rank,
+            ">" => rank > self.rank,
+            "<" => rank < self.rank,
+            _ => rank == self.rank,
+        }
+    }
+}
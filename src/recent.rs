@@ -0,0 +1,45 @@
+//! Recently opened files, kept outside the documents.
+//!
+//! The list lives in a plain text file under the data dir
+//! (`~/.local/share/revw/recent` on Linux): one absolute path per line,
+//! most recently opened first.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How many files the list remembers
+pub const MAX_RECENT: usize = 20;
+
+/// Default location of the recent files list, under the data dir
+pub fn default_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("revw").join("recent"))
+}
+
+/// Paths in the list at `list_path`, most recent first (empty when there is none yet)
+pub fn load(list_path: &Path) -> Vec<PathBuf> {
+    fs::read_to_string(list_path)
+        .map(|content| {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(PathBuf::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Move `opened` to the top of the list at `list_path`, dropping the oldest beyond `MAX_RECENT`
+pub fn record(list_path: &Path, opened: &Path) -> Result<(), String> {
+    let opened = fs::canonicalize(opened).unwrap_or_else(|_| opened.to_path_buf());
+    let mut paths = load(list_path);
+    paths.retain(|path| *path != opened);
+    paths.insert(0, opened);
+    paths.truncate(MAX_RECENT);
+
+    if let Some(dir) = list_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    }
+    let content: String = paths.iter().map(|path| format!("{}\n", path.display())).collect();
+    fs::write(list_path, content).map_err(|e| format!("Cannot write {}: {}", list_path.display(), e))
+}
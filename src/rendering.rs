@@ -1,6 +1,12 @@
+use chrono::{Local, NaiveDate};
 use ratatui::style::Color;
-use regex::RegexBuilder;
-use unicode_width::UnicodeWidthChar;
+use regex::{Regex, RegexBuilder};
+use crate::due::{self, DueFilter};
+use crate::graphemes;
+use crate::priority::{self, PriorityFilter};
+use crate::search::{SearchQuery, Searchable};
+use crate::status::{self, EntryStatus};
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone, Debug, Default)]
 pub struct RelfLineStyle {
@@ -18,8 +24,55 @@ pub struct RelfEntry {
     pub url: Option<String>,
     pub context: Option<String>,
     pub percentage: Option<i64>,
+    // Priority rank 1 (lowest) to 5 (highest), see crate::priority
+    pub priority: Option<u8>,
+    // Due date (OUTSIDE only), see crate::due
+    pub due: Option<NaiveDate>,
+    // Active, done (dimmed) or dropped (hidden by default), see crate::status
+    pub status: EntryStatus,
     // Fields for inside entries
     pub date: Option<String>,
+    // File paths attached to the entry (both sections)
+    pub attachments: Vec<String>,
+    // Custom fields as (key, display text), in file order
+    pub fields: Vec<(String, String)>,
+    // File of a merged view the entry belongs to, see crate::sources
+    pub source: Option<String>,
+}
+
+/// Fields `:y FIELD` copies, with the letter of their `y` key and command (`yu`, `:yu`)
+pub const COPY_FIELDS: &[(char, &str)] = &[('u', "url"), ('n', "name"), ('c', "context"), ('d', "date"), ('p', "percentage")];
+
+impl RelfEntry {
+    /// Value of one field as plain text: url, name, context, date, percentage (the number
+    /// alone) or a custom field. None when the entry has no such field or it is empty.
+    pub fn field(&self, key: &str) -> Option<String> {
+        let value = match key {
+            "url" => self.url.clone(),
+            "name" => self.name.clone(),
+            "context" => self.context.clone(),
+            "date" => self.date.clone(),
+            "percentage" => self.percentage.map(|pct| pct.to_string()),
+            _ => self.fields.iter().find(|(field, _)| field == key).map(|(_, value)| value.clone()),
+        };
+        value.filter(|value| !value.is_empty())
+    }
+
+    /// What identifies the entry across reloads: the date of an INSIDE entry, else the URL
+    /// or name of an OUTSIDE one
+    pub fn identity(&self) -> String {
+        match (&self.date, &self.url, &self.name) {
+            (Some(date), _, _) => format!("date:{}", date),
+            (_, Some(url), _) if !url.is_empty() => format!("url:{}", url),
+            (_, _, Some(name)) => format!("name:{}", name),
+            _ => self.lines.join("\n"),
+        }
+    }
+
+    /// Custom fields as `key: value` lines, shown below the context
+    pub fn field_lines(&self) -> Vec<String> {
+        self.fields.iter().map(|(key, value)| format!("{}: {}", key, value)).collect()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -32,17 +85,15 @@ pub struct RelfRenderResult {
 pub struct Renderer;
 
 impl Renderer {
+    /// Display columns of `s`, measured per grapheme cluster so emoji sequences and
+    /// combining accents take the columns the terminal gives them
     pub fn display_width_str(s: &str) -> usize {
-        s.chars()
-            .map(|c| UnicodeWidthChar::width(c).unwrap_or(0))
-            .sum()
+        s.graphemes(true).map(graphemes::width).sum()
     }
 
     pub fn prefix_display_width(s: &str, char_pos: usize) -> usize {
-        s.chars()
-            .take(char_pos)
-            .map(|c| UnicodeWidthChar::width(c).unwrap_or(0))
-            .sum()
+        let end = s.char_indices().nth(char_pos).map_or(s.len(), |(i, _)| i);
+        Self::display_width_str(&s[..end])
     }
 
     pub fn slice_columns(s: &str, start_cols: usize, width_cols: usize) -> String {
@@ -51,10 +102,11 @@ impl Renderer {
         }
         let mut sum = 0usize;
         let mut start_idx = 0usize;
-        for (i, c) in s.chars().enumerate() {
-            let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        let clusters: Vec<&str> = s.graphemes(true).collect();
+        for (i, grapheme) in clusters.iter().enumerate() {
+            let w = graphemes::width(grapheme);
             if sum + w > start_cols {
-                // This character extends past start_cols, so start here
+                // This cluster extends past start_cols, so start here
                 start_idx = i;
                 break;
             }
@@ -63,32 +115,55 @@ impl Renderer {
         }
         let mut out = String::new();
         let mut used = 0usize;
-        for c in s.chars().skip(start_idx) {
-            let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        for grapheme in clusters.iter().skip(start_idx) {
+            let w = graphemes::width(grapheme);
             if used + w > width_cols {
                 break;
             }
-            out.push(c);
+            out.push_str(grapheme);
             used += w;
         }
         out
     }
 
+    /// `s` cut to at most `width_cols` display columns, ending in `…` when it was cut
+    pub fn elide(s: &str, width_cols: usize) -> String {
+        if Self::display_width_str(s) <= width_cols {
+            return s.to_string();
+        }
+        if width_cols == 0 {
+            return String::new();
+        }
+        format!("{}…", Self::slice_columns(s, 0, width_cols - 1))
+    }
+
+    /// Regex a text filter matches cards with (case-insensitive, literal when not a valid
+    /// regex); None for no filter and for priority, due and field filters
+    pub fn filter_regex(filter_pattern: &str) -> Option<Regex> {
+        if filter_pattern.is_empty()
+            || PriorityFilter::parse(filter_pattern).is_some()
+            || DueFilter::parse(filter_pattern).is_some()
+            || SearchQuery::parse(filter_pattern).is_some()
+        {
+            return None;
+        }
+        RegexBuilder::new(filter_pattern)
+            .case_insensitive(true)
+            .build()
+            .ok()
+            .or_else(|| RegexBuilder::new(&regex::escape(filter_pattern)).case_insensitive(true).build().ok())
+    }
+
     pub fn render_relf(json_input: &str, filter_pattern: &str) -> RelfRenderResult {
-        let filter_re = if !filter_pattern.is_empty() {
-            RegexBuilder::new(filter_pattern)
-                .case_insensitive(true)
-                .build()
-                .ok()
-                .or_else(|| {
-                    RegexBuilder::new(&regex::escape(filter_pattern))
-                        .case_insensitive(true)
-                        .build()
-                        .ok()
-                })
-        } else {
-            None
-        };
+        // `priority>=high` style filters compare ranks instead of matching text
+        let priority_filter = PriorityFilter::parse(filter_pattern);
+        // `due<=7` style filters compare days left until the due date
+        let due_filter = DueFilter::parse(filter_pattern);
+        let today = Local::now().date_naive();
+        let compares = priority_filter.is_some() || due_filter.is_some();
+        // `name:rust url:github` limits terms to fields instead of matching the whole card
+        let field_query = if compares { None } else { SearchQuery::parse(filter_pattern) };
+        let filter_re = Self::filter_regex(filter_pattern);
 
         if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(json_input) {
             let mut result = RelfRenderResult::default();
@@ -96,99 +171,144 @@ impl Renderer {
             if let Some(obj) = json_value.as_object() {
                 let mut global_index = 0; // Track the original index across all entries
 
-                for (section_key, section_value) in obj {
-                    if section_key == "outside" || section_key == "inside" {
-                        if let Some(section_array) = section_value.as_array() {
-                            for item in section_array {
-                                let original_index = global_index;
-                                global_index += 1;
-
-                                if let Some(item_obj) = item.as_object() {
-                                    if section_key == "outside" {
-
-                                        let mut entry_lines = Vec::new();
-
-                                        let name = item_obj
-                                            .get("name")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let context = item_obj
-                                            .get("context")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let url = item_obj
-                                            .get("url")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let percentage = item_obj
-                                            .get("percentage")
-                                            .and_then(|v| v.as_i64());
-
-                                        entry_lines.push(name.to_string());
-                                        if !context.is_empty() {
-                                            entry_lines.push(context.to_string());
-                                        }
-                                        if !url.is_empty() {
-                                            entry_lines.push(url.to_string());
-                                        }
-                                        // Add percentage line only if specified
-                                        if let Some(pct) = percentage {
-                                            entry_lines.push(format!("{}%", pct));
-                                        }
+                // Always walk outside before inside so original_index matches the
+                // outside-then-inside arithmetic used by editing operations,
+                // regardless of key order in the file
+                for section_key in ["outside", "inside"] {
+                    if let Some(section_value) = obj.get(section_key)
+                        && let Some(section_array) = section_value.as_array() {
+                        for item in section_array {
+                            let original_index = global_index;
+                            global_index += 1;
 
-                                        // Apply filter if pattern is provided
-                                        if let Some(ref re) = filter_re {
-                                            let matches = entry_lines.iter().any(|line| re.is_match(line));
-                                            if !matches {
-                                                continue; // Skip this entry
-                                            }
-                                        }
+                            if let Some(item_obj) = item.as_object() {
+                                if field_query
+                                    .as_ref()
+                                    .is_some_and(|query| !query.matches(&Searchable::from_object(item_obj, section_key)))
+                                {
+                                    continue;
+                                }
+                                let attachments: Vec<String> = item_obj
+                                    .get("attachments")
+                                    .and_then(|v| v.as_array())
+                                    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                                    .unwrap_or_default();
+                                let fields: Vec<(String, String)> = crate::fields::custom_fields(item_obj, section_key)
+                                    .into_iter()
+                                    .filter(|(key, _)| key.as_str() != crate::sources::KEY)
+                                    .map(|(key, value)| (key.clone(), crate::fields::display_value(value)))
+                                    .collect();
+                                let source = item_obj.get(crate::sources::KEY).and_then(|v| v.as_str()).map(str::to_string);
 
-                                        result.entries.push(RelfEntry {
-                                            lines: entry_lines,
-                                            original_index,
-                                            name: Some(name.to_string()),
-                                            url: if !url.is_empty() { Some(url.to_string()) } else { None },
-                                            context: if !context.is_empty() { Some(context.to_string()) } else { None },
-                                            percentage,
-                                            date: None,
-                                        });
-                                    } else if section_key == "inside" {
-                                        let date = item_obj
-                                            .get("date")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-                                        let context = item_obj
-                                            .get("context")
-                                            .and_then(|v| v.as_str())
-                                            .unwrap_or("");
-
-                                        let mut entry_lines = Vec::new();
-                                        if !date.is_empty() {
-                                            entry_lines.push(date.to_string());
-                                        }
-                                        if !context.is_empty() {
-                                            entry_lines.push(context.to_string());
-                                        }
+                                if section_key == "outside" {
+
+                                    let mut entry_lines = Vec::new();
+
+                                    let name = item_obj
+                                        .get("name")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let context = item_obj
+                                        .get("context")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let url = item_obj
+                                        .get("url")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let percentage = item_obj
+                                        .get("percentage")
+                                        .and_then(|v| v.as_i64());
+                                    let priority = item_obj.get("priority").and_then(priority::rank);
+                                    let due = due::of(item_obj.get("due"));
+
+                                    entry_lines.push(name.to_string());
+                                    if !context.is_empty() {
+                                        entry_lines.push(context.to_string());
+                                    }
+                                    if !url.is_empty() {
+                                        entry_lines.push(url.to_string());
+                                    }
+                                    // Add percentage line only if specified
+                                    if let Some(pct) = percentage {
+                                        entry_lines.push(format!("{}%", pct));
+                                    }
 
-                                        // Apply filter if pattern is provided
-                                        if let Some(ref re) = filter_re {
-                                            let matches = entry_lines.iter().any(|line| re.is_match(line));
-                                            if !matches {
-                                                continue; // Skip this entry
-                                            }
+                                    if priority_filter.is_some_and(|f| !f.matches(priority)) {
+                                        continue;
+                                    }
+                                    if due_filter.is_some_and(|f| !f.matches(due, today)) {
+                                        continue;
+                                    }
+                                    // Apply filter if pattern is provided (custom fields count too)
+                                    if let Some(ref re) = filter_re {
+                                        let matches = entry_lines.iter().any(|line| re.is_match(line))
+                                            || fields.iter().any(|(key, value)| re.is_match(&format!("{}: {}", key, value)));
+                                        if !matches {
+                                            continue; // Skip this entry
                                         }
+                                    }
+
+                                    result.entries.push(RelfEntry {
+                                        lines: entry_lines,
+                                        original_index,
+                                        name: Some(name.to_string()),
+                                        url: if !url.is_empty() { Some(url.to_string()) } else { None },
+                                        context: if !context.is_empty() { Some(context.to_string()) } else { None },
+                                        percentage,
+                                        priority,
+                                        due,
+                                        status: EntryStatus::of(item_obj.get(status::KEY)),
+                                        date: None,
+                                        attachments,
+                                        fields,
+                                        source,
+                                    });
+                                } else if section_key == "inside" {
+                                    let date = item_obj
+                                        .get("date")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+                                    let context = item_obj
+                                        .get("context")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("");
+
+                                    let mut entry_lines = Vec::new();
+                                    if !date.is_empty() {
+                                        entry_lines.push(date.to_string());
+                                    }
+                                    if !context.is_empty() {
+                                        entry_lines.push(context.to_string());
+                                    }
 
-                                        result.entries.push(RelfEntry {
-                                            lines: entry_lines,
-                                            original_index,
-                                            name: None,
-                                            url: None,
-                                            context: if !context.is_empty() { Some(context.to_string()) } else { None },
-                                            percentage: None,
-                                            date: if !date.is_empty() { Some(date.to_string()) } else { None },
-                                        });
+                                    if compares {
+                                        continue; // INSIDE entries have no priority or due date
                                     }
+                                    // Apply filter if pattern is provided (custom fields count too)
+                                    if let Some(ref re) = filter_re {
+                                        let matches = entry_lines.iter().any(|line| re.is_match(line))
+                                            || fields.iter().any(|(key, value)| re.is_match(&format!("{}: {}", key, value)));
+                                        if !matches {
+                                            continue; // Skip this entry
+                                        }
+                                    }
+
+                                    result.entries.push(RelfEntry {
+                                        lines: entry_lines,
+                                        original_index,
+                                        name: None,
+                                        url: None,
+                                        context: if !context.is_empty() { Some(context.to_string()) } else { None },
+                                        percentage: None,
+                                        priority: None,
+                                        due: None,
+                                        status: EntryStatus::Active,
+                                        date: if !date.is_empty() { Some(date.to_string()) } else { None },
+                                        attachments,
+                                        fields,
+                                        source,
+                                    });
                                 }
                             }
                         }
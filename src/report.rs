@@ -0,0 +1,109 @@
+//! Markdown progress summary for a weekly review (`:report`, `--report`).
+//!
+//! Entries carry no edit timestamps, so a resource counts as touched by the newest INSIDE
+//! note that mentions its name; resources no note mentions are the most untouched.
+
+use crate::status::{self, EntryStatus};
+use chrono::{Duration, NaiveDate};
+use serde_json::Value;
+use std::cmp::Reverse;
+
+/// Notes from this many days back count as recent
+const RECENT_DAYS: i64 = 7;
+/// Recent notes listed at most
+const RECENT_LIMIT: usize = 10;
+/// Untouched resources listed at most
+const UNTOUCHED_LIMIT: usize = 5;
+
+/// Completion buckets, in report order
+const BUCKETS: [&str; 4] = ["Not started", "1-49%", "50-99%", "Complete"];
+
+/// The report for a document, as of `today`
+pub fn generate(json_input: &str, today: NaiveDate) -> Result<String, String> {
+    let value: Value = serde_json::from_str(json_input).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let empty = Vec::new();
+    let outside = value.get("outside").and_then(Value::as_array).unwrap_or(&empty);
+    let inside = value.get("inside").and_then(Value::as_array).unwrap_or(&empty);
+
+    // (day, date text, context) of every dated note
+    let notes: Vec<(NaiveDate, &str, &str)> = inside
+        .iter()
+        .filter_map(|note| {
+            let date = note.get("date").and_then(Value::as_str)?;
+            let context = note.get("context").and_then(Value::as_str).unwrap_or("");
+            Some((parse_day(date)?, date, context))
+        })
+        .collect();
+
+    let mut out = format!("# Weekly review ({})\n\n## Progress\n\n", today.format("%Y-%m-%d"));
+    let mut counts = [0usize; BUCKETS.len()];
+    let mut dropped = 0;
+    let mut open = Vec::new();
+    for entry in outside {
+        let status = EntryStatus::of(entry.get(status::KEY));
+        let percentage = entry.get("percentage").and_then(Value::as_i64);
+        let bucket = match (status, percentage) {
+            (EntryStatus::Dropped, _) => {
+                dropped += 1;
+                continue;
+            }
+            (EntryStatus::Done, _) => 3,
+            (_, Some(pct)) if pct >= 100 => 3,
+            (_, Some(pct)) if pct >= 50 => 2,
+            (_, Some(pct)) if pct >= 1 => 1,
+            _ => 0,
+        };
+        counts[bucket] += 1;
+        if bucket < 3 {
+            open.push((entry.get("name").and_then(Value::as_str).unwrap_or(""), percentage));
+        }
+    }
+    out.push_str("| Completion | Resources |\n|---|---:|\n");
+    for (bucket, count) in BUCKETS.iter().zip(counts) {
+        out.push_str(&format!("| {} | {} |\n", bucket, count));
+    }
+    if dropped > 0 {
+        out.push_str(&format!("| Dropped | {} |\n", dropped));
+    }
+
+    out.push_str(&format!("\n## Recent notes (last {} days)\n\n", RECENT_DAYS));
+    let since = today - Duration::days(RECENT_DAYS);
+    let mut recent: Vec<&(NaiveDate, &str, &str)> = notes.iter().filter(|(day, _, _)| *day > since && *day <= today).collect();
+    recent.sort_by_key(|(_, date, _)| Reverse(*date));
+    if recent.is_empty() {
+        out.push_str("_No new notes._\n");
+    }
+    for (_, date, context) in recent.into_iter().take(RECENT_LIMIT) {
+        out.push_str(&format!("- {}: {}\n", date, context.lines().next().unwrap_or("").trim()));
+    }
+
+    out.push_str("\n## Longest untouched\n\n");
+    // Newest note mentioning each open resource; None sorts first
+    let mut untouched: Vec<(Option<NaiveDate>, &str, Option<i64>)> = open
+        .into_iter()
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, percentage)| {
+            let needle = name.to_lowercase();
+            let last = notes.iter().filter(|(_, _, context)| context.to_lowercase().contains(&needle)).map(|(day, _, _)| *day).max();
+            (last, name, percentage)
+        })
+        .collect();
+    untouched.sort_by_key(|(last, _, _)| *last);
+    if untouched.is_empty() {
+        out.push_str("_Nothing open._\n");
+    }
+    for (last, name, percentage) in untouched.into_iter().take(UNTOUCHED_LIMIT) {
+        let progress = percentage.map(|pct| format!(" ({}%)", pct)).unwrap_or_default();
+        let touched = match last {
+            Some(day) => format!("last note {}", day.format("%Y-%m-%d")),
+            None => "no notes".to_string(),
+        };
+        out.push_str(&format!("- **{}**{}: {}\n", name, progress, touched));
+    }
+    Ok(out)
+}
+
+/// Day of an INSIDE date such as `2024-05-01 09:30:00`
+fn parse_day(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim().get(..10)?, "%Y-%m-%d").ok()
+}
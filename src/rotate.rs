@@ -0,0 +1,136 @@
+//! Monthly rotation of INSIDE notes (`:rotate monthly`, `--rotate monthly`).
+//!
+//! INSIDE entries dated before the current month move into archive files next to the
+//! main file, one per month: `notes.json` → `notes-2025-01.json`. Archives use the main
+//! file's format. Entries an archive already has are not added again, so an interrupted
+//! rotation can simply be run again.
+
+use crate::due;
+use crate::line_endings;
+use crate::markdown_ops::MarkdownOperations;
+use chrono::{Datelike, NaiveDate};
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// One archive file a rotation wrote to
+#[derive(Clone, Debug, PartialEq)]
+pub struct Archive {
+    /// `YYYY-MM`
+    pub month: String,
+    pub path: PathBuf,
+    /// Entries moved out of the main file (ones the archive already had are not repeated)
+    pub moved: usize,
+}
+
+/// `YYYY-MM` of an INSIDE date such as `2025-01-31 08:00:00`
+pub fn month_of(date: &str) -> Option<String> {
+    due::parse(date).map(|date| date.format("%Y-%m").to_string())
+}
+
+/// Split the INSIDE entries dated before `today`'s month off `value`, by month (oldest
+/// first). Entries without a valid date stay.
+pub fn split_monthly(value: &Value, today: NaiveDate) -> (Value, BTreeMap<String, Vec<Value>>) {
+    let current = format!("{:04}-{:02}", today.year(), today.month());
+    let mut kept = value.clone();
+    let mut months: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    if let Some(inside) = kept.get_mut("inside").and_then(Value::as_array_mut) {
+        inside.retain(|entry| {
+            let month = entry.get("date").and_then(Value::as_str).and_then(month_of);
+            match month {
+                Some(month) if month < current => {
+                    months.entry(month).or_default().push(entry.clone());
+                    false
+                }
+                _ => true,
+            }
+        });
+    }
+    (kept, months)
+}
+
+/// Archive file of `month` for the file at `path`: `notes.json` → `notes-2025-01.json`
+pub fn archive_path(path: &Path, month: &str) -> PathBuf {
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{}-{}.{}", stem, month, ext.to_string_lossy()),
+        None => format!("{}-{}", stem, month),
+    };
+    path.with_file_name(name)
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md")
+}
+
+fn read_archive(path: &Path) -> Result<Value, String> {
+    if !path.exists() {
+        return Ok(json!({"outside": [], "inside": []}));
+    }
+    let content = fs::read_to_string(path).map_err(|e| format!("Cannot read '{}': {}", path.display(), e))?;
+    let content = line_endings::normalize_owned(content).0;
+    let json = if is_markdown(path) { MarkdownOperations::parse_to_json(&content)? } else { content };
+    serde_json::from_str(&json).map_err(|e| format!("Invalid JSON in '{}': {}", path.display(), e))
+}
+
+fn write_archive(path: &Path, value: &Value) -> Result<(), String> {
+    let content = if is_markdown(path) {
+        MarkdownOperations::render_from_json(value)
+    } else {
+        serde_json::to_string_pretty(value).map_err(|e| e.to_string())?
+    };
+    fs::write(path, content).map_err(|e| format!("Cannot write '{}': {}", path.display(), e))
+}
+
+/// Move the INSIDE entries of `value` (the document at `path`) dated before `today`'s
+/// month into monthly archives. The archives are written here; the caller writes the
+/// returned document back to `path`, so an error leaves the main file untouched.
+pub fn rotate_monthly(path: &Path, value: &Value, today: NaiveDate) -> Result<(Value, Vec<Archive>), String> {
+    let (kept, months) = split_monthly(value, today);
+    let mut archives = Vec::new();
+    for (month, entries) in months {
+        let archive_path = archive_path(path, &month);
+        let mut archive = read_archive(&archive_path)?;
+        let inside = archive
+            .as_object_mut()
+            .ok_or_else(|| format!("'{}' is not a revw file", archive_path.display()))?
+            .entry("inside")
+            .or_insert_with(|| json!([]));
+        let Some(inside) = inside.as_array_mut() else {
+            return Err(format!("'{}' has no INSIDE list", archive_path.display()));
+        };
+        let moved = entries.len();
+        for entry in entries {
+            if !inside.contains(&entry) {
+                inside.push(entry);
+            }
+        }
+        // Newest first, as notes are added
+        inside.sort_by(|a, b| {
+            let date = |v: &Value| v.get("date").and_then(Value::as_str).unwrap_or("").to_string();
+            date(b).cmp(&date(a))
+        });
+        write_archive(&archive_path, &archive)?;
+        archives.push(Archive { month, path: archive_path, moved });
+    }
+    Ok((kept, archives))
+}
+
+/// What a rotation moved, for the status bar and the CLI
+pub fn summary(archives: &[Archive]) -> String {
+    if archives.is_empty() {
+        return "Nothing to rotate: no INSIDE notes before this month".to_string();
+    }
+    let total: usize = archives.iter().map(|archive| archive.moved).sum();
+    let parts: Vec<String> = archives
+        .iter()
+        .map(|archive| {
+            let name = archive.path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            format!("{} ({}) → {}", archive.month, archive.moved, name)
+        })
+        .collect();
+    format!("Moved {} note(s): {}", total, parts.join(", "))
+}
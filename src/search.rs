@@ -0,0 +1,153 @@
+//! Field-scoped queries for `/` search and the card filter, e.g. `name:rust url:github`.
+//!
+//! Terms are separated by spaces and must all match. A `name:`, `url:`, `ctx:` (or
+//! `context:`) or `date:` prefix limits a term to that field; a term without one matches
+//! anywhere in the card, custom fields included. Double quotes keep spaces in a term
+//! (`name:"rust book"`), and case is ignored. Queries without any field prefix are not
+//! parsed here: callers keep matching them as plain text (or a regex, for the filter).
+
+use crate::fields;
+use serde_json::{Map, Value};
+
+/// Entry fields a query term can be limited to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchField {
+    Name,
+    Url,
+    Context,
+    Date,
+}
+
+impl SearchField {
+    /// Field named by a term prefix (`name`, `url`, `ctx`/`context`, `date`)
+    pub fn by_prefix(prefix: &str) -> Option<Self> {
+        match prefix.to_ascii_lowercase().as_str() {
+            "name" => Some(SearchField::Name),
+            "url" => Some(SearchField::Url),
+            "ctx" | "context" => Some(SearchField::Context),
+            "date" => Some(SearchField::Date),
+            _ => None,
+        }
+    }
+}
+
+/// One term: lowercased text, optionally limited to a field
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchTerm {
+    pub field: Option<SearchField>,
+    pub text: String,
+}
+
+/// All terms of a query; an entry matches when every term does
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+/// The text of one entry as a query sees it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Searchable {
+    pub name: String,
+    pub url: String,
+    pub context: String,
+    pub date: String,
+    /// Everything else shown on the card: percentage and `key: value` custom fields
+    pub other: Vec<String>,
+}
+
+impl Searchable {
+    /// Text of a JSON entry in `section` (`outside` or `inside`)
+    pub fn from_object(item: &Map<String, Value>, section: &str) -> Self {
+        let text = |key: &str| item.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string();
+        let mut other: Vec<String> = fields::custom_fields(item, section)
+            .into_iter()
+            .map(|(key, value)| format!("{}: {}", key, fields::display_value(value)))
+            .collect();
+        if let Some(percentage) = item.get("percentage").and_then(|v| v.as_i64()) {
+            other.push(format!("{}%", percentage));
+        }
+        Self { name: text("name"), url: text("url"), context: text("context"), date: text("date"), other }
+    }
+
+    fn field(&self, field: SearchField) -> &str {
+        match field {
+            SearchField::Name => &self.name,
+            SearchField::Url => &self.url,
+            SearchField::Context => &self.context,
+            SearchField::Date => &self.date,
+        }
+    }
+}
+
+impl SearchQuery {
+    /// Parse `input` when at least one term has a field prefix; `None` for plain text
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut terms = Vec::new();
+        let mut scoped = false;
+        for token in tokenize(input) {
+            let term = match token.split_once(':') {
+                Some((prefix, text)) => match SearchField::by_prefix(prefix) {
+                    Some(field) => {
+                        scoped = true;
+                        SearchTerm { field: Some(field), text: unquote(text).to_lowercase() }
+                    }
+                    None => SearchTerm { field: None, text: unquote(&token).to_lowercase() },
+                },
+                None => SearchTerm { field: None, text: unquote(&token).to_lowercase() },
+            };
+            if !term.text.is_empty() {
+                terms.push(term);
+            }
+        }
+        scoped.then_some(Self { terms })
+    }
+
+    pub fn matches(&self, entry: &Searchable) -> bool {
+        self.terms.iter().all(|term| match term.field {
+            Some(field) => entry.field(field).to_lowercase().contains(&term.text),
+            None => [SearchField::Name, SearchField::Url, SearchField::Context, SearchField::Date]
+                .iter()
+                .map(|&field| entry.field(field))
+                .chain(entry.other.iter().map(String::as_str))
+                .any(|text| text.to_lowercase().contains(&term.text)),
+        })
+    }
+
+    /// Term texts to highlight in `field`: its own terms and unscoped ones (all terms for `None`)
+    pub fn highlights(&self, field: Option<SearchField>) -> Vec<&str> {
+        self.terms
+            .iter()
+            .filter(|term| field.is_none() || term.field.is_none() || term.field == field)
+            .map(|term| term.text.as_str())
+            .collect()
+    }
+}
+
+/// Split on spaces outside double quotes
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"').map(|t| t.strip_suffix('"').unwrap_or(t)).unwrap_or(text)
+}
@@ -0,0 +1,158 @@
+//! Named checkpoints of a document, kept outside the file.
+//!
+//! Each document gets its own directory under the data dir
+//! (`~/.local/share/revw/snapshots/` on Linux) named after its full path, and
+//! each snapshot is a copy of the file written as `<timestamp>_<name>.<ext>`.
+
+use chrono::{Local, NaiveDateTime, Timelike};
+use similar::{Algorithm, ChangeTag};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// One stored checkpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub name: String,
+    pub created: NaiveDateTime,
+    pub path: PathBuf,
+}
+
+/// Snapshots of a single document
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+/// One line of a snapshot diff
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffLine {
+    Same(String),
+    /// Only in the newer text
+    Added(String),
+    /// Only in the older text
+    Removed(String),
+}
+
+/// Default root for all snapshot stores, under the data dir
+pub fn default_root() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("revw").join("snapshots"))
+}
+
+/// File name standing for `document`: its full path with separators turned into '%',
+/// the same scheme as vim's undodir
+pub fn document_key(document: &Path) -> String {
+    let full = fs::canonicalize(document).unwrap_or_else(|_| document.to_path_buf());
+    full.to_string_lossy().replace(['/', '\\', ':'], "%")
+}
+
+impl SnapshotStore {
+    /// Store for `document` (None for an unnamed buffer) under `root`
+    pub fn new(root: &Path, document: Option<&Path>) -> Self {
+        let key = document.map_or_else(|| "unnamed".to_string(), document_key);
+        Self { dir: root.join(key) }
+    }
+
+    /// Write `content` as a new snapshot called `name`
+    pub fn save(&self, name: &str, content: &str, extension: &str) -> Result<Snapshot, String> {
+        let name = sanitize_name(name);
+        if name.is_empty() {
+            return Err("Snapshot name is empty".to_string());
+        }
+        fs::create_dir_all(&self.dir).map_err(|e| format!("Cannot create {}: {}", self.dir.display(), e))?;
+
+        // Whole seconds, as stored in the file name
+        let created = Local::now().naive_local().with_nanosecond(0).unwrap_or_default();
+        let file_name = format!("{}_{}.{}", created.format(TIMESTAMP_FORMAT), name, extension);
+        let path = self.dir.join(file_name);
+        fs::write(&path, content).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+        Ok(Snapshot { name, created, path })
+    }
+
+    /// All snapshots, newest first
+    pub fn list(&self) -> Vec<Snapshot> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut snapshots: Vec<Snapshot> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| parse_file_name(&entry.path()))
+            .collect();
+        snapshots.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| b.path.cmp(&a.path)));
+        snapshots
+    }
+
+    pub fn read(&self, snapshot: &Snapshot) -> Result<String, String> {
+        fs::read_to_string(&snapshot.path).map_err(|e| format!("Cannot read {}: {}", snapshot.path.display(), e))
+    }
+}
+
+/// Keep names usable in file names: letters, digits, `-`, `_` and `.`; anything else becomes `-`
+fn sanitize_name(name: &str) -> String {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '-' })
+        .collect();
+    name.trim_matches('-').to_string()
+}
+
+fn parse_file_name(path: &Path) -> Option<Snapshot> {
+    let stem = path.file_stem()?.to_str()?;
+    let (timestamp, name) = stem.split_once('_')?;
+    let created = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+    Some(Snapshot { name: name.to_string(), created, path: path.to_path_buf() })
+}
+
+/// Line diff from `old` to `new` (Myers, linear space: whole documents stay cheap)
+pub fn diff(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    similar::capture_diff_slices(Algorithm::Myers, &old, &new)
+        .iter()
+        .flat_map(|op| op.iter_changes(&old, &new))
+        .map(|change| {
+            let text = change.value().to_string();
+            match change.tag() {
+                ChangeTag::Equal => DiffLine::Same(text),
+                ChangeTag::Delete => DiffLine::Removed(text),
+                ChangeTag::Insert => DiffLine::Added(text),
+            }
+        })
+        .collect()
+}
+
+/// Changed lines of a diff with `context` unchanged lines around them; `None` marks skipped runs
+pub fn diff_hunks(lines: &[DiffLine], context: usize) -> Vec<Option<&DiffLine>> {
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut hunks = Vec::new();
+    let mut next = 0; // First line not shown yet
+    for (k, &i) in changed.iter().enumerate() {
+        let start = i.saturating_sub(context).max(next);
+        if start > next {
+            hunks.push(None);
+        }
+        for line in &lines[start..=i] {
+            hunks.push(Some(line));
+        }
+        next = i + 1;
+        // Trailing context, cut short when the next change is close
+        let limit = changed.get(k + 1).copied().unwrap_or(lines.len());
+        let end = (i + 1 + context).min(limit);
+        for line in &lines[next..end] {
+            hunks.push(Some(line));
+        }
+        next = end;
+    }
+    if !changed.is_empty() && next < lines.len() {
+        hunks.push(None);
+    }
+    hunks
+}
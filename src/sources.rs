@@ -0,0 +1,84 @@
+//! Merged view of several files (`revw a.json b.json c.json`).
+//!
+//! The files are combined into one document: OUTSIDE and INSIDE entries keep their file
+//! order and are grouped per file, and each entry carries the label of the file it came
+//! from under [`KEY`]. Saving splits the document by that key again, so every entry goes
+//! back to its own file; editing the key in Edit mode moves an entry to another file.
+
+use serde_json::{Map, Value};
+use std::path::{Path, PathBuf};
+
+/// Entry key holding the label of the file an entry belongs to
+pub const KEY: &str = "_source";
+
+const SECTIONS: [&str; 2] = ["outside", "inside"];
+
+/// Labels for `paths`: file names, or the full path where two files share a name
+pub fn labels(paths: &[PathBuf]) -> Vec<String> {
+    let name = |path: &Path| path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    paths
+        .iter()
+        .map(|path| {
+            let shared = paths.iter().filter(|other| name(other) == name(path)).count() > 1;
+            if shared { path.display().to_string() } else { name(path) }
+        })
+        .collect()
+}
+
+/// One document with the entries of every `(label, document)`, tagged with their label
+pub fn combine(docs: &[(String, Value)]) -> Value {
+    let mut combined = Map::new();
+    for section in SECTIONS {
+        let mut entries = Vec::new();
+        for (label, doc) in docs {
+            for entry in doc.get(section).and_then(Value::as_array).into_iter().flatten() {
+                let mut entry = entry.clone();
+                if let Some(obj) = entry.as_object_mut() {
+                    obj.insert(KEY.to_string(), Value::String(label.clone()));
+                }
+                entries.push(entry);
+            }
+        }
+        combined.insert(section.to_string(), Value::Array(entries));
+    }
+    Value::Object(combined)
+}
+
+/// Split `combined` back into one document per label, each starting from its `bases`
+/// document so other top-level keys survive. An entry without a known label (a card
+/// added in the merged view) goes with the entry before it in its section, or the first
+/// file when it leads the section.
+pub fn split(combined: &Value, bases: &[(String, Value)]) -> Result<Vec<Value>, String> {
+    if bases.is_empty() {
+        return Err("No source files".to_string());
+    }
+    let mut docs: Vec<Value> = bases.iter().map(|(_, base)| base.clone()).collect();
+    for section in SECTIONS {
+        let mut parts: Vec<Vec<Value>> = vec![Vec::new(); bases.len()];
+        let entries = match combined.get(section) {
+            Some(Value::Array(entries)) => entries.as_slice(),
+            Some(_) => return Err(format!("'{}' is not a list", section)),
+            None => &[],
+        };
+        let mut current = 0;
+        for entry in entries {
+            let mut entry = entry.clone();
+            if let Some(obj) = entry.as_object_mut() {
+                let label = obj.shift_remove(KEY);
+                if let Some(label) = label.as_ref().and_then(Value::as_str) {
+                    match bases.iter().position(|(known, _)| known == label) {
+                        Some(index) => current = index,
+                        None => return Err(format!("Unknown {} '{}'", KEY, label)),
+                    }
+                }
+            }
+            parts[current].push(entry);
+        }
+        for (doc, part) in docs.iter_mut().zip(parts) {
+            if let Some(obj) = doc.as_object_mut() {
+                obj.insert(section.to_string(), Value::Array(part));
+            }
+        }
+    }
+    Ok(docs)
+}
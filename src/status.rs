@@ -0,0 +1,43 @@
+//! Optional `status` of OUTSIDE entries: active (the default), done or dropped.
+//!
+//! Independent of the percentage. It is stored like a custom field, so Markdown keeps
+//! it as a `**status:** done` line; done cards are dimmed and dropped ones are hidden
+//! unless `show_dropped` is set or the filter asks for them.
+
+use serde_json::Value;
+
+/// Entry key holding the status
+pub const KEY: &str = "status";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryStatus {
+    #[default]
+    Active,
+    Done,
+    Dropped,
+}
+
+impl EntryStatus {
+    /// Parse a status word (case-insensitive)
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "active" => Some(EntryStatus::Active),
+            "done" => Some(EntryStatus::Done),
+            "dropped" => Some(EntryStatus::Dropped),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EntryStatus::Active => "active",
+            EntryStatus::Done => "done",
+            EntryStatus::Dropped => "dropped",
+        }
+    }
+
+    /// Status of a JSON `status` value; missing or unknown values count as active
+    pub fn of(value: Option<&Value>) -> Self {
+        value.and_then(Value::as_str).and_then(Self::by_name).unwrap_or_default()
+    }
+}
@@ -0,0 +1,126 @@
+//! Crash recovery: the unsaved buffer of the open file, written every few seconds.
+//!
+//! Swap files live under the data dir (`~/.local/share/revw/swap/` on Linux), one per
+//! document, named after its full path like snapshot stores. A normal exit removes it,
+//! so one that is still there on the next launch holds work a crash would have lost,
+//! unless the revw named in its header is still running and editing the file.
+
+use crate::snapshot::document_key;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How often the swap file of a modified buffer is rewritten
+pub const INTERVAL: Duration = Duration::from_secs(4);
+
+/// Default root for swap files, under the data dir
+pub fn default_root() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("revw").join("swap"))
+}
+
+/// Swap file of `document` under `root`
+pub fn path_for(root: &Path, document: &Path) -> PathBuf {
+    root.join(format!("{}.swp", document_key(document)))
+}
+
+/// Start of a swap file's first line, which names the session writing it
+const HEADER: &str = "revw swap";
+
+/// A swap file as read back: the session that wrote it and the buffer it holds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Swap {
+    pub pid: Option<u32>,
+    pub host: Option<String>,
+    pub content: String,
+}
+
+impl Swap {
+    /// Whether another revw on this machine, still running, keeps it up to date
+    pub fn in_use(&self) -> bool {
+        match (self.pid, self.host.as_deref()) {
+            (Some(pid), Some(host)) => pid != std::process::id() && host == hostname() && process_alive(pid),
+            _ => false,
+        }
+    }
+}
+
+/// Write `content` to the swap file at `path`, headed by this session's pid and host.
+/// Only the owner may read it: it holds the same notes as the file.
+pub fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create '{}': {}", dir.display(), e))?;
+    }
+    let error = |e: std::io::Error| format!("Cannot write swap file '{}': {}", path.display(), e);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // A file from before swap files were private keeps its mode on open
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(error)?;
+        }
+    }
+    let mut file = options.open(path).map_err(error)?;
+    write!(file, "{} pid={} host={}\n{}", HEADER, std::process::id(), hostname(), content).map_err(error)
+}
+
+/// The swap file at `path`, if there is one. One without a header (older revw) has no
+/// known owner.
+pub fn read(path: &Path) -> Option<Swap> {
+    let text = fs::read_to_string(path).ok()?;
+    let header = text.split_once('\n').filter(|(first, _)| first.starts_with(HEADER));
+    let Some((first, content)) = header else {
+        return Some(Swap { pid: None, host: None, content: text });
+    };
+    let mut swap = Swap { pid: None, host: None, content: content.to_string() };
+    for field in first[HEADER.len()..].split_whitespace() {
+        match field.split_once('=') {
+            Some(("pid", pid)) => swap.pid = pid.parse().ok(),
+            Some(("host", host)) => swap.host = Some(host.to_string()),
+            _ => {}
+        }
+    }
+    Some(swap)
+}
+
+/// Delete the swap file at `path` (nothing to do when there is none)
+pub fn remove(path: &Path) {
+    let _ = fs::remove_file(path);
+}
+
+/// Name of this machine, as written in swap file headers
+pub fn hostname() -> String {
+    #[cfg(unix)]
+    {
+        let mut buffer = [0u8; 256];
+        // SAFETY: the buffer is valid for its whole length, and gethostname writes at most that
+        if unsafe { libc::gethostname(buffer.as_mut_ptr().cast(), buffer.len()) } == 0 {
+            let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+            return String::from_utf8_lossy(&buffer[..end]).into_owned();
+        }
+    }
+    std::env::var("COMPUTERNAME").or_else(|_| std::env::var("HOSTNAME")).unwrap_or_default()
+}
+
+/// Whether process `pid` is running
+fn process_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        let Ok(pid) = i32::try_from(pid) else {
+            return false;
+        };
+        // SAFETY: signal 0 only checks that the process exists and may be signalled
+        if unsafe { libc::kill(pid, 0) } == 0 {
+            return true;
+        }
+        std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = pid;
+        false
+    }
+}
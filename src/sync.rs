@@ -0,0 +1,300 @@
+//! Pull and push the open file to a WebDAV folder such as Nextcloud's (`:sync`),
+//! configured in `~/.revwrc`:
+//!
+//! ```text
+//! sync url https://cloud.example.com/remote.php/dav/files/me/notes/
+//! sync user me
+//! sync password_cmd secret-tool lookup service nextcloud
+//! ```
+//!
+//! The password never goes in the rc file: `password_env NAME` reads it from an
+//! environment variable and `password_cmd COMMAND` from what a command prints, such as a
+//! keyring lookup. Talking to the server needs a build with `--features sync`.
+//!
+//! This module only talks to the server and decides what a sync does; reading and
+//! writing the local file stays with the app. To tell who changed what, the hash of the
+//! content both sides agreed on at the last sync is kept per file under the data dir
+//! (`~/.local/share/revw/sync/` on Linux): a side that still has it is unchanged, and
+//! when both sides moved on the sync stops instead of picking one.
+
+use crate::snapshot;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Where the remote copy lives and how to sign in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncConfig {
+    /// WebDAV folder (ending in `/`, the file's name is appended) or the file itself
+    pub url: Option<String>,
+    pub user: Option<String>,
+    /// Environment variable holding the password
+    pub password_env: Option<String>,
+    /// Shell command printing the password
+    pub password_cmd: Option<String>,
+}
+
+impl SyncConfig {
+    /// Apply a `sync KEY VALUE` rc line; false for an unknown key
+    pub fn set(&mut self, key: &str, value: &str) -> bool {
+        let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        match key {
+            "url" => self.url = value,
+            "user" => self.user = value,
+            "password_env" => self.password_env = value,
+            "password_cmd" => self.password_cmd = value,
+            _ => return false,
+        }
+        true
+    }
+
+    /// URL of the remote copy of the file called `file_name`
+    pub fn remote_url(&self, file_name: &str) -> Option<String> {
+        let url = self.url.as_deref()?;
+        if url.ends_with('/') { Some(format!("{}{}", url, encode_path_segment(file_name))) } else { Some(url.to_string()) }
+    }
+
+    /// The password from the environment or the password command; None when neither is set
+    pub fn password(&self) -> Result<Option<String>, String> {
+        if let Some(var) = &self.password_env {
+            return std::env::var(var).map(Some).map_err(|_| format!("Sync password: ${} is not set", var));
+        }
+        let Some(command) = &self.password_cmd else {
+            return Ok(None);
+        };
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .output()
+            .map_err(|e| format!("Sync password command failed: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("Sync password command failed with {}", output.status));
+        }
+        let password = String::from_utf8_lossy(&output.stdout);
+        Ok(Some(password.trim_end_matches(['\n', '\r']).to_string()))
+    }
+}
+
+/// The remote copy as fetched
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteFile {
+    pub content: String,
+    /// Version tag the server gave it, sent back to only overwrite that version
+    pub etag: Option<String>,
+}
+
+/// Why a write to the server was refused
+#[derive(Debug, Clone, PartialEq)]
+pub enum PutError {
+    /// The remote copy is no longer the version the write was based on
+    #[cfg_attr(not(feature = "sync"), allow(dead_code))] // only the HTTP client sees it
+    Changed,
+    Failed(String),
+}
+
+/// A place the file syncs with
+pub trait Remote {
+    /// The remote copy, None when there is none yet
+    fn get(&self) -> Result<Option<RemoteFile>, String>;
+
+    /// Replace the remote copy with `content` if it is still at `etag`, or create it when
+    /// `etag` is None and there is no copy yet
+    fn put(&self, content: &str, etag: Option<&str>) -> Result<(), PutError>;
+}
+
+/// Which way `:sync` goes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// Whichever side changed; stop when both did
+    Auto,
+    /// Take the remote copy (`:sync pull`)
+    Pull,
+    /// Upload the local file (`:sync push`)
+    Push,
+}
+
+impl Direction {
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "" => Some(Direction::Auto),
+            "pull" => Some(Direction::Pull),
+            "push" => Some(Direction::Push),
+            _ => None,
+        }
+    }
+}
+
+/// What a sync did
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    UpToDate,
+    /// The remote copy, to be written to the local file
+    Pulled(String),
+    Pushed,
+    /// Both sides changed since the last sync; nothing was written
+    Conflict,
+}
+
+/// Hash of the content both sides had at the last sync. FNV-1a, so it stays the same
+/// across builds.
+pub fn content_hash(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Sync `local` (the saved file) with `remote`. `base` is the hash recorded at the last
+/// sync; on success the new one is the hash of the local content afterwards.
+pub fn sync(remote: &dyn Remote, local: &str, base: Option<u64>, direction: Direction) -> Result<Outcome, String> {
+    let fetched = remote.get()?;
+    let push = |etag: Option<&str>| match remote.put(local, etag) {
+        Ok(()) => Ok(Outcome::Pushed),
+        // Someone uploaded between our fetch and our write
+        Err(PutError::Changed) => Ok(Outcome::Conflict),
+        Err(PutError::Failed(e)) => Err(e),
+    };
+    let Some(fetched) = fetched else {
+        return match direction {
+            Direction::Pull => Err("No remote copy to pull".to_string()),
+            _ => push(None),
+        };
+    };
+    if fetched.content == local {
+        return Ok(Outcome::UpToDate);
+    }
+    let local_changed = base != Some(content_hash(local));
+    let remote_changed = base != Some(content_hash(&fetched.content));
+    match direction {
+        Direction::Pull => Ok(Outcome::Pulled(fetched.content)),
+        Direction::Push => push(fetched.etag.as_deref()),
+        Direction::Auto if local_changed && remote_changed => Ok(Outcome::Conflict),
+        Direction::Auto if remote_changed => Ok(Outcome::Pulled(fetched.content)),
+        Direction::Auto => push(fetched.etag.as_deref()),
+    }
+}
+
+/// Default root for the last-sync records, under the data dir
+pub fn default_root() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("revw").join("sync"))
+}
+
+/// Last-sync record of `document` under `root`, named like its snapshot directory
+fn state_path(root: &Path, document: &Path) -> PathBuf {
+    root.join(snapshot::document_key(document))
+}
+
+/// Hash recorded at the last sync of `document`
+pub fn load_base(root: &Path, document: &Path) -> Option<u64> {
+    let text = fs::read_to_string(state_path(root, document)).ok()?;
+    u64::from_str_radix(text.trim(), 16).ok()
+}
+
+pub fn save_base(root: &Path, document: &Path, hash: u64) -> Result<(), String> {
+    fs::create_dir_all(root).map_err(|e| format!("Cannot create {}: {}", root.display(), e))?;
+    let path = state_path(root, document);
+    fs::write(&path, format!("{:016x}\n", hash)).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Percent-encode a file name for a URL path
+fn encode_path_segment(name: &str) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// A file on a WebDAV server, with HTTP basic auth
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "sync"), allow(dead_code))] // read by the HTTP client only
+pub struct WebDav {
+    pub url: String,
+    pub user: Option<String>,
+    pub password: Option<String>,
+}
+
+impl WebDav {
+    /// The remote copy of `file_name` as configured, still without the password
+    pub fn from_config(config: &SyncConfig, file_name: &str) -> Result<Self, String> {
+        let url = config
+            .remote_url(file_name)
+            .ok_or_else(|| "Sync not configured: add `sync url URL` to ~/.revwrc".to_string())?;
+        Ok(WebDav { url, user: config.user.clone(), password: None })
+    }
+
+    /// Read the password as configured. The password command may wait on a keyring, so
+    /// this runs on the sync's worker thread.
+    pub fn unlock(mut self, config: &SyncConfig) -> Result<Self, String> {
+        self.password = config.password()?;
+        Ok(self)
+    }
+
+    #[cfg(feature = "sync")]
+    fn authorization(&self) -> Option<String> {
+        use std::io::Write;
+        let user = self.user.as_deref()?;
+        let mut credentials = Vec::new();
+        write!(credentials, "{}:{}", user, self.password.as_deref().unwrap_or("")).ok()?;
+        Some(format!("Basic {}", crate::base64::encode(&credentials)))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl WebDav {
+    fn agent() -> ureq::Agent {
+        let config = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(std::time::Duration::from_secs(30)))
+            .build();
+        ureq::Agent::new_with_config(config)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl Remote for WebDav {
+    fn get(&self) -> Result<Option<RemoteFile>, String> {
+        let mut request = Self::agent().get(&self.url);
+        if let Some(auth) = self.authorization() {
+            request = request.header("Authorization", auth);
+        }
+        let mut response = request.call().map_err(|e| format!("Sync: {}", e))?;
+        match response.status().as_u16() {
+            404 => Ok(None),
+            200 => {
+                let etag = response.headers().get("etag").and_then(|v| v.to_str().ok()).map(str::to_string);
+                let content = response.body_mut().read_to_string().map_err(|e| format!("Sync: {}", e))?;
+                Ok(Some(RemoteFile { content, etag }))
+            }
+            401 | 403 => Err("Sync: the server refused the credentials".to_string()),
+            status => Err(format!("Sync: server answered {}", status)),
+        }
+    }
+
+    fn put(&self, content: &str, etag: Option<&str>) -> Result<(), PutError> {
+        let mut request = Self::agent().put(&self.url);
+        if let Some(auth) = self.authorization() {
+            request = request.header("Authorization", auth);
+        }
+        request = match etag {
+            Some(etag) => request.header("If-Match", etag),
+            None => request.header("If-None-Match", "*"),
+        };
+        let response = request.send(content).map_err(|e| PutError::Failed(format!("Sync: {}", e)))?;
+        match response.status().as_u16() {
+            200..=299 => Ok(()),
+            412 => Err(PutError::Changed),
+            401 | 403 => Err(PutError::Failed("Sync: the server refused the credentials".to_string())),
+            status => Err(PutError::Failed(format!("Sync: server answered {}", status))),
+        }
+    }
+}
+
+#[cfg(not(feature = "sync"))]
+impl Remote for WebDav {
+    fn get(&self) -> Result<Option<RemoteFile>, String> {
+        Err("Sync not available (build with --features sync)".to_string())
+    }
+
+    fn put(&self, _content: &str, _etag: Option<&str>) -> Result<(), PutError> {
+        Err(PutError::Failed("Sync not available (build with --features sync)".to_string()))
+    }
+}
@@ -10,6 +10,8 @@ pub struct SyntaxHighlighter {
     syntax_set: SyntaxSet,
     theme: Theme,
     colorscheme: ColorScheme,
+    // Marker drawn in place of "- " list bullets
+    pub bullet: &'static str,
 }
 
 impl SyntaxHighlighter {
@@ -22,6 +24,7 @@ impl SyntaxHighlighter {
             syntax_set,
             theme,
             colorscheme,
+            bullet: "• ",
         }
     }
 
@@ -223,7 +226,7 @@ impl SyntaxHighlighter {
             }
             // Render bullet point
             spans.push(Span::styled(
-                "• ".to_string(),
+                self.bullet.to_string(),
                 Style::default().fg(self.colorscheme.md_url),
             ));
 
@@ -234,8 +237,8 @@ impl SyntaxHighlighter {
         }
 
         // Otherwise, check for bold text (render mode)
-        let highlighted = self.render_bold_in_text(text, default_style);
-        highlighted
+        
+        self.render_bold_in_text(text, default_style)
     }
 
     /// Render bold text (**text**) - removes markers and applies bold style
@@ -0,0 +1,112 @@
+//! Long operations run on a worker thread so the UI keeps drawing.
+//!
+//! A task reports progress and finally a closure that applies its result to the app,
+//! all over a channel the event loop drains between frames. The status bar shows the
+//! progress (or a spinner when the total is unknown) and Esc cancels: the task is
+//! dropped at once and the worker stops at its next `Progress::is_cancelled` check.
+
+use crate::app::App;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+/// What a finished task does to the app, run on the UI thread
+pub type Apply = Box<dyn FnOnce(&mut App) + Send>;
+
+enum TaskEvent {
+    Progress { done: u64, total: u64 },
+    Stage(String),
+    Finished(Result<Apply, String>),
+}
+
+/// Handle a task's worker uses to report progress and to notice cancellation
+pub struct Progress {
+    sender: Sender<TaskEvent>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Progress {
+    /// `done` of `total` units (bytes, entries, ...); a `total` of 0 shows a spinner
+    pub fn update(&self, done: u64, total: u64) {
+        let _ = self.sender.send(TaskEvent::Progress { done, total });
+    }
+
+    /// Replace the task's label, e.g. when moving from reading to parsing
+    pub fn stage(&self, label: &str) {
+        let _ = self.sender.send(TaskEvent::Stage(label.to_string()));
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A running task as the app sees it
+pub struct BackgroundTask {
+    pub label: String,
+    pub done: u64,
+    pub total: u64,
+    receiver: Receiver<TaskEvent>,
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+}
+
+const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+const BAR_WIDTH: usize = 20;
+
+impl BackgroundTask {
+    /// Run `work` on a new thread. It returns what to apply to the app, or an error message.
+    pub fn spawn<F>(label: &str, work: F) -> Self
+    where
+        F: FnOnce(&Progress) -> Result<Apply, String> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let progress = Progress { sender, cancelled: Arc::clone(&cancelled) };
+        thread::spawn(move || {
+            let result = work(&progress);
+            let _ = progress.sender.send(TaskEvent::Finished(result));
+        });
+        Self { label: label.to_string(), done: 0, total: 0, receiver, cancelled, started: Instant::now() }
+    }
+
+    /// Ask the worker to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Take in the worker's messages; the result once it has finished
+    pub fn poll(&mut self) -> Option<Result<Apply, String>> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(TaskEvent::Progress { done, total }) => {
+                    self.done = done;
+                    self.total = total;
+                }
+                Ok(TaskEvent::Stage(label)) => self.label = label,
+                Ok(TaskEvent::Finished(result)) => return Some(result),
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => return Some(Err(format!("{}: task stopped", self.label))),
+            }
+        }
+    }
+
+    /// Status bar text: `Loading big.md [#######.............] 35% (Esc to cancel)`
+    pub fn status_text(&self) -> String {
+        if self.total == 0 {
+            let frame = (self.started.elapsed().as_millis() / 100) as usize % SPINNER.len();
+            return format!("{} {} (Esc to cancel)", self.label, SPINNER[frame]);
+        }
+        let ratio = (self.done as f64 / self.total as f64).clamp(0.0, 1.0);
+        let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+        format!(
+            "{} [{}{}] {}% (Esc to cancel)",
+            self.label,
+            "#".repeat(filled),
+            ".".repeat(BAR_WIDTH - filled),
+            (ratio * 100.0).round() as u64
+        )
+    }
+}
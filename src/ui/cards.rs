@@ -1,15 +1,26 @@
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
 use crate::app::App;
+use crate::content_ops;
+use crate::due::{self, DueState};
+use crate::preview;
+use crate::priority;
+use crate::status::EntryStatus;
 use crate::wrap;
-use crate::rendering::RelfEntry;
+use crate::rendering::{RelfEntry, Renderer};
+use crate::search::SearchField;
 use crate::syntax_highlight::SyntaxHighlighter;
+use chrono::Local;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
 
 use super::utils::highlight_search_in_line;
 
@@ -18,7 +29,16 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
     if app.syntax_highlighter.is_none() {
         app.syntax_highlighter = Some(SyntaxHighlighter::new(app.colorscheme.clone()));
     }
+    let bullet = app.glyph("• ", "- ");
+    if let Some(highlighter) = app.syntax_highlighter.as_mut() {
+        highlighter.bullet = bullet;
+    }
     let title = match &app.file_path {
+        // Merged view: the files in the order their cards appear
+        None if !app.sources.is_empty() => {
+            let labels: Vec<&str> = app.sources.iter().map(|file| file.label.as_str()).collect();
+            format!(" {} ", labels.join(" + "))
+        }
         Some(path) => {
             if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
                 // Remove extension if show_extension is false
@@ -39,11 +59,28 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
         None => String::new(),
     };
 
+    // Pagination indicator, e.g. " cards 12–19 of 143 "; focus mode shows the selected card alone
+    let (scroll_start, scroll_end) = if app.focus_mode {
+        let selected = app.selected_entry_index.min(app.relf_entries.len().saturating_sub(1));
+        (selected, (selected + 1).min(app.relf_entries.len()))
+    } else {
+        let start = app.next_card_scroll_frame();
+        (start, (start + app.max_visible_cards.max(1)).min(app.relf_entries.len()))
+    };
+    let page_label = if app.focus_mode {
+        format!(" focus: card {} of {} ", scroll_start + 1, app.relf_entries.len())
+    } else if app.relf_entries.len() > app.max_visible_cards.max(1) {
+        format!(" cards {}{}{} of {} ", scroll_start + 1, app.glyph("–", "-"), scroll_end, app.relf_entries.len())
+    } else {
+        String::new()
+    };
+
     let outer_block = Block::default()
         .title(title)
+        .title_bottom(Line::from(page_label).right_aligned())
         .title_style(Style::default().fg(app.colorscheme.window_title))
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(app.colorscheme.window_border))
         .style(Style::default().bg(app.colorscheme.background));
 
@@ -64,39 +101,54 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Compute visual row count for selected card (used by key handler for scroll bounds)
     let card_inner_width = inner_area.width.saturating_sub(2) as usize;
-    {
-        let context = app.relf_entries.get(selected)
-            .and_then(|e| e.context.as_deref())
-            .unwrap_or("");
-        app.card_context_rows = wrap::total_rows(context, card_inner_width);
+    app.card_context_rows = app.relf_entries.get(selected)
+        .map(|e| body_rows(app, e, card_inner_width, app.context_line_limit(e)))
+        .unwrap_or(0);
+
+    // Only the visible window is materialized: highlight bodies of cards that came into
+    // view or changed, and drop the ones that scrolled out
+    let mut body_cache = std::mem::take(&mut app.card_body_cache);
+    body_cache.retain(|idx, _| (scroll_start..scroll_end).contains(idx));
+    for (idx, entry) in app.relf_entries.iter().enumerate().take(scroll_end).skip(scroll_start) {
+        let limit = app.context_line_limit(entry);
+        let key = body_cache_key(app, entry, limit);
+        if body_cache.get(&idx).is_none_or(|(cached_key, _)| *cached_key != key) {
+            body_cache.insert(idx, (key, body_lines(app, entry, limit)));
+        }
     }
+    app.card_body_cache = body_cache;
 
-    // Limit number of visible cards (use app setting)
-    let max_visible_cards = app.max_visible_cards;
-
-    // Calculate scroll window to keep selected entry visible
-    let scroll_start = if selected < max_visible_cards {
-        0
+    // Image preview of the selected card, resolved before the entries are borrowed below
+    let selected_image = if app.image_preview && preview::ENABLED {
+        let url = app.relf_entries.get(selected).and_then(|entry| entry.url.clone());
+        url.and_then(|url| app.preview_path(&url))
     } else {
-        selected - max_visible_cards + 1
+        None
     };
 
-    // Get visible entries
+    // Get visible entries (scroll window keeps the selected entry visible)
     let visible_entries: Vec<(usize, &RelfEntry)> = app.relf_entries
         .iter()
         .enumerate()
         .skip(scroll_start)
-        .take(max_visible_cards)
+        .take(scroll_end - scroll_start)
         .collect();
 
     if visible_entries.is_empty() {
         return;
     }
 
-    // Create constraints with Min for flexible heights
+    // Create constraints with Min for flexible heights; collapsed cards take just their
+    // rows so expanded ones get the rest of the screen
     let constraints: Vec<Constraint> = visible_entries
         .iter()
-        .map(|_| Constraint::Min(3)) // Minimum 3 lines per card
+        .map(|(_, entry)| match app.context_line_limit(entry) {
+            Some(limit) if !app.focus_mode => {
+                let rows = body_rows(app, entry, card_inner_width, Some(limit)) as u16;
+                Constraint::Length(rows.saturating_add(2).max(3))
+            }
+            _ => Constraint::Min(3), // Minimum 3 lines per card
+        })
         .collect();
 
     let chunks = Layout::default()
@@ -104,18 +156,15 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
         .constraints(constraints)
         .split(inner_area);
 
+    let mut preview_request = None;
+    let mut preview_remote = None;
+
     // Render each card with Block border
     for (i, (entry_idx, entry)) in visible_entries.iter().enumerate() {
         let is_selected = *entry_idx == selected;
 
         // Check if this card is in Visual mode selection range
-        let in_visual_range = if app.visual_mode {
-            let visual_start = app.visual_start_index.min(app.visual_end_index);
-            let visual_end = app.visual_start_index.max(app.visual_end_index);
-            *entry_idx >= visual_start && *entry_idx <= visual_end
-        } else {
-            false
-        };
+        let in_visual_range = app.is_card_visually_selected(*entry_idx);
 
         // Highlight selected card with different border color
         let border_style = if in_visual_range {
@@ -124,6 +173,15 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
         } else if is_selected {
             // Selected card border
             Style::default().fg(app.colorscheme.card_selected).bg(app.colorscheme.background)
+        } else if entry.status == EntryStatus::Done {
+            // Done cards fade into the background
+            Style::default().fg(app.colorscheme.text_dim).bg(app.colorscheme.background)
+        } else if let Some(color) = due_color(app, entry) {
+            // Overdue (red) and soon-due (yellow) cards stand out over their priority
+            Style::default().fg(color).bg(app.colorscheme.background)
+        } else if let Some(rank) = entry.priority {
+            // Unselected cards with a priority take its color
+            Style::default().fg(priority::color(rank)).bg(app.colorscheme.background)
         } else {
             // Normal card border
             Style::default().fg(app.colorscheme.card_border).bg(app.colorscheme.background)
@@ -131,52 +189,82 @@ pub fn render_relf_cards(f: &mut Frame, app: &mut App, area: Rect) {
 
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_type(app.border_style.to_border_type())
+            .border_set(app.border_set())
             .style(border_style);
 
-        let inner = block.inner(chunks[i]);
+        let mut inner = block.inner(chunks[i]);
         f.render_widget(block, chunks[i]);
 
+        // Split off the right half of the selected card for an image preview
+        if is_selected && app.image_preview && preview::ENABLED {
+            if let Some(path) = selected_image.clone() {
+                let halves = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(inner);
+                inner = halves[0];
+                let image_area = halves[1].inner(Margin { horizontal: 1, vertical: 0 });
+                f.render_widget(Clear, image_area);
+                if image_area.width > 0 && image_area.height > 0 {
+                    preview_request = Some((path, image_area));
+                }
+            } else if let Some(url) = entry.url.as_deref().filter(|url| preview::is_remote_image(url)) {
+                preview_remote = Some(url.to_string());
+            }
+        }
+
         // Check if this is an outside entry (has name field)
         if entry.name.is_some() {
             // Outside entry: corner layout
-            render_outside_card(f, app, entry, chunks[i], inner, is_selected);
+            render_outside_card(f, app, *entry_idx, entry, chunks[i], inner, is_selected);
         } else {
             // Inside entry: simple layout
-            render_inside_card(f, app, entry, chunks[i], inner, is_selected);
+            render_inside_card(f, app, *entry_idx, entry, chunks[i], inner, is_selected);
         }
     }
+
+    app.preview_request = preview_request;
+    app.note_remote_preview(preview_remote);
 }
 
-fn render_outside_card(f: &mut Frame, app: &App, entry: &RelfEntry, card_area: Rect, inner_area: Rect, is_selected: bool) {
+fn render_outside_card(f: &mut Frame, app: &App, entry_idx: usize, entry: &RelfEntry, card_area: Rect, inner_area: Rect, is_selected: bool) {
     // Render labels on the border (outside the inner area)
     let name = entry.name.as_deref().unwrap_or("");
     let url = entry.url.as_deref().unwrap_or("");
 
-    // Top-left: name (on the border)
+    // Top-left: name (on the border), colored by priority when set
     if !name.is_empty() {
-        let name_text = format!(" {} ", name);
+        let name_text = left_border_label(name, card_area, spans_width(&top_right_label_spans(app, entry)));
+        let name_color = if entry.status == EntryStatus::Done {
+            app.colorscheme.text_dim
+        } else {
+            entry.priority.map(priority::color).unwrap_or(app.colorscheme.card_title)
+        };
         let name_span = if !app.search_query.is_empty() {
             highlight_search_in_line(
                 &name_text,
-                &app.search_query,
-                Style::default().fg(app.colorscheme.card_title),
+                &app.search_highlight_texts(Some(SearchField::Name)),
+                Style::default().fg(name_color),
             )
         } else {
-            Line::styled(name_text, Style::default().fg(app.colorscheme.card_title))
+            Line::styled(name_text, Style::default().fg(name_color))
         };
         let name_area = Rect { x: card_area.x + 2, y: card_area.y, width: card_area.width.saturating_sub(4), height: 1 };
         let name_para = Paragraph::new(name_span).alignment(Alignment::Left);
         f.render_widget(name_para, name_area);
     }
 
+    render_top_right_labels(f, app, entry, card_area);
+
     // Bottom-left: url (on the border) - render first
     if !url.is_empty() {
-        let url_text = format!(" {} ", url);
+        // The percentage stays pinned to the right, the URL gives way
+        let percentage_width = entry.percentage.map_or(0, |percentage| format!(" {}% ", percentage).len());
+        let url_text = left_border_label(url, card_area, percentage_width);
         let url_span = if !app.search_query.is_empty() {
             highlight_search_in_line(
                 &url_text,
-                &app.search_query,
+                &app.search_highlight_texts(Some(SearchField::Url)),
                 Style::default().fg(app.colorscheme.card_title),
             )
         } else {
@@ -209,51 +297,18 @@ fn render_outside_card(f: &mut Frame, app: &App, entry: &RelfEntry, card_area: R
         f.render_widget(percentage_para, percentage_area);
     }
 
-    // Middle: context (inside the card)
-    let context = entry.context.as_deref().unwrap_or("");
-    if !context.is_empty() {
-        let highlighted_lines: Vec<Line> = if !app.search_query.is_empty() {
-            context.lines().map(|line| {
-                highlight_search_in_line(line, &app.search_query, Style::default().fg(app.colorscheme.card_content))
-            }).collect()
-        } else {
-            let highlighter = app.syntax_highlighter.as_ref();
-            if let Some(h) = highlighter {
-                h.render_lines(context, Style::default().fg(app.colorscheme.card_content))
-            } else {
-                context.lines().map(|line| {
-                    Line::styled(line.to_string(), Style::default().fg(app.colorscheme.card_content))
-                }).collect()
-            }
-        };
-
-        // Count visual (wrapped) rows for accurate scroll-by-row behavior
-        let total_vis_rows = wrap::total_rows(context, inner_area.width as usize);
-        let visible_rows = inner_area.height as usize;
-        let max_vscroll = total_vis_rows.saturating_sub(visible_rows);
-        let vscroll = if is_selected {
-            (app.hscroll as usize).min(max_vscroll)
-        } else {
-            0
-        };
-
-        // Pass all lines; Paragraph::scroll advances by visual rows (wrap-aware)
-        let context_para = Paragraph::new(highlighted_lines)
-            .wrap(Wrap { trim: false })
-            .scroll((vscroll as u16, 0))
-            .alignment(Alignment::Left);
-        f.render_widget(context_para, inner_area);
-    }
+    // Middle: context and custom fields (inside the card)
+    render_card_body(f, app, entry_idx, entry, inner_area, is_selected);
 }
 
-fn render_inside_card(f: &mut Frame, app: &App, entry: &RelfEntry, card_area: Rect, inner_area: Rect, is_selected: bool) {
+fn render_inside_card(f: &mut Frame, app: &App, entry_idx: usize, entry: &RelfEntry, card_area: Rect, inner_area: Rect, is_selected: bool) {
     // Date on the border (top-left)
     if let Some(date) = &entry.date {
         let date_text = format!(" {} ", date);
         let date_span = if !app.search_query.is_empty() {
             highlight_search_in_line(
                 &date_text,
-                &app.search_query,
+                &app.search_highlight_texts(Some(SearchField::Date)),
                 Style::default().fg(app.colorscheme.card_title),
             )
         } else {
@@ -267,37 +322,183 @@ fn render_inside_card(f: &mut Frame, app: &App, entry: &RelfEntry, card_area: Re
         f.render_widget(date_para, date_area);
     }
 
-    // Context inside the card
-    if let Some(context) = &entry.context {
-        let highlighted_lines: Vec<Line> = if !app.search_query.is_empty() {
-            context.lines().map(|line| {
-                highlight_search_in_line(line, &app.search_query, Style::default().fg(app.colorscheme.card_content))
-            }).collect()
+    render_top_right_labels(f, app, entry, card_area);
+
+    // Context and custom fields inside the card
+    render_card_body(f, app, entry_idx, entry, inner_area, is_selected);
+}
+
+/// Cells in the checkbox progress bar
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// Hash of everything a card body is built from, so cached lines are rebuilt when it changes
+fn body_cache_key(app: &App, entry: &RelfEntry, limit: Option<usize>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    entry.context.hash(&mut hasher);
+    entry.fields.hash(&mut hasher);
+    (entry.status == EntryStatus::Done).hash(&mut hasher);
+    app.search_query.hash(&mut hasher);
+    limit.hash(&mut hasher);
+    (app.accessible, app.glyphs).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Highlighted body of a card: progress bar, context and custom fields (`key: value`).
+/// With a `limit`, the context stops after that many lines and a `… (+N lines)` line.
+fn body_lines(app: &App, entry: &RelfEntry, limit: Option<usize>) -> Vec<Line<'static>> {
+    let context = entry.context.as_deref().unwrap_or("");
+    let field_lines = entry.field_lines();
+    if context.is_empty() && field_lines.is_empty() {
+        return Vec::new();
+    }
+
+    let content_color = if entry.status == EntryStatus::Done { app.colorscheme.text_dim } else { app.colorscheme.card_content };
+    let content_style = Style::default().fg(content_color);
+    let mut highlighted_lines: Vec<Line<'static>> = Vec::new();
+    if let Some(progress) = progress_line(app, entry) {
+        highlighted_lines.push(Line::styled(progress, Style::default().fg(app.colorscheme.card_title)));
+    }
+    let mut context_lines: Vec<Line<'static>> = if !app.search_query.is_empty() {
+        let texts = app.search_highlight_texts(Some(SearchField::Context));
+        context.lines().map(|line| {
+            highlight_search_in_line(line, &texts, content_style)
+        }).collect()
+    } else {
+        let highlighter = app.syntax_highlighter.as_ref();
+        if let Some(h) = highlighter {
+            h.render_lines(context, content_style)
         } else {
-            let highlighter = app.syntax_highlighter.as_ref();
-            if let Some(h) = highlighter {
-                h.render_lines(context, Style::default().fg(app.colorscheme.card_content))
-            } else {
-                context.lines().map(|line| {
-                    Line::styled(line.to_string(), Style::default().fg(app.colorscheme.card_content))
-                }).collect()
-            }
-        };
+            context.lines().map(|line| Line::styled(line.to_string(), content_style)).collect()
+        }
+    };
+    let field_style = Style::default().fg(app.colorscheme.text_dim);
+    if let Some((limit, hidden)) = limit.and_then(|limit| Some((limit, hidden_context_lines(context, limit)?))) {
+        context_lines.truncate(limit);
+        context_lines.push(Line::styled(format!("{} (+{} lines)", app.glyph("…", "..."), hidden), field_style));
+    }
+    highlighted_lines.extend(context_lines);
+    let field_texts = app.search_highlight_texts(None);
+    for line in field_lines {
+        highlighted_lines.push(if !app.search_query.is_empty() {
+            highlight_search_in_line(&line, &field_texts, field_style)
+        } else {
+            Line::styled(line, field_style)
+        });
+    }
+    highlighted_lines
+}
+
+/// Context followed by custom fields, scrollable on the selected card
+fn render_card_body(f: &mut Frame, app: &App, entry_idx: usize, entry: &RelfEntry, inner_area: Rect, is_selected: bool) {
+    let Some((_, highlighted_lines)) = app.card_body_cache.get(&entry_idx) else {
+        return;
+    };
+    if highlighted_lines.is_empty() {
+        return;
+    }
+
+    // Count visual (wrapped) rows for accurate scroll-by-row behavior
+    let total_vis_rows = body_rows(app, entry, inner_area.width as usize, app.context_line_limit(entry));
+    let visible_rows = inner_area.height as usize;
+    let max_vscroll = total_vis_rows.saturating_sub(visible_rows);
+    let vscroll = if is_selected {
+        (app.hscroll as usize).min(max_vscroll)
+    } else {
+        0
+    };
+
+    // Pass all lines; Paragraph::scroll advances by visual rows (wrap-aware)
+    let body_para = Paragraph::new(highlighted_lines.clone())
+        .wrap(Wrap { trim: false })
+        .scroll((vscroll as u16, 0));
+    f.render_widget(body_para, inner_area);
+}
 
-        // Count visual (wrapped) rows for accurate scroll-by-row behavior
-        let total_vis_rows = wrap::total_rows(context, inner_area.width as usize);
-        let visible_rows = inner_area.height as usize;
-        let max_vscroll = total_vis_rows.saturating_sub(visible_rows);
-        let vscroll = if is_selected {
-            (app.hscroll as usize).min(max_vscroll)
+/// Visual (wrapped) rows of a card body: progress bar, context (cut at `limit` lines plus
+/// the indicator) and one line per custom field
+fn body_rows(app: &App, entry: &RelfEntry, width: usize, limit: Option<usize>) -> usize {
+    let context = entry.context.as_deref().unwrap_or("");
+    let context_rows = match limit.and_then(|limit| hidden_context_lines(context, limit).map(|_| limit)) {
+        Some(limit) => wrap::total_rows(&context.lines().take(limit).collect::<Vec<_>>().join("\n"), width) + 1,
+        None if context.is_empty() => 0,
+        None => wrap::total_rows(context, width),
+    };
+    let progress_rows = progress_line(app, entry).map_or(0, |line| wrap::total_rows(&line, width));
+    progress_rows + context_rows + entry.field_lines().iter().map(|line| wrap::total_rows(line, width)).sum::<usize>()
+}
+
+/// Context lines past `limit`, if any are hidden
+fn hidden_context_lines(context: &str, limit: usize) -> Option<usize> {
+    let hidden = context.lines().count().saturating_sub(limit);
+    (hidden > 0).then_some(hidden)
+}
+
+/// `[██████░░░░] 3/5 done` when the context has task checkboxes (`3/5 done` in accessible mode)
+fn progress_line(app: &App, entry: &RelfEntry) -> Option<String> {
+    let (checked, total) = content_ops::checkbox_progress(entry.context.as_deref()?)?;
+    if app.accessible {
+        return Some(format!("{}/{} done", checked, total));
+    }
+    let filled = checked * PROGRESS_BAR_WIDTH / total;
+    Some(format!(
+        "[{}{}] {}/{} done",
+        app.glyph("█", "#").repeat(filled),
+        app.glyph("░", "-").repeat(PROGRESS_BAR_WIDTH - filled),
+        checked,
+        total
+    ))
+}
+
+/// Top-right border label with the attachment count; missing files are flagged in red
+/// Warning color of a card's due date when it is overdue or due soon
+fn due_color(app: &App, entry: &RelfEntry) -> Option<Color> {
+    let due = entry.due?;
+    DueState::of(due, entry.status, Local::now().date_naive(), app.due_soon_days).color()
+}
+
+/// Top-right border labels: the due date, then the attachment count
+/// Labels on the top-right of a card border: merged-view file, due date, attachments
+fn top_right_label_spans(app: &App, entry: &RelfEntry) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    if let Some(source) = &entry.source {
+        spans.push(Span::styled(format!(" {} ", source), Style::default().fg(app.colorscheme.card_title)));
+    }
+    if let Some(date) = entry.due {
+        let text = format!(" {} ", due::label(date, Local::now().date_naive()));
+        let color = due_color(app, entry).unwrap_or(app.colorscheme.card_title);
+        spans.push(Span::styled(text, Style::default().fg(color)));
+    }
+    if !entry.attachments.is_empty() {
+        let missing = app.missing_attachments(&entry.attachments).len();
+        let (text, color) = if missing > 0 {
+            (format!(" @{} ({} missing) ", entry.attachments.len(), missing), Color::Red)
         } else {
-            0
+            (format!(" @{} ", entry.attachments.len()), app.colorscheme.card_title)
         };
+        spans.push(Span::styled(text, Style::default().fg(color)));
+    }
+    spans
+}
 
-        // Pass all lines; Paragraph::scroll advances by visual rows (wrap-aware)
-        let context_para = Paragraph::new(highlighted_lines)
-            .wrap(Wrap { trim: false })
-            .scroll((vscroll as u16, 0));
-        f.render_widget(context_para, inner_area);
+fn spans_width(spans: &[Span]) -> usize {
+    spans.iter().map(|span| Renderer::display_width_str(&span.content)).sum()
+}
+
+fn render_top_right_labels(f: &mut Frame, app: &App, entry: &RelfEntry, card_area: Rect) {
+    let spans = top_right_label_spans(app, entry);
+    if spans.is_empty() {
+        return;
     }
+    let label_area = Rect { x: card_area.x + 2, y: card_area.y, width: card_area.width.saturating_sub(4), height: 1 };
+    let label = Paragraph::new(Line::from(spans)).alignment(Alignment::Right);
+    f.render_widget(label, label_area);
+}
+
+/// A label on the left of a border, cut with `…` so `right_width` columns stay free for
+/// the label pinned to the right
+fn left_border_label(text: &str, card_area: Rect, right_width: usize) -> String {
+    let border_width = card_area.width.saturating_sub(4) as usize;
+    let gap = if right_width > 0 { 1 } else { 0 };
+    let room = border_width.saturating_sub(right_width + gap + 2);
+    format!(" {} ", Renderer::elide(text, room))
 }
@@ -0,0 +1,55 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::keymap;
+use crate::rendering::Renderer;
+
+/// Compact list of the keys available in the current context, centered over everything else
+pub fn render_cheatsheet(f: &mut Frame, app: &App) {
+    let context = app.key_context();
+    let bindings = keymap::bindings(context);
+    let keys_width = bindings.iter().map(|b| Renderer::display_width_str(&b.keys)).max().unwrap_or(0);
+    let action_width = bindings.iter().map(|b| Renderer::display_width_str(b.action)).max().unwrap_or(0);
+    let title = app.msg_with(Msg::KeysTitle, &[&app.msg(context.title())]);
+
+    let area = f.area();
+    let width = ((keys_width + action_width + 7).max(Renderer::display_width_str(&title) + 2) as u16).min(area.width);
+    let height = (bindings.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let key_style = Style::default().fg(app.colorscheme.card_title).add_modifier(Modifier::BOLD);
+    let action_style = Style::default().fg(app.colorscheme.text);
+    let lines: Vec<Line> = bindings
+        .iter()
+        .map(|binding| {
+            let padding = keys_width - Renderer::display_width_str(&binding.keys);
+            Line::from(vec![
+                Span::styled(format!(" {}{}", binding.keys, " ".repeat(padding)), key_style),
+                Span::styled(format!("   {}", binding.action), action_style),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
@@ -6,11 +6,17 @@ use ratatui::{
     Frame,
 };
 
+use std::collections::{hash_map::DefaultHasher, HashMap};
+use std::hash::{Hash, Hasher};
+use unicode_segmentation::UnicodeSegmentation;
+
 use crate::app::{App, FormatMode, InputMode};
-use crate::wrap::layout_wrapped_text;
+use crate::graphemes;
+use crate::syntax_highlight::SyntaxHighlighter;
+use crate::wrap::{self, layout_wrapped_text, WrappedRow};
 
 use super::json_highlight::highlight_json_line;
-use super::markdown_highlight::highlight_markdown_line;
+use super::markdown_highlight::{code_blocks, fence_spans, highlight_code_block, highlight_markdown_line, CodeBlock};
 use super::utils::{apply_relf_style, slice_spans_by_width};
 
 pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
@@ -55,7 +61,7 @@ pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
     let visible_content: Vec<_> = visual_lines
         .iter()
         .skip(app.scroll as usize)
-        .chain(std::iter::repeat(&empty_line).take(bottom_padding as usize))
+        .chain(std::iter::repeat_n(&empty_line, bottom_padding as usize))
         .take(visible_height as usize)
         .collect();
 
@@ -121,12 +127,7 @@ pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
             if !app.search_query.is_empty() && app.format_mode == FormatMode::Edit {
                 // In Edit mode with search: apply syntax highlighting to full line first
                 let json_spans = if app.is_markdown_file() {
-                    // Use cached highlight if available
-                    if actual_idx < app.markdown_highlight_cache.len() {
-                        app.markdown_highlight_cache[actual_idx].clone()
-                    } else {
-                        highlight_markdown_line(s, &app.colorscheme)
-                    }
+                    highlight_markdown_line(s, &app.colorscheme)
                 } else {
                     highlight_json_line(s, &app.colorscheme)
                 };
@@ -271,12 +272,7 @@ pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
                 if app.format_mode == FormatMode::Edit {
                     // Apply syntax highlighting to full line, then slice
                     let full_line_spans = if app.is_markdown_file() {
-                        // Use cached highlight if available
-                        if actual_idx < app.markdown_highlight_cache.len() {
-                            app.markdown_highlight_cache[actual_idx].clone()
-                        } else {
-                            highlight_markdown_line(s, &app.colorscheme)
-                        }
+                        highlight_markdown_line(s, &app.colorscheme)
                     } else {
                         highlight_json_line(s, &app.colorscheme)
                     };
@@ -294,79 +290,77 @@ pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
             if app.format_mode == FormatMode::Edit
                 && (app.input_mode == InputMode::Insert || app.input_mode == InputMode::Normal)
                 && app.show_cursor
-            {
-                if actual_idx == app.content_cursor_line {
-                    let cursor_char_pos = app.content_cursor_col;
-                    let prefix_cols = app.prefix_display_width(s, cursor_char_pos);
-                    if prefix_cols >= off_cols {
-                        // Insert cursor while preserving existing highlighting
-                        // (with wrapping enabled, cursor may be on a wrapped row - ratatui places it correctly)
-                        let insert_col_in_view = prefix_cols - off_cols;
-
-                        // Calculate display width position across all spans
-                        let mut display_width_count = 0;
-                        let mut cursor_inserted = false;
-                        let mut new_spans: Vec<Span> = Vec::new();
-
-                        for span in content_spans.iter() {
-                            let span_text = span.content.to_string();
-                            let span_display_width = app.display_width_str(&span_text);
-
-                            if !cursor_inserted && display_width_count + span_display_width >= insert_col_in_view {
-                                // Cursor belongs in this span
-                                // Find the character position within this span
-                                let target_width_in_span = insert_col_in_view - display_width_count;
-
-                                let span_chars: Vec<char> = span_text.chars().collect();
-                                let mut pos_in_span = 0;
-                                let mut accumulated_width = 0;
-
-                                for (i, ch) in span_chars.iter().enumerate() {
-                                    // Check if adding this character would exceed target
-                                    let ch_width = app.display_width_str(&ch.to_string());
-                                    if accumulated_width + ch_width > target_width_in_span {
-                                        // Cursor should be placed before this character
-                                        pos_in_span = i;
-                                        break;
-                                    }
-                                    accumulated_width += ch_width;
-                                    pos_in_span = i + 1;
+                && actual_idx == app.content_cursor_line {
+                let cursor_char_pos = app.content_cursor_col;
+                let prefix_cols = app.prefix_display_width(s, cursor_char_pos);
+                if prefix_cols >= off_cols {
+                    // Insert cursor while preserving existing highlighting
+                    // (with wrapping enabled, cursor may be on a wrapped row - ratatui places it correctly)
+                    let insert_col_in_view = prefix_cols - off_cols;
+
+                    // Calculate display width position across all spans
+                    let mut display_width_count = 0;
+                    let mut cursor_inserted = false;
+                    let mut new_spans: Vec<Span> = Vec::new();
+
+                    for span in content_spans.iter() {
+                        let span_text = span.content.to_string();
+                        let span_display_width = app.display_width_str(&span_text);
+
+                        if !cursor_inserted && display_width_count + span_display_width >= insert_col_in_view {
+                            // Cursor belongs in this span
+                            // Find the character position within this span
+                            let target_width_in_span = insert_col_in_view - display_width_count;
+
+                            let span_chars: Vec<&str> = span_text.graphemes(true).collect();
+                            let mut pos_in_span = 0;
+                            let mut accumulated_width = 0;
+
+                            for (i, ch) in span_chars.iter().enumerate() {
+                                // Check if adding this character (a whole cluster) would exceed target
+                                let ch_width = graphemes::width(ch);
+                                if accumulated_width + ch_width > target_width_in_span {
+                                    // Cursor should be placed before this character
+                                    pos_in_span = i;
+                                    break;
                                 }
+                                accumulated_width += ch_width;
+                                pos_in_span = i + 1;
+                            }
 
-                                // Split span at cursor position
-                                if pos_in_span == 0 {
-                                    // Cursor at start
-                                    new_spans.push(Span::styled("│".to_string(), span.style));
-                                    new_spans.push(span.clone());
-                                } else if pos_in_span >= span_chars.len() {
-                                    // Cursor at end
-                                    new_spans.push(span.clone());
-                                    new_spans.push(Span::styled("│".to_string(), span.style));
-                                } else {
-                                    // Cursor in middle
-                                    let before: String = span_chars[..pos_in_span].iter().collect();
-                                    let after: String = span_chars[pos_in_span..].iter().collect();
-
-                                    new_spans.push(Span::styled(before, span.style));
-                                    new_spans.push(Span::styled("│".to_string(), span.style));
-                                    new_spans.push(Span::styled(after, span.style));
-                                }
-                                cursor_inserted = true;
-                            } else {
+                            // Split span at cursor position
+                            if pos_in_span == 0 {
+                                // Cursor at start
+                                new_spans.push(Span::styled(app.glyph("│", "|").to_string(), span.style));
                                 new_spans.push(span.clone());
-                            }
+                            } else if pos_in_span >= span_chars.len() {
+                                // Cursor at end
+                                new_spans.push(span.clone());
+                                new_spans.push(Span::styled(app.glyph("│", "|").to_string(), span.style));
+                            } else {
+                                // Cursor in middle
+                                let before = span_chars[..pos_in_span].concat();
+                                let after = span_chars[pos_in_span..].concat();
 
-                            display_width_count += span_display_width;
+                                new_spans.push(Span::styled(before, span.style));
+                                new_spans.push(Span::styled(app.glyph("│", "|").to_string(), span.style));
+                                new_spans.push(Span::styled(after, span.style));
+                            }
+                            cursor_inserted = true;
+                        } else {
+                            new_spans.push(span.clone());
                         }
 
-                        // If cursor wasn't inserted yet, add it at the end
-                        if !cursor_inserted {
-                            let last_style = content_spans.last().map(|s| s.style).unwrap_or_default();
-                            new_spans.push(Span::styled("│".to_string(), last_style));
-                        }
+                        display_width_count += span_display_width;
+                    }
 
-                        content_spans = new_spans;
+                    // If cursor wasn't inserted yet, add it at the end
+                    if !cursor_inserted {
+                        let last_style = content_spans.last().map(|s| s.style).unwrap_or_default();
+                        new_spans.push(Span::styled(app.glyph("│", "|").to_string(), last_style));
                     }
+
+                    content_spans = new_spans;
                 }
             }
 
@@ -415,7 +409,7 @@ pub fn render_content(f: &mut Frame, app: &mut App, area: Rect) {
         .title(title)
         .title_style(Style::default().fg(app.colorscheme.window_title))
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(app.colorscheme.window_border))
         .style(Style::default().bg(app.colorscheme.background));
 
@@ -449,13 +443,12 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         (0, (inner_area.width as usize).saturating_sub(1))
     };
 
-    // --- Build flat content string and layout ---
-    let flat_content = lines.join("\n");
-    let flat_cursor = app.cursor_flat_pos();
+    // --- Row counts and cursor ---
+    // Counts are memoized per line, so a frame only measures lines edited since the last
+    // one; just the lines on screen are wrapped into rows and highlighted.
     let wrap_width = content_wrap_width.max(1);
-    let layout = layout_wrapped_text(&flat_content, flat_cursor, wrap_width);
-
-    let total_vis_rows = layout.rows.len();
+    let row_counts = wrap::row_counts(&lines, wrap_width, &mut app.edit_wrap_cache);
+    let total_vis_rows: usize = row_counts.iter().sum();
     let vis_height = inner_area.height as usize;
     let bottom_padding = 10usize;
     app.max_scroll = (total_vis_rows + bottom_padding).saturating_sub(vis_height) as u16;
@@ -463,6 +456,11 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         app.scroll = app.max_scroll;
     }
 
+    let flat_cursor = app.cursor_flat_pos();
+    let (cursor_logical, cursor_col) = App::flat_to_cursor(&lines, flat_cursor);
+    let cursor_layout = layout_wrapped_text(&lines[cursor_logical], cursor_col, wrap_width);
+    let cursor_vis_row = row_counts[..cursor_logical].iter().sum::<usize>() + cursor_layout.cursor.visual_row;
+
     // --- Pre-compute logical line start positions (flat char offsets) ---
     let mut line_starts: Vec<usize> = Vec::with_capacity(lines.len());
     {
@@ -473,43 +471,59 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         }
     }
 
-    // --- Render visible visual rows ---
+    // --- Wrap the visible lines: (logical line, row within it, row at flat positions) ---
     let vscroll = app.scroll as usize;
-    let cursor_vis_row = layout.cursor.visual_row;
+    let mut logical = 0usize;
+    let mut skip = vscroll;
+    while logical < lines.len() && skip >= row_counts[logical] {
+        skip -= row_counts[logical];
+        logical += 1;
+    }
+    let mut visible_rows: Vec<(usize, usize, WrappedRow)> = Vec::with_capacity(vis_height);
+    while logical < lines.len() && visible_rows.len() < vis_height {
+        let layout = layout_wrapped_text(&lines[logical], 0, wrap_width);
+        for (row_in_line, mut row) in layout.rows.into_iter().enumerate().skip(skip) {
+            if visible_rows.len() == vis_height {
+                break;
+            }
+            row.start_pos += line_starts[logical];
+            row.end_pos += line_starts[logical];
+            visible_rows.push((logical, row_in_line, row));
+        }
+        skip = 0;
+        logical += 1;
+    }
+
+    // --- Render visible visual rows ---
     let cursor_is_active = app.show_cursor
         && (app.input_mode == InputMode::Normal || app.input_mode == InputMode::Insert);
+    let selection = app.text_visual_range();
+
+    // Lines are highlighted whole and each visual row takes its slice, so highlighting
+    // survives wrapping. Highlights of lines still on screen carry over between frames.
+    let blocks = if app.is_markdown_file() { code_blocks(&lines) } else { Vec::new() };
+    if !blocks.is_empty() && app.syntax_highlighter.is_none() {
+        app.syntax_highlighter = Some(SyntaxHighlighter::new(app.colorscheme.clone()));
+    }
+    let mut previous_highlights = std::mem::take(&mut app.edit_highlight_cache);
+    let mut highlights = HashMap::new();
 
     let mut lines_vec: Vec<Line> = Vec::with_capacity(vis_height);
 
     for row_off in 0..vis_height {
         let row_idx = vscroll + row_off;
 
-        if row_idx >= total_vis_rows {
+        let Some((logical_idx, row_in_line, row)) = visible_rows.get(row_off) else {
             lines_vec.push(Line::from(Span::raw("")));
             continue;
-        }
-
-        let row = &layout.rows[row_idx];
-
-        // Determine logical line index (binary search over line_starts)
-        let logical_idx = line_starts.partition_point(|&s| s <= row.start_pos).saturating_sub(1);
-
-        // Is this the first visual row for its logical line?
-        let is_first_row_of_logical = row_idx == 0
-            || {
-                let prev = &layout.rows[row_idx - 1];
-                line_starts.partition_point(|&s| s <= prev.start_pos).saturating_sub(1)
-                    < logical_idx
-            };
+        };
+        let logical_idx = *logical_idx;
 
         // --- Line number span ---
         let line_num_span: Option<Span> = if gutter_width > 0 {
-            let num_str = if is_first_row_of_logical {
+            let num_str = if *row_in_line == 0 {
                 let digits = gutter_width - 1;
                 if app.show_relative_line_numbers {
-                    let cursor_logical = line_starts
-                        .partition_point(|&s| s <= flat_cursor)
-                        .saturating_sub(1);
                     if logical_idx == cursor_logical {
                         format!("{:>width$} ", logical_idx + 1, width = digits)
                     } else {
@@ -530,11 +544,9 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         let display_text = row.text.clone();
 
         // --- Syntax highlighting ---
-        let mut content_spans: Vec<Span> = if app.is_markdown_file() {
-            highlight_markdown_line(&display_text, &app.colorscheme)
-        } else {
-            highlight_json_line(&display_text, &app.colorscheme)
-        };
+        let row_col = row.start_pos - line_starts[logical_idx];
+        let line_spans = edit_line_spans(app, &lines, &blocks, logical_idx, &mut previous_highlights, &mut highlights);
+        let mut content_spans: Vec<Span> = slice_spans_by_chars(&line_spans, row_col, display_text.chars().count());
 
         // --- Search highlighting (inline, applied over syntax spans) ---
         if !app.search_query.is_empty() {
@@ -542,7 +554,6 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
             let text_lower = display_text.to_lowercase();
             // Check if there's any match before rebuilding spans
             if text_lower.contains(&query_lower) {
-                let actual_vis_row = row_idx;
                 content_spans = rebuild_spans_with_search(
                     &display_text,
                     content_spans,
@@ -551,8 +562,22 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
                     app.current_match_index,
                     logical_idx,
                     // column offset of this visual row within the logical line
-                    row.start_pos.saturating_sub(*line_starts.get(logical_idx).unwrap_or(&0)),
-                    actual_vis_row,
+                    row_col,
+                );
+            }
+        }
+
+        // --- Visual selection highlighting ---
+        if let Some((sel_start, sel_end)) = selection {
+            let row_len = display_text.chars().count();
+            let from = sel_start.max(row.start_pos);
+            let to = sel_end.min(row.start_pos + row_len);
+            if from < to {
+                content_spans = apply_selection_to_spans(
+                    content_spans,
+                    from - row.start_pos,
+                    to - row.start_pos,
+                    Style::default().fg(Color::Black).bg(app.colorscheme.card_visual),
                 );
             }
         }
@@ -560,7 +585,7 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         if cursor_is_active && row_idx == cursor_vis_row {
             content_spans = apply_block_cursor_to_spans(
                 content_spans,
-                layout.cursor.row_char_offset,
+                cursor_layout.cursor.row_char_offset,
             );
         }
 
@@ -572,6 +597,7 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         spans.extend(content_spans);
         lines_vec.push(Line::from(spans));
     }
+    app.edit_highlight_cache = highlights;
 
     // --- Build block and render ---
     let title = match &app.file_path {
@@ -591,13 +617,121 @@ fn render_edit_wrapped(f: &mut Frame, app: &mut App, area: Rect) {
         .title(title)
         .title_style(Style::default().fg(app.colorscheme.window_title))
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(app.colorscheme.window_border))
         .style(Style::default().bg(app.colorscheme.background));
 
     f.render_widget(Paragraph::new(lines_vec).block(block), area);
 }
 
+/// Syntax spans of logical line `idx`. A line is highlighted once per content (a line of a
+/// code block together with its block, whose highlighting depends on the lines above) and
+/// carried from `previous` into `current` by a hash of that text.
+fn edit_line_spans(
+    app: &App,
+    lines: &[String],
+    blocks: &[CodeBlock],
+    idx: usize,
+    previous: &mut HashMap<u64, Vec<Vec<Span<'static>>>>,
+    current: &mut HashMap<u64, Vec<Vec<Span<'static>>>>,
+) -> Vec<Span<'static>> {
+    let markdown = app.is_markdown_file();
+    let block = blocks.iter().find(|block| block.body.contains(&idx));
+    let mut hasher = DefaultHasher::new();
+    markdown.hash(&mut hasher);
+    let first = match block {
+        Some(block) => {
+            lines[block.body.clone()].hash(&mut hasher);
+            block.lang.hash(&mut hasher);
+            block.body.start
+        }
+        None => {
+            lines[idx].hash(&mut hasher);
+            idx
+        }
+    };
+    let key = hasher.finish();
+
+    let unit = current.entry(key).or_insert_with(|| {
+        previous.remove(&key).unwrap_or_else(|| match block {
+            Some(block) => highlight_code_block(
+                &lines[block.body.clone()],
+                block.lang.as_deref(),
+                &app.colorscheme,
+                app.syntax_highlighter.as_ref(),
+            ),
+            None if !markdown => vec![highlight_json_line(&lines[idx], &app.colorscheme)],
+            None if lines[idx].trim_start().starts_with("```") => vec![fence_spans(&lines[idx], &app.colorscheme)],
+            None => vec![highlight_markdown_line(&lines[idx], &app.colorscheme)],
+        })
+    });
+    unit.get(idx - first)
+        .cloned()
+        .unwrap_or_else(|| vec![Span::styled(lines[idx].clone(), Style::default().fg(app.colorscheme.md_text))])
+}
+
+/// Chars `[start, start + len)` of a highlighted line, keeping each span's style
+fn slice_spans_by_chars(spans: &[Span<'static>], start: usize, len: usize) -> Vec<Span<'static>> {
+    let end = start + len;
+    let mut result = Vec::new();
+    let mut seen_chars = 0usize;
+    for span in spans {
+        let span_len = span.content.chars().count();
+        let span_start = seen_chars;
+        seen_chars += span_len;
+        if seen_chars <= start || span_start >= end {
+            continue;
+        }
+        let text: String = span
+            .content
+            .chars()
+            .skip(start.saturating_sub(span_start))
+            .take(end.min(seen_chars) - start.max(span_start))
+            .collect();
+        result.push(Span::styled(text, span.style));
+    }
+    result
+}
+
+/// Restyle chars `[start, end)` of a row (char offsets) with the selection style
+fn apply_selection_to_spans(
+    spans: Vec<Span<'static>>,
+    start: usize,
+    end: usize,
+    selection_style: Style,
+) -> Vec<Span<'static>> {
+    let mut result = Vec::new();
+    let mut seen_chars = 0usize;
+
+    for span in spans {
+        let chars: Vec<char> = span.content.chars().collect();
+        let span_start = seen_chars;
+        let span_end = seen_chars + chars.len();
+        seen_chars = span_end;
+
+        if span_end <= start || span_start >= end {
+            result.push(span);
+            continue;
+        }
+
+        let local_from = start.saturating_sub(span_start);
+        let local_to = end.min(span_end) - span_start;
+        let before: String = chars[..local_from].iter().collect();
+        let selected: String = chars[local_from..local_to].iter().collect();
+        let after: String = chars[local_to..].iter().collect();
+
+        if !before.is_empty() {
+            result.push(Span::styled(before, span.style));
+        }
+        result.push(Span::styled(selected, selection_style));
+        if !after.is_empty() {
+            result.push(Span::styled(after, span.style));
+        }
+    }
+
+    result
+}
+
 fn apply_block_cursor_to_spans(
     spans: Vec<Span<'static>>,
     cursor_char_pos: usize,
@@ -650,7 +784,6 @@ fn rebuild_spans_with_search(
     current_match_index: Option<usize>,
     logical_line: usize,
     col_offset_in_line: usize,
-    _vis_row: usize,
 ) -> Vec<Span<'static>> {
     let query_lower = query.to_lowercase();
     let text_lower = display_text.to_lowercase();
@@ -726,7 +859,7 @@ fn render_help_content(f: &mut Frame, app: &mut App, area: Rect) {
     // Create a block with border like View/Edit modes
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(app.colorscheme.window_border))
         .style(Style::default().bg(app.colorscheme.background));
 
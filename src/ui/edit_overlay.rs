@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::graphemes;
 use crate::wrap::layout_wrapped_text;
 
 pub fn overlay_layout(area: Rect) -> (Rect, Rect, Rect) {
@@ -59,24 +60,107 @@ pub fn render_edit_overlay(f: &mut Frame, app: &App) {
     f.render_widget(blank_paragraph, clear_area);
 
     // Determine if editing INSIDE or OUTSIDE entry
-    // INSIDE: date, context (2 fields)
-    // OUTSIDE: name, context, url, percentage (4 fields)
-    let is_inside = app.edit_buffer.len() == 2;
+    // INSIDE: date, context (+ custom fields)
+    // OUTSIDE: name, context, url, percentage (+ priority, due and custom fields)
+    let is_inside = app.edit_is_inside();
+    let (inner_area, fields_area) = split_extra_field_rows(inner_area, app.edit_extra_field_count());
 
     // Render the popup border
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .style(Style::default().bg(app.colorscheme.background).fg(Color::White));
 
     f.render_widget(block.clone(), popup_area);
 
     // Render fields on borders and content
+    render_extra_fields(f, app, fields_area);
     if is_inside {
         render_inside_overlay(f, app, popup_area, inner_area);
     } else {
         render_outside_overlay(f, app, popup_area, inner_area);
     }
+    render_completions(f, app, popup_area, fields_area);
+}
+
+/// Suggestions for the field being typed in: below the name on the top border, above a
+/// tags row at the bottom
+fn render_completions(f: &mut Frame, app: &App, popup_area: Rect, fields_area: Rect) {
+    if app.edit_completions.is_empty() {
+        return;
+    }
+    let width = (app.edit_completions.iter().map(|value| value.chars().count()).max().unwrap_or(0) as u16 + 4)
+        .min(popup_area.width.saturating_sub(4));
+    let height = app.edit_completions.len() as u16 + 2;
+    let standard = app.edit_buffer.len() - app.edit_extra_field_count();
+    let (x, y) = if app.edit_field_index < standard {
+        (popup_area.x + 2, popup_area.y + 1)
+    } else {
+        let first = app.edit_field_index.saturating_sub(standard).saturating_sub(fields_area.height.saturating_sub(1) as usize);
+        let row = fields_area.y + (app.edit_field_index - standard - first) as u16;
+        let label_width = app.edit_field_key(app.edit_field_index).chars().count() as u16 + 2;
+        (fields_area.x + label_width, row.saturating_sub(height))
+    };
+    let area = Rect { x, y, width, height }.intersection(f.area());
+
+    let lines: Vec<Line> = app
+        .edit_completions
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let style = if i == app.edit_completion_selected {
+                Style::default().fg(app.colorscheme.background).bg(app.colorscheme.card_selected)
+            } else {
+                Style::default().fg(app.colorscheme.text)
+            };
+            Line::styled(format!(" {} ", value), style)
+        })
+        .collect();
+    let block = Block::default()
+        .title_bottom(Line::from(" Tab ").right_aligned())
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Reserve one row per extra field (at most half the area) below the context
+pub fn split_extra_field_rows(inner_area: Rect, count: usize) -> (Rect, Rect) {
+    let rows = (count as u16).min(inner_area.height / 2);
+    let context_area = Rect { height: inner_area.height - rows, ..inner_area };
+    let fields_area = Rect { y: inner_area.y + context_area.height, height: rows, ..inner_area };
+    (context_area, fields_area)
+}
+
+/// Priority and custom fields as `key: value` rows; the selected row scrolls horizontally while editing
+fn render_extra_fields(f: &mut Frame, app: &App, fields_area: Rect) {
+    let standard = app.edit_buffer.len() - app.edit_extra_field_count();
+    // Keep the selected row visible when there are more fields than rows
+    let first = app.edit_field_index.saturating_sub(standard).saturating_sub(fields_area.height.saturating_sub(1) as usize);
+    for (row, idx) in (standard + first..app.edit_buffer.len()).take(fields_area.height as usize).enumerate() {
+        let is_selected = app.edit_field_index == idx;
+        let is_placeholder = app.edit_buffer_is_placeholder.get(idx).copied().unwrap_or(false);
+        let style = get_field_style(app, is_selected, is_placeholder);
+        let label = format!("{}:", app.edit_field_key(idx));
+        let label_width = label.chars().count() as u16;
+
+        let label_area = Rect { y: fields_area.y + row as u16, height: 1, ..fields_area };
+        f.render_widget(Paragraph::new(Line::styled(label, Style::default().fg(app.colorscheme.text_dim))), label_area);
+
+        let value_area = Rect {
+            x: label_area.x + label_width,
+            width: label_area.width.saturating_sub(label_width),
+            ..label_area
+        };
+        let value_line = if is_selected && (app.edit_insert_mode || app.edit_field_editing_mode) {
+            render_scrollable_field_line(&app.edit_buffer[idx], app.edit_cursor_pos, value_area.width as usize, 1, style)
+        } else {
+            Line::styled(format!(" {} ", app.edit_buffer[idx]), style)
+        };
+        f.render_widget(Paragraph::new(value_line), value_area);
+    }
 }
 
 fn render_inside_overlay(f: &mut Frame, app: &App, card_area: Rect, inner_area: Rect) {
@@ -85,7 +169,7 @@ fn render_inside_overlay(f: &mut Frame, app: &App, card_area: Rect, inner_area:
     // Date on top-left border
     if !app.edit_buffer.is_empty() {
         let is_selected = app.edit_field_index == 0;
-        let is_placeholder = app.edit_buffer_is_placeholder.get(0).copied().unwrap_or(false);
+        let is_placeholder = app.edit_buffer_is_placeholder.first().copied().unwrap_or(false);
 
         let style = get_field_style(app, is_selected, is_placeholder);
 
@@ -117,7 +201,7 @@ fn render_outside_overlay(f: &mut Frame, app: &App, card_area: Rect, inner_area:
     // Name on top-left border
     if !app.edit_buffer.is_empty() {
         let is_selected = app.edit_field_index == 0;
-        let is_placeholder = app.edit_buffer_is_placeholder.get(0).copied().unwrap_or(false);
+        let is_placeholder = app.edit_buffer_is_placeholder.first().copied().unwrap_or(false);
 
         let style = get_field_style(app, is_selected, is_placeholder);
 
@@ -351,9 +435,11 @@ fn build_context_line_with_cursor(text: &str, base_style: Style, cursor_char_pos
         return Line::from(spans);
     }
 
+    // The cursor covers the whole cluster it sits on (an emoji or accented letter)
+    let cluster_end = graphemes::next_boundary(text, cursor_char_pos);
     let before: String = chars[..cursor_char_pos].iter().collect();
-    let at_cursor = chars[cursor_char_pos].to_string();
-    let after: String = chars[cursor_char_pos + 1..].iter().collect();
+    let at_cursor: String = chars[cursor_char_pos..cluster_end].iter().collect();
+    let after: String = chars[cluster_end..].iter().collect();
 
     let mut spans = Vec::with_capacity(3);
     if !before.is_empty() {
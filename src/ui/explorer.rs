@@ -21,7 +21,7 @@ pub fn render_explorer(f: &mut Frame, app: &App, area: Rect) {
         .title(title)
         .title_style(Style::default().fg(app.colorscheme.explorer_title))
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(app.colorscheme.explorer_border))
         .style(Style::default().bg(app.colorscheme.background));
 
@@ -53,18 +53,17 @@ pub fn render_explorer(f: &mut Frame, app: &App, area: Rect) {
             .to_string();
 
         // Remove extension if show_extension is false and it's a file
-        if !app.show_extension && entry.path.is_file() {
-            if let Some(stem) = entry.path.file_stem().and_then(|s| s.to_str()) {
-                name = stem.to_string();
-            }
+        if !app.show_extension && entry.path.is_file()
+            && let Some(stem) = entry.path.file_stem().and_then(|s| s.to_str()) {
+            name = stem.to_string();
         }
 
         // Add expand/collapse indicator for directories
         let indicator = if entry.path.is_dir() {
             if entry.is_expanded {
-                "▾ " // Expanded
+                app.glyph("▾ ", "- ") // Expanded
             } else {
-                "▸ " // Collapsed
+                app.glyph("▸ ", "+ ") // Collapsed
             }
         } else {
             "  " // File (no indicator)
@@ -0,0 +1,69 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+
+/// The Ctrl+p finder: query line, then the matching paths with matched characters highlighted
+pub fn render_finder(f: &mut Frame, app: &App) {
+    let Some(index) = app.finder_index.as_ref() else {
+        return;
+    };
+    let area = f.area();
+    let width = (area.width * 7 / 10).max(40).min(area.width);
+    let height = (area.height * 6 / 10).max(8).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let progress = if index.done { String::new() } else { format!(", indexing{}", app.glyph("…", "...")) };
+    let block = Block::default()
+        .title(app.msg_with(Msg::FinderTitle, &[&app.finder_matches.len(), &index.files.len(), &progress]))
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+    let inner = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled("> ", Style::default().fg(app.colorscheme.card_selected)),
+        Span::styled(format!("{}{}", app.finder_query, app.glyph("█", "_")), Style::default().fg(app.colorscheme.text)),
+    ])];
+
+    // Keep the selected row visible below the query line
+    let visible = (inner.height as usize).saturating_sub(1);
+    let start = app.finder_selected.saturating_sub(visible.saturating_sub(1));
+    let match_style = Style::default().fg(app.colorscheme.card_selected).add_modifier(Modifier::BOLD);
+    for (i, (file, positions)) in app.finder_matches.iter().enumerate().skip(start).take(visible) {
+        let row_style = if i == app.finder_selected {
+            Style::default().fg(app.colorscheme.explorer_file_selected).bg(Color::Rgb(60, 60, 60))
+        } else {
+            Style::default().fg(app.colorscheme.text)
+        };
+        let spans: Vec<Span> = index.files[*file]
+            .chars()
+            .enumerate()
+            .map(|(pos, c)| {
+                let style = if positions.contains(&pos) { row_style.patch(match_style) } else { row_style };
+                Span::styled(c.to_string(), style)
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    if app.finder_matches.is_empty() && index.done {
+        lines.push(Line::styled("No matching files", Style::default().fg(app.colorscheme.text_dim)));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
@@ -27,7 +27,7 @@ pub fn highlight_json_line(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'s
                 let mut string_content = String::from("\"");
                 let mut escaped = false;
 
-                while let Some(next_ch) = chars.next() {
+                for next_ch in chars.by_ref() {
                     string_content.push(next_ch);
                     if next_ch == '\\' && !escaped {
                         escaped = true;
@@ -39,9 +39,9 @@ pub fn highlight_json_line(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'s
                 }
 
                 // Determine if this is a key (followed by ':')
-                let mut temp_chars = chars.clone();
+                let temp_chars = chars.clone();
                 let mut is_key = false;
-                while let Some(peek_ch) = temp_chars.next() {
+                for peek_ch in temp_chars {
                     if peek_ch == ':' {
                         is_key = true;
                         break;
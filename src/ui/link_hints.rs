@@ -0,0 +1,48 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::rendering::Renderer;
+
+/// The selected card's links with their hint letters (gl), centered over the cards
+pub fn render_link_hints(f: &mut Frame, app: &App) {
+    let title = app.msg(Msg::LinksTitle);
+    let url_width = app.link_hints.iter().map(|(_, url)| Renderer::display_width_str(url)).max().unwrap_or(0);
+
+    let area = f.area();
+    let width = ((url_width + 7).max(Renderer::display_width_str(title) + 2) as u16).min(area.width);
+    let height = (app.link_hints.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let hint_style = Style::default().fg(app.colorscheme.card_selected).add_modifier(Modifier::BOLD);
+    let url_style = Style::default().fg(app.colorscheme.text);
+    let lines: Vec<Line> = app
+        .link_hints
+        .iter()
+        .map(|(hint, url)| {
+            Line::from(vec![Span::styled(format!(" [{}] ", hint), hint_style), Span::styled(url.clone(), url_style)])
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
@@ -0,0 +1,45 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::rendering::Renderer;
+
+/// Newest lines of the debug log (`:log`), as many as fit, over everything else
+pub fn render_log(f: &mut Frame, app: &App) {
+    let Some(log_lines) = &app.log_lines else {
+        return;
+    };
+    let title = app.msg(Msg::LogTitle);
+
+    let area = f.area();
+    let popup = Rect {
+        x: area.x + area.width / 20,
+        y: area.y + area.height / 10,
+        width: area.width - area.width / 10,
+        height: area.height - area.height / 5,
+    };
+    let inner_width = popup.width.saturating_sub(2) as usize;
+    let visible = popup.height.saturating_sub(2) as usize;
+    let text_style = Style::default().fg(app.colorscheme.text);
+    let lines: Vec<Line> = log_lines[log_lines.len().saturating_sub(visible)..]
+        .iter()
+        .map(|line| Line::styled(Renderer::elide(line, inner_width), text_style))
+        .collect();
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
@@ -1,8 +1,10 @@
 use ratatui::{
-    style::Style,
+    style::{Modifier, Style},
     text::Span,
 };
 
+use std::ops::Range;
+
 use crate::config::ColorScheme;
 use crate::syntax_highlight::SyntaxHighlighter;
 
@@ -18,6 +20,17 @@ pub fn highlight_markdown_line(line: &str, colorscheme: &ColorScheme) -> Vec<Spa
         return spans;
     }
 
+    // Section headers stand out from entry headings
+    if matches!(line.trim_end(), "## OUTSIDE" | "## INSIDE") {
+        spans.push(Span::styled(
+            line.to_string(),
+            Style::default()
+                .fg(colorscheme.md_header)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        ));
+        return spans;
+    }
+
     // Check for headers (##, ###, etc.)
     if line.starts_with('#') {
         let header_end = line.chars().take_while(|c| *c == '#').count();
@@ -76,13 +89,8 @@ fn parse_bold_in_line(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'static
         // Check for bold text **text**
         if i + 1 < chars.len() && chars[i] == '*' && chars[i + 1] == '*' {
             // Flush current text
-            if !current_text.is_empty() {
-                spans.push(Span::styled(
-                    current_text.clone(),
-                    Style::default().fg(colorscheme.md_text),
-                ));
-                current_text.clear();
-            }
+            push_text_with_urls(&mut spans, &current_text, colorscheme);
+            current_text.clear();
 
             // Find closing **
             let start = i + 2;
@@ -124,12 +132,7 @@ fn parse_bold_in_line(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'static
     }
 
     // Flush remaining text
-    if !current_text.is_empty() {
-        spans.push(Span::styled(
-            current_text,
-            Style::default().fg(colorscheme.md_text),
-        ));
-    }
+    push_text_with_urls(&mut spans, &current_text, colorscheme);
 
     if spans.is_empty() {
         spans.push(Span::styled(
@@ -141,89 +144,79 @@ fn parse_bold_in_line(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'static
     spans
 }
 
-/// Highlight markdown with code block support (for Edit mode)
-pub fn highlight_markdown_with_code_blocks(
-    lines: &[String],
-    colorscheme: &ColorScheme,
-    syntax_highlighter: Option<&SyntaxHighlighter>,
-) -> Vec<Vec<Span<'static>>> {
-    let mut result = Vec::new();
-    let mut in_code_block = false;
-    let mut code_lang: Option<String> = None;
-    let mut code_lines: Vec<String> = Vec::new();
-
-    for line in lines {
-        // Check for code block markers
-        if line.trim_start().starts_with("```") {
-            if in_code_block {
-                // End of code block - highlight accumulated code
-                if let Some(highlighter) = syntax_highlighter {
-                    let code = code_lines.join("\n");
-                    let highlighted = highlighter.highlight_code(&code, code_lang.as_deref());
-
-                    // Add the highlighted lines to result
-                    for highlighted_line in highlighted {
-                        result.push(highlighted_line.spans);
-                    }
-                } else {
-                    // No highlighter - just use plain text
-                    for code_line in &code_lines {
-                        result.push(vec![Span::styled(
-                            code_line.to_string(),
-                            Style::default().fg(colorscheme.md_text),
-                        )]);
-                    }
-                }
+/// Plain text as spans, with `http://` and `https://` URLs in the URL color
+fn push_text_with_urls(spans: &mut Vec<Span<'static>>, text: &str, colorscheme: &ColorScheme) {
+    let text_style = Style::default().fg(colorscheme.md_text);
+    let mut rest = text;
+    while let Some(start) = ["http://", "https://"].iter().filter_map(|scheme| rest.find(scheme)).min() {
+        let end = rest[start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, ')' | '>' | '"'))
+            .map_or(rest.len(), |len| start + len);
+        if start > 0 {
+            spans.push(Span::styled(rest[..start].to_string(), text_style));
+        }
+        spans.push(Span::styled(rest[start..end].to_string(), Style::default().fg(colorscheme.md_url)));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest.to_string(), text_style));
+    }
+}
 
-                code_lines.clear();
-                code_lang = None;
-                in_code_block = false;
+/// Fenced code block in Edit mode: the lines between the fences (up to the end of the text
+/// when the block is never closed) and the language named on the opening fence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    pub body: Range<usize>,
+    pub lang: Option<String>,
+}
 
-                // Add the closing ``` fence
-                result.push(vec![Span::styled(
-                    line.to_string(),
-                    Style::default().fg(colorscheme.md_url),
-                )]);
-            } else {
-                // Start of code block
-                in_code_block = true;
-                let lang_str = line.trim_start()[3..].trim();
-                if !lang_str.is_empty() {
-                    code_lang = Some(lang_str.to_string());
-                }
+/// Fenced code blocks of `lines`, in order
+pub fn code_blocks(lines: &[String]) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(usize, Option<String>)> = None;
 
-                // Add the opening ``` fence
-                result.push(vec![Span::styled(
-                    line.to_string(),
-                    Style::default().fg(colorscheme.md_url),
-                )]);
-            }
-        } else if in_code_block {
-            // Inside code block - accumulate lines
-            code_lines.push(line.to_string());
-        } else {
-            // Regular markdown line
-            result.push(highlight_markdown_line(line, colorscheme));
+    for (i, line) in lines.iter().enumerate() {
+        if !line.trim_start().starts_with("```") {
+            continue;
         }
-    }
-
-    // Handle unclosed code block
-    if in_code_block && !code_lines.is_empty() {
-        if let Some(highlighter) = syntax_highlighter {
-            let code = code_lines.join("\n");
-            let highlighted = highlighter.highlight_code(&code, code_lang.as_deref());
-            for highlighted_line in highlighted {
-                result.push(highlighted_line.spans);
-            }
-        } else {
-            for code_line in &code_lines {
-                result.push(vec![Span::styled(
-                    code_line.to_string(),
-                    Style::default().fg(colorscheme.md_text),
-                )]);
+        match open.take() {
+            Some((start, lang)) => blocks.push(CodeBlock { body: start..i, lang }),
+            None => {
+                let lang_str = line.trim_start()[3..].trim();
+                open = Some((i + 1, (!lang_str.is_empty()).then(|| lang_str.to_string())));
             }
         }
     }
+    if let Some((start, lang)) = open {
+        blocks.push(CodeBlock { body: start..lines.len(), lang });
+    }
+
+    blocks
+}
+
+/// A ``` fence line
+pub fn fence_spans(line: &str, colorscheme: &ColorScheme) -> Vec<Span<'static>> {
+    vec![Span::styled(line.to_string(), Style::default().fg(colorscheme.md_url))]
+}
 
-    result
+/// Highlight a code block body as a whole (the syntax of a line depends on the ones above
+/// it), one span list per line; plain text when there is no highlighter
+pub fn highlight_code_block(
+    code_lines: &[String],
+    lang: Option<&str>,
+    colorscheme: &ColorScheme,
+    syntax_highlighter: Option<&SyntaxHighlighter>,
+) -> Vec<Vec<Span<'static>>> {
+    match syntax_highlighter {
+        Some(highlighter) => highlighter
+            .highlight_code(&code_lines.join("\n"), lang)
+            .into_iter()
+            .map(|line| line.spans)
+            .collect(),
+        None => code_lines
+            .iter()
+            .map(|line| vec![Span::styled(line.to_string(), Style::default().fg(colorscheme.md_text))])
+            .collect(),
+    }
 }
@@ -0,0 +1,68 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+
+use super::utils::highlight_search_in_line;
+
+/// Most rows the match list takes below the content (borders included)
+pub const MAX_HEIGHT: u16 = 10;
+
+/// Height of the match list panel for the current matches
+pub fn panel_height(app: &App) -> u16 {
+    (app.search_matches.len() as u16).saturating_add(2).min(MAX_HEIGHT)
+}
+
+pub fn render_match_list(f: &mut Frame, app: &App, area: Rect) {
+    let items = app.match_list_items();
+    let title = app.msg_with(Msg::MatchesTitle, &[&app.search_query, &items.len()]);
+    let border_color = if app.matches_has_focus {
+        app.colorscheme.card_selected
+    } else {
+        app.colorscheme.explorer_border
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.explorer_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    // Keep the selected row visible
+    let visible_height = inner_area.height as usize;
+    let start = app.matches_selected_index.saturating_sub(visible_height.saturating_sub(1));
+
+    let mut lines = Vec::new();
+    for item in items.iter().skip(start).take(visible_height) {
+        let is_selected = item.match_index == app.matches_selected_index;
+        let (label_style, text_style) = if is_selected {
+            let style = Style::default()
+                .fg(app.colorscheme.explorer_file_selected)
+                .bg(Color::Rgb(60, 60, 60))
+                .add_modifier(Modifier::BOLD);
+            (style, style)
+        } else {
+            (Style::default().fg(app.colorscheme.card_title), Style::default().fg(app.colorscheme.text))
+        };
+
+        let mut spans = vec![
+            Span::styled(format!("{} ", item.label), label_style),
+            Span::styled(app.glyph("│ ", ": "), Style::default().fg(app.colorscheme.text_dim)),
+        ];
+        spans.extend(highlight_search_in_line(&item.snippet, &app.search_highlight_texts(None), text_style).spans);
+        lines.push(Line::from(spans));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
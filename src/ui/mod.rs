@@ -7,6 +7,15 @@ mod cards;
 mod edit_overlay;
 mod content;
 mod outline;
+mod matches;
+mod snapshots;
+mod recent;
+mod cheatsheet;
+mod log;
+mod stats;
+mod finder;
+mod palette;
+mod link_hints;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -15,13 +24,25 @@ use ratatui::{
 
 use crate::app::App;
 
+use cheatsheet::render_cheatsheet;
 use content::render_content;
-use edit_overlay::{overlay_layout, render_edit_overlay};
+use edit_overlay::{overlay_layout, render_edit_overlay, split_extra_field_rows};
 use explorer::render_explorer;
+use finder::render_finder;
+use link_hints::render_link_hints;
+use log::render_log;
+use matches::render_match_list;
 use outline::render_outline;
+use palette::render_palette;
+use recent::render_recent_list;
+use snapshots::render_snapshot_list;
+use stats::render_tag_stats;
 use status_bar::render_status_bar;
 
 pub fn ui(f: &mut Frame, app: &mut App) {
+    // Card renderer requests an image preview each frame if one should be shown
+    app.preview_request = None;
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(1)])
@@ -41,6 +62,8 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                 ])
                 .split(chunks[0]);
 
+            app.explorer_area = horizontal_chunks[0];
+            app.outline_area = horizontal_chunks[2];
             render_explorer(f, app, horizontal_chunks[0]);
             render_outline(f, app, horizontal_chunks[2]);
             horizontal_chunks[1]
@@ -52,6 +75,7 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                 .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
                 .split(chunks[0]);
 
+            app.explorer_area = horizontal_chunks[0];
             render_explorer(f, app, horizontal_chunks[0]);
             horizontal_chunks[1]
         }
@@ -62,6 +86,7 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                 .constraints([Constraint::Percentage(80), Constraint::Percentage(20)])
                 .split(chunks[0]);
 
+            app.outline_area = horizontal_chunks[1];
             render_outline(f, app, horizontal_chunks[1]);
             horizontal_chunks[0]
         }
@@ -71,13 +96,57 @@ pub fn ui(f: &mut Frame, app: &mut App) {
         }
     };
 
+    // Match list (:matches) sits below the content, like vim's quickfix window
+    let content_area = if app.matches_open {
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(matches::panel_height(app))])
+            .split(content_area);
+        render_match_list(f, app, vertical_chunks[1]);
+        vertical_chunks[0]
+    } else {
+        content_area
+    };
+
+    // Snapshot browser (:snapshots) also sits below the content
+    let content_area = if app.snapshots_open {
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(snapshots::panel_height(app, content_area.height)),
+            ])
+            .split(content_area);
+        render_snapshot_list(f, app, vertical_chunks[1]);
+        vertical_chunks[0]
+    } else {
+        content_area
+    };
+
+    // Recent files list (:recent); fills the content area on the startup screen
+    let content_area = if app.recent_open {
+        let vertical_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(recent::panel_height(app, content_area.height)),
+            ])
+            .split(content_area);
+        render_recent_list(f, app, vertical_chunks[1]);
+        vertical_chunks[0]
+    } else {
+        content_area
+    };
+
     // Always render content and status bar (even when overlay is active)
     render_content(f, app, content_area);
     render_status_bar(f, app, chunks[1]);
 
     // Render editing overlay on top if active
     if app.editing_entry {
+        app.preview_request = None;
         let (popup_area, _, inner_area) = overlay_layout(f.area());
+        let (inner_area, _) = split_extra_field_rows(inner_area, app.edit_extra_field_count());
         app.set_overlay_viewport(
             inner_area.height,
             inner_area.width,
@@ -85,4 +154,40 @@ pub fn ui(f: &mut Frame, app: &mut App) {
         );
         render_edit_overlay(f, app);
     }
+
+    // Link hints (gl) over the cards they came from
+    if !app.link_hints.is_empty() {
+        app.preview_request = None;
+        render_link_hints(f, app);
+    }
+
+    // File finder (Ctrl+p) over the windows it opens into
+    if app.finder_open {
+        app.preview_request = None;
+        render_finder(f, app);
+    }
+
+    // Command palette over the windows its actions apply to
+    if app.palette_open {
+        app.preview_request = None;
+        render_palette(f, app);
+    }
+
+    // :stats tags table over the windows it summarizes
+    if app.tag_stats.is_some() {
+        app.preview_request = None;
+        render_tag_stats(f, app);
+    }
+
+    // :log view over the windows whose history it tells
+    if app.log_lines.is_some() {
+        app.preview_request = None;
+        render_log(f, app);
+    }
+
+    // Cheat sheet goes over everything, including the overlay it describes
+    if app.cheatsheet_open {
+        app.preview_request = None;
+        render_cheatsheet(f, app);
+    }
 }
@@ -7,28 +7,26 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::i18n::Msg;
 
-pub fn render_outline(f: &mut Frame, app: &App, area: Rect) {
-    let title = " Outline ";
+pub fn render_outline(f: &mut Frame, app: &mut App, area: Rect) {
+    let title = app.msg(Msg::OutlineTitle).to_string();
     let border_color = app.colorscheme.explorer_border;
 
     let block = Block::default()
         .title(title)
         .title_style(Style::default().fg(app.colorscheme.explorer_title))
         .borders(Borders::ALL)
-        .border_type(app.border_style.to_border_type())
+        .border_set(app.border_set())
         .border_style(Style::default().fg(border_color))
         .style(Style::default().bg(app.colorscheme.background));
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    // Get outline entries
-    let entries = app.get_outline_entries();
-
     // Calculate visible range
     let visible_height = inner_area.height as usize;
-    let total_items = entries.len();
+    let total_items = app.outline_len();
 
     // Auto-scroll to keep selected item visible
     let scroll = if total_items > visible_height {
@@ -53,24 +51,36 @@ pub fn render_outline(f: &mut Frame, app: &App, area: Rect) {
 
     let start = scroll.min(total_items.saturating_sub(1));
     let end = (start + visible_height).min(total_items);
+    // Kept so mouse clicks find the row under the pointer
+    app.outline_scroll = start as u16;
 
-    // Render entries
+    // Render entries; with a search or filter active each shows its match count and
+    // entries without matches are dimmed
+    let counts = app.outline_match_counts(start..end);
     let mut lines = Vec::new();
-    for (i, entry) in entries[start..end].iter().enumerate() {
+    for (i, entry) in app.outline_window(start..end).into_iter().enumerate() {
         let abs_index = start + i;
         let is_selected = abs_index == app.outline_selected_index;
+        let matches = counts.as_ref().and_then(|counts| counts.get(i).copied());
 
         let style = if is_selected {
             Style::default()
                 .fg(app.colorscheme.explorer_file_selected)
                 .bg(Color::Rgb(60, 60, 60))
                 .add_modifier(Modifier::BOLD)
+        } else if matches == Some(0) {
+            Style::default()
+                .fg(app.colorscheme.text_dim)
         } else {
             Style::default()
                 .fg(app.colorscheme.text)
         };
 
-        lines.push(Line::styled(entry.clone(), style));
+        let text = match matches {
+            Some(count) if count > 0 => format!("{} ({})", entry, count),
+            _ => entry,
+        };
+        lines.push(Line::styled(text, style));
     }
 
     let content = Paragraph::new(lines)
@@ -0,0 +1,77 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::rendering::Renderer;
+
+/// The command palette: query line, then the matching actions with their keys on the right
+pub fn render_palette(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let width = (area.width * 6 / 10).max(50).min(area.width);
+    let height = (area.height * 6 / 10).max(8).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .title(app.msg_with(Msg::PaletteTitle, &[&app.palette_matches.len(), &app.palette_items.len()]))
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+    let inner = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from(vec![
+        Span::styled("> ", Style::default().fg(app.colorscheme.card_selected)),
+        Span::styled(format!("{}{}", app.palette_query, app.glyph("█", "_")), Style::default().fg(app.colorscheme.text)),
+    ])];
+
+    // Keep the selected row visible below the query line
+    let visible = (inner.height as usize).saturating_sub(1);
+    let start = app.palette_selected.saturating_sub(visible.saturating_sub(1));
+    let match_style = Style::default().fg(app.colorscheme.card_selected).add_modifier(Modifier::BOLD);
+    let row_width = inner.width as usize;
+    for (i, (item, positions)) in app.palette_matches.iter().enumerate().skip(start).take(visible) {
+        let item = &app.palette_items[*item];
+        let (row_style, keys_style) = if i == app.palette_selected {
+            let style = Style::default().fg(app.colorscheme.explorer_file_selected).bg(Color::Rgb(60, 60, 60));
+            (style, style)
+        } else {
+            (Style::default().fg(app.colorscheme.text), Style::default().fg(app.colorscheme.text_dim))
+        };
+        let mut spans: Vec<Span> = item
+            .title
+            .chars()
+            .enumerate()
+            .map(|(pos, c)| {
+                let style = if positions.contains(&pos) { row_style.patch(match_style) } else { row_style };
+                Span::styled(c.to_string(), style)
+            })
+            .collect();
+        // Keys right-aligned; dropped when the title leaves no room for them
+        let title_width = Renderer::display_width_str(item.title);
+        let keys_width = Renderer::display_width_str(&item.keys);
+        if title_width + keys_width + 2 <= row_width {
+            spans.push(Span::styled(" ".repeat(row_width - title_width - keys_width), row_style));
+            spans.push(Span::styled(item.keys.clone(), keys_style));
+        }
+        lines.push(Line::from(spans));
+    }
+    if app.palette_matches.is_empty() {
+        lines.push(Line::styled("No matching actions", Style::default().fg(app.colorscheme.text_dim)));
+    }
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
@@ -0,0 +1,62 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+
+/// Height of the recent files list: the list fits below the content, or the whole
+/// content area when no file is open (the startup screen)
+pub fn panel_height(app: &App, content_height: u16) -> u16 {
+    if app.file_path.is_none() {
+        content_height
+    } else {
+        (app.recent_files.len() as u16).saturating_add(2).min(content_height / 2)
+    }
+}
+
+pub fn render_recent_list(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title(app.msg_with(Msg::RecentTitle, &[&app.recent_files.len()]))
+        .title_style(Style::default().fg(app.colorscheme.explorer_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+    let visible_height = inner_area.height as usize;
+
+    // Keep the selected row visible
+    let start = app.recent_selected_index.saturating_sub(visible_height.saturating_sub(1));
+    let lines: Vec<Line> = app
+        .recent_files
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible_height)
+        .map(|(i, path)| {
+            let style = if i == app.recent_selected_index {
+                Style::default()
+                    .fg(app.colorscheme.explorer_file_selected)
+                    .bg(Color::Rgb(60, 60, 60))
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(app.colorscheme.text)
+            };
+            let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let dir = path.parent().map(|dir| dir.display().to_string()).unwrap_or_default();
+            Line::from(vec![
+                Span::styled(name, style),
+                Span::styled(format!("  {}", dir), style.fg(app.colorscheme.text_dim)),
+            ])
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
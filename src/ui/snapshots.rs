@@ -0,0 +1,80 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::snapshot::DiffLine;
+
+/// Height of the snapshot browser: the list fits, the diff gets half the content area
+pub fn panel_height(app: &App, content_height: u16) -> u16 {
+    if app.snapshot_diff.is_some() {
+        content_height / 2
+    } else {
+        (app.snapshots.len() as u16).saturating_add(2).min(content_height / 2)
+    }
+}
+
+pub fn render_snapshot_list(f: &mut Frame, app: &App, area: Rect) {
+    let title = match app.snapshots.get(app.snapshots_selected_index) {
+        Some(selected) if app.snapshot_diff.is_some() => {
+            format!(" Snapshot '{}' {} buffer (d: back, Enter: restore) ", selected.name, app.glyph("→", "->"))
+        }
+        None if app.snapshot_diff.is_some() => format!(" Disk {} buffer (q: close, :reload! loads the disk version) ", app.glyph("→", "->")),
+        _ => format!(" Snapshots ({}) (d: diff, Enter: restore) ", app.snapshots.len()),
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.explorer_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+    let visible_height = inner_area.height as usize;
+
+    let lines: Vec<Line> = if app.snapshot_diff.is_some() {
+        app.snapshot_diff_rows()
+            .into_iter()
+            .skip(app.snapshot_diff_scroll)
+            .take(visible_height)
+            .map(|row| match row {
+                Some(DiffLine::Added(text)) => Line::styled(format!("+ {}", text), Style::default().fg(Color::Green)),
+                Some(DiffLine::Removed(text)) => Line::styled(format!("- {}", text), Style::default().fg(Color::Red)),
+                Some(DiffLine::Same(text)) => Line::styled(format!("  {}", text), Style::default().fg(app.colorscheme.text)),
+                None => Line::styled(format!("  {}", app.glyph("…", "...")), Style::default().fg(app.colorscheme.text_dim)),
+            })
+            .collect()
+    } else {
+        // Keep the selected row visible
+        let start = app.snapshots_selected_index.saturating_sub(visible_height.saturating_sub(1));
+        app.snapshots
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible_height)
+            .map(|(i, snapshot)| {
+                let style = if i == app.snapshots_selected_index {
+                    Style::default()
+                        .fg(app.colorscheme.explorer_file_selected)
+                        .bg(Color::Rgb(60, 60, 60))
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(app.colorscheme.text)
+                };
+                Line::from(vec![
+                    Span::styled(snapshot.created.format("%Y-%m-%d %H:%M:%S ").to_string(), style.fg(app.colorscheme.text_dim)),
+                    Span::styled(snapshot.name.clone(), style),
+                ])
+            })
+            .collect()
+    };
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
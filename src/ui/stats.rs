@@ -0,0 +1,86 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::i18n::Msg;
+use crate::rendering::Renderer;
+
+/// Longest tag shown in full; longer ones are cut so the numbers stay in view
+const MAX_TAG_WIDTH: usize = 40;
+
+/// Table of `:stats tags`, centered over everything else
+pub fn render_tag_stats(f: &mut Frame, app: &App) {
+    let Some(stats) = &app.tag_stats else {
+        return;
+    };
+    let title = app.msg(Msg::TagsTitle);
+    let headers = [app.msg(Msg::TagColumn), app.msg(Msg::EntriesColumn), app.msg(Msg::AverageColumn)];
+
+    let area = f.area();
+    let tag_width = stats
+        .iter()
+        .map(|row| Renderer::display_width_str(&row.tag))
+        .chain([Renderer::display_width_str(headers[0])])
+        .max()
+        .unwrap_or(0)
+        .min(MAX_TAG_WIDTH)
+        .min((area.width as usize).saturating_sub(24));
+    let count_width = Renderer::display_width_str(headers[1]).max(5);
+    let average_width = Renderer::display_width_str(headers[2]).max(5);
+    let row_width = tag_width + count_width + average_width + 8;
+    let width = (row_width.max(Renderer::display_width_str(title) + 2) as u16).min(area.width);
+    let height = (stats.len() as u16 + 3).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let cell = |text: &str, width: usize, right: bool| {
+        let text = Renderer::elide(text, width);
+        let padding = " ".repeat(width - Renderer::display_width_str(&text));
+        if right { format!("{}{}", padding, text) } else { format!("{}{}", text, padding) }
+    };
+    let header_style = Style::default().fg(app.colorscheme.window_title).add_modifier(Modifier::BOLD);
+    let tag_style = Style::default().fg(app.colorscheme.card_title);
+    let number_style = Style::default().fg(app.colorscheme.text);
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            " {}   {}   {}",
+            cell(headers[0], tag_width, false),
+            cell(headers[1], count_width, true),
+            cell(headers[2], average_width, true)
+        ),
+        header_style,
+    ))];
+    lines.extend(stats.iter().map(|row| {
+        Line::from(vec![
+            Span::styled(format!(" {}", cell(&row.tag, tag_width, false)), tag_style),
+            Span::styled(
+                format!(
+                    "   {}   {}",
+                    cell(&row.entries.to_string(), count_width, true),
+                    cell(&format!("{:.0}%", row.average), average_width, true)
+                ),
+                number_style,
+            ),
+        ])
+    }));
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(app.colorscheme.window_title))
+        .borders(Borders::ALL)
+        .border_set(app.border_set())
+        .border_style(Style::default().fg(app.colorscheme.card_selected))
+        .style(Style::default().bg(app.colorscheme.background));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
@@ -11,28 +11,53 @@ use crate::app::{App, FormatMode};
 pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
     let mut spans = Vec::new();
 
-    // Left side: status message
-    if !app.status_message.is_empty() {
-        let status_text = format!(" {} ", app.status_message);
+    // Left side: status message, or the progress of a running background task
+    let message = match &app.background_task {
+        Some(task) => task.status_text(),
+        None => app.status_message.clone(),
+    };
+    if !message.is_empty() {
+        let status_text = format!(" {} ", message);
         spans.push(Span::styled(
             status_text,
             Style::default().fg(app.colorscheme.status_bar),
         ));
     }
 
-    // Right side: cursor position in Edit mode
+    // Right side: overdue count, search match position, pending-write, journal and safe-mode markers, and in Edit mode the JSON path and cursor position
+    let mut right_text = String::new();
+    if let Some(position) = app.match_position() {
+        right_text.push_str(&format!("{}  ", position));
+    }
+    if app.is_modified {
+        right_text.push_str("[+] ");
+    }
+    if app.is_journal() {
+        right_text.push_str("[journal] ");
+    }
+    if app.safe_mode {
+        right_text.push_str("[safe] ");
+    }
     if app.format_mode == FormatMode::Edit {
+        if let Some(path) = app.cursor_json_path() {
+            right_text.push_str(&format!("{}  ", path));
+        }
         let current_line = app.content_cursor_line + 1;
         let current_col = app.content_cursor_col + 1;
-        let position_text = format!("{}:{} ", current_line, current_col);
+        right_text.push_str(&format!("{}:{} ", current_line, current_col));
+    }
+
+    // Overdue reminders in red, ahead of the gray markers
+    let overdue_text = if app.overdue_count > 0 { format!("{} overdue ", app.overdue_count) } else { String::new() };
 
+    if !right_text.is_empty() || !overdue_text.is_empty() {
         // Calculate padding to right-align
-        let status_width = if !app.status_message.is_empty() {
-            app.status_message.len() + 2
+        let status_width = if !message.is_empty() {
+            message.len() + 2
         } else {
             0
         };
-        let position_width = position_text.len();
+        let position_width = right_text.len() + overdue_text.len();
         let available_width = area.width as usize;
 
         if available_width > status_width + position_width {
@@ -40,8 +65,9 @@ pub fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
             spans.push(Span::raw(" ".repeat(padding_width)));
         }
 
+        spans.push(Span::styled(overdue_text, Style::default().fg(Color::Red)));
         spans.push(Span::styled(
-            position_text,
+            right_text,
             Style::default().fg(Color::DarkGray),
         ));
     }
@@ -30,11 +30,7 @@ pub fn slice_spans_by_width(app: &App, spans: Vec<Span>, start_col: usize, width
         }
 
         // This span overlaps with visible range - need to slice it
-        let visible_start = if span_start < start_col {
-            start_col - span_start
-        } else {
-            0
-        };
+        let visible_start = start_col.saturating_sub(span_start);
 
         let visible_end = if span_end > end_col {
             span_width - (span_end - end_col)
@@ -55,14 +51,24 @@ pub fn slice_spans_by_width(app: &App, spans: Vec<Span>, start_col: usize, width
     result
 }
 
-pub fn highlight_search_in_line(line: &str, query: &str, base_style: Style) -> Line<'static> {
-    let query_lower = query.to_lowercase();
+/// `line` with every occurrence of any of `queries` highlighted (case-insensitive)
+pub fn highlight_search_in_line<S: AsRef<str>>(line: &str, queries: &[S], base_style: Style) -> Line<'static> {
+    let queries_lower: Vec<String> = queries
+        .iter()
+        .map(|query| query.as_ref().to_lowercase())
+        .filter(|query| !query.is_empty())
+        .collect();
     let line_lower = line.to_lowercase();
     let mut spans = Vec::new();
     let mut byte_pos = 0;
 
     while byte_pos < line_lower.len() {
-        if let Some(match_pos) = line_lower[byte_pos..].find(&query_lower) {
+        // Earliest match of any query from here, the longest one on a tie
+        let found = queries_lower
+            .iter()
+            .filter_map(|query| line_lower[byte_pos..].find(query.as_str()).map(|pos| (pos, query.len())))
+            .min_by_key(|&(pos, len)| (pos, std::cmp::Reverse(len)));
+        if let Some((match_pos, match_len)) = found {
             let actual_byte_pos = byte_pos + match_pos;
 
             // Add text before match (ensuring char boundaries)
@@ -74,7 +80,7 @@ pub fn highlight_search_in_line(line: &str, query: &str, base_style: Style) -> L
             }
 
             // Add highlighted match (ensuring char boundaries)
-            let match_end_byte = actual_byte_pos + query_lower.len();
+            let match_end_byte = actual_byte_pos + match_len;
             if line.is_char_boundary(actual_byte_pos) && match_end_byte <= line.len() {
                 let safe_end = if line.is_char_boundary(match_end_byte) {
                     match_end_byte
@@ -0,0 +1,117 @@
+//! URL clean-up applied when an OUTSIDE entry's URL is edited or pasted.
+//!
+//! Each rule can be switched on or off in ~/.revwrc or with `:set`. Local paths are left
+//! alone; whatever still fails `validate::is_valid_url` afterwards is reported so the
+//! caller can warn in the status bar.
+
+use crate::validate;
+
+/// Query parameters that only track where a link was clicked
+const TRACKING_PARAMS: &[&str] = &["fbclid", "gclid", "dclid", "msclkid", "mc_cid", "mc_eid", "igshid", "yclid", "_hsenc", "_hsmi"];
+
+/// File extensions that make `name.ext` a local file rather than a domain
+const FILE_EXTENSIONS: &[&str] = &["md", "json", "txt", "png", "jpg", "jpeg", "gif", "webp", "svg", "pdf", "html", "htm"];
+
+/// Which normalizations run on a URL
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UrlRules {
+    /// `example.com/page` becomes `https://example.com/page`
+    pub add_scheme: bool,
+    /// Drop `utm_*`, `fbclid`, `gclid` and similar query parameters
+    pub strip_tracking: bool,
+    /// Spaces in web URLs become `%20`
+    pub encode_spaces: bool,
+}
+
+impl Default for UrlRules {
+    fn default() -> Self {
+        Self { add_scheme: true, strip_tracking: false, encode_spaces: true }
+    }
+}
+
+impl UrlRules {
+    /// Apply a `url_add_scheme` / `nourl_add_scheme` style option; false if it is not one
+    pub fn set(&mut self, option: &str) -> bool {
+        let (name, enabled) = match option.strip_prefix("no") {
+            Some(name) => (name, false),
+            None => (option, true),
+        };
+        match name {
+            "url_add_scheme" => self.add_scheme = enabled,
+            "url_strip_tracking" => self.strip_tracking = enabled,
+            "url_encode_spaces" => self.encode_spaces = enabled,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// The URL after the enabled rules, and whether the result looks like a valid URL
+pub fn normalize(url: &str, rules: &UrlRules) -> (String, bool) {
+    let mut url = url.trim().to_string();
+    if url.is_empty() {
+        return (url, true);
+    }
+    if rules.add_scheme && looks_like_domain(&url) {
+        url = format!("https://{}", url);
+    }
+    if is_web_url(&url) {
+        if rules.encode_spaces {
+            url = url.replace(' ', "%20");
+        }
+        if rules.strip_tracking {
+            url = strip_tracking_params(&url);
+        }
+    }
+    let valid = validate::is_valid_url(&url);
+    (url, valid)
+}
+
+fn is_web_url(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// `example.com`, `www.rust-lang.org/learn`: a dotted host with a word for a TLD and no scheme
+fn looks_like_domain(url: &str) -> bool {
+    if url.contains("://") || url.starts_with(['/', '.', '~']) {
+        return false;
+    }
+    let host = url.split(['/', '?', '#']).next().unwrap_or("");
+    let host = host.split(':').next().unwrap_or("");
+    let Some((_, tld)) = host.rsplit_once('.') else {
+        return false;
+    };
+    host.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+        && !host.starts_with('.')
+        && !host.contains("..")
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_ascii_alphabetic())
+        && (url.len() > host.len() || !FILE_EXTENSIONS.contains(&tld.to_ascii_lowercase().as_str()))
+}
+
+fn strip_tracking_params(url: &str) -> String {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((base, query)) = rest.split_once('?') else {
+        return url.to_string();
+    };
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|param| {
+            let key = param.split('=').next().unwrap_or("");
+            !param.is_empty() && !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key)
+        })
+        .collect();
+    let mut stripped = base.to_string();
+    if !kept.is_empty() {
+        stripped.push('?');
+        stripped.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        stripped.push('#');
+        stripped.push_str(fragment);
+    }
+    stripped
+}
@@ -0,0 +1,258 @@
+//! Schema checks behind `revw --check`.
+//!
+//! Reports parse errors, bad dates (entry and due), out-of-range percentages, unknown
+//! priorities and malformed URLs, each with the 1-based line it was found on.
+
+use crate::convert::Format;
+use crate::due;
+use crate::journal;
+use crate::navigation::Navigator;
+use crate::priority;
+use crate::status::{self, EntryStatus};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Date layouts accepted for INSIDE entries (the app writes the first one)
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"];
+
+/// One problem found in a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Issue {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Check `content` in the given format; an empty list means the file is clean
+pub fn check(content: &str, format: Format) -> Vec<Issue> {
+    match format {
+        Format::Md => check_markdown(content),
+        Format::Toon => vec![Issue { line: 1, message: "TOON files are not supported yet".to_string() }],
+        _ => check_json(content),
+    }
+}
+
+/// `YYYY-MM-DD`, optionally followed by `HH:MM` or `HH:MM:SS`
+pub fn is_valid_date(date: &str) -> bool {
+    let date = date.trim();
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()
+        || DATE_FORMATS.iter().any(|format| NaiveDateTime::parse_from_str(date, format).is_ok())
+}
+
+/// http(s) URLs with a host, `file://` URLs and local paths; no whitespace anywhere
+pub fn is_valid_url(url: &str) -> bool {
+    if url.is_empty() || url.chars().any(char::is_whitespace) {
+        return false;
+    }
+    if let Some(rest) = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://")) {
+        let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+        return !host.is_empty() && !host.starts_with('.') && !host.ends_with('.');
+    }
+    url.starts_with("file://") || !url.contains("://")
+}
+
+fn check_percentage_text(text: &str) -> Option<String> {
+    let digits = text.trim().trim_end_matches('%').trim();
+    match digits.parse::<i64>() {
+        Ok(pct) => check_percentage(pct),
+        Err(_) => Some(format!("percentage \"{}\" is not a number", text.trim())),
+    }
+}
+
+fn check_percentage(pct: i64) -> Option<String> {
+    if (0..=100).contains(&pct) {
+        None
+    } else {
+        Some(format!("percentage {} is out of range (0-100)", pct))
+    }
+}
+
+fn check_markdown(content: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut section: Option<&str> = None;
+    let mut entry = String::new();
+    let mut in_code_block = false;
+
+    let front_matter = journal::front_matter(content).map_or(0, |front| front.lines);
+    for (i, raw_line) in content.lines().enumerate().skip(front_matter) {
+        let line = raw_line.trim();
+        let mut report = |message: String| issues.push(Issue { line: i + 1, message });
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix("## ") {
+            section = match name.trim() {
+                "OUTSIDE" => Some("outside"),
+                "INSIDE" => Some("inside"),
+                other => {
+                    report(format!("unknown section \"{}\" (its entries are ignored)", other));
+                    Some("")
+                }
+            };
+            continue;
+        }
+        let Some(current) = section else {
+            // A `# Title` above the sections is fine; other text is silently dropped
+            if !line.starts_with('#') {
+                report("text before the first section is ignored".to_string());
+            }
+            continue;
+        };
+        if current.is_empty() {
+            continue;
+        }
+
+        if let Some(title) = line.strip_prefix("### ") {
+            entry = format!("{} \"{}\"", current, title.trim());
+            if current == "inside" && !is_valid_date(title) {
+                report(format!("{}: invalid date", entry));
+            }
+        } else if let Some(url) = line.strip_prefix("**URL:**") {
+            let url = url.trim();
+            if !url.is_empty() && !is_valid_url(url) {
+                report(format!("{}: malformed URL \"{}\"", entry, url));
+            }
+        } else if let Some(pct) = line.strip_prefix("**Percentage:**") {
+            if let Some(problem) = check_percentage_text(pct) {
+                report(format!("{}: {}", entry, problem));
+            }
+        } else if let Some(value) = line.strip_prefix("**Priority:**") {
+            if priority::rank_from_text(value.trim()).is_none() {
+                report(format!("{}: unknown priority \"{}\"", entry, value.trim()));
+            }
+        } else if let Some(value) = line.strip_prefix("**status:**") {
+            if current == "outside" && EntryStatus::by_name(value).is_none() {
+                report(format!("{}: unknown status \"{}\"", entry, value.trim()));
+            }
+        } else if let Some(value) = line.strip_prefix("**Due:**")
+            && due::parse(value).is_none() {
+            report(format!("{}: invalid due date \"{}\"", entry, value.trim()));
+        }
+    }
+
+    issues
+}
+
+fn check_json(content: &str) -> Vec<Issue> {
+    let json_value: Value = match serde_json::from_str(content) {
+        Ok(value) => value,
+        Err(e) => {
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or(&message);
+            return vec![Issue { line: e.line().max(1), message: format!("invalid JSON: {}", message) }];
+        }
+    };
+    let Some(obj) = json_value.as_object() else {
+        return vec![Issue { line: 1, message: "top level must be an object".to_string() }];
+    };
+
+    let lines: Vec<String> = content.lines().map(String::from).collect();
+    let starts = Navigator::entry_start_lines(&lines, false);
+    let total: usize = obj.values().filter_map(|v| v.as_array()).map(|a| a.len()).sum();
+    // Compact JSON keeps several entries per line; fall back to searching from the top
+    let entry_lines = |n: usize| {
+        if starts.len() == total {
+            (starts[n], starts.get(n + 1).copied().unwrap_or(lines.len()))
+        } else {
+            (0, lines.len())
+        }
+    };
+
+    let mut issues = Vec::new();
+    if obj.get(journal::KEY).is_some_and(|flag| !flag.is_boolean()) {
+        let line = find_line(&lines, 0, &format!("\"{}\"", journal::KEY));
+        issues.push(Issue { line, message: "journal must be true or false".to_string() });
+    }
+    let mut n = 0;
+    for (section, value) in obj {
+        let Some(entries) = value.as_array() else {
+            if section == "outside" || section == "inside" {
+                let line = find_line(&lines, 0, &format!("\"{}\"", section));
+                issues.push(Issue { line, message: format!("\"{}\" must be an array", section) });
+            }
+            continue;
+        };
+        for (i, entry) in entries.iter().enumerate() {
+            let (start, end) = entry_lines(n);
+            n += 1;
+            if section != "outside" && section != "inside" {
+                continue;
+            }
+            let Some(entry_obj) = entry.as_object() else {
+                issues.push(Issue { line: start + 1, message: format!("{}[{}]: entry must be an object", section, i) });
+                continue;
+            };
+            for (field, message) in check_json_entry(section, entry_obj) {
+                let line = find_line(&lines[..end], start, &format!("\"{}\"", field));
+                issues.push(Issue { line, message: format!("{}[{}]: {}", section, i, message) });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Problems in one entry as (field, message)
+fn check_json_entry(section: &str, entry: &Map<String, Value>) -> Vec<(&'static str, String)> {
+    let mut problems = Vec::new();
+
+    if section == "inside" {
+        match entry.get("date") {
+            Some(Value::String(date)) if is_valid_date(date) => {}
+            Some(Value::String(date)) => problems.push(("date", format!("invalid date \"{}\"", date))),
+            Some(_) => problems.push(("date", "date must be a string".to_string())),
+            None => problems.push(("date", "missing date".to_string())),
+        }
+        return problems;
+    }
+
+    match entry.get("url") {
+        None | Some(Value::Null) => {}
+        Some(Value::String(url)) if url.is_empty() || is_valid_url(url) => {}
+        Some(Value::String(url)) => problems.push(("url", format!("malformed URL \"{}\"", url))),
+        Some(_) => problems.push(("url", "url must be a string".to_string())),
+    }
+    match entry.get("percentage") {
+        None | Some(Value::Null) => {}
+        Some(value) => match value.as_i64() {
+            Some(pct) => problems.extend(check_percentage(pct).map(|p| ("percentage", p))),
+            None => problems.push(("percentage", "percentage must be an integer".to_string())),
+        },
+    }
+    if let Some(value) = entry.get("priority")
+        && !value.is_null() && priority::rank(value).is_none() {
+        problems.push(("priority", format!("unknown priority {}", value)));
+    }
+    match entry.get("due") {
+        None | Some(Value::Null) => {}
+        Some(value) if value.as_str().and_then(due::parse).is_some() => {}
+        Some(value) => problems.push(("due", format!("invalid due date {}", value))),
+    }
+    if let Some(value) = entry.get(status::KEY)
+        && value.as_str().and_then(EntryStatus::by_name).is_none() {
+        problems.push(("status", format!("unknown status {}", value)));
+    }
+    problems
+}
+
+/// 1-based line of the first occurrence of `needle` at or after `from` (0-based)
+fn find_line(lines: &[String], from: usize, needle: &str) -> usize {
+    lines
+        .iter()
+        .enumerate()
+        .skip(from)
+        .find(|(_, line)| line.contains(needle))
+        .map_or(from + 1, |(i, _)| i + 1)
+}
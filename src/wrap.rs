@@ -1,4 +1,8 @@
+use crate::graphemes;
 use crate::rendering::Renderer;
+use std::collections::{hash_map::DefaultHasher, HashMap};
+use std::hash::{Hash, Hasher};
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WrappedRow {
@@ -39,20 +43,81 @@ pub fn move_cursor_vertical(text: &str, cursor_pos: usize, width: usize, delta:
     }
 
     let target = &layout.rows[target_row];
-    let desired_col = layout.cursor.visual_col;
+    target.start_pos + offset_at_column(&target.text, layout.cursor.visual_col)
+}
+
+/// Chars of `row` that fit before display column `desired_col`, in whole clusters
+fn offset_at_column(row: &str, desired_col: usize) -> usize {
     let mut measured = 0;
     let mut char_offset = 0;
 
-    for ch in target.text.chars() {
-        let ch_width = Renderer::display_width_str(&ch.to_string());
-        if measured + ch_width > desired_col {
+    for grapheme in row.graphemes(true) {
+        let width = graphemes::width(grapheme);
+        if measured + width > desired_col {
             break;
         }
-        measured += ch_width;
-        char_offset += 1;
+        measured += width;
+        char_offset += grapheme.chars().count();
+    }
+
+    char_offset
+}
+
+/// `move_cursor_vertical` over logical lines, laying out only the lines the cursor
+/// passes through instead of the whole text. Takes and returns a (line, col) cursor.
+pub fn move_cursor_vertical_in_lines(
+    lines: &[String],
+    line: usize,
+    col: usize,
+    width: usize,
+    delta: isize,
+) -> (usize, usize) {
+    let Some(current) = lines.get(line) else {
+        return (line, col);
+    };
+    let start = layout_wrapped_text(current, col, width);
+    let desired_col = start.cursor.visual_col;
+
+    let mut target_line = line;
+    let mut layout = start.clone();
+    let mut row = start.cursor.visual_row as isize + delta;
+    while row < 0 && target_line > 0 {
+        target_line -= 1;
+        layout = layout_wrapped_text(&lines[target_line], 0, width);
+        row += layout.rows.len() as isize;
+    }
+    while row >= layout.rows.len() as isize && target_line + 1 < lines.len() {
+        row -= layout.rows.len() as isize;
+        target_line += 1;
+        layout = layout_wrapped_text(&lines[target_line], 0, width);
+    }
+    let row = row.clamp(0, layout.rows.len() as isize - 1) as usize;
+
+    if target_line == line && row == start.cursor.visual_row {
+        return (line, col);
     }
+    let target = &layout.rows[row];
+    (target_line, target.start_pos + offset_at_column(&target.text, desired_col))
+}
 
-    target.start_pos + char_offset
+/// Visual rows each logical line wraps into at `width`. Counts are memoized in `cache`
+/// by line text, so a frame only measures lines edited since the previous one; the cache
+/// starts over once it holds twice as many entries as there are lines.
+pub fn row_counts(lines: &[String], width: usize, cache: &mut HashMap<u64, usize>) -> Vec<usize> {
+    if cache.len() > lines.len() * 2 {
+        cache.clear();
+    }
+    lines
+        .iter()
+        .map(|line| {
+            let mut hasher = DefaultHasher::new();
+            line.hash(&mut hasher);
+            width.hash(&mut hasher);
+            *cache
+                .entry(hasher.finish())
+                .or_insert_with(|| layout_wrapped_text(line, 0, width).rows.len())
+        })
+        .collect()
 }
 
 pub fn layout_wrapped_text(text: &str, cursor_pos: usize, width: usize) -> WrappedTextLayout {
@@ -68,10 +133,11 @@ pub fn layout_wrapped_text(text: &str, cursor_pos: usize, width: usize) -> Wrapp
     let mut cursor_found = false;
 
     for (line_idx, line) in logical_lines.iter().enumerate() {
-        let line_chars: Vec<char> = line.chars().collect();
-        let line_len = line_chars.len();
+        // Rows break between grapheme clusters, never inside one
+        let clusters: Vec<&str> = line.graphemes(true).collect();
+        let line_len = line.chars().count();
 
-        if line_chars.is_empty() {
+        if clusters.is_empty() {
             let row_index = rows.len();
             rows.push(WrappedRow {
                 text: String::new(),
@@ -88,21 +154,23 @@ pub fn layout_wrapped_text(text: &str, cursor_pos: usize, width: usize) -> Wrapp
                 cursor_found = true;
             }
         } else {
+            let mut start = 0;
             let mut start_char = 0;
-            while start_char < line_len {
+            while start < clusters.len() {
+                let mut end = start;
                 let mut end_char = start_char;
                 let mut width_used = 0;
 
-                while end_char < line_len {
-                    let ch = line_chars[end_char];
-                    let ch_width = Renderer::display_width_str(&ch.to_string());
+                while end < clusters.len() {
+                    let width = graphemes::width(clusters[end]);
 
-                    if width_used > 0 && width_used + ch_width > wrap_width {
+                    if width_used > 0 && width_used + width > wrap_width {
                         break;
                     }
 
-                    width_used += ch_width;
-                    end_char += 1;
+                    width_used += width;
+                    end_char += clusters[end].chars().count();
+                    end += 1;
 
                     if width_used >= wrap_width {
                         break;
@@ -110,7 +178,7 @@ pub fn layout_wrapped_text(text: &str, cursor_pos: usize, width: usize) -> Wrapp
                 }
 
                 let row_index = rows.len();
-                let row_text: String = line_chars[start_char..end_char].iter().collect();
+                let row_text: String = clusters[start..end].concat();
                 let row_start_pos = line_start_pos + start_char;
                 let row_end_pos = line_start_pos + end_char;
                 rows.push(WrappedRow {
@@ -136,6 +204,7 @@ pub fn layout_wrapped_text(text: &str, cursor_pos: usize, width: usize) -> Wrapp
                     cursor_found = true;
                 }
 
+                start = end;
                 start_char = end_char;
             }
         }
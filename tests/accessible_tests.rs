@@ -0,0 +1,84 @@
+mod common;
+
+use common::run;
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::config::{ColorScheme, Glyphs};
+
+fn app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{
+  "outside": [
+    {"name": "Book", "context": "- [x] read\n- [ ] notes", "url": "", "percentage": null, "tags": "rust"}
+  ],
+  "inside": []
+}"#
+    .to_string();
+    app.convert_json();
+    app
+}
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn cards_are_drawn_with_box_drawing_by_default() {
+    let mut app = app();
+    let text = screen(&mut app);
+    assert!(text.contains('╭'));
+    assert!(text.contains("█░"));
+}
+
+#[test]
+fn accessible_mode_draws_only_text() {
+    let mut app = app();
+    run(&mut app, "set accessible");
+    assert_eq!(app.status_message, "Accessible mode enabled");
+
+    let text = screen(&mut app);
+    assert!(text.contains("Book"));
+    assert!(text.contains("1/2 done"));
+    assert!(!text.chars().any(|c| ('\u{2500}'..='\u{259F}').contains(&c)), "{}", text);
+
+    run(&mut app, "set noaccessible");
+    assert_eq!(app.status_message, "Accessible mode disabled");
+    assert!(screen(&mut app).contains('╭'));
+}
+
+#[test]
+fn outline_folds_use_ascii_markers() {
+    let mut app = app();
+    run(&mut app, "set accessible");
+    run(&mut app, "outline group tag");
+    assert_eq!(app.outline_window(0..1), ["- rust [1]"]);
+}
+
+#[test]
+fn high_contrast_theme_is_built_in() {
+    let scheme = ColorScheme::by_name("highcontrast").unwrap();
+    assert_eq!(scheme.name, "HighContrast");
+    assert_eq!(ColorScheme::by_name("high-contrast"), Some(scheme));
+    assert!(ColorScheme::all_scheme_names().contains(&"HighContrast"));
+}
+
+#[test]
+fn ascii_glyphs_replace_box_drawing() {
+    let mut app = app();
+    run(&mut app, "set glyphs=ascii");
+    assert_eq!(app.glyphs, Glyphs::Ascii);
+    assert_eq!(app.status_message, "Glyphs set to ascii");
+
+    let text = screen(&mut app);
+    assert!(text.contains("+----"), "{}", text);
+    assert!(text.contains("[#####-----] 1/2 done"), "{}", text);
+    assert!(text.is_ascii(), "{}", text);
+
+    run(&mut app, "set glyphs=fancy");
+    assert_eq!(app.status_message, "Glyphs must be unicode or ascii");
+    run(&mut app, "set glyphs=unicode");
+    assert!(screen(&mut app).contains('╭'));
+}
@@ -1,4 +1,4 @@
-use revw::app::{App, FileMode, FormatMode, InputMode};
+use revw::app::{App, FileMode, FormatMode, InputMode, Motion, TextVisualMode};
 
 #[test]
 fn test_app_creation() {
@@ -735,3 +735,327 @@ fn test_mode_toggle() {
     let app2 = App::new(FormatMode::Edit);
     assert_eq!(app2.format_mode, FormatMode::Edit);
 }
+
+fn edit_app(content: &str) -> App {
+    let mut app = App::new(FormatMode::Edit);
+    app.file_mode = FileMode::Json;
+    app.json_input = content.to_string();
+    app
+}
+
+#[test]
+fn test_visual_char_yank_and_delete() {
+    let mut app = edit_app("alpha beta\ngamma");
+    app.content_cursor_col = 6;
+    app.start_text_visual(TextVisualMode::Char);
+    app.content_cursor_line = 1;
+    app.content_cursor_col = 1;
+    assert_eq!(app.text_visual_range(), Some((6, 13)));
+
+    app.yank_text_selection();
+    assert_eq!(app.line_yank_buffer, "beta\nga");
+    assert!(app.text_visual.is_none());
+    assert_eq!((app.content_cursor_line, app.content_cursor_col), (0, 6));
+
+    app.start_text_visual(TextVisualMode::Char);
+    app.content_cursor_col = 9;
+    app.delete_text_selection();
+    assert_eq!(app.json_input, "alpha \ngamma\n");
+    assert_eq!(app.line_yank_buffer, "beta");
+    assert!(app.is_modified);
+}
+
+#[test]
+fn test_visual_line_delete_and_indent() {
+    let mut app = edit_app("one\ntwo\nthree\nfour");
+    app.content_cursor_line = 1;
+    app.start_text_visual(TextVisualMode::Line);
+    app.content_cursor_line = 2;
+    app.indent_text_selection(false);
+    assert_eq!(app.json_input, "one\n  two\n  three\nfour\n");
+
+    app.start_text_visual(TextVisualMode::Line);
+    app.indent_text_selection(true);
+    assert_eq!(app.json_input, "one\ntwo\n  three\nfour\n");
+
+    app.start_text_visual(TextVisualMode::Line);
+    app.content_cursor_line = 2;
+    app.delete_text_selection();
+    assert_eq!(app.json_input, "one\nfour\n");
+    assert_eq!(app.line_yank_buffer, "two\n  three");
+    assert_eq!(app.content_cursor_line, 1);
+
+    app.undo();
+    assert_eq!(app.json_input, "one\ntwo\n  three\nfour\n");
+}
+
+fn press(app: &mut App, keys: &str) {
+    for c in keys.chars() {
+        if !app.pending_operator.is_empty() {
+            app.handle_operator_key(c);
+        } else {
+            app.pending_operator = c.to_string();
+        }
+    }
+}
+
+#[test]
+fn test_delete_operators() {
+    let mut app = edit_app("foo bar baz\nnext");
+    press(&mut app, "dw");
+    assert_eq!(app.json_input, "bar baz\nnext\n");
+
+    press(&mut app, "de");
+    assert_eq!(app.json_input, " baz\nnext\n");
+
+    app.content_cursor_col = 2;
+    app.apply_operator('d', Motion::LineEnd);
+    assert_eq!(app.json_input, " b\nnext\n");
+    assert_eq!(app.content_cursor_col, 1);
+
+    // dw on the last word stops at the end of the line
+    app.content_cursor_col = 1;
+    press(&mut app, "dw");
+    assert_eq!(app.json_input, " \nnext\n");
+
+    press(&mut app, "dd");
+    assert_eq!(app.json_input, "next\n");
+}
+
+#[test]
+fn test_change_operators_enter_insert_with_one_undo_step() {
+    let mut app = edit_app("  say hello world");
+    app.content_cursor_col = 7;
+    press(&mut app, "ciw");
+    assert_eq!(app.json_input, "  say  world\n");
+    assert_eq!(app.content_cursor_col, 6);
+    assert!(app.input_mode == InputMode::Insert);
+
+    app.insert_char('h');
+    app.insert_char('i');
+    app.end_undo_group();
+    app.input_mode = InputMode::Normal;
+    assert_eq!(app.json_input, "  say hi world\n");
+
+    // The change and the typed text undo together
+    app.undo();
+    assert_eq!(app.json_input, "  say hello world");
+
+    press(&mut app, "cc");
+    assert_eq!(app.json_input, "  \n");
+    assert_eq!(app.content_cursor_col, 2);
+    app.end_undo_group();
+
+    let mut app = edit_app("alpha beta");
+    press(&mut app, "cw");
+    assert_eq!(app.json_input, " beta\n");
+}
+
+#[test]
+fn test_operator_cancelled_by_unknown_motion() {
+    let mut app = edit_app("keep me");
+    press(&mut app, "dz");
+    assert!(app.pending_operator.is_empty());
+    assert_eq!(app.json_input, "keep me");
+}
+
+#[test]
+fn test_select_all_and_invert_cards() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [{"name": "a"}, {"name": "b"}], "inside": [{"date": "d1", "context": "c"}, {"date": "d2", "context": "c"}]}"#.to_string();
+    app.convert_json();
+
+    app.command_buffer = "select all".to_string();
+    app.execute_command();
+    assert!(app.visual_mode);
+    assert_eq!(app.visual_selected_indices(), vec![0, 1, 2, 3]);
+
+    app.command_buffer = "select invert".to_string();
+    app.execute_command();
+    assert!(app.visual_selected_indices().is_empty());
+
+    // Outside Visual mode, invert selects everything but the current card
+    app.visual_mode = false;
+    app.selected_entry_index = 1;
+    app.invert_card_selection();
+    assert_eq!(app.visual_selected_indices(), vec![0, 2, 3]);
+    assert!(!app.is_card_visually_selected(1));
+    assert_eq!(app.status_message, "-- VISUAL -- 3 selected");
+}
+
+#[test]
+fn test_autosave_policy() {
+    use revw::config::AutosavePolicy;
+
+    let path = std::env::temp_dir().join(format!("revw_autosave_{}.json", std::process::id()));
+    let json = r#"{"outside": [{"name": "a"}], "inside": []}"#;
+    std::fs::write(&path, json).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = json.to_string();
+    app.file_path = Some(path.clone());
+    app.convert_json();
+    // Every autosave writes right away (test_autosave_interval covers the interval)
+    app.autosave_interval = std::time::Duration::ZERO;
+
+    // manual: the duplicate stays pending until :w
+    app.autosave = AutosavePolicy::Manual;
+    app.duplicate_selected_entry();
+    assert!(app.is_modified);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), json);
+
+    // on-action: the next View-mode operation writes everything pending
+    app.autosave = AutosavePolicy::OnAction;
+    app.duplicate_selected_entry();
+    assert!(!app.is_modified);
+    assert_eq!(std::fs::read_to_string(&path).unwrap().matches("\"a\"").count(), 3);
+
+    // on-action leaves Edit-mode changes alone; always writes them
+    app.format_mode = FormatMode::Edit;
+    app.is_modified = true;
+    app.autosave();
+    assert!(app.is_modified);
+    app.autosave = AutosavePolicy::Always;
+    app.autosave();
+    assert!(!app.is_modified);
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_autosave_interval() {
+    use std::time::{Duration, Instant};
+
+    let path = std::env::temp_dir().join(format!("revw_autosave_interval_{}.json", std::process::id()));
+    let json = r#"{"outside": [{"name": "a"}], "inside": []}"#;
+    std::fs::write(&path, json).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = json.to_string();
+    app.file_path = Some(path.clone());
+    app.convert_json();
+    app.autosave = revw::config::AutosavePolicy::OnAction;
+    app.autosave_interval = Duration::from_secs(60);
+    let count = |path: &std::path::Path| std::fs::read_to_string(path).unwrap().matches("\"a\"").count();
+
+    // The first change is written right away, the next ones wait for the interval
+    app.duplicate_selected_entry();
+    assert!(!app.is_modified);
+    assert_eq!(count(&path), 2);
+    app.duplicate_selected_entry();
+    app.duplicate_selected_entry();
+    assert!(app.is_modified && app.autosave_pending);
+    assert_eq!(count(&path), 2);
+    app.flush_autosave(false);
+    assert_eq!(count(&path), 2);
+
+    // Once it has passed, the event loop writes them in one go
+    app.last_save_time = Instant::now().checked_sub(Duration::from_secs(61));
+    app.flush_autosave(false);
+    assert!(!app.is_modified && !app.autosave_pending);
+    assert_eq!(count(&path), 4);
+
+    // Quitting or opening another file does not wait
+    app.duplicate_selected_entry();
+    assert!(app.autosave_pending);
+    app.flush_autosave(true);
+    assert!(!app.is_modified);
+    assert_eq!(count(&path), 5);
+
+    // A save just written is taken for the watcher event it causes
+    assert!(app.just_saved());
+    app.last_save_time = Instant::now().checked_sub(Duration::from_secs(2));
+    assert!(!app.just_saved());
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_smart_paste_prompts_for_existing_urls() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [{"name": "A", "context": "old", "url": "https://a", "percentage": 10}, {"name": "C", "context": "", "url": "https://c", "percentage": null}], "inside": []}"#.to_string();
+    app.convert_json();
+
+    // No URL overlap: left to the plain append path
+    assert!(!app.merge_outside_from_text(r#"{"outside": [{"name": "D", "url": "https://d"}]}"#));
+    assert!(!app.is_modified);
+
+    let pasted = r#"{"outside": [
+        {"name": "A", "context": "new", "url": "https://a", "percentage": 60},
+        {"name": "B", "context": "", "url": "https://b", "percentage": null},
+        {"name": "C", "context": "again", "url": "https://c", "percentage": 5}
+    ]}"#;
+    assert!(app.merge_outside_from_text(pasted));
+    // The new URL is appended right away; the two known ones wait for an answer
+    assert_eq!(app.relf_entries.len(), 3);
+    assert_eq!(app.paste_merge_pending.len(), 2);
+    assert!(app.status_message.starts_with("URL exists: A (https://a)"));
+
+    app.handle_paste_merge_confirmation('y');
+    app.handle_paste_merge_confirmation('n');
+    assert!(app.paste_merge_pending.is_empty());
+    assert_eq!(app.status_message, "Smart paste finished");
+
+    let json: serde_json::Value = serde_json::from_str(&app.json_input).unwrap();
+    let outside = json["outside"].as_array().unwrap();
+    let names: Vec<&str> = outside.iter().map(|e| e["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["A", "C", "B", "C"]);
+    assert_eq!(outside[0]["context"], "new");
+    assert_eq!(outside[0]["percentage"], 60);
+    assert_eq!(outside[1]["context"], "");
+}
+
+#[test]
+fn test_live_filter_narrows_cards_as_you_type() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [
+        {"name": "Rust book", "context": "", "url": "", "percentage": null},
+        {"name": "Ruby guide", "context": "", "url": "", "percentage": null},
+        {"name": "Go tour", "context": "", "url": "", "percentage": null}
+    ], "inside": []}"#
+        .to_string();
+    app.convert_json();
+
+    app.start_live_filter();
+    assert!(app.input_mode == InputMode::Filter);
+    app.update_live_filter("ru".to_string());
+    assert_eq!(app.relf_entries.len(), 2);
+    assert_eq!(app.status_message, "filter: ru (2 entries)");
+    app.update_live_filter("rus".to_string());
+    assert_eq!(app.relf_entries.len(), 1);
+
+    // Enter keeps the filter active
+    app.accept_live_filter();
+    assert!(app.input_mode == InputMode::Normal);
+    assert_eq!(app.filter_pattern, "rus");
+    assert_eq!(app.relf_entries.len(), 1);
+
+    // Reopening starts from the active filter; Esc clears it
+    app.start_live_filter();
+    assert_eq!(app.status_message, "filter: rus (1 entries)");
+    app.cancel_live_filter();
+    assert!(app.input_mode == InputMode::Normal);
+    assert!(app.filter_pattern.is_empty());
+    assert_eq!(app.relf_entries.len(), 3);
+}
+
+#[test]
+fn test_filter_command_applies_pattern_immediately() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [
+        {"name": "Rust book", "context": "", "url": "", "percentage": null},
+        {"name": "Go tour", "context": "", "url": "", "percentage": null}
+    ], "inside": []}"#
+        .to_string();
+    app.convert_json();
+
+    app.apply_filter("go".to_string());
+    assert_eq!(app.relf_entries.len(), 1);
+    assert_eq!(app.status_message, "Filter: go (1 entries)");
+}
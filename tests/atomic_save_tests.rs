@@ -0,0 +1,129 @@
+use revw::app::{App, FormatMode};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("revw_atomic_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    fs::write(&path, serde_json::to_string_pretty(&json!({"outside": [{"name": "a"}], "inside": []})).unwrap()).unwrap();
+    path
+}
+
+fn save_renamed(path: &Path, atomic: bool) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.to_path_buf());
+    app.atomic_save = atomic;
+    app.json_input = app.json_input.replace("\"a\"", "\"b\"");
+    app.is_modified = true;
+    app.save_file();
+    app
+}
+
+fn first_name(path: &Path) -> String {
+    let value: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    value["outside"][0]["name"].as_str().unwrap().to_string()
+}
+
+fn dir_entries(path: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn atomic_save_is_the_default_and_leaves_no_temp_file() {
+    assert!(App::new(FormatMode::View).atomic_save);
+    let path = temp_file("default");
+    let mut app = save_renamed(&path, true);
+    assert!(!app.is_modified);
+    assert_eq!(first_name(&path), "b");
+    assert_eq!(dir_entries(&path), ["notes.json"]);
+
+    // The recorded disk state matches the replaced file: saving again is not a conflict
+    app.json_input = app.json_input.replace("\"b\"", "\"c\"");
+    app.save_file();
+    assert!(!app.save_conflict);
+    assert_eq!(first_name(&path), "c");
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn atomic_save_keeps_permissions_and_symlinks() {
+    use std::os::unix::fs::{symlink, PermissionsExt};
+
+    let path = temp_file("unix");
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+    let link = path.with_file_name("link.json");
+    symlink(&path, &link).unwrap();
+
+    save_renamed(&link, true);
+    assert!(fs::symlink_metadata(&link).unwrap().file_type().is_symlink());
+    assert_eq!(first_name(&path), "b");
+    assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn noatomic_save_rewrites_in_place() {
+    use std::os::unix::fs::MetadataExt;
+
+    let path = temp_file("inplace");
+    let inode = fs::metadata(&path).unwrap().ino();
+    save_renamed(&path, false);
+    assert_eq!(first_name(&path), "b");
+    assert_eq!(fs::metadata(&path).unwrap().ino(), inode);
+
+    save_renamed(&path, true);
+    assert_ne!(fs::metadata(&path).unwrap().ino(), inode);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn set_command_toggles_atomic_save() {
+    let mut app = App::new(FormatMode::View);
+    app.command_buffer = "set noatomic_save".to_string();
+    app.execute_command();
+    assert!(!app.atomic_save);
+    assert_eq!(app.status_message, "Atomic save disabled");
+    app.command_buffer = "set atomic_save".to_string();
+    app.execute_command();
+    assert!(app.atomic_save);
+}
+
+#[test]
+fn concurrent_atomic_saves_of_the_same_version_let_only_one_through() {
+    let path = temp_file("race");
+    for round in 0..20 {
+        fs::write(&path, serde_json::to_string_pretty(&json!({"outside": [{"name": "a"}], "inside": []})).unwrap()).unwrap();
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(4));
+        let writers: Vec<_> = (0..4)
+            .map(|i| {
+                let path = path.clone();
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    let mut app = App::new(FormatMode::View);
+                    app.load_file(path);
+                    app.json_input = app.json_input.replace("\"a\"", &format!("\"writer {}\"", i));
+                    app.is_modified = true;
+                    barrier.wait();
+                    app.save_file();
+                    !app.save_conflict
+                })
+            })
+            .collect();
+        let saved = writers.into_iter().map(|writer| writer.join().unwrap()).filter(|&saved| saved).count();
+        // Every writer loaded the same version: the first save wins, the rest are conflicts
+        assert_eq!(saved, 1, "round {}", round);
+    }
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
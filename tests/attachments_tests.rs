@@ -0,0 +1,68 @@
+mod common;
+
+use common::{run, view_app};
+use revw::app::FileMode;
+use revw::json_ops::JsonOperations;
+use serde_json::Value;
+use std::fs;
+
+#[test]
+fn attachments_roundtrip_through_markdown() {
+    let json = r#"{
+  "outside": [
+    {
+      "name": "Paper",
+      "context": "notes",
+      "url": "",
+      "percentage": null,
+      "attachments": ["paper.pdf", "~/scans/fig 1.png"]
+    }
+  ],
+  "inside": [
+    {
+      "date": "2025-01-01 00:00:00",
+      "context": "memo",
+      "attachments": ["memo.txt"]
+    }
+  ]
+}"#;
+    let app = view_app(json, FileMode::Markdown);
+    let markdown = app.convert_to_markdown().unwrap();
+    assert!(markdown.contains("**Attachment:** paper.pdf"), "{}", markdown);
+    assert!(markdown.contains("**Attachment:** memo.txt"), "{}", markdown);
+
+    let back: Value = serde_json::from_str(&app.parse_markdown(&markdown).unwrap()).unwrap();
+    let original: Value = serde_json::from_str(json).unwrap();
+    assert_eq!(back, original);
+    assert_eq!(JsonOperations::validate_strict(json), Ok(()));
+}
+
+#[test]
+fn attach_records_path_and_flags_missing_files() {
+    let dir = std::env::temp_dir().join(format!("revw_attach_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("notes.json");
+    fs::write(dir.join("present.txt"), "x").unwrap();
+
+    let json = r#"{"outside": [{"name": "A", "context": "", "url": "", "percentage": null}], "inside": []}"#;
+    fs::write(&file, json).unwrap();
+    let mut app = view_app(json, FileMode::Json);
+    app.file_path = Some(file.clone());
+
+    run(&mut app, "attach present.txt");
+    assert_eq!(app.status_message, "Attached: present.txt");
+    run(&mut app, "attach gone.pdf");
+    assert_eq!(app.status_message, "Attached (file not found): gone.pdf");
+    run(&mut app, "attach gone.pdf");
+    assert_eq!(app.status_message, "Already attached: gone.pdf");
+
+    assert_eq!(app.relf_entries[0].attachments, vec!["present.txt", "gone.pdf"]);
+    assert_eq!(app.missing_attachments(&app.relf_entries[0].attachments), vec!["gone.pdf"]);
+
+    // Saved to disk alongside the entry, the later changes once autosave's interval is over
+    app.flush_autosave(true);
+    let saved: Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(saved["outside"][0]["attachments"], serde_json::json!(["present.txt", "gone.pdf"]));
+
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::task::{Apply, BackgroundTask};
+use std::{
+    fs,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Poll like the event loop does until the task is gone
+fn wait_for_task(app: &mut App) {
+    let started = Instant::now();
+    while app.background_task.is_some() {
+        assert!(started.elapsed() < Duration::from_secs(10), "task did not finish");
+        app.poll_background_task();
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn progress_shows_in_the_status_text() {
+    let (gate, wait) = mpsc::channel::<()>();
+    let mut task = BackgroundTask::spawn("Working", move |progress| {
+        progress.update(5, 10);
+        wait.recv().ok();
+        progress.stage("Finishing");
+        let apply: Apply = Box::new(|app: &mut App| app.set_status("done"));
+        Ok(apply)
+    });
+
+    let started = Instant::now();
+    while task.done != 5 {
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(task.poll().is_none());
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(task.status_text(), "Working [##########..........] 50% (Esc to cancel)");
+
+    gate.send(()).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.background_task = Some(task);
+    wait_for_task(&mut app);
+    assert_eq!(app.status_message, "done");
+}
+
+#[test]
+fn unknown_total_shows_a_spinner() {
+    let (gate, wait) = mpsc::channel::<()>();
+    let task = BackgroundTask::spawn("Scanning", move |_| {
+        wait.recv().ok();
+        Err("stopped".to_string())
+    });
+    let text = task.status_text();
+    assert!(text.starts_with("Scanning "), "{}", text);
+    assert!(text.ends_with(" (Esc to cancel)"), "{}", text);
+    drop(gate);
+}
+
+#[test]
+fn cancel_drops_the_task_and_stops_the_worker() {
+    let (stopped_tx, stopped_rx) = mpsc::channel();
+    let mut app = App::new(FormatMode::View);
+    app.background_task = Some(BackgroundTask::spawn("Importing", move |progress| {
+        while !progress.is_cancelled() {
+            thread::sleep(Duration::from_millis(5));
+        }
+        stopped_tx.send(()).unwrap();
+        Err("Cancelled".to_string())
+    }));
+
+    app.cancel_background_task();
+    assert!(app.background_task.is_none());
+    assert_eq!(app.status_message, "Cancelled: Importing");
+    assert!(stopped_rx.recv_timeout(Duration::from_secs(10)).is_ok());
+}
+
+#[test]
+fn files_load_in_the_background() {
+    let dir = temp_dir("load");
+    let path = dir.join("notes.md");
+    fs::write(&path, "## OUTSIDE\n\n### Rust\nbook\n\n## INSIDE\n").unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.load_file_in_background(path.clone());
+    assert!(app.background_task.as_ref().unwrap().status_text().contains("notes.md"));
+    assert!(app.file_path.is_none());
+
+    wait_for_task(&mut app);
+    assert_eq!(app.file_path, Some(path.clone()));
+    assert_eq!(app.relf_entries[0].name.as_deref(), Some("Rust"));
+    assert!(app.markdown_input.contains("### Rust"));
+}
+
+#[test]
+fn background_load_reports_errors() {
+    let dir = temp_dir("errors");
+    let mut app = App::new(FormatMode::View);
+    app.load_file_in_background(dir.join("missing.json"));
+    wait_for_task(&mut app);
+    assert!(app.status_message.starts_with("Error reading "), "{}", app.status_message);
+    assert!(app.file_path.is_none());
+}
+
+#[test]
+fn small_files_open_right_away() {
+    let dir = temp_dir("small");
+    let path = dir.join("notes.json");
+    fs::write(&path, r#"{"outside": [{"name": "A"}], "inside": []}"#).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.open_file(path.clone());
+    assert!(app.background_task.is_none());
+    assert_eq!(app.file_path, Some(path));
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::tmp_path;
+use revw::app::{App, FormatMode};
+use revw::bench::{format_timings, synthetic_json, synthetic_markdown, time_load};
+use revw::config::RcConfig;
+use std::{fs, process::Command, time::Duration};
+
+fn app() -> App {
+    App::with_config(FormatMode::View, RcConfig::default())
+}
+
+#[test]
+fn synthetic_documents_hold_the_requested_entries() {
+    let json = tmp_path("bench_json", "json");
+    fs::write(&json, synthetic_json(101)).unwrap();
+    let mut from_json = app();
+    time_load(&mut from_json, &json, None).unwrap();
+    assert_eq!(from_json.relf_entries.len(), 101);
+
+    let markdown = tmp_path("bench_md", "md");
+    fs::write(&markdown, synthetic_markdown(101)).unwrap();
+    let mut from_markdown = app();
+    time_load(&mut from_markdown, &markdown, None).unwrap();
+    assert_eq!(from_markdown.relf_entries.len(), 101);
+
+    let _ = fs::remove_file(json);
+    let _ = fs::remove_file(markdown);
+}
+
+#[test]
+fn time_load_reports_each_stage() {
+    let path = tmp_path("bench_stages", "json");
+    fs::write(&path, synthetic_json(20)).unwrap();
+
+    let mut plain = app();
+    let stages: Vec<&str> = time_load(&mut plain, &path, None).unwrap().into_iter().map(|(stage, _)| stage).collect();
+    assert_eq!(stages, ["read", "parse", "render", "draw"]);
+
+    let mut filtered = app();
+    let stages: Vec<&str> = time_load(&mut filtered, &path, Some("Resource 3")).unwrap().into_iter().map(|(stage, _)| stage).collect();
+    assert_eq!(stages, ["read", "parse", "render", "filter", "draw"]);
+    assert!(filtered.relf_entries.len() < 20);
+
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn time_load_fails_on_invalid_input() {
+    let path = tmp_path("bench_invalid", "json");
+    fs::write(&path, "{ not json").unwrap();
+    assert!(time_load(&mut app(), &path, None).is_err());
+    assert!(time_load(&mut app(), &tmp_path("bench_missing", "json"), None).is_err());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn format_timings_adds_a_total() {
+    let text = format_timings(&[("read", Duration::from_micros(1500)), ("parse", Duration::from_micros(500))]);
+    assert_eq!(text, "read         1.500 ms\nparse        0.500 ms\ntotal        2.000 ms\n");
+}
+
+#[test]
+fn bench_load_flag_prints_timings() {
+    let path = tmp_path("bench_cli", "json");
+    fs::write(&path, synthetic_json(10)).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .arg("--bench-load")
+        .arg(&path)
+        .output()
+        .expect("failed to execute revw");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for stage in ["read", "parse", "render", "draw", "total"] {
+        assert!(stdout.lines().any(|line| line.starts_with(stage) && line.ends_with(" ms")), "{}", stdout);
+    }
+    assert!(stdout.contains("entries 10"));
+
+    let help = Command::new(env!("CARGO_BIN_EXE_revw")).arg("--help").output().unwrap();
+    assert!(!String::from_utf8_lossy(&help.stdout).contains("bench-load"));
+    let _ = fs::remove_file(path);
+}
@@ -0,0 +1,137 @@
+use revw::import_ops::{self, Bookmark, BookmarkFormat, BookmarkMapping};
+use serde_json::json;
+
+const HTML: &str = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><H3 ADD_DATE="1" PERSONAL_TOOLBAR_FOLDER="true">Bookmarks bar</H3>
+    <DL><p>
+        <DT><A HREF="https://www.rust-lang.org/" ADD_DATE="1">Rust &amp; Cargo</A>
+        <DT><H3 ADD_DATE="1">Reading</H3>
+        <DL><p>
+            <DT><A HREF="https://example.com/a?x=1&amp;y=2">Article</A>
+            <DT><H3>Papers</H3>
+            <DL><p>
+                <DT><A HREF="https://example.com/paper.pdf">Paper</A>
+            </DL><p>
+        </DL><p>
+        <DT><A HREF="javascript:alert(1)">Bookmarklet</A>
+    </DL><p>
+    <DT><A HREF="https://top.example/">Top level</A>
+</DL><p>
+"#;
+
+const CHROME: &str = r#"{
+  "checksum": "0",
+  "roots": {
+    "bookmark_bar": {
+      "children": [
+        {"id": "4", "name": "Docs", "type": "url", "url": "https://docs.rs/"},
+        {"id": "5", "name": "Dev", "type": "folder", "children": [
+          {"id": "6", "name": "Crates", "type": "url", "url": "https://crates.io/"}
+        ]}
+      ],
+      "id": "1", "name": "Bookmarks bar", "type": "folder"
+    },
+    "other": {"children": [], "id": "2", "name": "Other bookmarks", "type": "folder"}
+  },
+  "version": 1
+}"#;
+
+const FIREFOX: &str = r#"{
+  "guid": "root________", "title": "", "root": "placesRoot", "type": "text/x-moz-place-container",
+  "children": [
+    {"guid": "menu________", "title": "menu", "root": "bookmarksMenuFolder", "type": "text/x-moz-place-container",
+     "children": [
+       {"title": "Mozilla", "type": "text/x-moz-place", "uri": "https://www.mozilla.org/"},
+       {"type": "text/x-moz-place-separator"},
+       {"title": "Recent", "type": "text/x-moz-place", "uri": "place:sort=8"},
+       {"title": "Work", "type": "text/x-moz-place-container", "children": [
+         {"title": "Tracker", "type": "text/x-moz-place", "uri": "https://tracker.example/"}
+       ]}
+     ]}
+  ]
+}"#;
+
+fn bookmark(title: &str, url: &str, folders: &[&str]) -> Bookmark {
+    Bookmark {
+        title: title.to_string(),
+        url: url.to_string(),
+        folders: folders.iter().map(|f| f.to_string()).collect(),
+    }
+}
+
+#[test]
+fn detects_bookmark_exports_only() {
+    assert_eq!(import_ops::detect(HTML), Some(BookmarkFormat::Html));
+    assert_eq!(import_ops::detect(CHROME), Some(BookmarkFormat::ChromeJson));
+    assert_eq!(import_ops::detect(FIREFOX), Some(BookmarkFormat::FirefoxJson));
+    assert_eq!(import_ops::detect(r#"{"outside": [], "inside": []}"#), None);
+    assert_eq!(import_ops::detect("## OUTSIDE\n### Book\n"), None);
+}
+
+#[test]
+fn html_export_keeps_folders_below_browser_roots() {
+    let bookmarks = import_ops::parse(HTML, BookmarkFormat::Html).unwrap();
+    assert_eq!(
+        bookmarks,
+        vec![
+            bookmark("Rust & Cargo", "https://www.rust-lang.org/", &[]),
+            bookmark("Article", "https://example.com/a?x=1&y=2", &["Reading"]),
+            bookmark("Paper", "https://example.com/paper.pdf", &["Reading", "Papers"]),
+            bookmark("Top level", "https://top.example/", &[]),
+        ]
+    );
+}
+
+#[test]
+fn json_exports_skip_roots_and_non_web_urls() {
+    let chrome = import_ops::parse(CHROME, BookmarkFormat::ChromeJson).unwrap();
+    assert_eq!(
+        chrome,
+        vec![bookmark("Docs", "https://docs.rs/", &[]), bookmark("Crates", "https://crates.io/", &["Dev"])]
+    );
+
+    let firefox = import_ops::parse(FIREFOX, BookmarkFormat::FirefoxJson).unwrap();
+    assert_eq!(
+        firefox,
+        vec![
+            bookmark("Mozilla", "https://www.mozilla.org/", &[]),
+            bookmark("Tracker", "https://tracker.example/", &["Work"]),
+        ]
+    );
+}
+
+#[test]
+fn mapping_routes_title_url_and_folder() {
+    let bookmarks = vec![bookmark("Paper", "https://example.com/paper.pdf", &["Reading", "Papers"])];
+
+    let entries = import_ops::bookmarks_to_entries(&bookmarks, &BookmarkMapping::default());
+    assert_eq!(
+        entries,
+        json!({"outside": [{"name": "Paper", "context": "", "url": "https://example.com/paper.pdf", "percentage": null}]})
+    );
+
+    let mapping = BookmarkMapping::from_specs(["folder=tag", "title=context"]).unwrap();
+    let entries = import_ops::bookmarks_to_entries(&bookmarks, &mapping);
+    assert_eq!(
+        entries["outside"][0],
+        json!({"name": "", "context": "Paper", "url": "https://example.com/paper.pdf", "percentage": null, "tags": ["Reading/Papers"]})
+    );
+
+    let mapping = BookmarkMapping::from_specs(["folder=collection"]).unwrap();
+    let entries = import_ops::bookmarks_to_entries(&bookmarks, &mapping);
+    assert_eq!(entries["outside"][0]["collection"], json!("Reading/Papers"));
+
+    assert_eq!(
+        BookmarkMapping::from_specs(["folder=percentage"]),
+        Err("Cannot map folder to 'percentage'".to_string())
+    );
+    assert_eq!(
+        BookmarkMapping::from_specs(["date=tag"]),
+        Err("Unknown mapping source 'date': use title, url or folder".to_string())
+    );
+    assert!(BookmarkMapping::from_specs(["folder"]).is_err());
+}
@@ -0,0 +1,113 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::navigation::Navigator;
+
+const JSON: &str = r#"{
+  "outside": [
+    {
+      "name": "A",
+      "context": "has a { brace",
+      "url": "",
+      "percentage": null
+    },
+    {
+      "name": "B",
+      "context": "",
+      "url": "",
+      "percentage": null
+    },
+    {
+      "name": "C",
+      "context": "",
+      "url": "",
+      "percentage": null
+    }
+  ],
+  "inside": [
+    {
+      "date": "2025-01-01 00:00:00",
+      "context": "x"
+    },
+    {
+      "date": "2025-01-02 00:00:00",
+      "context": "y"
+    }
+  ]
+}"#;
+
+const MD: &str = "## OUTSIDE\n\n### A\n```\n### not a heading\n```\n\n### B\n\n## INSIDE\n\n### 2025-01-01 00:00:00\nx\n";
+
+fn app(file_mode: FileMode, format_mode: FormatMode) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = file_mode;
+    app.json_input = JSON.to_string();
+    if file_mode == FileMode::Markdown {
+        app.markdown_input = MD.to_string();
+        app.json_input = app.parse_markdown(MD).unwrap();
+    }
+    app.format_mode = format_mode;
+    app.convert_json();
+    app
+}
+
+#[test]
+fn card_section_helpers() {
+    let sections = [true, true, true, false, false];
+    assert_eq!(Navigator::card_section_bounds(&sections, 1), (0, 2));
+    assert_eq!(Navigator::card_section_bounds(&sections, 4), (3, 4));
+    assert_eq!(Navigator::next_card_section(&sections, 0), 3);
+    assert_eq!(Navigator::next_card_section(&sections, 3), 4);
+    assert_eq!(Navigator::prev_card_section(&sections, 4), 3);
+    assert_eq!(Navigator::prev_card_section(&sections, 3), 0);
+    assert_eq!(Navigator::prev_card_section(&sections, 0), 0);
+}
+
+#[test]
+fn view_mode_braces_move_between_sections() {
+    let mut app = app(FileMode::Json, FormatMode::View);
+    app.selected_entry_index = 1;
+
+    app.bracket_motion("}");
+    assert_eq!(app.selected_entry_index, 3); // first INSIDE card
+    app.bracket_motion("]]");
+    assert_eq!(app.selected_entry_index, 4); // last INSIDE card
+    app.bracket_motion("[[");
+    assert_eq!(app.selected_entry_index, 3);
+    app.bracket_motion("{");
+    assert_eq!(app.selected_entry_index, 0); // first OUTSIDE card
+    app.bracket_motion("]]");
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn edit_mode_json_moves_between_objects_and_sections() {
+    let mut app = app(FileMode::Json, FormatMode::Edit);
+    let lines = app.get_content_lines();
+    let line_of = |needle: &str| lines.iter().position(|l| l.contains(needle)).unwrap();
+
+    app.content_cursor_line = 0;
+    app.bracket_motion("}");
+    assert_eq!(app.content_cursor_line, line_of("\"A\"") - 1);
+    app.bracket_motion("}");
+    assert_eq!(app.content_cursor_line, line_of("\"B\"") - 1);
+    app.bracket_motion("]]");
+    assert_eq!(app.content_cursor_line, line_of("\"inside\""));
+    app.bracket_motion("{");
+    assert_eq!(app.content_cursor_line, line_of("\"C\"") - 1);
+    app.bracket_motion("[[");
+    assert_eq!(app.content_cursor_line, line_of("\"outside\""));
+}
+
+#[test]
+fn edit_mode_markdown_moves_between_headings() {
+    let mut app = app(FileMode::Markdown, FormatMode::Edit);
+    app.content_cursor_line = 0;
+
+    app.bracket_motion("}");
+    assert_eq!(app.content_cursor_line, 2); // ### A
+    app.bracket_motion("}");
+    assert_eq!(app.content_cursor_line, 7); // ### B (skips the code block)
+    app.bracket_motion("]]");
+    assert_eq!(app.content_cursor_line, 9); // ## INSIDE
+    app.bracket_motion("[[");
+    assert_eq!(app.content_cursor_line, 0); // ## OUTSIDE
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::{run, value};
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "", "percentage": 10},
+    {"name": "B", "context": "", "url": "", "percentage": 20, "tags": ["research"]},
+    {"name": "C", "context": "", "url": "", "percentage": 30}
+  ],
+  "inside": [
+    {"date": "2024-01-01", "context": "day"}
+  ]
+}"#;
+
+fn visual_app(start: usize, end: usize) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.visual_mode = true;
+    app.visual_start_index = start;
+    app.visual_end_index = end;
+    app.selected_entry_index = end;
+    app
+}
+
+#[test]
+fn set_pct_applies_to_selection_in_one_undo_step() {
+    let mut app = visual_app(0, 1);
+    let undo_depth = app.undo_stack.len();
+
+    run(&mut app, "set pct 100");
+    assert_eq!(app.status_message, "Set 100%: 2 of 2 cards");
+    let doc = value(&app);
+    assert_eq!(doc["outside"][0]["percentage"], json!(100));
+    assert_eq!(doc["outside"][1]["percentage"], json!(100));
+    assert_eq!(doc["outside"][2]["percentage"], json!(30));
+    assert_eq!(app.undo_stack.len(), undo_depth + 1);
+
+    // Selection survives so another bulk command can follow
+    assert!(app.visual_mode);
+    assert_eq!(app.visual_selected_indices(), vec![0, 1]);
+
+    app.undo();
+    let doc = value(&app);
+    assert_eq!(doc["outside"][0]["percentage"], json!(10));
+    assert_eq!(doc["outside"][1]["percentage"], json!(20));
+
+    run(&mut app, "set pct 140");
+    assert_eq!(app.status_message, "Percentage must be a number from 0 to 100");
+}
+
+#[test]
+fn priority_skips_inside_entries_and_can_be_cleared() {
+    let mut app = visual_app(2, 3);
+    run(&mut app, "priority high");
+    assert_eq!(app.status_message, "Priority high: 1 of 2 cards");
+    let doc = value(&app);
+    assert_eq!(doc["outside"][2]["priority"], json!("high"));
+    assert!(doc["inside"][0].get("priority").is_none());
+    assert_eq!(app.relf_entries[2].priority, Some(5));
+
+    run(&mut app, "priority 2");
+    assert_eq!(value(&app)["outside"][2]["priority"], json!(2));
+
+    run(&mut app, "priority none");
+    assert!(value(&app)["outside"][2].get("priority").is_none());
+
+    run(&mut app, "priority urgent");
+    assert!(app.status_message.starts_with("Priority must be"));
+}
+
+#[test]
+fn tag_add_and_remove_edit_the_tags_list() {
+    let mut app = visual_app(0, 3);
+    run(&mut app, "tag add research");
+    assert_eq!(app.status_message, "Tag 'research' added: 3 of 4 cards");
+    let doc = value(&app);
+    assert_eq!(doc["outside"][0]["tags"], json!(["research"]));
+    assert_eq!(doc["outside"][1]["tags"], json!(["research"]));
+    assert_eq!(doc["inside"][0]["tags"], json!(["research"]));
+
+    run(&mut app, "tag remove research");
+    let doc = value(&app);
+    assert!(doc["outside"][1].get("tags").is_none());
+    assert!(doc["inside"][0].get("tags").is_none());
+
+    run(&mut app, "tag remove research");
+    assert_eq!(app.status_message, "Tag 'research' removed: nothing to change in 4 cards");
+}
+
+#[test]
+fn without_visual_mode_the_selected_card_is_edited() {
+    let mut app = visual_app(0, 0);
+    app.visual_mode = false;
+    app.selected_entry_index = 1;
+    run(&mut app, "set pct 55");
+    let doc = value(&app);
+    assert_eq!(doc["outside"][0]["percentage"], json!(10));
+    assert_eq!(doc["outside"][1]["percentage"], json!(55));
+}
+
+#[test]
+fn modify_entries_counts_changes() {
+    let (formatted, changed) = JsonOperations::modify_entries(JSON, &[0, 3, 9], |entry, section| {
+        entry.insert("seen".to_string(), json!(section));
+        true
+    })
+    .unwrap();
+    assert_eq!(changed, 2);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"][0]["seen"], json!("outside"));
+    assert_eq!(doc["inside"][0]["seen"], json!("inside"));
+}
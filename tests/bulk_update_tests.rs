@@ -0,0 +1,164 @@
+mod common;
+
+use common::{run, value, view_app};
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "https://old.com/a?x=1", "tags": ["rust", "todo"]},
+    {"name": "B", "context": "", "url": "https://docs.old.com:8080/b", "tags": ["todo", "later"]},
+    {"name": "C", "context": "", "url": "https://bold.com/c", "tags": ["later"]},
+    {"name": "D", "context": "", "url": "old.com"}
+  ],
+  "inside": [
+    {"date": "2024-01-01", "context": "day", "tags": ["todo"]}
+  ]
+}"#;
+
+#[test]
+fn transform_field_counts_changed_entries() {
+    let (formatted, changed) = JsonOperations::transform_field(JSON, "name", |name| {
+        (name == "B").then(|| json!("Bee"))
+    })
+    .unwrap();
+    assert_eq!(changed, 1);
+    let value: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(value["outside"][1]["name"], "Bee");
+    assert_eq!(value["outside"][0]["name"], "A");
+
+    // Returning the same value is not a change
+    let (_, changed) = JsonOperations::transform_field(JSON, "name", |name| Some(name.clone())).unwrap();
+    assert_eq!(changed, 0);
+}
+
+#[test]
+fn rename_tag_asks_before_changing_every_entry() {
+    let mut app = view_app(JSON, FileMode::Json);
+    let undo_depth = app.undo_stack.len();
+
+    run(&mut app, "rename-tag todo next");
+    assert_eq!(app.status_message, "Rename tag 'todo' to 'next': 3 entries will change. Apply? (y/n)");
+    assert_eq!(app.json_input, JSON);
+
+    app.handle_bulk_update_confirmation('y');
+    assert_eq!(app.status_message, "Rename tag 'todo' to 'next': 3 entries changed");
+    let value = value(&app);
+    assert_eq!(value["outside"][0]["tags"], json!(["rust", "next"]));
+    assert_eq!(value["outside"][1]["tags"], json!(["next", "later"]));
+    assert_eq!(value["inside"][0]["tags"], json!(["next"]));
+    assert!(app.is_modified);
+    assert_eq!(app.undo_stack.len(), undo_depth + 1);
+}
+
+#[test]
+fn rename_tag_to_an_existing_tag_keeps_one_copy() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "rename-tag todo later");
+    app.handle_bulk_update_confirmation('y');
+    assert_eq!(value(&app)["outside"][1]["tags"], json!(["later"]));
+}
+
+#[test]
+fn declined_or_empty_bulk_update_leaves_the_file_alone() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "rename-tag todo next");
+    app.handle_bulk_update_confirmation('n');
+    assert_eq!(app.status_message, "Bulk update cancelled");
+    assert_eq!(app.json_input, JSON);
+    assert!(!app.is_modified);
+
+    run(&mut app, "rename-tag missing next");
+    assert_eq!(app.status_message, "Rename tag 'missing' to 'next': no entries would change");
+    assert!(app.bulk_update_pending.is_none());
+
+    run(&mut app, "rename-tag only");
+    assert_eq!(app.status_message, "Usage: :rename-tag OLD NEW");
+}
+
+#[test]
+fn set_url_domain_rewrites_host_and_subdomains() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "set-url-domain old.com new.org");
+    assert_eq!(app.status_message, "Change URL domain old.com to new.org: 3 entries will change. Apply? (y/n)");
+
+    app.handle_bulk_update_confirmation('y');
+    let value = value(&app);
+    assert_eq!(value["outside"][0]["url"], "https://new.org/a?x=1");
+    assert_eq!(value["outside"][1]["url"], "https://docs.new.org:8080/b");
+    // Only whole domain labels match
+    assert_eq!(value["outside"][2]["url"], "https://bold.com/c");
+    assert_eq!(value["outside"][3]["url"], "new.org");
+}
+
+#[test]
+fn set_url_domain_handles_non_ascii_hosts_and_userinfo() {
+    let json = r#"{"outside": [
+        {"name": "A", "url": "https://éa.com/"},
+        {"name": "B", "url": "https://ü.a.com/x"},
+        {"name": "C", "url": "https://user:pw@a.com:8080/"}
+    ], "inside": []}"#;
+    let mut app = view_app(json, FileMode::Json);
+    run(&mut app, "set-url-domain a.com b.com");
+    assert_eq!(app.status_message, "Change URL domain a.com to b.com: 2 entries will change. Apply? (y/n)");
+
+    app.handle_bulk_update_confirmation('y');
+    let value = value(&app);
+    assert_eq!(value["outside"][0]["url"], "https://éa.com/");
+    assert_eq!(value["outside"][1]["url"], "https://ü.b.com/x");
+    assert_eq!(value["outside"][2]["url"], "https://user:pw@b.com:8080/");
+}
+
+#[test]
+fn name_from_context_takes_the_first_line_and_cuts_it() {
+    assert_eq!(JsonOperations::name_from_context("\n  Read the nom docs  \nmore"), Some("Read the nom docs".to_string()));
+    assert_eq!(JsonOperations::name_from_context(" \n "), None);
+    let long = "word ".repeat(20);
+    let name = JsonOperations::name_from_context(&long).unwrap();
+    assert!(name.ends_with("...") && name.chars().count() <= 60, "{}", name);
+}
+
+#[test]
+fn autoname_names_empty_entries_after_a_prompt() {
+    let json = r#"{"outside": [
+        {"name": "", "context": "Parser notes\nsecond line", "url": ""},
+        {"name": "Kept", "context": "other"},
+        {"context": "No name key"},
+        {"name": "  ", "context": ""}
+    ], "inside": [{"date": "2024-01-01", "context": "day"}]}"#;
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = json.to_string();
+    app.convert_json();
+
+    run(&mut app, "autoname");
+    assert_eq!(app.status_message, "Autoname: 2 entries ('Parser notes', 'No name key'). Apply? (y/n)");
+    app.handle_bulk_update_confirmation('y');
+    assert_eq!(app.status_message, "Autoname: named 2 entries");
+    let value = value(&app);
+    assert_eq!(value["outside"][0]["name"], "Parser notes");
+    assert_eq!(value["outside"][1]["name"], "Kept");
+    assert_eq!(value["outside"][2]["name"], "No name key");
+    assert_eq!(value["outside"][3]["name"], "  ");
+
+    run(&mut app, "autoname");
+    assert_eq!(app.status_message, "Autoname: no unnamed entries with a context");
+}
+
+#[test]
+fn autoname_on_save_names_entries_before_writing() {
+    let dir = std::env::temp_dir().join(format!("revw_autoname_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    std::fs::write(&path, r#"{"outside": [{"name": "", "context": "Imported note"}], "inside": []}"#).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+    app.autoname_on_save = true;
+    app.save_file();
+    let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved["outside"][0]["name"], "Imported note");
+    assert_eq!(app.relf_entries[0].name.as_deref(), Some("Imported note"));
+    std::fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use common::view_app;
+use revw::app::{App, FileMode};
+use revw::json_ops::JsonOperations;
+use serde_json::Value;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "", "percentage": null},
+    {"name": "B", "context": "", "url": "", "percentage": null},
+    {"name": "C", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2024-01-01", "context": "one"},
+    {"date": "2024-01-02", "context": "two"}
+  ]
+}"#;
+
+fn names(app: &App) -> Vec<String> {
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    let section = |key: &str, field: &str| -> Vec<String> {
+        value[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry[field].as_str().unwrap().to_string())
+            .collect()
+    };
+    let mut names = section("outside", "name");
+    names.extend(section("inside", "context"));
+    names
+}
+
+#[test]
+fn counted_delete_fills_the_register() {
+    let mut app = view_app(JSON, FileMode::Json);
+    let undo_depth = app.undo_stack.len();
+    app.selected_entry_index = 1;
+    app.delete_cards_forward(3);
+
+    // Deleting runs on past the section end, like lines in vim
+    assert_eq!(names(&app), vec!["A", "two"]);
+    assert_eq!(app.status_message, "3 cards deleted");
+    assert_eq!(app.card_register.len(), 3);
+    assert_eq!(app.card_register[2].0, "inside");
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.undo_stack.len(), undo_depth + 1);
+    assert!(app.is_modified);
+}
+
+#[test]
+fn count_is_clamped_to_the_last_card() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 4;
+    app.delete_cards_forward(10);
+    assert_eq!(names(&app), vec!["A", "B", "C", "one"]);
+    assert_eq!(app.status_message, "1 card deleted");
+    assert_eq!(app.selected_entry_index, 3);
+}
+
+#[test]
+fn put_after_and_before_the_selected_card() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.delete_cards_forward(1);
+    assert_eq!(names(&app), vec!["B", "C", "one", "two"]);
+
+    // p puts after the selection and selects the put card
+    app.put_cards(true, 1);
+    assert_eq!(names(&app), vec!["B", "A", "C", "one", "two"]);
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.status_message, "1 card put");
+
+    // The register stays filled; a count puts it that many times
+    app.put_cards(false, 2);
+    assert_eq!(names(&app), vec!["B", "A", "A", "A", "C", "one", "two"]);
+    assert_eq!(app.selected_entry_index, 1);
+}
+
+#[test]
+fn cards_go_back_to_their_own_section() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 2;
+    app.delete_cards_forward(2);
+    assert_eq!(names(&app), vec!["A", "B", "two"]);
+
+    // Selection on INSIDE: the OUTSIDE card goes to the end of OUTSIDE
+    app.selected_entry_index = 2;
+    app.put_cards(true, 1);
+    assert_eq!(names(&app), vec!["A", "B", "C", "two", "one"]);
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn put_with_empty_register_does_nothing() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.put_cards(true, 1);
+    assert_eq!(app.status_message, "Card register is empty");
+    assert_eq!(app.json_input, JSON);
+}
+
+#[test]
+fn command_dd_also_fills_the_register() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.command_buffer = "dd".to_string();
+    app.execute_command();
+    assert_eq!(names(&app), vec!["B", "C", "one", "two"]);
+    assert_eq!(app.card_register.len(), 1);
+}
+
+#[test]
+fn put_entries_into_an_empty_file() {
+    let entries = vec![("inside".to_string(), serde_json::json!({"date": "2024-01-01", "context": "x"}))];
+    let (formatted, first) = JsonOperations::put_entries("", None, true, &entries).unwrap();
+    let value: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(value["inside"][0]["context"], "x");
+    assert_eq!(first, 0);
+}
@@ -0,0 +1,105 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+fn large_app(count: usize) -> App {
+    let outside: Vec<_> = (0..count)
+        .map(|i| {
+            json!({
+                "name": format!("Entry {}", i),
+                "context": format!("- [x] read chapter {}\n- [ ] notes\n```rust\nlet n = {};\n```", i, i),
+                "url": format!("https://example.com/{}", i),
+                "percentage": i % 100
+            })
+        })
+        .collect();
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = serde_json::to_string_pretty(&json!({"outside": outside, "inside": []})).unwrap();
+    app.convert_json();
+    app
+}
+
+fn draw(terminal: &mut Terminal<TestBackend>, app: &mut App) {
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+}
+
+fn screen_text(terminal: &Terminal<TestBackend>) -> String {
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn only_visible_cards_are_materialized() {
+    let mut app = large_app(10_000);
+    app.max_visible_cards = 4;
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+
+    draw(&mut terminal, &mut app);
+    let mut cached: Vec<usize> = app.card_body_cache.keys().copied().collect();
+    cached.sort();
+    assert_eq!(cached, vec![0, 1, 2, 3]);
+
+    app.selected_entry_index = 9_000;
+    draw(&mut terminal, &mut app);
+    let mut cached: Vec<usize> = app.card_body_cache.keys().copied().collect();
+    cached.sort();
+    assert_eq!(cached, vec![8_997, 8_998, 8_999, 9_000]);
+    assert!(screen_text(&terminal).contains("Entry 9000"));
+}
+
+#[test]
+fn cached_card_bodies_follow_content_and_search_changes() {
+    let mut app = large_app(3);
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    draw(&mut terminal, &mut app);
+    assert!(screen_text(&terminal).contains("read chapter 0"));
+
+    app.json_input = app.json_input.replace("read chapter 0", "skimmed preface");
+    app.convert_json();
+    draw(&mut terminal, &mut app);
+    let text = screen_text(&terminal);
+    assert!(text.contains("skimmed preface"));
+    assert!(!text.contains("read chapter 0"));
+
+    let (key, _) = app.card_body_cache[&1].clone();
+    app.search_query = "notes".to_string();
+    draw(&mut terminal, &mut app);
+    assert_ne!(app.card_body_cache[&1].0, key);
+}
+
+#[test]
+fn outline_builds_only_visible_titles() {
+    let mut app = large_app(10_000);
+    assert_eq!(app.outline_len(), 10_000);
+    assert_eq!(app.outline_window(5_000..5_002), vec!["Entry 5000", "Entry 5001"]);
+    assert!(app.outline_window(10_000..10_010).is_empty());
+
+    app.toggle_outline();
+    app.outline_selected_index = 9_999;
+    let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    draw(&mut terminal, &mut app);
+    assert!(screen_text(&terminal).contains("Entry 9999"));
+}
+
+/// Frame times with 10k cards; run with
+/// `cargo test --release --test card_render_tests -- --ignored --nocapture`
+#[test]
+#[ignore]
+fn bench_frame_time_with_10k_entries() {
+    let mut app = large_app(10_000);
+    let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+    draw(&mut terminal, &mut app);
+
+    for (label, step) in [("static", 0), ("scrolling", 1)] {
+        let frames = 500;
+        let start = Instant::now();
+        for _ in 0..frames {
+            app.selected_entry_index = (app.selected_entry_index + step) % 10_000;
+            draw(&mut terminal, &mut app);
+        }
+        let per_frame = start.elapsed() / frames;
+        println!("{}: {:?} per frame", label, per_frame);
+        assert!(per_frame < Duration::from_millis(50), "{}: {:?} per frame", label, per_frame);
+    }
+}
@@ -231,6 +231,9 @@ fn test_card_full_height_display() {
     // visible_height (20) / max_visible_cards (5) - borders (2) = 2 lines per card
     let expected_visible = (app.visible_height as usize / app.max_visible_cards).saturating_sub(2);
 
+    // The renderer records the wrapped context row count each frame
+    app.card_context_rows = lines.len();
+
     // Calculate max scroll
     let max_scroll = lines.len().saturating_sub(expected_visible);
     let calculated_max = app.relf_max_hscroll() as usize;
@@ -293,3 +296,36 @@ fn test_card_scroll_with_newlines() {
     app.hscroll = 2;
     assert_eq!(app.hscroll, 2);
 }
+
+#[test]
+fn test_card_paging_moves_a_screen_of_cards() {
+    let inside: Vec<String> = (0..12)
+        .map(|i| format!(r#"{{"date": "2025-01-{:02} 00:00:00", "context": "entry {}"}}"#, i + 1, i))
+        .collect();
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = format!(r#"{{"outside": [], "inside": [{}]}}"#, inside.join(","));
+    app.convert_json();
+    app.max_visible_cards = 5;
+
+    assert_eq!(app.visible_card_range(), (0, 5));
+
+    // Each page flips the whole window
+    app.page_cards(true);
+    assert_eq!(app.selected_entry_index, 5);
+    assert_eq!(app.visible_card_range(), (5, 10));
+
+    // The last page stays full
+    app.page_cards(true);
+    assert_eq!(app.selected_entry_index, 10);
+    assert_eq!(app.visible_card_range(), (7, 12));
+    app.page_cards(true);
+    assert_eq!(app.selected_entry_index, 11);
+
+    app.page_cards(false);
+    assert_eq!(app.selected_entry_index, 6);
+    assert_eq!(app.visible_card_range(), (2, 7));
+    app.page_cards(false);
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.visible_card_range(), (0, 5));
+}
@@ -0,0 +1,97 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode, TextVisualMode};
+use revw::keymap::{self, KeyContext};
+
+fn sample_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [{"name": "Book", "context": "notes", "url": "", "percentage": 10}], "inside": []}"#.to_string();
+    app.convert_json();
+    app
+}
+
+fn screen_text(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn key_context_follows_mode() {
+    let mut app = sample_app();
+    assert_eq!(app.key_context(), KeyContext::View);
+
+    app.visual_mode = true;
+    assert_eq!(app.key_context(), KeyContext::Visual);
+    app.visual_mode = false;
+
+    app.start_editing_entry();
+    assert_eq!(app.key_context(), KeyContext::Overlay);
+    app.edit_field_editing_mode = true;
+    assert_eq!(app.key_context(), KeyContext::OverlayField);
+    app.cancel_editing_entry();
+
+    app.format_mode = FormatMode::Edit;
+    app.convert_json();
+    assert_eq!(app.key_context(), KeyContext::Edit);
+    app.start_text_visual(TextVisualMode::Line);
+    assert_eq!(app.key_context(), KeyContext::Selection);
+}
+
+#[test]
+fn every_context_has_bindings() {
+    for context in [
+        KeyContext::View,
+        KeyContext::Visual,
+        KeyContext::Edit,
+        KeyContext::Selection,
+        KeyContext::Overlay,
+        KeyContext::OverlayField,
+    ] {
+        let bindings = keymap::bindings(context);
+        assert!(!bindings.is_empty(), "{:?}", context);
+        assert!(bindings.iter().all(|b| !b.keys.is_empty() && !b.action.is_empty()));
+    }
+}
+
+#[test]
+fn g_question_mark_toggles_cheat_sheet_for_current_mode() {
+    let mut app = sample_app();
+    for c in ['g', '?'] {
+        app.press_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    }
+    assert!(app.cheatsheet_open);
+    assert!(!app.showing_help);
+
+    let text = screen_text(&mut app);
+    assert!(text.contains("Keys: View mode"));
+    for binding in keymap::bindings(KeyContext::View) {
+        assert!(text.contains(binding.action), "missing {:?}", binding.action);
+    }
+
+    app.toggle_cheatsheet();
+    app.format_mode = FormatMode::Edit;
+    app.convert_json();
+    app.toggle_cheatsheet();
+    let text = screen_text(&mut app);
+    assert!(text.contains("Keys: Edit mode"));
+    assert!(text.contains("select characters / lines"));
+}
+
+#[test]
+fn cheat_sheet_rows_come_from_the_key_tables() {
+    let rows = keymap::bindings(KeyContext::View);
+    let row = |action: &str| rows.iter().find(|b| b.action == action).map(|b| b.keys.clone());
+    assert_eq!(row("select next / previous card").as_deref(), Some("j / k"));
+    assert_eq!(row("focus mode (selected card full-screen)").as_deref(), Some("z"));
+    assert_eq!(row("page through cards").as_deref(), Some("Ctrl+f / Ctrl+b"));
+    // Every action bound in View mode has a row
+    for (_, action) in keymap::key_table(keymap::KeyMode::View) {
+        let description = action.description(keymap::KeyMode::View);
+        assert!(rows.iter().any(|b| b.action == description), "{:?}", action);
+    }
+
+    let field = keymap::bindings(KeyContext::OverlayField);
+    assert!(field.iter().any(|b| b.keys == "dd / yy / p" && b.action == "delete / yank / paste line"));
+}
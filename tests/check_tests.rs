@@ -0,0 +1,110 @@
+use revw::convert::Format;
+use revw::validate::{self, Issue};
+use std::{fs, process::Command};
+
+fn lines_of(issues: &[Issue]) -> Vec<usize> {
+    issues.iter().map(|issue| issue.line).collect()
+}
+
+#[test]
+fn clean_files_have_no_issues() {
+    let md = "# Notes\n\n## OUTSIDE\n\n### Rust\nbook\n\n**URL:** https://doc.rust-lang.org/book/\n\n**Percentage:** 40%\n\n## INSIDE\n\n### 2025-01-01 10:00:00\nnote\n";
+    assert_eq!(validate::check(md, Format::Md), vec![]);
+
+    let json = r#"{"outside": [{"name": "A", "url": "./notes/a.png", "percentage": 100, "priority": "high"}], "inside": [{"date": "2025-01-01", "context": "x"}]}"#;
+    assert_eq!(validate::check(json, Format::Json), vec![]);
+}
+
+#[test]
+fn markdown_issues_carry_line_numbers() {
+    let md = "stray\n## OUTSIDE\n### A\n**URL:** htp:/bad link\n**Percentage:** 150%\n### B\n**Percentage:** lots\n**Priority:** urgent\n## INSIDE\n### yesterday\nnote\n";
+    let issues = validate::check(md, Format::Md);
+    assert_eq!(lines_of(&issues), vec![1, 4, 5, 7, 8, 10]);
+    assert_eq!(issues[1].message, r#"outside "A": malformed URL "htp:/bad link""#);
+    assert_eq!(issues[2].message, r#"outside "A": percentage 150 is out of range (0-100)"#);
+    assert_eq!(issues[5].to_string(), r#"line 10: inside "yesterday": invalid date"#);
+}
+
+#[test]
+fn markdown_code_blocks_are_skipped() {
+    let md = "## INSIDE\n### 2025-01-01 10:00\n```\n## NOT A SECTION\n**Percentage:** 900%\n```\n";
+    assert_eq!(validate::check(md, Format::Md), vec![]);
+}
+
+#[test]
+fn json_issues_point_at_the_field_line() {
+    let json = r#"{
+  "outside": [
+    {
+      "name": "A",
+      "percentage": 101
+    },
+    {
+      "name": "B",
+      "url": "https://"
+    }
+  ],
+  "inside": [
+    {
+      "context": "no date"
+    }
+  ]
+}"#;
+    let issues = validate::check(json, Format::Json);
+    assert_eq!(lines_of(&issues), vec![5, 9, 13]);
+    assert_eq!(issues[0].message, "outside[0]: percentage 101 is out of range (0-100)");
+    assert_eq!(issues[1].message, r#"outside[1]: malformed URL "https://""#);
+    assert_eq!(issues[2].message, "inside[0]: missing date");
+}
+
+#[test]
+fn json_parse_errors_report_their_line() {
+    let issues = validate::check("{\n  \"outside\": [\n}", Format::Json);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].line, 3);
+    assert!(issues[0].message.starts_with("invalid JSON"), "{}", issues[0].message);
+}
+
+#[test]
+fn url_and_date_rules() {
+    assert!(validate::is_valid_url("https://example.com/a?b=c"));
+    assert!(validate::is_valid_url("file:///tmp/a.png"));
+    assert!(validate::is_valid_url("images/a.png"));
+    assert!(!validate::is_valid_url("https:///path"));
+    assert!(!validate::is_valid_url("ftp://example.com"));
+    assert!(!validate::is_valid_url("http://exa mple.com"));
+
+    assert!(validate::is_valid_date("2025-02-28 23:59:59"));
+    assert!(validate::is_valid_date("2025-02-28"));
+    assert!(!validate::is_valid_date("2025-02-30"));
+    assert!(!validate::is_valid_date("28/02/2025"));
+}
+
+#[test]
+fn check_flag_exits_non_zero_on_issues() {
+    let dir = std::env::temp_dir();
+    let good = dir.join(format!("revw_check_good_{}.md", std::process::id()));
+    let bad = dir.join(format!("revw_check_bad_{}.json", std::process::id()));
+    fs::write(&good, "## INSIDE\n\n### 2025-01-01 00:00:00\nnote\n").unwrap();
+    fs::write(&bad, "{\"outside\": [{\"name\": \"A\", \"percentage\": -5}]}").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).arg("--check").arg(&good).output().unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .arg("--check")
+        .arg(&good)
+        .arg(&bad)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout.trim(),
+        format!("{}:1: outside[0]: percentage -5 is out of range (0-100)", bad.display())
+    );
+
+    fs::remove_file(&good).ok();
+    fs::remove_file(&bad).ok();
+}
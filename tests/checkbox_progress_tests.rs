@@ -0,0 +1,88 @@
+mod common;
+
+use common::view_app;
+use revw::app::FileMode;
+use revw::content_ops::{checkbox_percentage, checkbox_progress};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {
+      "name": "Course",
+      "context": "- [x] intro\n- [x] basics\n- [ ] project",
+      "url": "",
+      "percentage": 10
+    },
+    {
+      "name": "Book",
+      "context": "no tasks here",
+      "url": "",
+      "percentage": 40
+    }
+  ],
+  "inside": []
+}"#;
+
+#[test]
+fn checkbox_progress_counts_task_items() {
+    assert_eq!(checkbox_progress("- [x] a\n- [ ] b\n  * [X] nested\ntext"), Some((2, 3)));
+    assert_eq!(checkbox_progress("plain\n- item\n[x] not a list item"), None);
+    // Boxes in code blocks are examples, not tasks
+    assert_eq!(checkbox_progress("- [ ] real\n```\n- [x] example\n```"), Some((0, 1)));
+
+    assert_eq!(checkbox_percentage("- [x] a\n- [ ] b\n- [ ] c"), Some(33));
+    assert_eq!(checkbox_percentage("- [x] a\n- [x] b\n- [ ] c"), Some(67));
+    assert_eq!(checkbox_percentage("- [x] done"), Some(100));
+    assert_eq!(checkbox_percentage("nothing"), None);
+}
+
+#[test]
+fn apply_checkbox_percentages_only_touches_entries_with_tasks() {
+    let (formatted, updated) = JsonOperations::apply_checkbox_percentages(JSON).unwrap();
+    assert_eq!(updated, 1);
+    let value: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(value["outside"][0]["percentage"], json!(67));
+    assert_eq!(value["outside"][1]["percentage"], json!(40));
+
+    let (_, updated) = JsonOperations::apply_checkbox_percentages(&formatted).unwrap();
+    assert_eq!(updated, 0);
+}
+
+#[test]
+fn progress_command_updates_json_and_markdown() {
+    let mut app = view_app(JSON, FileMode::Markdown);
+    app.markdown_input = app.convert_to_markdown().unwrap();
+
+    app.command_buffer = "progress".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Updated 1 percentages from checkboxes");
+    assert_eq!(app.relf_entries[0].percentage, Some(67));
+    assert!(app.markdown_input.contains("**Percentage:** 67%"), "{}", app.markdown_input);
+
+    app.undo();
+    assert_eq!(app.relf_entries[0].percentage, Some(10));
+}
+
+#[test]
+fn overlay_save_sets_percentage_when_enabled() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.start_editing_entry();
+    app.edit_buffer[1] = "- [x] intro\n- [ ] basics".to_string();
+    app.save_edited_entry();
+    assert_eq!(app.relf_entries[0].percentage, Some(10));
+
+    app.checkbox_percentage = true;
+    app.start_editing_entry();
+    app.edit_buffer[1] = "- [x] intro\n- [x] basics\n- [x] project\n- [ ] extra".to_string();
+    app.save_edited_entry();
+    assert_eq!(app.relf_entries[0].percentage, Some(75));
+
+    // Entries without checkboxes keep the typed percentage
+    app.selected_entry_index = 1;
+    app.start_editing_entry();
+    app.edit_buffer[3] = "55".to_string();
+    app.edit_buffer_is_placeholder[3] = false;
+    app.save_edited_entry();
+    assert_eq!(app.relf_entries[1].percentage, Some(55));
+}
@@ -1,23 +1,7 @@
-use std::{
-    fs,
-    path::PathBuf,
-    process::Command,
-    time::{SystemTime, UNIX_EPOCH},
-};
-
-fn tmp_path(prefix: &str, ext: &str) -> PathBuf {
-    let nanos = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .expect("system time before unix epoch")
-        .as_nanos();
-    std::env::temp_dir().join(format!(
-        "revw_{}_{}_{}.{}",
-        prefix,
-        std::process::id(),
-        nanos,
-        ext
-    ))
-}
+mod common;
+
+use common::tmp_path;
+use std::{fs, process::Command};
 
 fn run_cmd(args: &[String]) -> std::process::Output {
     Command::new(env!("CARGO_BIN_EXE_revw"))
@@ -94,3 +78,206 @@ fn input_conflicts_with_stdout_mode() {
     assert!(stderr.contains("--stdout"));
 }
 
+
+#[test]
+fn append_merge_strategy_controls_existing_urls() {
+    let existing = r#"{"outside":[{"name":"A","context":"old","url":"https://a","percentage":10}],"inside":[]}"#;
+    let incoming = r#"{"outside":[{"name":"A2","context":"new","url":"https://a","percentage":50},{"name":"B","context":"","url":"https://b","percentage":null}]}"#;
+    let input = tmp_path("merge_input", "json");
+    fs::write(&input, incoming).expect("failed to write input file");
+
+    let append = |strategy: Option<&str>| -> serde_json::Value {
+        let target = tmp_path("merge_target", "json");
+        fs::write(&target, existing).expect("failed to write target file");
+        let mut args = vec!["--append".to_string(), "--input".to_string(), input.to_string_lossy().to_string()];
+        if let Some(strategy) = strategy {
+            args.push("--merge-strategy".to_string());
+            args.push(strategy.to_string());
+        }
+        args.push(target.to_string_lossy().to_string());
+        let output = run_cmd(&args);
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        serde_json::from_str(&fs::read_to_string(&target).unwrap()).unwrap()
+    };
+
+    // Default keeps the old behavior: blind append
+    let merged = append(None);
+    assert_eq!(merged["outside"].as_array().unwrap().len(), 3);
+
+    // update refreshes context/percentage in place, keeps the name
+    let merged = append(Some("update"));
+    let outside = merged["outside"].as_array().unwrap();
+    assert_eq!(outside.len(), 2);
+    assert_eq!(outside[0]["name"], "A");
+    assert_eq!(outside[0]["context"], "new");
+    assert_eq!(outside[0]["percentage"], 50);
+    assert_eq!(outside[1]["name"], "B");
+
+    // skip leaves the existing entry untouched
+    let merged = append(Some("skip"));
+    let outside = merged["outside"].as_array().unwrap();
+    assert_eq!(outside.len(), 2);
+    assert_eq!(outside[0]["context"], "old");
+}
+
+#[test]
+fn merge_strategy_requires_append() {
+    let target = tmp_path("merge_requires_append", "json");
+    fs::write(&target, r#"{"outside":[],"inside":[]}"#).expect("failed to write target file");
+
+    let output = run_cmd(&[
+        "--merge-strategy".to_string(),
+        "update".to_string(),
+        target.to_string_lossy().to_string(),
+    ]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--append"));
+}
+
+#[test]
+fn tsv_output_lists_outside_entries() {
+    let target = tmp_path("tsv_output", "json");
+    fs::write(
+        &target,
+        r#"{"outside":[{"name":"Book","context":"","url":"https://example.com","percentage":40}],"inside":[{"date":"2025-01-01","context":"x"}]}"#,
+    )
+    .expect("failed to write target file");
+
+    let output = run_cmd(&["--stdout".to_string(), "--tsv".to_string(), target.to_string_lossy().to_string()]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "name\tpercentage\turl\nBook\t40\thttps://example.com\n"
+    );
+
+    let output = run_cmd(&[
+        "--stdout".to_string(),
+        "--table".to_string(),
+        "--json".to_string(),
+        target.to_string_lossy().to_string(),
+    ]);
+    assert!(!output.status.success());
+    let _ = fs::remove_file(target);
+}
+
+#[test]
+fn append_imports_bookmark_export_with_folder_tags() {
+    let target = tmp_path("bookmark_target", "json");
+    let input = tmp_path("bookmark_input", "html");
+    fs::write(&target, r#"{"outside":[],"inside":[]}"#).expect("failed to write target file");
+    fs::write(
+        &input,
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n<DL><p>\n<DT><H3>Reading</H3>\n<DL><p>\n<DT><A HREF=\"https://example.com/\">Example</A>\n</DL><p>\n</DL><p>\n",
+    )
+    .expect("failed to write input file");
+
+    let output = run_cmd(&[
+        "--append".to_string(),
+        "--input".to_string(),
+        input.to_string_lossy().to_string(),
+        "--map".to_string(),
+        "folder=tag".to_string(),
+        target.to_string_lossy().to_string(),
+    ]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let merged: serde_json::Value = serde_json::from_str(&fs::read_to_string(&target).unwrap()).unwrap();
+    assert_eq!(merged["outside"][0]["name"], "Example");
+    assert_eq!(merged["outside"][0]["url"], "https://example.com/");
+    assert_eq!(merged["outside"][0]["tags"], serde_json::json!(["Reading"]));
+
+    // --map needs a bookmark export
+    let plain = tmp_path("bookmark_plain", "json");
+    fs::write(&plain, r#"{"outside":[{"name":"A","context":"","url":"","percentage":null}]}"#).unwrap();
+    let output = run_cmd(&[
+        "--append".to_string(),
+        "--input".to_string(),
+        plain.to_string_lossy().to_string(),
+        "--map".to_string(),
+        "folder=tag".to_string(),
+        target.to_string_lossy().to_string(),
+    ]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--map only applies"));
+
+    let _ = fs::remove_file(target);
+    let _ = fs::remove_file(input);
+    let _ = fs::remove_file(plain);
+}
+
+#[test]
+fn plain_output_prints_selected_fields_without_headers() {
+    let target = tmp_path("plain_output", "json");
+    fs::write(
+        &target,
+        r#"{"outside":[{"name":"Book","context":"","url":"https://example.com","percentage":40},{"name":"Site","url":"https://x.y"}],"inside":[{"date":"2025-01-01","context":"x"}]}"#,
+    )
+    .expect("failed to write target file");
+    let path = target.to_string_lossy().to_string();
+
+    // --plain implies --stdout
+    let output = run_cmd(&["--plain".to_string(), "--outside".to_string(), "--fields".to_string(), "url".to_string(), path.clone()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "https://example.com\nhttps://x.y\n");
+
+    let output = run_cmd(&["--plain".to_string(), "--fields".to_string(), "name,percentage".to_string(), "--separator".to_string(), ",".to_string(), "--outside".to_string(), path.clone()]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Book,40\nSite,\n");
+
+    // Nothing at all when no entry matches
+    let output = run_cmd(&["--plain".to_string(), "--filter".to_string(), "nomatch".to_string(), path.clone()]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    // --fields needs --plain
+    let output = run_cmd(&["--stdout".to_string(), "--fields".to_string(), "url".to_string(), path]);
+    assert!(!output.status.success());
+    let _ = fs::remove_file(target);
+}
+
+#[test]
+fn color_output_follows_color_flag() {
+    let target = tmp_path("color_output", "json");
+    fs::write(&target, r#"{"outside":[{"name":"Book","context":"","url":"https://example.com","percentage":40}],"inside":[]}"#)
+        .expect("failed to write target file");
+    let path = target.to_string_lossy().to_string();
+
+    // Piped stdout is not a terminal, so auto stays plain
+    let output = run_cmd(&["--stdout".to_string(), path.clone()]);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains('\x1b'));
+
+    let output = run_cmd(&["--stdout".to_string(), "--color".to_string(), "always".to_string(), path.clone()]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\x1b[1;"), "{}", stdout);
+    assert!(stdout.contains("https://example.com\x1b[0m"), "{}", stdout);
+
+    // JSON stays machine-readable even when forced
+    let output = run_cmd(&["--stdout".to_string(), "--json".to_string(), "--color".to_string(), "always".to_string(), path.clone()]);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains('\x1b'));
+
+    let output = run_cmd(&["--stdout".to_string(), "--color".to_string(), "sometimes".to_string(), path]);
+    assert!(!output.status.success());
+    let _ = fs::remove_file(target);
+}
+
+#[test]
+fn mono_output_uses_weight_instead_of_colors() {
+    let target = tmp_path("mono_output", "json");
+    fs::write(&target, r#"{"outside":[{"name":"Book","context":"","url":"https://example.com","percentage":40}],"inside":[]}"#)
+        .expect("failed to write target file");
+    let path = target.to_string_lossy().to_string();
+
+    let output = run_cmd(&["--stdout".to_string(), "--color".to_string(), "mono".to_string(), path.clone()]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\x1b[1m"), "{}", stdout);
+    assert!(stdout.contains("\x1b[4mhttps://example.com\x1b[0m"), "{}", stdout);
+    // No hues: percentages are gray at most
+    assert!(stdout.contains("\x1b[90m40%"), "{}", stdout);
+    assert!(!regex::Regex::new("\x1b\\[[0-9;]*(3[0-7]|9[1-7]|38;)").unwrap().is_match(&stdout), "{}", stdout);
+
+    let output = run_cmd(&["--stdout".to_string(), "--no-color".to_string(), path]);
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains('\x1b'));
+    let _ = fs::remove_file(target);
+}
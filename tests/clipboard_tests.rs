@@ -0,0 +1,45 @@
+use revw::app::{osc52_sequence, App, FormatMode};
+use revw::config::PasteSource;
+
+#[test]
+fn osc52_sequence_encodes_text_as_base64() {
+    assert_eq!(osc52_sequence("hello", false), "\x1b]52;c;aGVsbG8=\x07");
+    assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+    assert_eq!(osc52_sequence("abc", false), "\x1b]52;c;YWJj\x07");
+    assert_eq!(osc52_sequence("", false), "\x1b]52;c;\x07");
+    // Multi-byte UTF-8 is encoded byte by byte
+    assert_eq!(osc52_sequence("é", false), "\x1b]52;c;w6k=\x07");
+}
+
+#[test]
+fn osc52_sequence_wraps_for_tmux() {
+    assert_eq!(
+        osc52_sequence("hello", true),
+        "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\"
+    );
+}
+
+#[test]
+fn paste_source_can_be_set_at_runtime() {
+    let mut app = App::new(FormatMode::View);
+    assert_eq!(app.paste_source, PasteSource::Clipboard);
+    app.command_buffer = "set paste_source=primary".to_string();
+    app.execute_command();
+    assert_eq!(app.paste_source, PasteSource::Primary);
+    assert_eq!(app.status_message, "Paste source set to primary");
+
+    app.command_buffer = "set paste_source=secondary".to_string();
+    app.execute_command();
+    assert_eq!(app.paste_source, PasteSource::Primary);
+    assert_eq!(app.status_message, "Paste source must be clipboard or primary");
+}
+
+#[test]
+fn paste_url_from_primary_keeps_the_paste_source() {
+    // With no cards nothing is read, but the setting must be restored either way
+    let mut app = App::new(FormatMode::View);
+    app.command_buffer = "vup".to_string();
+    app.execute_command();
+    assert_eq!(app.paste_source, PasteSource::Clipboard);
+    assert_eq!(app.status_message, "Not in card view mode");
+}
@@ -0,0 +1,52 @@
+// Helpers shared by the integration tests; each test crate uses a subset
+#![allow(dead_code)]
+
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::Value;
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Runs a `:` command as if typed on the command line
+pub fn run(app: &mut App, command: &str) {
+    app.command_buffer = command.to_string();
+    app.execute_command();
+}
+
+/// An app in View mode showing `json_input`
+pub fn view_app(json_input: &str, file_mode: FileMode) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = file_mode;
+    app.json_input = json_input.to_string();
+    app.convert_json();
+    app
+}
+
+/// The app's buffer parsed as JSON
+pub fn value(app: &App) -> Value {
+    serde_json::from_str(&app.json_input).unwrap()
+}
+
+/// An empty directory named after the test crate and `name`
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "revw_{}_{}_{}",
+        env!("CARGO_CRATE_NAME"),
+        name,
+        std::process::id()
+    ));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A fresh file path in the temp directory, unique per call
+pub fn tmp_path(prefix: &str, ext: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time before unix epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!("revw_{}_{}_{}.{}", prefix, std::process::id(), nanos, ext))
+}
@@ -0,0 +1,107 @@
+use revw::app::{App, ConfirmAction, FileMode, FormatMode};
+
+fn sample_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "", "percentage": null},
+    {"name": "B", "context": "", "url": "", "percentage": null},
+    {"name": "C", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2025-01-01 00:00:00", "context": "note"}
+  ]
+}"#
+    .to_string();
+    app.convert_json();
+    app
+}
+
+fn command(app: &mut App, cmd: &str) {
+    app.command_buffer = cmd.to_string();
+    app.execute_command();
+}
+
+#[test]
+fn without_the_flags_actions_run_at_once() {
+    let mut app = sample_app();
+    command(&mut app, "xi");
+    assert_eq!(app.confirm_pending, None);
+    assert_eq!(app.status_message, "INSIDE section cleared");
+}
+
+#[test]
+fn clearing_waits_for_yes() {
+    let mut app = sample_app();
+    command(&mut app, "set confirm_clear");
+    command(&mut app, "xo");
+    assert_eq!(app.confirm_pending, Some(ConfirmAction::ClearOutside));
+    assert_eq!(app.status_message, "Clear the OUTSIDE section? (y/n)");
+    assert_eq!(app.relf_entries.len(), 4);
+
+    app.handle_confirmation('n');
+    assert_eq!(app.confirm_pending, None);
+    assert_eq!(app.status_message, "Cancelled");
+    assert_eq!(app.relf_entries.len(), 4);
+
+    command(&mut app, "xo");
+    app.handle_confirmation('y');
+    assert_eq!(app.relf_entries.len(), 1);
+}
+
+#[test]
+fn deleting_cards_names_how_many() {
+    let mut app = sample_app();
+    app.confirm_delete = true;
+    app.selected_entry_index = 1;
+    app.confirm_action(ConfirmAction::DeleteCardsForward(5));
+    assert_eq!(app.status_message, "Delete 3 cards? (y/n)");
+    app.handle_confirmation('y');
+    assert_eq!(app.relf_entries.len(), 1);
+    assert_eq!(app.card_register.len(), 3);
+
+    // Turned off, dd deletes right away
+    command(&mut app, "set noconfirm_delete");
+    assert!(!app.confirm_delete);
+    command(&mut app, "dd");
+    assert_eq!(app.confirm_pending, None);
+    assert!(app.relf_entries.is_empty());
+}
+
+#[test]
+fn pastes_over_the_limit_ask_with_the_count() {
+    let mut app = sample_app();
+    let entries: Vec<String> = (0..150).map(|i| format!("{{\"date\": \"2025-01-01 00:00:{:02}\", \"context\": \"n{}\"}}", i % 60, i)).collect();
+    let text = format!("{{\"outside\": [{{\"name\": \"X\"}}], \"inside\": [{}]}}", entries.join(","));
+    assert_eq!(app.pasted_entry_count(&text, None), Some(151));
+    assert_eq!(app.pasted_entry_count(&text, Some("outside")), Some(1));
+    assert_eq!(app.pasted_entry_count("just words", None), None);
+
+    assert_eq!(
+        app.paste_limit_prompt(&ConfirmAction::PasteAppend(None), &text).as_deref(),
+        Some("Paste 151 entries (more than paste_limit=100)? (y/n)")
+    );
+    assert_eq!(app.paste_limit_prompt(&ConfirmAction::PasteAppendOutside(None), &text), None);
+    // Not a paste
+    assert_eq!(app.paste_limit_prompt(&ConfirmAction::ClearAll, &text), None);
+
+    command(&mut app, "set paste_limit=0");
+    assert_eq!(app.status_message, "Paste limit set to 0");
+    assert_eq!(app.paste_limit_prompt(&ConfirmAction::PasteInside(None), &text), None);
+    command(&mut app, "set paste_limit=200");
+    assert_eq!(app.paste_limit_prompt(&ConfirmAction::Paste(None), &text), None);
+}
+
+#[test]
+fn confirmed_pastes_use_the_text_that_was_counted() {
+    let mut app = sample_app();
+    app.safe_mode = true;
+    let text = r#"{"outside": [{"name": "D"}], "inside": []}"#.to_string();
+    app.confirm_pending = Some(ConfirmAction::PasteAppendOutside(Some(text)));
+    app.handle_confirmation('y');
+    assert_eq!(app.confirm_pending, None);
+    let value: serde_json::Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"].as_array().unwrap().len(), 4);
+    assert_eq!(value["outside"][3]["name"], "D");
+}
@@ -0,0 +1,108 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::json;
+
+fn app() -> App {
+    let long: Vec<String> = (1..=6).map(|i| format!("line {}", i)).collect();
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = serde_json::to_string_pretty(&json!({
+        "outside": [
+            {"name": "Long", "context": long.join("\n"), "url": "", "percentage": null},
+            {"name": "Short", "context": "just one", "url": "", "percentage": null}
+        ],
+        "inside": []
+    }))
+    .unwrap();
+    app.convert_json();
+    app
+}
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 30)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn contexts_are_shown_in_full_by_default() {
+    let mut app = app();
+    let text = screen(&mut app);
+    assert!(text.contains("line 6"));
+    assert!(!text.contains("(+"));
+}
+
+#[test]
+fn long_contexts_collapse_with_an_indicator() {
+    let mut app = app();
+    app.command_buffer = "set max_context_lines=2".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Contexts collapsed to 2 lines (Tab expands a card)");
+
+    let text = screen(&mut app);
+    assert!(text.contains("line 2"));
+    assert!(!text.contains("line 3"));
+    assert!(text.contains("… (+4 lines)"));
+    assert!(text.contains("just one"));
+    // Scroll bounds follow the collapsed body: two lines and the indicator
+    assert_eq!(app.card_context_rows, 3);
+}
+
+#[test]
+fn tab_expands_and_collapses_the_selected_card() {
+    let mut app = app();
+    app.max_context_lines = 2;
+    app.toggle_card_expansion();
+    assert_eq!(app.status_message, "Card expanded");
+    let text = screen(&mut app);
+    assert!(text.contains("line 6"));
+    assert!(!text.contains("(+"));
+    assert!(text.contains("just one"));
+    assert_eq!(app.card_context_rows, 6);
+
+    app.toggle_card_expansion();
+    assert_eq!(app.status_message, "Card collapsed");
+    assert!(screen(&mut app).contains("… (+4 lines)"));
+}
+
+#[test]
+fn tab_without_a_limit_explains_itself() {
+    let mut app = app();
+    app.toggle_card_expansion();
+    assert!(app.expanded_cards.is_empty());
+    assert_eq!(app.status_message, "Contexts are shown in full (:set max_context_lines=N to collapse)");
+}
+
+#[test]
+fn expansion_follows_the_card_when_cards_above_it_are_deleted() {
+    let mut app = app();
+    app.max_context_lines = 2;
+    app.selected_entry_index = 1;
+    app.toggle_card_expansion();
+    app.selected_entry_index = 0;
+    app.delete_cards_forward(1);
+    // "Short" is now the first card; "Long" is gone and no other card opened in its place
+    let text = screen(&mut app);
+    assert!(text.contains("just one"));
+    assert_eq!(app.expanded_cards.len(), 1);
+    assert_eq!(app.context_line_limit(&app.relf_entries[0]), None);
+
+    app.put_cards(true, 1);
+    assert!(screen(&mut app).contains("… (+4 lines)"));
+}
+
+#[test]
+fn opening_another_file_collapses_every_card() {
+    let dir = std::env::temp_dir().join(format!("revw-collapse-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("other.json");
+    std::fs::write(&path, r#"{"outside": [{"name": "Long", "context": "a\nb\nc\nd", "url": "", "percentage": null}], "inside": []}"#).unwrap();
+
+    let mut app = app();
+    app.max_context_lines = 2;
+    app.toggle_card_expansion();
+    app.load_file(path);
+    assert!(app.expanded_cards.is_empty());
+    assert!(screen(&mut app).contains("… (+2 lines)"));
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,159 @@
+mod common;
+
+use common::{run, temp_dir};
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::{json, Value};
+use std::{fs, path::PathBuf};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Book", "context": "notes", "url": "https://book.example", "percentage": 40}
+  ],
+  "inside": [
+    {"date": "2024-01-01 10:00:00", "context": "day one"}
+  ]
+}"#;
+
+fn loaded_app(path: PathBuf) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path);
+    app
+}
+
+#[test]
+fn convert_writes_next_to_original_and_reopens_it() {
+    let dir = temp_dir("reopen");
+    let json_path = dir.join("notes.json");
+    fs::write(&json_path, JSON).unwrap();
+    let mut app = loaded_app(json_path.clone());
+
+    run(&mut app, "convert md");
+    let md_path = dir.join("notes.md");
+    assert_eq!(app.file_path.as_ref(), Some(&md_path));
+    assert!(matches!(app.file_mode, FileMode::Markdown));
+    assert!(!app.is_modified);
+    assert_eq!(
+        app.status_message,
+        format!("Converted to {}; delete {}? (y/n)", md_path.display(), json_path.display())
+    );
+    let markdown = fs::read_to_string(&md_path).unwrap();
+    assert!(markdown.contains("### Book"));
+    assert!(markdown.contains("**URL:** https://book.example"));
+
+    // Keep the original
+    app.handle_convert_delete_confirmation('n');
+    assert!(app.convert_delete_pending.is_none());
+    assert!(json_path.exists());
+
+    // Back to JSON at a chosen path, deleting the Markdown file
+    let back = dir.join("copy.json");
+    run(&mut app, &format!("convert json {}", back.display()));
+    assert_eq!(app.file_path.as_ref(), Some(&back));
+    app.handle_convert_delete_confirmation('y');
+    assert!(!md_path.exists());
+    let value: Value = serde_json::from_str(&fs::read_to_string(&back).unwrap()).unwrap();
+    assert_eq!(value, serde_json::from_str::<Value>(JSON).unwrap());
+    assert_eq!(value["outside"][0]["percentage"], json!(40));
+}
+
+#[test]
+fn convert_refuses_to_clobber_or_guess() {
+    let dir = temp_dir("refuse");
+    let json_path = dir.join("notes.json");
+    let md_path = dir.join("notes.md");
+    fs::write(&json_path, JSON).unwrap();
+    fs::write(&md_path, "## OUTSIDE\n").unwrap();
+    let mut app = loaded_app(json_path.clone());
+
+    run(&mut app, "convert md");
+    assert_eq!(app.status_message, format!("Error: {} exists (use :convert! to overwrite)", md_path.display()));
+    assert_eq!(app.file_path.as_ref(), Some(&json_path));
+
+    run(&mut app, "convert json");
+    assert_eq!(app.status_message, "Already a .json file");
+
+    run(&mut app, "convert toon");
+    assert_eq!(app.status_message, "Error: TOON is not supported yet; use :convert md or :convert json");
+
+    run(&mut app, "convert md other.json");
+    assert_eq!(app.status_message, "Error: other.json must end with .md");
+
+    run(&mut app, "convert! md");
+    assert_eq!(app.file_path.as_ref(), Some(&md_path));
+    assert!(fs::read_to_string(&md_path).unwrap().contains("### Book"));
+}
+
+#[test]
+fn extensionless_markdown_is_loaded_and_saved_as_markdown() {
+    let dir = temp_dir("sniff");
+    let path = dir.join("notes");
+    fs::write(&path, "## OUTSIDE\n\n### Book\nnotes\n").unwrap();
+    let mut app = loaded_app(path.clone());
+    assert_eq!(app.file_mode, FileMode::Markdown);
+    assert_eq!(app.relf_entries.len(), 1);
+
+    run(&mut app, "w");
+    assert!(fs::read_to_string(&path).unwrap().starts_with("## OUTSIDE"));
+
+    let toon_path = dir.join("notes.toon");
+    fs::write(&toon_path, "outside[1]{name}:\n  Book\n").unwrap();
+    let app = loaded_app(toon_path);
+    assert!(app.status_message.contains("TOON files are not supported yet"), "{}", app.status_message);
+}
+
+#[test]
+fn markdown_export_keeps_contexts_as_stored() {
+    let dir = temp_dir("export");
+    let path = dir.join("notes.json");
+    let doc = json!({
+        "outside": [{"name": "Code", "context": "first line\nprintf(\"done\\n\");", "url": "", "percentage": 5, "priority": "high"}],
+        "inside": [{"date": "2024-01-01 10:00:00", "context": "one\ntwo"}]
+    });
+    fs::write(&path, serde_json::to_string_pretty(&doc).unwrap()).unwrap();
+    let mut app = loaded_app(path);
+
+    run(&mut app, "markdown");
+    let exported = fs::read_to_string(dir.join("notes.md")).unwrap();
+    assert_eq!(exported, app.convert_to_markdown().unwrap());
+    // Real line breaks stay line breaks, a literal backslash-n stays text
+    assert!(exported.contains("### Code\nfirst line\nprintf(\"done\\n\");\n"), "{}", exported);
+    assert!(exported.contains("**Priority:** high"), "{}", exported);
+
+    let back: Value = serde_json::from_str(&app.parse_markdown(&exported).unwrap()).unwrap();
+    assert_eq!(back, doc);
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn literal_backslash_n_survives_load_and_save() {
+    let dir = temp_dir("newlines");
+    let path = dir.join("notes.json");
+    // A real line break, a Windows path and a regex, the last two with a backslash before `n`
+    let doc = r#"{
+  "outside": [
+    {"name": "Real", "context": "one\ntwo"},
+    {"name": "Path", "context": "path C:\\notes\\new"}
+  ],
+  "inside": [
+    {"date": "2024-01-01 10:00:00", "context": "match \\d+\\n at the end"}
+  ]
+}"#;
+    fs::write(&path, doc).unwrap();
+    let mut app = loaded_app(path.clone());
+    let expected = ["one\ntwo", "path C:\\notes\\new", "match \\d+\\n at the end"];
+
+    let contexts: Vec<_> = app.relf_entries.iter().map(|e| e.context.clone().unwrap()).collect();
+    assert_eq!(contexts, expected);
+
+    run(&mut app, "w");
+    let saved: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved["outside"][1]["context"], expected[1]);
+    assert_eq!(saved["inside"][0]["context"], expected[2]);
+
+    // Through Markdown and back the contexts stay the same
+    run(&mut app, "convert md");
+    let app = loaded_app(dir.join("notes.md"));
+    let contexts: Vec<_> = app.relf_entries.iter().map(|e| e.context.clone().unwrap()).collect();
+    assert_eq!(contexts, expected);
+    fs::remove_dir_all(&dir).ok();
+}
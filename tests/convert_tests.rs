@@ -0,0 +1,150 @@
+use revw::convert::{Converter, Format, Sections};
+use revw::rendering::Renderer;
+use serde_json::Value;
+use std::path::Path;
+
+const MD: &str = "## OUTSIDE\n\n### Book\nchapter 3\n\n**URL:** https://example.com\n\n**Percentage:** 40%\n\n## INSIDE\n\n### 2025-01-01 10:00:00\nnote\n";
+
+#[test]
+fn markdown_to_json_and_back() {
+    let json = Converter::new().from(Format::Md).to(Format::Json).convert(MD).unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["outside"][0]["name"], "Book");
+    assert_eq!(value["outside"][0]["percentage"], 40);
+    assert_eq!(value["inside"][0]["context"], "note");
+
+    let md = Converter::new().to(Format::Md).convert(&json).unwrap();
+    assert_eq!(md.trim_end(), MD.trim_end());
+}
+
+#[test]
+fn input_format_is_detected_when_not_given() {
+    assert_eq!(Format::detect(MD), Format::Md);
+    assert_eq!(Format::detect("{\"inside\": []}"), Format::Json);
+    assert_eq!(Format::from_path(Path::new("notes.MD")), Format::Md);
+    assert_eq!(Format::from_path(Path::new("notes.json")), Format::Json);
+
+    let json = Converter::new().pretty(false).convert(MD).unwrap();
+    assert!(json.starts_with("{\"outside\":[{"), "{}", json);
+}
+
+#[test]
+fn format_is_sniffed_from_content() {
+    assert_eq!(Format::detect("### Book\nchapter 3\n"), Format::Md);
+    assert_eq!(Format::detect("Notes\n\n## OUTSIDE\n\n### Book\n"), Format::Md);
+    assert_eq!(Format::detect("  [{\"name\": \"Book\"}]"), Format::Json);
+    assert_eq!(Format::detect("outside[2]{name,url}:\n  Book,https://x.y\n"), Format::Toon);
+    assert_eq!(Format::detect("inside[1]:\n"), Format::Toon);
+    assert_eq!(Format::detect("outside[x]:\n"), Format::Json);
+
+    // An extension decides when it says .md or .json, the content otherwise
+    assert_eq!(Format::for_file(Path::new("notes"), MD), Format::Md);
+    assert_eq!(Format::for_file(Path::new("notes.txt"), "{}"), Format::Json);
+    assert_eq!(Format::for_file(Path::new("notes.json"), MD), Format::Json);
+
+    assert_eq!(
+        Converter::new().convert("outside[1]{name}:\n  Book\n"),
+        Err("TOON input is not supported yet".to_string())
+    );
+}
+
+#[test]
+fn sections_and_filter_limit_output() {
+    let text = Converter::new().to(Format::Text).sections(Sections::OutsideOnly).convert(MD).unwrap();
+    assert_eq!(text, "OUTSIDE\n\nBook\nchapter 3\nhttps://example.com\n40%\n");
+
+    let json = Converter::new().filter("note").pretty(false).convert(MD).unwrap();
+    assert_eq!(json, r#"{"outside":[],"inside":[{"date":"2025-01-01 10:00:00","context":"note"}]}"#);
+}
+
+#[test]
+fn invalid_input_is_an_error() {
+    assert_eq!(Converter::new().from(Format::Json).convert("not json"), Err("Invalid JSON".to_string()));
+    assert!(Converter::new().from(Format::Text).convert("x").is_err());
+}
+
+const TABLE_MD: &str = "## OUTSIDE\n\n### Rust Book\nch 3\n\n**URL:** https://doc.rust-lang.org/book\n\n**Percentage:** 40%\n\n### 日本語\n\n**Percentage:** 100%\n\n### Unset\n\n**URL:** https://x.y\n\n## INSIDE\n\n### 2025-01-01 10:00:00\nnote\n";
+
+#[test]
+fn table_aligns_outside_columns() {
+    let table = Converter::new().to(Format::Table).convert(TABLE_MD).unwrap();
+    assert_eq!(
+        table,
+        "NAME       PERCENTAGE  URL\n\
+         Rust Book         40%  https://doc.rust-lang.org/book\n\
+         日本語           100%\n\
+         Unset               -  https://x.y"
+    );
+}
+
+#[test]
+fn table_fits_a_width_and_keeps_the_percentage_column() {
+    let table = Converter::new().to(Format::Table).width(24).convert(TABLE_MD).unwrap();
+    assert_eq!(
+        table,
+        "NAME    PERCENTAGE  URL\n\
+         Rust …         40%  htt…\n\
+         日本語        100%\n\
+         Unset            -  htt…"
+    );
+    // Without a width (piped output) nothing is cut
+    assert!(Converter::new().to(Format::Table).convert(TABLE_MD).unwrap().contains("https://doc.rust-lang.org/book"));
+}
+
+#[test]
+fn elide_cuts_by_display_width() {
+    assert_eq!(Renderer::elide("Rust Book", 9), "Rust Book");
+    assert_eq!(Renderer::elide("Rust Book", 6), "Rust …");
+    assert_eq!(Renderer::elide("日本語", 5), "日本…");
+    assert_eq!(Renderer::elide("abc", 0), "");
+}
+
+#[test]
+fn tsv_has_one_row_per_outside_entry() {
+    let tsv = Converter::new().to(Format::Tsv).filter("book|x.y").convert(TABLE_MD).unwrap();
+    assert_eq!(tsv, "name\tpercentage\turl\nRust Book\t40\thttps://doc.rust-lang.org/book\nUnset\t\thttps://x.y");
+
+    // Tabs and line breaks inside fields cannot split a row
+    let json = r#"{"outside": [{"name": "a\tb\nc", "url": "", "percentage": 5}]}"#;
+    assert_eq!(Converter::new().to(Format::Tsv).convert(json).unwrap(), "name\tpercentage\turl\na b c\t5\t");
+    assert!(Converter::new().from(Format::Tsv).convert("x").is_err());
+}
+
+#[test]
+fn plain_prints_one_line_per_entry() {
+    let json = r#"{
+      "outside": [{"name": "Rust", "context": "a\nb", "url": "https://r.rs", "percentage": 50, "tags": ["x", "y"]}],
+      "inside": [{"date": "2025-01-01 10:00:00", "context": "note\nmore"}]
+    }"#;
+    let plain = Converter::new().to(Format::Plain).convert(json).unwrap();
+    assert_eq!(plain, "Rust\thttps://r.rs\t50\n2025-01-01 10:00:00\tnote more");
+
+    let plain = Converter::new()
+        .to(Format::Plain)
+        .sections(Sections::OutsideOnly)
+        .fields(vec!["name".to_string(), "tags".to_string(), "missing".to_string()])
+        .separator(";")
+        .convert(json)
+        .unwrap();
+    assert_eq!(plain, "Rust;x,y;");
+}
+
+#[test]
+fn colored_table_keeps_columns_aligned() {
+    let scheme = revw::config::ColorScheme::default();
+    let colored = Converter::new().from(Format::Md).to(Format::Table).colors(scheme).convert(MD).unwrap();
+    let plain = Converter::new().from(Format::Md).to(Format::Table).convert(MD).unwrap();
+    assert_ne!(colored, plain);
+    let stripped = regex::Regex::new("\x1b\\[[0-9;]*m").unwrap().replace_all(&colored, "").to_string();
+    assert_eq!(stripped, plain);
+}
+
+#[test]
+fn mono_scheme_paints_no_colors() {
+    let scheme = revw::config::ColorScheme::by_name("mono").unwrap();
+    let mono = Converter::new().from(Format::Md).to(Format::Table).colors(scheme).convert(MD).unwrap();
+    let plain = Converter::new().from(Format::Md).to(Format::Table).convert(MD).unwrap();
+    assert!(mono.contains("\x1b[1m"));
+    let stripped = regex::Regex::new("\x1b\\[[0-9;]*m").unwrap().replace_all(&mono, "").to_string();
+    assert_eq!(stripped, plain);
+}
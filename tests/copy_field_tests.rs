@@ -0,0 +1,52 @@
+mod common;
+
+use common::{run, view_app};
+use revw::app::{App, FileMode};
+use serde_json::json;
+
+fn sample_app() -> App {
+    let json_input = serde_json::to_string_pretty(&json!({
+        "outside": [
+            {"name": "Book", "context": "line one\nline two", "url": "https://example.com", "percentage": 40, "isbn": "978-0"},
+            {"name": "Bare", "context": "", "url": "", "percentage": null}
+        ],
+        "inside": [{"date": "2025-01-01 09:00:00", "context": "note"}]
+    }))
+    .unwrap();
+    view_app(&json_input, FileMode::Json)
+}
+
+#[test]
+fn entry_fields_are_plain_values() {
+    let app = sample_app();
+    let book = &app.relf_entries[0];
+    assert_eq!(book.field("url").as_deref(), Some("https://example.com"));
+    assert_eq!(book.field("name").as_deref(), Some("Book"));
+    assert_eq!(book.field("context").as_deref(), Some("line one\nline two"));
+    assert_eq!(book.field("percentage").as_deref(), Some("40"));
+    assert_eq!(book.field("isbn").as_deref(), Some("978-0"));
+    assert_eq!(book.field("date"), None);
+
+    let bare = &app.relf_entries[1];
+    assert_eq!(bare.field("url"), None);
+    assert_eq!(bare.field("percentage"), None);
+    let note = &app.relf_entries[2];
+    assert_eq!(note.field("date").as_deref(), Some("2025-01-01 09:00:00"));
+    assert_eq!(note.field("name"), None);
+}
+
+#[test]
+fn missing_fields_are_reported() {
+    let mut app = sample_app();
+    app.selected_entry_index = 1;
+    run(&mut app, "yu");
+    assert_eq!(app.status_message, "No url on the selected card");
+    run(&mut app, "yd");
+    assert_eq!(app.status_message, "No date on the selected card");
+    run(&mut app, "y isbn");
+    assert_eq!(app.status_message, "No isbn on the selected card");
+
+    app.toggle_format_mode();
+    run(&mut app, "yn");
+    assert_eq!(app.status_message, "Not in card view mode");
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::view_app;
+use revw::app::FileMode;
+use revw::markdown_ops::MarkdownOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {
+      "name": "Paper",
+      "context": "notes",
+      "url": "",
+      "percentage": null,
+      "status": "reading",
+      "rating": 2,
+      "starred": true,
+      "code": "5",
+      "summary": "line one\nline two"
+    }
+  ],
+  "inside": [
+    {
+      "date": "2025-01-01 00:00:00",
+      "context": "memo",
+      "mood": "calm"
+    }
+  ]
+}"#;
+
+#[test]
+fn custom_fields_roundtrip_through_markdown() {
+    let app = view_app(JSON, FileMode::Markdown);
+    let markdown = app.convert_to_markdown().unwrap();
+    assert!(markdown.contains("**status:** reading"), "{}", markdown);
+    assert!(markdown.contains("**rating:** 2"), "{}", markdown);
+    assert!(markdown.contains("**code:** \"5\""), "{}", markdown);
+    assert!(markdown.contains("**mood:** calm"), "{}", markdown);
+
+    let back: Value = serde_json::from_str(&app.parse_markdown(&markdown).unwrap()).unwrap();
+    let original: Value = serde_json::from_str(JSON).unwrap();
+    assert_eq!(back, original);
+}
+
+#[test]
+fn bold_labels_inside_context_stay_in_context() {
+    let markdown = "## OUTSIDE\n### Paper\n**Note:** keep this\n";
+    let json: Value = serde_json::from_str(&MarkdownOperations::parse_to_json(markdown).unwrap()).unwrap();
+    let entry = &json["outside"][0];
+    assert_eq!(entry["context"], json!("**Note:** keep this"));
+    assert!(entry.get("Note").is_none());
+}
+
+#[test]
+fn custom_fields_render_on_cards_and_match_filters() {
+    let mut app = view_app(JSON, FileMode::Json);
+    let fields = app.relf_entries[0].field_lines();
+    assert_eq!(fields[0], "status: reading");
+    assert_eq!(fields[1], "rating: 2");
+    assert_eq!(fields[2], "starred: true");
+
+    app.filter_pattern = "reading".to_string();
+    app.convert_json();
+    assert_eq!(app.relf_entries.len(), 1);
+    assert_eq!(app.relf_entries[0].name.as_deref(), Some("Paper"));
+}
+
+#[test]
+fn custom_fields_are_editable_in_overlay() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 0;
+    app.start_editing_entry();
+    // name, context, url, percentage, priority, due, then the five custom fields
+    assert_eq!(app.edit_buffer.len(), 11);
+    assert_eq!(app.edit_extra_field_count(), 7);
+    assert_eq!(app.edit_field_key(6), "status");
+    assert_eq!(app.edit_buffer[6], "reading");
+    assert_eq!(app.edit_buffer[9], "\"5\"");
+
+    app.edit_buffer[6] = "done".to_string();
+    app.edit_buffer[7] = "3".to_string();
+    app.save_edited_entry();
+
+    let saved: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(saved["outside"][0]["status"], json!("done"));
+    assert_eq!(saved["outside"][0]["rating"], json!(3));
+    assert_eq!(saved["outside"][0]["code"], json!("5"));
+    assert_eq!(saved["outside"][0]["summary"], json!("line one\nline two"));
+}
+
+#[test]
+fn strict_markdown_rejects_custom_fields() {
+    let markdown = "## OUTSIDE\n### Paper\n**source:** library\n";
+    let err = MarkdownOperations::validate_strict(markdown).unwrap_err();
+    assert!(err.contains("unknown field \"source\""), "{}", err);
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::temp_dir;
+use chrono::NaiveDate;
+use revw::import_ops::{self, DailyNote};
+use serde_json::{json, Value};
+use std::{fs, path::PathBuf, process::Command};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+/// A vault folder with nested month folders, front matter, headings and noise
+fn vault(name: &str) -> PathBuf {
+    let dir = temp_dir(name);
+    fs::create_dir_all(dir.join("2025/03")).unwrap();
+    fs::create_dir_all(dir.join(".obsidian")).unwrap();
+    fs::write(dir.join("2025-03-01.md"), "---\ntags: [daily]\n---\n# Saturday\nread the nom docs\n").unwrap();
+    fs::write(dir.join("2025/03/2025_03_02 Sunday.md"), "## Tasks\n- [x] walk\n").unwrap();
+    fs::write(dir.join("20250303.md"), "\n\n").unwrap();
+    fs::write(dir.join("ideas.md"), "not a daily note").unwrap();
+    fs::write(dir.join("2025-03-04.txt"), "not Markdown").unwrap();
+    fs::write(dir.join(".obsidian/2025-03-05.md"), "settings").unwrap();
+    dir
+}
+
+#[test]
+fn dates_come_from_file_names() {
+    assert_eq!(import_ops::date_from_filename("2025-03-02"), Some(date(2025, 3, 2)));
+    assert_eq!(import_ops::date_from_filename("2025_03_02 Sunday"), Some(date(2025, 3, 2)));
+    assert_eq!(import_ops::date_from_filename("Journal 20250302"), Some(date(2025, 3, 2)));
+    assert_eq!(import_ops::date_from_filename("2025-02-30"), None);
+    assert_eq!(import_ops::date_from_filename("ideas"), None);
+}
+
+#[test]
+fn folder_is_read_newest_first() {
+    let dir = vault("read");
+    let daily = import_ops::read_daily_notes(&dir).unwrap();
+
+    assert_eq!(
+        daily.notes,
+        vec![
+            DailyNote { date: date(2025, 3, 2), body: "#### Tasks\n- [x] walk".to_string() },
+            DailyNote { date: date(2025, 3, 1), body: "#### Saturday\nread the nom docs".to_string() },
+        ]
+    );
+    assert_eq!(daily.skipped, vec![dir.join("ideas.md")]);
+}
+
+#[test]
+fn days_already_in_the_file_are_left_out() {
+    let notes = vec![
+        DailyNote { date: date(2025, 3, 2), body: "new".to_string() },
+        DailyNote { date: date(2025, 3, 1), body: "again".to_string() },
+    ];
+    let current = json!({"outside": [], "inside": [{"date": "2025-03-01 00:00:00", "context": "old"}]});
+    let (entries, duplicates) = import_ops::daily_notes_to_entries(&notes, &current);
+    assert_eq!(duplicates, 1);
+    assert_eq!(entries, json!({"inside": [{"date": "2025-03-02 00:00:00", "context": "new"}]}));
+}
+
+#[test]
+fn cli_imports_into_markdown_and_skips_on_rerun() {
+    let dir = vault("cli");
+    let target = dir.join("journal.md");
+    fs::write(&target, "## OUTSIDE\n\n### Resource\nkeep me\n\n## INSIDE\n").unwrap();
+
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_revw"))
+            .env("HOME", &dir)
+            .arg("--import-obsidian")
+            .arg(&dir)
+            .arg(&target)
+            .output()
+            .expect("failed to execute revw")
+    };
+
+    let output = run();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("imported 2 note(s), 0 already there"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("ideas.md: no date in the file name"));
+
+    let parsed = |path: &PathBuf| -> Value {
+        let json = revw::convert::Converter::new()
+            .from(revw::convert::Format::Md)
+            .to(revw::convert::Format::Json)
+            .convert(&fs::read_to_string(path).unwrap())
+            .unwrap();
+        serde_json::from_str(&json).unwrap()
+    };
+    let value = parsed(&target);
+    assert_eq!(value["outside"][0]["context"], "keep me");
+    assert_eq!(value["inside"][0]["date"], "2025-03-02 00:00:00");
+    assert_eq!(value["inside"][0]["context"], "#### Tasks\n- [x] walk");
+    assert_eq!(value["inside"][1]["date"], "2025-03-01 00:00:00");
+
+    let output = run();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("imported 0 note(s), 2 already there"));
+    assert_eq!(parsed(&target)["inside"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,81 @@
+use revw::diff::{self, ChangeKind};
+use serde_json::json;
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn entries_pair_up_by_url_date_then_name() {
+    let old = json!({
+        "outside": [
+            {"name": "Rust book", "url": "https://doc.rust-lang.org/book", "percentage": 40},
+            {"name": "Tokio", "url": "https://tokio.rs", "percentage": null},
+            {"name": "Gone", "url": ""}
+        ],
+        "inside": [{"date": "2024-01-01 00:00:00", "context": "start"}]
+    });
+    let new = json!({
+        "outside": [
+            {"name": "New", "url": "https://new.example"},
+            {"name": "Tokio", "url": "https://tokio.rs/tokio", "context": ""},
+            {"name": "The Rust book", "url": "https://doc.rust-lang.org/book", "percentage": 60}
+        ],
+        "inside": [{"date": "2024-01-01 00:00:00", "context": "start"}]
+    });
+    let changes = diff::diff(&old, &new);
+    let kinds: Vec<(ChangeKind, String)> = changes.iter().map(|c| (c.kind, c.label())).collect();
+    assert_eq!(
+        kinds,
+        [
+            (ChangeKind::Added, "New".to_string()),
+            (ChangeKind::Changed, "Tokio".to_string()),
+            (ChangeKind::Changed, "The Rust book".to_string()),
+            (ChangeKind::Removed, "Gone".to_string()),
+        ]
+    );
+    // A missing field, null and "" are the same, so only the URL changed
+    assert_eq!(changes[1].fields.len(), 1);
+    assert_eq!(changes[1].fields[0].key, "url");
+    let keys: Vec<&str> = changes[2].fields.iter().map(|f| f.key.as_str()).collect();
+    assert_eq!(keys, ["name", "percentage"]);
+    assert_eq!(diff::summary(&changes), "1 added, 1 removed, 2 changed");
+}
+
+#[test]
+fn text_and_json_output() {
+    let old = json!({"outside": [{"name": "A", "percentage": 10}], "inside": []});
+    let new = json!({"outside": [{"name": "A", "percentage": 20}], "inside": [{"date": "2024-02-01 00:00:00", "context": "note"}]});
+    let changes = diff::diff(&old, &new);
+    assert_eq!(
+        diff::render_text(&changes),
+        "OUTSIDE\n~ A\n    percentage: 10 → 20\n\nINSIDE\n+ 2024-02-01 00:00:00\n\n1 added, 0 removed, 1 changed"
+    );
+    let value = diff::to_json(&changes);
+    assert_eq!(value["summary"], json!({"added": 1, "removed": 0, "changed": 1}));
+    assert_eq!(value["changes"][0]["fields"], json!([{"key": "percentage", "old": 10, "new": 20}]));
+    assert_eq!(value["changes"][1]["change"], "added");
+    assert!(diff::diff(&old, &old).is_empty());
+}
+
+#[test]
+fn cli_compares_json_with_markdown() {
+    let dir = std::env::temp_dir().join(format!("revw_diff_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let old = dir.join("old.json");
+    let new = dir.join("new.md");
+    fs::write(&old, r#"{"outside": [{"name": "Rust", "context": "book", "url": "", "percentage": 50}], "inside": []}"#).unwrap();
+    fs::write(&new, "## OUTSIDE\n\n### Rust\nbook\n**Percentage:** 50%\n\n## INSIDE\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).arg("--diff").arg(&old).arg(&new).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No differences\n");
+
+    fs::write(&new, "## OUTSIDE\n\n### Rust\nbook\n**Percentage:** 80%\n\n## INSIDE\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).args(["--diff", "--json"]).arg(&old).arg(&new).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["summary"]["changed"], 1);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).arg("--diff").arg(&old).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("needs two files"));
+    fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::run;
+use revw::app::{App, FormatMode};
+use std::{fs, path::PathBuf};
+
+const WARNING: &str = "File changed on disk (:reload! to load, :diff to compare)";
+
+fn loaded(name: &str) -> (App, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("revw_disk_change_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    fs::write(&path, r#"{"outside": [{"name": "a", "context": "", "url": "", "percentage": null}], "inside": []}"#).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+    // Unsaved change in the buffer
+    app.json_input = app.json_input.replace("\"a\"", "\"buffer\"");
+    app.is_modified = true;
+    (app, path)
+}
+
+#[test]
+fn change_on_disk_warns_until_reloaded() {
+    let (mut app, path) = loaded("warn");
+    // The disk content is still what was loaded: nothing to warn about
+    app.note_disk_change();
+    assert!(!app.disk_changed);
+
+    fs::write(&path, fs::read_to_string(&path).unwrap().replace("\"a\"", "\"disk\"")).unwrap();
+    app.note_disk_change();
+    assert!(app.disk_changed);
+    assert_eq!(app.status_message, WARNING);
+
+    // Other messages pass, then the warning comes back
+    app.set_status("");
+    app.update_status();
+    assert_eq!(app.status_message, WARNING);
+
+    run(&mut app, "reload");
+    assert!(app.json_input.contains("buffer"));
+    assert_eq!(app.status_message, "Unsaved changes (:reload! discards them, :diff compares)");
+
+    run(&mut app, "reload!");
+    assert!(app.json_input.contains("disk"));
+    assert!(!app.disk_changed && !app.is_modified);
+    app.set_status("");
+    app.update_status();
+    assert_eq!(app.status_message, "");
+
+    // The buffer changes are one undo away
+    app.undo();
+    assert!(app.json_input.contains("buffer"));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn diff_compares_disk_with_buffer() {
+    let (mut app, path) = loaded("diff");
+    run(&mut app, "diff");
+    assert!(app.snapshots_open && app.snapshots.is_empty());
+    assert_eq!(app.status_message, "disk → buffer: +1 -1 lines");
+    let rows = app.snapshot_diff_rows();
+    assert!(rows.iter().flatten().any(|line| format!("{:?}", line).contains("buffer")));
+
+    // d has no list to go back to; :reload! closes the panel
+    app.toggle_snapshot_diff();
+    assert!(app.snapshot_diff.is_some());
+    run(&mut app, "reload!");
+    assert!(!app.snapshots_open);
+    run(&mut app, "diff");
+    assert_eq!(app.status_message, "Buffer matches the file on disk");
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use common::{run, view_app};
+use chrono::{Duration, Local, NaiveDate};
+use revw::app::{App, FileMode};
+use revw::convert::Format;
+use revw::due::{self, DueFilter, DueState};
+use revw::json_ops::JsonOperations;
+use revw::markdown_ops::MarkdownOperations;
+use revw::status::EntryStatus;
+use revw::validate;
+use serde_json::{json, Value};
+
+fn date(text: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+}
+
+fn days_from_today(days: i64) -> String {
+    (Local::now().date_naive() + Duration::days(days)).format("%Y-%m-%d").to_string()
+}
+
+fn sample_app() -> App {
+    let json_input = serde_json::to_string_pretty(&json!({
+        "outside": [
+            {"name": "Later", "context": "", "url": "", "percentage": null, "due": days_from_today(30)},
+            {"name": "Undated", "context": "", "url": "", "percentage": null},
+            {"name": "Late", "context": "", "url": "", "percentage": null, "due": days_from_today(-2)},
+            {"name": "Soon", "context": "", "url": "", "percentage": null, "due": days_from_today(1)},
+            {"name": "Late but done", "context": "", "url": "", "percentage": null, "due": days_from_today(-5), "status": "done"}
+        ],
+        "inside": [{"date": "2024-01-01 00:00:00", "context": "note"}]
+    }))
+    .unwrap();
+    view_app(&json_input, FileMode::Json)
+}
+
+fn names(app: &App) -> Vec<String> {
+    app.relf_entries.iter().filter_map(|entry| entry.name.clone()).collect()
+}
+
+#[test]
+fn dates_and_relative_input_parse() {
+    let today = date("2025-03-10");
+    assert_eq!(due::parse("2025-03-12"), Some(date("2025-03-12")));
+    assert_eq!(due::parse(" 2025-03-12 10:00 "), Some(date("2025-03-12")));
+    assert_eq!(due::parse("next week"), None);
+
+    assert_eq!(due::parse_input("today", today), Some(today));
+    assert_eq!(due::parse_input("Tomorrow", today), Some(date("2025-03-11")));
+    assert_eq!(due::parse_input("+3", today), Some(date("2025-03-13")));
+    assert_eq!(due::parse_input("+3d", today), Some(date("2025-03-13")));
+    assert_eq!(due::parse_input("+2w", today), Some(date("2025-03-24")));
+    assert_eq!(due::parse_input("+x", today), None);
+}
+
+#[test]
+fn labels_and_states_follow_days_left() {
+    let today = date("2025-03-10");
+    assert_eq!(due::label(date("2025-03-07"), today), "overdue 3d");
+    assert_eq!(due::label(today, today), "due today");
+    assert_eq!(due::label(date("2025-03-11"), today), "due tomorrow");
+    assert_eq!(due::label(date("2025-03-15"), today), "due in 5d");
+    assert_eq!(due::label(date("2025-04-01"), today), "due 2025-04-01");
+
+    let state = |text: &str, status| DueState::of(date(text), status, today, 3);
+    assert_eq!(state("2025-03-09", EntryStatus::Active), DueState::Overdue);
+    assert_eq!(state("2025-03-13", EntryStatus::Active), DueState::Soon);
+    assert_eq!(state("2025-03-14", EntryStatus::Active), DueState::Later);
+    assert_eq!(state("2025-03-09", EntryStatus::Done), DueState::Later);
+    assert_eq!(DueState::Later.color(), None);
+}
+
+#[test]
+fn filters_compare_days_left() {
+    let today = date("2025-03-10");
+    let within_week = DueFilter::parse("due<=7").unwrap();
+    assert!(within_week.matches(Some(date("2025-03-01")), today));
+    assert!(within_week.matches(Some(date("2025-03-17")), today));
+    assert!(!within_week.matches(Some(date("2025-03-18")), today));
+    assert!(!within_week.matches(None, today));
+    assert!(DueFilter::parse("due<0").unwrap().matches(Some(date("2025-03-09")), today));
+    assert_eq!(DueFilter::parse("due soon"), None);
+}
+
+#[test]
+fn due_filter_and_sort_commands() {
+    let mut app = sample_app();
+    run(&mut app, "due");
+    assert_eq!(names(&app), ["Late", "Soon", "Late but done"]);
+    assert_eq!(app.status_message, "Filter: due<=3 (3 entries)");
+
+    run(&mut app, "due 60");
+    assert_eq!(names(&app), ["Later", "Late", "Soon", "Late but done"]);
+    run(&mut app, "due soon");
+    assert_eq!(app.status_message, "Usage: :due [DAYS]");
+    run(&mut app, "nof");
+
+    run(&mut app, "sort due");
+    assert_eq!(names(&app), ["Late but done", "Late", "Soon", "Later", "Undated"]);
+    assert_eq!(app.status_message, "Ordered by due date");
+}
+
+#[test]
+fn overdue_count_skips_done_entries() {
+    let app = sample_app();
+    assert_eq!(app.overdue_count, 1);
+    assert_eq!(app.relf_entries[2].due, due::parse(&days_from_today(-2)));
+}
+
+#[test]
+fn edit_overlay_saves_relative_due_dates() {
+    let mut app = sample_app();
+    app.selected_entry_index = 1;
+    app.start_editing_entry();
+    let row = app.edit_field_keys.iter().position(|key| key == "due").unwrap();
+    assert!(app.edit_buffer_is_placeholder[row]);
+
+    app.edit_buffer[row] = "+3d".to_string();
+    app.edit_buffer_is_placeholder[row] = false;
+    app.save_edited_entry();
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"][1]["due"], days_from_today(3));
+}
+
+#[test]
+fn markdown_keeps_due_lines() {
+    let md = "## OUTSIDE\n\n### Book\n\n**Priority:** high\n\n**Due:** 2025-03-12\n\n### Site\n\n**due:** 2025-01-01\n";
+    let json = MarkdownOperations::parse_to_json(md).unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["outside"][0]["due"], "2025-03-12");
+    assert_eq!(value["outside"][1]["due"], "2025-01-01");
+
+    let rendered = MarkdownOperations::render_from_json(&value);
+    assert!(rendered.contains("**Priority:** high\n\n**Due:** 2025-03-12"), "{}", rendered);
+
+    let (ordered, message) = MarkdownOperations::order_by_due(md).unwrap();
+    assert_eq!(message, "Ordered by due date");
+    assert!(ordered.find("### Site").unwrap() < ordered.find("### Book").unwrap(), "{}", ordered);
+    assert!(ordered.contains("**Due:** 2025-03-12"));
+
+    let (ordered, _) = JsonOperations::order_by_due(&json).unwrap();
+    let ordered: Value = serde_json::from_str(&ordered).unwrap();
+    assert_eq!(ordered["outside"][0]["name"], "Site");
+}
+
+#[test]
+fn invalid_due_dates_are_reported() {
+    let md = "## OUTSIDE\n\n### Book\n\n**Due:** someday\n";
+    assert_eq!(
+        MarkdownOperations::validate_strict(md).unwrap_err(),
+        "line 5: invalid due date \"someday\""
+    );
+    assert!(MarkdownOperations::validate_strict("## INSIDE\n\n### 2025-01-01\n\n**Due:** 2025-01-02\n").is_err());
+    let issues = validate::check(md, Format::Md);
+    assert_eq!(issues[0].to_string(), "line 5: outside \"Book\": invalid due date \"someday\"");
+
+    let json = r#"{"outside": [{"name": "Book", "due": "2025-13-01"}]}"#;
+    assert!(JsonOperations::validate_strict(json).is_err());
+    assert!(JsonOperations::validate_strict(r#"{"outside": [{"name": "Book", "due": "2025-12-01"}]}"#).is_ok());
+    assert_eq!(validate::check(json, Format::Json)[0].message, "outside[0]: invalid due date \"2025-13-01\"");
+}
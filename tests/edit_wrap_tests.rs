@@ -0,0 +1,116 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::ui::markdown_highlight::{code_blocks, CodeBlock};
+use revw::wrap::{layout_wrapped_text, move_cursor_vertical, move_cursor_vertical_in_lines, row_counts};
+use std::collections::HashMap;
+
+const TEXT: &str = "abcdefgh\n\nij\n日本語のテキスト\nklmnopqrstu\nv";
+
+fn lines(text: &str) -> Vec<String> {
+    text.split('\n').map(str::to_string).collect()
+}
+
+fn large_json_app(entries: usize) -> App {
+    let outside: Vec<String> = (0..entries)
+        .map(|i| format!("    {{\"name\": \"Entry {i}\", \"context\": \"{}\", \"url\": \"\", \"percentage\": null}}", "word ".repeat(i % 30)))
+        .collect();
+    let mut app = App::new(FormatMode::Edit);
+    app.file_mode = FileMode::Json;
+    app.json_input = format!("{{\n  \"outside\": [\n{}\n  ],\n  \"inside\": []\n}}", outside.join(",\n"));
+    app.convert_json();
+    app
+}
+
+fn screen(app: &mut App, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    (0..height).map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect()).collect()
+}
+
+#[test]
+fn line_wise_vertical_moves_match_full_text_layout() {
+    let lines = lines(TEXT);
+    for width in [3, 4, 7] {
+        for line in 0..lines.len() {
+            for col in 0..=lines[line].chars().count() {
+                for delta in [-1, 1] {
+                    let flat = App::cursor_to_flat(&lines, line, col);
+                    let expected = App::flat_to_cursor(&lines, move_cursor_vertical(TEXT, flat, width, delta));
+                    assert_eq!(
+                        move_cursor_vertical_in_lines(&lines, line, col, width, delta),
+                        expected,
+                        "line {line} col {col} width {width} delta {delta}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn row_counts_are_memoized_per_line() {
+    let lines = lines(TEXT);
+    let mut cache = HashMap::new();
+    let counts = row_counts(&lines, 4, &mut cache);
+    assert_eq!(counts.iter().sum::<usize>(), layout_wrapped_text(TEXT, 0, 4).rows.len());
+    assert_eq!(counts, [2, 1, 1, 4, 3, 1]);
+    assert_eq!(cache.len(), 6);
+
+    // Another width is measured again rather than reusing the counts
+    assert_eq!(row_counts(&lines, 20, &mut cache), [1; 6]);
+    // Stale entries are dropped once they outnumber the lines twice over
+    row_counts(&lines, 5, &mut cache);
+    assert_eq!(cache.len(), 18);
+    row_counts(&lines, 6, &mut cache);
+    assert_eq!(cache.len(), 6);
+}
+
+#[test]
+fn code_blocks_cover_bodies_between_fences() {
+    let lines = lines("# Title\n```rust\nfn main() {}\n```\ntext\n```\nopen");
+    assert_eq!(
+        code_blocks(&lines),
+        [
+            CodeBlock { body: 2..3, lang: Some("rust".to_string()) },
+            CodeBlock { body: 6..7, lang: None },
+        ]
+    );
+}
+
+#[test]
+fn large_files_render_only_visible_lines() {
+    let mut app = large_json_app(5000);
+    screen(&mut app, 60, 20);
+    app.content_cursor_line = 4000;
+    app.ensure_cursor_visible();
+    let rows = screen(&mut app, 60, 20);
+
+    assert!(rows.iter().any(|row| row.contains("Entry 3997")), "{:#?}", rows);
+    // Highlights are kept for the lines on screen only
+    assert!(app.edit_highlight_cache.len() <= 20, "{}", app.edit_highlight_cache.len());
+
+    // Editing a line re-wraps it without disturbing the others
+    let before = app.edit_wrap_cache.len();
+    app.json_input = app.json_input.replacen("Entry 3998", "Entry 3998 with a much longer name that wraps", 1);
+    app.convert_json();
+    let rows = screen(&mut app, 60, 20);
+    assert!(rows.iter().any(|row| row.contains("much longer")), "{:#?}", rows);
+    assert_eq!(app.edit_wrap_cache.len(), before + 1);
+}
+
+#[test]
+fn cursor_moves_through_wrapped_rows() {
+    let mut app = large_json_app(3);
+    screen(&mut app, 30, 20);
+    let lines = app.get_content_lines();
+    let wrapped = lines.iter().position(|line| line.contains("Entry 2")).unwrap();
+    app.content_cursor_line = wrapped;
+    app.content_cursor_col = 0;
+
+    app.move_cursor_down();
+    assert_eq!(app.content_cursor_line, wrapped);
+    assert!(app.content_cursor_col > 0);
+    app.move_cursor_up();
+    assert_eq!((app.content_cursor_line, app.content_cursor_col), (wrapped, 0));
+}
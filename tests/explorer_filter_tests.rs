@@ -0,0 +1,65 @@
+mod common;
+
+use common::run;
+use revw::app::{App, FormatMode};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    fs::create_dir_all(dir.join("notes")).unwrap();
+    fs::create_dir_all(dir.join("build")).unwrap();
+    for file in ["a.json", "b.md", "c.toon", "d.txt", ".hidden.md", "build/out.json", "notes/n.md"] {
+        fs::write(dir.join(file), "").unwrap();
+    }
+    fs::write(dir.join(".gitignore"), "build/\n*.log\n").unwrap();
+    fs::write(dir.join("debug.log"), "").unwrap();
+    dir
+}
+
+fn listed(app: &mut App, dir: &Path) -> Vec<String> {
+    app.explorer_current_dir = dir.to_path_buf();
+    app.load_explorer_entries();
+    app.explorer_entries
+        .iter()
+        .map(|entry| entry.path.strip_prefix(dir).unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn supported_files_only_by_default() {
+    let dir = temp_dir("default");
+    let mut app = App::new(FormatMode::View);
+    assert_eq!(listed(&mut app, &dir), ["notes", "a.json", "b.md", "c.toon"]);
+}
+
+#[test]
+fn show_all_toggle_lists_everything() {
+    let dir = temp_dir("all");
+    let mut app = App::new(FormatMode::View);
+    app.explorer_show_all = true;
+    assert_eq!(
+        listed(&mut app, &dir),
+        ["build", "notes", ".gitignore", ".hidden.md", "a.json", "b.md", "c.toon", "d.txt", "debug.log"]
+    );
+}
+
+#[test]
+fn each_filter_can_be_switched_off() {
+    let dir = temp_dir("options");
+    let mut app = App::new(FormatMode::View);
+    run(&mut app, "set noexplorer_hide_ignored");
+    assert_eq!(app.status_message, "explorer_hide_ignored disabled");
+    assert_eq!(listed(&mut app, &dir), ["build", "notes", "a.json", "b.md", "c.toon"]);
+
+    run(&mut app, "set explorer_hide_ignored");
+    run(&mut app, "set noexplorer_hide_dotfiles");
+    assert_eq!(listed(&mut app, &dir), ["notes", ".hidden.md", "a.json", "b.md", "c.toon"]);
+
+    run(&mut app, "set explorer_hide_dotfiles");
+    run(&mut app, "set noexplorer_supported_only");
+    assert_eq!(listed(&mut app, &dir), ["notes", "a.json", "b.md", "c.toon", "d.txt"]);
+
+    run(&mut app, "set explorer_sideways");
+    assert_eq!(app.status_message, "Unknown explorer option: explorer_sideways");
+}
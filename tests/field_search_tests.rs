@@ -0,0 +1,111 @@
+mod common;
+
+use common::view_app;
+use revw::app::{App, FileMode};
+use revw::json_ops::JsonOperations;
+use revw::search::{SearchField, SearchQuery, SearchTerm, Searchable};
+use serde_json::Value;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust parser", "context": "nom combinators", "url": "https://github.com/rust-bakery/nom", "percentage": 40},
+    {"name": "Go tour", "context": "rust comparison, parser notes", "url": "https://go.dev/tour", "percentage": null, "author": "Rob"}
+  ],
+  "inside": [
+    {"date": "2025-03-02 09:00:00", "context": "wrote a parser in rust"},
+    {"date": "2025-04-01 10:00:00", "context": "parser again"}
+  ]
+}"#;
+
+fn names(app: &App) -> Vec<String> {
+    app.relf_entries
+        .iter()
+        .map(|entry| entry.name.clone().or_else(|| entry.date.clone()).unwrap_or_default())
+        .collect()
+}
+
+#[test]
+fn only_queries_with_a_field_prefix_are_parsed() {
+    assert_eq!(SearchQuery::parse("rust parser"), None);
+    assert_eq!(SearchQuery::parse("https://github.com"), None);
+
+    let query = SearchQuery::parse(r#"Name:"Rust Book" ctx:parser docs"#).unwrap();
+    assert_eq!(
+        query.terms,
+        vec![
+            SearchTerm { field: Some(SearchField::Name), text: "rust book".to_string() },
+            SearchTerm { field: Some(SearchField::Context), text: "parser".to_string() },
+            SearchTerm { field: None, text: "docs".to_string() },
+        ]
+    );
+}
+
+#[test]
+fn every_term_has_to_match_its_field() {
+    let query = SearchQuery::parse("name:rust url:github").unwrap();
+    let entry = Searchable {
+        name: "Rust parser".to_string(),
+        url: "https://github.com/x".to_string(),
+        ..Default::default()
+    };
+    assert!(query.matches(&entry));
+    assert!(!query.matches(&Searchable { url: "https://go.dev".to_string(), ..entry.clone() }));
+
+    // Unscoped terms also look at custom fields
+    let query = SearchQuery { terms: vec![SearchTerm { field: None, text: "rob".to_string() }] };
+    let entry = Searchable { other: vec!["author: Rob".to_string()], ..Default::default() };
+    assert!(query.matches(&entry));
+}
+
+#[test]
+fn filter_limits_terms_to_fields() {
+    let mut app = view_app(JSON, FileMode::Json);
+    // Plain text matches anywhere, as before
+    app.apply_filter("rust".to_string());
+    assert_eq!(names(&app), vec!["Rust parser", "Go tour", "2025-03-02 09:00:00"]);
+
+    app.apply_filter("name:rust".to_string());
+    assert_eq!(names(&app), vec!["Rust parser"]);
+
+    app.apply_filter("ctx:parser rust".to_string());
+    assert_eq!(names(&app), vec!["Go tour", "2025-03-02 09:00:00"]);
+
+    app.apply_filter("url:github ctx:nom".to_string());
+    assert_eq!(names(&app), vec!["Rust parser"]);
+
+    app.apply_filter("date:2025-03".to_string());
+    assert_eq!(names(&app), vec!["2025-03-02 09:00:00"]);
+}
+
+#[test]
+fn search_only_matches_scoped_fields() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.search_buffer = "url:github".to_string();
+    app.execute_search();
+    assert_eq!(app.search_matches, vec![(0, 8)]);
+    assert_eq!(app.status_message, "match 1/1 for 'url:github'");
+
+    // `rust` in the Go tour context does not count for a name: term
+    app.search_buffer = "name:rust".to_string();
+    app.execute_search();
+    assert_eq!(app.search_matches, vec![(0, 0)]);
+
+    app.search_buffer = "ctx:parser rust".to_string();
+    app.execute_search();
+    let entries: Vec<usize> = app.search_matches.iter().map(|&(entry, _)| entry).collect();
+    assert_eq!(entries, vec![1, 1, 2, 2]);
+
+    app.open_match_list();
+    assert_eq!(app.match_list_items().len(), app.search_matches.len());
+    assert_eq!(app.search_highlight_texts(Some(SearchField::Name)), vec!["rust"]);
+    assert_eq!(app.search_highlight_texts(Some(SearchField::Context)), vec!["parser", "rust"]);
+}
+
+#[test]
+fn cli_filter_understands_field_queries() {
+    let value: Value = serde_json::from_str(JSON).unwrap();
+    let filtered = JsonOperations::filter_entries(&value, "url:go.dev");
+    assert_eq!(filtered["outside"].as_array().unwrap().len(), 1);
+    assert_eq!(filtered["outside"][0]["name"], "Go tour");
+    assert_eq!(filtered["inside"].as_array().unwrap().len(), 0);
+}
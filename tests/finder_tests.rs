@@ -0,0 +1,100 @@
+use revw::app::{App, FormatMode};
+use revw::fuzzy;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn tree(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("revw_finder_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    for sub in ["projects/rust/notes", "archive", "node_modules/pkg"] {
+        fs::create_dir_all(dir.join(sub)).unwrap();
+    }
+    for file in ["projects/rust/notes/reading.md", "projects/rust/todo.json", "archive/old_reading.md", "archive/image.png", "node_modules/pkg/readme.md"] {
+        fs::write(dir.join(file), "").unwrap();
+    }
+    fs::write(dir.join(".gitignore"), "node_modules/\n").unwrap();
+    dir
+}
+
+/// Poll like the event loop does until the index is complete
+fn wait_for_index(app: &mut App) {
+    let started = Instant::now();
+    while !app.finder_index.as_ref().unwrap().done {
+        assert!(started.elapsed() < Duration::from_secs(10), "index did not finish");
+        app.poll_finder_index();
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn matched_paths(app: &App) -> Vec<String> {
+    let index = app.finder_index.as_ref().unwrap();
+    app.finder_matches.iter().map(|(file, _)| index.files[*file].clone()).collect()
+}
+
+#[test]
+fn characters_must_appear_in_order() {
+    assert!(fuzzy::score("rdm", "reading.md").is_some());
+    assert!(fuzzy::score("RDM", "reading.md").is_some());
+    assert!(fuzzy::score("mdr", "reading.md").is_none());
+    assert_eq!(fuzzy::score("", "anything"), Some((0, vec![])));
+}
+
+#[test]
+fn consecutive_and_boundary_matches_rank_first() {
+    let candidates = vec!["src/treading.md".to_string(), "src/reading.md".to_string(), "r/e/a/d/i/n/g.md".to_string()];
+    let ranked = fuzzy::rank("reading", &candidates);
+    assert_eq!(ranked[0].0, 1);
+    assert_eq!(ranked[0].1, vec![4, 5, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+fn shorter_paths_win_ties() {
+    let candidates = vec!["notes/long/todo.json".to_string(), "notes/todo.json".to_string()];
+    assert_eq!(fuzzy::rank("todo", &candidates)[0].0, 1);
+}
+
+#[test]
+fn finder_indexes_the_tree_and_opens_the_selection() {
+    let dir = tree("open");
+    let mut app = App::new(FormatMode::View);
+    app.explorer_current_dir = dir.clone();
+    app.command_buffer = "find".to_string();
+    app.execute_command();
+    assert!(app.finder_open);
+    wait_for_index(&mut app);
+
+    // Ignored directories and unsupported files are left out, as in the explorer
+    let mut all = matched_paths(&app);
+    all.sort();
+    assert_eq!(all, ["archive/old_reading.md", "projects/rust/notes/reading.md", "projects/rust/todo.json"]);
+
+    for c in "prjreading".chars() {
+        app.finder_push_char(c);
+    }
+    assert_eq!(matched_paths(&app), ["projects/rust/notes/reading.md"]);
+    app.finder_backspace();
+    app.finder_clear_query();
+    for c in "todo".chars() {
+        app.finder_push_char(c);
+    }
+    app.open_selected_finder_file();
+    assert!(!app.finder_open);
+    assert_eq!(app.file_path, Some(dir.join("projects/rust/todo.json")));
+}
+
+#[test]
+fn find_takes_an_initial_query() {
+    let dir = tree("query");
+    let mut app = App::new(FormatMode::View);
+    app.explorer_current_dir = dir;
+    app.command_buffer = "find old".to_string();
+    app.execute_command();
+    wait_for_index(&mut app);
+    assert_eq!(app.finder_query, "old");
+    assert_eq!(matched_paths(&app), ["archive/old_reading.md"]);
+
+    app.close_finder();
+    assert!(app.finder_index.is_none());
+}
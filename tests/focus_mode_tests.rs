@@ -0,0 +1,85 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::keymap::KeyContext;
+
+fn long_app() -> App {
+    let long_context: Vec<String> = (1..=60).map(|i| format!("line {}", i)).collect();
+    let json = serde_json::json!({
+        "outside": [
+            {"name": "First", "context": "short", "url": "", "percentage": null},
+            {"name": "Second", "context": long_context.join("\n"), "url": "", "percentage": null},
+            {"name": "Third", "context": "short too", "url": "", "percentage": null}
+        ],
+        "inside": []
+    });
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = serde_json::to_string_pretty(&json).unwrap();
+    app.convert_json();
+    app.selected_entry_index = 1;
+    app
+}
+
+fn screen_text(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn focus_mode_shows_only_selected_card() {
+    let mut app = long_app();
+    let screen = screen_text(&mut app);
+    assert!(screen.contains("First"));
+
+    app.toggle_focus_mode();
+    assert!(app.focus_mode);
+    assert_eq!(app.key_context(), KeyContext::Focus);
+    let screen = screen_text(&mut app);
+    assert!(screen.contains("Second"));
+    assert!(!screen.contains("First"));
+    assert!(!screen.contains("Third"));
+    assert!(screen.contains("focus: card 2 of 3"));
+    // The card uses the whole content area: far more than a third of the context is visible
+    assert!(screen.contains("line 20"));
+
+    app.toggle_focus_mode();
+    assert!(!app.focus_mode);
+    assert!(screen_text(&mut app).contains("First"));
+}
+
+#[test]
+fn focus_mode_scrolls_within_card_and_switches_cards() {
+    let mut app = long_app();
+    app.toggle_focus_mode();
+    screen_text(&mut app);
+
+    app.focus_scroll(5);
+    assert_eq!(app.hscroll, 5);
+    let screen = screen_text(&mut app);
+    assert!(screen.contains("line 6"));
+    assert!(!screen.contains("line 5 "));
+
+    // Scrolling stops at the end of the card
+    app.focus_scroll(1000);
+    let max = app.hscroll;
+    let screen = screen_text(&mut app);
+    assert!(screen.contains("line 60"));
+    app.focus_scroll(1);
+    assert_eq!(app.hscroll, max);
+
+    app.focus_next_card(true);
+    assert_eq!(app.selected_entry_index, 2);
+    assert_eq!(app.hscroll, 0);
+    assert!(screen_text(&mut app).contains("Third"));
+    app.focus_next_card(true);
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn focus_mode_needs_cards() {
+    let mut app = App::new(FormatMode::Edit);
+    app.toggle_focus_mode();
+    assert!(!app.focus_mode);
+    assert_eq!(app.status_message, "Not in card view mode");
+}
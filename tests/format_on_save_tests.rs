@@ -0,0 +1,64 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+
+#[test]
+fn canonical_orders_sections_and_fields() {
+    let json = r#"{"inside": [{"context": "note", "date": "2025-01-01 00:00:00"}], "outside": [{"percentage": 50, "url": null, "status": "reading", "context": "c", "name": "A"}]}"#;
+    let expected = r#"{
+  "outside": [
+    {
+      "name": "A",
+      "context": "c",
+      "url": null,
+      "percentage": 50,
+      "status": "reading"
+    }
+  ],
+  "inside": [
+    {
+      "date": "2025-01-01 00:00:00",
+      "context": "note"
+    }
+  ]
+}
+"#;
+    assert_eq!(JsonOperations::to_canonical(json).unwrap(), expected);
+}
+
+#[test]
+fn canonical_is_idempotent_and_keeps_custom_field_order() {
+    let json = r#"{"outside": [{"zeta": 1, "name": "A", "alpha": 2}], "inside": [], "misc": true}"#;
+    let once = JsonOperations::to_canonical(json).unwrap();
+    assert_eq!(JsonOperations::to_canonical(&once).unwrap(), once);
+
+    let zeta = once.find("\"zeta\"").unwrap();
+    let alpha = once.find("\"alpha\"").unwrap();
+    assert!(once.find("\"name\"").unwrap() < zeta && zeta < alpha);
+    assert!(once.find("\"inside\"").unwrap() < once.find("\"misc\"").unwrap());
+    assert!(JsonOperations::to_canonical("{not json").is_err());
+}
+
+#[test]
+fn save_file_formats_only_when_enabled() {
+    let path = std::env::temp_dir().join(format!("revw_format_on_save_{}.json", std::process::id()));
+    let json = r#"{"outside": [{"url": null, "name": "a"}], "inside": []}"#;
+
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = json.to_string();
+    app.file_path = Some(path.clone());
+
+    app.save_file();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), json);
+
+    app.command_buffer = "set format_on_save".to_string();
+    app.execute_command();
+    assert!(app.format_on_save);
+    app.save_file();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(saved, JsonOperations::to_canonical(json).unwrap());
+    assert!(saved.ends_with("]\n}\n"));
+    assert_eq!(app.json_input, saved);
+
+    std::fs::remove_file(&path).ok();
+}
@@ -0,0 +1,71 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::graphemes;
+use revw::rendering::Renderer;
+use revw::wrap::layout_wrapped_text;
+
+// "a", thumbs up with a skin tone (2 chars), "e" with a combining accent (2 chars), "b"
+const TEXT: &str = "a👍🏽e\u{301}b";
+
+#[test]
+fn boundaries_step_over_whole_clusters() {
+    assert_eq!(graphemes::next_boundary(TEXT, 1), 3);
+    assert_eq!(graphemes::next_boundary(TEXT, 3), 5);
+    assert_eq!(graphemes::next_boundary(TEXT, 6), 6);
+    assert_eq!(graphemes::prev_boundary(TEXT, 5), 3);
+    assert_eq!(graphemes::prev_boundary(TEXT, 3), 1);
+    assert_eq!(graphemes::prev_boundary(TEXT, 0), 0);
+    assert_eq!(graphemes::remove_chars(TEXT, 1, 3), "ae\u{301}b");
+}
+
+#[test]
+fn widths_follow_what_the_terminal_shows() {
+    assert_eq!(Renderer::display_width_str(TEXT), 5);
+    assert_eq!(Renderer::display_width_str("👨\u{200d}👩\u{200d}👧"), 2);
+    assert_eq!(Renderer::display_width_str("🇯🇵 日本"), 7);
+    assert_eq!(Renderer::prefix_display_width(TEXT, 3), 3);
+    // Slicing never cuts a cluster in half
+    assert_eq!(Renderer::slice_columns(TEXT, 1, 3), "👍🏽e\u{301}");
+}
+
+#[test]
+fn wrapping_keeps_clusters_on_one_row() {
+    let layout = layout_wrapped_text(TEXT, 3, 2);
+    let rows: Vec<&str> = layout.rows.iter().map(|row| row.text.as_str()).collect();
+    assert_eq!(rows, ["a", "👍🏽", "e\u{301}b"]);
+    assert_eq!(layout.rows[2].start_pos, 3);
+    assert_eq!((layout.cursor.visual_row, layout.cursor.visual_col), (2, 0));
+}
+
+#[test]
+fn edit_mode_moves_and_deletes_by_cluster() {
+    let mut app = App::new(FormatMode::Edit);
+    app.file_mode = FileMode::Markdown;
+    app.markdown_input = TEXT.to_string();
+    app.content_cursor_line = 0;
+    app.content_cursor_col = 1;
+
+    app.move_cursor_right();
+    assert_eq!(app.content_cursor_col, 3);
+    app.move_cursor_right();
+    assert_eq!(app.content_cursor_col, 5);
+    app.move_cursor_left();
+    assert_eq!(app.content_cursor_col, 3);
+
+    app.backspace();
+    assert_eq!(app.get_content_lines()[0], "ae\u{301}b");
+    assert_eq!(app.content_cursor_col, 1);
+    app.delete_char();
+    assert_eq!(app.get_content_lines()[0], "ab");
+}
+
+#[test]
+fn overlay_cursor_steps_over_clusters() {
+    let mut app = App::new(FormatMode::View);
+    app.edit_buffer = vec![TEXT.to_string()];
+    app.edit_field_index = 0;
+    app.edit_cursor_pos = 3;
+    assert_eq!(app.edit_prev_boundary(), 1);
+    assert_eq!(app.edit_next_boundary(), 5);
+    app.edit_cursor_pos = 6;
+    assert_eq!(app.edit_next_boundary(), 6);
+}
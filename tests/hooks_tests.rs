@@ -0,0 +1,162 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::hooks::{HookEvent, Hooks};
+use std::{
+    fs,
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+const JSON: &str = "{\n  \"outside\": [],\n  \"inside\": []\n}";
+
+#[test]
+fn hook_gets_file_and_format() {
+    let dir = temp_dir("args");
+    let file = dir.join("my notes.json");
+    let mut hooks = Hooks::default();
+    hooks.add(HookEvent::PostSave, "printf '%s|%s|{format}' \"$1\" \"$2\" > {file}.out");
+
+    assert_eq!(hooks.run(HookEvent::PostSave, &file, "json"), Ok(1));
+    let out = fs::read_to_string(dir.join("my notes.json.out")).unwrap();
+    assert_eq!(out, format!("{}|json|json", file.display()));
+    // Other events have nothing to run
+    assert_eq!(hooks.run(HookEvent::PreSave, &file, "json"), Ok(0));
+}
+
+#[test]
+fn failing_hook_reports_its_last_error_line() {
+    let mut hooks = Hooks::default();
+    hooks.add(HookEvent::PreSave, "echo first >&2; echo 'not allowed' >&2; exit 3");
+    hooks.add(HookEvent::PreSave, "touch never-runs");
+    assert_eq!(
+        hooks.run(HookEvent::PreSave, &PathBuf::from("notes.md"), "md"),
+        Err("pre_save hook failed (3): not allowed".to_string())
+    );
+}
+
+#[test]
+fn slow_hook_is_killed_after_the_timeout() {
+    let mut hooks = Hooks::default();
+    hooks.timeout = Duration::from_secs(1);
+    hooks.add(HookEvent::PostSave, "sleep 5");
+    let started = Instant::now();
+    assert_eq!(
+        hooks.run(HookEvent::PostSave, &PathBuf::from("notes.md"), "md"),
+        Err("post_save hook timed out after 1s".to_string())
+    );
+    assert!(started.elapsed() < Duration::from_secs(4));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn timeout_also_kills_what_the_hook_started() {
+    let dir = temp_dir("group");
+    let pid_file = dir.join("pid");
+    let mut hooks = Hooks::default();
+    hooks.timeout = Duration::from_secs(1);
+    hooks.add(HookEvent::PostSave, &format!("sh -c 'echo $$ > {}; exec sleep 30' & sleep 5", pid_file.display()));
+    assert!(hooks.run(HookEvent::PostSave, &dir.join("notes.md"), "md").unwrap_err().contains("timed out"));
+
+    let pid = fs::read_to_string(&pid_file).unwrap().trim().to_string();
+    std::thread::sleep(Duration::from_millis(200));
+    // Gone, or a zombie waiting to be reaped
+    let alive = fs::read_to_string(format!("/proc/{}/stat", pid)).is_ok_and(|stat| !stat.contains(") Z "));
+    assert!(!alive, "sleep {} survived the timeout", pid);
+}
+
+#[test]
+fn background_process_holding_stderr_does_not_stall_a_failed_hook() {
+    let mut hooks = Hooks::default();
+    hooks.add(HookEvent::PreSave, "sleep 5 & echo broken >&2; exit 2");
+    let started = Instant::now();
+    assert_eq!(
+        hooks.run(HookEvent::PreSave, &PathBuf::from("notes.md"), "md"),
+        Err("pre_save hook failed (2): broken".to_string())
+    );
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn pre_save_hook_can_cancel_the_write() {
+    let dir = temp_dir("pre_save");
+    let path = dir.join("notes.json");
+    fs::write(&path, JSON).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+
+    app.hooks.add(HookEvent::PreSave, "echo locked >&2; exit 1");
+    app.json_input = JSON.replace("\"outside\": []", "\"outside\": [{\"name\": \"New\"}]");
+    app.is_modified = true;
+    app.save_file();
+    assert_eq!(app.status_message, "Not saved: pre_save hook failed (1): locked");
+    assert_eq!(fs::read_to_string(&path).unwrap(), JSON);
+    assert!(app.is_modified);
+}
+
+#[test]
+fn post_save_hook_runs_after_the_write() {
+    let dir = temp_dir("post_save");
+    let path = dir.join("notes.json");
+    fs::write(&path, JSON).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+
+    // The hook sees the file as written
+    app.hooks.add(HookEvent::PostSave, "grep -c New {file} > {file}.count");
+    app.json_input = JSON.replace("\"outside\": []", "\"outside\": [{\"name\": \"New\"}]");
+    app.is_modified = true;
+    app.save_file();
+    // The hook runs on a worker thread; the save itself is already done
+    assert_eq!(app.status_message, format!("Saved: {} (running 1 post_save hook)", path.display()));
+    assert!(app.hook_task.is_some());
+    app.finish_hooks();
+    assert_eq!(app.status_message, format!("Saved: {} (ran 1 post_save hook)", path.display()));
+    assert_eq!(fs::read_to_string(dir.join("notes.json.count")).unwrap().trim(), "1");
+}
+
+#[test]
+fn autosave_runs_pre_save_hooks_off_the_ui_thread() {
+    let dir = temp_dir("autosave");
+    let path = dir.join("notes.json");
+    fs::write(&path, JSON).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+
+    app.hooks.add(HookEvent::PreSave, "sleep 1");
+    app.json_input = JSON.replace("\"outside\": []", "\"outside\": [{\"name\": \"New\"}]");
+    app.is_modified = true;
+    let started = Instant::now();
+    app.autosave();
+    assert!(started.elapsed() < Duration::from_millis(500));
+    assert!(app.hook_task.is_some());
+    assert_eq!(fs::read_to_string(&path).unwrap(), JSON);
+
+    // Written once the hook has passed
+    app.finish_hooks();
+    assert!(fs::read_to_string(&path).unwrap().contains("New"));
+    assert!(!app.is_modified);
+}
+
+#[test]
+fn post_import_hook_runs_after_append() {
+    let home = temp_dir("post_import");
+    fs::write(home.join(".revwrc"), "hook post_import echo {format} > {file}.imported\n").unwrap();
+    let target = home.join("notes.md");
+    fs::write(&target, "## OUTSIDE\n\n### Old\n").unwrap();
+
+    fs::write(home.join("new.json"), r#"{"outside": [{"name": "New"}]}"#).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .env("HOME", &home)
+        .args(["--append", "--input"])
+        .arg(home.join("new.json"))
+        .arg(&target)
+        .output()
+        .expect("failed to execute revw");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(home.join("notes.md.imported")).unwrap().trim(), "md");
+}
@@ -0,0 +1,68 @@
+use revw::app::{App, FormatMode};
+use revw::i18n::{self, Lang, Msg};
+
+#[test]
+fn messages_translate_and_fill_their_placeholders() {
+    assert_eq!(i18n::text(Lang::Ja, Msg::Undo), "元に戻しました");
+    assert_eq!(i18n::format(Lang::Ja, Msg::Saved, &[&"notes.md"]), "保存しました: notes.md");
+    assert_eq!(i18n::format(Lang::Ja, Msg::FilterCount, &[&"rust", &3]), "フィルタ: rust (3 件)");
+    assert_eq!(i18n::format(Lang::En, Msg::MatchPosition, &[&2, &5, &"rust"]), "match 2/5 for 'rust'");
+    // English, and Auto until it is resolved, use the English text
+    assert_eq!(i18n::text(Lang::En, Msg::Undo), "Undo");
+    assert_eq!(i18n::text(Lang::Auto, Msg::Undo), "Undo");
+}
+
+#[test]
+fn every_message_has_a_japanese_entry_with_the_same_placeholders() {
+    for &msg in Msg::ALL {
+        assert!(i18n::has_translation(Lang::Ja, msg), "{:?} has no Japanese entry", msg);
+        assert_eq!(
+            i18n::text(Lang::Ja, msg).matches("{}").count(),
+            msg.english().matches("{}").count(),
+            "{:?} placeholders differ",
+            msg
+        );
+    }
+}
+
+#[test]
+fn languages_by_name_and_locale() {
+    assert_eq!(Lang::by_name("ja_JP.UTF-8"), Some(Lang::Ja));
+    assert_eq!(Lang::by_name("en_US"), Some(Lang::En));
+    assert_eq!(Lang::by_name("C"), Some(Lang::En));
+    assert_eq!(Lang::by_name("auto"), Some(Lang::Auto));
+    assert_eq!(Lang::by_name("fr_FR"), None);
+    assert_eq!(Lang::Ja.resolve(), Lang::Ja);
+}
+
+#[test]
+fn set_lang_switches_help_and_status_language() {
+    let mut app = App::new(FormatMode::View);
+    app.command_buffer = "set lang=ja".to_string();
+    app.execute_command();
+    assert_eq!(app.lang, Lang::Ja);
+    // Messages are translated where they are set
+    assert_eq!(app.status_message, "言語を ja に設定しました");
+    app.start_field_edit("name");
+    assert_eq!(app.status_message, "エントリが選択されていません");
+
+    app.show_help();
+    assert!(app.rendered_content.iter().any(|line| line == "コマンドラインでの使い方"));
+
+    app.command_buffer = "set lang=xx".to_string();
+    app.execute_command();
+    assert_eq!(app.lang, Lang::Ja);
+    assert_eq!(app.status_message, "Language must be en, ja or auto");
+}
+
+#[test]
+fn edit_mode_duplicate_reports_in_the_chosen_language() {
+    let mut app = App::new(FormatMode::Edit);
+    app.lang = Lang::Ja;
+    app.json_input = "{\n  \"outside\": [\n    {\n      \"name\": \"a\"\n    }\n  ],\n  \"inside\": []\n}".to_string();
+    app.convert_json();
+    app.content_cursor_line = 4;
+    app.duplicate_selected_entry();
+    assert_eq!(app.json_input.matches("\"a\"").count(), 2);
+    assert_eq!(app.status_message, "エントリを複製しました");
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::value;
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "", "percentage": 10},
+    {"name": "B", "context": "", "url": "", "percentage": 20}
+  ],
+  "inside": [
+    {"date": "2024-01-02", "context": "second"},
+    {"date": "2024-01-01", "context": "first"}
+  ]
+}"#;
+
+fn card_app(selected: usize) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.selected_entry_index = selected;
+    app
+}
+
+#[test]
+fn insert_entry_places_blank_entry_in_same_section() {
+    let (formatted, index) = JsonOperations::insert_entry(JSON, Some(0), true).unwrap();
+    assert_eq!(index, 1);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"].as_array().unwrap().len(), 3);
+    assert_eq!(doc["outside"][1], json!({"name": "", "context": "", "url": "", "percentage": null}));
+    assert_eq!(doc["outside"][2]["name"], json!("B"));
+
+    // Before the first INSIDE entry stays in INSIDE
+    let (formatted, index) = JsonOperations::insert_entry(JSON, Some(2), false).unwrap();
+    assert_eq!(index, 2);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"].as_array().unwrap().len(), 2);
+    assert_eq!(doc["inside"].as_array().unwrap().len(), 3);
+    assert_eq!(doc["inside"][0]["context"], json!(""));
+    assert!(doc["inside"][0]["date"].as_str().is_some_and(|d| !d.is_empty()));
+
+    // After the last OUTSIDE entry
+    let (formatted, index) = JsonOperations::insert_entry(JSON, Some(1), true).unwrap();
+    assert_eq!(index, 2);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"][2]["name"], json!(""));
+    assert_eq!(doc["inside"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn insert_entry_without_cards_appends_outside() {
+    let (formatted, index) = JsonOperations::insert_entry("", None, true).unwrap();
+    assert_eq!(index, 0);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn o_opens_overlay_on_new_card_after_selection() {
+    let mut app = card_app(0);
+    app.insert_card(true);
+
+    assert!(app.editing_entry);
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.edit_field_keys[0], "name");
+    assert!(app.edit_buffer_is_placeholder[0]);
+    assert_eq!(value(&app)["outside"].as_array().unwrap().len(), 3);
+
+    app.edit_buffer[0] = "New".to_string();
+    app.edit_buffer_is_placeholder[0] = false;
+    app.save_edited_entry();
+    let doc = value(&app);
+    assert_eq!(doc["outside"][1]["name"], json!("New"));
+    assert_eq!(doc["outside"][2]["name"], json!("B"));
+    assert!(app.editing_new_entry.is_none());
+}
+
+#[test]
+fn shift_o_inserts_before_inside_card() {
+    let mut app = card_app(3);
+    app.insert_card(false);
+
+    assert!(app.editing_entry);
+    assert_eq!(app.selected_entry_index, 3);
+    assert_eq!(app.edit_field_keys, vec!["date".to_string(), "context".to_string()]);
+    let doc = value(&app);
+    assert_eq!(doc["inside"].as_array().unwrap().len(), 3);
+    assert_eq!(doc["inside"][1]["context"], json!(""));
+    assert_eq!(doc["inside"][2]["context"], json!("first"));
+}
+
+#[test]
+fn cancelling_overlay_discards_new_card() {
+    let mut app = card_app(1);
+    let undo_depth = app.undo_stack.len();
+    app.insert_card(true);
+    app.cancel_editing_entry();
+
+    assert!(!app.editing_entry);
+    assert_eq!(value(&app), serde_json::from_str::<Value>(JSON).unwrap());
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.undo_stack.len(), undo_depth);
+    assert!(app.redo_stack.is_empty());
+    assert_eq!(app.status_message, "New entry discarded");
+}
+
+#[test]
+fn new_card_clears_filter_that_would_hide_it() {
+    let mut app = card_app(0);
+    app.apply_filter("A".to_string());
+    assert_eq!(app.relf_entries.len(), 1);
+
+    app.insert_card(true);
+    assert!(app.filter_pattern.is_empty());
+    assert!(app.editing_entry);
+    assert_eq!(app.relf_entries[app.selected_entry_index].original_index, 1);
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::{run, view_app};
+use revw::app::{App, FileMode};
+use revw::config::InsideOrder;
+use serde_json::json;
+
+fn sample_app() -> App {
+    let json_input = serde_json::to_string_pretty(&json!({
+        "outside": [{"name": "Book", "context": "", "url": "", "percentage": null}],
+        "inside": [
+            {"date": "2025-01-01 09:00:00", "context": "first"},
+            {"date": "2025-03-01 09:00:00", "context": "third"},
+            {"date": "2025-02-01 09:00:00", "context": "second"}
+        ]
+    }))
+    .unwrap();
+    view_app(&json_input, FileMode::Json)
+}
+
+fn contexts(app: &App) -> Vec<String> {
+    app.relf_entries.iter().map(|entry| entry.context.clone().unwrap_or_default()).collect()
+}
+
+#[test]
+fn newest_first_sorts_inside_cards_by_date_without_touching_the_file() {
+    let mut app = sample_app();
+    assert_eq!(contexts(&app), ["", "first", "third", "second"]);
+    let before = app.json_input.clone();
+
+    run(&mut app, "set inside_order=newest_first");
+    assert_eq!(app.inside_order, InsideOrder::NewestFirst);
+    assert_eq!(app.status_message, "INSIDE order set to newest_first");
+    assert_eq!(contexts(&app), ["", "third", "second", "first"]);
+    assert_eq!(app.json_input, before);
+    assert!(!app.is_modified);
+
+    run(&mut app, "set inside_order=file_order");
+    assert_eq!(contexts(&app), ["", "first", "third", "second"]);
+}
+
+#[test]
+fn the_selected_card_stays_selected_when_the_order_changes() {
+    let mut app = sample_app();
+    app.selected_entry_index = 1; // "first"
+    run(&mut app, "set inside_order=newest_first");
+    assert_eq!(app.selected_entry_index, 3);
+    assert_eq!(app.relf_entries[3].context.as_deref(), Some("first"));
+}
+
+#[test]
+fn unknown_orders_are_refused() {
+    let mut app = sample_app();
+    run(&mut app, "set inside_order=sideways");
+    assert_eq!(app.inside_order, InsideOrder::FileOrder);
+    assert_eq!(app.status_message, "INSIDE order must be newest_first or file_order");
+}
+
+#[test]
+fn inside_cards_do_not_move_while_shown_newest_first() {
+    let mut app = sample_app();
+    run(&mut app, "set inside_order=newest_first");
+    let before = app.json_input.clone();
+    app.selected_entry_index = 2;
+    app.move_selected_card(1);
+    assert_eq!(app.json_input, before);
+    assert!(app.status_message.contains("newest first"));
+}
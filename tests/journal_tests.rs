@@ -0,0 +1,138 @@
+mod common;
+
+use common::run;
+use revw::app::{App, FileMode, FormatMode};
+use revw::journal;
+use revw::markdown_ops::MarkdownOperations;
+use serde_json::Value;
+
+const JSON: &str = r#"{
+  "journal": true,
+  "outside": [
+    {"name": "Book", "context": "", "url": "", "percentage": 40}
+  ],
+  "inside": [
+    {"date": "2024-01-02 00:00:00", "context": "second"},
+    {"date": "2024-01-01 00:00:00", "context": "first"}
+  ]
+}"#;
+
+const MD: &str = "---\njournal: true\n---\n\n## OUTSIDE\n\n### Book\n\n## INSIDE\n\n### 2024-01-01 00:00:00\nfirst\n";
+
+fn journal_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.record_journal_history();
+    app
+}
+
+fn inside_contexts(app: &App) -> Vec<String> {
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    journal::inside_entries(&value)
+        .iter()
+        .map(|entry| entry["context"].as_str().unwrap_or("").to_string())
+        .collect()
+}
+
+#[test]
+fn past_inside_entries_cannot_be_edited() {
+    let mut app = journal_app();
+    assert!(app.is_journal());
+
+    app.selected_entry_index = 1;
+    app.start_editing_entry();
+    assert!(!app.editing_entry);
+    assert_eq!(app.status_message, "Journal: past INSIDE entries are read-only");
+
+    // OUTSIDE entries stay editable
+    app.selected_entry_index = 0;
+    app.start_editing_entry();
+    assert!(app.editing_entry);
+}
+
+#[test]
+fn deleting_history_is_rolled_back() {
+    let mut app = journal_app();
+    app.selected_entry_index = 2;
+    app.delete_cards_forward(1);
+    assert_eq!(inside_contexts(&app), ["second"]);
+
+    app.enforce_journal();
+    assert_eq!(inside_contexts(&app), ["second", "first"]);
+    assert_eq!(app.status_message, "Journal: past INSIDE entries are read-only");
+    // The refused change leaves nothing to undo
+    assert!(app.undo_stack.is_empty());
+}
+
+#[test]
+fn new_notes_and_reordering_are_allowed() {
+    let mut app = journal_app();
+    run(&mut app, "ai");
+    app.enforce_journal();
+    assert_eq!(inside_contexts(&app).len(), 3);
+
+    // A note added this session can still be deleted
+    let new_note = app.relf_entries.iter().position(|entry| entry.date.is_some() && entry.lines.iter().all(|line| !line.contains("first") && !line.contains("second"))).unwrap();
+    app.selected_entry_index = new_note;
+    app.delete_cards_forward(1);
+    app.enforce_journal();
+    assert_eq!(inside_contexts(&app), ["second", "first"]);
+    assert_ne!(app.status_message, "Journal: past INSIDE entries are read-only");
+
+    let mut value: Value = serde_json::from_str(&app.json_input).unwrap();
+    value["inside"].as_array_mut().unwrap().reverse();
+    app.json_input = serde_json::to_string_pretty(&value).unwrap();
+    app.enforce_journal();
+    assert_eq!(inside_contexts(&app)[..2], ["first", "second"]);
+}
+
+#[test]
+fn removing_the_flag_is_rolled_back() {
+    let mut app = journal_app();
+    app.json_input = JSON.replace("\"journal\": true,", "");
+    app.enforce_journal();
+    assert!(journal::is_journal(&serde_json::from_str(&app.json_input).unwrap()));
+}
+
+#[test]
+fn files_without_the_flag_are_not_journals() {
+    let mut app = journal_app();
+    app.json_input = JSON.replace("\"journal\": true,", "");
+    app.convert_json();
+    app.record_journal_history();
+    assert!(!app.is_journal());
+
+    app.selected_entry_index = 1;
+    app.start_editing_entry();
+    assert!(app.editing_entry);
+}
+
+#[test]
+fn markdown_front_matter_round_trips() {
+    assert!(journal::markdown_is_journal(MD));
+    assert!(!journal::markdown_is_journal("---\njournal: false\n---\n"));
+    assert!(!journal::markdown_is_journal("---\njournal: true\n"));
+
+    let json = MarkdownOperations::parse_to_json(MD).unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert!(journal::is_journal(&value));
+    assert_eq!(value["outside"][0]["name"], "Book");
+    assert_eq!(value["inside"][0]["context"], "first");
+
+    let md = MarkdownOperations::render_from_json(&value);
+    assert!(md.starts_with("---\njournal: true\n---\n\n## OUTSIDE"), "{}", md);
+
+    let (ordered, _) = MarkdownOperations::order_entries(MD).unwrap();
+    assert!(ordered.starts_with("---\njournal: true\n---\n"), "{}", ordered);
+}
+
+#[test]
+fn strict_mode_accepts_the_flag_only() {
+    assert!(MarkdownOperations::validate_strict(MD).is_ok());
+    let err = MarkdownOperations::validate_strict("---\ntitle: x\n---\n\n## INSIDE\n").unwrap_err();
+    assert_eq!(err, "line 2: unknown front matter key \"title\"");
+    assert!(revw::json_ops::JsonOperations::validate_strict(JSON).is_ok());
+    assert!(revw::json_ops::JsonOperations::validate_strict(r#"{"journal": "yes"}"#).is_err());
+}
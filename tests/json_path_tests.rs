@@ -0,0 +1,61 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_path::path_at;
+
+const JSON: &str = r#"{
+  "outside": [
+    {
+      "name": "A",
+      "context": "first, \"quoted\" [not an array]"
+    },
+    {
+      "name": "B",
+      "tags": ["x", "y"],
+      "odd key": 1
+    }
+  ],
+  "inside": []
+}"#;
+
+/// Cursor on the first occurrence of `needle` in JSON
+fn at(needle: &str) -> Option<String> {
+    let (line, text) = JSON.lines().enumerate().find(|(_, text)| text.contains(needle)).unwrap();
+    path_at(JSON, line, text[..text.find(needle).unwrap()].chars().count())
+}
+
+#[test]
+fn paths_follow_keys_and_indices() {
+    assert_eq!(at("\"name\": \"A\""), Some("outside[0].name".to_string()));
+    assert_eq!(at("first,"), Some("outside[0].context".to_string()));
+    assert_eq!(at("not an array"), Some("outside[0].context".to_string()));
+    assert_eq!(at("\"B\""), Some("outside[1].name".to_string()));
+    assert_eq!(at("\"y\""), Some("outside[1].tags[1]".to_string()));
+    assert_eq!(at("\"odd key\""), Some("outside[1][\"odd key\"]".to_string()));
+    assert_eq!(at("[]"), Some("inside".to_string()));
+}
+
+#[test]
+fn indentation_takes_the_key_of_its_line() {
+    // Column 0 of `      "name": "B",`
+    let line = JSON.lines().position(|text| text.contains("\"B\"")).unwrap();
+    assert_eq!(path_at(JSON, line, 0), Some("outside[1].name".to_string()));
+    // The line holding only `{` belongs to the array element
+    assert_eq!(path_at(JSON, 2, 0), Some("outside[0]".to_string()));
+    assert_eq!(path_at(JSON, 0, 0), None);
+    assert_eq!(path_at(JSON, 0, 1), Some("$".to_string()));
+}
+
+#[test]
+fn status_bar_shows_the_path_in_edit_mode() {
+    let mut app = App::new(FormatMode::Edit);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.content_cursor_line = 4;
+    app.content_cursor_col = 8;
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let last_row: String = (0..80).map(|x| buffer[(x, 9)].symbol()).collect();
+    assert!(last_row.contains("outside[0].context  5:9"), "{}", last_row);
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use revw::app::{App, FileMode};
+use revw::keymap::{key_table, parse_keys, Action, Key, KeyMode, KeyOutcome, Keymap};
+use std::time::{Duration, Instant};
+
+fn key(c: char) -> Key {
+    Key { code: KeyCode::Char(c), ctrl: false }
+}
+
+fn ctrl(c: char) -> Key {
+    Key { code: KeyCode::Char(c), ctrl: true }
+}
+
+fn feed(keymap: &mut Keymap, mode: KeyMode, keys: &str, now: Instant) -> Vec<KeyOutcome> {
+    keys.chars().map(|c| keymap.feed(mode, key(c), true, now)).collect()
+}
+
+fn view_app(cards: usize) -> App {
+    let outside: Vec<String> = (0..cards)
+        .map(|i| format!("{{\"name\": \"Card {i}\", \"context\": \"\", \"url\": \"\", \"percentage\": null}}"))
+        .collect();
+    let json_input = format!("{{\n  \"outside\": [\n{}\n  ],\n  \"inside\": []\n}}", outside.join(",\n"));
+    common::view_app(&json_input, FileMode::Json)
+}
+
+fn press(app: &mut App, keys: &str) -> bool {
+    let mut quit = false;
+    for key in parse_keys(keys).unwrap() {
+        let modifiers = if key.ctrl { KeyModifiers::CONTROL } else { KeyModifiers::NONE };
+        quit |= app.press_key(KeyEvent::new(key.code, modifiers));
+    }
+    quit
+}
+
+#[test]
+fn key_notation_parses_names_and_ctrl() {
+    assert_eq!(parse_keys("gg"), Some(vec![key('g'), key('g')]));
+    assert_eq!(parse_keys("<C-f>"), Some(vec![ctrl('f')]));
+    assert_eq!(parse_keys("<Esc>j"), Some(vec![Key { code: KeyCode::Esc, ctrl: false }, key('j')]));
+    assert_eq!(parse_keys("<lt>"), Some(vec![key('<')]));
+    assert_eq!(parse_keys("<Nope>"), None);
+}
+
+#[test]
+fn tables_parse_and_no_sequence_shadows_another() {
+    for mode in [
+        KeyMode::View,
+        KeyMode::Edit,
+        KeyMode::Focus,
+        KeyMode::Explorer,
+        KeyMode::Outline,
+        KeyMode::Matches,
+        KeyMode::Overlay,
+        KeyMode::OverlayField,
+    ] {
+        let sequences: Vec<Vec<Key>> = key_table(mode).iter().map(|(text, _)| parse_keys(text).unwrap()).collect();
+        for (i, a) in sequences.iter().enumerate() {
+            for (j, b) in sequences.iter().enumerate() {
+                assert!(i == j || !b.starts_with(a), "{:?}: {:?} shadows {:?}", mode, a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn sequences_counts_and_broken_prefixes() {
+    let mut keymap = Keymap::default();
+    let now = Instant::now();
+    assert_eq!(feed(&mut keymap, KeyMode::View, "gg", now), [KeyOutcome::Pending, KeyOutcome::Run(Action::Top, None)]);
+    assert_eq!(
+        feed(&mut keymap, KeyMode::View, "12dd", now).last(),
+        Some(&KeyOutcome::Run(Action::DeleteCards, Some(12)))
+    );
+    // 0 is a motion in Edit mode unless it continues a count
+    assert_eq!(feed(&mut keymap, KeyMode::Edit, "0", now), [KeyOutcome::Run(Action::LineStart, None)]);
+    assert_eq!(feed(&mut keymap, KeyMode::Edit, "10j", now).last(), Some(&KeyOutcome::Run(Action::Down, Some(10))));
+    // g then j: the g is dropped and j runs on its own
+    assert_eq!(feed(&mut keymap, KeyMode::View, "gj", now).last(), Some(&KeyOutcome::Run(Action::Down, None)));
+    assert_eq!(feed(&mut keymap, KeyMode::View, "Z", now), [KeyOutcome::Unbound]);
+    // Counts are not read when the caller turns them off
+    assert_eq!(keymap.feed(KeyMode::View, key('3'), false, now), KeyOutcome::Unbound);
+}
+
+#[test]
+fn pending_sequences_time_out() {
+    let mut keymap = Keymap::default();
+    let now = Instant::now();
+    feed(&mut keymap, KeyMode::View, "3d", now);
+    let late = now + keymap.timeout + Duration::from_millis(1);
+    // The count and the first d are gone, so this d starts a new sequence
+    assert_eq!(keymap.feed(KeyMode::View, key('d'), true, late), KeyOutcome::Pending);
+    assert_eq!(keymap.feed(KeyMode::View, key('d'), true, late), KeyOutcome::Run(Action::DeleteCards, None));
+}
+
+#[test]
+fn ctrl_falls_back_to_the_plain_key_when_unbound() {
+    let mut keymap = Keymap::default();
+    let now = Instant::now();
+    assert_eq!(keymap.feed(KeyMode::View, ctrl('f'), true, now), KeyOutcome::Run(Action::PageCards(true), None));
+    assert_eq!(keymap.feed(KeyMode::View, ctrl('j'), true, now), KeyOutcome::Run(Action::Down, None));
+    assert_eq!(keymap.feed(KeyMode::Focus, ctrl('d'), true, now), KeyOutcome::Run(Action::HalfPageDown, None));
+}
+
+#[test]
+fn counted_card_commands_through_keys() {
+    let mut app = view_app(6);
+    press(&mut app, "2j");
+    assert_eq!(app.selected_entry_index, 2);
+    press(&mut app, "3dd");
+    assert_eq!(app.relf_entries.len(), 3);
+    press(&mut app, "gg2p");
+    assert_eq!(app.relf_entries.len(), 9);
+    press(&mut app, "G");
+    assert_eq!(app.selected_entry_index, 8);
+
+    // In Visual mode digits are not a count and q leaves the mode instead of quitting
+    press(&mut app, "v");
+    assert!(app.visual_mode);
+    assert!(!press(&mut app, "q"));
+    assert!(!app.visual_mode);
+    assert!(press(&mut app, "q"));
+}
+
+#[test]
+fn edit_motions_repeat_with_a_count() {
+    let mut app = view_app(3);
+    app.toggle_format_mode();
+    press(&mut app, "gg3j");
+    assert_eq!(app.content_cursor_line, 3);
+    press(&mut app, "$0");
+    assert_eq!(app.content_cursor_col, 0);
+    press(&mut app, "d");
+    assert_eq!(app.pending_operator, "d");
+}
+
+#[test]
+fn focus_mode_has_its_own_keys() {
+    let mut app = view_app(3);
+    press(&mut app, "z");
+    assert!(app.focus_mode);
+    press(&mut app, "]]");
+    assert_eq!(app.selected_entry_index, 2);
+    press(&mut app, "<Esc>");
+    assert!(!app.focus_mode);
+}
+
+#[test]
+fn f_and_b_scroll_card_content_instead_of_focusing() {
+    let mut app = view_app(3);
+    press(&mut app, "f");
+    assert!(!app.focus_mode);
+    let table = key_table(KeyMode::View);
+    assert!(table.contains(&("f", Action::ScrollCard(1))));
+    assert!(table.contains(&("b", Action::ScrollCard(-1))));
+    assert!(table.contains(&("z", Action::ToggleFocus)));
+}
+
+#[test]
+fn lists_and_the_overlay_resolve_g_d_and_y_sequences() {
+    let now = Instant::now();
+    let mut keymap = Keymap::default();
+    let explorer = feed(&mut keymap, KeyMode::Explorer, "gogho", now);
+    assert_eq!(
+        explorer,
+        vec![
+            KeyOutcome::Pending,
+            KeyOutcome::Run(Action::Preview, None),
+            KeyOutcome::Pending,
+            KeyOutcome::Run(Action::ShowAllFiles, None),
+            KeyOutcome::Run(Action::Select, None),
+        ]
+    );
+    assert_eq!(feed(&mut keymap, KeyMode::Outline, "gg", now)[1], KeyOutcome::Run(Action::Top, None));
+    assert_eq!(feed(&mut keymap, KeyMode::Matches, "gG", now)[1], KeyOutcome::Run(Action::Bottom, None));
+    assert_eq!(feed(&mut keymap, KeyMode::OverlayField, "dd", now)[1], KeyOutcome::Run(Action::DeleteLine, None));
+    assert_eq!(feed(&mut keymap, KeyMode::OverlayField, "yy", now)[1], KeyOutcome::Run(Action::YankLine, None));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::line_endings::{self, TextFormat};
+use std::fs;
+use std::process::Command;
+
+const WINDOWS_MD: &str = "\u{feff}## OUTSIDE\r\n\r\n### Rust book\r\nchapter 3\r\n**URL:** https://doc.rust-lang.org/book\r\n\r\n## INSIDE\r\n";
+
+#[test]
+fn normalize_strips_bom_and_crlf_and_apply_restores_them() {
+    let (text, format) = line_endings::normalize(WINDOWS_MD);
+    assert_eq!(format, TextFormat { crlf: true, bom: true });
+    assert!(!text.contains('\r'));
+    assert!(text.starts_with("## OUTSIDE\n"));
+    assert_eq!(format.apply(&text), WINDOWS_MD);
+
+    let (text, format) = line_endings::normalize("a\nb\n");
+    assert_eq!(format, TextFormat::default());
+    assert_eq!(text, "a\nb\n");
+}
+
+#[test]
+fn windows_files_load_without_stray_carriage_returns() {
+    let dir = temp_dir("load");
+    let path = dir.join("notes.md");
+    fs::write(&path, WINDOWS_MD).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+    let value: serde_json::Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"][0]["name"], "Rust book");
+    assert_eq!(value["outside"][0]["context"], "chapter 3");
+    assert!(!app.markdown_input.contains('\r'));
+    assert_eq!(app.text_format, TextFormat { crlf: true, bom: true });
+
+    // Saved with \n by default
+    app.is_modified = true;
+    app.save_file();
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(!saved.contains('\r') && !saved.starts_with('\u{feff}'));
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn preserve_line_endings_saves_crlf_and_bom_back() {
+    let dir = temp_dir("preserve");
+    let path = dir.join("notes.json");
+    fs::write(&path, "\u{feff}{\r\n  \"outside\": [\r\n    {\"name\": \"Old\"}\r\n  ],\r\n  \"inside\": []\r\n}").unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.preserve_line_endings = true;
+    app.load_file(path.clone());
+    assert_eq!(app.relf_entries.len(), 1);
+    app.json_input = app.json_input.replace("\"Old\"", "\"New\"");
+    app.is_modified = true;
+    app.save_file();
+    assert!(app.status_message.starts_with("Saved"), "{}", app.status_message);
+
+    let saved = fs::read_to_string(&path).unwrap();
+    assert!(saved.starts_with('\u{feff}'));
+    assert!(saved.contains("\"New\""));
+    assert_eq!(saved.matches('\n').count(), saved.matches("\r\n").count());
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn cli_reads_crlf_markdown() {
+    let dir = temp_dir("cli");
+    let path = dir.join("notes.md");
+    fs::write(&path, WINDOWS_MD).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).args(["--stdout", "--json"]).arg(&path).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["outside"][0]["context"], "chapter 3");
+    fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,77 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::content_ops::find_urls;
+use serde_json::json;
+
+fn app(context: &str) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = serde_json::to_string_pretty(&json!({
+        "outside": [
+            {"name": "Rust", "context": context, "url": "https://www.rust-lang.org", "percentage": null}
+        ],
+        "inside": []
+    }))
+    .unwrap();
+    app.convert_json();
+    app
+}
+
+#[test]
+fn urls_are_found_in_prose_and_markdown_links() {
+    let text = "See https://doc.rust-lang.org/book/. Also [std](https://doc.rust-lang.org/std/) \
+                and (https://en.wikipedia.org/wiki/Rust_(programming_language)), again https://doc.rust-lang.org/book/";
+    assert_eq!(
+        find_urls(text),
+        vec![
+            "https://doc.rust-lang.org/book/",
+            "https://doc.rust-lang.org/std/",
+            "https://en.wikipedia.org/wiki/Rust_(programming_language)",
+        ]
+    );
+    assert!(find_urls("no links, just http:// and ftp://x").is_empty());
+}
+
+#[test]
+fn gl_labels_the_card_url_then_context_links() {
+    let mut app = app("Book: https://doc.rust-lang.org/book/\nHome again: https://www.rust-lang.org");
+    for c in ['g', 'l'] {
+        app.press_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    }
+    assert_eq!(
+        app.link_hints,
+        vec![('a', "https://www.rust-lang.org".to_string()), ('s', "https://doc.rust-lang.org/book/".to_string())]
+    );
+    assert_eq!(app.status_message, "Link hints: letter copies, Shift+letter opens, Esc cancels");
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let text: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+    assert!(text.contains("[s] https://doc.rust-lang.org/book/"));
+}
+
+#[test]
+fn unknown_letters_and_esc_close_the_hints() {
+    let mut app = app("https://a.io");
+    app.start_link_hints();
+    app.handle_link_hint('z');
+    assert!(app.link_hints.is_empty());
+    assert_eq!(app.status_message, "No link labelled z");
+
+    app.start_link_hints();
+    app.cancel_link_hints();
+    assert!(app.link_hints.is_empty());
+    assert_eq!(app.status_message, "Link hints cancelled");
+}
+
+#[test]
+fn cards_without_links_say_so() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [], "inside": [{"date": "2024-01-01", "context": "plain"}]}"#.to_string();
+    app.convert_json();
+    app.start_link_hints();
+    assert!(app.link_hints.is_empty());
+    assert_eq!(app.status_message, "No links in selected entry");
+}
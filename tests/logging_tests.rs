@@ -0,0 +1,94 @@
+mod common;
+
+use common::{run, tmp_path};
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FormatMode};
+use revw::config::RcConfig;
+use revw::logging;
+use std::{fs, process::Command};
+
+fn app() -> App {
+    App::with_config(FormatMode::View, RcConfig::default())
+}
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>() + "\n")
+        .collect()
+}
+
+#[test]
+fn tail_keeps_the_newest_lines() {
+    let path = tmp_path("log_tail", "log");
+    fs::write(&path, "one\ntwo\nthree\nfour\n").unwrap();
+    assert_eq!(logging::tail(&path, 2).unwrap(), ["three", "four"]);
+    assert_eq!(logging::tail(&path, 10).unwrap().len(), 4);
+    assert!(logging::tail(&tmp_path("log_missing", "log"), 10).is_err());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn log_command_needs_a_log_file() {
+    let mut app = app();
+    run(&mut app, "log");
+    assert!(app.log_lines.is_none());
+    assert!(app.status_message.starts_with("Logging is off"));
+}
+
+#[test]
+fn log_command_shows_the_newest_lines() {
+    let path = tmp_path("log_view", "log");
+    let lines: Vec<String> = (1..=50).map(|i| format!("2025-01-01 00:00:00  INFO command command=\"w\" #{}", i)).collect();
+    fs::write(&path, lines.join("\n")).unwrap();
+
+    let mut app = app();
+    app.log_file = Some(path.clone());
+    run(&mut app, "log");
+    assert_eq!(app.log_lines.as_ref().map(Vec::len), Some(50));
+
+    let text = screen(&mut app);
+    assert!(text.contains("Log (any key closes)"));
+    assert!(text.contains("#50"));
+    assert!(!text.contains("#1\n") && !text.contains("#1 "));
+
+    app.close_log();
+    assert!(app.log_lines.is_none());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn log_file_flag_records_loads() {
+    let log = tmp_path("log_cli", "log");
+    let notes = tmp_path("log_notes", "json");
+    fs::write(&notes, r#"{"outside": [], "inside": []}"#).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .arg("--log-file")
+        .arg(&log)
+        .arg("--report")
+        .arg(&notes)
+        .output()
+        .expect("failed to execute revw");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let logged = fs::read_to_string(&log).unwrap();
+    assert!(logged.contains("INFO") && logged.contains("loaded"), "{}", logged);
+    assert!(logged.contains(&notes.display().to_string()));
+    let _ = fs::remove_file(log);
+    let _ = fs::remove_file(notes);
+}
+
+#[test]
+fn unwritable_log_file_is_an_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .arg("--log-file")
+        .arg("/proc/revw/cannot.log")
+        .arg("--token")
+        .arg("missing.json")
+        .output()
+        .expect("failed to execute revw");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error:"));
+}
@@ -0,0 +1,58 @@
+use ratatui::{backend::TestBackend, style::Modifier, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::config::ColorScheme;
+use revw::ui::markdown_highlight::highlight_markdown_line;
+
+const MD: &str = "## OUTSIDE\n\n### A heading long enough to wrap onto a second row\nread https://example.com/a/very/long/path/that/wraps/too\n\n**URL:** https://example.com\n";
+
+fn edit_app() -> App {
+    let mut app = App::new(FormatMode::Edit);
+    app.file_mode = FileMode::Markdown;
+    app.markdown_input = MD.to_string();
+    app.json_input = app.parse_markdown(MD).unwrap();
+    app.convert_json();
+    app
+}
+
+#[test]
+fn section_headers_urls_and_markers_are_styled() {
+    let colors = ColorScheme::default();
+
+    let section = highlight_markdown_line("## INSIDE", &colors);
+    assert!(section[0].style.add_modifier.contains(Modifier::BOLD | Modifier::UNDERLINED));
+    let heading = highlight_markdown_line("### Book", &colors);
+    assert_eq!(heading[0].style.fg, Some(colors.md_header));
+    assert!(!heading[0].style.add_modifier.contains(Modifier::UNDERLINED));
+
+    let spans = highlight_markdown_line("**URL:** https://example.com/x and more", &colors);
+    let texts: Vec<&str> = spans.iter().map(|span| span.content.as_ref()).collect();
+    assert_eq!(texts, vec!["**URL:**", " ", "https://example.com/x", " and more"]);
+    assert_eq!(spans[2].style.fg, Some(colors.md_url));
+    assert_eq!(spans[3].style.fg, Some(colors.md_text));
+}
+
+#[test]
+fn highlighting_follows_wrapped_rows_in_edit_mode() {
+    let mut app = edit_app();
+    let colors = app.colorscheme.clone();
+    let mut terminal = Terminal::new(TestBackend::new(40, 16)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+
+    let row_text = |y: u16| (0..40).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+    let row_of = |needle: &str| (0..16).find(|&y| row_text(y).contains(needle)).unwrap_or_else(|| panic!("{} not on screen", needle));
+
+    // Second row of the wrapped heading keeps the heading color
+    let heading_tail = row_of("second row");
+    let x = row_text(heading_tail).find("second").unwrap() as u16;
+    assert_eq!(buffer[(x, heading_tail)].fg, colors.md_header);
+
+    // The wrapped part of the URL keeps the URL color
+    let url_tail = row_of("wraps/too");
+    let x = row_text(url_tail).find("wraps").unwrap() as u16;
+    assert_eq!(buffer[(x, url_tail)].fg, colors.md_url);
+
+    let section = row_of("## OUTSIDE");
+    let x = row_text(section).find("OUTSIDE").unwrap() as u16;
+    assert!(buffer[(x, section)].modifier.contains(Modifier::UNDERLINED));
+}
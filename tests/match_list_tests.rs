@@ -0,0 +1,126 @@
+mod common;
+
+use common::{run, view_app};
+use revw::app::{App, FileMode, FormatMode};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust book", "context": "intro\nborrowing in rust", "url": "", "percentage": null},
+    {"name": "Go tour", "context": "no match here", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2025-01-01 00:00:00", "context": "wrote some Rust today"}
+  ]
+}"#;
+
+fn search(app: &mut App, query: &str) {
+    app.search_buffer = query.to_string();
+    app.execute_search();
+}
+
+#[test]
+fn matches_lists_every_match_with_its_entry() {
+    let mut app = view_app(JSON, FileMode::Json);
+    search(&mut app, "rust");
+    run(&mut app, "matches");
+    assert!(app.matches_open);
+    assert!(app.matches_has_focus);
+
+    let items = app.match_list_items();
+    let rows: Vec<(&str, &str)> = items.iter().map(|i| (i.label.as_str(), i.snippet.as_str())).collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("Rust book", "Rust book"),
+            ("Rust book", "borrowing in rust"),
+            ("2025-01-01 00:00:00", "wrote some Rust today"),
+        ]
+    );
+    assert_eq!(items.len(), app.search_matches.len());
+}
+
+#[test]
+fn snippets_start_shortly_before_late_matches() {
+    let mut app = view_app(JSON, FileMode::Json);
+    search(&mut app, "today");
+    let items = app.match_list_items();
+    assert_eq!(items[0].snippet, "wrote some Rust today");
+
+    app.json_input = JSON.replace("wrote some Rust today", "a very long line that only mentions today at the end");
+    app.convert_json();
+    search(&mut app, "today");
+    assert_eq!(app.match_list_items()[0].snippet, "…that only mentions today at the end");
+}
+
+#[test]
+fn enter_jumps_to_the_selected_card() {
+    let mut app = view_app(JSON, FileMode::Json);
+    search(&mut app, "rust");
+    run(&mut app, "matches");
+
+    app.match_list_move_down();
+    app.match_list_move_down();
+    app.match_list_move_down(); // stays on the last match
+    assert_eq!(app.matches_selected_index, 2);
+
+    app.match_list_jump();
+    assert_eq!(app.selected_entry_index, 2);
+    assert_eq!(app.current_match_index, Some(2));
+    assert!(app.matches_open);
+    assert!(!app.matches_has_focus);
+
+    run(&mut app, "cclose");
+    assert!(!app.matches_open);
+}
+
+#[test]
+fn matches_needs_a_search_with_results() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "matches");
+    assert!(!app.matches_open);
+    assert_eq!(app.status_message, "No search: use /pattern first");
+
+    search(&mut app, "zzz");
+    run(&mut app, "matches");
+    assert!(!app.matches_open);
+}
+
+#[test]
+fn edit_mode_matches_are_labelled_with_entry_and_line() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.format_mode = FormatMode::Edit;
+    search(&mut app, "borrowing");
+    let items = app.match_list_items();
+    assert_eq!(items.len(), 1);
+    assert!(items[0].label.starts_with("Rust book (line "), "{}", items[0].label);
+
+    run(&mut app, "matches");
+    app.match_list_jump();
+    let (line, col) = app.search_matches[0];
+    assert_eq!((app.content_cursor_line, app.content_cursor_col), (line, col));
+}
+
+#[test]
+fn search_and_n_show_the_match_position() {
+    let mut app = view_app(JSON, FileMode::Json);
+    search(&mut app, "rust");
+    let total = app.search_matches.len();
+    assert_eq!(app.match_position(), Some(format!("match 1/{}", total)));
+    assert_eq!(app.status_message, format!("match 1/{} for 'rust'", total));
+
+    app.next_match();
+    assert_eq!(app.status_message, format!("match 2/{} for 'rust'", total));
+    app.prev_match();
+    app.prev_match();
+    assert_eq!(app.match_position(), Some(format!("match {}/{}", total, total)));
+}
+
+#[test]
+fn nohl_clears_the_highlight_and_the_position() {
+    let mut app = view_app(JSON, FileMode::Json);
+    search(&mut app, "rust");
+    run(&mut app, "nohl");
+    assert!(app.search_matches.is_empty());
+    assert_eq!(app.match_position(), None);
+    assert_eq!(app.status_message, "Search highlight cleared");
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::sources;
+use serde_json::{json, Value};
+use std::{fs, path::PathBuf};
+
+fn card(name: &str) -> Value {
+    json!({"name": name, "context": "", "url": "", "percentage": null})
+}
+
+#[test]
+fn combine_tags_entries_and_split_routes_them_back() {
+    let docs = vec![
+        ("a.json".to_string(), json!({"outside": [card("A1"), card("A2")], "inside": []})),
+        ("b.json".to_string(), json!({"journal": true, "outside": [card("B1")], "inside": [{"date": "2024-01-01 00:00:00", "context": "b"}]})),
+    ];
+    let mut combined = sources::combine(&docs);
+    assert_eq!(combined["outside"][2]["_source"], "b.json");
+    assert_eq!(combined["inside"][0]["_source"], "b.json");
+
+    // A card added after B1 without a label belongs to b.json
+    combined["outside"].as_array_mut().unwrap().push(card("B2"));
+    let split = sources::split(&combined, &docs).unwrap();
+    assert_eq!(split[0], json!({"outside": [card("A1"), card("A2")], "inside": []}));
+    assert_eq!(split[1]["outside"], json!([card("B1"), card("B2")]));
+    assert_eq!(split[1]["journal"], true);
+
+    combined["outside"][0]["_source"] = json!("c.json");
+    assert!(sources::split(&combined, &docs).unwrap_err().contains("c.json"));
+}
+
+#[test]
+fn files_sharing_a_name_are_labelled_by_path() {
+    let paths = [PathBuf::from("work/notes.json"), PathBuf::from("home/notes.json"), PathBuf::from("todo.md")];
+    assert_eq!(sources::labels(&paths), ["work/notes.json", "home/notes.json", "todo.md"]);
+}
+
+#[test]
+fn edits_are_saved_to_the_file_each_card_came_from() {
+    let dir = temp_dir("save");
+    let a = dir.join("a.json");
+    let b = dir.join("b.md");
+    let a_content = serde_json::to_string_pretty(&json!({"outside": [card("A1")], "inside": []})).unwrap();
+    fs::write(&a, &a_content).unwrap();
+    fs::write(&b, "## OUTSIDE\n### B1\n\n## INSIDE\n").unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.open_sources(vec![a.clone(), b.clone()]);
+    assert_eq!(app.relf_entries.len(), 2);
+    assert_eq!(app.relf_entries[1].source.as_deref(), Some("b.md"));
+    // The label is not shown as a custom field
+    assert!(app.relf_entries[1].fields.is_empty());
+
+    let mut value: Value = serde_json::from_str(&app.json_input).unwrap();
+    value["outside"][1]["name"] = json!("B1 renamed");
+    app.json_input = serde_json::to_string_pretty(&value).unwrap();
+    app.is_modified = true;
+    app.save_file();
+
+    assert_eq!(app.status_message, "Saved: b.md");
+    assert!(!app.is_modified);
+    assert!(fs::read_to_string(&b).unwrap().contains("### B1 renamed"));
+    assert!(!fs::read_to_string(&b).unwrap().contains("_source"));
+    // Untouched files are not rewritten
+    assert_eq!(fs::read_to_string(&a).unwrap(), a_content);
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn a_file_changed_on_disk_is_not_overwritten() {
+    let dir = temp_dir("conflict");
+    let a = dir.join("a.json");
+    let b = dir.join("b.json");
+    fs::write(&a, serde_json::to_string_pretty(&json!({"outside": [card("A1")], "inside": []})).unwrap()).unwrap();
+    fs::write(&b, serde_json::to_string_pretty(&json!({"outside": [card("B1")], "inside": []})).unwrap()).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.open_sources(vec![a.clone(), b.clone()]);
+    fs::write(&a, serde_json::to_string_pretty(&json!({"outside": [card("Elsewhere")], "inside": []})).unwrap()).unwrap();
+    app.json_input = app.json_input.replace("\"A1\"", "\"A1 edited\"");
+    app.is_modified = true;
+    app.save_file();
+
+    assert!(app.status_message.contains("not saved: a.json"));
+    assert!(app.is_modified);
+    assert!(fs::read_to_string(&a).unwrap().contains("Elsewhere"));
+
+    app.force_save_file();
+    assert!(fs::read_to_string(&a).unwrap().contains("A1 edited"));
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn journal_files_are_refused_in_a_merged_view() {
+    let dir = temp_dir("journal");
+    let a = dir.join("a.json");
+    let b = dir.join("b.md");
+    fs::write(&a, serde_json::to_string_pretty(&json!({"outside": [card("A1")], "inside": []})).unwrap()).unwrap();
+    fs::write(&b, "---\njournal: true\n---\n## INSIDE\n### 2024-01-01 00:00:00\npast\n").unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.open_sources(vec![a.clone(), b.clone()]);
+    assert!(app.status_message.contains("is a journal"), "{}", app.status_message);
+    assert!(app.sources.is_empty());
+    assert!(app.relf_entries.is_empty());
+    fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,87 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::navigation::Navigator;
+
+// INSIDE comes first in the text, but OUTSIDE entries still take the first indices
+const JSON: &str = r#"{
+  "inside": [
+    {
+      "date": "2025-01-01 00:00:00",
+      "context": "x"
+    }
+  ],
+  "outside": [
+    {
+      "name": "A",
+      "context": "line\nbreak"
+    },
+    {
+      "name": "B",
+      "context": ""
+    }
+  ]
+}"#;
+
+const MD: &str = "## OUTSIDE\n\n### A\nsome context\n\n### B\n\n## INSIDE\n\n### 2025-01-01 00:00:00\nx\n";
+
+fn app(file_mode: FileMode) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = file_mode;
+    app.json_input = JSON.to_string();
+    if file_mode == FileMode::Markdown {
+        app.markdown_input = MD.to_string();
+        app.json_input = app.parse_markdown(MD).unwrap();
+    }
+    app.convert_json();
+    app
+}
+
+fn line_of(app: &App, needle: &str) -> usize {
+    app.get_content_lines().iter().position(|line| line.contains(needle)).unwrap()
+}
+
+#[test]
+fn entry_lines_are_ordered_outside_first() {
+    let lines: Vec<String> = JSON.lines().map(str::to_string).collect();
+    assert_eq!(Navigator::entry_lines_by_index(&lines, false), vec![8, 12, 2]);
+    let lines: Vec<String> = MD.lines().map(str::to_string).collect();
+    assert_eq!(Navigator::entry_lines_by_index(&lines, true), vec![2, 5, 9]);
+}
+
+#[test]
+fn selected_card_puts_the_edit_cursor_on_its_entry() {
+    let mut app = app(FileMode::Json);
+    let index = app.relf_entries.iter().position(|e| e.name.as_deref() == Some("B")).unwrap();
+    app.selected_entry_index = index;
+    app.toggle_format_mode();
+    assert_eq!(app.format_mode, FormatMode::Edit);
+    assert_eq!(app.content_cursor_line, 12);
+    assert_eq!(app.status_message, "Edit mode");
+}
+
+#[test]
+fn edit_cursor_selects_its_card_in_view() {
+    let mut app = app(FileMode::Json);
+    app.toggle_format_mode();
+    app.content_cursor_line = line_of(&app, "\"context\": \"x\"");
+    app.toggle_format_mode();
+    assert_eq!(app.format_mode, FormatMode::View);
+    assert_eq!(app.relf_entries[app.selected_entry_index].context.as_deref(), Some("x"));
+
+    // On a section header the selection stays where it was
+    app.toggle_format_mode();
+    app.content_cursor_line = line_of(&app, "\"outside\"");
+    app.toggle_format_mode();
+    assert_eq!(app.relf_entries[app.selected_entry_index].context.as_deref(), Some("x"));
+}
+
+#[test]
+fn markdown_round_trip_keeps_the_entry() {
+    let mut app = app(FileMode::Markdown);
+    app.selected_entry_index = 1;
+    app.toggle_format_mode();
+    assert_eq!(app.content_cursor_line, line_of(&app, "### B"));
+
+    app.content_cursor_line = line_of(&app, "some context");
+    app.toggle_format_mode();
+    assert_eq!(app.relf_entries[app.selected_entry_index].name.as_deref(), Some("A"));
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use common::{run, value};
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "a", "url": "", "percentage": null},
+    {"name": "B", "context": "b", "url": "https://b.example", "percentage": 40, "tags": ["x"]},
+    {"name": "C", "context": "c", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2024-01-02", "context": "second"},
+    {"date": "2024-01-01", "context": "first"}
+  ]
+}"#;
+
+fn card_app(selected: usize) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.selected_entry_index = selected;
+    app
+}
+
+fn names(app: &App) -> Vec<String> {
+    value(app)["outside"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn move_entry_stays_within_section() {
+    let (formatted, index) = JsonOperations::move_entry(JSON, 0, 5).unwrap();
+    assert_eq!(index, 2);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["outside"][2]["name"], json!("A"));
+    assert_eq!(doc["inside"].as_array().unwrap().len(), 2);
+
+    let (formatted, index) = JsonOperations::move_entry(JSON, 4, -1).unwrap();
+    assert_eq!(index, 3);
+    let doc: Value = serde_json::from_str(&formatted).unwrap();
+    assert_eq!(doc["inside"][0]["context"], json!("first"));
+}
+
+#[test]
+fn j_and_k_move_selected_card_and_follow_it() {
+    let mut app = card_app(0);
+    app.move_selected_card(1);
+    assert_eq!(names(&app), vec!["B", "A", "C"]);
+    assert_eq!(app.selected_entry_index, 1);
+    assert_eq!(app.status_message, "Moved card down");
+
+    app.move_selected_card(-1);
+    assert_eq!(names(&app), vec!["A", "B", "C"]);
+    assert_eq!(app.selected_entry_index, 0);
+
+    app.move_selected_card(-1);
+    assert_eq!(app.status_message, "Already the first card");
+
+    app.undo();
+    assert_eq!(names(&app), vec!["B", "A", "C"]);
+}
+
+#[test]
+fn move_command_takes_signed_offsets() {
+    let mut app = card_app(0);
+    run(&mut app, "move +2");
+    assert_eq!(names(&app), vec!["B", "C", "A"]);
+    assert_eq!(app.status_message, "Moved card down 2 places");
+
+    run(&mut app, "move -1");
+    assert_eq!(names(&app), vec!["B", "A", "C"]);
+
+    run(&mut app, "move 0");
+    assert_eq!(app.status_message, "Usage: :move +N, :move -N, :move inside or :move outside");
+}
+
+#[test]
+fn moving_past_outside_end_without_extra_fields_converts_directly() {
+    let mut app = card_app(2);
+    app.json_input = JSON.replace(r#""name": "C", "context": "c""#, r#""name": "", "context": "C""#);
+    app.convert_json();
+    app.move_selected_card(1);
+
+    assert!(app.card_move_pending.is_none());
+    assert_eq!(app.status_message, "Moved to INSIDE");
+    let doc = value(&app);
+    assert_eq!(doc["outside"].as_array().unwrap().len(), 2);
+    let moved = doc["inside"][0].as_object().unwrap();
+    assert_eq!(moved["context"], json!("C"));
+    assert!(moved["date"].as_str().is_some_and(|d| !d.is_empty()));
+    assert!(!moved.contains_key("name"));
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn moving_to_inside_prompts_for_fields_without_a_place() {
+    let mut app = card_app(1);
+    run(&mut app, "move inside");
+    assert_eq!(app.card_move_pending, Some(1));
+    assert_eq!(app.status_message, "INSIDE has no name, url, percentage: (f)old into context, (d)rop, (c)ancel?");
+
+    app.handle_card_move_confirmation('c');
+    assert!(app.card_move_pending.is_none());
+    assert_eq!(value(&app), serde_json::from_str::<Value>(JSON).unwrap());
+
+    run(&mut app, "move inside");
+    app.handle_card_move_confirmation('f');
+    let doc = value(&app);
+    let moved = &doc["inside"][0];
+    assert_eq!(moved["context"], json!("b\n\nname: B\nurl: https://b.example\npercentage: 40"));
+    assert_eq!(moved["tags"], json!(["x"]));
+    assert!(moved.get("url").is_none());
+}
+
+#[test]
+fn moving_above_first_inside_card_goes_to_end_of_outside() {
+    let mut app = card_app(3);
+    app.move_selected_card(-1);
+    assert_eq!(app.card_move_pending, Some(3));
+
+    app.handle_card_move_confirmation('d');
+    let doc = value(&app);
+    assert_eq!(doc["outside"].as_array().unwrap().len(), 4);
+    assert_eq!(doc["outside"][3], json!({"name": "", "context": "second", "url": "", "percentage": null}));
+    assert_eq!(doc["inside"].as_array().unwrap().len(), 1);
+    assert_eq!(app.selected_entry_index, 3);
+
+    run(&mut app, "move outside");
+    assert_eq!(app.status_message, "Card is already in OUTSIDE");
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::{run, temp_dir};
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::{json, Value};
+use std::fs;
+
+#[test]
+fn new_creates_an_empty_skeleton_in_the_extensions_format() {
+    let dir = temp_dir("formats");
+    let mut app = App::new(FormatMode::View);
+
+    let md = dir.join("notes/2025/reading.md");
+    run(&mut app, &format!("new {}", md.display()));
+    assert_eq!(app.status_message, format!("Created new file: {}", md.display()));
+    assert_eq!(fs::read_to_string(&md).unwrap(), "## OUTSIDE\n\n## INSIDE\n");
+    assert_eq!(app.file_path.as_deref(), Some(md.as_path()));
+    assert_eq!(app.file_mode, FileMode::Markdown);
+    assert!(!app.is_modified);
+
+    let path = dir.join("todo.json");
+    run(&mut app, &format!("new {}", path.display()));
+    let written: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written, json!({ "outside": [], "inside": [] }));
+    assert_eq!(app.file_mode, FileMode::Json);
+    assert!(app.relf_entries.is_empty());
+    // An empty note file is still a note file
+    assert_eq!(app.status_message, format!("Created new file: {}", path.display()));
+    app.convert_json();
+    assert_eq!(app.status_message, "");
+
+    run(&mut app, "ai");
+    assert_eq!(app.relf_entries.len(), 1);
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn new_refuses_existing_files_and_unknown_extensions() {
+    let dir = temp_dir("refuse");
+    let path = dir.join("kept.json");
+    fs::write(&path, r#"{"outside": [], "inside": [{"date": "2025-01-01 00:00:00", "context": "keep me"}]}"#).unwrap();
+    let mut app = App::new(FormatMode::View);
+
+    run(&mut app, &format!("new {}", path.display()));
+    assert_eq!(app.status_message, format!("Error: {} already exists (:e opens it)", path.display()));
+    assert!(fs::read_to_string(&path).unwrap().contains("keep me"));
+    assert_eq!(app.file_path, None);
+
+    run(&mut app, &format!("new {}", dir.join("notes.txt").display()));
+    assert_eq!(app.status_message, "Error: Filename must end with .json or .md");
+    assert!(!dir.join("notes.txt").exists());
+
+    run(&mut app, "new");
+    assert_eq!(app.status_message, "Usage: :new path/to/file.md (or .json)");
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn skeleton_files_from_the_rc_replace_the_empty_sections() {
+    let dir = temp_dir("skeleton");
+    let skeleton = dir.join("note.md");
+    fs::write(&skeleton, "## OUTSIDE\n### Reading list\n\n## INSIDE\n### {date}\nStarted\n").unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.skeleton_md = Some(skeleton);
+
+    let path = dir.join("journal.md");
+    run(&mut app, &format!("new {}", path.display()));
+    let written = fs::read_to_string(&path).unwrap();
+    assert!(written.starts_with("## OUTSIDE\n### Reading list\n\n## INSIDE\n### 20"), "{}", written);
+    assert!(!written.contains("{date}"));
+    assert_eq!(app.relf_entries.len(), 2);
+
+    // JSON has no skeleton set, so it keeps the empty sections
+    assert!(app.skeleton(&dir.join("x.json")).unwrap().contains("\"outside\": []"));
+
+    app.skeleton_md = Some(dir.join("gone.md"));
+    run(&mut app, &format!("new {}", dir.join("other.md").display()));
+    assert!(app.status_message.starts_with("Error reading skeleton"), "{}", app.status_message);
+    assert!(!dir.join("other.md").exists());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn missing_startup_file_is_only_created_after_yes() {
+    let dir = temp_dir("offer");
+    let path = dir.join("fresh.json");
+    let mut app = App::new(FormatMode::View);
+
+    app.open_or_offer(path.clone());
+    assert_eq!(app.status_message, format!("{} does not exist. Create it from empty sections? (y/n)", path.display()));
+    assert!(!path.exists());
+    app.handle_confirmation('n');
+    assert_eq!(app.status_message, format!("Not created: {}", path.display()));
+    assert!(!path.exists());
+    assert_eq!(app.file_path, None);
+
+    app.open_or_offer(path.clone());
+    app.handle_confirmation('y');
+    assert!(path.exists());
+    assert_eq!(app.file_path.as_deref(), Some(path.as_path()));
+
+    // Existing files open right away
+    let mut other = App::new(FormatMode::View);
+    other.open_or_offer(path.clone());
+    assert_eq!(other.confirm_pending, None);
+    assert_eq!(other.file_path.as_deref(), Some(path.as_path()));
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::{run, view_app};
+use revw::app::{App, FileMode, OutlineGroup};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust book", "context": "", "url": "", "percentage": 10, "priority": "low", "tags": ["rust", "books"]},
+    {"name": "Go tour", "context": "", "url": "", "percentage": null, "priority": "high", "tags": "go"},
+    {"name": "Nom", "context": "parser", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2025-03-02 09:00:00", "context": "wrote a parser"},
+    {"date": "2025-02-20 09:00:00", "context": "read"},
+    {"date": "2025-03-01 09:00:00", "context": "parser again"}
+  ]
+}"#;
+
+fn rows(app: &App) -> Vec<String> {
+    app.outline_window(0..app.outline_len())
+}
+
+#[test]
+fn date_groups_inside_cards_by_month() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "outline group date");
+    assert!(app.outline_open);
+    assert_eq!(app.outline_group, OutlineGroup::Date);
+    assert_eq!(
+        rows(&app),
+        vec![
+            "▾ OUTSIDE [3]", "  Rust book", "  Go tour", "  Nom",
+            "▾ 2025-03 [2]", "  2025-03-02 09:00:00", "  2025-03-01 09:00:00",
+            "▾ 2025-02 [1]", "  2025-02-20 09:00:00",
+        ]
+    );
+}
+
+#[test]
+fn tag_and_priority_groups() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "ol group tag");
+    assert_eq!(
+        rows(&app)[..7],
+        ["▾ rust [1]", "  Rust book", "▾ books [1]", "  Rust book", "▾ go [1]", "  Go tour", "▾ (untagged) [4]"]
+    );
+
+    run(&mut app, "outline group priority");
+    assert_eq!(
+        rows(&app),
+        vec!["▾ priority high [1]", "  Go tour", "▾ priority low [1]", "  Rust book", "▾ (no priority) [1]", "  Nom", "▾ INSIDE [3]",
+            "  2025-03-02 09:00:00", "  2025-02-20 09:00:00", "  2025-03-01 09:00:00"]
+    );
+
+    run(&mut app, "outline group colour");
+    assert_eq!(app.status_message, "Usage: :outline group date|tag|priority|none");
+    run(&mut app, "outline group none");
+    assert_eq!(app.outline_len(), 6);
+}
+
+#[test]
+fn groups_fold_and_rows_jump_to_their_cards() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "outline group date");
+
+    // Enter on a group folds it
+    app.outline_selected_index = 0;
+    assert!(app.outline_toggle_group());
+    assert_eq!(rows(&app)[..3], ["▸ OUTSIDE [3]", "▾ 2025-03 [2]", "  2025-03-02 09:00:00"]);
+
+    // A card row jumps to its card
+    app.outline_selected_index = 2;
+    assert!(!app.outline_toggle_group());
+    app.outline_jump_to_selected();
+    assert_eq!(app.selected_entry_index, 3);
+
+    // Moving stops at the last row
+    app.outline_selected_index = 0;
+    for _ in 0..20 {
+        app.outline_move_down();
+    }
+    assert_eq!(app.outline_selected_index, app.outline_len() - 1);
+
+    // Search counts add up per group
+    app.search_buffer = "parser".to_string();
+    app.execute_search();
+    assert_eq!(app.outline_match_counts(0..3), Some(vec![1, 2, 1]));
+}
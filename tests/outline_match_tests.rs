@@ -0,0 +1,59 @@
+use revw::app::{App, FileMode, FormatMode};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust parser", "context": "a parser for rust", "url": "", "percentage": null},
+    {"name": "Go tour", "context": "", "url": "https://go.dev/tour", "percentage": null},
+    {"name": "Nom", "context": "parser combinators", "url": "", "percentage": null, "topic": "parser"}
+  ],
+  "inside": [
+    {"date": "2025-03-02 09:00:00", "context": "wrote a parser"}
+  ]
+}"#;
+
+fn app(format_mode: FormatMode) -> App {
+    let mut app = App::new(format_mode);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app
+}
+
+fn search(app: &mut App, query: &str) {
+    app.search_buffer = query.to_string();
+    app.execute_search();
+}
+
+#[test]
+fn no_counts_without_a_search_or_filter() {
+    let app = app(FormatMode::View);
+    assert_eq!(app.outline_match_counts(0..4), None);
+}
+
+#[test]
+fn search_matches_are_counted_per_card() {
+    let mut app = app(FormatMode::View);
+    search(&mut app, "parser");
+    assert_eq!(app.outline_match_counts(0..4), Some(vec![2, 0, 1, 1]));
+    // Only the rows asked for
+    assert_eq!(app.outline_match_counts(1..3), Some(vec![0, 1]));
+}
+
+#[test]
+fn filter_matches_are_counted_when_there_is_no_search() {
+    let mut app = app(FormatMode::View);
+    app.apply_filter("parser".to_string());
+    assert_eq!(app.relf_entries.len(), 3);
+    // Custom fields count too
+    assert_eq!(app.outline_match_counts(0..3), Some(vec![2, 2, 1]));
+}
+
+#[test]
+fn edit_mode_counts_lines_under_each_entry() {
+    let mut app = app(FormatMode::Edit);
+    // One key per line, as the editor writes JSON
+    let value: serde_json::Value = serde_json::from_str(JSON).unwrap();
+    app.json_input = serde_json::to_string_pretty(&value).unwrap();
+    search(&mut app, "parser");
+    assert_eq!(app.outline_match_counts(0..4), Some(vec![2, 0, 2, 1]));
+}
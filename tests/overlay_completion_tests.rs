@@ -0,0 +1,76 @@
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::Value;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust Book", "context": "", "url": "", "percentage": null, "tags": ["rust", "books"]},
+    {"name": "Rustlings", "context": "", "url": "", "percentage": null, "tags": ["rust", "exercises"]},
+    {"name": "New", "context": "", "url": "", "percentage": null, "tags": []}
+  ],
+  "inside": [
+    {"date": "2024-01-01 00:00:00", "context": "x", "tags": "bookmarks"}
+  ]
+}"#;
+
+/// Overlay open on the third card, typing `text` into field `key`
+fn typing(key: &str, text: &str) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.selected_entry_index = 2;
+    app.start_editing_entry();
+    app.edit_field_index = app.edit_field_keys.iter().position(|k| k == key).unwrap();
+    app.edit_insert_mode = true;
+    app.edit_buffer[app.edit_field_index] = text.to_string();
+    app.edit_cursor_pos = text.chars().count();
+    app.update_edit_completions();
+    app
+}
+
+#[test]
+fn names_complete_from_other_cards() {
+    let mut app = typing("name", "rust");
+    assert_eq!(app.edit_completions, vec!["Rust Book", "Rustlings"]);
+
+    app.cycle_edit_completion(1);
+    app.accept_edit_completion();
+    assert_eq!(app.edit_buffer[0], "Rustlings");
+    assert_eq!(app.edit_cursor_pos, "Rustlings".len());
+    assert!(app.edit_completions.is_empty());
+}
+
+#[test]
+fn tags_complete_the_word_at_the_cursor() {
+    let mut app = typing("tags", r#"["rust", "boo"#);
+    // Tags of both sections; "rust" is not offered again once in the list
+    assert_eq!(app.edit_completions, vec!["bookmarks", "books"]);
+    app.accept_edit_completion();
+    assert_eq!(app.edit_buffer[app.edit_field_index], r#"["rust", "bookmarks"#);
+
+    let app = typing("tags", r#"["rust", "r"#);
+    assert!(app.edit_completions.is_empty());
+}
+
+#[test]
+fn other_fields_and_empty_prefixes_get_nothing() {
+    assert!(typing("url", "http").edit_completions.is_empty());
+    assert!(typing("tags", "[\"").edit_completions.is_empty());
+    assert!(typing("name", "Rust Book").edit_completions.is_empty());
+}
+
+#[test]
+fn popup_is_drawn_and_saving_keeps_the_completion() {
+    let mut app = typing("name", "Rust B");
+    let mut terminal = Terminal::new(TestBackend::new(90, 30)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let text: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+    assert!(text.contains(" Rust Book "));
+    assert!(text.contains(" Tab "));
+
+    app.accept_edit_completion();
+    app.save_edited_entry();
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"][2]["name"], "Rust Book");
+}
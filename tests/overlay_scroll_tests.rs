@@ -1,5 +1,5 @@
 use revw::app::{App, FileMode, FormatMode};
-use revw::overlay_context::{layout_wrapped_text, move_cursor_vertical};
+use revw::wrap::{layout_wrapped_text, move_cursor_vertical};
 
 #[test]
 fn test_overlay_scroll_initialization() {
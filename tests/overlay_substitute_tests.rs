@@ -0,0 +1,72 @@
+use revw::app::{App, FileMode, FormatMode};
+use serde_json::{json, Value};
+
+fn overlay_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = serde_json::to_string_pretty(&json!({
+        "outside": [{"name": "Teh book", "context": "teh first\nteh second, teh end", "url": "", "percentage": null}],
+        "inside": []
+    }))
+    .unwrap();
+    app.convert_json();
+    app.selected_entry_index = 0;
+    app.start_editing_entry();
+    app
+}
+
+fn field(app: &App, key: &str) -> String {
+    let row = app.edit_field_keys.iter().position(|k| k == key).unwrap();
+    app.edit_buffer[row].clone()
+}
+
+fn select(app: &mut App, key: &str) {
+    app.edit_field_index = app.edit_field_keys.iter().position(|k| k == key).unwrap();
+    app.edit_field_editing_mode = true;
+}
+
+#[test]
+fn substitutes_within_the_edited_field_only() {
+    let mut app = overlay_app();
+    select(&mut app, "context");
+
+    app.execute_overlay_command("s/teh/the/");
+    assert_eq!(field(&app, "context"), "the first\nteh second, teh end");
+    assert_eq!(app.status_message, "1 substitution made");
+
+    app.execute_overlay_command("s/teh/the/g");
+    assert_eq!(field(&app, "context"), "the first\nthe second, the end");
+    assert_eq!(app.status_message, "2 substitutions made");
+    assert_eq!(field(&app, "name"), "Teh book");
+
+    // Nothing is written until the overlay is saved
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"][0]["context"], "teh first\nteh second, teh end");
+    app.save_edited_entry();
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(value["outside"][0]["context"], "the first\nthe second, the end");
+}
+
+#[test]
+fn cursor_stays_inside_the_shortened_field() {
+    let mut app = overlay_app();
+    select(&mut app, "name");
+    app.edit_cursor_pos = 7;
+    app.execute_overlay_command("%s/Teh book/X/");
+    assert_eq!(field(&app, "name"), "X");
+    assert_eq!(app.edit_cursor_pos, 1);
+}
+
+#[test]
+fn reports_missing_patterns_and_other_commands() {
+    let mut app = overlay_app();
+    select(&mut app, "url");
+    app.execute_overlay_command("s/teh/the/g");
+    assert_eq!(app.status_message, "Pattern not found: teh");
+
+    app.execute_overlay_command("s//x/");
+    assert_eq!(app.status_message, "Empty pattern");
+    app.execute_overlay_command("w");
+    assert_eq!(app.status_message, "Only :s/old/new/[g] works in the edit overlay");
+    assert!(app.editing_entry);
+}
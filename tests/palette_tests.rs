@@ -0,0 +1,79 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FileMode, FormatMode};
+use revw::keymap::{self, Action, KeyMode};
+
+fn sample_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"outside": [{"name": "Book", "context": "notes", "url": "", "percentage": 10}], "inside": []}"#.to_string();
+    app.convert_json();
+    app
+}
+
+fn press(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool {
+    app.press_key(KeyEvent::new(code, modifiers))
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.palette_push_char(c);
+    }
+}
+
+#[test]
+fn items_carry_the_keys_of_their_mode() {
+    let view = keymap::palette_items(KeyMode::View);
+    let move_down = view.iter().find(|item| item.title == "Move card down").unwrap();
+    assert_eq!(move_down.keys, "J");
+    // Edit-only actions are not offered in View mode
+    assert!(view.iter().all(|item| item.action != Action::YankLine));
+    let top = view.iter().find(|item| item.action == Action::Top).unwrap();
+    assert_eq!(top.keys, "gg");
+    let save = view.iter().find(|item| item.action == Action::Command("w")).unwrap();
+    assert_eq!(save.keys, ":w");
+
+    let edit = keymap::palette_items(KeyMode::Edit);
+    let undo = edit.iter().find(|item| item.action == Action::Undo).unwrap();
+    assert_eq!(undo.keys, "u g-");
+}
+
+#[test]
+fn space_opens_the_palette_and_enter_runs_the_best_match() {
+    let mut app = sample_app();
+    press(&mut app, KeyCode::Char(' '), KeyModifiers::NONE);
+    assert!(app.palette_open);
+    assert_eq!(app.palette_matches.len(), app.palette_items.len());
+
+    type_text(&mut app, "focus");
+    let (best, _) = &app.palette_matches[0];
+    assert_eq!(app.palette_items[*best].title, "Focus mode");
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let screen: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+    assert!(screen.contains("Command palette"));
+
+    assert!(!app.run_selected_palette_item());
+    assert!(!app.palette_open);
+    assert!(app.focus_mode);
+}
+
+#[test]
+fn ctrl_k_opens_it_and_commands_run_from_it() {
+    let mut app = sample_app();
+    press(&mut app, KeyCode::Char('k'), KeyModifiers::CONTROL);
+    assert!(app.palette_open);
+    app.close_palette();
+
+    press(&mut app, KeyCode::Char('k'), KeyModifiers::CONTROL);
+    type_text(&mut app, "tag statistics");
+    assert!(!app.run_selected_palette_item());
+    assert!(app.tag_stats.is_some());
+
+    // Quit from the palette quits
+    app.close_stats();
+    press(&mut app, KeyCode::Char(' '), KeyModifiers::NONE);
+    type_text(&mut app, "quit");
+    assert!(app.run_selected_palette_item());
+}
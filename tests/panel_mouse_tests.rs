@@ -0,0 +1,91 @@
+mod common;
+
+use ratatui::layout::Rect;
+use revw::app::{App, FileMode, FormatMode};
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    fs::create_dir_all(dir.join("notes")).unwrap();
+    fs::write(dir.join("notes/n.md"), "## OUTSIDE\n\n## INSIDE\n").unwrap();
+    fs::write(dir.join("a.json"), r#"{"outside": [{"name": "A", "context": "", "url": "", "percentage": null}], "inside": []}"#).unwrap();
+    dir
+}
+
+fn cards_app() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{
+  "outside": [
+    {"name": "One", "context": "", "url": "", "percentage": null},
+    {"name": "Two", "context": "", "url": "", "percentage": null},
+    {"name": "Three", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": []
+}"#
+    .to_string();
+    app.convert_json();
+    app
+}
+
+#[test]
+fn clicking_an_outline_entry_jumps_to_the_card() {
+    let mut app = cards_app();
+    app.toggle_outline();
+    app.outline_area = Rect::new(60, 0, 20, 10);
+
+    // Row 0 is the top border, so row 3 is the third entry
+    assert!(app.outline_click(65, 3));
+    assert_eq!(app.outline_selected_index, 2);
+    assert_eq!(app.selected_entry_index, 2);
+    assert!(!app.outline_has_focus);
+
+    // Past the last entry only focuses the outline
+    assert!(app.outline_click(65, 7));
+    assert_eq!(app.selected_entry_index, 2);
+    assert!(app.outline_has_focus);
+
+    // Outside the panel the click is not the outline's
+    assert!(!app.outline_click(10, 3));
+}
+
+#[test]
+fn outline_clicks_follow_its_scroll() {
+    let mut app = cards_app();
+    app.toggle_outline();
+    app.outline_area = Rect::new(60, 0, 20, 4);
+    app.outline_scroll = 1;
+    assert!(app.outline_click(61, 2));
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn clicking_a_closed_panel_does_nothing() {
+    let mut app = cards_app();
+    app.outline_area = Rect::new(60, 0, 20, 10);
+    assert!(!app.over_outline(65, 3));
+    assert!(!app.outline_click(65, 3));
+    assert_eq!(app.selected_entry_index, 0);
+}
+
+#[test]
+fn clicking_explorer_entries_folds_folders_and_opens_files() {
+    let dir = temp_dir("click");
+    let mut app = App::new(FormatMode::View);
+    app.explorer_current_dir = dir.clone();
+    app.toggle_explorer();
+    app.explorer_area = Rect::new(0, 0, 20, 10);
+    assert!(app.over_explorer(5, 5));
+
+    // notes/ then a.json: clicking the folder expands it
+    assert!(app.explorer_click(3, 1));
+    assert!(app.explorer_entries[0].is_expanded);
+    assert_eq!(app.explorer_entries.len(), 3);
+
+    // notes/, notes/n.md, a.json: clicking the file opens it
+    assert!(app.explorer_click(3, 3));
+    assert_eq!(app.file_path.as_deref(), Some(dir.join("a.json").as_path()));
+    assert!(!app.explorer_has_focus);
+    assert_eq!(app.relf_entries.len(), 1);
+}
@@ -0,0 +1,71 @@
+use revw::app::{App, FormatMode};
+use revw::paths::expand;
+use std::fs;
+use std::path::PathBuf;
+
+fn home() -> PathBuf {
+    dirs::home_dir().unwrap()
+}
+
+#[test]
+fn tilde_is_the_home_directory() {
+    assert_eq!(expand("~"), home());
+    assert_eq!(expand("~/notes/inbox.md"), home().join("notes/inbox.md"));
+    // Only a leading ~ of its own
+    assert_eq!(expand("~other/x.md"), PathBuf::from("~other/x.md"));
+    assert_eq!(expand("notes/~/x.md"), PathBuf::from("notes/~/x.md"));
+}
+
+#[test]
+fn environment_variables_expand() {
+    let home = std::env::var("HOME").unwrap();
+    assert_eq!(expand("$HOME/notes.md"), PathBuf::from(format!("{}/notes.md", home)));
+    assert_eq!(expand("${HOME}_backup/notes.md"), PathBuf::from(format!("{}_backup/notes.md", home)));
+    assert_eq!(expand("/a/$HOME"), PathBuf::from(format!("/a/{}", home)));
+}
+
+#[test]
+fn unset_variables_stay_as_written() {
+    assert_eq!(expand("$REVW_SURELY_UNSET_VAR/x.md"), PathBuf::from("$REVW_SURELY_UNSET_VAR/x.md"));
+    assert_eq!(expand("${REVW_SURELY_UNSET_VAR}/x.md"), PathBuf::from("${REVW_SURELY_UNSET_VAR}/x.md"));
+    assert_eq!(expand("price$5.md"), PathBuf::from("price$5.md"));
+    assert_eq!(expand("a$/b.md"), PathBuf::from("a$/b.md"));
+    assert_eq!(expand("${HOME/x.md"), PathBuf::from("${HOME/x.md"));
+}
+
+#[test]
+fn opening_and_saving_expand_paths() {
+    // cargo sets CARGO_MANIFEST_DIR for running tests
+    let root = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let name = format!("revw_paths_{}", std::process::id());
+    let dir = root.join("target").join(&name);
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("a.json"), r#"{"outside": [{"name": "A", "context": "", "url": "", "percentage": null}], "inside": []}"#).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.command_buffer = format!("e $CARGO_MANIFEST_DIR/target/{}/a.json", name);
+    app.execute_command();
+    assert_eq!(app.file_path, Some(dir.join("a.json")));
+    assert_eq!(app.relf_entries.len(), 1);
+
+    app.save_file_as(&format!("${{CARGO_MANIFEST_DIR}}/target/{}/b.json", name));
+    assert!(dir.join("b.json").exists());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn paths_revw_found_itself_are_not_expanded() {
+    let dir = std::env::temp_dir().join(format!("revw_paths_literal_{}", std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    // A file whose name only looks like a variable, as the explorer or finder would hand it over
+    let literal = dir.join("$HOME.json");
+    fs::write(&literal, r#"{"outside": [{"name": "Literal", "context": "", "url": "", "percentage": null}], "inside": []}"#).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.open_file(literal.clone());
+    assert_eq!(app.file_path, Some(literal));
+    assert_eq!(app.relf_entries.len(), 1);
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,76 @@
+use revw::preview::{is_image_url, local_image_path};
+use std::fs;
+
+#[test]
+fn detects_image_extensions() {
+    assert!(is_image_url("https://example.com/cat.png"));
+    assert!(is_image_url("https://example.com/photo.JPG?size=large"));
+    assert!(is_image_url("file:///tmp/diagram.webp#top"));
+    assert!(!is_image_url("https://example.com/article.html"));
+    assert!(!is_image_url("https://example.com/"));
+}
+
+#[test]
+fn remote_images_are_not_resolved_locally() {
+    assert_eq!(local_image_path("https://example.com/cat.png", None), None);
+}
+
+#[test]
+fn relative_image_paths_resolve_against_base_dir() {
+    let dir = std::env::temp_dir().join(format!("revw_preview_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let image = dir.join("thumb.png");
+    fs::write(&image, b"not really a png").unwrap();
+
+    assert_eq!(local_image_path("thumb.png", Some(&dir)), Some(image.clone()));
+    let file_url = format!("file://{}", image.display());
+    assert_eq!(local_image_path(&file_url, None), Some(image));
+    assert_eq!(local_image_path("missing.png", Some(&dir)), None);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn remote_images_are_noted_once_in_the_status_bar() {
+    let mut app = revw::app::App::new(revw::app::FormatMode::View);
+    let url = "https://example.com/cat.png".to_string();
+    assert!(revw::preview::is_remote_image(&url));
+    assert!(!revw::preview::is_remote_image("cat.png"));
+
+    app.note_remote_preview(Some(url.clone()));
+    assert_eq!(app.status_message, format!("Remote images are not previewed, only local files: {}", url));
+
+    // Not repeated every frame while the card stays selected
+    app.set_status("");
+    app.note_remote_preview(Some(url));
+    assert_eq!(app.status_message, "");
+    app.note_remote_preview(None);
+    assert_eq!(app.preview_remote, None);
+}
+
+#[test]
+fn preview_paths_are_resolved_once_until_the_file_is_reloaded() {
+    let dir = std::env::temp_dir().join(format!("revw_preview_cache_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let image = dir.join("cover.png");
+    fs::write(&image, b"not really a png").unwrap();
+    let notes = dir.join("notes.json");
+    fs::write(&notes, r#"{"outside": [{"name": "Book", "context": "", "url": "cover.png", "percentage": null}], "inside": []}"#).unwrap();
+
+    let mut app = revw::app::App::new(revw::app::FormatMode::View);
+    app.load_file(notes);
+    assert_eq!(app.preview_path("cover.png"), Some(image.clone()));
+
+    // Drawing again does not look at the disk
+    fs::remove_file(&image).unwrap();
+    assert_eq!(app.preview_path("cover.png"), Some(image.clone()));
+
+    app.reload_file();
+    assert_eq!(app.preview_path("cover.png"), None);
+    fs::write(&image, b"back again").unwrap();
+    assert_eq!(app.preview_path("cover.png"), None);
+    app.reload_file();
+    assert_eq!(app.preview_path("cover.png"), Some(image));
+
+    let _ = fs::remove_dir_all(&dir);
+}
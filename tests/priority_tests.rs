@@ -0,0 +1,125 @@
+mod common;
+
+use common::view_app;
+use revw::app::FileMode;
+use revw::json_ops::JsonOperations;
+use revw::markdown_ops::MarkdownOperations;
+use revw::priority::{self, PriorityFilter};
+use serde_json::{json, Value};
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Low", "context": "", "url": "", "percentage": null, "priority": "low"},
+    {"name": "None", "context": "", "url": "", "percentage": null},
+    {"name": "Four", "context": "", "url": "", "percentage": null, "priority": 4},
+    {"name": "High", "context": "", "url": "", "percentage": null, "priority": "high"}
+  ],
+  "inside": [
+    {"date": "2025-01-01 00:00:00", "context": "memo"}
+  ]
+}"#;
+
+fn names(json_input: &str) -> Vec<String> {
+    let value: Value = serde_json::from_str(json_input).unwrap();
+    value["outside"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn priority_words_and_numbers_share_one_scale() {
+    assert_eq!(priority::rank(&json!("high")), Some(5));
+    assert_eq!(priority::rank(&json!("Medium")), Some(3));
+    assert_eq!(priority::rank(&json!("low")), Some(1));
+    assert_eq!(priority::rank(&json!(4)), Some(4));
+    assert_eq!(priority::rank(&json!(6)), None);
+    assert_eq!(priority::rank(&json!("urgent")), None);
+    assert_eq!(priority::value_from_text(" 2 "), Some(json!(2)));
+    assert_eq!(priority::value_from_text("HIGH"), Some(json!("high")));
+}
+
+#[test]
+fn priority_filter_compares_ranks() {
+    let filter = PriorityFilter::parse("priority>=high").unwrap();
+    assert!(filter.matches(Some(5)));
+    assert!(!filter.matches(Some(4)));
+    assert!(!filter.matches(None));
+    assert!(PriorityFilter::parse("priority < 3").unwrap().matches(Some(2)));
+    assert!(PriorityFilter::parse("priority").is_none());
+    assert!(PriorityFilter::parse("priority>=urgent").is_none());
+
+    let mut app = view_app(JSON, FileMode::Json);
+    app.filter_pattern = "priority>=4".to_string();
+    app.convert_json();
+    let shown: Vec<_> = app.relf_entries.iter().map(|e| e.name.clone().unwrap()).collect();
+    assert_eq!(shown, vec!["Four", "High"]);
+
+    let filtered = JsonOperations::filter_entries(&serde_json::from_str(JSON).unwrap(), "priority=low");
+    assert_eq!(names(&filtered.to_string()), vec!["Low"]);
+    assert_eq!(filtered["inside"], json!([]));
+}
+
+#[test]
+fn priority_roundtrips_through_markdown() {
+    let app = view_app(JSON, FileMode::Markdown);
+    let markdown = app.convert_to_markdown().unwrap();
+    assert!(markdown.contains("**Priority:** low"), "{}", markdown);
+    assert!(markdown.contains("**Priority:** 4"), "{}", markdown);
+
+    let back: Value = serde_json::from_str(&app.parse_markdown(&markdown).unwrap()).unwrap();
+    let original: Value = serde_json::from_str(JSON).unwrap();
+    assert_eq!(back, original);
+    assert_eq!(JsonOperations::validate_strict(JSON), Ok(()));
+    assert_eq!(MarkdownOperations::validate_strict(&markdown), Ok(()));
+}
+
+#[test]
+fn strict_mode_rejects_invalid_priorities() {
+    let err = MarkdownOperations::validate_strict("## OUTSIDE\n### A\n**Priority:** urgent\n").unwrap_err();
+    assert!(err.contains("invalid priority"), "{}", err);
+    let err = MarkdownOperations::validate_strict("## INSIDE\n### 2025-01-01\n**Priority:** high\n").unwrap_err();
+    assert!(err.contains("only allowed in OUTSIDE"), "{}", err);
+    let err = JsonOperations::validate_strict(r#"{"outside": [{"name": "A", "priority": 9}]}"#).unwrap_err();
+    assert!(err.contains("\"priority\" has the wrong type"), "{}", err);
+}
+
+#[test]
+fn sort_priority_orders_highest_first_in_both_formats() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.command_buffer = "sort priority".to_string();
+    app.execute_command();
+    assert_eq!(names(&app.json_input), vec!["High", "Four", "Low", "None"]);
+    assert_eq!(app.status_message, "Ordered by priority");
+
+    let mut app = view_app(JSON, FileMode::Markdown);
+    app.markdown_input = app.convert_to_markdown().unwrap();
+    app.order_by_priority();
+    assert_eq!(names(&app.json_input), vec!["High", "Four", "Low", "None"]);
+    assert!(app.markdown_input.contains("**Priority:** high"));
+}
+
+#[test]
+fn priority_is_editable_in_overlay() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 1; // "None"
+    app.start_editing_entry();
+    assert_eq!(app.edit_field_key(4), "priority");
+    assert!(app.edit_buffer_is_placeholder[4]);
+
+    app.edit_buffer[4] = "medium".to_string();
+    app.edit_buffer_is_placeholder[4] = false;
+    app.save_edited_entry();
+    let saved: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert_eq!(saved["outside"][1]["priority"], json!("medium"));
+    assert_eq!(app.relf_entries[1].priority, Some(3));
+
+    // Clearing the row removes the field
+    app.start_editing_entry();
+    app.edit_buffer[4] = String::new();
+    app.save_edited_entry();
+    let saved: Value = serde_json::from_str(&app.json_input).unwrap();
+    assert!(saved["outside"][1].get("priority").is_none());
+}
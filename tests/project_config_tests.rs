@@ -0,0 +1,90 @@
+mod common;
+
+use revw::app::{App, FormatMode};
+use revw::config::rc::find_project_rc;
+use revw::config::{AutosavePolicy, RcConfig};
+use revw::hooks::Hooks;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = common::temp_dir(name);
+    fs::create_dir_all(dir.join("work/meetings/2025")).unwrap();
+    dir
+}
+
+#[test]
+fn the_nearest_project_config_is_found_upward() {
+    let dir = temp_dir("find");
+    assert_eq!(find_project_rc(&dir.join("work/meetings/2025"), None), None);
+
+    fs::write(dir.join("work/.revwrc"), "set autosave=manual\n").unwrap();
+    assert_eq!(find_project_rc(&dir.join("work/meetings/2025"), None), Some(dir.join("work/.revwrc")));
+
+    fs::write(dir.join("work/meetings/.revwrc"), "").unwrap();
+    assert_eq!(find_project_rc(&dir.join("work/meetings/2025"), None), Some(dir.join("work/meetings/.revwrc")));
+
+    // The global rc is not a project config
+    let global = dir.join("work/meetings/.revwrc");
+    assert_eq!(find_project_rc(&dir.join("work/meetings/2025"), Some(&global)), Some(dir.join("work/.revwrc")));
+}
+
+#[test]
+fn project_settings_override_the_global_ones() {
+    let dir = temp_dir("layer");
+    let project = dir.join("work/.revwrc");
+    fs::write(&project, "colorscheme Morning\nset autosave=manual\nset card=3\n").unwrap();
+
+    let mut config = RcConfig { max_visible_cards: 7, confirm_delete: true, ..RcConfig::default() };
+    config.apply_project(&project);
+
+    assert_eq!(config.autosave, AutosavePolicy::Manual);
+    assert_eq!(config.max_visible_cards, 3);
+    assert_eq!(config.colorscheme.name, "Morning");
+    // Settings the project leaves alone keep their global value
+    assert!(config.confirm_delete);
+    assert_eq!(config.project_rc, Some(project));
+
+    let app = App::with_config(FormatMode::View, config);
+    assert_eq!(app.autosave, AutosavePolicy::Manual);
+    assert_eq!(app.max_visible_cards, 3);
+}
+
+#[test]
+fn project_configs_cannot_run_commands() {
+    let dir = temp_dir("hooks");
+    let project = dir.join("work/.revwrc");
+    fs::write(&project, "hook post_save touch pwned\nsync password_cmd cat secret\nsync url https://example.com/\n").unwrap();
+
+    let mut config = RcConfig::default();
+    config.apply_project(&project);
+    assert_eq!(config.hooks, Hooks::default());
+    assert_eq!(config.sync.password_cmd, None);
+    assert_eq!(config.sync.url, None);
+}
+
+#[test]
+fn project_configs_cannot_pick_the_log_file() {
+    let dir = temp_dir("log");
+    let project = dir.join("work/.revwrc");
+    fs::write(&project, "set log_file=/tmp/elsewhere.log\nset card=3\n").unwrap();
+
+    let mut config = RcConfig { log_file: Some(PathBuf::from("/home/me/revw.log")), ..RcConfig::default() };
+    config.apply_project(&project);
+    assert_eq!(config.log_file, Some(PathBuf::from("/home/me/revw.log")));
+    assert_eq!(config.max_visible_cards, 3);
+}
+
+#[test]
+fn relative_paths_are_relative_to_the_project_folder() {
+    let dir = temp_dir("paths");
+    let project = dir.join("work/.revwrc");
+    fs::write(&project, "set default_file=inbox.md\nset skeleton_md=templates/note.md\n").unwrap();
+
+    let mut config = RcConfig { skeleton_json: Some(PathBuf::from("global.json")), ..RcConfig::default() };
+    config.apply_project(&project);
+    assert_eq!(config.default_file, Some(dir.join("work/inbox.md")));
+    assert_eq!(config.skeleton_md, Some(dir.join("work/templates/note.md")));
+    // Not set by the project, so left as it was
+    assert_eq!(config.skeleton_json, Some(PathBuf::from("global.json")));
+}
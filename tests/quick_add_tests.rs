@@ -0,0 +1,70 @@
+mod common;
+
+use common::{run, value, view_app};
+use revw::app::{App, FileMode, FormatMode};
+use revw::config::DefaultSection;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "A", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2024-01-01 00:00:00", "context": "old"}
+  ]
+}"#;
+
+#[test]
+fn quick_add_goes_to_inside_by_default() {
+    let mut app = view_app(JSON, FileMode::Json);
+    let undo_depth = app.undo_stack.len();
+    run(&mut app, "a remember the milk");
+
+    let value = value(&app);
+    assert_eq!(value["inside"].as_array().unwrap().len(), 2);
+    assert_eq!(value["inside"][0]["context"], "remember the milk");
+    assert_eq!(value["inside"][0]["date"].as_str().unwrap().len(), "2024-01-01 00:00:00".len());
+    assert_eq!(app.status_message, "Added to INSIDE");
+    assert!(!app.editing_entry);
+    assert_eq!(app.undo_stack.len(), undo_depth + 1);
+    // The new note is selected
+    assert_eq!(app.relf_entries[app.selected_entry_index].context.as_deref(), Some("remember the milk"));
+}
+
+#[test]
+fn quick_add_to_outside_names_the_entry_or_sets_its_url() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.default_section = DefaultSection::Outside;
+    run(&mut app, "a Rust book");
+    run(&mut app, "a https://doc.rust-lang.org/book/");
+
+    let value = value(&app);
+    let outside = value["outside"].as_array().unwrap();
+    assert_eq!(outside.len(), 3);
+    assert_eq!(outside[1]["name"], "Rust book");
+    assert_eq!(outside[2]["name"], "");
+    assert_eq!(outside[2]["url"], "https://doc.rust-lang.org/book/");
+    assert_eq!(app.status_message, "Added to OUTSIDE");
+    assert_eq!(app.selected_entry_index, 2);
+}
+
+#[test]
+fn default_section_can_be_set_at_runtime() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "set default_section=outside");
+    assert_eq!(app.default_section, DefaultSection::Outside);
+    assert_eq!(app.status_message, "Default section set to outside");
+
+    run(&mut app, "set default_section=middle");
+    assert_eq!(app.status_message, "Default section must be inside or outside");
+
+    run(&mut app, "a");
+    assert_eq!(app.status_message, "Usage: :a TEXT");
+    assert_eq!(app.json_input, JSON);
+}
+
+#[test]
+fn quick_add_works_on_an_empty_buffer() {
+    let mut app = App::new(FormatMode::View);
+    run(&mut app, "a first note");
+    assert_eq!(value(&app)["inside"][0]["context"], "first note");
+}
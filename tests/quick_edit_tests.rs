@@ -0,0 +1,113 @@
+mod common;
+
+use common::view_app;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use revw::app::{App, FileMode, InputMode};
+use revw::keymap::parse_keys;
+use serde_json::{json, Value};
+
+fn sample_app() -> App {
+    let json_input = serde_json::to_string_pretty(&json!({
+        "outside": [{"name": "Book", "context": "notes", "url": "https://example.com", "percentage": 40}],
+        "inside": [{"date": "2025-01-01 09:00:00", "context": "note"}]
+    }))
+    .unwrap();
+    view_app(&json_input, FileMode::Json)
+}
+
+fn press(app: &mut App, keys: &str) {
+    for key in parse_keys(keys).unwrap() {
+        let event = KeyEvent::new(key.code, KeyModifiers::NONE);
+        match app.input_mode {
+            InputMode::Field => match key.code {
+                KeyCode::Esc => app.cancel_field_edit(),
+                KeyCode::Enter => app.accept_field_edit(),
+                KeyCode::Backspace => {
+                    app.field_edit_buffer.pop();
+                }
+                KeyCode::Char(c) => app.field_edit_buffer.push(c),
+                _ => {}
+            },
+            _ => {
+                app.press_key(event);
+            }
+        }
+    }
+}
+
+fn outside(app: &App) -> Value {
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    value["outside"][0].clone()
+}
+
+#[test]
+fn cn_prefills_the_name_and_saves_it() {
+    let mut app = sample_app();
+    press(&mut app, "cn");
+    assert!(app.input_mode == InputMode::Field);
+    assert_eq!(app.field_edit_buffer, "Book");
+    assert_eq!(app.status_message, "name: Book");
+
+    press(&mut app, "<BS><BS><BS><BS>Atlas<Enter>");
+    assert!(app.input_mode == InputMode::Normal);
+    assert_eq!(outside(&app)["name"], "Atlas");
+    assert_eq!(outside(&app)["context"], "notes");
+    assert_eq!(app.status_message, "name updated");
+
+    app.undo();
+    assert_eq!(outside(&app)["name"], "Book");
+}
+
+#[test]
+fn cu_normalizes_the_url() {
+    let mut app = sample_app();
+    app.url_rules.add_scheme = true;
+    press(&mut app, "cu");
+    app.field_edit_buffer = "rust-lang.org".to_string();
+    press(&mut app, "<Enter>");
+    assert_eq!(outside(&app)["url"], "https://rust-lang.org");
+}
+
+#[test]
+fn cp_checks_the_range_and_empty_clears() {
+    let mut app = sample_app();
+    press(&mut app, "cp");
+    assert_eq!(app.field_edit_buffer, "40");
+
+    app.field_edit_buffer = "150".to_string();
+    press(&mut app, "<Enter>");
+    assert_eq!(app.status_message, "Percentage must be a number from 0 to 100");
+    assert_eq!(outside(&app)["percentage"], 40);
+
+    press(&mut app, "cp");
+    app.field_edit_buffer = "75%".to_string();
+    press(&mut app, "<Enter>");
+    assert_eq!(outside(&app)["percentage"], 75);
+
+    press(&mut app, "cp");
+    app.field_edit_buffer.clear();
+    press(&mut app, "<Enter>");
+    assert_eq!(outside(&app)["percentage"], Value::Null);
+}
+
+#[test]
+fn esc_and_unchanged_values_leave_the_card_alone() {
+    let mut app = sample_app();
+    let before = app.json_input.clone();
+    press(&mut app, "cnx<Esc>");
+    assert!(app.input_mode == InputMode::Normal);
+    assert_eq!(app.json_input, before);
+
+    press(&mut app, "cn<Enter>");
+    assert_eq!(app.status_message, "name unchanged");
+    assert!(!app.is_modified);
+}
+
+#[test]
+fn inside_cards_have_no_quick_edit_fields() {
+    let mut app = sample_app();
+    app.selected_entry_index = 1;
+    press(&mut app, "cu");
+    assert!(app.input_mode == InputMode::Normal);
+    assert_eq!(app.status_message, "INSIDE cards have no url");
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::run;
+use ratatui::{backend::TestBackend, Terminal};
+use revw::app::{App, FormatMode};
+use revw::recent;
+use std::{fs, path::{Path, PathBuf}};
+
+fn temp_dir(name: &str) -> PathBuf {
+    fs::canonicalize(common::temp_dir(name)).unwrap()
+}
+
+fn write_doc(dir: &Path, name: &str) -> PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, format!("## OUTSIDE\n\n### {}\n", name)).unwrap();
+    path
+}
+
+#[test]
+fn record_moves_file_to_top_and_keeps_the_newest() {
+    let dir = temp_dir("record");
+    let list = dir.join("state").join("recent");
+    let a = write_doc(&dir, "a.md");
+    let b = write_doc(&dir, "b.md");
+
+    recent::record(&list, &a).unwrap();
+    recent::record(&list, &b).unwrap();
+    recent::record(&list, &a).unwrap();
+    assert_eq!(recent::load(&list), vec![a.clone(), b.clone()]);
+
+    for i in 0..recent::MAX_RECENT + 5 {
+        recent::record(&list, &dir.join(format!("{}.md", i))).unwrap();
+    }
+    let paths = recent::load(&list);
+    assert_eq!(paths.len(), recent::MAX_RECENT);
+    assert_eq!(paths[0], dir.join(format!("{}.md", recent::MAX_RECENT + 4)));
+}
+
+#[test]
+fn recent_lists_other_existing_files_and_reopens_them() {
+    let dir = temp_dir("reopen");
+    let a = write_doc(&dir, "a.md");
+    let b = write_doc(&dir, "b.md");
+    let gone = write_doc(&dir, "gone.md");
+
+    let mut app = App::new(FormatMode::View);
+    app.recent_list_path = Some(dir.join("recent"));
+    app.load_file(gone.clone());
+    app.load_file(a.clone());
+    app.load_file(b.clone());
+    fs::remove_file(&gone).unwrap();
+
+    run(&mut app, "recent");
+    assert!(app.recent_open);
+    // The open file and deleted files are left out
+    assert_eq!(app.recent_files, vec![a.clone()]);
+
+    app.open_selected_recent_file();
+    assert!(!app.recent_open);
+    assert_eq!(app.file_path.as_deref(), Some(a.as_path()));
+    assert_eq!(recent::load(&dir.join("recent"))[0], a);
+}
+
+#[test]
+fn recent_without_history_says_so() {
+    let dir = temp_dir("empty");
+    let mut app = App::new(FormatMode::View);
+    run(&mut app, "recent");
+    assert_eq!(app.status_message, "No data directory for recent files");
+
+    app.recent_list_path = Some(dir.join("recent"));
+    run(&mut app, "recent");
+    assert!(!app.recent_open);
+    assert_eq!(app.status_message, "No recent files");
+}
+
+#[test]
+fn startup_screen_fills_the_window_without_a_file() {
+    let dir = temp_dir("startup");
+    let a = write_doc(&dir, "notes.md");
+    let list = dir.join("recent");
+    recent::record(&list, &a).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.recent_list_path = Some(list);
+    app.open_recent_list();
+    assert!(app.recent_open);
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+    terminal.draw(|f| revw::ui::ui(f, &mut app)).unwrap();
+    let screen: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+    assert!(screen.contains("Recent files (1)"));
+    assert!(screen.contains("notes.md"));
+}
@@ -0,0 +1,70 @@
+use revw::app::{App, FormatMode};
+use serde_json::json;
+use std::{fs, path::PathBuf};
+
+fn write(path: &PathBuf, names: &[&str]) {
+    let outside: Vec<_> = names
+        .iter()
+        .map(|name| json!({"name": name, "context": "", "url": format!("https://{}.example", name.to_lowercase()), "percentage": null}))
+        .collect();
+    let value = json!({"outside": outside, "inside": [{"date": "2024-01-01 00:00:00", "context": "note"}]});
+    fs::write(path, serde_json::to_string_pretty(&value).unwrap()).unwrap();
+}
+
+fn loaded(name: &str, names: &[&str]) -> (App, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("revw_reload_{}_{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    write(&path, names);
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+    (app, path)
+}
+
+fn selected_name(app: &App) -> Option<String> {
+    let entry = &app.relf_entries[app.selected_entry_index];
+    entry.name.clone().or(entry.date.clone())
+}
+
+#[test]
+fn selection_follows_the_entry_when_cards_are_added_above() {
+    let (mut app, path) = loaded("added", &["A", "B", "C"]);
+    app.selected_entry_index = 1;
+    write(&path, &["New", "Other", "A", "B", "C"]);
+    app.reload_file();
+    assert_eq!(selected_name(&app).as_deref(), Some("B"));
+    assert_eq!(app.selected_entry_index, 3);
+
+    // INSIDE entries are tracked by date
+    app.selected_entry_index = 5;
+    write(&path, &["A", "B", "C"]);
+    app.reload_file();
+    assert_eq!(selected_name(&app).as_deref(), Some("2024-01-01 00:00:00"));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn removed_entry_selects_the_nearest_remaining_one() {
+    let (mut app, path) = loaded("removed", &["A", "B", "C", "D"]);
+    app.selected_entry_index = 2;
+    write(&path, &["A", "B", "D"]);
+    app.reload_file();
+    assert_eq!(selected_name(&app).as_deref(), Some("D"));
+
+    // With nothing after it left in OUTSIDE, the INSIDE entry after it is next
+    write(&path, &["A", "B"]);
+    app.reload_file();
+    assert_eq!(selected_name(&app).as_deref(), Some("2024-01-01 00:00:00"));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn renamed_entry_with_the_same_url_stays_selected() {
+    let (mut app, path) = loaded("renamed", &["A", "B", "C"]);
+    app.selected_entry_index = 1;
+    let content = fs::read_to_string(&path).unwrap().replacen("\"B\"", "\"B, second edition\"", 1);
+    fs::write(&path, content.replacen("\"A\"", "\"A0\"", 1).replacen("https://a.example", "https://a0.example", 1)).unwrap();
+    app.reload_file();
+    assert_eq!(selected_name(&app).as_deref(), Some("B, second edition"));
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
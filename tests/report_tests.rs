@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use revw::report;
+use std::fs;
+use std::process::Command;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust book", "context": "", "url": "", "percentage": 40},
+    {"name": "Tokio guide", "context": "", "url": "", "percentage": 0},
+    {"name": "Axum", "context": "", "url": "", "percentage": 75},
+    {"name": "Serde", "context": "", "url": "", "percentage": 100},
+    {"name": "Diesel", "context": "", "url": "", "percentage": 30, "status": "done"},
+    {"name": "Actix", "context": "", "url": "", "percentage": 10, "status": "dropped"}
+  ],
+  "inside": [
+    {"date": "2024-05-09 20:00:00", "context": "Finished chapter 8 of the Rust book\nmore"},
+    {"date": "2024-05-06 08:00:00", "context": "Tried axum extractors"},
+    {"date": "2024-04-01 08:00:00", "context": "Tokio guide bookmarked"}
+  ]
+}"#;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, 10).unwrap()
+}
+
+#[test]
+fn progress_is_counted_by_completion_bucket() {
+    let text = report::generate(JSON, today()).unwrap();
+    assert!(text.starts_with("# Weekly review (2024-05-10)\n"), "{}", text);
+    assert!(text.contains("| Not started | 1 |\n| 1-49% | 1 |\n| 50-99% | 1 |\n| Complete | 2 |\n| Dropped | 1 |\n"), "{}", text);
+}
+
+#[test]
+fn recent_notes_are_newest_first_and_one_line() {
+    let text = report::generate(JSON, today()).unwrap();
+    assert!(
+        text.contains("## Recent notes (last 7 days)\n\n- 2024-05-09 20:00:00: Finished chapter 8 of the Rust book\n- 2024-05-06 08:00:00: Tried axum extractors\n\n"),
+        "{}",
+        text
+    );
+    assert!(!text.contains("bookmarked"));
+}
+
+#[test]
+fn untouched_resources_come_oldest_note_first() {
+    let text = report::generate(JSON, today()).unwrap();
+    let untouched = text.split("## Longest untouched\n\n").nth(1).unwrap();
+    assert_eq!(
+        untouched,
+        "- **Tokio guide** (0%): last note 2024-04-01\n- **Axum** (75%): last note 2024-05-06\n- **Rust book** (40%): last note 2024-05-09\n"
+    );
+
+    let text = report::generate(r#"{"outside": [{"name": "Lonely"}], "inside": []}"#, today()).unwrap();
+    assert!(text.contains("_No new notes._"));
+    assert!(text.ends_with("- **Lonely**: no notes\n"), "{}", text);
+}
+
+#[test]
+fn invalid_json_is_an_error() {
+    assert!(report::generate("not json", today()).is_err());
+}
+
+#[test]
+fn cli_prints_the_report() {
+    let dir = std::env::temp_dir().join(format!("revw_report_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.md");
+    fs::write(&path, "## OUTSIDE\n\n### Rust\nbook\n**Percentage:** 50%\n\n## INSIDE\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).arg("--report").arg(&path).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("| 50-99% | 1 |"), "{}", stdout);
+    assert!(stdout.contains("- **Rust** (50%): no notes"), "{}", stdout);
+}
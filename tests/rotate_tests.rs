@@ -0,0 +1,119 @@
+mod common;
+
+use common::temp_dir;
+use chrono::{Local, NaiveDate};
+use revw::app::{App, FormatMode};
+use revw::rotate::{self, Archive};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+fn now() -> String {
+    Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn document() -> Value {
+    json!({
+        "outside": [{"name": "Book", "context": "", "url": "", "percentage": null}],
+        "inside": [
+            {"date": now(), "context": "this month"},
+            {"date": "2024-02-03 09:00:00", "context": "february"},
+            {"date": "", "context": "undated"},
+            {"date": "2024-01-31 23:00:00", "context": "january late"},
+            {"date": "2024-01-02 08:00:00", "context": "january early"}
+        ]
+    })
+}
+
+fn contexts(value: &Value) -> Vec<String> {
+    value["inside"].as_array().unwrap().iter().map(|e| e["context"].as_str().unwrap().to_string()).collect()
+}
+
+fn read(path: &Path) -> Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn entries_before_this_month_are_bucketed_by_month() {
+    let today = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+    let (kept, months) = rotate::split_monthly(&document(), today);
+    assert_eq!(contexts(&kept), ["this month", "february", "undated"]);
+    assert_eq!(months.keys().collect::<Vec<_>>(), ["2024-01"]);
+    assert_eq!(months["2024-01"].len(), 2);
+
+    assert_eq!(rotate::month_of("2024-01-31 23:00:00").as_deref(), Some("2024-01"));
+    assert_eq!(rotate::month_of("someday"), None);
+    assert_eq!(rotate::archive_path(Path::new("/x/notes.md"), "2024-01"), PathBuf::from("/x/notes-2024-01.md"));
+}
+
+#[test]
+fn rotation_writes_archives_and_can_run_again() {
+    let dir = temp_dir("archives");
+    let path = dir.join("notes.json");
+    let today = Local::now().date_naive();
+    let (kept, archives) = rotate::rotate_monthly(&path, &document(), today).unwrap();
+    assert_eq!(contexts(&kept), ["this month", "undated"]);
+    assert_eq!(
+        archives,
+        [
+            Archive { month: "2024-01".to_string(), path: dir.join("notes-2024-01.json"), moved: 2 },
+            Archive { month: "2024-02".to_string(), path: dir.join("notes-2024-02.json"), moved: 1 },
+        ]
+    );
+    assert_eq!(
+        rotate::summary(&archives),
+        "Moved 3 note(s): 2024-01 (2) → notes-2024-01.json, 2024-02 (1) → notes-2024-02.json"
+    );
+    assert_eq!(contexts(&read(&dir.join("notes-2024-01.json"))), ["january late", "january early"]);
+
+    // A rerun from the unrotated file does not repeat entries in the archives
+    rotate::rotate_monthly(&path, &document(), today).unwrap();
+    assert_eq!(contexts(&read(&dir.join("notes-2024-01.json"))).len(), 2);
+    let (_, archives) = rotate::rotate_monthly(&path, &kept, today).unwrap();
+    assert_eq!(rotate::summary(&archives), "Nothing to rotate: no INSIDE notes before this month");
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn rotate_command_moves_notes_and_saves() {
+    let dir = temp_dir("command");
+    let path = dir.join("notes.json");
+    fs::write(&path, serde_json::to_string_pretty(&document()).unwrap()).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.clone());
+
+    app.command_buffer = "rotate".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Usage: :rotate monthly");
+
+    app.command_buffer = "rotate monthly".to_string();
+    app.execute_command();
+    assert!(app.status_message.starts_with("Moved 3 note(s)"), "{}", app.status_message);
+    assert!(!app.is_modified);
+    assert_eq!(contexts(&read(&path)), ["this month", "undated"]);
+    assert_eq!(contexts(&read(&dir.join("notes-2024-02.json"))), ["february"]);
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn cli_rotates_markdown_files() {
+    let dir = temp_dir("cli");
+    let path = dir.join("notes.md");
+    let markdown = format!(
+        "## OUTSIDE\n\n### Book\n\n## INSIDE\n\n### {}\n\nthis month\n\n### 2024-01-02 08:00:00\n\njanuary\n",
+        now()
+    );
+    fs::write(&path, markdown).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_revw")).args(["--rotate", "monthly"]).arg(&path).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Moved 1 note(s): 2024-01 (1) → notes-2024-01.md"));
+
+    let main = fs::read_to_string(&path).unwrap();
+    assert!(main.contains("this month") && !main.contains("january"), "{}", main);
+    let archive = fs::read_to_string(dir.join("notes-2024-01.md")).unwrap();
+    assert!(archive.contains("### 2024-01-02 08:00:00") && archive.contains("january"), "{}", archive);
+    fs::remove_dir_all(dir).ok();
+}
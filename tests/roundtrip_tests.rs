@@ -0,0 +1,264 @@
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use revw::app::{App, FileMode, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+
+const CASES: u64 = 200;
+const WORDS: &[&str] = &["alpha", "beta", "日本語", "café", "x", "42", "a-b", "emoji 🎉", "tab\tin"];
+
+fn random_text(rng: &mut StdRng, max_words: usize) -> String {
+    let count = rng.random_range(1..=max_words);
+    (0..count)
+        .map(|_| WORDS[rng.random_range(0..WORDS.len())])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn random_context(rng: &mut StdRng) -> String {
+    if rng.random_bool(0.2) {
+        return String::new();
+    }
+    let lines = rng.random_range(1..=4);
+    (0..lines)
+        .map(|_| random_text(rng, 5))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Random document in canonical form (the shape Markdown parsing produces)
+fn random_document(rng: &mut StdRng) -> Value {
+    let outside: Vec<Value> = (0..rng.random_range(0..6))
+        .map(|_| {
+            let name = if rng.random_bool(0.1) { String::new() } else { random_text(rng, 3) };
+            let url = if rng.random_bool(0.5) {
+                format!("https://example.com/{}", rng.random_range(0..1000))
+            } else {
+                String::new()
+            };
+            let percentage = if rng.random_bool(0.5) {
+                Value::from(rng.random_range(0..=100))
+            } else {
+                Value::Null
+            };
+            json!({ "name": name, "context": random_context(rng), "url": url, "percentage": percentage })
+        })
+        .collect();
+
+    let inside: Vec<Value> = (0..rng.random_range(0..6))
+        .map(|i| {
+            json!({
+                "date": format!("2025-01-{:02} 10:00:00", 28 - i),
+                "context": random_context(rng)
+            })
+        })
+        .collect();
+
+    json!({ "outside": outside, "inside": inside })
+}
+
+/// Lines that look like Markdown structure, fields or escapes
+const TRICKY_LINES: &[&str] = &[
+    "## OUTSIDE",
+    "## INSIDE",
+    "### not a title",
+    "###",
+    "#### deeper",
+    "  ## indented",
+    "# plain heading",
+    "**URL:** https://example.com",
+    "**Percentage:** 50%",
+    "**Priority:** high",
+    "**Due:** 2025-01-01",
+    "**Attachment:** a.png",
+    "**tag:** value",
+    "**bold** text",
+    "\\### escaped already",
+    "\\\\**URL:** twice",
+    "\\plain backslash",
+    "```",
+    "```rust",
+];
+
+fn tricky_context(rng: &mut StdRng) -> String {
+    let lines = rng.random_range(1..=6);
+    (0..lines)
+        .map(|_| {
+            if rng.random_bool(0.6) {
+                TRICKY_LINES[rng.random_range(0..TRICKY_LINES.len())].to_string()
+            } else {
+                random_text(rng, 3)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn markdown_app(json_input: &str) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Markdown;
+    app.json_input = json_input.to_string();
+    app
+}
+
+fn keys_of(value: &Value, section: &str) -> Vec<Vec<String>> {
+    value[section]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|e| e.as_object().map(|o| o.keys().cloned().collect()).unwrap_or_default())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn json_markdown_json_roundtrip_is_lossless() {
+    for seed in 0..CASES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let doc = random_document(&mut rng);
+        let app = markdown_app(&serde_json::to_string_pretty(&doc).unwrap());
+
+        let markdown = app.convert_to_markdown().unwrap();
+        let back: Value = serde_json::from_str(&app.parse_markdown(&markdown).unwrap()).unwrap();
+
+        assert_eq!(back, doc, "seed {} markdown:\n{}", seed, markdown);
+    }
+}
+
+#[test]
+fn markdown_output_is_stable_across_roundtrips() {
+    for seed in 0..CASES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let doc = random_document(&mut rng);
+        let app = markdown_app(&serde_json::to_string_pretty(&doc).unwrap());
+
+        let first = app.convert_to_markdown().unwrap();
+        let reparsed = markdown_app(&app.parse_markdown(&first).unwrap());
+        let second = reparsed.convert_to_markdown().unwrap();
+
+        assert_eq!(first, second, "seed {}", seed);
+    }
+}
+
+#[test]
+fn key_order_survives_reserialization() {
+    // Non-canonical key order must come back exactly as written
+    let input = r#"{
+  "inside": [
+    {
+      "context": "note",
+      "date": "2025-01-01 00:00:00"
+    }
+  ],
+  "outside": [
+    {
+      "percentage": 10,
+      "url": "https://a",
+      "name": "A",
+      "context": "c"
+    }
+  ]
+}"#;
+    let value: Value = serde_json::from_str(input).unwrap();
+    assert_eq!(serde_json::to_string_pretty(&value).unwrap(), input);
+
+    let (ordered, _) = JsonOperations::order_entries(input).unwrap();
+    let ordered: Value = serde_json::from_str(&ordered).unwrap();
+    assert_eq!(
+        ordered.as_object().unwrap().keys().collect::<Vec<_>>(),
+        vec!["inside", "outside"]
+    );
+    assert_eq!(keys_of(&ordered, "outside"), vec![vec!["percentage", "url", "name", "context"]]);
+    assert_eq!(keys_of(&ordered, "inside"), vec![vec!["context", "date"]]);
+}
+
+#[test]
+fn operations_keep_entry_order_and_keys() {
+    for seed in 0..CASES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let doc = random_document(&mut rng);
+        let input = serde_json::to_string_pretty(&doc).unwrap();
+
+        // Appending keeps existing entries in place: outside appended, inside prepended
+        let extra = random_document(&mut rng);
+        let merged = JsonOperations::append_entries(&doc, &extra, false, false);
+        let outside = doc["outside"].as_array().unwrap();
+        let merged_outside = merged["outside"].as_array().unwrap();
+        assert_eq!(&merged_outside[..outside.len()], &outside[..], "seed {}", seed);
+        let inside = doc["inside"].as_array().unwrap();
+        let merged_inside = merged["inside"].as_array().unwrap();
+        assert_eq!(&merged_inside[merged_inside.len() - inside.len()..], &inside[..], "seed {}", seed);
+
+        // Ordering permutes entries but never rewrites them
+        let (ordered, _) = JsonOperations::order_by_name(&input).unwrap();
+        let ordered: Value = serde_json::from_str(&ordered).unwrap();
+        for section in ["outside", "inside"] {
+            let mut before: Vec<String> = doc[section].as_array().unwrap().iter().map(|e| e.to_string()).collect();
+            let mut after: Vec<String> = ordered[section].as_array().unwrap().iter().map(|e| e.to_string()).collect();
+            before.sort();
+            after.sort();
+            assert_eq!(before, after, "seed {} section {}", seed, section);
+        }
+
+        // Ordering an already ordered document is a no-op
+        let once = JsonOperations::order_entries(&input).unwrap().0;
+        let twice = JsonOperations::order_entries(&once).unwrap().0;
+        assert_eq!(once, twice, "seed {}", seed);
+    }
+}
+
+#[test]
+fn cards_follow_outside_then_inside_regardless_of_key_order() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = r#"{"inside": [{"date": "d1", "context": "i"}], "outside": [{"name": "o", "context": ""}]}"#.to_string();
+    app.convert_json();
+
+    assert_eq!(app.relf_entries.len(), 2);
+    assert_eq!(app.relf_entries[0].name.as_deref(), Some("o"));
+    assert_eq!(app.relf_entries[0].original_index, 0);
+    assert_eq!(app.relf_entries[1].date.as_deref(), Some("d1"));
+    assert_eq!(app.relf_entries[1].original_index, 1);
+}
+
+#[test]
+fn contexts_that_look_like_markdown_structure_survive_roundtrips() {
+    for seed in 0..CASES {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let doc = json!({
+            "outside": [
+                { "name": "A", "context": tricky_context(&mut rng), "url": "", "percentage": null },
+                { "name": "B", "context": tricky_context(&mut rng), "url": "https://b.example", "percentage": 10 }
+            ],
+            "inside": [
+                { "date": "2025-01-02 10:00:00", "context": tricky_context(&mut rng) },
+                { "date": "2025-01-01 10:00:00", "context": tricky_context(&mut rng) }
+            ]
+        });
+        let app = markdown_app(&serde_json::to_string_pretty(&doc).unwrap());
+
+        let markdown = app.convert_to_markdown().unwrap();
+        let reparsed_json = app.parse_markdown(&markdown).unwrap();
+        let back: Value = serde_json::from_str(&reparsed_json).unwrap();
+        assert_eq!(back, doc, "seed {} markdown:\n{}", seed, markdown);
+
+        let second = markdown_app(&reparsed_json).convert_to_markdown().unwrap();
+        assert_eq!(markdown, second, "seed {}", seed);
+    }
+}
+
+#[test]
+fn escaping_only_touches_lines_that_need_it() {
+    let doc = json!({
+        "outside": [],
+        "inside": [{
+            "date": "2025-01-01 10:00:00",
+            "context": "# plain heading\n**bold** text\n### looks like a title\n**URL:** not a url\n```sh\n# comment\n```"
+        }]
+    });
+    let app = markdown_app(&serde_json::to_string_pretty(&doc).unwrap());
+    let markdown = app.convert_to_markdown().unwrap();
+
+    assert!(markdown.contains("\n# plain heading\n**bold** text\n\\### looks like a title\n\\**URL:** not a url\n```sh\n# comment\n```"), "{}", markdown);
+}
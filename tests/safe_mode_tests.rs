@@ -0,0 +1,60 @@
+use revw::app::{App, ConfirmAction, FormatMode};
+use revw::config::AutosavePolicy;
+use revw::hooks::{HookEvent, Hooks};
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn safe_mode_turns_off_autosave_watching_hooks_and_clipboard_reads() {
+    let dir = std::env::temp_dir().join(format!("revw_safe_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    let content = r#"{"outside": [{"name": "A", "context": "", "url": "", "percentage": null}], "inside": []}"#;
+    fs::write(&path, content).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.autosave = AutosavePolicy::Always;
+    app.hooks.add(HookEvent::PreSave, "false");
+    app.enter_safe_mode();
+    app.load_file(path.clone());
+    assert!(!app.auto_reload);
+    assert_eq!(app.hooks, Hooks::default());
+    assert!(app.recent_list_path.is_none());
+
+    app.json_input = app.json_input.replace("\"A\"", "\"B\"");
+    app.is_modified = true;
+    app.autosave();
+    assert_eq!(fs::read_to_string(&path).unwrap(), content);
+
+    app.confirm_paste = false;
+    app.confirm_action(ConfirmAction::Paste(None));
+    assert!(app.status_message.contains("Safe mode: clipboard reads are off"));
+
+    app.command_buffer = "ar".to_string();
+    app.execute_command();
+    assert!(!app.auto_reload);
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn append_skips_post_import_hooks_with_safe() {
+    let dir = std::env::temp_dir().join(format!("revw_safe_append_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    let marker = dir.join("hook_ran");
+    fs::write(&path, r#"{"outside": [], "inside": []}"#).unwrap();
+    fs::write(dir.join(".revwrc"), format!("hook post_import touch {}\n", marker.display())).unwrap();
+    fs::write(dir.join("new.json"), r#"{"outside": [{"name": "New"}]}"#).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .env("HOME", &dir)
+        .args(["--safe", "--append", "--input"])
+        .arg(dir.join("new.json"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(fs::read_to_string(&path).unwrap().contains("New"));
+    assert!(!marker.exists());
+    fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,221 @@
+use revw::app::{App, FormatMode};
+use revw::json_ops::JsonOperations;
+use serde_json::{json, Value};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn temp_file(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("revw_conflict_{}_{}", name, std::process::id()));
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("notes.json");
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn document(outside: Value) -> String {
+    serde_json::to_string_pretty(&json!({"outside": outside, "inside": []})).unwrap()
+}
+
+fn loaded_app(path: &Path) -> App {
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path.to_path_buf());
+    app
+}
+
+fn edit_buffer(app: &mut App, outside: Value) {
+    app.json_input = document(outside);
+    app.is_modified = true;
+    app.convert_json();
+}
+
+fn names(json_input: &str) -> Vec<String> {
+    let value: Value = serde_json::from_str(json_input).unwrap();
+    value["outside"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn unchanged_file_saves_without_prompt() {
+    let path = temp_file("clean", &document(json!([{"name": "a", "url": "u1"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "b", "url": "u1"}]));
+
+    app.save_file();
+    assert!(!app.save_conflict);
+    assert!(!app.is_modified);
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["b"]);
+
+    // Saving again after our own write is not a conflict either
+    edit_buffer(&mut app, json!([{"name": "c", "url": "u1"}]));
+    app.save_file();
+    assert!(!app.save_conflict);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn external_change_prompts_instead_of_overwriting() {
+    let path = temp_file("prompt", &document(json!([{"name": "a", "url": "u1"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "mine", "url": "u1"}]));
+    fs::write(&path, document(json!([{"name": "theirs", "url": "u2"}]))).unwrap();
+
+    app.save_file();
+    assert!(app.save_conflict);
+    assert!(app.status_message.contains("changed on disk"), "{}", app.status_message);
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["theirs"]);
+
+    // Cancelling holds autosave so the prompt does not come straight back
+    app.handle_save_conflict('c');
+    assert!(!app.save_conflict);
+    app.autosave();
+    assert!(!app.save_conflict);
+    assert!(app.is_modified);
+
+    app.save_file();
+    assert!(app.save_conflict);
+    app.handle_save_conflict('o');
+    assert!(!app.save_conflict);
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["mine"]);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn reload_answer_takes_disk_version_and_is_undoable() {
+    let path = temp_file("reload", &document(json!([{"name": "a", "url": "u1"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "mine", "url": "u1"}]));
+    fs::write(&path, document(json!([{"name": "theirs", "url": "u1"}]))).unwrap();
+
+    app.save_file();
+    app.handle_save_conflict('r');
+    assert!(!app.save_conflict);
+    assert!(!app.is_modified);
+    assert_eq!(names(&app.json_input), vec!["theirs"]);
+
+    app.undo();
+    assert_eq!(names(&app.json_input), vec!["mine"]);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn merge_answer_combines_both_versions() {
+    let path = temp_file("merge", &document(json!([{"name": "a", "url": "u1"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "a edited", "url": "u1"}, {"name": "mine", "url": "u3"}]));
+    fs::write(&path, document(json!([{"name": "a", "url": "u1"}, {"name": "theirs", "url": "u2"}]))).unwrap();
+
+    app.save_file();
+    app.handle_save_conflict('m');
+    assert!(!app.save_conflict);
+    assert!(!app.is_modified);
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["a edited", "theirs", "mine"]);
+    assert!(app.status_message.starts_with("Merged"), "{}", app.status_message);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn force_write_command_skips_the_check() {
+    let path = temp_file("force", &document(json!([{"name": "a", "url": "u1"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "mine", "url": "u1"}]));
+    fs::write(&path, document(json!([{"name": "theirs", "url": "u1"}]))).unwrap();
+
+    app.command_buffer = "w!".to_string();
+    app.execute_command();
+    assert!(!app.save_conflict);
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["mine"]);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
+
+#[test]
+fn merge_concurrent_keeps_disk_entries_and_applies_buffer_edits() {
+    let disk = json!({
+        "outside": [{"name": "a", "url": "u1"}, {"name": "theirs", "url": "u2"}],
+        "inside": [{"date": "2024-01-02", "context": "disk"}]
+    });
+    let ours = json!({
+        "outside": [{"name": "a2", "url": "u1"}, {"name": "no url", "url": ""}],
+        "inside": [{"date": "2024-01-03", "context": "new"}, {"date": "2024-01-02", "context": "disk"}]
+    });
+    let base = json!({
+        "outside": [{"name": "a", "url": "u1"}],
+        "inside": [{"date": "2024-01-02", "context": "disk"}]
+    });
+    let merged = JsonOperations::merge_concurrent(Some(&base), &disk, &ours);
+    assert_eq!(
+        merged,
+        json!({
+            "outside": [{"name": "a2", "url": "u1"}, {"name": "theirs", "url": "u2"}, {"name": "no url", "url": ""}],
+            "inside": [{"date": "2024-01-03", "context": "new"}, {"date": "2024-01-02", "context": "disk"}]
+        })
+    );
+}
+
+#[test]
+fn merge_concurrent_keeps_deletions_on_either_side() {
+    let base = json!({"outside": [{"name": "a"}, {"name": "b"}, {"name": "c"}], "inside": []});
+    // The buffer deleted b, the disk deleted c and added d
+    let ours = json!({"outside": [{"name": "a"}, {"name": "c"}], "inside": []});
+    let disk = json!({"outside": [{"name": "a"}, {"name": "b"}, {"name": "d"}], "inside": []});
+    let merged = JsonOperations::merge_concurrent(Some(&base), &disk, &ours);
+    assert_eq!(merged, json!({"outside": [{"name": "a"}, {"name": "d"}], "inside": []}));
+
+    // Without a base nothing counts as deleted
+    let merged = JsonOperations::merge_concurrent(None, &disk, &ours);
+    assert_eq!(merged["outside"], json!([{"name": "a"}, {"name": "b"}, {"name": "d"}, {"name": "c"}]));
+}
+
+#[test]
+fn merge_concurrent_keeps_other_notes_with_the_same_date_or_url() {
+    let day = "2024-01-02 09:00:00";
+    let base = json!({"outside": [{"name": "a", "url": "u"}], "inside": [{"date": day, "context": "one"}]});
+    // Disk adds a second note of the same day and another entry with the same URL
+    let disk = json!({
+        "outside": [{"name": "a", "url": "u"}, {"name": "other", "url": "u"}],
+        "inside": [{"date": day, "context": "two"}, {"date": day, "context": "one"}]
+    });
+    // The buffer edits the first note and adds one more of the same day
+    let ours = json!({
+        "outside": [{"name": "a", "url": "u"}],
+        "inside": [{"date": day, "context": "three"}, {"date": day, "context": "one edited"}]
+    });
+    let merged = JsonOperations::merge_concurrent(Some(&base), &disk, &ours);
+    assert_eq!(
+        merged,
+        json!({
+            "outside": [{"name": "a", "url": "u"}, {"name": "other", "url": "u"}],
+            "inside": [
+                {"date": day, "context": "three"},
+                {"date": day, "context": "two"},
+                {"date": day, "context": "one edited"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn merge_answer_does_not_bring_back_deleted_entries() {
+    let path = temp_file("merge_delete", &document(json!([{"name": "a", "url": "u1"}, {"name": "b", "url": "u2"}])));
+    let mut app = loaded_app(&path);
+    edit_buffer(&mut app, json!([{"name": "a", "url": "u1"}]));
+    fs::write(&path, document(json!([{"name": "a", "url": "u1"}, {"name": "b", "url": "u2"}, {"name": "theirs", "url": "u3"}]))).unwrap();
+
+    app.save_file();
+    app.handle_save_conflict('m');
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["a", "theirs"]);
+
+    // The merged save is the base of the next merge
+    edit_buffer(&mut app, json!([{"name": "theirs", "url": "u3"}]));
+    fs::write(&path, document(json!([{"name": "a", "url": "u1"}, {"name": "theirs", "url": "u3"}, {"name": "new", "url": "u4"}]))).unwrap();
+    app.save_file();
+    app.handle_save_conflict('m');
+    assert_eq!(names(&fs::read_to_string(&path).unwrap()), vec!["theirs", "new"]);
+    fs::remove_dir_all(path.parent().unwrap()).ok();
+}
@@ -0,0 +1,63 @@
+use revw::app::{App, FileMode, FormatMode};
+
+fn long_app() -> App {
+    let cards: Vec<String> = (0..40).map(|i| format!(r#"{{"name": "card {}", "context": "", "url": "", "percentage": null}}"#, i)).collect();
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = format!(r#"{{"outside": [{}], "inside": []}}"#, cards.join(","));
+    app.convert_json();
+    app.max_visible_cards = 5;
+    app
+}
+
+fn command(app: &mut App, cmd: &str) {
+    app.command_buffer = cmd.to_string();
+    app.execute_command();
+}
+
+#[test]
+fn instant_scroll_is_the_default() {
+    let mut app = long_app();
+    assert!(!app.smooth_scroll);
+    app.selected_entry_index = 30;
+    assert_eq!(app.next_card_scroll_frame(), 26);
+    assert!(!app.scroll_animating);
+}
+
+#[test]
+fn smooth_scroll_glides_over_several_frames() {
+    let mut app = long_app();
+    command(&mut app, "set smooth_scroll");
+    assert_eq!(app.status_message, "Smooth scroll enabled");
+    app.selected_entry_index = 30;
+
+    let mut frames = vec![app.next_card_scroll_frame()];
+    while app.scroll_animating {
+        frames.push(app.next_card_scroll_frame());
+    }
+    assert_eq!(frames.first(), Some(&9));
+    assert_eq!(frames.last(), Some(&26));
+    assert!(frames.len() > 2);
+    assert!(frames.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(app.card_scroll, 26);
+
+    // And back up again
+    app.selected_entry_index = 0;
+    let first = app.next_card_scroll_frame();
+    assert!(first > 0 && first < 26);
+    while app.scroll_animating {
+        app.next_card_scroll_frame();
+    }
+    assert_eq!(app.card_scroll_shown, 0);
+}
+
+#[test]
+fn turning_it_off_scrolls_instantly_again() {
+    let mut app = long_app();
+    command(&mut app, "set smooth_scroll");
+    command(&mut app, "set nosmooth_scroll");
+    assert_eq!(app.status_message, "Smooth scroll disabled");
+    app.selected_entry_index = 30;
+    assert_eq!(app.next_card_scroll_frame(), 26);
+    assert!(!app.scroll_animating);
+}
@@ -0,0 +1,122 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::snapshot::{self, DiffLine, SnapshotStore};
+use std::{fs, path::PathBuf};
+
+fn temp_root(prefix: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("revw_{}_{}", prefix, std::process::id()));
+    fs::remove_dir_all(&root).ok();
+    root
+}
+
+#[test]
+fn store_saves_lists_and_reads_snapshots() {
+    let root = temp_root("snapshot_store");
+    let store = SnapshotStore::new(&root, Some(&PathBuf::from("/notes/reading.md")));
+    assert!(store.list().is_empty());
+
+    let saved = store.save("before cleanup!", "## INSIDE\n", "md").unwrap();
+    assert_eq!(saved.name, "before-cleanup");
+    assert!(saved.path.starts_with(root.join("%notes%reading.md")));
+
+    let listed = store.list();
+    assert_eq!(listed, vec![saved.clone()]);
+    assert_eq!(store.read(&listed[0]).unwrap(), "## INSIDE\n");
+
+    // Other documents keep their own snapshots
+    let other = SnapshotStore::new(&root, None);
+    assert!(other.list().is_empty());
+    assert!(store.save("  ", "x", "md").is_err());
+
+    fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn diff_marks_added_and_removed_lines() {
+    let diff = snapshot::diff("a\nb\nc\n", "a\nc\nd\n");
+    assert_eq!(
+        diff,
+        vec![
+            DiffLine::Same("a".to_string()),
+            DiffLine::Removed("b".to_string()),
+            DiffLine::Same("c".to_string()),
+            DiffLine::Added("d".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn diff_of_large_documents_finds_the_few_changed_lines() {
+    // A quadratic table for these would take gigabytes
+    let old: String = (0..40_000).map(|n| format!("line {}\n", n)).collect();
+    let new = old.replace("line 20000\n", "changed\n").replace("line 39999\n", "");
+    let diff = snapshot::diff(&old, &new);
+    let changed: Vec<&DiffLine> = diff.iter().filter(|line| !matches!(line, DiffLine::Same(_))).collect();
+    assert_eq!(
+        changed,
+        vec![
+            &DiffLine::Removed("line 20000".to_string()),
+            &DiffLine::Added("changed".to_string()),
+            &DiffLine::Removed("line 39999".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn diff_hunks_keep_context_and_mark_skipped_lines() {
+    let old: String = (1..=10).map(|n| format!("{}\n", n)).collect();
+    let new = old.replace("5\n", "five\n");
+    let diff = snapshot::diff(&old, &new);
+
+    let rows: Vec<String> = snapshot::diff_hunks(&diff, 1)
+        .into_iter()
+        .map(|row| match row {
+            Some(DiffLine::Same(text)) => format!(" {}", text),
+            Some(DiffLine::Added(text)) => format!("+{}", text),
+            Some(DiffLine::Removed(text)) => format!("-{}", text),
+            None => "…".to_string(),
+        })
+        .collect();
+    assert_eq!(rows, vec!["…", " 4", "-5", "+five", " 6", "…"]);
+
+    assert!(snapshot::diff_hunks(&snapshot::diff("same", "same"), 2).is_empty());
+}
+
+#[test]
+fn snapshot_restore_is_undoable() {
+    let root = temp_root("snapshot_app");
+    let original = r#"{"outside": [{"name": "first"}], "inside": []}"#;
+
+    let mut app = App::new(FormatMode::View);
+    app.snapshot_root = Some(root.clone());
+    app.file_mode = FileMode::Json;
+    app.json_input = original.to_string();
+    app.convert_json();
+
+    app.command_buffer = "snapshot start".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Snapshot 'start' saved");
+
+    app.json_input = r#"{"outside": [{"name": "second"}], "inside": []}"#.to_string();
+    app.convert_json();
+
+    app.command_buffer = "snapshots".to_string();
+    app.execute_command();
+    assert!(app.snapshots_open);
+    assert_eq!(app.snapshots.len(), 1);
+
+    app.toggle_snapshot_diff();
+    assert!(app.snapshot_diff.is_some());
+    assert!(app.status_message.ends_with("+1 -1 lines"), "{}", app.status_message);
+    app.toggle_snapshot_diff();
+    assert!(app.snapshot_diff.is_none());
+
+    app.restore_selected_snapshot();
+    assert!(!app.snapshots_open);
+    assert_eq!(app.json_input, original);
+    assert!(app.is_modified);
+
+    app.undo();
+    assert!(app.json_input.contains("second"));
+
+    fs::remove_dir_all(&root).ok();
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::{run, value, view_app};
+use revw::app::{App, FileMode};
+use revw::status::EntryStatus;
+use revw::convert::Format;
+use revw::validate;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Open", "context": "", "url": "", "percentage": 40},
+    {"name": "Finished", "context": "", "url": "", "percentage": 100, "status": "done"},
+    {"name": "Abandoned", "context": "", "url": "", "percentage": 10, "status": "dropped"}
+  ],
+  "inside": [
+    {"date": "2024-01-01 00:00:00", "context": "note"}
+  ]
+}"#;
+
+fn names(app: &App) -> Vec<String> {
+    app.relf_entries.iter().filter_map(|entry| entry.name.clone()).collect()
+}
+
+#[test]
+fn dropped_entries_are_hidden_by_default() {
+    let mut app = view_app(JSON, FileMode::Json);
+    assert_eq!(names(&app), ["Open", "Finished"]);
+    assert_eq!(app.relf_entries[1].status, EntryStatus::Done);
+
+    run(&mut app, "set show_dropped");
+    assert_eq!(names(&app), ["Open", "Finished", "Abandoned"]);
+    assert_eq!(app.status_message, "Dropped entries shown");
+
+    run(&mut app, "set noshow_dropped");
+    assert_eq!(names(&app), ["Open", "Finished"]);
+}
+
+#[test]
+fn filtering_for_dropped_shows_them() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.apply_filter("dropped".to_string());
+    assert_eq!(names(&app), ["Abandoned"]);
+}
+
+#[test]
+fn done_toggles_without_touching_the_percentage() {
+    let mut app = view_app(JSON, FileMode::Json);
+    run(&mut app, "done");
+    assert_eq!(value(&app)["outside"][0]["status"], "done");
+    assert_eq!(value(&app)["outside"][0]["percentage"], 40);
+    assert_eq!(app.status_message, "Status done: 1 of 1 card");
+
+    run(&mut app, "done");
+    assert!(value(&app)["outside"][0].get("status").is_none());
+    assert_eq!(app.status_message, "Status active: 1 of 1 card");
+}
+
+#[test]
+fn status_command_sets_or_clears_the_status() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 1;
+    run(&mut app, "status dropped");
+    assert_eq!(value(&app)["outside"][1]["status"], "dropped");
+    assert_eq!(names(&app), ["Open"]);
+
+    run(&mut app, "status finished");
+    assert_eq!(app.status_message, "Status must be active, done or dropped");
+
+    run(&mut app, "set show_dropped");
+    app.selected_entry_index = 2;
+    run(&mut app, "status active");
+    assert!(value(&app)["outside"][2].get("status").is_none());
+}
+
+#[test]
+fn inside_entries_have_no_status() {
+    let mut app = view_app(JSON, FileMode::Json);
+    app.selected_entry_index = 2;
+    run(&mut app, "done");
+    assert_eq!(app.status_message, "Status done: nothing to change in 1 card");
+}
+
+#[test]
+fn status_round_trips_through_markdown() {
+    let app = view_app(JSON, FileMode::Json);
+    let markdown = app.convert_to_markdown().unwrap();
+    assert!(markdown.contains("**status:** done"), "{}", markdown);
+}
+
+#[test]
+fn unknown_status_is_reported() {
+    let json = r#"{"outside": [{"name": "A", "status": "paused"}], "inside": []}"#;
+    let issues = validate::check(json, Format::Json);
+    assert!(issues.iter().any(|issue| issue.message.contains("unknown status \"paused\"")), "{:?}", issues);
+}
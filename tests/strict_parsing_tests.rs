@@ -0,0 +1,136 @@
+mod common;
+
+use common::{run, temp_dir};
+use revw::app::{App, FormatMode};
+use revw::json_ops::JsonOperations;
+use revw::markdown_ops::MarkdownOperations;
+use std::{fs, process::Command};
+
+#[test]
+fn strict_json_accepts_canonical_structure() {
+    let json = r#"{
+  "outside": [{"name": "A", "context": "c", "url": null, "percentage": 50}],
+  "inside": [{"date": "2025-01-01 00:00:00", "context": "note"}]
+}"#;
+    assert_eq!(JsonOperations::validate_strict(json), Ok(()));
+}
+
+#[test]
+fn strict_json_points_at_offending_entry() {
+    let json = r#"{"outside": [{"name": "A"}, {"name": "B", "isbn": "978-0"}], "inside": []}"#;
+    let err = JsonOperations::validate_strict(json).unwrap_err();
+    assert_eq!(err, r#"outside[1]: unknown field "isbn""#);
+
+    let json = r#"{"outside": [], "inside": [{"date": "d", "context": 3}]}"#;
+    let err = JsonOperations::validate_strict(json).unwrap_err();
+    assert!(err.starts_with("inside[0]"), "{}", err);
+
+    let json = r#"{"outside": [], "misc": []}"#;
+    assert!(JsonOperations::validate_strict(json).unwrap_err().contains("misc"));
+}
+
+#[test]
+fn strict_markdown_reports_line_numbers() {
+    let ok = "## OUTSIDE\n\n### A\nctx\n\n**URL:** https://a\n\n**Percentage:** 10%\n\n## INSIDE\n\n### 2025-01-01\nnote\n";
+    assert_eq!(MarkdownOperations::validate_strict(ok), Ok(()));
+
+    let headerless = "## OUTSIDE\n\nloose line\n";
+    assert_eq!(
+        MarkdownOperations::validate_strict(headerless).unwrap_err(),
+        "line 3: entry without ### header"
+    );
+
+    let bad_section = "## OUTSIDE\n### A\n## NOTES\n";
+    assert!(MarkdownOperations::validate_strict(bad_section).unwrap_err().starts_with("line 3"));
+
+    let url_inside = "## INSIDE\n### 2025-01-01\n**URL:** https://a\n";
+    assert!(MarkdownOperations::validate_strict(url_inside).unwrap_err().starts_with("line 3"));
+}
+
+#[test]
+fn strict_flag_rejects_unknown_fields_on_cli() {
+    let target = std::env::temp_dir().join(format!("revw_strict_{}.json", std::process::id()));
+    fs::write(&target, r#"{"outside":[{"name":"A","isbn":"978-0"}],"inside":[]}"#).unwrap();
+
+    let lenient = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .args(["--stdout", target.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(lenient.status.success());
+
+    let strict = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .args(["--stdout", "--strict", target.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(!strict.status.success());
+    assert!(String::from_utf8_lossy(&strict.stderr).contains("outside[0]"));
+
+    let _ = fs::remove_file(&target);
+}
+
+#[test]
+fn strict_accepts_entry_status_and_checks_its_value() {
+    let json = r#"{"outside": [{"name": "A", "status": "done"}, {"name": "B", "status": "Dropped"}], "inside": []}"#;
+    assert_eq!(JsonOperations::validate_strict(json), Ok(()));
+
+    let json = r#"{"outside": [{"name": "A", "status": "finished"}], "inside": []}"#;
+    assert_eq!(JsonOperations::validate_strict(json).unwrap_err(), r#"outside[0]: field "status" has the wrong type"#);
+
+    let json = r#"{"outside": [], "inside": [{"date": "d", "status": "done"}]}"#;
+    assert_eq!(JsonOperations::validate_strict(json).unwrap_err(), r#"inside[0]: unknown field "status""#);
+
+    let ok = "## OUTSIDE\n\n### A\n\n**status:** done\n";
+    assert_eq!(MarkdownOperations::validate_strict(ok), Ok(()));
+    let bad = "## OUTSIDE\n\n### A\n\n**status:** finished\n";
+    assert_eq!(MarkdownOperations::validate_strict(bad).unwrap_err(), "line 5: invalid status \"finished\"");
+    let inside = "## INSIDE\n\n### 2025-01-01\n**status:** done\n";
+    assert!(MarkdownOperations::validate_strict(inside).unwrap_err().starts_with("line 4"));
+
+    let target = std::env::temp_dir().join(format!("revw_strict_status_{}.json", std::process::id()));
+    fs::write(&target, r#"{"outside":[{"name":"A","status":"done"}],"inside":[]}"#).unwrap();
+    let strict = Command::new(env!("CARGO_BIN_EXE_revw"))
+        .args(["--stdout", "--strict", target.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(strict.status.success(), "{}", String::from_utf8_lossy(&strict.stderr));
+    let _ = fs::remove_file(&target);
+}
+
+#[test]
+fn strict_accepts_tags_and_saves_after_tagging() {
+    let json = r#"{"outside": [{"name": "A", "tags": ["x"]}], "inside": [{"date": "d", "tags": []}]}"#;
+    assert_eq!(JsonOperations::validate_strict(json), Ok(()));
+    let json = r#"{"outside": [{"name": "A", "tags": "x"}], "inside": []}"#;
+    assert_eq!(JsonOperations::validate_strict(json).unwrap_err(), r#"outside[0]: field "tags" has the wrong type"#);
+
+    let ok = "## OUTSIDE\n\n### A\n\n**tags:** [\"x\"]\n";
+    assert_eq!(MarkdownOperations::validate_strict(ok), Ok(()));
+    let bad = "## OUTSIDE\n\n### A\n\n**tags:** x\n";
+    assert_eq!(MarkdownOperations::validate_strict(bad).unwrap_err(), "line 5: tags must be a list of strings");
+
+    let dir = temp_dir("tags");
+    for (file, content) in [
+        ("notes.json", r#"{"outside": [{"name": "A"}], "inside": [{"date": "2025-01-01 00:00:00", "context": "note"}]}"#),
+        ("notes.md", "## OUTSIDE\n\n### A\n\n## INSIDE\n\n### 2025-01-01 00:00:00\nnote\n"),
+    ] {
+        let path = dir.join(file);
+        fs::write(&path, content).unwrap();
+        let mut app = App::new(FormatMode::View);
+        app.load_file(path.clone());
+        run(&mut app, "set strict");
+        app.visual_mode = true;
+        app.visual_start_index = 0;
+        app.visual_end_index = 1;
+        run(&mut app, "tag add x");
+        run(&mut app, "w");
+        assert!(!app.is_modified, "{}: {}", file, app.status_message);
+        assert_eq!(fs::read_to_string(&path).unwrap().matches("\"x\"").count(), 2, "{}", file);
+
+        let check = Command::new(env!("CARGO_BIN_EXE_revw"))
+            .args(["--check", "--strict", path.to_str().unwrap()])
+            .output()
+            .unwrap();
+        assert!(check.status.success(), "{}", String::from_utf8_lossy(&check.stderr));
+    }
+    let _ = fs::remove_dir_all(&dir);
+}
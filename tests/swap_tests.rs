@@ -0,0 +1,194 @@
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::config::RcConfig;
+use revw::swap;
+use std::{fs, path::{Path, PathBuf}};
+
+const NOTES: &str = r#"{"outside": [{"name": "a", "context": "", "url": "", "percentage": null}], "inside": []}"#;
+
+/// An app writing swap files under `dir`, with `file` opened from it
+fn opened(dir: &Path, file: &str, content: &str) -> (App, PathBuf) {
+    let path = dir.join(file);
+    fs::write(&path, content).unwrap();
+    let mut app = App::with_config(FormatMode::View, RcConfig::default());
+    app.swap_root = Some(dir.join("swap"));
+    app.load_file(path.clone());
+    (app, path)
+}
+
+#[test]
+fn unsaved_changes_are_written_to_the_swap_file() {
+    let dir = temp_dir("write");
+    let (mut app, path) = opened(&dir, "notes.json", NOTES);
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+
+    // Nothing to keep while the buffer matches the file
+    app.update_swap();
+    assert!(!swap_path.exists());
+
+    app.json_input = app.json_input.replace("\"a\"", "\"buffer\"");
+    app.is_modified = true;
+    app.update_swap();
+    assert!(fs::read_to_string(&swap_path).unwrap().contains("\"buffer\""));
+
+    // Saving leaves nothing to recover
+    app.save_file();
+    app.update_swap();
+    assert!(!swap_path.exists());
+}
+
+#[test]
+fn a_normal_exit_removes_the_swap_file() {
+    let dir = temp_dir("exit");
+    let (mut app, path) = opened(&dir, "notes.json", NOTES);
+    app.is_modified = true;
+    app.update_swap();
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+    assert!(swap_path.exists());
+
+    app.remove_swap();
+    assert!(!swap_path.exists());
+}
+
+#[test]
+fn a_leftover_swap_file_can_be_recovered() {
+    let dir = temp_dir("recover");
+    let path = dir.join("notes.json");
+    swap::write(&swap::path_for(&dir.join("swap"), &path), &NOTES.replace("\"a\"", "\"lost work\"")).unwrap();
+
+    let (mut app, _) = opened(&dir, "notes.json", NOTES);
+    assert!(app.swap_recovery_pending.is_some());
+    assert!(app.status_message.contains("(r)ecover, (d)iscard, Esc later?"));
+    assert!(!app.json_input.contains("lost work"));
+
+    app.handle_swap_recovery('r');
+    assert!(app.swap_recovery_pending.is_none());
+    assert!(app.json_input.contains("lost work"));
+    assert!(app.is_modified);
+    assert_eq!(app.relf_entries.len(), 1);
+
+    // The file on disk is untouched until saved
+    assert_eq!(fs::read_to_string(&path).unwrap(), NOTES);
+}
+
+#[test]
+fn a_leftover_markdown_swap_file_can_be_recovered() {
+    let dir = temp_dir("recover_md");
+    let markdown = "## OUTSIDE\n### a\n\n## INSIDE\n";
+    let path = dir.join("notes.md");
+    swap::write(&swap::path_for(&dir.join("swap"), &path), &markdown.replace("### a", "### lost work")).unwrap();
+
+    let (mut app, _) = opened(&dir, "notes.md", markdown);
+    app.handle_swap_recovery('r');
+    assert!(app.markdown_input.contains("### lost work"));
+    assert!(app.json_input.contains("lost work"));
+}
+
+#[test]
+fn a_leftover_swap_file_can_be_discarded() {
+    let dir = temp_dir("discard");
+    let path = dir.join("notes.json");
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+    swap::write(&swap_path, "{}").unwrap();
+
+    let (mut app, _) = opened(&dir, "notes.json", NOTES);
+    app.handle_swap_recovery('d');
+    assert!(app.swap_recovery_pending.is_none());
+    assert!(!swap_path.exists());
+    assert!(!app.is_modified);
+    assert_eq!(app.status_message, "Swap file discarded");
+}
+
+#[test]
+fn a_swap_file_matching_the_file_is_removed_quietly() {
+    let dir = temp_dir("same");
+    let path = dir.join("notes.json");
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+    swap::write(&swap_path, NOTES).unwrap();
+
+    let (app, _) = opened(&dir, "notes.json", NOTES);
+    assert!(app.swap_recovery_pending.is_none());
+    assert!(!swap_path.exists());
+}
+
+#[test]
+fn safe_mode_writes_no_swap_file() {
+    let dir = temp_dir("safe");
+    let (mut app, path) = opened(&dir, "notes.json", NOTES);
+    app.enter_safe_mode();
+    app.is_modified = true;
+    app.update_swap();
+    assert!(!swap::path_for(&dir.join("swap"), &path).exists());
+}
+
+#[test]
+fn esc_keeps_the_swap_file_for_later() {
+    let dir = temp_dir("later");
+    let path = dir.join("notes.json");
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+    swap::write(&swap_path, &NOTES.replace("\"a\"", "\"lost work\"")).unwrap();
+
+    let (mut app, _) = opened(&dir, "notes.json", NOTES);
+    app.defer_swap_recovery();
+    assert!(app.swap_recovery_pending.is_none());
+    assert!(!app.json_input.contains("lost work"));
+
+    // Editing in this session does not overwrite it
+    app.json_input = app.json_input.replace("\"a\"", "\"new\"");
+    app.is_modified = true;
+    app.update_swap();
+    app.remove_swap();
+    assert!(swap::read(&swap_path).unwrap().content.contains("lost work"));
+
+    // Opening the file again asks again
+    let (app, _) = opened(&dir, "notes.json", NOTES);
+    assert!(app.swap_recovery_pending.is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn a_swap_file_of_a_running_session_is_left_alone() {
+    let dir = temp_dir("running");
+    let path = dir.join("notes.json");
+    let swap_path = swap::path_for(&dir.join("swap"), &path);
+    fs::create_dir_all(dir.join("swap")).unwrap();
+    let mut other = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+    let header = format!("revw swap pid={} host={}\n", other.id(), swap::hostname());
+    fs::write(&swap_path, header.clone() + "their work").unwrap();
+
+    let (mut app, _) = opened(&dir, "notes.json", NOTES);
+    assert!(app.swap_recovery_pending.is_none());
+    assert!(app.status_message.contains("another revw"), "{}", app.status_message);
+    app.is_modified = true;
+    app.update_swap();
+    assert_eq!(fs::read_to_string(&swap_path).unwrap(), header.clone() + "their work");
+
+    // Once that session is gone, its swap file is a leftover to recover
+    other.kill().unwrap();
+    other.wait().unwrap();
+    let (app, _) = opened(&dir, "notes.json", NOTES);
+    assert_eq!(app.swap_recovery_pending.as_deref(), Some("their work"));
+}
+
+#[cfg(unix)]
+#[test]
+fn swap_files_are_private_and_name_their_session() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = temp_dir("private");
+    let swap_path = dir.join("notes.swp");
+    swap::write(&swap_path, "notes").unwrap();
+    assert_eq!(fs::metadata(&swap_path).unwrap().permissions().mode() & 0o777, 0o600);
+
+    let written = swap::read(&swap_path).unwrap();
+    assert_eq!(written.pid, Some(std::process::id()));
+    assert_eq!(written.host, Some(swap::hostname()));
+    assert_eq!(written.content, "notes");
+    // This session's own swap file is not another session's
+    assert!(!written.in_use());
+
+    // Swap files from before the header have no owner
+    fs::write(&swap_path, "{}").unwrap();
+    assert_eq!(swap::read(&swap_path).unwrap().content, "{}");
+}
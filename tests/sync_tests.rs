@@ -0,0 +1,213 @@
+mod common;
+
+use common::temp_dir;
+use revw::app::{App, FormatMode};
+use revw::sync::{self, Direction, Outcome, PutError, Remote, RemoteFile};
+use std::fs;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// An in-memory server: the content and a version bumped on every write. Clones share it.
+#[derive(Clone, Default)]
+struct FakeRemote {
+    file: Arc<Mutex<Option<(String, u32)>>>,
+}
+
+impl FakeRemote {
+    fn with(content: &str) -> Self {
+        FakeRemote { file: Arc::new(Mutex::new(Some((content.to_string(), 1)))) }
+    }
+
+    fn content(&self) -> Option<String> {
+        self.file.lock().unwrap().as_ref().map(|(content, _)| content.clone())
+    }
+
+    fn replace(&self, content: &str, version: u32) {
+        *self.file.lock().unwrap() = Some((content.to_string(), version));
+    }
+}
+
+impl Remote for FakeRemote {
+    fn get(&self) -> Result<Option<RemoteFile>, String> {
+        Ok(self.file.lock().unwrap().as_ref().map(|(content, version)| RemoteFile {
+            content: content.clone(),
+            etag: Some(version.to_string()),
+        }))
+    }
+
+    fn put(&self, content: &str, etag: Option<&str>) -> Result<(), PutError> {
+        let mut file = self.file.lock().unwrap();
+        let current = file.as_ref().map(|(_, version)| version.to_string());
+        if current.as_deref() != etag {
+            return Err(PutError::Changed);
+        }
+        let version = file.as_ref().map_or(1, |(_, version)| version + 1);
+        *file = Some((content.to_string(), version));
+        Ok(())
+    }
+}
+
+/// Start a sync of the open file with `remote` and wait for it to finish
+fn sync_and_wait(app: &mut App, remote: &FakeRemote) {
+    let remote = remote.clone();
+    app.sync_with(Direction::Auto, move || Ok(remote));
+    while app.background_task.is_some() {
+        app.poll_background_task();
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn doc(name: &str) -> String {
+    format!("{{\n  \"outside\": [\n    {{\n      \"name\": \"{}\",\n      \"context\": \"\",\n      \"url\": \"\",\n      \"percentage\": null\n    }}\n  ],\n  \"inside\": []\n}}", name)
+}
+
+#[test]
+fn the_side_that_changed_wins_and_both_changing_stops() {
+    let base = Some(sync::content_hash("v1"));
+
+    let remote = FakeRemote::default();
+    assert_eq!(sync::sync(&remote, "v1", None, Direction::Auto), Ok(Outcome::Pushed));
+    assert_eq!(remote.content().as_deref(), Some("v1"));
+    assert_eq!(sync::sync(&remote, "v1", base, Direction::Auto), Ok(Outcome::UpToDate));
+
+    // Only local changed
+    assert_eq!(sync::sync(&remote, "v2 local", base, Direction::Auto), Ok(Outcome::Pushed));
+    assert_eq!(remote.content().as_deref(), Some("v2 local"));
+
+    // Only the server changed
+    let remote = FakeRemote::with("v2 remote");
+    assert_eq!(sync::sync(&remote, "v1", base, Direction::Auto), Ok(Outcome::Pulled("v2 remote".to_string())));
+
+    // Both changed, or nothing is known about the last sync
+    assert_eq!(sync::sync(&remote, "v2 local", base, Direction::Auto), Ok(Outcome::Conflict));
+    assert_eq!(sync::sync(&remote, "v2 local", None, Direction::Auto), Ok(Outcome::Conflict));
+    assert_eq!(remote.content().as_deref(), Some("v2 remote"));
+
+    // Picking a side
+    assert_eq!(sync::sync(&remote, "v2 local", base, Direction::Pull), Ok(Outcome::Pulled("v2 remote".to_string())));
+    assert_eq!(sync::sync(&remote, "v2 local", base, Direction::Push), Ok(Outcome::Pushed));
+    assert_eq!(remote.content().as_deref(), Some("v2 local"));
+    assert!(sync::sync(&FakeRemote::default(), "v1", None, Direction::Pull).is_err());
+}
+
+#[test]
+fn remote_url_appends_the_file_name_to_a_folder() {
+    let mut config = sync::SyncConfig::default();
+    assert_eq!(config.remote_url("notes.json"), None);
+    config.set("url", "https://cloud.example.com/dav/notes/");
+    assert_eq!(config.remote_url("my notes.md").as_deref(), Some("https://cloud.example.com/dav/notes/my%20notes.md"));
+    config.set("url", "https://cloud.example.com/dav/notes/main.json");
+    assert_eq!(config.remote_url("other.json").as_deref(), Some("https://cloud.example.com/dav/notes/main.json"));
+
+    config.set("password_cmd", "printf 'secret\\n'");
+    assert_eq!(config.password(), Ok(Some("secret".to_string())));
+}
+
+#[test]
+fn sync_pulls_into_the_open_file_and_remembers_the_last_sync() {
+    let dir = temp_dir("app");
+    let path = dir.join("notes.json");
+    fs::write(&path, doc("Local")).unwrap();
+
+    let mut app = App::new(FormatMode::View);
+    app.sync_root = Some(dir.join("state"));
+    app.load_file(path.clone());
+
+    // First sync: the server has nothing yet
+    let remote = FakeRemote::default();
+    sync_and_wait(&mut app, &remote);
+    assert_eq!(app.status_message, "Sync: pushed notes.json");
+    assert_eq!(remote.content(), Some(doc("Local")));
+
+    // Someone else changes the server copy
+    remote.replace(&doc("From phone"), 7);
+    sync_and_wait(&mut app, &remote);
+    assert_eq!(app.status_message, "Sync: pulled notes.json");
+    assert_eq!(fs::read_to_string(&path).unwrap(), doc("From phone"));
+    assert!(app.json_input.contains("From phone"));
+
+    // Unsaved edits have to be written first
+    app.is_modified = true;
+    sync_and_wait(&mut app, &remote);
+    assert_eq!(app.status_message, "Unsaved changes: :w before :sync");
+
+    // Both sides change: nothing is written
+    app.is_modified = false;
+    fs::write(&path, doc("Laptop")).unwrap();
+    app.reload_file();
+    remote.replace(&doc("Phone again"), 8);
+    sync_and_wait(&mut app, &remote);
+    assert!(app.status_message.starts_with("Sync conflict"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), doc("Laptop"));
+    assert_eq!(remote.content(), Some(doc("Phone again")));
+    fs::remove_dir_all(dir).ok();
+}
+
+#[test]
+fn sync_without_configuration_says_how_to_set_it_up() {
+    let dir = temp_dir("unconfigured");
+    let path = dir.join("notes.json");
+    fs::write(&path, doc("A")).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.load_file(path);
+    app.command_buffer = "sync".to_string();
+    app.execute_command();
+    assert!(app.status_message.starts_with("Sync not configured"), "{}", app.status_message);
+    app.command_buffer = "sync sideways".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Usage: :sync [pull|push]");
+    fs::remove_dir_all(dir).ok();
+}
+
+/// A server that answers the fetch only once released, and counts uploads
+struct SlowRemote {
+    release: Mutex<Receiver<()>>,
+    puts: Arc<Mutex<usize>>,
+    // Dropped with the remote, so the test can tell when the worker is done
+    _alive: Sender<()>,
+}
+
+impl Remote for SlowRemote {
+    fn get(&self) -> Result<Option<RemoteFile>, String> {
+        let _ = self.release.lock().unwrap().recv();
+        Ok(Some(RemoteFile { content: "server".to_string(), etag: Some("1".to_string()) }))
+    }
+
+    fn put(&self, _content: &str, _etag: Option<&str>) -> Result<(), PutError> {
+        *self.puts.lock().unwrap() += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn sync_runs_off_the_ui_thread_and_esc_cancels_it() {
+    let dir = temp_dir("cancel");
+    let path = dir.join("notes.json");
+    fs::write(&path, doc("Local")).unwrap();
+    let mut app = App::new(FormatMode::View);
+    app.sync_root = Some(dir.join("state"));
+    app.load_file(path.clone());
+
+    let (release, waiting) = mpsc::channel();
+    let (alive, finished) = mpsc::channel::<()>();
+    let puts = Arc::new(Mutex::new(0));
+    let remote = SlowRemote { release: Mutex::new(waiting), puts: Arc::clone(&puts), _alive: alive };
+    app.sync_with(Direction::Push, move || Ok(remote));
+
+    // The server has not answered, yet the app is free and shows the task
+    let task = app.background_task.as_ref().expect("sync runs as a background task");
+    assert!(task.status_text().starts_with("Syncing notes.json"), "{}", task.status_text());
+
+    app.cancel_background_task();
+    assert_eq!(app.status_message, "Cancelled: Syncing notes.json");
+    assert!(app.background_task.is_none());
+
+    // Once the server answers, the cancelled sync uploads nothing
+    release.send(()).unwrap();
+    assert!(finished.recv().is_err());
+    assert_eq!(*puts.lock().unwrap(), 0);
+    assert_eq!(fs::read_to_string(&path).unwrap(), doc("Local"));
+    fs::remove_dir_all(dir).ok();
+}
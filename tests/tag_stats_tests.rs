@@ -0,0 +1,46 @@
+use revw::app::{App, FileMode, FormatMode};
+use revw::content_ops::{self, TagStats};
+use serde_json::json;
+
+fn row(tag: &str, entries: usize, average: f64) -> TagStats {
+    TagStats { tag: tag.to_string(), entries, average }
+}
+
+#[test]
+fn tags_are_averaged_with_lagging_ones_first() {
+    let doc = json!({
+        "outside": [
+            {"name": "Book", "percentage": 80, "tags": ["rust", "books"]},
+            {"name": "Course", "percentage": null, "tags": ["rust"]},
+            {"name": "Talk", "percentage": 20, "tags": "video"},
+            {"name": "Paper", "percentage": 10, "status": "done", "tags": ["books"]},
+            {"name": "Old", "percentage": 0, "status": "dropped", "tags": ["video"]},
+            {"name": "Loose", "percentage": 50}
+        ],
+        "inside": []
+    });
+    assert_eq!(
+        content_ops::tag_stats(&doc),
+        [row("video", 1, 20.0), row("rust", 2, 40.0), row("books", 2, 90.0), row(content_ops::UNTAGGED, 1, 50.0)]
+    );
+    assert!(content_ops::tag_stats(&json!({"outside": [], "inside": []})).is_empty());
+}
+
+#[test]
+fn stats_command_opens_the_table_and_any_key_closes_it() {
+    let mut app = App::new(FormatMode::View);
+    app.file_mode = FileMode::Json;
+    app.json_input = json!({"outside": [{"name": "A", "percentage": 30, "tags": ["x"]}], "inside": []}).to_string();
+    app.convert_json();
+
+    app.command_buffer = "stats".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "Usage: :stats tags");
+    assert!(app.tag_stats.is_none());
+
+    app.command_buffer = "stats tags".to_string();
+    app.execute_command();
+    assert_eq!(app.tag_stats, Some(vec![row("x", 1, 30.0)]));
+    app.close_stats();
+    assert!(app.tag_stats.is_none());
+}
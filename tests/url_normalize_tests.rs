@@ -0,0 +1,95 @@
+mod common;
+
+use common::view_app;
+use revw::app::{App, FileMode};
+use revw::url_ops::{self, UrlRules};
+use serde_json::Value;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": []
+}"#;
+
+fn saved_url(app: &App) -> String {
+    let value: Value = serde_json::from_str(&app.json_input).unwrap();
+    value["outside"][0]["url"].as_str().unwrap().to_string()
+}
+
+fn edit_url(app: &mut App, url: &str) {
+    app.start_editing_entry();
+    app.edit_buffer[2] = url.to_string();
+    app.edit_buffer_is_placeholder[2] = false;
+    app.save_edited_entry();
+}
+
+#[test]
+fn default_rules_add_the_scheme_and_encode_spaces() {
+    let rules = UrlRules::default();
+    assert_eq!(url_ops::normalize(" example.com/a b ", &rules), ("https://example.com/a%20b".to_string(), true));
+    assert_eq!(url_ops::normalize("www.rust-lang.org", &rules), ("https://www.rust-lang.org".to_string(), true));
+    // Tracking parameters stay unless asked for
+    assert_eq!(url_ops::normalize("https://a.io/?utm_source=x", &rules).0, "https://a.io/?utm_source=x");
+}
+
+#[test]
+fn local_paths_are_left_alone() {
+    let rules = UrlRules::default();
+    for path in ["/home/me/my notes.md", "notes.md", "images/cat.png", "~/x.json", "file:///tmp/a"] {
+        assert_eq!(url_ops::normalize(path, &rules).0, path);
+    }
+}
+
+#[test]
+fn tracking_parameters_are_stripped_when_enabled() {
+    let rules = UrlRules { strip_tracking: true, ..UrlRules::default() };
+    let normalize = |url: &str| url_ops::normalize(url, &rules).0;
+    assert_eq!(normalize("https://a.io/p?utm_source=x&id=3&fbclid=y#top"), "https://a.io/p?id=3#top");
+    assert_eq!(normalize("https://a.io/p?utm_medium=mail"), "https://a.io/p");
+    assert_eq!(normalize("https://a.io/p?q=rust"), "https://a.io/p?q=rust");
+}
+
+#[test]
+fn rules_can_be_switched_off() {
+    let rules = UrlRules { add_scheme: false, encode_spaces: false, strip_tracking: false };
+    // Without encoding the space makes the URL invalid
+    assert_eq!(url_ops::normalize("https://a.io/a b", &rules), ("https://a.io/a b".to_string(), false));
+    assert_eq!(url_ops::normalize("example.com", &rules).0, "example.com");
+}
+
+#[test]
+fn overlay_save_normalizes_the_url() {
+    let mut app = view_app(JSON, FileMode::Json);
+    edit_url(&mut app, "doc.rust-lang.org/book/");
+    assert_eq!(saved_url(&app), "https://doc.rust-lang.org/book/");
+    assert_eq!(app.status_message, "Entry updated");
+}
+
+#[test]
+fn overlay_save_warns_on_invalid_urls() {
+    let mut app = view_app(JSON, FileMode::Json);
+    edit_url(&mut app, "https://");
+    assert_eq!(saved_url(&app), "https://");
+    assert_eq!(app.status_message, "Entry updated; malformed URL \"https://\"");
+}
+
+#[test]
+fn rules_are_set_at_runtime() {
+    let mut app = view_app(JSON, FileMode::Json);
+    let run = |app: &mut App, command: &str| {
+        app.command_buffer = command.to_string();
+        app.execute_command();
+    };
+    run(&mut app, "set url_strip_tracking");
+    assert!(app.url_rules.strip_tracking);
+    assert_eq!(app.status_message, "url_strip_tracking enabled");
+    run(&mut app, "set nourl_add_scheme");
+    assert!(!app.url_rules.add_scheme);
+    assert_eq!(app.status_message, "url_add_scheme disabled");
+    run(&mut app, "set url_shorten");
+    assert_eq!(app.status_message, "Unknown URL rule: url_shorten");
+
+    edit_url(&mut app, "https://a.io/?gclid=1");
+    assert_eq!(saved_url(&app), "https://a.io/");
+}
@@ -0,0 +1,57 @@
+use revw::app::{App, FormatMode};
+use revw::fields;
+use serde_json::json;
+
+const JSON: &str = r#"{
+  "outside": [
+    {"name": "Rust Book", "context": "ownership\nborrowing", "url": "https://doc.rust-lang.org/book/", "percentage": 40, "tag": "rust"},
+    {"name": "No URL", "context": "", "url": "", "percentage": null}
+  ],
+  "inside": [
+    {"date": "2025-01-01 10:00:00", "context": "read chapter 4"}
+  ]
+}"#;
+
+fn app_with_templates() -> App {
+    let mut app = App::new(FormatMode::View);
+    app.json_input = JSON.to_string();
+    app.convert_json();
+    app.templates = vec![
+        ("link".to_string(), "- [{name}]({url}) — {percentage}%".to_string()),
+        ("note".to_string(), "{date}: {context}".to_string()),
+    ];
+    app
+}
+
+#[test]
+fn placeholders_take_any_field_and_leave_other_braces() {
+    let entry = json!({"name": "A", "percentage": 5, "url": null, "tag": "x"});
+    let entry = entry.as_object().unwrap();
+    assert_eq!(fields::fill_template("{name} {percentage}% [{url}] #{tag} {missing}", entry), "A 5% [] #x ");
+    assert_eq!(fields::fill_template("{} {not a key} {name", entry), "{} {not a key} {name");
+}
+
+#[test]
+fn selected_cards_fill_the_named_or_first_template() {
+    let mut app = app_with_templates();
+    assert_eq!(
+        app.selected_cards_with_template("").unwrap(),
+        "- [Rust Book](https://doc.rust-lang.org/book/) — 40%"
+    );
+
+    app.visual_mode = true;
+    app.visual_start_index = 0;
+    app.visual_end_index = 2;
+    app.selected_entry_index = 2;
+    assert_eq!(
+        app.selected_cards_with_template("link").unwrap(),
+        "- [Rust Book](https://doc.rust-lang.org/book/) — 40%\n- [No URL]() — %\n- []() — %"
+    );
+    assert_eq!(app.selected_cards_with_template("note").unwrap().lines().last(), Some("2025-01-01 10:00:00: read chapter 4"));
+    assert_eq!(app.selected_cards_with_template("nope").unwrap_err(), "No template named nope");
+
+    app.templates.clear();
+    app.command_buffer = "yank-template".to_string();
+    app.execute_command();
+    assert_eq!(app.status_message, "No templates: add `template NAME TEXT` to ~/.revwrc");
+}